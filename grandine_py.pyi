@@ -1,14 +1,464 @@
-from typing import Protocol, Self, TypedDict
+import array
+import os
+from collections.abc import Buffer, Callable, Iterable, Iterator, Mapping, Sequence
+from typing import (
+    Any,
+    BinaryIO,
+    Literal,
+    NotRequired,
+    Protocol,
+    Self,
+    TypedDict,
+    overload,
+)
+
+class ApiMetadata(TypedDict):
+    version: NotRequired[str]
+    execution_optimistic: NotRequired[bool]
+    finalized: NotRequired[bool]
 
 class _SSZDeserializableObject(Protocol):
     @classmethod
-    def from_ssz(cls, i: bytes) -> Self: ...
+    def from_ssz(cls, i: Buffer) -> Self: ...
+    @classmethod
+    def from_ssz_snappy(cls, i: Buffer, framed: bool | None = None) -> Self: ...
+    @classmethod
+    def from_json(cls, i: Buffer) -> Self: ...
+    @classmethod
+    def from_json_file(cls, source: str | os.PathLike[str] | BinaryIO) -> Self: ...
+    @classmethod
+    def from_json_api(cls, i: Buffer) -> tuple[Self, ApiMetadata]: ...
     @classmethod
-    def from_json(cls, i: bytes) -> Self: ...
+    def from_json_batch(cls, items: Iterable[Buffer]) -> list[Self]: ...
+    @classmethod
+    def from_dict(cls, d: dict[str, Any]) -> Self: ...
+    @classmethod
+    def ssz_fixed_size(cls) -> int: ...
+    @classmethod
+    def ssz_min_size(cls) -> int: ...
+    @classmethod
+    def ssz_max_size(cls) -> int: ...
 
 class _SSZObject(_SSZDeserializableObject):
+    @overload
+    @classmethod
+    def from_ssz(cls, i: Buffer, strict: Literal[True] = True) -> Self: ...
+    @overload
+    @classmethod
+    def from_ssz(cls, i: Buffer, strict: Literal[False]) -> tuple[Self, int]: ...
+    @staticmethod
+    def hash_tree_root_of_ssz(i: Buffer) -> str: ...
+    @classmethod
+    def from_ssz_batch(cls, items: Iterable[Buffer]) -> list[Self]: ...
+    @classmethod
+    def to_ssz_batch(cls, objects: Iterable[Self]) -> list[bytes]: ...
+    @classmethod
+    def hash_tree_root_batch(cls, objects: Iterable[Self]) -> list[str]: ...
     def to_ssz(self) -> bytes: ...
+    def to_ssz_snappy(self, framed: bool = False) -> bytes: ...
     def to_json(self) -> bytes: ...
+    def to_json_file(
+        self, destination: str | os.PathLike[str] | BinaryIO
+    ) -> None: ...
+    def to_json_api(
+        self,
+        version: str,
+        execution_optimistic: bool | None = None,
+        finalized: bool | None = None,
+    ) -> bytes: ...
+    def to_json_canonical(self) -> bytes: ...
+    def to_pyspec_dict(self) -> dict[str, Any]: ...
+    def to_dict(self) -> Any: ...
+    def __getattr__(self, name: str) -> Any: ...
+    @classmethod
+    def from_pyspec_dict(cls, d: dict[str, Any]) -> Self: ...
+    def to_json_delta(self, base: Self) -> bytes: ...
+    @classmethod
+    def from_json_delta(cls, base: Self, delta: Buffer) -> Self: ...
+    def equals_ignoring(
+        self, other: Self, fields: Sequence[str] = ("signature", "state_root")
+    ) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __copy__(self) -> Self: ...
+    def __deepcopy__(self, memo: dict[int, Any]) -> Self: ...
+    def __reduce__(self) -> tuple[Callable[[type[Self]], Self], tuple[type[Self]]]: ...
+
+class SszList[T]:
+    """Lazy read-only view over an SSZ list; elements are converted on access."""
+
+    def __len__(self) -> int: ...
+    @overload
+    def __getitem__(self, index: int) -> T: ...
+    @overload
+    def __getitem__(self, index: slice) -> SszList[T]: ...
+    def __iter__(self) -> Iterator[T]: ...
+
+class ByteView:
+    """Zero-copy read-only view over bytes; supports the buffer protocol."""
+
+    def __len__(self) -> int: ...
+    def __bytes__(self) -> bytes: ...
+    def __buffer__(self, flags: int, /) -> memoryview: ...
+
+class _BlockObject(_SSZObject):
+    def slot(self, config: Config | None = None) -> Slot: ...
+    @overload
+    def signing_root(self, domain: bytes | str, /) -> str: ...
+    @overload
+    def signing_root(
+        self, genesis_validators_root: bytes | str, fork_version: bytes | str, /
+    ) -> str: ...
+    def requests_hash(self) -> str: ...
+    def attestations(self) -> SszList[_SSZObject]: ...
+    def proposer_slashings(self) -> SszList[ProposerSlashing]: ...
+    def attester_slashings(self) -> SszList[_SSZObject]: ...
+    def deposits(self) -> SszList[Deposit]: ...
+    def voluntary_exits(self) -> SszList[SignedVoluntaryExit]: ...
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
+    def prove(self, generalized_index: int) -> list[bytes]: ...
+    def prove_path(self, path: Sequence[str | int]) -> list[bytes]: ...
+    def generalized_index(self, path: Sequence[str | int]) -> int: ...
+
+class _PayloadBlockObject(_BlockObject):
+    def payload_stats(self) -> PayloadStats: ...
+    def el_withdrawals_root(self) -> str: ...
+    def transactions(self) -> SszList[bytes]: ...
+    def withdrawals(self) -> SszList[dict[str, str]]: ...
+
+class _HistoricalBlockObject(_SSZObject):
+    def slot(self, config: Config | None = None) -> Slot: ...
+    @overload
+    def signing_root(self, domain: bytes | str, /) -> str: ...
+    @overload
+    def signing_root(
+        self, genesis_validators_root: bytes | str, fork_version: bytes | str, /
+    ) -> str: ...
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def attestations(self) -> SszList[_SSZObject]: ...
+    def proposer_slashings(self) -> SszList[ProposerSlashing]: ...
+    def deposits(self) -> SszList[Deposit]: ...
+    def voluntary_exits(self) -> SszList[SignedVoluntaryExit]: ...
+
+class _HistoricalPayloadBlockObject(_HistoricalBlockObject):
+    def transactions(self) -> SszList[bytes]: ...
+
+class _ContentsObject(_PayloadBlockObject):
+    def blobs(self) -> SszList[ByteView]: ...
+    def kzg_proofs(self) -> SszList[ByteView]: ...
+    def verify_blob_kzg_proofs(self, setup: TrustedSetup | None = None) -> bool: ...
+
+class DecodeLimits(TypedDict):
+    max_input_size: int | None
+    max_list_length: int | None
+    max_json_depth: int | None
+
+def set_decode_limits(
+    *,
+    max_input_size: int | None = None,
+    max_list_length: int | None = None,
+    max_json_depth: int | None = None,
+) -> None:
+    """Limit the input accepted by `from_ssz` and `from_json`; `None` disables a limit."""
+    ...
+
+def get_decode_limits() -> DecodeLimits: ...
+
+class HexFormat(TypedDict):
+    uppercase: bool
+    prefix: bool
+
+def set_hex_format(*, uppercase: bool = False, prefix: bool = True) -> None:
+    """Control hex casing and the `0x` prefix in `to_json`, dicts and roots."""
+    ...
+
+def get_hex_format() -> HexFormat: ...
+
+class BlsEncodingError(ValueError): ...
+class BlsHexError(BlsEncodingError): ...
+class BlsLengthError(BlsEncodingError): ...
+class BlsPointError(BlsEncodingError): ...
+
+def validate_signature_bytes(signature: bytes | str) -> None:
+    """Check that `signature` is a valid compressed G2 point."""
+    ...
+
+def validate_pubkey_bytes(pubkey: bytes | str) -> None:
+    """Check that `pubkey` is a valid compressed G1 point."""
+    ...
+
+def bls_sign(secret_key: bytes | str, signing_root: bytes | str) -> str:
+    """Sign a signing root with a BLS secret key; returns the signature hex."""
+    ...
+
+def bls_pubkey(secret_key: bytes | str) -> str: ...
+
+class TrustedSetup:
+    """A KZG trusted setup for verifying blob commitments and proofs."""
+
+    @staticmethod
+    def mainnet() -> TrustedSetup: ...
+    @property
+    def is_mainnet(self) -> bool: ...
+
+def load_trusted_setup(path_or_bytes: bytes | str | os.PathLike[str]) -> TrustedSetup:
+    """Load a `trusted_setup.txt` or consensus-specs JSON trusted setup."""
+    ...
+
+def set_trusted_setup(setup: TrustedSetup | None = None) -> None:
+    """Set the default trusted setup; `None` restores the embedded mainnet setup."""
+    ...
+
+def get_trusted_setup() -> TrustedSetup: ...
+
+def verify_blob_kzg_proof_batch(
+    blobs: Sequence[Buffer],
+    commitments: Sequence[Buffer],
+    proofs: Sequence[Buffer],
+    setup: TrustedSetup | None = None,
+) -> bool:
+    """Verify blob KZG proofs in parallel batches, reading `bytes` blobs in place."""
+    ...
+
+class PresetConstants:
+    """Constants of one preset, e.g. `presets.GNOSIS.SLOTS_PER_EPOCH`."""
+
+    @property
+    def preset(self) -> str: ...
+    SLOTS_PER_EPOCH: int
+    MAX_COMMITTEES_PER_SLOT: int
+    MAX_VALIDATORS_PER_COMMITTEE: int
+    SYNC_COMMITTEE_SIZE: int
+    MAX_WITHDRAWALS_PER_PAYLOAD: int
+    MAX_BLOB_COMMITMENTS_PER_BLOCK: int
+    def __getattr__(self, name: str) -> int: ...
+    def as_dict(self) -> dict[str, int]: ...
+
+class _PresetsModule:
+    PresetConstants: type[PresetConstants]
+    MAINNET: PresetConstants
+    MINIMAL: PresetConstants
+    GNOSIS: PresetConstants
+
+presets: _PresetsModule
+
+def diff_presets(
+    preset_a: str | PresetConstants, preset_b: str | PresetConstants
+) -> dict[str, tuple[int, int]]:
+    """Return the constants that differ between two presets."""
+    ...
+
+class Config:
+    def __init__(
+        self,
+        name: str,
+        preset: str,
+        seconds_per_slot: int,
+        genesis_time: int = 0,
+        fulu_fork_epoch: int = 2**64 - 1,
+        max_blobs_per_block_electra: int = 9,
+    ) -> None: ...
+    @staticmethod
+    def mainnet() -> Config: ...
+    @staticmethod
+    def hoodi() -> Config: ...
+    @staticmethod
+    def gnosis() -> Config: ...
+    @staticmethod
+    def chiado() -> Config: ...
+    @staticmethod
+    def minimal() -> Config: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def preset(self) -> str: ...
+    @property
+    def seconds_per_slot(self) -> int: ...
+    @property
+    def genesis_time(self) -> int: ...
+    @property
+    def fulu_fork_epoch(self) -> int: ...
+    @property
+    def max_blobs_per_block_electra(self) -> int: ...
+    @property
+    def slots_per_epoch(self) -> int: ...
+    def slot(self, value: int) -> Slot: ...
+    def epoch(self, value: int) -> Epoch: ...
+
+def diff_configs(
+    config_a: Config, config_b: Config
+) -> dict[str, tuple[str | int, str | int]]:
+    """Return the config values and preset constants that differ."""
+    ...
+
+class _TypedInt:
+    @property
+    def value(self) -> int: ...
+    @property
+    def config(self) -> Config: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __hash__(self) -> int: ...
+    def __lt__(self, other: Self | int) -> bool: ...
+    def __le__(self, other: Self | int) -> bool: ...
+    def __gt__(self, other: Self | int) -> bool: ...
+    def __ge__(self, other: Self | int) -> bool: ...
+    def __add__(self, other: int) -> Self: ...
+    def __radd__(self, other: int) -> Self: ...
+    @overload
+    def __sub__(self, other: Self) -> int: ...
+    @overload
+    def __sub__(self, other: int) -> Self: ...
+
+class Slot(_TypedInt):
+    def __init__(self, value: int, config: Config | None = None) -> None: ...
+    def epoch(self) -> Epoch: ...
+    def index_in_epoch(self) -> int: ...
+    def is_epoch_start(self) -> bool: ...
+    def start_time(self) -> int: ...
+
+class Epoch(_TypedInt):
+    def __init__(self, value: int, config: Config | None = None) -> None: ...
+    def start_slot(self) -> Slot: ...
+    def end_slot(self) -> Slot: ...
+    def start_time(self) -> int: ...
+    def __contains__(self, slot: Slot) -> bool: ...
+
+type _Root = bytes | str
+
+class SlotClock:
+    """Maps time to slots of a config using a monotonic clock."""
+
+    def __init__(self, config: Config | None = None) -> None: ...
+    @property
+    def config(self) -> Config: ...
+    def resync(self) -> None: ...
+    def time(self, now: float | None = None) -> float: ...
+    def current_slot(self, now: float | None = None) -> Slot | None: ...
+    def current_epoch(self, now: float | None = None) -> Epoch | None: ...
+    def time_to_slot_start(self, slot: Slot | int, now: float | None = None) -> float: ...
+    def time_to_next_slot(self, now: float | None = None) -> float: ...
+    def slot_progress(self, now: float | None = None) -> float: ...
+
+def seconds_into_slot(
+    timestamp: float, slot: Slot | int, config: Config | None = None
+) -> float:
+    """Seconds from the start of `slot` to `timestamp`; negative if before it."""
+    ...
+
+class LatencyDistribution(TypedDict):
+    slot: int
+    count: int
+    min: float
+    max: float
+    mean: float
+    p50: float
+    p90: float
+    p99: float
+
+class ArrivalTracker:
+    """Records first arrivals by root and their latency into the slot."""
+
+    def __init__(self, config: Config | None = None) -> None: ...
+    @property
+    def config(self) -> Config: ...
+    def record(
+        self, root: _Root, slot: Slot | int, timestamp: float | None = None
+    ) -> float | None: ...
+    def latency(self, root: _Root) -> float | None: ...
+    def latencies(self, slot: Slot | int) -> list[float]: ...
+    def distribution(self, slot: Slot | int) -> LatencyDistribution | None: ...
+    def prune(self, slot: Slot | int) -> int: ...
+    def __len__(self) -> int: ...
+    def __contains__(self, root: _Root) -> bool: ...
+
+class StateCache:
+    def __init__(self, capacity: int = 4) -> None: ...
+    @property
+    def capacity(self) -> int: ...
+    def get(self, root: _Root, epoch: int) -> object | None: ...
+    def insert(self, root: _Root, epoch: int, state: object) -> None: ...
+    def get_or_insert(
+        self, root: _Root, epoch: int, factory: Callable[[], object]
+    ) -> object: ...
+    def pin(self, root: _Root, epoch: int) -> None: ...
+    def unpin(self, root: _Root, epoch: int) -> None: ...
+    def evict(self, root: _Root, epoch: int) -> bool: ...
+    def clear(self) -> None: ...
+    def keys(self) -> list[tuple[str, int]]: ...
+    def __len__(self) -> int: ...
+    def __contains__(self, key: tuple[_Root, int]) -> bool: ...
+
+class GossipMessage:
+    @property
+    def timestamp(self) -> float: ...
+    @property
+    def timestamp_ns(self) -> int: ...
+    @property
+    def topic(self) -> str: ...
+    @property
+    def data(self) -> bytes: ...
+    def ssz(self) -> bytes: ...
+    def message_id(self) -> str: ...
+
+class GossipCaptureWriter:
+    def __init__(self, path: str | os.PathLike[str]) -> None: ...
+    def write(self, topic: str, data: bytes, timestamp: float | None = None) -> None: ...
+    def flush(self) -> None: ...
+    def close(self) -> None: ...
+    def __enter__(self) -> Self: ...
+    def __exit__(self, *args: object) -> None: ...
+
+class GossipReplay(Iterator[GossipMessage]):
+    def __next__(self) -> GossipMessage: ...
+
+class GossipCaptureReader:
+    def __init__(
+        self, path: str | os.PathLike[str], config: Config | None = None
+    ) -> None: ...
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> GossipMessage: ...
+    def __iter__(self) -> GossipReplay: ...
+    def replay(self, speed: float = 1.0) -> GossipReplay: ...
+    def decode(self, message: GossipMessage) -> object: ...
+
+class EraWriter:
+    def __init__(
+        self, path: str | os.PathLike[str], preset: str = "mainnet"
+    ) -> None: ...
+    def add_block(self, block: object) -> None: ...
+    def finish(self, state: object) -> int:
+        """Write the state ending the era and the indices; returns the era number."""
+        ...
+    def close(self) -> None: ...
+    def __enter__(self) -> Self: ...
+    def __exit__(self, *args: object) -> None: ...
+
+class EraBlocks(Iterator[Any]):
+    def __next__(self) -> Any: ...
+
+class EraReader:
+    def __init__(
+        self,
+        path: str | os.PathLike[str],
+        preset: str = "mainnet",
+        fork: str | None = None,
+        fork_schedule: Mapping[str, int] | None = None,
+    ) -> None: ...
+    @property
+    def era(self) -> int: ...
+    @property
+    def starting_slot(self) -> int | None: ...
+    @property
+    def state_slot(self) -> int: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> EraBlocks: ...
+    def slots(self) -> list[int]: ...
+    def block_ssz(self, slot: int) -> bytes | None: ...
+    def block(self, slot: int) -> Any | None: ...
+    def state_ssz(self) -> bytes: ...
+    def state(self) -> Any: ...
 
 class BlockHeaderDict(TypedDict):
     slot: str
@@ -17,77 +467,1692 @@ class BlockHeaderDict(TypedDict):
     state_root: str
     body_root: str
 
+# (slot, proposer_index, parent_root, state_root, body_root)
+type HeaderTuple = tuple[int, int, bytes, bytes, bytes]
+
+class ProducedBlock:
+    """A parsed `produceBlockV3` response, tagged by `execution_payload_blinded`."""
+
+    @property
+    def version(self) -> str: ...
+    @property
+    def execution_payload_blinded(self) -> bool: ...
+    @property
+    def execution_payload_value(self) -> int | None: ...
+    @property
+    def consensus_block_value(self) -> int | None: ...
+    @property
+    def block(self) -> Any: ...
+
+class BlockBodyBuilder:
+    """Assembles an Electra block body piece by piece, validated by `build()`."""
+
+    def __init__(self, config: Config | None = None) -> None: ...
+    def set_randao_reveal(self, signature: str) -> None: ...
+    def set_eth1_data(
+        self, deposit_root: bytes | str, deposit_count: int, block_hash: bytes | str
+    ) -> None: ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_sync_aggregate(self, bits: bytes, signature: str) -> None: ...
+    def add_attestation(self, attestation: _SSZObject) -> None: ...
+    def set_execution_payload(self, payload: _SSZObject) -> None: ...
+    def set_execution_payload_header(self, header: _SSZObject) -> None: ...
+    def set_blob_kzg_commitments(self, commitments: list[bytes]) -> None: ...
+    def set_blobs(self, blobs: list[bytes], kzg_proofs: list[bytes]) -> None: ...
+    def build(
+        self,
+        slot: int,
+        proposer_index: int,
+        parent_root: bytes | str,
+        state_root: bytes | str | None = None,
+    ) -> Any:
+        """Block contents if a payload was set, a blinded block if a header was set."""
+        ...
+
+class PayloadStats(TypedDict):
+    gas_used: int
+    gas_limit: int
+    base_fee_per_gas: int
+    blob_gas_used: int
+    excess_blob_gas: int
+    tx_count: int
+    blob_tx_count: int
+    payload_size: int
+
+class TransactionGroup(TypedDict):
+    count: int
+    transactions: list[bytes]
+
+class GnoWithdrawal(TypedDict):
+    index: int
+    validator_index: int
+    address: str
+    amount: int
+    amount_gno_wei: int
+
+def gwei_to_gno_wei(amount: int) -> int:
+    """Convert a Gnosis Chain Gwei amount (of mGNO) to wei of GNO paid out on withdrawal."""
+    ...
+
+type PubkeyProvider = PubkeyIndexMap | Callable[[int], bytes | str]
+type CommitteeProvider = Callable[[int, int], Sequence[int]]
+
+class SignatureResults(TypedDict):
+    proposer: bool
+    randao_reveal: bool
+    attestations: list[bool] | None
+    sync_aggregate: bool | None
+
 # Mainnet classes
-class ElectraSignedBeaconBlockMainnet(_SSZObject): ...
-class ElectraSignedBuilderBidMainnet(_SSZDeserializableObject): ...
+class ElectraSignedBeaconBlockMainnet(_PayloadBlockObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def verify_signatures(
+        self,
+        pubkey_provider: PubkeyProvider,
+        genesis_validators_root: bytes | str,
+        fork_version: bytes | str,
+        committees: CommitteeProvider | None = None,
+        sync_committee: Sequence[int] | None = None,
+    ) -> SignatureResults:
+        """Batch-verify the proposer, RANDAO, attestation and sync aggregate signatures."""
+        ...
+    def check_structure(self) -> list[str]: ...
+    def with_blobs(
+        self,
+        blobs: Iterable[bytes],
+        proofs: Iterable[bytes],
+        verify: bool = False,
+        setup: TrustedSetup | None = None,
+    ) -> ElectraSignedBeaconBlockContentsMainnet: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def blind(self) -> ElectraSignedBlindedBeaconBlockMainnet: ...
+
+class ElectraSignedBuilderBidMainnet(_SSZDeserializableObject):
+    def value(self) -> int: ...
+    def pubkey(self) -> str: ...
+    def verify(
+        self, builder_pubkey: bytes | str, genesis_fork_version: bytes | str
+    ) -> bool: ...
 
-class ElectraBeaconBlockContentsMainnet(_SSZObject):
+class ElectraBeaconBlockContentsMainnet(_ContentsObject):
     def header_dict(self) -> BlockHeaderDict: ...
+    def header_tuple(self) -> HeaderTuple: ...
     def sign(self, signature: str) -> ElectraSignedBeaconBlockContentsMainnet: ...
     def block_hash_tree_root(self) -> str: ...
+    def body_root(self) -> str: ...
+    def payload_root(self) -> str: ...
     def replace_execution_payload_with_builder_bid(
         self, signed_builder_bid: ElectraSignedBuilderBidMainnet
     ) -> ElectraBlindedBeaconBlockMainnet: ...
+    def clone_mut(self) -> ElectraMutableBeaconBlockContentsMainnet: ...
+
+class ElectraMutableBeaconBlockContentsMainnet:
+    """Mutable copy of block contents, from `clone_mut()`."""
+
+    def freeze(self) -> ElectraBeaconBlockContentsMainnet: ...
+    def to_ssz(self) -> bytes: ...
+    def to_json(self) -> bytes: ...
+    def recompute_roots(self) -> dict[str, tuple[str, str]]:
+        """Fix state-independent roots in place and return `{path: (old, new)}`."""
+        ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_state_root(self, state_root: bytes | str) -> None: ...
+    def set_execution_payload(self, payload: ElectraExecutionPayloadMainnet) -> None: ...
 
-class ElectraSignedBeaconBlockContentsMainnet(_SSZObject): ...
+class ElectraSignedBeaconBlockContentsMainnet(_ContentsObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def strip_blobs(self) -> ElectraSignedBeaconBlockMainnet: ...
+    def blob_sidecars(self) -> list[ElectraBlobSidecarMainnet]: ...
 
-class ElectraBlindedBeaconBlockMainnet(_SSZObject):
+class ElectraBlindedBeaconBlockMainnet(_BlockObject):
+    @staticmethod
+    def from_produce_block_v3(
+        json_bytes: bytes, headers: Mapping[str, str] | None = None
+    ) -> ProducedBlock: ...
     def header_dict(self) -> BlockHeaderDict: ...
+    def header_tuple(self) -> HeaderTuple: ...
     def sign(self, signature: str) -> ElectraSignedBlindedBeaconBlockMainnet: ...
     def block_hash_tree_root(self) -> str: ...
+    def body_root(self) -> str: ...
+    def payload_root(self) -> str: ...
     def replace_execution_payload_with_builder_bid(
         self, signed_builder_bid: ElectraSignedBuilderBidMainnet
     ) -> ElectraBlindedBeaconBlockMainnet: ...
+    def clone_mut(self) -> ElectraMutableBlindedBeaconBlockMainnet: ...
+
+class ElectraMutableBlindedBeaconBlockMainnet:
+    """Mutable copy of a blinded block, from `clone_mut()`."""
+
+    def freeze(self) -> ElectraBlindedBeaconBlockMainnet: ...
+    def to_ssz(self) -> bytes: ...
+    def to_json(self) -> bytes: ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_state_root(self, state_root: bytes | str) -> None: ...
+    def set_execution_payload_header(
+        self, header: ElectraExecutionPayloadHeaderMainnet
+    ) -> None: ...
+
+class ElectraSignedBlindedBeaconBlockMainnet(_BlockObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def unblind(
+        self, execution_payload_ssz: bytes, blobs_bundle_ssz: bytes
+    ) -> ElectraSignedBeaconBlockContentsMainnet: ...
+
+class ElectraExecutionPayloadMainnet(_SSZObject):
+    def payload_stats(self) -> PayloadStats: ...
+    def transactions_by_type(self) -> dict[str, TransactionGroup]: ...
+    def el_withdrawals_root(self) -> str: ...
+    def transactions(self) -> SszList[bytes]: ...
+    def withdrawals(self) -> SszList[dict[str, str]]: ...
+    def block_hash(self) -> str: ...
+    def el_transactions_root(self) -> str: ...
+
+class ElectraExecutionPayloadHeaderMainnet(_SSZObject):
+    @staticmethod
+    def from_el_header_json(
+        rpc_block_json: Buffer, raw_transactions: list[bytes] | None = None
+    ) -> ElectraExecutionPayloadHeaderMainnet: ...
+    def block_hash(self) -> str: ...
+    def transactions_root(self) -> str: ...
+    def withdrawals_root(self) -> str: ...
+
+class ElectraExecutionPayloadAndBlobsBundleMainnet(_SSZObject):
+    def execution_payload(self) -> ElectraExecutionPayloadMainnet: ...
+    def kzg_commitments(self) -> SszList[ByteView]: ...
+    def kzg_proofs(self) -> SszList[ByteView]: ...
+    def blobs(self) -> SszList[ByteView]: ...
+    def block_hash(self) -> str: ...
+    def el_transactions_root(self) -> str: ...
+    def el_withdrawals_root(self) -> str: ...
+
+class ElectraAttestationMainnet(_SSZObject):
+    def aggregation_bits(self) -> SszList[bool]: ...
+    def hash_tree_root(self) -> str: ...
+    def to_phase0(self) -> Phase0AttestationMainnet: ...
+    def committee_indices(self) -> list[int]: ...
+    def for_committee(
+        self, index: int, committee_sizes: list[int] | None = None
+    ) -> list[bool]: ...
+
+class ElectraIndexedAttestationMainnet(_SSZObject):
+    def attesting_indices(self) -> list[int]: ...
+    def hash_tree_root(self) -> str: ...
+
+class ElectraAttesterSlashingMainnet(_SSZObject):
+    def attestation_1(self) -> ElectraIndexedAttestationMainnet: ...
+    def attestation_2(self) -> ElectraIndexedAttestationMainnet: ...
+    def slashable_indices(self) -> list[int]: ...
+    def hash_tree_root(self) -> str: ...
+
+class Phase0AttestationMainnet(_SSZObject):
+    def aggregation_bits(self) -> SszList[bool]: ...
+    def to_electra(self) -> ElectraAttestationMainnet: ...
+
+class ElectraBeaconStateMainnet(_SSZObject):
+    def hash_tree_root(self) -> str: ...
+    def prove(self, generalized_index: int) -> list[bytes]: ...
+    def prove_path(self, path: Sequence[str | int]) -> list[bytes]: ...
+    def generalized_index(self, path: Sequence[str | int]) -> int: ...
+
+class FuluSignedBeaconBlockMainnet(_PayloadBlockObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def verify_signatures(
+        self,
+        pubkey_provider: PubkeyProvider,
+        genesis_validators_root: bytes | str,
+        fork_version: bytes | str,
+        committees: CommitteeProvider | None = None,
+        sync_committee: Sequence[int] | None = None,
+    ) -> SignatureResults:
+        """Batch-verify the proposer, RANDAO, attestation and sync aggregate signatures."""
+        ...
+    def check_structure(self) -> list[str]: ...
+    def with_blobs(
+        self,
+        blobs: Iterable[bytes],
+        cell_proofs: Iterable[bytes],
+        verify: bool = False,
+        setup: TrustedSetup | None = None,
+    ) -> FuluSignedBeaconBlockContentsMainnet: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def blind(self) -> FuluSignedBlindedBeaconBlockMainnet: ...
+
+class FuluBeaconBlockContentsMainnet(_ContentsObject):
+    def header_dict(self) -> BlockHeaderDict: ...
+    def header_tuple(self) -> HeaderTuple: ...
+    def sign(self, signature: str) -> FuluSignedBeaconBlockContentsMainnet: ...
+    def block_hash_tree_root(self) -> str: ...
+    def body_root(self) -> str: ...
+    def payload_root(self) -> str: ...
+    def clone_mut(self) -> FuluMutableBeaconBlockContentsMainnet: ...
+
+class FuluMutableBeaconBlockContentsMainnet:
+    """Mutable copy of block contents, from `clone_mut()`."""
+
+    def freeze(self) -> FuluBeaconBlockContentsMainnet: ...
+    def to_ssz(self) -> bytes: ...
+    def to_json(self) -> bytes: ...
+    def recompute_roots(self) -> dict[str, tuple[str, str]]:
+        """Fix state-independent roots in place and return `{path: (old, new)}`."""
+        ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_state_root(self, state_root: bytes | str) -> None: ...
+    def set_execution_payload(self, payload: ElectraExecutionPayloadMainnet) -> None: ...
+
+class FuluSignedBeaconBlockContentsMainnet(_ContentsObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def strip_blobs(self) -> FuluSignedBeaconBlockMainnet: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+
+class FuluBlindedBeaconBlockMainnet(_BlockObject):
+    def header_dict(self) -> BlockHeaderDict: ...
+    def header_tuple(self) -> HeaderTuple: ...
+    def sign(self, signature: str) -> FuluSignedBlindedBeaconBlockMainnet: ...
+    def block_hash_tree_root(self) -> str: ...
+    def body_root(self) -> str: ...
+    def payload_root(self) -> str: ...
+    def clone_mut(self) -> FuluMutableBlindedBeaconBlockMainnet: ...
+
+class FuluMutableBlindedBeaconBlockMainnet:
+    """Mutable copy of a blinded block, from `clone_mut()`."""
+
+    def freeze(self) -> FuluBlindedBeaconBlockMainnet: ...
+    def to_ssz(self) -> bytes: ...
+    def to_json(self) -> bytes: ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_state_root(self, state_root: bytes | str) -> None: ...
+    def set_execution_payload_header(
+        self, header: ElectraExecutionPayloadHeaderMainnet
+    ) -> None: ...
+
+class FuluSignedBlindedBeaconBlockMainnet(_BlockObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def unblind(
+        self, execution_payload_ssz: bytes, blobs_bundle_ssz: bytes
+    ) -> FuluSignedBeaconBlockContentsMainnet: ...
+
+class FuluSignedBeaconBlockMinimal(_PayloadBlockObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def verify_signatures(
+        self,
+        pubkey_provider: PubkeyProvider,
+        genesis_validators_root: bytes | str,
+        fork_version: bytes | str,
+        committees: CommitteeProvider | None = None,
+        sync_committee: Sequence[int] | None = None,
+    ) -> SignatureResults:
+        """Batch-verify the proposer, RANDAO, attestation and sync aggregate signatures."""
+        ...
+    def check_structure(self) -> list[str]: ...
+    def with_blobs(
+        self,
+        blobs: Iterable[bytes],
+        cell_proofs: Iterable[bytes],
+        verify: bool = False,
+        setup: TrustedSetup | None = None,
+    ) -> FuluSignedBeaconBlockContentsMinimal: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def blind(self) -> FuluSignedBlindedBeaconBlockMinimal: ...
+
+class FuluBeaconBlockContentsMinimal(_ContentsObject):
+    def header_dict(self) -> BlockHeaderDict: ...
+    def header_tuple(self) -> HeaderTuple: ...
+    def sign(self, signature: str) -> FuluSignedBeaconBlockContentsMinimal: ...
+    def block_hash_tree_root(self) -> str: ...
+    def body_root(self) -> str: ...
+    def payload_root(self) -> str: ...
+    def clone_mut(self) -> FuluMutableBeaconBlockContentsMinimal: ...
+
+class FuluMutableBeaconBlockContentsMinimal:
+    """Mutable copy of block contents, from `clone_mut()`."""
+
+    def freeze(self) -> FuluBeaconBlockContentsMinimal: ...
+    def to_ssz(self) -> bytes: ...
+    def to_json(self) -> bytes: ...
+    def recompute_roots(self) -> dict[str, tuple[str, str]]:
+        """Fix state-independent roots in place and return `{path: (old, new)}`."""
+        ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_state_root(self, state_root: bytes | str) -> None: ...
+    def set_execution_payload(self, payload: ElectraExecutionPayloadMinimal) -> None: ...
+
+class FuluSignedBeaconBlockContentsMinimal(_ContentsObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def strip_blobs(self) -> FuluSignedBeaconBlockMinimal: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+
+class FuluBlindedBeaconBlockMinimal(_BlockObject):
+    def header_dict(self) -> BlockHeaderDict: ...
+    def header_tuple(self) -> HeaderTuple: ...
+    def sign(self, signature: str) -> FuluSignedBlindedBeaconBlockMinimal: ...
+    def block_hash_tree_root(self) -> str: ...
+    def body_root(self) -> str: ...
+    def payload_root(self) -> str: ...
+    def clone_mut(self) -> FuluMutableBlindedBeaconBlockMinimal: ...
+
+class FuluMutableBlindedBeaconBlockMinimal:
+    """Mutable copy of a blinded block, from `clone_mut()`."""
+
+    def freeze(self) -> FuluBlindedBeaconBlockMinimal: ...
+    def to_ssz(self) -> bytes: ...
+    def to_json(self) -> bytes: ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_state_root(self, state_root: bytes | str) -> None: ...
+    def set_execution_payload_header(
+        self, header: ElectraExecutionPayloadHeaderMinimal
+    ) -> None: ...
+
+class FuluSignedBlindedBeaconBlockMinimal(_BlockObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def unblind(
+        self, execution_payload_ssz: bytes, blobs_bundle_ssz: bytes
+    ) -> FuluSignedBeaconBlockContentsMinimal: ...
+
+class FuluSignedBeaconBlockGnosis(_PayloadBlockObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def verify_signatures(
+        self,
+        pubkey_provider: PubkeyProvider,
+        genesis_validators_root: bytes | str,
+        fork_version: bytes | str,
+        committees: CommitteeProvider | None = None,
+        sync_committee: Sequence[int] | None = None,
+    ) -> SignatureResults:
+        """Batch-verify the proposer, RANDAO, attestation and sync aggregate signatures."""
+        ...
+    def check_structure(self) -> list[str]: ...
+    def with_blobs(
+        self,
+        blobs: Iterable[bytes],
+        cell_proofs: Iterable[bytes],
+        verify: bool = False,
+        setup: TrustedSetup | None = None,
+    ) -> FuluSignedBeaconBlockContentsGnosis: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def blind(self) -> FuluSignedBlindedBeaconBlockGnosis: ...
+
+class FuluBeaconBlockContentsGnosis(_ContentsObject):
+    def header_dict(self) -> BlockHeaderDict: ...
+    def header_tuple(self) -> HeaderTuple: ...
+    def sign(self, signature: str) -> FuluSignedBeaconBlockContentsGnosis: ...
+    def block_hash_tree_root(self) -> str: ...
+    def body_root(self) -> str: ...
+    def payload_root(self) -> str: ...
+    def clone_mut(self) -> FuluMutableBeaconBlockContentsGnosis: ...
+
+class FuluMutableBeaconBlockContentsGnosis:
+    """Mutable copy of block contents, from `clone_mut()`."""
+
+    def freeze(self) -> FuluBeaconBlockContentsGnosis: ...
+    def to_ssz(self) -> bytes: ...
+    def to_json(self) -> bytes: ...
+    def recompute_roots(self) -> dict[str, tuple[str, str]]:
+        """Fix state-independent roots in place and return `{path: (old, new)}`."""
+        ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_state_root(self, state_root: bytes | str) -> None: ...
+    def set_execution_payload(self, payload: ElectraExecutionPayloadGnosis) -> None: ...
+
+class FuluSignedBeaconBlockContentsGnosis(_ContentsObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def strip_blobs(self) -> FuluSignedBeaconBlockGnosis: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+
+class FuluBlindedBeaconBlockGnosis(_BlockObject):
+    def header_dict(self) -> BlockHeaderDict: ...
+    def header_tuple(self) -> HeaderTuple: ...
+    def sign(self, signature: str) -> FuluSignedBlindedBeaconBlockGnosis: ...
+    def block_hash_tree_root(self) -> str: ...
+    def body_root(self) -> str: ...
+    def payload_root(self) -> str: ...
+    def clone_mut(self) -> FuluMutableBlindedBeaconBlockGnosis: ...
+
+class FuluMutableBlindedBeaconBlockGnosis:
+    """Mutable copy of a blinded block, from `clone_mut()`."""
+
+    def freeze(self) -> FuluBlindedBeaconBlockGnosis: ...
+    def to_ssz(self) -> bytes: ...
+    def to_json(self) -> bytes: ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_state_root(self, state_root: bytes | str) -> None: ...
+    def set_execution_payload_header(
+        self, header: ElectraExecutionPayloadHeaderGnosis
+    ) -> None: ...
+
+class FuluSignedBlindedBeaconBlockGnosis(_BlockObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def unblind(
+        self, execution_payload_ssz: bytes, blobs_bundle_ssz: bytes
+    ) -> FuluSignedBeaconBlockContentsGnosis: ...
+
+class Phase0SignedBeaconBlockMainnet(_HistoricalBlockObject): ...
+
+class AltairSignedBeaconBlockMainnet(_HistoricalBlockObject): ...
+
+class BellatrixSignedBeaconBlockMainnet(_HistoricalPayloadBlockObject): ...
+
+class BellatrixSignedBlindedBeaconBlockMainnet(_HistoricalBlockObject): ...
+
+class CapellaSignedBeaconBlockMainnet(_HistoricalPayloadBlockObject):
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
+    def withdrawals(self) -> SszList[dict[str, str]]: ...
+
+class CapellaSignedBlindedBeaconBlockMainnet(_HistoricalBlockObject):
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
+
+class DenebSignedBeaconBlockMainnet(_HistoricalPayloadBlockObject):
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
+    def withdrawals(self) -> SszList[dict[str, str]]: ...
+
+class DenebSignedBlindedBeaconBlockMainnet(_HistoricalBlockObject):
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
+
+class Phase0SignedBeaconBlockMinimal(_HistoricalBlockObject): ...
+
+class AltairSignedBeaconBlockMinimal(_HistoricalBlockObject): ...
+
+class BellatrixSignedBeaconBlockMinimal(_HistoricalPayloadBlockObject): ...
+
+class BellatrixSignedBlindedBeaconBlockMinimal(_HistoricalBlockObject): ...
+
+class CapellaSignedBeaconBlockMinimal(_HistoricalPayloadBlockObject):
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
+    def withdrawals(self) -> SszList[dict[str, str]]: ...
+
+class CapellaSignedBlindedBeaconBlockMinimal(_HistoricalBlockObject):
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
+
+class DenebSignedBeaconBlockMinimal(_HistoricalPayloadBlockObject):
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
+    def withdrawals(self) -> SszList[dict[str, str]]: ...
+
+class DenebSignedBlindedBeaconBlockMinimal(_HistoricalBlockObject):
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
 
-class ElectraSignedBlindedBeaconBlockMainnet(_SSZObject): ...
+class Phase0SignedBeaconBlockGnosis(_HistoricalBlockObject): ...
+
+class AltairSignedBeaconBlockGnosis(_HistoricalBlockObject): ...
+
+class BellatrixSignedBeaconBlockGnosis(_HistoricalPayloadBlockObject): ...
+
+class BellatrixSignedBlindedBeaconBlockGnosis(_HistoricalBlockObject): ...
+
+class CapellaSignedBeaconBlockGnosis(_HistoricalPayloadBlockObject):
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
+    def withdrawals(self) -> SszList[dict[str, str]]: ...
+
+class CapellaSignedBlindedBeaconBlockGnosis(_HistoricalBlockObject):
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
+
+class DenebSignedBeaconBlockGnosis(_HistoricalPayloadBlockObject):
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
+    def withdrawals(self) -> SszList[dict[str, str]]: ...
+
+class DenebSignedBlindedBeaconBlockGnosis(_HistoricalBlockObject):
+    def bls_to_execution_changes(self) -> SszList[SignedBLSToExecutionChange]: ...
+
+class FuluBeaconStateMainnet(_SSZObject):
+    def proposer_lookahead(self) -> list[int]: ...
+    def prove(self, generalized_index: int) -> list[bytes]: ...
+    def prove_path(self, path: Sequence[str | int]) -> list[bytes]: ...
+    def generalized_index(self, path: Sequence[str | int]) -> int: ...
 
 # Minimal classes
-class ElectraSignedBeaconBlockMinimal(_SSZObject): ...
-class ElectraSignedBuilderBidMinimal(_SSZDeserializableObject): ...
+class ElectraSignedBeaconBlockMinimal(_PayloadBlockObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def verify_signatures(
+        self,
+        pubkey_provider: PubkeyProvider,
+        genesis_validators_root: bytes | str,
+        fork_version: bytes | str,
+        committees: CommitteeProvider | None = None,
+        sync_committee: Sequence[int] | None = None,
+    ) -> SignatureResults:
+        """Batch-verify the proposer, RANDAO, attestation and sync aggregate signatures."""
+        ...
+    def check_structure(self) -> list[str]: ...
+    def with_blobs(
+        self,
+        blobs: Iterable[bytes],
+        proofs: Iterable[bytes],
+        verify: bool = False,
+        setup: TrustedSetup | None = None,
+    ) -> ElectraSignedBeaconBlockContentsMinimal: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def blind(self) -> ElectraSignedBlindedBeaconBlockMinimal: ...
+
+class ElectraSignedBuilderBidMinimal(_SSZDeserializableObject):
+    def value(self) -> int: ...
+    def pubkey(self) -> str: ...
+    def verify(
+        self, builder_pubkey: bytes | str, genesis_fork_version: bytes | str
+    ) -> bool: ...
 
-class ElectraBeaconBlockContentsMinimal(_SSZObject):
+class ElectraBeaconBlockContentsMinimal(_ContentsObject):
     def header_dict(self) -> BlockHeaderDict: ...
+    def header_tuple(self) -> HeaderTuple: ...
     def sign(self, signature: str) -> ElectraSignedBeaconBlockContentsMinimal: ...
     def block_hash_tree_root(self) -> str: ...
+    def body_root(self) -> str: ...
+    def payload_root(self) -> str: ...
     def replace_execution_payload_with_builder_bid(
         self, signed_builder_bid: ElectraSignedBuilderBidMinimal
     ) -> ElectraBlindedBeaconBlockMinimal: ...
+    def clone_mut(self) -> ElectraMutableBeaconBlockContentsMinimal: ...
 
-class ElectraSignedBeaconBlockContentsMinimal(_SSZObject): ...
+class ElectraMutableBeaconBlockContentsMinimal:
+    """Mutable copy of block contents, from `clone_mut()`."""
 
-class ElectraBlindedBeaconBlockMinimal(_SSZObject):
+    def freeze(self) -> ElectraBeaconBlockContentsMinimal: ...
+    def to_ssz(self) -> bytes: ...
+    def to_json(self) -> bytes: ...
+    def recompute_roots(self) -> dict[str, tuple[str, str]]:
+        """Fix state-independent roots in place and return `{path: (old, new)}`."""
+        ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_state_root(self, state_root: bytes | str) -> None: ...
+    def set_execution_payload(self, payload: ElectraExecutionPayloadMinimal) -> None: ...
+
+class ElectraSignedBeaconBlockContentsMinimal(_ContentsObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def strip_blobs(self) -> ElectraSignedBeaconBlockMinimal: ...
+    def blob_sidecars(self) -> list[ElectraBlobSidecarMinimal]: ...
+
+class ElectraBlindedBeaconBlockMinimal(_BlockObject):
+    @staticmethod
+    def from_produce_block_v3(
+        json_bytes: bytes, headers: Mapping[str, str] | None = None
+    ) -> ProducedBlock: ...
     def header_dict(self) -> BlockHeaderDict: ...
+    def header_tuple(self) -> HeaderTuple: ...
     def sign(self, signature: str) -> ElectraSignedBlindedBeaconBlockMinimal: ...
     def block_hash_tree_root(self) -> str: ...
+    def body_root(self) -> str: ...
+    def payload_root(self) -> str: ...
     def replace_execution_payload_with_builder_bid(
         self, signed_builder_bid: ElectraSignedBuilderBidMinimal
     ) -> ElectraBlindedBeaconBlockMinimal: ...
+    def clone_mut(self) -> ElectraMutableBlindedBeaconBlockMinimal: ...
+
+class ElectraMutableBlindedBeaconBlockMinimal:
+    """Mutable copy of a blinded block, from `clone_mut()`."""
+
+    def freeze(self) -> ElectraBlindedBeaconBlockMinimal: ...
+    def to_ssz(self) -> bytes: ...
+    def to_json(self) -> bytes: ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_state_root(self, state_root: bytes | str) -> None: ...
+    def set_execution_payload_header(
+        self, header: ElectraExecutionPayloadHeaderMinimal
+    ) -> None: ...
+
+class ElectraSignedBlindedBeaconBlockMinimal(_BlockObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def unblind(
+        self, execution_payload_ssz: bytes, blobs_bundle_ssz: bytes
+    ) -> ElectraSignedBeaconBlockContentsMinimal: ...
+
+class ElectraExecutionPayloadMinimal(_SSZObject):
+    def payload_stats(self) -> PayloadStats: ...
+    def transactions_by_type(self) -> dict[str, TransactionGroup]: ...
+    def el_withdrawals_root(self) -> str: ...
+    def transactions(self) -> SszList[bytes]: ...
+    def withdrawals(self) -> SszList[dict[str, str]]: ...
+    def block_hash(self) -> str: ...
+    def el_transactions_root(self) -> str: ...
+
+class ElectraExecutionPayloadHeaderMinimal(_SSZObject):
+    @staticmethod
+    def from_el_header_json(
+        rpc_block_json: Buffer, raw_transactions: list[bytes] | None = None
+    ) -> ElectraExecutionPayloadHeaderMinimal: ...
+    def block_hash(self) -> str: ...
+    def transactions_root(self) -> str: ...
+    def withdrawals_root(self) -> str: ...
+
+class ElectraExecutionPayloadAndBlobsBundleMinimal(_SSZObject):
+    def execution_payload(self) -> ElectraExecutionPayloadMinimal: ...
+    def kzg_commitments(self) -> SszList[ByteView]: ...
+    def kzg_proofs(self) -> SszList[ByteView]: ...
+    def blobs(self) -> SszList[ByteView]: ...
+    def block_hash(self) -> str: ...
+    def el_transactions_root(self) -> str: ...
+    def el_withdrawals_root(self) -> str: ...
 
-class ElectraSignedBlindedBeaconBlockMinimal(_SSZObject): ...
+class ElectraAttestationMinimal(_SSZObject):
+    def aggregation_bits(self) -> SszList[bool]: ...
+    def hash_tree_root(self) -> str: ...
+    def to_phase0(self) -> Phase0AttestationMinimal: ...
+    def committee_indices(self) -> list[int]: ...
+    def for_committee(
+        self, index: int, committee_sizes: list[int] | None = None
+    ) -> list[bool]: ...
+
+class ElectraIndexedAttestationMinimal(_SSZObject):
+    def attesting_indices(self) -> list[int]: ...
+    def hash_tree_root(self) -> str: ...
+
+class ElectraAttesterSlashingMinimal(_SSZObject):
+    def attestation_1(self) -> ElectraIndexedAttestationMinimal: ...
+    def attestation_2(self) -> ElectraIndexedAttestationMinimal: ...
+    def slashable_indices(self) -> list[int]: ...
+    def hash_tree_root(self) -> str: ...
+
+class Phase0AttestationMinimal(_SSZObject):
+    def aggregation_bits(self) -> SszList[bool]: ...
+    def to_electra(self) -> ElectraAttestationMinimal: ...
+
+class ElectraBeaconStateMinimal(_SSZObject):
+    def hash_tree_root(self) -> str: ...
+    def prove(self, generalized_index: int) -> list[bytes]: ...
+    def prove_path(self, path: Sequence[str | int]) -> list[bytes]: ...
+    def generalized_index(self, path: Sequence[str | int]) -> int: ...
+
+class FuluBeaconStateMinimal(_SSZObject):
+    def proposer_lookahead(self) -> list[int]: ...
+    def prove(self, generalized_index: int) -> list[bytes]: ...
+    def prove_path(self, path: Sequence[str | int]) -> list[bytes]: ...
+    def generalized_index(self, path: Sequence[str | int]) -> int: ...
 
 # Gnosis classes
-class ElectraSignedBeaconBlockGnosis(_SSZObject): ...
-class ElectraSignedBuilderBidGnosis(_SSZDeserializableObject): ...
+class ElectraSignedBeaconBlockGnosis(_PayloadBlockObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def verify_signatures(
+        self,
+        pubkey_provider: PubkeyProvider,
+        genesis_validators_root: bytes | str,
+        fork_version: bytes | str,
+        committees: CommitteeProvider | None = None,
+        sync_committee: Sequence[int] | None = None,
+    ) -> SignatureResults:
+        """Batch-verify the proposer, RANDAO, attestation and sync aggregate signatures."""
+        ...
+    def check_structure(self) -> list[str]: ...
+    def gno_withdrawals(self) -> list[GnoWithdrawal]: ...
+    def with_blobs(
+        self,
+        blobs: Iterable[bytes],
+        proofs: Iterable[bytes],
+        verify: bool = False,
+        setup: TrustedSetup | None = None,
+    ) -> ElectraSignedBeaconBlockContentsGnosis: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def blind(self) -> ElectraSignedBlindedBeaconBlockGnosis: ...
 
-class ElectraBeaconBlockContentsGnosis(_SSZObject):
+class ElectraSignedBuilderBidGnosis(_SSZDeserializableObject):
+    def value(self) -> int: ...
+    def pubkey(self) -> str: ...
+    def verify(
+        self, builder_pubkey: bytes | str, genesis_fork_version: bytes | str
+    ) -> bool: ...
+
+class ElectraBeaconBlockContentsGnosis(_ContentsObject):
+    def gno_withdrawals(self) -> list[GnoWithdrawal]: ...
     def header_dict(self) -> BlockHeaderDict: ...
+    def header_tuple(self) -> HeaderTuple: ...
     def sign(self, signature: str) -> ElectraSignedBeaconBlockContentsGnosis: ...
     def block_hash_tree_root(self) -> str: ...
+    def body_root(self) -> str: ...
+    def payload_root(self) -> str: ...
     def replace_execution_payload_with_builder_bid(
         self, signed_builder_bid: ElectraSignedBuilderBidGnosis
     ) -> ElectraBlindedBeaconBlockGnosis: ...
+    def clone_mut(self) -> ElectraMutableBeaconBlockContentsGnosis: ...
+
+class ElectraMutableBeaconBlockContentsGnosis:
+    """Mutable copy of block contents, from `clone_mut()`."""
+
+    def freeze(self) -> ElectraBeaconBlockContentsGnosis: ...
+    def to_ssz(self) -> bytes: ...
+    def to_json(self) -> bytes: ...
+    def recompute_roots(self) -> dict[str, tuple[str, str]]:
+        """Fix state-independent roots in place and return `{path: (old, new)}`."""
+        ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_state_root(self, state_root: bytes | str) -> None: ...
+    def set_execution_payload(self, payload: ElectraExecutionPayloadGnosis) -> None: ...
 
-class ElectraSignedBeaconBlockContentsGnosis(_SSZObject): ...
+class ElectraSignedBeaconBlockContentsGnosis(_ContentsObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def gno_withdrawals(self) -> list[GnoWithdrawal]: ...
+    def strip_blobs(self) -> ElectraSignedBeaconBlockGnosis: ...
+    def blob_sidecars(self) -> list[ElectraBlobSidecarGnosis]: ...
 
-class ElectraBlindedBeaconBlockGnosis(_SSZObject):
+class ElectraBlindedBeaconBlockGnosis(_BlockObject):
+    @staticmethod
+    def from_produce_block_v3(
+        json_bytes: bytes, headers: Mapping[str, str] | None = None
+    ) -> ProducedBlock: ...
     def header_dict(self) -> BlockHeaderDict: ...
+    def header_tuple(self) -> HeaderTuple: ...
     def sign(self, signature: str) -> ElectraSignedBlindedBeaconBlockGnosis: ...
     def block_hash_tree_root(self) -> str: ...
+    def body_root(self) -> str: ...
+    def payload_root(self) -> str: ...
     def replace_execution_payload_with_builder_bid(
         self, signed_builder_bid: ElectraSignedBuilderBidGnosis
     ) -> ElectraBlindedBeaconBlockGnosis: ...
+    def clone_mut(self) -> ElectraMutableBlindedBeaconBlockGnosis: ...
+
+class ElectraMutableBlindedBeaconBlockGnosis:
+    """Mutable copy of a blinded block, from `clone_mut()`."""
+
+    def freeze(self) -> ElectraBlindedBeaconBlockGnosis: ...
+    def to_ssz(self) -> bytes: ...
+    def to_json(self) -> bytes: ...
+    def set_graffiti(self, graffiti: bytes | str) -> None: ...
+    def set_state_root(self, state_root: bytes | str) -> None: ...
+    def set_execution_payload_header(
+        self, header: ElectraExecutionPayloadHeaderGnosis
+    ) -> None: ...
+
+class ElectraSignedBlindedBeaconBlockGnosis(_BlockObject):
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+    def signed_header(self) -> SignedBeaconBlockHeader: ...
+    def unblind(
+        self, execution_payload_ssz: bytes, blobs_bundle_ssz: bytes
+    ) -> ElectraSignedBeaconBlockContentsGnosis: ...
+
+class ElectraExecutionPayloadGnosis(_SSZObject):
+    def gno_withdrawals(self) -> list[GnoWithdrawal]: ...
+    def payload_stats(self) -> PayloadStats: ...
+    def transactions_by_type(self) -> dict[str, TransactionGroup]: ...
+    def el_withdrawals_root(self) -> str: ...
+    def transactions(self) -> SszList[bytes]: ...
+    def withdrawals(self) -> SszList[dict[str, str]]: ...
+    def block_hash(self) -> str: ...
+    def el_transactions_root(self) -> str: ...
+
+class ElectraExecutionPayloadHeaderGnosis(_SSZObject):
+    @staticmethod
+    def from_el_header_json(
+        rpc_block_json: Buffer, raw_transactions: list[bytes] | None = None
+    ) -> ElectraExecutionPayloadHeaderGnosis: ...
+    def block_hash(self) -> str: ...
+    def transactions_root(self) -> str: ...
+    def withdrawals_root(self) -> str: ...
+
+class ElectraExecutionPayloadAndBlobsBundleGnosis(_SSZObject):
+    def execution_payload(self) -> ElectraExecutionPayloadGnosis: ...
+    def kzg_commitments(self) -> SszList[ByteView]: ...
+    def kzg_proofs(self) -> SszList[ByteView]: ...
+    def blobs(self) -> SszList[ByteView]: ...
+    def block_hash(self) -> str: ...
+    def el_transactions_root(self) -> str: ...
+    def el_withdrawals_root(self) -> str: ...
+
+class ElectraAttestationGnosis(_SSZObject):
+    def aggregation_bits(self) -> SszList[bool]: ...
+    def hash_tree_root(self) -> str: ...
+    def to_phase0(self) -> Phase0AttestationGnosis: ...
+    def committee_indices(self) -> list[int]: ...
+    def for_committee(
+        self, index: int, committee_sizes: list[int] | None = None
+    ) -> list[bool]: ...
+
+class ElectraIndexedAttestationGnosis(_SSZObject):
+    def attesting_indices(self) -> list[int]: ...
+    def hash_tree_root(self) -> str: ...
+
+class ElectraAttesterSlashingGnosis(_SSZObject):
+    def attestation_1(self) -> ElectraIndexedAttestationGnosis: ...
+    def attestation_2(self) -> ElectraIndexedAttestationGnosis: ...
+    def slashable_indices(self) -> list[int]: ...
+    def hash_tree_root(self) -> str: ...
+
+class Phase0AttestationGnosis(_SSZObject):
+    def aggregation_bits(self) -> SszList[bool]: ...
+    def to_electra(self) -> ElectraAttestationGnosis: ...
+
+class ElectraBeaconStateGnosis(_SSZObject):
+    def hash_tree_root(self) -> str: ...
+    def prove(self, generalized_index: int) -> list[bytes]: ...
+    def prove_path(self, path: Sequence[str | int]) -> list[bytes]: ...
+    def generalized_index(self, path: Sequence[str | int]) -> int: ...
+
+class FuluBeaconStateGnosis(_SSZObject):
+    def proposer_lookahead(self) -> list[int]: ...
+    def prove(self, generalized_index: int) -> list[bytes]: ...
+    def prove_path(self, path: Sequence[str | int]) -> list[bytes]: ...
+    def generalized_index(self, path: Sequence[str | int]) -> int: ...
+
+class ElectraSingleAttestation(_SSZObject): ...
+
+class ElectraAggregateAndProofMainnet(_SSZObject):
+    def aggregator_index(self) -> int: ...
+    def aggregate(self) -> ElectraAttestationMainnet: ...
+    def selection_proof(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def with_signature(
+        self, signature: bytes | str
+    ) -> ElectraSignedAggregateAndProofMainnet: ...
+
+class ElectraSignedAggregateAndProofMainnet(_SSZObject):
+    def message(self) -> ElectraAggregateAndProofMainnet: ...
+    def signature(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+
+class ElectraAggregateAndProofMinimal(_SSZObject):
+    def aggregator_index(self) -> int: ...
+    def aggregate(self) -> ElectraAttestationMinimal: ...
+    def selection_proof(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def with_signature(
+        self, signature: bytes | str
+    ) -> ElectraSignedAggregateAndProofMinimal: ...
+
+class ElectraSignedAggregateAndProofMinimal(_SSZObject):
+    def message(self) -> ElectraAggregateAndProofMinimal: ...
+    def signature(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+
+class ElectraAggregateAndProofGnosis(_SSZObject):
+    def aggregator_index(self) -> int: ...
+    def aggregate(self) -> ElectraAttestationGnosis: ...
+    def selection_proof(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def with_signature(
+        self, signature: bytes | str
+    ) -> ElectraSignedAggregateAndProofGnosis: ...
+
+class ElectraSignedAggregateAndProofGnosis(_SSZObject):
+    def message(self) -> ElectraAggregateAndProofGnosis: ...
+    def signature(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+
+@overload
+def make_aggregate_and_proof(
+    aggregator_index: int,
+    aggregate_attestation: ElectraAttestationMainnet,
+    selection_proof: bytes | str,
+) -> ElectraAggregateAndProofMainnet: ...
+@overload
+def make_aggregate_and_proof(
+    aggregator_index: int,
+    aggregate_attestation: ElectraAttestationMinimal,
+    selection_proof: bytes | str,
+) -> ElectraAggregateAndProofMinimal: ...
+@overload
+def make_aggregate_and_proof(
+    aggregator_index: int,
+    aggregate_attestation: ElectraAttestationGnosis,
+    selection_proof: bytes | str,
+) -> ElectraAggregateAndProofGnosis: ...
+
+class AltairSyncCommitteeMessage(_SSZObject):
+    def slot(self) -> int: ...
+    def beacon_block_root(self) -> str: ...
+    def validator_index(self) -> int: ...
+    def signature(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+
+class AltairSyncCommitteeContributionMainnet(_SSZObject):
+    def slot(self) -> int: ...
+    def beacon_block_root(self) -> str: ...
+    def subcommittee_index(self) -> int: ...
+    def aggregation_bits(self) -> SszList[bool]: ...
+    def signature(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+
+class AltairContributionAndProofMainnet(_SSZObject):
+    def aggregator_index(self) -> int: ...
+    def contribution(self) -> AltairSyncCommitteeContributionMainnet: ...
+    def selection_proof(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def with_signature(
+        self, signature: bytes | str
+    ) -> AltairSignedContributionAndProofMainnet: ...
+
+class AltairSignedContributionAndProofMainnet(_SSZObject):
+    def message(self) -> AltairContributionAndProofMainnet: ...
+    def signature(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+
+class AltairSyncCommitteeContributionMinimal(_SSZObject):
+    def slot(self) -> int: ...
+    def beacon_block_root(self) -> str: ...
+    def subcommittee_index(self) -> int: ...
+    def aggregation_bits(self) -> SszList[bool]: ...
+    def signature(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+
+class AltairContributionAndProofMinimal(_SSZObject):
+    def aggregator_index(self) -> int: ...
+    def contribution(self) -> AltairSyncCommitteeContributionMinimal: ...
+    def selection_proof(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def with_signature(
+        self, signature: bytes | str
+    ) -> AltairSignedContributionAndProofMinimal: ...
+
+class AltairSignedContributionAndProofMinimal(_SSZObject):
+    def message(self) -> AltairContributionAndProofMinimal: ...
+    def signature(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+
+class AltairSyncCommitteeContributionGnosis(_SSZObject):
+    def slot(self) -> int: ...
+    def beacon_block_root(self) -> str: ...
+    def subcommittee_index(self) -> int: ...
+    def aggregation_bits(self) -> SszList[bool]: ...
+    def signature(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+
+class AltairContributionAndProofGnosis(_SSZObject):
+    def aggregator_index(self) -> int: ...
+    def contribution(self) -> AltairSyncCommitteeContributionGnosis: ...
+    def selection_proof(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def with_signature(
+        self, signature: bytes | str
+    ) -> AltairSignedContributionAndProofGnosis: ...
+
+class AltairSignedContributionAndProofGnosis(_SSZObject):
+    def message(self) -> AltairContributionAndProofGnosis: ...
+    def signature(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+
+class _LightClientBootstrapObject(_SSZObject):
+    def slot(self) -> int: ...
+    def block_root(self) -> str: ...
+    def current_sync_committee_branch(self) -> list[str]: ...
+    def verify_current_sync_committee(self) -> bool: ...
+
+class _LightClientOptimisticUpdateObject(_SSZObject):
+    def attested_slot(self) -> int: ...
+    def signature_slot(self) -> int: ...
+
+class _LightClientFinalityUpdateObject(_LightClientOptimisticUpdateObject):
+    def finalized_slot(self) -> int: ...
+    def finality_branch(self) -> list[str]: ...
+    def verify_finality_branch(self) -> bool: ...
+
+class _LightClientUpdateObject(_LightClientFinalityUpdateObject):
+    def next_sync_committee_branch(self) -> list[str]: ...
+    def verify_next_sync_committee(self) -> bool: ...
+
+class AltairLightClientBootstrapMainnet(_LightClientBootstrapObject): ...
+class AltairLightClientUpdateMainnet(_LightClientUpdateObject): ...
+class AltairLightClientFinalityUpdateMainnet(_LightClientFinalityUpdateObject): ...
+class AltairLightClientOptimisticUpdateMainnet(_LightClientOptimisticUpdateObject): ...
+class AltairLightClientBootstrapMinimal(_LightClientBootstrapObject): ...
+class AltairLightClientUpdateMinimal(_LightClientUpdateObject): ...
+class AltairLightClientFinalityUpdateMinimal(_LightClientFinalityUpdateObject): ...
+class AltairLightClientOptimisticUpdateMinimal(_LightClientOptimisticUpdateObject): ...
+class AltairLightClientBootstrapGnosis(_LightClientBootstrapObject): ...
+class AltairLightClientUpdateGnosis(_LightClientUpdateObject): ...
+class AltairLightClientFinalityUpdateGnosis(_LightClientFinalityUpdateObject): ...
+class AltairLightClientOptimisticUpdateGnosis(_LightClientOptimisticUpdateObject): ...
+class CapellaLightClientBootstrapMainnet(_LightClientBootstrapObject): ...
+class CapellaLightClientUpdateMainnet(_LightClientUpdateObject): ...
+class CapellaLightClientFinalityUpdateMainnet(_LightClientFinalityUpdateObject): ...
+class CapellaLightClientOptimisticUpdateMainnet(_LightClientOptimisticUpdateObject): ...
+class CapellaLightClientBootstrapMinimal(_LightClientBootstrapObject): ...
+class CapellaLightClientUpdateMinimal(_LightClientUpdateObject): ...
+class CapellaLightClientFinalityUpdateMinimal(_LightClientFinalityUpdateObject): ...
+class CapellaLightClientOptimisticUpdateMinimal(_LightClientOptimisticUpdateObject): ...
+class CapellaLightClientBootstrapGnosis(_LightClientBootstrapObject): ...
+class CapellaLightClientUpdateGnosis(_LightClientUpdateObject): ...
+class CapellaLightClientFinalityUpdateGnosis(_LightClientFinalityUpdateObject): ...
+class CapellaLightClientOptimisticUpdateGnosis(_LightClientOptimisticUpdateObject): ...
+class DenebLightClientBootstrapMainnet(_LightClientBootstrapObject): ...
+class DenebLightClientUpdateMainnet(_LightClientUpdateObject): ...
+class DenebLightClientFinalityUpdateMainnet(_LightClientFinalityUpdateObject): ...
+class DenebLightClientOptimisticUpdateMainnet(_LightClientOptimisticUpdateObject): ...
+class DenebLightClientBootstrapMinimal(_LightClientBootstrapObject): ...
+class DenebLightClientUpdateMinimal(_LightClientUpdateObject): ...
+class DenebLightClientFinalityUpdateMinimal(_LightClientFinalityUpdateObject): ...
+class DenebLightClientOptimisticUpdateMinimal(_LightClientOptimisticUpdateObject): ...
+class DenebLightClientBootstrapGnosis(_LightClientBootstrapObject): ...
+class DenebLightClientUpdateGnosis(_LightClientUpdateObject): ...
+class DenebLightClientFinalityUpdateGnosis(_LightClientFinalityUpdateObject): ...
+class DenebLightClientOptimisticUpdateGnosis(_LightClientOptimisticUpdateObject): ...
+class ElectraLightClientBootstrapMainnet(_LightClientBootstrapObject): ...
+class ElectraLightClientUpdateMainnet(_LightClientUpdateObject): ...
+class ElectraLightClientFinalityUpdateMainnet(_LightClientFinalityUpdateObject): ...
+class ElectraLightClientOptimisticUpdateMainnet(_LightClientOptimisticUpdateObject): ...
+class ElectraLightClientBootstrapMinimal(_LightClientBootstrapObject): ...
+class ElectraLightClientUpdateMinimal(_LightClientUpdateObject): ...
+class ElectraLightClientFinalityUpdateMinimal(_LightClientFinalityUpdateObject): ...
+class ElectraLightClientOptimisticUpdateMinimal(_LightClientOptimisticUpdateObject): ...
+class ElectraLightClientBootstrapGnosis(_LightClientBootstrapObject): ...
+class ElectraLightClientUpdateGnosis(_LightClientUpdateObject): ...
+class ElectraLightClientFinalityUpdateGnosis(_LightClientFinalityUpdateObject): ...
+class ElectraLightClientOptimisticUpdateGnosis(_LightClientOptimisticUpdateObject): ...
+
+class ElectraBlobSidecarMainnet(_SSZObject):
+    def index(self) -> int: ...
+    def kzg_commitment(self) -> str: ...
+    def kzg_proof(self) -> str: ...
+    def signed_block_header(self) -> SignedBeaconBlockHeader: ...
+    def block_root(self) -> str: ...
+    def verify_kzg_proof(self, setup: TrustedSetup | None = None) -> bool: ...
+
+class FuluDataColumnSidecarMainnet(_SSZObject):
+    def index(self) -> int: ...
+    def kzg_commitments(self) -> list[str]: ...
+    def signed_block_header(self) -> SignedBeaconBlockHeader: ...
+    def block_root(self) -> str: ...
+    def verify_kzg_proofs(self, setup: TrustedSetup | None = None) -> bool: ...
+
+class ElectraBlobSidecarMinimal(_SSZObject):
+    def index(self) -> int: ...
+    def kzg_commitment(self) -> str: ...
+    def kzg_proof(self) -> str: ...
+    def signed_block_header(self) -> SignedBeaconBlockHeader: ...
+    def block_root(self) -> str: ...
+    def verify_kzg_proof(self, setup: TrustedSetup | None = None) -> bool: ...
+
+class FuluDataColumnSidecarMinimal(_SSZObject):
+    def index(self) -> int: ...
+    def kzg_commitments(self) -> list[str]: ...
+    def signed_block_header(self) -> SignedBeaconBlockHeader: ...
+    def block_root(self) -> str: ...
+    def verify_kzg_proofs(self, setup: TrustedSetup | None = None) -> bool: ...
+
+class ElectraBlobSidecarGnosis(_SSZObject):
+    def index(self) -> int: ...
+    def kzg_commitment(self) -> str: ...
+    def kzg_proof(self) -> str: ...
+    def signed_block_header(self) -> SignedBeaconBlockHeader: ...
+    def block_root(self) -> str: ...
+    def verify_kzg_proof(self, setup: TrustedSetup | None = None) -> bool: ...
+
+class FuluDataColumnSidecarGnosis(_SSZObject):
+    def index(self) -> int: ...
+    def kzg_commitments(self) -> list[str]: ...
+    def signed_block_header(self) -> SignedBeaconBlockHeader: ...
+    def block_root(self) -> str: ...
+    def verify_kzg_proofs(self, setup: TrustedSetup | None = None) -> bool: ...
+
+class SignedBeaconBlockHeader(_SSZObject):
+    def slot(self) -> int: ...
+    def proposer_index(self) -> int: ...
+    def block_root(self) -> str: ...
+    def signing_root(self, domain: bytes | str) -> str: ...
+    def verify(self, pubkey: bytes | str, domain: bytes | str) -> bool: ...
+
+class ProposerSlashing(_SSZObject):
+    def __init__(
+        self,
+        signed_header_1: SignedBeaconBlockHeader,
+        signed_header_2: SignedBeaconBlockHeader,
+    ) -> None: ...
+    def signed_header_1(self) -> SignedBeaconBlockHeader: ...
+    def signed_header_2(self) -> SignedBeaconBlockHeader: ...
+    def hash_tree_root(self) -> str: ...
+
+class DepositData(_SSZObject):
+    def amount(self) -> int: ...
+    def hash_tree_root(self) -> str: ...
+
+class Deposit(_SSZObject):
+    def data(self) -> DepositData: ...
+    def proof(self) -> list[str]: ...
+    def hash_tree_root(self) -> str: ...
+
+class VoluntaryExit(_SSZObject):
+    def epoch(self) -> int: ...
+    def validator_index(self) -> int: ...
+    def hash_tree_root(self) -> str: ...
+
+class SignedVoluntaryExit(_SSZObject):
+    def message(self) -> VoluntaryExit: ...
+    def signature(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+
+class BLSToExecutionChange(_SSZObject):
+    def validator_index(self) -> int: ...
+    def from_bls_pubkey(self) -> str: ...
+    def to_execution_address(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+
+class SignedBLSToExecutionChange(_SSZObject):
+    def message(self) -> BLSToExecutionChange: ...
+    def signature(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
 
-class ElectraSignedBlindedBeaconBlockGnosis(_SSZObject): ...
+class ValidatorRegistration(_SSZObject):
+    def fee_recipient(self) -> str: ...
+    def gas_limit(self) -> int: ...
+    def timestamp(self) -> int: ...
+    def pubkey(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+    def signing_root(self, genesis_fork_version: bytes | str) -> str: ...
+
+class SignedValidatorRegistration(_SSZObject):
+    def message(self) -> ValidatorRegistration: ...
+    def signature(self) -> str: ...
+    def hash_tree_root(self) -> str: ...
+    def verify(self, genesis_fork_version: bytes | str) -> bool: ...
+
+def parse_deposit_log(data: bytes) -> tuple[DepositData, int]:
+    """Decode the data of a deposit contract `DepositEvent` log."""
+    ...
+
+class DepositTreeSnapshot:
+    """EIP-4881 snapshot of the finalized deposits."""
+
+    @staticmethod
+    def from_beacon_api_json(json: bytes) -> DepositTreeSnapshot: ...
+    def to_json(self) -> bytes: ...
+    @property
+    def finalized(self) -> list[str]: ...
+    @property
+    def deposit_root(self) -> str: ...
+    @property
+    def deposit_count(self) -> int: ...
+    @property
+    def execution_block_hash(self) -> str: ...
+    @property
+    def execution_block_height(self) -> int: ...
+
+class DepositTree:
+    """The deposit contract's Merkle tree, pruned below its finalized deposits."""
+
+    def __init__(self) -> None: ...
+    @staticmethod
+    def from_snapshot(snapshot: DepositTreeSnapshot) -> DepositTree: ...
+    def push(self, deposit_data: DepositData, index: int | None = None) -> int: ...
+    def push_root(self, root: _Root) -> int: ...
+    def deposit_root(self) -> str: ...
+    def deposit_count(self) -> int: ...
+    def finalized_count(self) -> int: ...
+    def proof(self, index: int) -> list[str]: ...
+    def finalize(
+        self,
+        deposit_count: int,
+        execution_block_hash: _Root,
+        execution_block_height: int,
+    ) -> None: ...
+    def snapshot(self) -> DepositTreeSnapshot: ...
+
+type ElectraAttestationType = (
+    ElectraAttestationMainnet
+    | ElectraAttestationGnosis
+    | ElectraAttestationMinimal
+    | ElectraSingleAttestation
+    | Phase0AttestationMainnet
+    | Phase0AttestationGnosis
+    | Phase0AttestationMinimal
+)
+
+def group_by_data_root(
+    attestations: Iterable[ElectraAttestationType],
+) -> dict[str, list[ElectraAttestationType]]: ...
+
+class AttestationClassification(TypedDict):
+    source: bool
+    target: bool
+    head: bool
+    inclusion_delay: int | None
+    timely_source: bool | None
+    timely_target: bool | None
+    timely_head: bool | None
+
+type ChainProvider = dict[int, _Root | None] | Callable[[int], _Root | None]
+
+def classify_attestation(
+    attestation: ElectraAttestationType,
+    chain_provider: ChainProvider,
+    inclusion_slot: int | None = None,
+    config: Config | None = None,
+) -> AttestationClassification:
+    """Classify source/target/head correctness against canonical block roots by slot."""
+    ...
+
+def classify_attestations(
+    attestations: Iterable[ElectraAttestationType],
+    chain_provider: ChainProvider,
+    inclusion_slot: int | None = None,
+    config: Config | None = None,
+) -> list[AttestationClassification]: ...
+
+type BeaconStateType = (
+    ElectraBeaconStateMainnet
+    | ElectraBeaconStateGnosis
+    | ElectraBeaconStateMinimal
+    | FuluBeaconStateMainnet
+    | FuluBeaconStateGnosis
+    | FuluBeaconStateMinimal
+)
+
+class PubkeyIndexMap:
+    """Maps validator pubkeys to validator indices."""
+
+    def __init__(self) -> None: ...
+    @staticmethod
+    def from_state(state: BeaconStateType) -> PubkeyIndexMap: ...
+    def update(self, state: BeaconStateType) -> int: ...
+    def add(self, pubkey: bytes | str) -> int: ...
+    def index_of(self, pubkey: bytes | str) -> int | None: ...
+    def pubkey_at(self, index: int) -> str: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> PubkeyIndexMap: ...
+    def save(self, path: str | os.PathLike[str]) -> None: ...
+    @staticmethod
+    def load(path: str | os.PathLike[str]) -> PubkeyIndexMap: ...
+    def __len__(self) -> int: ...
+    def __contains__(self, pubkey: bytes | str) -> bool: ...
+
+class ChainIndex:
+    """Indexes block roots by slot and parent, with the head's canonical chain."""
+
+    def __init__(self) -> None: ...
+    def add(self, header: SignedBeaconBlockHeader) -> bool: ...
+    def extend(self, headers: list[SignedBeaconBlockHeader]) -> int: ...
+    def insert_block(self, root: _Root, slot: int, parent_root: _Root) -> bool: ...
+    @property
+    def head(self) -> str | None: ...
+    def set_head(self, root: _Root) -> None: ...
+    def slot_of(self, root: _Root) -> int | None: ...
+    def parent_of(self, root: _Root) -> str | None: ...
+    def root_at_slot(self, slot: int) -> str | None: ...
+    def ancestor_at_slot(self, root: _Root, slot: int) -> str | None: ...
+    def is_canonical(self, root: _Root) -> bool: ...
+    def path_between(self, a: _Root, b: _Root) -> list[str] | None: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: Buffer) -> ChainIndex: ...
+    def save(self, path: str | os.PathLike[str]) -> None: ...
+    @staticmethod
+    def load(path: str | os.PathLike[str]) -> ChainIndex: ...
+    def __len__(self) -> int: ...
+    def __contains__(self, root: _Root) -> bool: ...
+
+class FinalityEvent(TypedDict):
+    kind: str
+    epoch: int
+    root: str
+    previous_epoch: int | None
+    previous_root: str | None
+    slot: int | None
+
+type SignedBeaconBlockType = (
+    ElectraSignedBeaconBlockMainnet
+    | ElectraSignedBeaconBlockGnosis
+    | ElectraSignedBeaconBlockMinimal
+    | FuluSignedBeaconBlockMainnet
+    | FuluSignedBeaconBlockGnosis
+    | FuluSignedBeaconBlockMinimal
+)
+
+class FinalityTracker:
+    """Tracks justified and finalized checkpoints from raw chain data."""
+
+    def __init__(self, preset: str = "mainnet") -> None: ...
+    def update_from_state(self, state: BeaconStateType) -> list[FinalityEvent]: ...
+    def update_from_block(
+        self, block: SignedBeaconBlockType
+    ) -> list[FinalityEvent]: ...
+    def update_from_attestation(
+        self, attestation: ElectraAttestationType
+    ) -> list[FinalityEvent]: ...
+    def update_from_finality_checkpoints(
+        self, response: dict[str, Any]
+    ) -> list[FinalityEvent]: ...
+    @property
+    def justified(self) -> tuple[int, str] | None: ...
+    @property
+    def finalized(self) -> tuple[int, str] | None: ...
+    @property
+    def head_slot(self) -> int | None: ...
+    def finality_lag(self, current_epoch: int | None = None) -> int | None: ...
+
+def next_epoch_proposers(
+    state: BeaconStateType, config: Config | None = None
+) -> list[int]:
+    """Proposer index of every slot in the epoch after the state's current epoch."""
+    ...
+
+class ProposerDiscrepancy(TypedDict):
+    slot: int
+    kind: str
+    expected_validator_index: int | None
+    claimed_validator_index: int | None
+
+def verify_proposer_schedule(
+    state: BeaconStateType,
+    epoch: int,
+    claimed_duties_json: bytes,
+    config: Config | None = None,
+) -> list[ProposerDiscrepancy]:
+    """Diff a proposer duties response for the current or next epoch against the state."""
+    ...
+
+class CommitteeSlotStats(TypedDict):
+    slot: int
+    committee_count: int
+    committee_sizes: list[int]
+
+class CommitteeStats(TypedDict):
+    epoch: int
+    active_validators: int
+    committees_per_slot: int
+    slots: list[CommitteeSlotStats]
+    subnet_attestations: list[int]
+
+class BeaconBlocksByRangeRequest:
+    def __init__(self, start_slot: int, count: int, step: int = 1) -> None: ...
+    @property
+    def start_slot(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def step(self) -> int: ...
+    @property
+    def end_slot(self) -> int: ...
+    @staticmethod
+    def from_ssz(data: bytes) -> BeaconBlocksByRangeRequest: ...
+    def to_ssz(self) -> bytes: ...
+
+def plan_block_requests(
+    start_slot: int,
+    end_slot: int,
+    max_per_request: int | None = None,
+    config: Config | None = None,
+) -> list[BeaconBlocksByRangeRequest]:
+    """Split `start_slot..end_slot` into epoch-aligned `BeaconBlocksByRange` requests."""
+    ...
+
+def committee_size_stats(
+    state: BeaconStateType, epoch: int, config: Config | None = None
+) -> CommitteeStats:
+    """Committee sizes per slot and expected attestations per subnet for `epoch`."""
+    ...
+
+def withdrawal_addresses(state: BeaconStateType) -> dict[int, str]:
+    """Execution withdrawal address of every validator with 0x01 or 0x02 credentials."""
+    ...
+
+type ElectraPublishableBlockType = (
+    ElectraSignedBeaconBlockMainnet
+    | ElectraSignedBeaconBlockGnosis
+    | ElectraSignedBeaconBlockMinimal
+    | ElectraSignedBeaconBlockContentsMainnet
+    | ElectraSignedBeaconBlockContentsGnosis
+    | ElectraSignedBeaconBlockContentsMinimal
+)
+
+type FuluPublishableBlockType = (
+    FuluSignedBeaconBlockMainnet
+    | FuluSignedBeaconBlockGnosis
+    | FuluSignedBeaconBlockMinimal
+    | FuluSignedBeaconBlockContentsMainnet
+    | FuluSignedBeaconBlockContentsGnosis
+    | FuluSignedBeaconBlockContentsMinimal
+)
+
+def validate_for_publish(
+    contents: ElectraPublishableBlockType,
+    state_provider: BeaconStateType | Callable[[str], BeaconStateType],
+    level: str = "gossip",
+    seen_headers: list[SignedBeaconBlockHeader] | None = None,
+    config: Config | None = None,
+) -> list[str]:
+    """Run the local checks of a `broadcast_validation` level; returns the failures."""
+    ...
+
+type SidecarType = (
+    ElectraBlobSidecarMainnet
+    | ElectraBlobSidecarMinimal
+    | ElectraBlobSidecarGnosis
+    | FuluDataColumnSidecarMainnet
+    | FuluDataColumnSidecarMinimal
+    | FuluDataColumnSidecarGnosis
+)
+
+class InvalidSidecar(TypedDict):
+    kind: str
+    index: int
+    reason: str
+
+class DataAvailability(TypedDict):
+    fork: str
+    slot: int
+    block_root: str
+    commitments: int
+    available: bool
+    missing_blobs: list[int]
+    missing_columns: list[int]
+    invalid: list[InvalidSidecar]
+
+def check_data_availability(
+    signed_block: ElectraPublishableBlockType | FuluPublishableBlockType,
+    sidecars_or_columns: list[SidecarType],
+    config: Config | None = None,
+    custody_columns: list[int] | None = None,
+    setup: TrustedSetup | None = None,
+) -> DataAvailability:
+    """Check that the block's blobs are covered by valid sidecars of its fork."""
+    ...
+
+def compute_selection_proof_signing_root(slot: int, domain: bytes | str) -> str:
+    """Signing root of the attestation aggregation selection proof for `slot`."""
+    ...
+
+def compute_sync_selection_proof_signing_root(
+    slot: int, subcommittee_index: int, domain: bytes | str
+) -> str:
+    """Signing root of the sync committee aggregation selection proof."""
+    ...
+
+def render_graffiti(
+    template: str, slot: int, validator_index: int, client_info: str | None = None
+) -> bytes:
+    """Render `{slot}`, `{validator_index}` and `{client}` into a 32-byte graffiti."""
+    ...
+
+def compute_voluntary_exit_signing_root(
+    epoch: int, validator_index: int, domain: bytes | str
+) -> str:
+    """Signing root of a voluntary exit, for the Capella `DOMAIN_VOLUNTARY_EXIT`."""
+    ...
+
+def generate_presigned_exits(
+    validators: Sequence[tuple[int, bytes | str]],
+    epochs: Sequence[int],
+    domain: bytes | str,
+    output_dir: str | os.PathLike[str] | None = None,
+) -> list[SignedVoluntaryExit]:
+    """Signed exits of `(validator_index, secret_key)` pairs at each epoch.
+
+    Keys are decrypted by the caller, and `domain` is the Capella
+    `DOMAIN_VOLUNTARY_EXIT`. Exits are also written to `output_dir`, if given,
+    as `exit_<validator_index>_<epoch>.json` and `.ssz`.
+    """
+    ...
+
+def compute_domain(
+    domain_type: bytes | str,
+    fork_version: bytes | str,
+    genesis_validators_root: bytes | str | None = None,
+) -> str:
+    """Domain of a domain type (4 bytes or a name like `DOMAIN_RANDAO`)."""
+    ...
+
+def compute_signing_root(obj: _SSZObject, domain: bytes | str) -> str:
+    """Signing root of an object of any generated class."""
+    ...
+
+def compute_message_id(topic: str, compressed_data: bytes) -> str:
+    """Gossipsub message ID of a message, as hex in the `set_hex_format` format."""
+    ...
+
+def fork_digest(
+    genesis_validators_root: bytes | str, fork_version: bytes | str
+) -> str:
+    """Fork digest of a fork version (up to Electra)."""
+    ...
+
+def topic_for(
+    fork_digest: bytes | str, name: str, subnet_id: int | None = None
+) -> str:
+    """Gossip topic, such as `/eth2/<fork_digest>/beacon_block/ssz_snappy`."""
+    ...
+
+class SignedAttestationRecord(TypedDict):
+    pubkey: bytes | str
+    source_epoch: int | str
+    target_epoch: int | str
+    signing_root: NotRequired[bytes | str | None]
+
+class SignedAttestationSummary(TypedDict):
+    source_epoch: int
+    target_epoch: int
+    signing_root: str | None
+
+class KeySlashingHistory(TypedDict):
+    attestations: int
+    max_source_epoch: int | None
+    max_target_epoch: int | None
+
+class DoubleVote(TypedDict):
+    pubkey: str
+    target_epoch: int
+    first: SignedAttestationSummary
+    second: SignedAttestationSummary
+
+class SurroundVote(TypedDict):
+    pubkey: str
+    surrounding: SignedAttestationSummary
+    surrounded: SignedAttestationSummary
+
+class SlashingExposure(TypedDict):
+    attestations: int
+    skipped: int
+    keys: dict[str, KeySlashingHistory]
+    safe: bool
+    double_votes: list[DoubleVote]
+    surround_votes: list[SurroundVote]
+
+def slashing_exposure(
+    signed_attestations_iter: Iterable[
+        SignedAttestationRecord
+        | tuple[bytes | str, int | str, int | str]
+        | tuple[bytes | str, int | str, int | str, bytes | str | None]
+    ],
+    pubkeys: list[bytes | str],
+) -> SlashingExposure:
+    """Scan signed attestations for double and surround votes of `pubkeys`."""
+    ...
+
+def extract(objects: Iterable[_SSZObject], expression: str) -> list[Any]:
+    """Evaluate a path/filter expression such as `message.body.attestations[*].data.slot`."""
+    ...
+
+def extract_columns(
+    blocks: Iterable[_BlockObject], columns: list[str]
+) -> dict[str, array.array[int] | list[Any]]:
+    """Extract columns in one pass; integer columns are `array.array("Q")` buffers."""
+    ...
+
+def wrap(object: _SSZObject) -> bytes:
+    """Encode an object as SSZ prefixed with its fork, preset and type."""
+    ...
+
+def unwrap(data: bytes) -> Any:
+    """Decode an envelope produced by `wrap` into an object of its class."""
+    ...
+
+def content_key(object: _SSZObject) -> bytes:
+    """Return the fork and preset bytes followed by the object's hash tree root."""
+    ...
+
+def parse_content_key(key: bytes) -> tuple[str, str | None, str]:
+    """Split a content key into its fork name, preset name and hash tree root."""
+    ...
+
+def verify_merkle_proof(
+    leaf: bytes | str,
+    branch: Sequence[bytes | str],
+    generalized_index: int,
+    root: bytes | str,
+) -> bool:
+    """Check a Merkle branch from `prove()` of `leaf` at `generalized_index`."""
+    ...
+
+def decode_signed_beacon_block(
+    data: bytes,
+    preset: str = "mainnet",
+    fork: str | None = None,
+    fork_schedule: Mapping[str, int] | None = None,
+) -> tuple[Any, str]:
+    """Decode a signed block of unknown fork; returns the block and its fork."""
+    ...
+
+def read_grandine_block_file(
+    path: str | os.PathLike[str],
+    preset: str = "mainnet",
+    fork: str | None = None,
+    fork_schedule: Mapping[str, int] | None = None,
+) -> tuple[Any, str]:
+    """Read a snappy-compressed signed block file; returns the block and its fork.
+
+    The file holds one block exported from a node. Grandine's data directory
+    (a libmdbx database) cannot be read directly.
+    """
+    ...
+
+def read_grandine_state_file(
+    path: str | os.PathLike[str],
+    preset: str = "mainnet",
+    fork: str | None = None,
+    fork_schedule: Mapping[str, int] | None = None,
+) -> tuple[Any, str]:
+    """Read a snappy-compressed state file; returns the state and its fork.
+
+    The file holds one state exported from a node. Grandine's data directory
+    (a libmdbx database) cannot be read directly.
+    """
+    ...
+
+def export_test_vectors(
+    dir_path: str | os.PathLike[str],
+    *,
+    cases: int = 1,
+    seed: int = 0,
+    types: Iterable[str] | None = None,
+) -> int:
+    """Write random `ssz_static` test vectors per type; returns the number of cases."""
+    ...
+
+class SszStaticResult(TypedDict):
+    preset: str
+    fork: str
+    type: str
+    passed: bool
+    failures: dict[Literal["serialized", "root", "value"], str]
+
+def run_ssz_static_test(
+    case_dir: str | os.PathLike[str],
+    *,
+    preset: str | None = None,
+    fork: str | None = None,
+    type_name: str | None = None,
+) -> SszStaticResult:
+    """Run a consensus-spec `ssz_static` case; the type is inferred from the path."""
+    ...
 
 type ElectraBeaconBlockContentsType = (
     ElectraBeaconBlockContentsMainnet
@@ -99,3 +2164,30 @@ type ElectraBlindedBeaconBlockType = (
     | ElectraBlindedBeaconBlockGnosis
     | ElectraBlindedBeaconBlockMinimal
 )
+type ElectraSignedBuilderBidType = (
+    ElectraSignedBuilderBidMainnet
+    | ElectraSignedBuilderBidGnosis
+    | ElectraSignedBuilderBidMinimal
+)
+
+class BlobCommitmentDifferences(TypedDict):
+    local: int
+    bid: int
+    only_local: list[str]
+    only_bid: list[str]
+
+class CandidateComparison(TypedDict):
+    local_value: int | None
+    bid_value: int
+    value_delta: int | None
+    header_differences: dict[str, tuple[str, str]]
+    blob_commitments: BlobCommitmentDifferences
+
+def compare_candidates(
+    local_contents: ElectraBeaconBlockContentsType | ProducedBlock,
+    relay_bid: ElectraSignedBuilderBidType,
+    config: Config | None = None,
+    local_value: int | None = None,
+) -> CandidateComparison:
+    """Compare local block contents with a builder bid: value, header and blobs."""
+    ...
@@ -0,0 +1,13 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from . import mainnet as mainnet, minimal as minimal, gnosis as gnosis
+from grandine_py import (
+    AltairSyncCommitteeMessage as SyncCommitteeMessage,
+)
+
+__all__ = [
+    "mainnet",
+    "minimal",
+    "gnosis",
+    "SyncCommitteeMessage",
+]
@@ -0,0 +1,23 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    AltairSignedBeaconBlockGnosis as SignedBeaconBlock,
+    AltairSyncCommitteeContributionGnosis as SyncCommitteeContribution,
+    AltairContributionAndProofGnosis as ContributionAndProof,
+    AltairSignedContributionAndProofGnosis as SignedContributionAndProof,
+    AltairLightClientBootstrapGnosis as LightClientBootstrap,
+    AltairLightClientUpdateGnosis as LightClientUpdate,
+    AltairLightClientFinalityUpdateGnosis as LightClientFinalityUpdate,
+    AltairLightClientOptimisticUpdateGnosis as LightClientOptimisticUpdate,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SyncCommitteeContribution",
+    "ContributionAndProof",
+    "SignedContributionAndProof",
+    "LightClientBootstrap",
+    "LightClientUpdate",
+    "LightClientFinalityUpdate",
+    "LightClientOptimisticUpdate",
+]
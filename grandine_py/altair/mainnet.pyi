@@ -0,0 +1,23 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    AltairSignedBeaconBlockMainnet as SignedBeaconBlock,
+    AltairSyncCommitteeContributionMainnet as SyncCommitteeContribution,
+    AltairContributionAndProofMainnet as ContributionAndProof,
+    AltairSignedContributionAndProofMainnet as SignedContributionAndProof,
+    AltairLightClientBootstrapMainnet as LightClientBootstrap,
+    AltairLightClientUpdateMainnet as LightClientUpdate,
+    AltairLightClientFinalityUpdateMainnet as LightClientFinalityUpdate,
+    AltairLightClientOptimisticUpdateMainnet as LightClientOptimisticUpdate,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SyncCommitteeContribution",
+    "ContributionAndProof",
+    "SignedContributionAndProof",
+    "LightClientBootstrap",
+    "LightClientUpdate",
+    "LightClientFinalityUpdate",
+    "LightClientOptimisticUpdate",
+]
@@ -0,0 +1,23 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    AltairSignedBeaconBlockMinimal as SignedBeaconBlock,
+    AltairSyncCommitteeContributionMinimal as SyncCommitteeContribution,
+    AltairContributionAndProofMinimal as ContributionAndProof,
+    AltairSignedContributionAndProofMinimal as SignedContributionAndProof,
+    AltairLightClientBootstrapMinimal as LightClientBootstrap,
+    AltairLightClientUpdateMinimal as LightClientUpdate,
+    AltairLightClientFinalityUpdateMinimal as LightClientFinalityUpdate,
+    AltairLightClientOptimisticUpdateMinimal as LightClientOptimisticUpdate,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SyncCommitteeContribution",
+    "ContributionAndProof",
+    "SignedContributionAndProof",
+    "LightClientBootstrap",
+    "LightClientUpdate",
+    "LightClientFinalityUpdate",
+    "LightClientOptimisticUpdate",
+]
@@ -0,0 +1,9 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from . import mainnet as mainnet, minimal as minimal, gnosis as gnosis
+
+__all__ = [
+    "mainnet",
+    "minimal",
+    "gnosis",
+]
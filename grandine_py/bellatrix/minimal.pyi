@@ -0,0 +1,11 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    BellatrixSignedBeaconBlockMinimal as SignedBeaconBlock,
+    BellatrixSignedBlindedBeaconBlockMinimal as SignedBlindedBeaconBlock,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+]
@@ -0,0 +1,19 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    CapellaSignedBeaconBlockGnosis as SignedBeaconBlock,
+    CapellaSignedBlindedBeaconBlockGnosis as SignedBlindedBeaconBlock,
+    CapellaLightClientBootstrapGnosis as LightClientBootstrap,
+    CapellaLightClientUpdateGnosis as LightClientUpdate,
+    CapellaLightClientFinalityUpdateGnosis as LightClientFinalityUpdate,
+    CapellaLightClientOptimisticUpdateGnosis as LightClientOptimisticUpdate,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+    "LightClientBootstrap",
+    "LightClientUpdate",
+    "LightClientFinalityUpdate",
+    "LightClientOptimisticUpdate",
+]
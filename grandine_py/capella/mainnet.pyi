@@ -0,0 +1,19 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    CapellaSignedBeaconBlockMainnet as SignedBeaconBlock,
+    CapellaSignedBlindedBeaconBlockMainnet as SignedBlindedBeaconBlock,
+    CapellaLightClientBootstrapMainnet as LightClientBootstrap,
+    CapellaLightClientUpdateMainnet as LightClientUpdate,
+    CapellaLightClientFinalityUpdateMainnet as LightClientFinalityUpdate,
+    CapellaLightClientOptimisticUpdateMainnet as LightClientOptimisticUpdate,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+    "LightClientBootstrap",
+    "LightClientUpdate",
+    "LightClientFinalityUpdate",
+    "LightClientOptimisticUpdate",
+]
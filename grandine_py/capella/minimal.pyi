@@ -0,0 +1,19 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    CapellaSignedBeaconBlockMinimal as SignedBeaconBlock,
+    CapellaSignedBlindedBeaconBlockMinimal as SignedBlindedBeaconBlock,
+    CapellaLightClientBootstrapMinimal as LightClientBootstrap,
+    CapellaLightClientUpdateMinimal as LightClientUpdate,
+    CapellaLightClientFinalityUpdateMinimal as LightClientFinalityUpdate,
+    CapellaLightClientOptimisticUpdateMinimal as LightClientOptimisticUpdate,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+    "LightClientBootstrap",
+    "LightClientUpdate",
+    "LightClientFinalityUpdate",
+    "LightClientOptimisticUpdate",
+]
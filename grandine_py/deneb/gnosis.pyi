@@ -0,0 +1,19 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    DenebSignedBeaconBlockGnosis as SignedBeaconBlock,
+    DenebSignedBlindedBeaconBlockGnosis as SignedBlindedBeaconBlock,
+    DenebLightClientBootstrapGnosis as LightClientBootstrap,
+    DenebLightClientUpdateGnosis as LightClientUpdate,
+    DenebLightClientFinalityUpdateGnosis as LightClientFinalityUpdate,
+    DenebLightClientOptimisticUpdateGnosis as LightClientOptimisticUpdate,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+    "LightClientBootstrap",
+    "LightClientUpdate",
+    "LightClientFinalityUpdate",
+    "LightClientOptimisticUpdate",
+]
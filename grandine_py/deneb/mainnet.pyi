@@ -0,0 +1,19 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    DenebSignedBeaconBlockMainnet as SignedBeaconBlock,
+    DenebSignedBlindedBeaconBlockMainnet as SignedBlindedBeaconBlock,
+    DenebLightClientBootstrapMainnet as LightClientBootstrap,
+    DenebLightClientUpdateMainnet as LightClientUpdate,
+    DenebLightClientFinalityUpdateMainnet as LightClientFinalityUpdate,
+    DenebLightClientOptimisticUpdateMainnet as LightClientOptimisticUpdate,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+    "LightClientBootstrap",
+    "LightClientUpdate",
+    "LightClientFinalityUpdate",
+    "LightClientOptimisticUpdate",
+]
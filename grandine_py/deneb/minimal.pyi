@@ -0,0 +1,19 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    DenebSignedBeaconBlockMinimal as SignedBeaconBlock,
+    DenebSignedBlindedBeaconBlockMinimal as SignedBlindedBeaconBlock,
+    DenebLightClientBootstrapMinimal as LightClientBootstrap,
+    DenebLightClientUpdateMinimal as LightClientUpdate,
+    DenebLightClientFinalityUpdateMinimal as LightClientFinalityUpdate,
+    DenebLightClientOptimisticUpdateMinimal as LightClientOptimisticUpdate,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+    "LightClientBootstrap",
+    "LightClientUpdate",
+    "LightClientFinalityUpdate",
+    "LightClientOptimisticUpdate",
+]
@@ -0,0 +1,51 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    ElectraSignedBeaconBlockGnosis as SignedBeaconBlock,
+    ElectraSignedBuilderBidGnosis as SignedBuilderBid,
+    ElectraBeaconBlockContentsGnosis as BeaconBlockContents,
+    ElectraMutableBeaconBlockContentsGnosis as MutableBeaconBlockContents,
+    ElectraSignedBeaconBlockContentsGnosis as SignedBeaconBlockContents,
+    ElectraBlindedBeaconBlockGnosis as BlindedBeaconBlock,
+    ElectraMutableBlindedBeaconBlockGnosis as MutableBlindedBeaconBlock,
+    ElectraSignedBlindedBeaconBlockGnosis as SignedBlindedBeaconBlock,
+    ElectraExecutionPayloadGnosis as ExecutionPayload,
+    ElectraExecutionPayloadHeaderGnosis as ExecutionPayloadHeader,
+    ElectraExecutionPayloadAndBlobsBundleGnosis as ExecutionPayloadAndBlobsBundle,
+    ElectraAttestationGnosis as Attestation,
+    ElectraIndexedAttestationGnosis as IndexedAttestation,
+    ElectraAttesterSlashingGnosis as AttesterSlashing,
+    ElectraBeaconStateGnosis as BeaconState,
+    ElectraAggregateAndProofGnosis as AggregateAndProof,
+    ElectraSignedAggregateAndProofGnosis as SignedAggregateAndProof,
+    ElectraLightClientBootstrapGnosis as LightClientBootstrap,
+    ElectraLightClientUpdateGnosis as LightClientUpdate,
+    ElectraLightClientFinalityUpdateGnosis as LightClientFinalityUpdate,
+    ElectraLightClientOptimisticUpdateGnosis as LightClientOptimisticUpdate,
+    ElectraBlobSidecarGnosis as BlobSidecar,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SignedBuilderBid",
+    "BeaconBlockContents",
+    "MutableBeaconBlockContents",
+    "SignedBeaconBlockContents",
+    "BlindedBeaconBlock",
+    "MutableBlindedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+    "ExecutionPayload",
+    "ExecutionPayloadHeader",
+    "ExecutionPayloadAndBlobsBundle",
+    "Attestation",
+    "IndexedAttestation",
+    "AttesterSlashing",
+    "BeaconState",
+    "AggregateAndProof",
+    "SignedAggregateAndProof",
+    "LightClientBootstrap",
+    "LightClientUpdate",
+    "LightClientFinalityUpdate",
+    "LightClientOptimisticUpdate",
+    "BlobSidecar",
+]
@@ -0,0 +1,51 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    ElectraSignedBeaconBlockMainnet as SignedBeaconBlock,
+    ElectraSignedBuilderBidMainnet as SignedBuilderBid,
+    ElectraBeaconBlockContentsMainnet as BeaconBlockContents,
+    ElectraMutableBeaconBlockContentsMainnet as MutableBeaconBlockContents,
+    ElectraSignedBeaconBlockContentsMainnet as SignedBeaconBlockContents,
+    ElectraBlindedBeaconBlockMainnet as BlindedBeaconBlock,
+    ElectraMutableBlindedBeaconBlockMainnet as MutableBlindedBeaconBlock,
+    ElectraSignedBlindedBeaconBlockMainnet as SignedBlindedBeaconBlock,
+    ElectraExecutionPayloadMainnet as ExecutionPayload,
+    ElectraExecutionPayloadHeaderMainnet as ExecutionPayloadHeader,
+    ElectraExecutionPayloadAndBlobsBundleMainnet as ExecutionPayloadAndBlobsBundle,
+    ElectraAttestationMainnet as Attestation,
+    ElectraIndexedAttestationMainnet as IndexedAttestation,
+    ElectraAttesterSlashingMainnet as AttesterSlashing,
+    ElectraBeaconStateMainnet as BeaconState,
+    ElectraAggregateAndProofMainnet as AggregateAndProof,
+    ElectraSignedAggregateAndProofMainnet as SignedAggregateAndProof,
+    ElectraLightClientBootstrapMainnet as LightClientBootstrap,
+    ElectraLightClientUpdateMainnet as LightClientUpdate,
+    ElectraLightClientFinalityUpdateMainnet as LightClientFinalityUpdate,
+    ElectraLightClientOptimisticUpdateMainnet as LightClientOptimisticUpdate,
+    ElectraBlobSidecarMainnet as BlobSidecar,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SignedBuilderBid",
+    "BeaconBlockContents",
+    "MutableBeaconBlockContents",
+    "SignedBeaconBlockContents",
+    "BlindedBeaconBlock",
+    "MutableBlindedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+    "ExecutionPayload",
+    "ExecutionPayloadHeader",
+    "ExecutionPayloadAndBlobsBundle",
+    "Attestation",
+    "IndexedAttestation",
+    "AttesterSlashing",
+    "BeaconState",
+    "AggregateAndProof",
+    "SignedAggregateAndProof",
+    "LightClientBootstrap",
+    "LightClientUpdate",
+    "LightClientFinalityUpdate",
+    "LightClientOptimisticUpdate",
+    "BlobSidecar",
+]
@@ -0,0 +1,51 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    ElectraSignedBeaconBlockMinimal as SignedBeaconBlock,
+    ElectraSignedBuilderBidMinimal as SignedBuilderBid,
+    ElectraBeaconBlockContentsMinimal as BeaconBlockContents,
+    ElectraMutableBeaconBlockContentsMinimal as MutableBeaconBlockContents,
+    ElectraSignedBeaconBlockContentsMinimal as SignedBeaconBlockContents,
+    ElectraBlindedBeaconBlockMinimal as BlindedBeaconBlock,
+    ElectraMutableBlindedBeaconBlockMinimal as MutableBlindedBeaconBlock,
+    ElectraSignedBlindedBeaconBlockMinimal as SignedBlindedBeaconBlock,
+    ElectraExecutionPayloadMinimal as ExecutionPayload,
+    ElectraExecutionPayloadHeaderMinimal as ExecutionPayloadHeader,
+    ElectraExecutionPayloadAndBlobsBundleMinimal as ExecutionPayloadAndBlobsBundle,
+    ElectraAttestationMinimal as Attestation,
+    ElectraIndexedAttestationMinimal as IndexedAttestation,
+    ElectraAttesterSlashingMinimal as AttesterSlashing,
+    ElectraBeaconStateMinimal as BeaconState,
+    ElectraAggregateAndProofMinimal as AggregateAndProof,
+    ElectraSignedAggregateAndProofMinimal as SignedAggregateAndProof,
+    ElectraLightClientBootstrapMinimal as LightClientBootstrap,
+    ElectraLightClientUpdateMinimal as LightClientUpdate,
+    ElectraLightClientFinalityUpdateMinimal as LightClientFinalityUpdate,
+    ElectraLightClientOptimisticUpdateMinimal as LightClientOptimisticUpdate,
+    ElectraBlobSidecarMinimal as BlobSidecar,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "SignedBuilderBid",
+    "BeaconBlockContents",
+    "MutableBeaconBlockContents",
+    "SignedBeaconBlockContents",
+    "BlindedBeaconBlock",
+    "MutableBlindedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+    "ExecutionPayload",
+    "ExecutionPayloadHeader",
+    "ExecutionPayloadAndBlobsBundle",
+    "Attestation",
+    "IndexedAttestation",
+    "AttesterSlashing",
+    "BeaconState",
+    "AggregateAndProof",
+    "SignedAggregateAndProof",
+    "LightClientBootstrap",
+    "LightClientUpdate",
+    "LightClientFinalityUpdate",
+    "LightClientOptimisticUpdate",
+    "BlobSidecar",
+]
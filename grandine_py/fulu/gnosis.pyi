@@ -0,0 +1,25 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    FuluSignedBeaconBlockGnosis as SignedBeaconBlock,
+    FuluBeaconBlockContentsGnosis as BeaconBlockContents,
+    FuluMutableBeaconBlockContentsGnosis as MutableBeaconBlockContents,
+    FuluSignedBeaconBlockContentsGnosis as SignedBeaconBlockContents,
+    FuluBlindedBeaconBlockGnosis as BlindedBeaconBlock,
+    FuluMutableBlindedBeaconBlockGnosis as MutableBlindedBeaconBlock,
+    FuluSignedBlindedBeaconBlockGnosis as SignedBlindedBeaconBlock,
+    FuluBeaconStateGnosis as BeaconState,
+    FuluDataColumnSidecarGnosis as DataColumnSidecar,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "BeaconBlockContents",
+    "MutableBeaconBlockContents",
+    "SignedBeaconBlockContents",
+    "BlindedBeaconBlock",
+    "MutableBlindedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+    "BeaconState",
+    "DataColumnSidecar",
+]
@@ -0,0 +1,25 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    FuluSignedBeaconBlockMainnet as SignedBeaconBlock,
+    FuluBeaconBlockContentsMainnet as BeaconBlockContents,
+    FuluMutableBeaconBlockContentsMainnet as MutableBeaconBlockContents,
+    FuluSignedBeaconBlockContentsMainnet as SignedBeaconBlockContents,
+    FuluBlindedBeaconBlockMainnet as BlindedBeaconBlock,
+    FuluMutableBlindedBeaconBlockMainnet as MutableBlindedBeaconBlock,
+    FuluSignedBlindedBeaconBlockMainnet as SignedBlindedBeaconBlock,
+    FuluBeaconStateMainnet as BeaconState,
+    FuluDataColumnSidecarMainnet as DataColumnSidecar,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "BeaconBlockContents",
+    "MutableBeaconBlockContents",
+    "SignedBeaconBlockContents",
+    "BlindedBeaconBlock",
+    "MutableBlindedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+    "BeaconState",
+    "DataColumnSidecar",
+]
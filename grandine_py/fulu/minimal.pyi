@@ -0,0 +1,25 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    FuluSignedBeaconBlockMinimal as SignedBeaconBlock,
+    FuluBeaconBlockContentsMinimal as BeaconBlockContents,
+    FuluMutableBeaconBlockContentsMinimal as MutableBeaconBlockContents,
+    FuluSignedBeaconBlockContentsMinimal as SignedBeaconBlockContents,
+    FuluBlindedBeaconBlockMinimal as BlindedBeaconBlock,
+    FuluMutableBlindedBeaconBlockMinimal as MutableBlindedBeaconBlock,
+    FuluSignedBlindedBeaconBlockMinimal as SignedBlindedBeaconBlock,
+    FuluBeaconStateMinimal as BeaconState,
+    FuluDataColumnSidecarMinimal as DataColumnSidecar,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "BeaconBlockContents",
+    "MutableBeaconBlockContents",
+    "SignedBeaconBlockContents",
+    "BlindedBeaconBlock",
+    "MutableBlindedBeaconBlock",
+    "SignedBlindedBeaconBlock",
+    "BeaconState",
+    "DataColumnSidecar",
+]
@@ -0,0 +1,11 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    Phase0AttestationMainnet as Attestation,
+    Phase0SignedBeaconBlockMainnet as SignedBeaconBlock,
+)
+
+__all__ = [
+    "Attestation",
+    "SignedBeaconBlock",
+]
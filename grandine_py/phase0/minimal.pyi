@@ -0,0 +1,11 @@
+# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.
+
+from grandine_py import (
+    Phase0SignedBeaconBlockMinimal as SignedBeaconBlock,
+    Phase0AttestationMinimal as Attestation,
+)
+
+__all__ = [
+    "SignedBeaconBlock",
+    "Attestation",
+]
@@ -0,0 +1,146 @@
+"""
+Generate the stubs of the fork and preset submodules from grandine_py.pyi.
+
+The extension files each class under its fork and preset with both left out of
+the name, e.g. `ElectraSignedBeaconBlockMainnet` as
+`grandine_py.electra.mainnet.SignedBeaconBlock` (see src/namespaces.rs). This
+script applies the same rule to the classes in grandine_py.pyi and writes one
+stub per submodule under grandine_py/, which re-exports the top-level classes
+under their short names.
+
+Run it after changing grandine_py.pyi. With --check, it writes nothing and
+exits with a non-0 exit code if a stub is missing or out of date.
+"""
+
+import argparse
+import ast
+import sys
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+STUB = ROOT / "grandine_py.pyi"
+PACKAGE = ROOT / "grandine_py"
+
+HEADER = "# Generated by scripts/generate_stubs.py from grandine_py.pyi. Do not edit.\n"
+
+# The class name prefix of each fork, with the name of its submodule
+FORKS = {
+    "Phase0": "phase0",
+    "Altair": "altair",
+    "Bellatrix": "bellatrix",
+    "Capella": "capella",
+    "Deneb": "deneb",
+    "Electra": "electra",
+    "Fulu": "fulu",
+}
+
+# The class name suffix of each preset, with the name of its submodule
+PRESETS = {
+    "Mainnet": "mainnet",
+    "Minimal": "minimal",
+    "Gnosis": "gnosis",
+}
+
+
+def classes(stub: Path) -> list[str]:
+    """Returns the names of the top-level classes in `stub`, in order."""
+    tree = ast.parse(stub.read_text())
+    return [node.name for node in tree.body if isinstance(node, ast.ClassDef)]
+
+
+def short_names(names: list[str]) -> dict[tuple[str, str | None], dict[str, str]]:
+    """
+    Returns the classes of each fork and preset submodule, keyed by the fork
+    and preset (None for the fork itself), as a mapping of short names to
+    top-level names.
+    """
+    modules: dict[tuple[str, str | None], dict[str, str]] = {}
+    for fork in FORKS.values():
+        modules[fork, None] = {}
+        for preset in PRESETS.values():
+            modules[fork, preset] = {}
+
+    for name in names:
+        for prefix, fork in FORKS.items():
+            if not name.startswith(prefix):
+                continue
+            short_name = name.removeprefix(prefix)
+            for suffix, preset in PRESETS.items():
+                if short_name.endswith(suffix):
+                    modules[fork, preset][short_name.removesuffix(suffix)] = name
+                    break
+            else:
+                modules[fork, None][short_name] = name
+
+    return modules
+
+
+def render(aliases: dict[str, str], submodules: list[str]) -> str:
+    """Renders a stub that re-exports `aliases` and imports `submodules`."""
+    lines = [HEADER]
+
+    if submodules:
+        lines.append(
+            "from . import " + ", ".join(f"{name} as {name}" for name in submodules)
+        )
+    if aliases:
+        lines.append("from grandine_py import (")
+        lines.extend(
+            f"    {name} as {short_name}," for short_name, name in aliases.items()
+        )
+        lines.append(")")
+    lines.append("")
+
+    exported = [*submodules, *aliases]
+    if exported:
+        lines.append("__all__ = [")
+        lines.extend(f'    "{name}",' for name in exported)
+        lines.append("]")
+    else:
+        lines.append("__all__: list[str] = []")
+
+    return "\n".join(lines) + "\n"
+
+
+def stubs() -> dict[Path, str]:
+    """Returns the content of each submodule stub, keyed by its path."""
+    modules = short_names(classes(STUB))
+    files = {}
+
+    for fork in FORKS.values():
+        presets = list(PRESETS.values())
+        files[PACKAGE / fork / "__init__.pyi"] = render(modules[fork, None], presets)
+        for preset in presets:
+            files[PACKAGE / fork / f"{preset}.pyi"] = render(modules[fork, preset], [])
+
+    return files
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[1])
+    parser.add_argument(
+        "--check",
+        action="store_true",
+        help="exit with a non-0 exit code if a stub is out of date",
+    )
+    args = parser.parse_args()
+
+    stale = []
+    for path, content in stubs().items():
+        if path.exists() and path.read_text() == content:
+            continue
+        stale.append(path.relative_to(ROOT))
+        if not args.check:
+            path.parent.mkdir(parents=True, exist_ok=True)
+            path.write_text(content)
+
+    for path in stale:
+        print(f"{'Out of date' if args.check else 'Wrote'}: {path}")
+
+    if args.check and stale:
+        print("Run `python scripts/generate_stubs.py` to update the stubs.")
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
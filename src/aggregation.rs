@@ -0,0 +1,136 @@
+//! Attestation aggregation helpers.
+//!
+//! These functions accept any of the attestation classes exposed by this
+//! module and operate on their `AttestationData`, so that candidate selection
+//! for aggregation can happen without converting objects to Python values.
+
+use std::collections::HashMap;
+
+use grandine_ssz::{SszHash as _, H256};
+use grandine_types::phase0::containers::AttestationData;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::convert::format_hash_tree_root;
+use crate::electra::attestation::{
+    PyAttestationGnosis, PyAttestationMainnet, PyAttestationMinimal, PySingleAttestation,
+};
+use crate::phase0::attestation::{
+    PyPhase0AttestationGnosis, PyPhase0AttestationMainnet, PyPhase0AttestationMinimal,
+};
+use crate::presets::PresetKind;
+
+/// Extracts the `AttestationData` of a Python attestation object.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if the object is not an attestation.
+pub fn attestation_data(item: &Bound<'_, PyAny>) -> PyResult<AttestationData> {
+    macro_rules! try_data {
+        ($($class:ty),* $(,)?) => {
+            $(
+                if let Ok(attestation) = item.cast::<$class>() {
+                    return Ok(attestation.borrow().inner.data);
+                }
+            )*
+        };
+    }
+
+    try_data!(
+        PyAttestationMainnet,
+        PyAttestationMinimal,
+        PyAttestationGnosis,
+        PySingleAttestation,
+        PyPhase0AttestationMainnet,
+        PyPhase0AttestationMinimal,
+        PyPhase0AttestationGnosis,
+    );
+
+    Err(PyTypeError::new_err(format!(
+        "Expected an attestation, got {}",
+        item.get_type().name()?
+    )))
+}
+
+/// Returns the preset of a Python attestation object, or `None` for
+/// preset-independent classes such as `SingleAttestation`.
+#[must_use]
+pub fn attestation_preset(item: &Bound<'_, PyAny>) -> Option<PresetKind> {
+    if item.is_instance_of::<PyAttestationMainnet>()
+        || item.is_instance_of::<PyPhase0AttestationMainnet>()
+    {
+        Some(PresetKind::Mainnet)
+    } else if item.is_instance_of::<PyAttestationMinimal>()
+        || item.is_instance_of::<PyPhase0AttestationMinimal>()
+    {
+        Some(PresetKind::Minimal)
+    } else if item.is_instance_of::<PyAttestationGnosis>()
+        || item.is_instance_of::<PyPhase0AttestationGnosis>()
+    {
+        Some(PresetKind::Gnosis)
+    } else {
+        None
+    }
+}
+
+/// Group attestations by the hash tree root of their `AttestationData`.
+///
+/// Returns a dict mapping `0x`-prefixed data roots to lists of the original
+/// attestation objects. Groups and the attestations within them keep the
+/// order in which they first appear in the input.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if an item is not an attestation.
+#[pyfunction]
+pub fn group_by_data_root<'py>(
+    py: Python<'py>,
+    attestations: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let mut items = Vec::new();
+    let mut data = Vec::new();
+
+    for item in attestations.try_iter()? {
+        let item = item?;
+        data.push(attestation_data(&item)?);
+        items.push(item);
+    }
+
+    let (order, groups) = py.detach(|| {
+        let mut order = Vec::<H256>::new();
+        let mut groups = HashMap::<H256, Vec<usize>>::new();
+
+        for (index, data) in data.iter().enumerate() {
+            let root = data.hash_tree_root();
+            groups
+                .entry(root)
+                .or_insert_with(|| {
+                    order.push(root);
+                    Vec::new()
+                })
+                .push(index);
+        }
+
+        (order, groups)
+    });
+
+    let result = PyDict::new(py);
+
+    for root in order {
+        let members = groups[&root].iter().map(|index| &items[*index]);
+        result.set_item(format_hash_tree_root(&root), PyList::new(py, members)?)?;
+    }
+
+    Ok(result)
+}
+
+/// Registers the aggregation helpers with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(group_by_data_root, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! Altair block types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `SignedBeaconBlock` (Mainnet, Minimal, Gnosis)
+//!
+//! Altair blocks add the sync aggregate to the Phase 0 body.
+//!
+//! The classes share the API of the Phase 0 block classes; see
+//! `define_signed_block_pyclass!`.
+
+use grandine_types::altair::containers::SignedBeaconBlock;
+use grandine_types::preset::{Mainnet, Minimal};
+use pyo3::prelude::*;
+
+use crate::define_signed_block_pyclass;
+use crate::phase0::attestation::{
+    PyPhase0AttestationGnosis, PyPhase0AttestationMainnet, PyPhase0AttestationMinimal,
+};
+use crate::Gnosis;
+
+define_signed_block_pyclass!(
+    PyAltairSignedBeaconBlockMainnet,
+    "AltairSignedBeaconBlockMainnet",
+    SignedBeaconBlock<Mainnet>,
+    preset = Mainnet,
+    attestation = PyPhase0AttestationMainnet
+);
+
+define_signed_block_pyclass!(
+    PyAltairSignedBeaconBlockMinimal,
+    "AltairSignedBeaconBlockMinimal",
+    SignedBeaconBlock<Minimal>,
+    preset = Minimal,
+    attestation = PyPhase0AttestationMinimal
+);
+
+define_signed_block_pyclass!(
+    PyAltairSignedBeaconBlockGnosis,
+    "AltairSignedBeaconBlockGnosis",
+    SignedBeaconBlock<Gnosis>,
+    preset = Gnosis,
+    attestation = PyPhase0AttestationGnosis
+);
+
+/// Registers the Altair block types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyAltairSignedBeaconBlockMainnet>()?;
+    m.add_class::<PyAltairSignedBeaconBlockMinimal>()?;
+    m.add_class::<PyAltairSignedBeaconBlockGnosis>()?;
+    Ok(())
+}
@@ -0,0 +1,7 @@
+//! Altair hardfork types support.
+//!
+//! This module contains the sync committee messages introduced in Altair,
+//! which kept their layout in every later fork, and the Altair blocks.
+
+pub mod block;
+pub mod sync_committee;
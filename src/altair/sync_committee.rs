@@ -0,0 +1,503 @@
+//! Sync committee message types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `SyncCommitteeMessage` (preset-independent), as published on the
+//!   `sync_committee_{subnet_id}` gossip topics
+//! - `SyncCommitteeContribution` (Mainnet, Minimal, Gnosis), the aggregate of
+//!   one sync subcommittee's messages
+//! - `ContributionAndProof` and `SignedContributionAndProof` (Mainnet,
+//!   Minimal, Gnosis), as published on the `sync_committee_contribution_and_proof`
+//!   gossip topic
+//!
+//! Every type exposes its `hash_tree_root`. Sync committee members sign the
+//! block root with the `DOMAIN_SYNC_COMMITTEE` domain, and aggregators sign
+//! the `ContributionAndProof` with the `DOMAIN_CONTRIBUTION_AND_PROOF` domain.
+
+use grandine_ssz::SszHash as _;
+use grandine_types::altair::containers::{
+    ContributionAndProof, SignedContributionAndProof, SyncCommitteeContribution,
+    SyncCommitteeMessage,
+};
+use grandine_types::preset::{Mainnet, Minimal};
+use paste::paste;
+use pyo3::prelude::*;
+
+use crate::bls::{extract_signature, parse_public_key, verify};
+use crate::convert::{format_hash_tree_root, parse_root};
+use crate::hex_format::format_hex;
+use crate::list::PySszList;
+use crate::signing::signing_root;
+use crate::Gnosis;
+use crate::{define_ssz_pyclass_for_preset, ssz_list_view};
+
+define_ssz_pyclass_for_preset!(
+    PySyncCommitteeMessage,
+    "AltairSyncCommitteeMessage",
+    SyncCommitteeMessage,
+    extra_methods = {
+        pub fn slot(&self) -> u64 {
+            self.inner.slot
+        }
+
+        pub fn beacon_block_root(&self) -> String {
+            format_hash_tree_root(&self.inner.beacon_block_root)
+        }
+
+        pub fn validator_index(&self) -> u64 {
+            self.inner.validator_index
+        }
+
+        pub fn signature(&self) -> String {
+            format_hex(self.inner.signature.as_bytes())
+        }
+
+        pub fn hash_tree_root(&self) -> String {
+            format_hash_tree_root(&self.inner.hash_tree_root())
+        }
+
+        /// Signing root of the block root for the given
+        /// `DOMAIN_SYNC_COMMITTEE` domain.
+        ///
+        /// # Errors
+        /// Returns `PyValueError` if `domain` is not 32 bytes.
+        pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+            let root = signing_root(self.inner.beacon_block_root, parse_root(domain)?);
+            Ok(format_hash_tree_root(&root))
+        }
+
+        /// Verify the signature against the member's `pubkey` and the
+        /// `DOMAIN_SYNC_COMMITTEE` domain of the message's epoch.
+        ///
+        /// # Errors
+        /// Returns a `BlsEncodingError` subclass if `pubkey` is not a valid
+        /// pubkey, or `PyValueError` if `domain` is not 32 bytes.
+        pub fn verify(
+            &self,
+            py: Python<'_>,
+            pubkey: &Bound<'_, PyAny>,
+            domain: &Bound<'_, PyAny>,
+        ) -> PyResult<bool> {
+            let pubkey = parse_public_key(pubkey)?;
+            let root = signing_root(self.inner.beacon_block_root, parse_root(domain)?);
+            let signature = self.inner.signature;
+            Ok(py.detach(|| verify(signature, root, &pubkey)))
+        }
+    }
+);
+
+paste! {
+    define_ssz_pyclass_for_preset!(
+        [<PySyncCommitteeContributionMainnet>],
+        "AltairSyncCommitteeContributionMainnet",
+        SyncCommitteeContribution<Mainnet>,
+        extra_methods = {
+            pub fn slot(&self) -> u64 {
+                self.inner.slot
+            }
+
+            pub fn beacon_block_root(&self) -> String {
+                format_hash_tree_root(&self.inner.beacon_block_root)
+            }
+
+            pub fn subcommittee_index(&self) -> u64 {
+                self.inner.subcommittee_index
+            }
+
+            pub fn aggregation_bits(slf: &Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.aggregation_bits, bool)
+            }
+
+            pub fn signature(&self) -> String {
+                format_hex(self.inner.signature.as_bytes())
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyContributionAndProofMainnet>],
+        "AltairContributionAndProofMainnet",
+        ContributionAndProof<Mainnet>,
+        extra_methods = {
+            pub fn aggregator_index(&self) -> u64 {
+                self.inner.aggregator_index
+            }
+
+            pub fn contribution(&self) -> [<PySyncCommitteeContributionMainnet>] {
+                [<PySyncCommitteeContributionMainnet>] {
+                    inner: self.inner.contribution.clone(),
+                }
+            }
+
+            pub fn selection_proof(&self) -> String {
+                format_hex(self.inner.selection_proof.as_bytes())
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+
+            /// Signing root for the given `DOMAIN_CONTRIBUTION_AND_PROOF` domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes.
+            pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+                let root = signing_root(self.inner.hash_tree_root(), parse_root(domain)?);
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Attach the aggregator's signature.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `signature` is not a
+            /// valid signature.
+            pub fn with_signature(
+                &self,
+                signature: &Bound<'_, PyAny>,
+            ) -> PyResult<[<PySignedContributionAndProofMainnet>]> {
+                Ok([<PySignedContributionAndProofMainnet>] {
+                    inner: SignedContributionAndProof {
+                        message: self.inner.clone(),
+                        signature: extract_signature(signature)?,
+                    },
+                })
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PySignedContributionAndProofMainnet>],
+        "AltairSignedContributionAndProofMainnet",
+        SignedContributionAndProof<Mainnet>,
+        extra_methods = {
+            pub fn message(&self) -> [<PyContributionAndProofMainnet>] {
+                [<PyContributionAndProofMainnet>] {
+                    inner: self.inner.message.clone(),
+                }
+            }
+
+            pub fn signature(&self) -> String {
+                format_hex(self.inner.signature.as_bytes())
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+
+            /// Signing root of the message for the given
+            /// `DOMAIN_CONTRIBUTION_AND_PROOF` domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes.
+            pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+                let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Verify the signature against the aggregator's `pubkey` and the
+            /// `DOMAIN_CONTRIBUTION_AND_PROOF` domain of the contribution's
+            /// epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a valid
+            /// pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: Python<'_>,
+                pubkey: &Bound<'_, PyAny>,
+                domain: &Bound<'_, PyAny>,
+            ) -> PyResult<bool> {
+                let pubkey = parse_public_key(pubkey)?;
+                let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+                let signature = self.inner.signature;
+                Ok(py.detach(|| verify(signature, root, &pubkey)))
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PySyncCommitteeContributionMinimal>],
+        "AltairSyncCommitteeContributionMinimal",
+        SyncCommitteeContribution<Minimal>,
+        extra_methods = {
+            pub fn slot(&self) -> u64 {
+                self.inner.slot
+            }
+
+            pub fn beacon_block_root(&self) -> String {
+                format_hash_tree_root(&self.inner.beacon_block_root)
+            }
+
+            pub fn subcommittee_index(&self) -> u64 {
+                self.inner.subcommittee_index
+            }
+
+            pub fn aggregation_bits(slf: &Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.aggregation_bits, bool)
+            }
+
+            pub fn signature(&self) -> String {
+                format_hex(self.inner.signature.as_bytes())
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyContributionAndProofMinimal>],
+        "AltairContributionAndProofMinimal",
+        ContributionAndProof<Minimal>,
+        extra_methods = {
+            pub fn aggregator_index(&self) -> u64 {
+                self.inner.aggregator_index
+            }
+
+            pub fn contribution(&self) -> [<PySyncCommitteeContributionMinimal>] {
+                [<PySyncCommitteeContributionMinimal>] {
+                    inner: self.inner.contribution.clone(),
+                }
+            }
+
+            pub fn selection_proof(&self) -> String {
+                format_hex(self.inner.selection_proof.as_bytes())
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+
+            /// Signing root for the given `DOMAIN_CONTRIBUTION_AND_PROOF` domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes.
+            pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+                let root = signing_root(self.inner.hash_tree_root(), parse_root(domain)?);
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Attach the aggregator's signature.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `signature` is not a
+            /// valid signature.
+            pub fn with_signature(
+                &self,
+                signature: &Bound<'_, PyAny>,
+            ) -> PyResult<[<PySignedContributionAndProofMinimal>]> {
+                Ok([<PySignedContributionAndProofMinimal>] {
+                    inner: SignedContributionAndProof {
+                        message: self.inner.clone(),
+                        signature: extract_signature(signature)?,
+                    },
+                })
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PySignedContributionAndProofMinimal>],
+        "AltairSignedContributionAndProofMinimal",
+        SignedContributionAndProof<Minimal>,
+        extra_methods = {
+            pub fn message(&self) -> [<PyContributionAndProofMinimal>] {
+                [<PyContributionAndProofMinimal>] {
+                    inner: self.inner.message.clone(),
+                }
+            }
+
+            pub fn signature(&self) -> String {
+                format_hex(self.inner.signature.as_bytes())
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+
+            /// Signing root of the message for the given
+            /// `DOMAIN_CONTRIBUTION_AND_PROOF` domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes.
+            pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+                let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Verify the signature against the aggregator's `pubkey` and the
+            /// `DOMAIN_CONTRIBUTION_AND_PROOF` domain of the contribution's
+            /// epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a valid
+            /// pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: Python<'_>,
+                pubkey: &Bound<'_, PyAny>,
+                domain: &Bound<'_, PyAny>,
+            ) -> PyResult<bool> {
+                let pubkey = parse_public_key(pubkey)?;
+                let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+                let signature = self.inner.signature;
+                Ok(py.detach(|| verify(signature, root, &pubkey)))
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PySyncCommitteeContributionGnosis>],
+        "AltairSyncCommitteeContributionGnosis",
+        SyncCommitteeContribution<Gnosis>,
+        extra_methods = {
+            pub fn slot(&self) -> u64 {
+                self.inner.slot
+            }
+
+            pub fn beacon_block_root(&self) -> String {
+                format_hash_tree_root(&self.inner.beacon_block_root)
+            }
+
+            pub fn subcommittee_index(&self) -> u64 {
+                self.inner.subcommittee_index
+            }
+
+            pub fn aggregation_bits(slf: &Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.aggregation_bits, bool)
+            }
+
+            pub fn signature(&self) -> String {
+                format_hex(self.inner.signature.as_bytes())
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyContributionAndProofGnosis>],
+        "AltairContributionAndProofGnosis",
+        ContributionAndProof<Gnosis>,
+        extra_methods = {
+            pub fn aggregator_index(&self) -> u64 {
+                self.inner.aggregator_index
+            }
+
+            pub fn contribution(&self) -> [<PySyncCommitteeContributionGnosis>] {
+                [<PySyncCommitteeContributionGnosis>] {
+                    inner: self.inner.contribution.clone(),
+                }
+            }
+
+            pub fn selection_proof(&self) -> String {
+                format_hex(self.inner.selection_proof.as_bytes())
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+
+            /// Signing root for the given `DOMAIN_CONTRIBUTION_AND_PROOF` domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes.
+            pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+                let root = signing_root(self.inner.hash_tree_root(), parse_root(domain)?);
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Attach the aggregator's signature.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `signature` is not a
+            /// valid signature.
+            pub fn with_signature(
+                &self,
+                signature: &Bound<'_, PyAny>,
+            ) -> PyResult<[<PySignedContributionAndProofGnosis>]> {
+                Ok([<PySignedContributionAndProofGnosis>] {
+                    inner: SignedContributionAndProof {
+                        message: self.inner.clone(),
+                        signature: extract_signature(signature)?,
+                    },
+                })
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PySignedContributionAndProofGnosis>],
+        "AltairSignedContributionAndProofGnosis",
+        SignedContributionAndProof<Gnosis>,
+        extra_methods = {
+            pub fn message(&self) -> [<PyContributionAndProofGnosis>] {
+                [<PyContributionAndProofGnosis>] {
+                    inner: self.inner.message.clone(),
+                }
+            }
+
+            pub fn signature(&self) -> String {
+                format_hex(self.inner.signature.as_bytes())
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+
+            /// Signing root of the message for the given
+            /// `DOMAIN_CONTRIBUTION_AND_PROOF` domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes.
+            pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+                let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Verify the signature against the aggregator's `pubkey` and the
+            /// `DOMAIN_CONTRIBUTION_AND_PROOF` domain of the contribution's
+            /// epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a valid
+            /// pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: Python<'_>,
+                pubkey: &Bound<'_, PyAny>,
+                domain: &Bound<'_, PyAny>,
+            ) -> PyResult<bool> {
+                let pubkey = parse_public_key(pubkey)?;
+                let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+                let signature = self.inner.signature;
+                Ok(py.detach(|| verify(signature, root, &pubkey)))
+            }
+        }
+    );
+}
+
+/// Registers all sync committee message types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PySyncCommitteeMessage>()?;
+    m.add_class::<PySyncCommitteeContributionMainnet>()?;
+    m.add_class::<PyContributionAndProofMainnet>()?;
+    m.add_class::<PySignedContributionAndProofMainnet>()?;
+    m.add_class::<PySyncCommitteeContributionMinimal>()?;
+    m.add_class::<PyContributionAndProofMinimal>()?;
+    m.add_class::<PySignedContributionAndProofMinimal>()?;
+    m.add_class::<PySyncCommitteeContributionGnosis>()?;
+    m.add_class::<PyContributionAndProofGnosis>()?;
+    m.add_class::<PySignedContributionAndProofGnosis>()?;
+    Ok(())
+}
@@ -0,0 +1,403 @@
+//! Data availability checks for blocks with blobs.
+//!
+//! `check_data_availability` checks that the blobs committed to in a block
+//! are covered by valid sidecars of the block's fork: a `BlobSidecar` for
+//! every commitment in Electra, or every required `DataColumnSidecar` in Fulu,
+//! where blobs are sampled by column. A sidecar is valid if it is for the
+//! block, carries the block's commitments and its KZG proofs verify.
+//!
+//! Fulu blocks have the Electra layout, so an Electra block at or after the
+//! Fulu fork epoch of the config is checked as a Fulu block.
+//!
+//! Inclusion proofs of the commitments are not checked, since the
+//! commitments are compared with the block's directly.
+
+use std::collections::BTreeSet;
+
+use grandine_ssz::{SszHash as _, H256};
+use grandine_types::deneb::containers::BlobSidecar;
+use grandine_types::electra::containers::SignedBeaconBlock;
+use grandine_types::fulu::containers::DataColumnSidecar;
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::config::{resolve_config, PyConfig};
+use crate::convert::format_hash_tree_root;
+use crate::electra::block::{
+    PySignedBeaconBlockContentsGnosis, PySignedBeaconBlockContentsMainnet,
+    PySignedBeaconBlockContentsMinimal, PySignedBeaconBlockGnosis, PySignedBeaconBlockMainnet,
+    PySignedBeaconBlockMinimal,
+};
+use crate::electra::sidecar::{
+    verify_sidecar_proof, PyBlobSidecarGnosis, PyBlobSidecarMainnet, PyBlobSidecarMinimal,
+};
+use crate::envelope::Fork;
+use crate::fulu::block::{
+    PyFuluSignedBeaconBlockContentsGnosis, PyFuluSignedBeaconBlockContentsMainnet,
+    PyFuluSignedBeaconBlockContentsMinimal, PyFuluSignedBeaconBlockGnosis,
+    PyFuluSignedBeaconBlockMainnet, PyFuluSignedBeaconBlockMinimal,
+};
+use crate::fulu::sidecar::{
+    verify_column_proofs, PyFuluDataColumnSidecarGnosis, PyFuluDataColumnSidecarMainnet,
+    PyFuluDataColumnSidecarMinimal,
+};
+use crate::kzg::{resolve_setup, PyTrustedSetup};
+use crate::Gnosis;
+
+/// `NUMBER_OF_COLUMNS`, the number of columns of the extended blob matrix.
+const NUMBER_OF_COLUMNS: u64 = 128;
+
+/// A sidecar that does not count towards availability.
+struct Invalid {
+    kind: &'static str,
+    index: u64,
+    reason: String,
+}
+
+/// The outcome of a data availability check.
+struct Report {
+    fork: Fork,
+    slot: u64,
+    block_root: H256,
+    commitments: usize,
+    missing_blobs: Vec<u64>,
+    missing_columns: Vec<u64>,
+    invalid: Vec<Invalid>,
+}
+
+impl Report {
+    fn available(&self) -> bool {
+        match self.fork {
+            Fork::Fulu => self.missing_columns.is_empty(),
+            _ => self.missing_blobs.is_empty(),
+        }
+    }
+
+    fn into_dict(self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let invalid = PyList::empty(py);
+
+        for sidecar in &self.invalid {
+            let item = PyDict::new(py);
+            item.set_item("kind", sidecar.kind)?;
+            item.set_item("index", sidecar.index)?;
+            item.set_item("reason", &sidecar.reason)?;
+            invalid.append(item)?;
+        }
+
+        let dict = PyDict::new(py);
+        dict.set_item("fork", self.fork.name())?;
+        dict.set_item("slot", self.slot)?;
+        dict.set_item("block_root", format_hash_tree_root(&self.block_root))?;
+        dict.set_item("commitments", self.commitments)?;
+        dict.set_item("available", self.available())?;
+        dict.set_item("missing_blobs", &self.missing_blobs)?;
+        dict.set_item("missing_columns", &self.missing_columns)?;
+        dict.set_item("invalid", invalid)?;
+        Ok(dict.unbind())
+    }
+}
+
+/// Why a blob sidecar does not cover a commitment of the block, if it does
+/// not.
+fn blob_sidecar_error<P: Preset>(
+    sidecar: &BlobSidecar<P>,
+    block: &SignedBeaconBlock<P>,
+    block_root: H256,
+    setup: &c_kzg::KzgSettings,
+) -> Option<String> {
+    let sidecar_root = sidecar.signed_block_header.message.hash_tree_root();
+
+    if sidecar_root != block_root {
+        return Some(format!(
+            "Sidecar is for block {}",
+            format_hash_tree_root(&sidecar_root)
+        ));
+    }
+
+    let commitments = &block.message.body.blob_kzg_commitments;
+
+    let Some(commitment) = usize::try_from(sidecar.index)
+        .ok()
+        .and_then(|index| commitments.get(index))
+    else {
+        return Some(format!(
+            "Blob index {} is out of range for {} KZG commitments",
+            sidecar.index,
+            commitments.len()
+        ));
+    };
+
+    if *commitment != sidecar.kzg_commitment {
+        return Some("KZG commitment does not match the block".to_owned());
+    }
+
+    match verify_sidecar_proof(sidecar, setup) {
+        Ok(true) => None,
+        Ok(false) => Some("Invalid KZG proof".to_owned()),
+        Err(error) => Some(error),
+    }
+}
+
+/// Why a data column sidecar does not cover its column of the block, if it
+/// does not.
+fn column_sidecar_error<P: Preset>(
+    sidecar: &DataColumnSidecar<P>,
+    block: &SignedBeaconBlock<P>,
+    block_root: H256,
+    setup: &c_kzg::KzgSettings,
+) -> Option<String> {
+    let sidecar_root = sidecar.signed_block_header.message.hash_tree_root();
+
+    if sidecar_root != block_root {
+        return Some(format!(
+            "Sidecar is for block {}",
+            format_hash_tree_root(&sidecar_root)
+        ));
+    }
+
+    if sidecar.index >= NUMBER_OF_COLUMNS {
+        return Some(format!(
+            "Column index {} is out of range for {NUMBER_OF_COLUMNS} columns",
+            sidecar.index
+        ));
+    }
+
+    if sidecar.kzg_commitments != block.message.body.blob_kzg_commitments {
+        return Some("KZG commitments do not match the block".to_owned());
+    }
+
+    match verify_column_proofs(sidecar, setup) {
+        Ok(true) => None,
+        Ok(false) => Some("Invalid KZG proofs".to_owned()),
+        Err(error) => Some(error),
+    }
+}
+
+/// Checks which commitments of `block` the sidecars of `fork` cover, Electra
+/// or Fulu.
+fn check<P: Preset>(
+    block: &SignedBeaconBlock<P>,
+    fork: Fork,
+    blob_sidecars: &[&BlobSidecar<P>],
+    column_sidecars: &[&DataColumnSidecar<P>],
+    custody_columns: Option<&[u64]>,
+    setup: &c_kzg::KzgSettings,
+) -> Report {
+    let block_root = block.message.hash_tree_root();
+    let commitments = block.message.body.blob_kzg_commitments.len();
+
+    let mut invalid = Vec::new();
+    let mut blobs = BTreeSet::new();
+    let mut columns = BTreeSet::new();
+
+    let fulu = fork == Fork::Fulu;
+
+    for sidecar in blob_sidecars {
+        let error = if fulu {
+            Some("Blob sidecars are not used from Fulu on".to_owned())
+        } else {
+            blob_sidecar_error(sidecar, block, block_root, setup)
+        };
+
+        match error {
+            Some(reason) => invalid.push(Invalid {
+                kind: "blob",
+                index: sidecar.index,
+                reason,
+            }),
+            None => {
+                blobs.insert(sidecar.index);
+            }
+        }
+    }
+
+    for sidecar in column_sidecars {
+        let error = if fulu {
+            column_sidecar_error(sidecar, block, block_root, setup)
+        } else {
+            Some("Data column sidecars are not used before Fulu".to_owned())
+        };
+
+        match error {
+            Some(reason) => invalid.push(Invalid {
+                kind: "column",
+                index: sidecar.index,
+                reason,
+            }),
+            None => {
+                columns.insert(sidecar.index);
+            }
+        }
+    }
+
+    let required_columns = if !fulu || commitments == 0 {
+        BTreeSet::new()
+    } else {
+        custody_columns.map_or_else(
+            || (0..NUMBER_OF_COLUMNS).collect(),
+            |custody| custody.iter().copied().collect(),
+        )
+    };
+
+    let required_blobs = if fulu { 0 } else { commitments as u64 };
+
+    Report {
+        fork,
+        slot: block.message.slot,
+        block_root,
+        commitments,
+        missing_blobs: (0..required_blobs)
+            .filter(|index| !blobs.contains(index))
+            .collect(),
+        missing_columns: required_columns.difference(&columns).copied().collect(),
+        invalid,
+    }
+}
+
+/// Check that the blobs committed to in a signed block are available.
+///
+/// `sidecars_or_columns` are the blob sidecars and data column sidecars
+/// received for the block, of the block's preset. The block's fork is that
+/// of its class, except that an Electra block at or after the Fulu fork
+/// epoch of `config` is a Fulu block. Electra blobs are available if there
+/// is a valid blob sidecar for every commitment, and Fulu blobs if there is
+/// a valid data column sidecar for every required column: all
+/// `NUMBER_OF_COLUMNS` (128) columns, or only `custody_columns` if given.
+/// Sidecars that are for another block or fork, carry other commitments or
+/// fail KZG verification are reported and ignored.
+///
+/// Returns a dict with the block's `fork`, `slot`, `block_root`, the number
+/// of `commitments`, whether the blobs are `available`, the `missing_blobs`
+/// and `missing_columns` indices (only of the fork's sidecars), and the
+/// `invalid` sidecars, each with its `kind` (`"blob"` or `"column"`), `index`
+/// and `reason`.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if `signed_block` is not an Electra or Fulu signed
+/// block or block contents or a sidecar is not of the block's preset, or
+/// `PyValueError` if a custody column is out of range or `config` belongs to
+/// another preset.
+#[pyfunction]
+#[pyo3(signature = (
+    signed_block,
+    sidecars_or_columns,
+    config = None,
+    custody_columns = None,
+    setup = None,
+))]
+pub fn check_data_availability(
+    py: Python<'_>,
+    signed_block: &Bound<'_, PyAny>,
+    sidecars_or_columns: Vec<Bound<'_, PyAny>>,
+    config: Option<PyRef<'_, PyConfig>>,
+    custody_columns: Option<Vec<u64>>,
+    setup: Option<PyRef<'_, PyTrustedSetup>>,
+) -> PyResult<Py<PyDict>> {
+    if let Some(column) = custody_columns
+        .iter()
+        .flatten()
+        .find(|column| **column >= NUMBER_OF_COLUMNS)
+    {
+        return Err(PyValueError::new_err(format!(
+            "Custody column {column} is out of range for {NUMBER_OF_COLUMNS} columns"
+        )));
+    }
+
+    let setup = resolve_setup(setup.as_deref());
+
+    macro_rules! try_presets {
+        ($(
+            $block:ty, $contents:ty, $fulu_block:ty, $fulu_contents:ty,
+                $blob:ty, $column:ty => $preset:ty
+        ),* $(,)?) => {
+            $(
+                let block_ref;
+                let contents_ref;
+                let fulu_block_ref;
+                let fulu_contents_ref;
+
+                let block = if let Ok(block) = signed_block.cast::<$block>() {
+                    block_ref = block.borrow();
+                    Some((&block_ref.inner, Fork::Electra))
+                } else if let Ok(contents) = signed_block.cast::<$contents>() {
+                    contents_ref = contents.borrow();
+                    Some((&contents_ref.inner.signed_block, Fork::Electra))
+                } else if let Ok(block) = signed_block.cast::<$fulu_block>() {
+                    fulu_block_ref = block.borrow();
+                    Some((&fulu_block_ref.inner, Fork::Fulu))
+                } else if let Ok(contents) = signed_block.cast::<$fulu_contents>() {
+                    fulu_contents_ref = contents.borrow();
+                    Some((&fulu_contents_ref.inner.signed_block, Fork::Fulu))
+                } else {
+                    None
+                };
+
+                if let Some((block, fork)) = block {
+                    let chain_config = resolve_config::<$preset>(config.as_deref())?;
+
+                    let fork = if chain_config.is_fulu_slot(block.message.slot) {
+                        Fork::Fulu
+                    } else {
+                        fork
+                    };
+
+                    let mut blob_refs = Vec::new();
+                    let mut column_refs = Vec::new();
+
+                    for sidecar in &sidecars_or_columns {
+                        if let Ok(blob) = sidecar.cast::<$blob>() {
+                            blob_refs.push(blob.borrow());
+                        } else if let Ok(column) = sidecar.cast::<$column>() {
+                            column_refs.push(column.borrow());
+                        } else {
+                            return Err(PyTypeError::new_err(format!(
+                                "Expected a blob or data column sidecar of the block's preset, got {}",
+                                sidecar.get_type().name()?
+                            )));
+                        }
+                    }
+
+                    let blobs = blob_refs.iter().map(|blob| &blob.inner).collect::<Vec<_>>();
+                    let columns = column_refs.iter().map(|column| &column.inner).collect::<Vec<_>>();
+                    let custody_columns = custody_columns.as_deref();
+
+                    let report = py.detach(|| {
+                        check::<$preset>(block, fork, &blobs, &columns, custody_columns, &setup)
+                    });
+
+                    return report.into_dict(py);
+                }
+            )*
+        };
+    }
+
+    try_presets!(
+        PySignedBeaconBlockMainnet, PySignedBeaconBlockContentsMainnet,
+            PyFuluSignedBeaconBlockMainnet, PyFuluSignedBeaconBlockContentsMainnet,
+            PyBlobSidecarMainnet, PyFuluDataColumnSidecarMainnet => Mainnet,
+        PySignedBeaconBlockMinimal, PySignedBeaconBlockContentsMinimal,
+            PyFuluSignedBeaconBlockMinimal, PyFuluSignedBeaconBlockContentsMinimal,
+            PyBlobSidecarMinimal, PyFuluDataColumnSidecarMinimal => Minimal,
+        PySignedBeaconBlockGnosis, PySignedBeaconBlockContentsGnosis,
+            PyFuluSignedBeaconBlockGnosis, PyFuluSignedBeaconBlockContentsGnosis,
+            PyBlobSidecarGnosis, PyFuluDataColumnSidecarGnosis => Gnosis,
+    );
+
+    Err(PyTypeError::new_err(format!(
+        "Expected a signed block or signed block contents, got {}",
+        signed_block.get_type().name()?
+    )))
+}
+
+/// Registers the data availability check with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(check_data_availability, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//! Bellatrix block types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `SignedBeaconBlock` (Mainnet, Minimal, Gnosis)
+//! - `SignedBlindedBeaconBlock` (Mainnet, Minimal, Gnosis)
+//!
+//! Bellatrix blocks carry an execution payload, whose `transactions` are
+//! exposed as a lazy `SszList` of `bytes`. Blinded blocks carry the payload
+//! header instead.
+//!
+//! The classes share the API of the Phase 0 block classes; see
+//! `define_signed_block_pyclass!`.
+
+use grandine_types::bellatrix::containers::{SignedBeaconBlock, SignedBlindedBeaconBlock};
+use grandine_types::preset::{Mainnet, Minimal};
+use pyo3::prelude::*;
+
+use crate::list::PySszList;
+use crate::phase0::attestation::{
+    PyPhase0AttestationGnosis, PyPhase0AttestationMainnet, PyPhase0AttestationMinimal,
+};
+use crate::Gnosis;
+use crate::{define_signed_block_pyclass, ssz_list_view};
+
+define_signed_block_pyclass!(
+    PyBellatrixSignedBeaconBlockMainnet,
+    "BellatrixSignedBeaconBlockMainnet",
+    SignedBeaconBlock<Mainnet>,
+    preset = Mainnet,
+    attestation = PyPhase0AttestationMainnet,
+    extra_methods = {
+        pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.transactions,
+                bytes
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyBellatrixSignedBeaconBlockMinimal,
+    "BellatrixSignedBeaconBlockMinimal",
+    SignedBeaconBlock<Minimal>,
+    preset = Minimal,
+    attestation = PyPhase0AttestationMinimal,
+    extra_methods = {
+        pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.transactions,
+                bytes
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyBellatrixSignedBeaconBlockGnosis,
+    "BellatrixSignedBeaconBlockGnosis",
+    SignedBeaconBlock<Gnosis>,
+    preset = Gnosis,
+    attestation = PyPhase0AttestationGnosis,
+    extra_methods = {
+        pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.transactions,
+                bytes
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyBellatrixSignedBlindedBeaconBlockMainnet,
+    "BellatrixSignedBlindedBeaconBlockMainnet",
+    SignedBlindedBeaconBlock<Mainnet>,
+    preset = Mainnet,
+    attestation = PyPhase0AttestationMainnet
+);
+
+define_signed_block_pyclass!(
+    PyBellatrixSignedBlindedBeaconBlockMinimal,
+    "BellatrixSignedBlindedBeaconBlockMinimal",
+    SignedBlindedBeaconBlock<Minimal>,
+    preset = Minimal,
+    attestation = PyPhase0AttestationMinimal
+);
+
+define_signed_block_pyclass!(
+    PyBellatrixSignedBlindedBeaconBlockGnosis,
+    "BellatrixSignedBlindedBeaconBlockGnosis",
+    SignedBlindedBeaconBlock<Gnosis>,
+    preset = Gnosis,
+    attestation = PyPhase0AttestationGnosis
+);
+
+/// Registers the Bellatrix block types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyBellatrixSignedBeaconBlockMainnet>()?;
+    m.add_class::<PyBellatrixSignedBeaconBlockMinimal>()?;
+    m.add_class::<PyBellatrixSignedBeaconBlockGnosis>()?;
+    m.add_class::<PyBellatrixSignedBlindedBeaconBlockMainnet>()?;
+    m.add_class::<PyBellatrixSignedBlindedBeaconBlockMinimal>()?;
+    m.add_class::<PyBellatrixSignedBlindedBeaconBlockGnosis>()?;
+    Ok(())
+}
@@ -0,0 +1,6 @@
+//! Bellatrix hardfork types support.
+//!
+//! This module contains the Bellatrix blocks, the first to carry an
+//! execution payload, for decoding historical blocks.
+
+pub mod block;
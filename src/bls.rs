@@ -0,0 +1,242 @@
+//! Validation of BLS pubkey and signature encodings.
+//!
+//! Signers and relays sometimes return malformed keys or signatures. Parsing
+//! raises a dedicated `BlsEncodingError` subclass for each failure mode so
+//! callers can tell them apart:
+//!
+//! - `BlsHexError`: the hex string is not valid hex;
+//! - `BlsLengthError`: the encoding is not 48 (pubkey) or 96 (signature)
+//!   bytes long;
+//! - `BlsPointError`: the bytes are not a compressed point in the G1 (pubkey)
+//!   or G2 (signature) subgroup.
+//!
+//! All of them derive from `ValueError`.
+//!
+//! `bls_sign` signs signing roots with a secret key and `bls_pubkey` derives
+//! the key's pubkey, so that a remote signer can produce and check
+//! signatures without a second BLS library. Secret keys are only held for
+//! the duration of the call.
+
+use grandine_bls::{
+    PublicKey, PublicKeyBytes, SecretKey, SecretKeyBytes, Signature, SignatureBytes,
+};
+use grandine_ssz::{SszReadDefault as _, H256};
+use pyo3::create_exception;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyString};
+
+use crate::convert::parse_root;
+use crate::hex_format::format_hex;
+
+create_exception!(
+    grandine_py,
+    BlsEncodingError,
+    PyValueError,
+    "Invalid BLS pubkey or signature encoding."
+);
+create_exception!(
+    grandine_py,
+    BlsHexError,
+    BlsEncodingError,
+    "BLS pubkey or signature hex is not valid hex."
+);
+create_exception!(
+    grandine_py,
+    BlsLengthError,
+    BlsEncodingError,
+    "BLS pubkey or signature has the wrong length."
+);
+create_exception!(
+    grandine_py,
+    BlsPointError,
+    BlsEncodingError,
+    "BLS pubkey or signature is not a valid subgroup point."
+);
+
+const PUBKEY_LEN: usize = 48;
+const SECRET_KEY_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 96;
+
+/// Decodes a `0x`-prefixed hex string and checks its length.
+fn decode_hex(value: &str, what: &str, length: usize) -> PyResult<Vec<u8>> {
+    let bytes = hex::decode(value.trim_start_matches("0x"))
+        .map_err(|e| BlsHexError::new_err(format!("Invalid {what} hex: {e}")))?;
+    check_length(bytes, what, length)
+}
+
+fn check_length(bytes: Vec<u8>, what: &str, length: usize) -> PyResult<Vec<u8>> {
+    if bytes.len() != length {
+        return Err(BlsLengthError::new_err(format!(
+            "{what} must be {length} bytes, got {}",
+            bytes.len()
+        )));
+    }
+
+    Ok(bytes)
+}
+
+/// Reads an encoding given as `bytes` or a hex string.
+fn extract(value: &Bound<'_, PyAny>, what: &str, length: usize) -> PyResult<Vec<u8>> {
+    if let Ok(bytes) = value.cast::<PyBytes>() {
+        check_length(bytes.as_bytes().to_vec(), what, length)
+    } else if let Ok(string) = value.cast::<PyString>() {
+        decode_hex(string.to_str()?, what, length)
+    } else {
+        Err(PyTypeError::new_err(format!(
+            "{what} must be bytes or a hex string"
+        )))
+    }
+}
+
+fn check_signature(bytes: &[u8]) -> PyResult<SignatureBytes> {
+    let signature = SignatureBytes::from_ssz_default(bytes)
+        .map_err(|e| BlsLengthError::new_err(format!("Invalid signature bytes: {e:?}")))?;
+
+    Signature::try_from(signature)
+        .map_err(|e| BlsPointError::new_err(format!("Signature is not a valid G2 point: {e:?}")))?;
+
+    Ok(signature)
+}
+
+fn check_pubkey(bytes: &[u8]) -> PyResult<PublicKey> {
+    let pubkey = PublicKeyBytes::from_ssz_default(bytes)
+        .map_err(|e| BlsLengthError::new_err(format!("Invalid pubkey bytes: {e:?}")))?;
+
+    PublicKey::try_from(pubkey)
+        .map_err(|e| BlsPointError::new_err(format!("Pubkey is not a valid G1 point: {e:?}")))
+}
+
+/// Parses a hex-encoded BLS signature string.
+///
+/// # Errors
+///
+/// Returns a `BlsEncodingError` subclass if the hex is invalid, the
+/// signature is not 96 bytes, or it is not a valid G2 point.
+pub fn parse_signature(signature: &str) -> PyResult<SignatureBytes> {
+    check_signature(&decode_hex(signature, "signature", SIGNATURE_LEN)?)
+}
+
+/// Parses a BLS signature given as `bytes` or a hex string.
+///
+/// # Errors
+///
+/// Returns a `BlsEncodingError` subclass if the encoding is invalid, or
+/// `PyTypeError` if `signature` is neither bytes nor a string.
+pub fn extract_signature(signature: &Bound<'_, PyAny>) -> PyResult<SignatureBytes> {
+    check_signature(&extract(signature, "signature", SIGNATURE_LEN)?)
+}
+
+/// Parses a pubkey given as `bytes` or a hex string and decompresses it into
+/// a validated `PublicKey`, unlike `pubkey_index::parse_pubkey`, which only
+/// checks the length.
+///
+/// # Errors
+///
+/// Returns a `BlsEncodingError` subclass if the encoding is invalid, or
+/// `PyTypeError` if `pubkey` is neither bytes nor a string.
+pub fn parse_public_key(pubkey: &Bound<'_, PyAny>) -> PyResult<PublicKey> {
+    check_pubkey(&extract(pubkey, "pubkey", PUBKEY_LEN)?)
+}
+
+/// Verifies `signature` over `message` with `pubkey`.
+///
+/// Signatures that are not valid G2 points do not verify.
+#[must_use]
+pub fn verify(signature: SignatureBytes, message: H256, pubkey: &PublicKey) -> bool {
+    Signature::try_from(signature).is_ok_and(|signature| signature.verify(message, pubkey))
+}
+
+/// Parses a secret key given as `bytes` or a hex string, overwriting the
+/// intermediate copy of its bytes.
+pub(crate) fn parse_secret_key(secret_key: &Bound<'_, PyAny>) -> PyResult<SecretKey> {
+    let mut bytes = extract(secret_key, "secret key", SECRET_KEY_LEN)?;
+    let mut secret_key_bytes = SecretKeyBytes::default();
+    secret_key_bytes.as_mut().copy_from_slice(&bytes);
+    bytes.fill(0);
+
+    SecretKey::try_from(secret_key_bytes)
+        .map_err(|e| PyValueError::new_err(format!("Invalid secret key: {e:?}")))
+}
+
+/// Sign `signing_root` with `secret_key` (bytes or a hex string), returning
+/// the signature as a hex string.
+///
+/// `signing_root` is the root returned by the `signing_root` methods and
+/// `compute_*_signing_root` functions.
+///
+/// # Errors
+///
+/// Returns `BlsHexError` or `BlsLengthError` if `secret_key` is not 32 bytes
+/// of hex, `PyValueError` if it is not a valid scalar or `signing_root` is
+/// not 32 bytes, or `PyTypeError` if `secret_key` is neither bytes nor a
+/// string.
+#[pyfunction]
+pub fn bls_sign(
+    py: Python<'_>,
+    secret_key: &Bound<'_, PyAny>,
+    signing_root: &Bound<'_, PyAny>,
+) -> PyResult<String> {
+    let secret_key = parse_secret_key(secret_key)?;
+    let signing_root = parse_root(signing_root)?;
+    let signature: SignatureBytes = py.detach(|| secret_key.sign(signing_root).into());
+    Ok(format_hex(signature.as_bytes()))
+}
+
+/// Return the pubkey of `secret_key` (bytes or a hex string) as a hex
+/// string.
+///
+/// # Errors
+///
+/// Same as `bls_sign`.
+#[pyfunction]
+pub fn bls_pubkey(secret_key: &Bound<'_, PyAny>) -> PyResult<String> {
+    let pubkey: PublicKeyBytes = parse_secret_key(secret_key)?.to_public_key().into();
+    Ok(format_hex(pubkey.as_bytes()))
+}
+
+/// Check that `signature` (bytes or a hex string) is a valid compressed BLS
+/// signature in the G2 subgroup.
+///
+/// # Errors
+///
+/// Returns `BlsHexError`, `BlsLengthError` or `BlsPointError` describing the
+/// first problem found, or `PyTypeError` if `signature` is neither bytes nor
+/// a string.
+#[pyfunction]
+pub fn validate_signature_bytes(signature: &Bound<'_, PyAny>) -> PyResult<()> {
+    extract_signature(signature)?;
+    Ok(())
+}
+
+/// Check that `pubkey` (bytes or a hex string) is a valid compressed BLS
+/// public key in the G1 subgroup.
+///
+/// # Errors
+///
+/// Returns `BlsHexError`, `BlsLengthError` or `BlsPointError` describing the
+/// first problem found, or `PyTypeError` if `pubkey` is neither bytes nor a
+/// string.
+#[pyfunction]
+pub fn validate_pubkey_bytes(pubkey: &Bound<'_, PyAny>) -> PyResult<()> {
+    parse_public_key(pubkey)?;
+    Ok(())
+}
+
+/// Registers the BLS functions and exceptions with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add("BlsEncodingError", py.get_type::<BlsEncodingError>())?;
+    m.add("BlsHexError", py.get_type::<BlsHexError>())?;
+    m.add("BlsLengthError", py.get_type::<BlsLengthError>())?;
+    m.add("BlsPointError", py.get_type::<BlsPointError>())?;
+    m.add_function(wrap_pyfunction!(validate_signature_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_pubkey_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(bls_sign, m)?)?;
+    m.add_function(wrap_pyfunction!(bls_pubkey, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,225 @@
+//! Builder API (MEV-boost) types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `ValidatorRegistration` (preset-independent), the fee recipient and gas
+//!   limit a validator asks relays to build for
+//! - `SignedValidatorRegistration` (preset-independent), as submitted to
+//!   `/eth/v1/builder/validators`
+//!
+//! Bids (`ElectraSignedBuilderBid*`) and the payloads relays return for a
+//! submitted blinded block (`ElectraExecutionPayloadAndBlobsBundle*`) are
+//! defined with the Electra block and execution payload types; the helpers
+//! here provide their `value`, `pubkey` and `verify` methods.
+//!
+//! Builder messages are signed with the `DOMAIN_APPLICATION_BUILDER` domain
+//! of the genesis fork version and a zero genesis validators root, so they
+//! stay valid across forks. The `verify` methods take the genesis fork
+//! version of the network, as it differs between networks of a preset.
+
+use grandine_bls::{PublicKey, SignatureBytes};
+use grandine_builder_api::combined::SignedBuilderBid;
+use grandine_builder_api::unphased::containers::{
+    SignedValidatorRegistrationV1, ValidatorRegistrationV1,
+};
+use grandine_ssz::{SszHash, H256};
+use grandine_types::preset::Preset;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyInt, PyString};
+
+use crate::bls::{parse_public_key, verify};
+use crate::convert::format_hash_tree_root;
+use crate::define_ssz_pyclass_for_preset;
+use crate::domains::{compute_domain, parse_fork_version, DOMAIN_APPLICATION_BUILDER};
+use crate::hex_format::format_hex;
+use crate::signing::signing_root;
+
+/// Computes the `DOMAIN_APPLICATION_BUILDER` domain for a genesis fork
+/// version.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `genesis_fork_version` is not 4 bytes.
+pub fn builder_domain(genesis_fork_version: &Bound<'_, PyAny>) -> PyResult<H256> {
+    Ok(compute_domain(
+        DOMAIN_APPLICATION_BUILDER,
+        parse_fork_version(genesis_fork_version)?,
+        H256::zero(),
+    ))
+}
+
+/// Verifies a builder API `signature` over `message` against `pubkey`, with
+/// the GIL released.
+fn verify_builder_signature<T: SszHash + Sync>(
+    py: Python<'_>,
+    message: &T,
+    signature: SignatureBytes,
+    pubkey: &PublicKey,
+    genesis_fork_version: &Bound<'_, PyAny>,
+) -> PyResult<bool> {
+    let domain = builder_domain(genesis_fork_version)?;
+    Ok(py.detach(|| {
+        verify(
+            signature,
+            signing_root(message.hash_tree_root(), domain),
+            pubkey,
+        )
+    }))
+}
+
+fn not_electra() -> PyErr {
+    PyValueError::new_err("SignedBuilderBid must be an Electra bid")
+}
+
+/// Returns the value of a bid, in wei, as a Python `int`.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the bid is not an Electra bid.
+pub fn bid_value<'py, P: Preset>(
+    py: Python<'py>,
+    signed_builder_bid: &SignedBuilderBid<P>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let SignedBuilderBid::Electra(signed_builder_bid) = signed_builder_bid else {
+        return Err(not_electra());
+    };
+
+    let value = signed_builder_bid.message.value.to_string();
+    py.get_type::<PyInt>().call1((PyString::new(py, &value),))
+}
+
+/// Returns the pubkey of the builder that made a bid, as a hex string.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the bid is not an Electra bid.
+pub fn bid_pubkey<P: Preset>(signed_builder_bid: &SignedBuilderBid<P>) -> PyResult<String> {
+    let SignedBuilderBid::Electra(signed_builder_bid) = signed_builder_bid else {
+        return Err(not_electra());
+    };
+
+    Ok(format_hex(signed_builder_bid.message.pubkey.as_bytes()))
+}
+
+/// Verifies the signature of a bid against `builder_pubkey`.
+///
+/// # Errors
+///
+/// Returns a `BlsEncodingError` subclass if `builder_pubkey` is not a valid
+/// pubkey, or `PyValueError` if `genesis_fork_version` is not 4 bytes or the
+/// bid is not an Electra bid.
+pub fn verify_bid<P: Preset>(
+    py: Python<'_>,
+    signed_builder_bid: &SignedBuilderBid<P>,
+    builder_pubkey: &Bound<'_, PyAny>,
+    genesis_fork_version: &Bound<'_, PyAny>,
+) -> PyResult<bool> {
+    let SignedBuilderBid::Electra(signed_builder_bid) = signed_builder_bid else {
+        return Err(not_electra());
+    };
+
+    verify_builder_signature(
+        py,
+        &signed_builder_bid.message,
+        signed_builder_bid.signature,
+        &parse_public_key(builder_pubkey)?,
+        genesis_fork_version,
+    )
+}
+
+define_ssz_pyclass_for_preset!(
+    PyValidatorRegistration,
+    "ValidatorRegistration",
+    ValidatorRegistrationV1,
+    extra_methods = {
+        pub fn fee_recipient(&self) -> String {
+            format_hex(self.inner.fee_recipient.as_bytes())
+        }
+
+        pub fn gas_limit(&self) -> u64 {
+            self.inner.gas_limit
+        }
+
+        pub fn timestamp(&self) -> u64 {
+            self.inner.timestamp
+        }
+
+        pub fn pubkey(&self) -> String {
+            format_hex(self.inner.pubkey.as_bytes())
+        }
+
+        pub fn hash_tree_root(&self) -> String {
+            format_hash_tree_root(&self.inner.hash_tree_root())
+        }
+
+        /// Signing root of the registration for the
+        /// `DOMAIN_APPLICATION_BUILDER` domain of `genesis_fork_version`.
+        ///
+        /// # Errors
+        /// Returns `PyValueError` if `genesis_fork_version` is not 4 bytes.
+        pub fn signing_root(&self, genesis_fork_version: &Bound<'_, PyAny>) -> PyResult<String> {
+            let domain = builder_domain(genesis_fork_version)?;
+            Ok(format_hash_tree_root(&signing_root(
+                self.inner.hash_tree_root(),
+                domain,
+            )))
+        }
+    }
+);
+
+define_ssz_pyclass_for_preset!(
+    PySignedValidatorRegistration,
+    "SignedValidatorRegistration",
+    SignedValidatorRegistrationV1,
+    extra_methods = {
+        pub fn message(&self) -> PyValidatorRegistration {
+            PyValidatorRegistration {
+                inner: self.inner.message,
+            }
+        }
+
+        pub fn signature(&self) -> String {
+            format_hex(self.inner.signature.as_bytes())
+        }
+
+        pub fn hash_tree_root(&self) -> String {
+            format_hash_tree_root(&self.inner.hash_tree_root())
+        }
+
+        /// Verify the signature against the pubkey of the registration and
+        /// the `DOMAIN_APPLICATION_BUILDER` domain of `genesis_fork_version`.
+        ///
+        /// Registrations whose pubkey is not a valid point do not verify.
+        ///
+        /// # Errors
+        /// Returns `PyValueError` if `genesis_fork_version` is not 4 bytes.
+        pub fn verify(
+            &self,
+            py: Python<'_>,
+            genesis_fork_version: &Bound<'_, PyAny>,
+        ) -> PyResult<bool> {
+            let Ok(pubkey) = PublicKey::try_from(self.inner.message.pubkey) else {
+                return Ok(false);
+            };
+
+            verify_builder_signature(
+                py,
+                &self.inner.message,
+                self.inner.signature,
+                &pubkey,
+                genesis_fork_version,
+            )
+        }
+    }
+);
+
+/// Registers the builder API types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyValidatorRegistration>()?;
+    m.add_class::<PySignedValidatorRegistration>()?;
+    Ok(())
+}
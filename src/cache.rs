@@ -0,0 +1,292 @@
+//! Cache of decoded beacon states.
+//!
+//! Decoding a mainnet `BeaconState` takes seconds and gigabytes of memory, so
+//! analysis code that repeatedly needs the same justified or finalized state
+//! should decode it once. `StateCache` holds decoded state objects keyed by
+//! `(state_root, epoch)` with least-recently-used eviction.
+//!
+//! The cache stores the Python objects themselves, so any state class (or any
+//! other object) can be cached. It is safe to share between Python threads.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+use grandine_ssz::H256;
+use pyo3::exceptions::{PyKeyError, PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+
+use crate::convert::{format_hash_tree_root, parse_root};
+
+type CacheKey = (H256, u64);
+
+struct Entry {
+    state: Py<PyAny>,
+    pinned: bool,
+    last_used: u64,
+}
+
+/// LRU bookkeeping behind [`PyStateCache`].
+///
+/// Recency is tracked with a counter rather than a linked list; eviction scans
+/// all entries, which is cheap for the handful of states that fit in memory.
+struct StateCacheInner {
+    capacity: usize,
+    entries: HashMap<CacheKey, Entry>,
+    clock: u64,
+}
+
+impl StateCacheInner {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    fn get(&mut self, key: &CacheKey, py: Python<'_>) -> Option<Py<PyAny>> {
+        let now = self.tick();
+        self.entries.get_mut(key).map(|entry| {
+            entry.last_used = now;
+            entry.state.clone_ref(py)
+        })
+    }
+
+    /// Inserts `state` and returns the states it displaces, the replaced one
+    /// and any evicted ones, so that they can be dropped after the lock is
+    /// released.
+    fn insert(&mut self, key: CacheKey, state: Py<PyAny>) -> Vec<Py<PyAny>> {
+        let last_used = self.tick();
+        let pinned = self.entries.get(&key).is_some_and(|entry| entry.pinned);
+
+        let replaced = self.entries.insert(
+            key,
+            Entry {
+                state,
+                pinned,
+                last_used,
+            },
+        );
+
+        let mut displaced = self.evict_overflow();
+        displaced.extend(replaced.map(|entry| entry.state));
+        displaced
+    }
+
+    /// Evicts unpinned entries until the cache fits its capacity and returns
+    /// them, so that they can be dropped after the lock is released.
+    fn evict_overflow(&mut self) -> Vec<Py<PyAny>> {
+        let mut evicted = Vec::new();
+
+        while self.entries.len() > self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .filter(|(_, entry)| !entry.pinned)
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| *key);
+
+            let Some(key) = oldest else {
+                break;
+            };
+
+            if let Some(entry) = self.entries.remove(&key) {
+                evicted.push(entry.state);
+            }
+        }
+
+        evicted
+    }
+
+    fn set_pinned(&mut self, key: &CacheKey, pinned: bool) -> PyResult<Vec<Py<PyAny>>> {
+        let entry = self
+            .entries
+            .get_mut(key)
+            .ok_or_else(|| PyKeyError::new_err(format_key(key)))?;
+        entry.pinned = pinned;
+        Ok(if pinned {
+            Vec::new()
+        } else {
+            self.evict_overflow()
+        })
+    }
+}
+
+fn format_key((root, epoch): &CacheKey) -> String {
+    format!("({}, {epoch})", format_hash_tree_root(root))
+}
+
+/// Thread-safe LRU cache of decoded states keyed by `(root, epoch)`.
+///
+/// Pinned entries are never evicted. They still count towards the capacity,
+/// so a cache whose entries are all pinned may temporarily exceed it.
+#[pyclass(name = "StateCache", frozen)]
+pub struct PyStateCache {
+    inner: Mutex<StateCacheInner>,
+}
+
+impl PyStateCache {
+    fn lock(&self) -> PyResult<MutexGuard<'_, StateCacheInner>> {
+        self.inner
+            .lock()
+            .map_err(|_| PyRuntimeError::new_err("StateCache lock is poisoned"))
+    }
+}
+
+#[pymethods]
+impl PyStateCache {
+    #[new]
+    #[pyo3(signature = (capacity = 4))]
+    /// Create a cache holding at most `capacity` unpinned states.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `capacity` is 0.
+    fn new(capacity: usize) -> PyResult<Self> {
+        if capacity == 0 {
+            return Err(PyValueError::new_err("capacity must be positive"));
+        }
+
+        Ok(Self {
+            inner: Mutex::new(StateCacheInner {
+                capacity,
+                entries: HashMap::new(),
+                clock: 0,
+            }),
+        })
+    }
+
+    #[getter]
+    fn capacity(&self) -> PyResult<usize> {
+        Ok(self.lock()?.capacity)
+    }
+
+    /// Return the cached state, or `None` if it is not cached.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `root` is not a valid 32-byte root.
+    fn get(
+        &self,
+        py: Python<'_>,
+        root: &Bound<'_, PyAny>,
+        epoch: u64,
+    ) -> PyResult<Option<Py<PyAny>>> {
+        let key = (parse_root(root)?, epoch);
+        Ok(self.lock()?.get(&key, py))
+    }
+
+    /// Cache a state, evicting the least recently used unpinned states if
+    /// the cache is full. Replacing a pinned entry keeps it pinned.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `root` is not a valid 32-byte root.
+    fn insert(&self, root: &Bound<'_, PyAny>, epoch: u64, state: Py<PyAny>) -> PyResult<()> {
+        let key = (parse_root(root)?, epoch);
+        let evicted = self.lock()?.insert(key, state);
+        drop(evicted);
+        Ok(())
+    }
+
+    /// Return the cached state, calling `factory()` and caching its result on
+    /// a miss.
+    ///
+    /// The lock is not held while `factory` runs, so concurrent misses for the
+    /// same key may each call it; the last result wins.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `root` is invalid, or any error raised by
+    /// `factory`.
+    fn get_or_insert(
+        &self,
+        py: Python<'_>,
+        root: &Bound<'_, PyAny>,
+        epoch: u64,
+        factory: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        let key = (parse_root(root)?, epoch);
+
+        if let Some(state) = self.lock()?.get(&key, py) {
+            return Ok(state);
+        }
+
+        let state = factory.call0()?.unbind();
+        let evicted = self.lock()?.insert(key, state.clone_ref(py));
+        drop(evicted);
+        Ok(state)
+    }
+
+    /// Protect a cached state from eviction.
+    ///
+    /// # Errors
+    /// Returns `PyKeyError` if the state is not cached.
+    fn pin(&self, root: &Bound<'_, PyAny>, epoch: u64) -> PyResult<()> {
+        let key = (parse_root(root)?, epoch);
+        self.lock()?.set_pinned(&key, true).map(drop)
+    }
+
+    /// Allow a pinned state to be evicted again.
+    ///
+    /// # Errors
+    /// Returns `PyKeyError` if the state is not cached.
+    fn unpin(&self, root: &Bound<'_, PyAny>, epoch: u64) -> PyResult<()> {
+        let key = (parse_root(root)?, epoch);
+        let evicted = self.lock()?.set_pinned(&key, false)?;
+        drop(evicted);
+        Ok(())
+    }
+
+    /// Remove a state from the cache, pinned or not.
+    ///
+    /// Returns whether the state was cached.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `root` is not a valid 32-byte root.
+    fn evict(&self, root: &Bound<'_, PyAny>, epoch: u64) -> PyResult<bool> {
+        let key = (parse_root(root)?, epoch);
+        let removed = self.lock()?.entries.remove(&key);
+        Ok(removed.is_some())
+    }
+
+    /// Remove all states, including pinned ones.
+    fn clear(&self) -> PyResult<()> {
+        let entries = std::mem::take(&mut self.lock()?.entries);
+        drop(entries);
+        Ok(())
+    }
+
+    /// Return the cached keys as `(root, epoch)` tuples, least recently used
+    /// first.
+    fn keys<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let mut keys = self
+            .lock()?
+            .entries
+            .iter()
+            .map(|(key, entry)| (entry.last_used, *key))
+            .collect::<Vec<_>>();
+
+        keys.sort_unstable_by_key(|(last_used, _)| *last_used);
+
+        PyList::new(
+            py,
+            keys.into_iter()
+                .map(|(_, (root, epoch))| (format_hash_tree_root(&root), epoch)),
+        )
+    }
+
+    fn __len__(&self) -> PyResult<usize> {
+        Ok(self.lock()?.entries.len())
+    }
+
+    fn __contains__(&self, key: (Bound<'_, PyAny>, u64)) -> PyResult<bool> {
+        let (root, epoch) = key;
+        let key = (parse_root(&root)?, epoch);
+        Ok(self.lock()?.entries.contains_key(&key))
+    }
+}
+
+/// Registers the state cache class with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyStateCache>()?;
+    Ok(())
+}
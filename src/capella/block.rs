@@ -0,0 +1,188 @@
+//! Capella block types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `SignedBeaconBlock` (Mainnet, Minimal, Gnosis)
+//! - `SignedBlindedBeaconBlock` (Mainnet, Minimal, Gnosis)
+//!
+//! Capella blocks add BLS to execution changes to the body, exposed as
+//! `bls_to_execution_changes`, and `withdrawals` to the execution payload.
+//!
+//! The classes share the API of the Phase 0 block classes; see
+//! `define_signed_block_pyclass!`.
+
+use grandine_types::capella::containers::{SignedBeaconBlock, SignedBlindedBeaconBlock};
+use grandine_types::preset::{Mainnet, Minimal};
+use pyo3::prelude::*;
+
+use crate::capella::bls_change::PySignedBlsToExecutionChange;
+use crate::list::PySszList;
+use crate::phase0::attestation::{
+    PyPhase0AttestationGnosis, PyPhase0AttestationMainnet, PyPhase0AttestationMinimal,
+};
+use crate::Gnosis;
+use crate::{define_signed_block_pyclass, ssz_list_view};
+
+define_signed_block_pyclass!(
+    PyCapellaSignedBeaconBlockMainnet,
+    "CapellaSignedBeaconBlockMainnet",
+    SignedBeaconBlock<Mainnet>,
+    preset = Mainnet,
+    attestation = PyPhase0AttestationMainnet,
+    extra_methods = {
+        pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.bls_to_execution_changes,
+                class = PySignedBlsToExecutionChange
+            )
+        }
+
+        pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.transactions,
+                bytes
+            )
+        }
+
+        pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.withdrawals,
+                json
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyCapellaSignedBeaconBlockMinimal,
+    "CapellaSignedBeaconBlockMinimal",
+    SignedBeaconBlock<Minimal>,
+    preset = Minimal,
+    attestation = PyPhase0AttestationMinimal,
+    extra_methods = {
+        pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.bls_to_execution_changes,
+                class = PySignedBlsToExecutionChange
+            )
+        }
+
+        pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.transactions,
+                bytes
+            )
+        }
+
+        pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.withdrawals,
+                json
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyCapellaSignedBeaconBlockGnosis,
+    "CapellaSignedBeaconBlockGnosis",
+    SignedBeaconBlock<Gnosis>,
+    preset = Gnosis,
+    attestation = PyPhase0AttestationGnosis,
+    extra_methods = {
+        pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.bls_to_execution_changes,
+                class = PySignedBlsToExecutionChange
+            )
+        }
+
+        pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.transactions,
+                bytes
+            )
+        }
+
+        pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.withdrawals,
+                json
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyCapellaSignedBlindedBeaconBlockMainnet,
+    "CapellaSignedBlindedBeaconBlockMainnet",
+    SignedBlindedBeaconBlock<Mainnet>,
+    preset = Mainnet,
+    attestation = PyPhase0AttestationMainnet,
+    extra_methods = {
+        pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.bls_to_execution_changes,
+                class = PySignedBlsToExecutionChange
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyCapellaSignedBlindedBeaconBlockMinimal,
+    "CapellaSignedBlindedBeaconBlockMinimal",
+    SignedBlindedBeaconBlock<Minimal>,
+    preset = Minimal,
+    attestation = PyPhase0AttestationMinimal,
+    extra_methods = {
+        pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.bls_to_execution_changes,
+                class = PySignedBlsToExecutionChange
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyCapellaSignedBlindedBeaconBlockGnosis,
+    "CapellaSignedBlindedBeaconBlockGnosis",
+    SignedBlindedBeaconBlock<Gnosis>,
+    preset = Gnosis,
+    attestation = PyPhase0AttestationGnosis,
+    extra_methods = {
+        pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.bls_to_execution_changes,
+                class = PySignedBlsToExecutionChange
+            )
+        }
+    }
+);
+
+/// Registers the Capella block types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyCapellaSignedBeaconBlockMainnet>()?;
+    m.add_class::<PyCapellaSignedBeaconBlockMinimal>()?;
+    m.add_class::<PyCapellaSignedBeaconBlockGnosis>()?;
+    m.add_class::<PyCapellaSignedBlindedBeaconBlockMainnet>()?;
+    m.add_class::<PyCapellaSignedBlindedBeaconBlockMinimal>()?;
+    m.add_class::<PyCapellaSignedBlindedBeaconBlockGnosis>()?;
+    Ok(())
+}
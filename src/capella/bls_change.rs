@@ -0,0 +1,70 @@
+//! BLS to execution change types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `BLSToExecutionChange` (preset-independent), which moves a validator
+//!   from BLS withdrawal credentials to an execution address
+//! - `SignedBLSToExecutionChange` (preset-independent), as published on the
+//!   `bls_to_execution_change` gossip topic and included in blocks
+
+use grandine_ssz::SszHash as _;
+use grandine_types::capella::containers::{BlsToExecutionChange, SignedBlsToExecutionChange};
+use pyo3::prelude::*;
+
+use crate::convert::format_hash_tree_root;
+use crate::define_ssz_pyclass_for_preset;
+use crate::hex_format::format_hex;
+
+define_ssz_pyclass_for_preset!(
+    PyBlsToExecutionChange,
+    "BLSToExecutionChange",
+    BlsToExecutionChange,
+    extra_methods = {
+        pub fn validator_index(&self) -> u64 {
+            self.inner.validator_index
+        }
+
+        pub fn from_bls_pubkey(&self) -> String {
+            format_hex(self.inner.from_bls_pubkey.as_bytes())
+        }
+
+        pub fn to_execution_address(&self) -> String {
+            format_hex(self.inner.to_execution_address.as_bytes())
+        }
+
+        pub fn hash_tree_root(&self) -> String {
+            format_hash_tree_root(&self.inner.hash_tree_root())
+        }
+    }
+);
+
+define_ssz_pyclass_for_preset!(
+    PySignedBlsToExecutionChange,
+    "SignedBLSToExecutionChange",
+    SignedBlsToExecutionChange,
+    extra_methods = {
+        pub fn message(&self) -> PyBlsToExecutionChange {
+            PyBlsToExecutionChange {
+                inner: self.inner.message,
+            }
+        }
+
+        pub fn signature(&self) -> String {
+            format_hex(self.inner.signature.as_bytes())
+        }
+
+        pub fn hash_tree_root(&self) -> String {
+            format_hash_tree_root(&self.inner.hash_tree_root())
+        }
+    }
+);
+
+/// Registers the BLS to execution change types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyBlsToExecutionChange>()?;
+    m.add_class::<PySignedBlsToExecutionChange>()?;
+    Ok(())
+}
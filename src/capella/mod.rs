@@ -0,0 +1,8 @@
+//! Capella hardfork types support.
+//!
+//! This module contains the BLS to execution credential changes introduced in
+//! Capella, which kept their layout in every later fork, and the Capella
+//! blocks.
+
+pub mod block;
+pub mod bls_change;
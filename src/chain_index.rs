@@ -0,0 +1,543 @@
+//! Block root, slot and parent lookups for a chain of block headers.
+//!
+//! `ChainIndex` stores the slot and parent root of every ingested block and
+//! the canonical chain as a slot to root map, which is followed back from the
+//! head. Queries on canonical blocks are answered from that map without
+//! walking parents; queries on other blocks walk their parents until they
+//! reach the canonical chain.
+//!
+//! The head advances only to an added block whose parent is the head, so a
+//! finalized chain has to be ingested in slot order for every block to be
+//! canonical, and then no query is worse than logarithmic in the number of
+//! blocks. Blocks added out of order are indexed but stay off the canonical
+//! chain, and queries on them walk their parents, until `set_head` is called
+//! with the tip of the chain. `set_head` also switches to another branch
+//! after a reorg.
+//!
+//! Every block must be at a later slot than its parent, whichever of the two
+//! is added first, so that the chain of any block can be followed back by
+//! decreasing slots.
+//!
+//! The file format is
+//!
+//! ```text
+//! index = "GCIX" count:u64 head:root entry[count]
+//! entry = root slot:u64 parent_root
+//! ```
+//!
+//! with integers little-endian, roots 32 bytes, entries in slot order and an
+//! all-zero `head` for an empty index.
+
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
+use std::path::PathBuf;
+
+use grandine_ssz::{SszHash as _, H256};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::convert::{buffer_bytes, format_hash_tree_root, input_buffer, parse_root};
+use crate::phase0::header::PySignedBeaconBlockHeader;
+
+const MAGIC: &[u8; 4] = b"GCIX";
+const HEADER_LEN: usize = 44;
+const ENTRY_LEN: usize = 72;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Entry {
+    slot: u64,
+    parent: H256,
+}
+
+/// Indexes block roots by slot and parent, with the canonical chain of the
+/// head.
+#[pyclass(name = "ChainIndex")]
+#[derive(Default)]
+pub struct PyChainIndex {
+    entries: HashMap<H256, Entry>,
+    canonical: BTreeMap<u64, H256>,
+    head: Option<H256>,
+    /// The earliest slot of the indexed children of each block that is not
+    /// indexed itself.
+    orphan_slots: HashMap<H256, u64>,
+}
+
+impl PyChainIndex {
+    /// Adds a block, returning whether it was new.
+    fn insert(&mut self, root: H256, slot: u64, parent: H256) -> Result<bool, String> {
+        let entry = Entry { slot, parent };
+
+        if let Some(known) = self.entries.get(&root) {
+            return if *known == entry {
+                Ok(false)
+            } else {
+                Err(format!(
+                    "Block {} is already indexed with another slot or parent",
+                    format_hash_tree_root(&root)
+                ))
+            };
+        }
+
+        if let Some(parent_entry) = self.entries.get(&parent) {
+            if parent_entry.slot >= slot {
+                return Err(format!(
+                    "Block slot {slot} is not after the parent block slot {}",
+                    parent_entry.slot
+                ));
+            }
+        }
+
+        if let Some(child_slot) = self.orphan_slots.get(&root) {
+            if *child_slot <= slot {
+                return Err(format!(
+                    "Block slot {slot} is not before the child block slot {child_slot}"
+                ));
+            }
+        }
+
+        self.entries.insert(root, entry);
+        self.orphan_slots.remove(&root);
+        self.add_orphan(parent, slot);
+
+        match self.head {
+            None => self.reorg(root)?,
+            Some(head) if head == parent => {
+                self.canonical.insert(slot, root);
+                self.head = Some(root);
+            }
+            Some(_) => {}
+        }
+
+        Ok(true)
+    }
+
+    /// Records a child at `slot` of `parent` if `parent` is not indexed.
+    fn add_orphan(&mut self, parent: H256, slot: u64) {
+        if !self.entries.contains_key(&parent) {
+            self.orphan_slots
+                .entry(parent)
+                .and_modify(|child_slot| *child_slot = (*child_slot).min(slot))
+                .or_insert(slot);
+        }
+    }
+
+    /// Returns the blocks from `head` back to the first canonical one, or the
+    /// last indexed one, checking that their slots decrease.
+    fn new_chain(&self, head: H256) -> Result<Vec<(H256, Entry)>, String> {
+        let mut chain = Vec::new();
+        let mut root = head;
+
+        while let Some(entry) = self.entries.get(&root).copied() {
+            if let Some((child, child_entry)) = chain.last() {
+                if entry.slot >= child_entry.slot {
+                    return Err(format!(
+                        "Block {} is not before its child {}",
+                        format_hash_tree_root(&root),
+                        format_hash_tree_root(child),
+                    ));
+                }
+            }
+
+            chain.push((root, entry));
+
+            if self.is_canonical_entry(root, entry) {
+                break;
+            }
+
+            root = entry.parent;
+        }
+
+        Ok(chain)
+    }
+
+    /// Makes `head` the head, rebuilding the canonical chain back to the
+    /// first block it shares with the current one.
+    fn reorg(&mut self, head: H256) -> Result<(), String> {
+        let chain = self.new_chain(head)?;
+        let (_, head_entry) = chain
+            .first()
+            .ok_or_else(|| format!("Block {} is not indexed", format_hash_tree_root(&head)))?;
+
+        if let Some(after_head) = head_entry.slot.checked_add(1) {
+            drop(self.canonical.split_off(&after_head));
+        }
+
+        let mut upper = head_entry.slot;
+
+        for (root, entry) in &chain {
+            // Slots skipped by the new chain may hold blocks of the old one.
+            let skipped = self
+                .canonical
+                .range((Bound::Excluded(entry.slot), Bound::Unbounded))
+                .map(|(slot, _)| *slot)
+                .take_while(|slot| *slot < upper)
+                .collect::<Vec<_>>();
+            for slot in skipped {
+                self.canonical.remove(&slot);
+            }
+
+            if self.canonical.get(&entry.slot) == Some(root) {
+                self.head = Some(head);
+                return Ok(());
+            }
+
+            self.canonical.insert(entry.slot, *root);
+            upper = entry.slot;
+        }
+
+        // The chain ends here, so nothing below it is canonical.
+        self.canonical = self.canonical.split_off(&upper);
+        self.head = Some(head);
+        Ok(())
+    }
+
+    fn is_canonical_entry(&self, root: H256, entry: Entry) -> bool {
+        self.canonical.get(&entry.slot) == Some(&root)
+    }
+
+    fn entry(&self, root: H256) -> Result<Entry, String> {
+        self.entries
+            .get(&root)
+            .copied()
+            .ok_or_else(|| format!("Block {} is not indexed", format_hash_tree_root(&root)))
+    }
+
+    /// Returns the latest ancestor of `root` (or `root` itself) at or before
+    /// `slot`.
+    fn ancestor(&self, mut root: H256, slot: u64) -> Option<H256> {
+        loop {
+            let entry = self.entries.get(&root)?;
+
+            if entry.slot <= slot {
+                return Some(root);
+            }
+
+            if self.is_canonical_entry(root, *entry) {
+                return self
+                    .canonical
+                    .range(..=slot)
+                    .next_back()
+                    .map(|(_, root)| *root);
+            }
+
+            root = entry.parent;
+        }
+    }
+
+    /// Returns the chain from `from` to `to`, both included, if `from` is an
+    /// ancestor of `to`.
+    fn path(&self, from: H256, to: H256) -> Option<Vec<H256>> {
+        let from_slot = self.entries.get(&from)?.slot;
+        let mut path = Vec::new();
+        let mut root = to;
+
+        loop {
+            let entry = self.entries.get(&root)?;
+
+            if root == from {
+                path.push(root);
+                break;
+            }
+
+            if entry.slot <= from_slot {
+                return None;
+            }
+
+            if self.is_canonical_entry(root, *entry) {
+                if self.canonical.get(&from_slot) != Some(&from) {
+                    return None;
+                }
+                path.extend(
+                    self.canonical
+                        .range(from_slot..=entry.slot)
+                        .rev()
+                        .map(|(_, root)| *root),
+                );
+                break;
+            }
+
+            path.push(root);
+            root = entry.parent;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let (header, entries) = bytes
+            .split_at_checked(HEADER_LEN)
+            .filter(|(header, _)| header.starts_with(MAGIC))
+            .ok_or("Not a ChainIndex")?;
+
+        let count = u64::from_le_bytes(header[4..12].try_into().map_err(|_| "Truncated header")?);
+        let head = H256::from_slice(&header[12..]);
+
+        if Some(entries.len())
+            != usize::try_from(count)
+                .ok()
+                .and_then(|c| c.checked_mul(ENTRY_LEN))
+        {
+            return Err(format!(
+                "Expected {count} entries, got {} bytes",
+                entries.len()
+            ));
+        }
+
+        let mut index = Self::default();
+        index.entries.reserve(entries.len() / ENTRY_LEN);
+
+        for entry in entries.chunks_exact(ENTRY_LEN) {
+            let root = H256::from_slice(&entry[..32]);
+            let slot = u64::from_le_bytes(entry[32..40].try_into().map_err(|_| "Truncated slot")?);
+            let parent = H256::from_slice(&entry[40..]);
+
+            if index.entries.insert(root, Entry { slot, parent }).is_some() {
+                return Err(format!("Duplicate block {}", format_hash_tree_root(&root)));
+            }
+        }
+
+        let parents = index
+            .entries
+            .iter()
+            .map(|(root, entry)| (*root, *entry))
+            .collect::<Vec<_>>();
+
+        for (root, entry) in parents {
+            match index.entries.get(&entry.parent) {
+                Some(parent) if parent.slot >= entry.slot => {
+                    return Err(format!(
+                        "Block {} is not after its parent",
+                        format_hash_tree_root(&root)
+                    ));
+                }
+                Some(_) => {}
+                None => index.add_orphan(entry.parent, entry.slot),
+            }
+        }
+
+        if index.entries.is_empty() {
+            return Ok(index);
+        }
+
+        if !index.entries.contains_key(&head) {
+            return Err("Head block is not indexed".to_owned());
+        }
+
+        index.reorg(head)?;
+        Ok(index)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut entries = self.entries.iter().collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|(root, entry)| (entry.slot, **root));
+
+        let mut out = Vec::with_capacity(HEADER_LEN + entries.len() * ENTRY_LEN);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&(entries.len() as u64).to_le_bytes());
+        out.extend_from_slice(self.head.unwrap_or_default().as_bytes());
+        for (root, entry) in entries {
+            out.extend_from_slice(root.as_bytes());
+            out.extend_from_slice(&entry.slot.to_le_bytes());
+            out.extend_from_slice(entry.parent.as_bytes());
+        }
+        out
+    }
+}
+
+#[pymethods]
+impl PyChainIndex {
+    #[new]
+    fn py_new() -> Self {
+        Self::default()
+    }
+
+    /// Add the block of a signed header, returning whether it was new.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the block is already indexed with another
+    /// parent, or its slot is not after its parent's or before its indexed
+    /// children's.
+    fn add(&mut self, header: PyRef<'_, PySignedBeaconBlockHeader>) -> PyResult<bool> {
+        let message = &header.inner.message;
+        self.insert(message.hash_tree_root(), message.slot, message.parent_root)
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Add the blocks of signed headers in order, returning how many were new.
+    ///
+    /// # Errors
+    /// Same as `add`.
+    fn extend(&mut self, headers: Vec<PyRef<'_, PySignedBeaconBlockHeader>>) -> PyResult<usize> {
+        let mut count = 0;
+        for header in headers {
+            count += usize::from(self.add(header)?);
+        }
+        Ok(count)
+    }
+
+    /// Add a block by root, slot and parent root, returning whether it was
+    /// new.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if a root is invalid, or as `add`.
+    fn insert_block(
+        &mut self,
+        root: &Bound<'_, PyAny>,
+        slot: u64,
+        parent_root: &Bound<'_, PyAny>,
+    ) -> PyResult<bool> {
+        self.insert(parse_root(root)?, slot, parse_root(parent_root)?)
+            .map_err(PyValueError::new_err)
+    }
+
+    /// The head block root, or `None` if the index is empty.
+    #[getter]
+    fn head(&self) -> Option<String> {
+        self.head.as_ref().map(format_hash_tree_root)
+    }
+
+    /// Make `root` the head, e.g. after a reorg.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `root` is invalid or not indexed.
+    fn set_head(&mut self, root: &Bound<'_, PyAny>) -> PyResult<()> {
+        let root = parse_root(root)?;
+        self.entry(root).map_err(PyValueError::new_err)?;
+        self.reorg(root).map_err(PyValueError::new_err)
+    }
+
+    /// Return the slot of `root`, or `None` if it is not indexed.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `root` is invalid.
+    fn slot_of(&self, root: &Bound<'_, PyAny>) -> PyResult<Option<u64>> {
+        Ok(self.entries.get(&parse_root(root)?).map(|entry| entry.slot))
+    }
+
+    /// Return the parent root of `root`, or `None` if it is not indexed.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `root` is invalid.
+    fn parent_of(&self, root: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+        Ok(self
+            .entries
+            .get(&parse_root(root)?)
+            .map(|entry| format_hash_tree_root(&entry.parent)))
+    }
+
+    /// Return the canonical block root at `slot`, or `None` if the slot is
+    /// empty or outside the indexed chain.
+    fn root_at_slot(&self, slot: u64) -> Option<String> {
+        self.canonical.get(&slot).map(format_hash_tree_root)
+    }
+
+    /// Return the latest ancestor of `root` at or before `slot`, as
+    /// `get_ancestor` in fork choice.
+    ///
+    /// Returns `root` itself if it is at or before `slot`, and `None` if
+    /// `root` is not indexed or `slot` is before its earliest indexed
+    /// ancestor.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `root` is invalid.
+    fn ancestor_at_slot(&self, root: &Bound<'_, PyAny>, slot: u64) -> PyResult<Option<String>> {
+        Ok(self
+            .ancestor(parse_root(root)?, slot)
+            .as_ref()
+            .map(format_hash_tree_root))
+    }
+
+    /// Return whether `root` is on the chain of the head.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `root` is invalid.
+    fn is_canonical(&self, root: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let root = parse_root(root)?;
+        Ok(self
+            .entries
+            .get(&root)
+            .is_some_and(|entry| self.is_canonical_entry(root, *entry)))
+    }
+
+    /// Return the block roots from `a` to `b`, both included, or `None` if
+    /// `a` is not an ancestor of `b`.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if a root is invalid.
+    fn path_between(
+        &self,
+        a: &Bound<'_, PyAny>,
+        b: &Bound<'_, PyAny>,
+    ) -> PyResult<Option<Vec<String>>> {
+        Ok(self
+            .path(parse_root(a)?, parse_root(b)?)
+            .map(|path| path.iter().map(format_hash_tree_root).collect()))
+    }
+
+    /// Serialize the index to bytes.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.encode())
+    }
+
+    /// Deserialize an index produced by `to_bytes`, from bytes or any other
+    /// buffer of bytes.
+    ///
+    /// # Errors
+    /// Returns `PyTypeError` if `data` is not a buffer of bytes, or
+    /// `PyValueError` if it is not C-contiguous or not a valid index.
+    #[staticmethod]
+    fn from_bytes(py: Python<'_>, data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let buffer = input_buffer(data)?;
+        py.detach(|| Self::decode(buffer_bytes(&buffer)))
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Write the index to a file.
+    ///
+    /// # Errors
+    /// Returns `OSError` if the file cannot be written.
+    fn save(&self, py: Python<'_>, path: PathBuf) -> PyResult<()> {
+        py.detach(|| std::fs::write(path, self.encode()))?;
+        Ok(())
+    }
+
+    /// Read an index written by `save`.
+    ///
+    /// # Errors
+    /// Returns `OSError` if the file cannot be read, or `PyValueError` if it
+    /// is not a valid index.
+    #[staticmethod]
+    fn load(py: Python<'_>, path: PathBuf) -> PyResult<Self> {
+        let bytes = py.detach(|| std::fs::read(path))?;
+        py.detach(|| Self::decode(&bytes))
+            .map_err(PyValueError::new_err)
+    }
+
+    fn __len__(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn __contains__(&self, root: &Bound<'_, PyAny>) -> PyResult<bool> {
+        Ok(self.entries.contains_key(&parse_root(root)?))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ChainIndex(len={}, canonical={})",
+            self.entries.len(),
+            self.canonical.len()
+        )
+    }
+}
+
+/// Registers the chain index class with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyChainIndex>()?;
+    Ok(())
+}
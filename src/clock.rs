@@ -0,0 +1,431 @@
+//! Wall-clock slot timing.
+//!
+//! A `SlotClock` maps the current time to slots of a `Config`. The clock reads
+//! the system time once, when it is created or resynced, and measures time
+//! since then with the monotonic clock. System time adjustments (NTP steps,
+//! manual changes) therefore never move it backwards or make it skip slots.
+//! The monotonic clock may stop while the machine is suspended, so long-running
+//! processes should call `resync()` after a resume or periodically.
+//!
+//! All methods accept an explicit `now` (a UNIX timestamp in seconds) in place
+//! of the clock's own time, which makes schedulers deterministic under test.
+//! Durations are computed in integer nanoseconds and only converted to
+//! floating point at the end, so they do not drift over long uptimes.
+//!
+//! `seconds_into_slot` and `ArrivalTracker` measure how late gossip messages
+//! arrive relative to the start of their slot. The tracker records the first
+//! arrival of each root, so duplicates received from other peers do not skew
+//! the latency distribution of a slot.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use grandine_ssz::H256;
+use pyo3::exceptions::{PyOverflowError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::config::{ChainConfig, PyConfig};
+use crate::convert::parse_root;
+use crate::primitives::{ensure_same_config, resolve_optional_config, PyEpoch, PySlot};
+
+const NANOS_PER_SECOND: f64 = 1e9;
+
+/// Reads the system time as a duration since the UNIX epoch.
+fn system_time() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// Converts a possibly negative number of nanoseconds to seconds.
+#[allow(clippy::cast_precision_loss)]
+fn seconds(nanos: i128) -> f64 {
+    nanos as f64 / NANOS_PER_SECOND
+}
+
+/// Converts a UNIX timestamp in seconds to a duration since the UNIX epoch.
+fn parse_time(time: f64) -> PyResult<Duration> {
+    Duration::try_from_secs_f64(time)
+        .map_err(|e| PyValueError::new_err(format!("Invalid time {time}: {e}")))
+}
+
+/// Returns `slot` as a number, checking that a `Slot` is bound to `config`.
+fn slot_value(config: &ChainConfig, slot: &Bound<'_, PyAny>) -> PyResult<u64> {
+    if let Ok(slot) = slot.cast::<PySlot>() {
+        let slot = slot.get();
+        ensure_same_config(config, &slot.config, "Slot")?;
+        return Ok(slot.value);
+    }
+
+    slot.extract()
+}
+
+/// Nanoseconds from `slot`'s start to `time` (nanoseconds since the UNIX
+/// epoch); negative if `time` is before the slot starts.
+fn nanos_into_slot(config: &ChainConfig, time: Duration, slot: u64) -> PyResult<i128> {
+    let start = (i128::from(config.genesis_time)
+        + i128::from(slot) * i128::from(config.seconds_per_slot))
+        * 1_000_000_000;
+    Ok(i128::try_from(time.as_nanos())? - start)
+}
+
+/// Maps time to the slots of a config, using a monotonic clock.
+#[pyclass(name = "SlotClock", frozen)]
+pub struct PySlotClock {
+    config: Arc<ChainConfig>,
+    /// System time and monotonic instant read together at the last resync.
+    anchor: Mutex<(Duration, Instant)>,
+}
+
+impl PySlotClock {
+    /// Returns the time to use as a duration since the UNIX epoch.
+    fn now(&self, now: Option<f64>) -> PyResult<Duration> {
+        match now {
+            Some(now) => parse_time(now),
+            None => {
+                let (system, instant) = *self.anchor.lock().unwrap_or_else(PoisonError::into_inner);
+                Ok(system + instant.elapsed())
+            }
+        }
+    }
+
+    fn genesis_nanos(&self) -> i128 {
+        i128::from(self.config.genesis_time) * 1_000_000_000
+    }
+
+    fn slot_nanos(&self) -> i128 {
+        i128::from(self.config.seconds_per_slot) * 1_000_000_000
+    }
+
+    /// Nanoseconds since genesis, or `None` before genesis.
+    fn since_genesis(&self, now: Option<f64>) -> PyResult<Option<i128>> {
+        let since = i128::try_from(self.now(now)?.as_nanos())? - self.genesis_nanos();
+        Ok((since >= 0).then_some(since))
+    }
+}
+
+#[pymethods]
+impl PySlotClock {
+    #[new]
+    #[pyo3(signature = (config = None))]
+    fn py_new(config: Option<PyRef<'_, PyConfig>>) -> Self {
+        Self {
+            config: resolve_optional_config(config),
+            anchor: Mutex::new((system_time(), Instant::now())),
+        }
+    }
+
+    #[getter]
+    fn config(&self) -> PyConfig {
+        PyConfig {
+            inner: self.config.clone(),
+        }
+    }
+
+    /// Re-read the system time, e.g. after the machine resumed from suspend.
+    fn resync(&self) {
+        *self.anchor.lock().unwrap_or_else(PoisonError::into_inner) =
+            (system_time(), Instant::now());
+    }
+
+    /// Return the UNIX timestamp the clock currently reads, in seconds.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `now` is negative or not finite.
+    #[pyo3(signature = (now = None))]
+    fn time(&self, now: Option<f64>) -> PyResult<f64> {
+        Ok(self.now(now)?.as_secs_f64())
+    }
+
+    /// Return the current slot, or `None` before genesis.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `now` is invalid, or `PyOverflowError` if the
+    /// slot does not fit in 64 bits.
+    #[pyo3(signature = (now = None))]
+    fn current_slot(&self, now: Option<f64>) -> PyResult<Option<PySlot>> {
+        self.since_genesis(now)?
+            .map(|since| {
+                u64::try_from(since / self.slot_nanos())
+                    .map(|slot| PySlot::new(slot, self.config.clone()))
+                    .map_err(|_| PyOverflowError::new_err("current slot out of range"))
+            })
+            .transpose()
+    }
+
+    /// Return the current epoch, or `None` before genesis.
+    ///
+    /// # Errors
+    /// Same as `current_slot`.
+    #[pyo3(signature = (now = None))]
+    fn current_epoch(&self, now: Option<f64>) -> PyResult<Option<PyEpoch>> {
+        Ok(self.current_slot(now)?.map(|slot| {
+            PyEpoch::new(
+                slot.value / self.config.slots_per_epoch(),
+                self.config.clone(),
+            )
+        }))
+    }
+
+    /// Return the seconds until `slot` starts; negative if it already has.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `now` is invalid or `slot` is bound to a
+    /// different config.
+    #[pyo3(signature = (slot, now = None))]
+    fn time_to_slot_start(&self, slot: &Bound<'_, PyAny>, now: Option<f64>) -> PyResult<f64> {
+        let slot = slot_value(&self.config, slot)?;
+        let start = self.genesis_nanos() + i128::from(slot) * self.slot_nanos();
+        let now = i128::try_from(self.now(now)?.as_nanos())?;
+        Ok(seconds(start - now))
+    }
+
+    /// Return the seconds until the next slot starts.
+    ///
+    /// Before genesis this is the time until genesis.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `now` is invalid.
+    #[pyo3(signature = (now = None))]
+    fn time_to_next_slot(&self, now: Option<f64>) -> PyResult<f64> {
+        let now = i128::try_from(self.now(now)?.as_nanos())?;
+        let since = now - self.genesis_nanos();
+
+        if since < 0 {
+            return Ok(seconds(-since));
+        }
+
+        Ok(seconds(self.slot_nanos() - since % self.slot_nanos()))
+    }
+
+    /// Return the elapsed fraction of the current slot, in `[0, 1)`.
+    ///
+    /// Before genesis this is 0.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `now` is invalid.
+    #[pyo3(signature = (now = None))]
+    fn slot_progress(&self, now: Option<f64>) -> PyResult<f64> {
+        Ok(self.since_genesis(now)?.map_or(0.0, |since| {
+            seconds(since % self.slot_nanos()) / seconds(self.slot_nanos())
+        }))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SlotClock(config={:?})", self.config.name)
+    }
+}
+
+/// Return the seconds from the start of `slot` to `timestamp` (a UNIX
+/// timestamp in seconds); negative if `timestamp` is before the slot starts.
+///
+/// `config` defaults to the config of `slot` if it is a `Slot`, and to mainnet
+/// otherwise.
+///
+/// # Errors
+/// Returns `PyValueError` if `timestamp` is negative or not finite, or if
+/// `slot` is bound to a different config.
+#[pyfunction]
+#[pyo3(signature = (timestamp, slot, config = None))]
+pub fn seconds_into_slot(
+    timestamp: f64,
+    slot: &Bound<'_, PyAny>,
+    config: Option<PyRef<'_, PyConfig>>,
+) -> PyResult<f64> {
+    let config = match (config, slot.cast::<PySlot>()) {
+        (None, Ok(slot)) => slot.get().config.clone(),
+        (config, _) => resolve_optional_config(config),
+    };
+    let slot = slot_value(&config, slot)?;
+    let time = parse_time(timestamp)?;
+    Ok(seconds(nanos_into_slot(&config, time, slot)?))
+}
+
+/// First arrivals recorded by an `ArrivalTracker`.
+#[derive(Default)]
+struct Arrivals {
+    /// Slot and latency of each root.
+    roots: HashMap<H256, (u64, i128)>,
+    /// Latencies of each slot, in arrival order.
+    slots: BTreeMap<u64, Vec<i128>>,
+}
+
+/// Returns the nearest-rank `percent` percentile of the sorted `latencies`.
+fn percentile(sorted: &[i128], percent: usize) -> f64 {
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    seconds(sorted[rank - 1])
+}
+
+/// Records the arrival times of gossip messages and their latency relative
+/// to the start of their slot.
+#[pyclass(name = "ArrivalTracker", frozen)]
+pub struct PyArrivalTracker {
+    config: Arc<ChainConfig>,
+    arrivals: Mutex<Arrivals>,
+}
+
+impl PyArrivalTracker {
+    fn arrivals(&self) -> MutexGuard<'_, Arrivals> {
+        self.arrivals.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[pymethods]
+impl PyArrivalTracker {
+    #[new]
+    #[pyo3(signature = (config = None))]
+    fn py_new(config: Option<PyRef<'_, PyConfig>>) -> Self {
+        Self {
+            config: resolve_optional_config(config),
+            arrivals: Mutex::default(),
+        }
+    }
+
+    #[getter]
+    fn config(&self) -> PyConfig {
+        PyConfig {
+            inner: self.config.clone(),
+        }
+    }
+
+    /// Record that `root` of `slot` arrived at `timestamp`, which defaults to
+    /// the system time.
+    ///
+    /// Returns the latency in seconds, or `None` if `root` already arrived.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `root` is not a 32-byte root, `timestamp` is
+    /// invalid, or `slot` is bound to a different config.
+    #[pyo3(signature = (root, slot, timestamp = None))]
+    fn record(
+        &self,
+        root: &Bound<'_, PyAny>,
+        slot: &Bound<'_, PyAny>,
+        timestamp: Option<f64>,
+    ) -> PyResult<Option<f64>> {
+        let root = parse_root(root)?;
+        let slot = slot_value(&self.config, slot)?;
+        let time = timestamp.map_or_else(|| Ok(system_time()), parse_time)?;
+        let latency = nanos_into_slot(&self.config, time, slot)?;
+
+        let mut arrivals = self.arrivals();
+
+        if arrivals.roots.contains_key(&root) {
+            return Ok(None);
+        }
+
+        arrivals.roots.insert(root, (slot, latency));
+        arrivals.slots.entry(slot).or_default().push(latency);
+        Ok(Some(seconds(latency)))
+    }
+
+    /// Return the latency of `root` in seconds, or `None` if it has not
+    /// arrived.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `root` is not a 32-byte root.
+    fn latency(&self, root: &Bound<'_, PyAny>) -> PyResult<Option<f64>> {
+        let root = parse_root(root)?;
+        Ok(self
+            .arrivals()
+            .roots
+            .get(&root)
+            .map(|(_, latency)| seconds(*latency)))
+    }
+
+    /// Return the latencies recorded for `slot` in seconds, in arrival order.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `slot` is bound to a different config.
+    fn latencies(&self, slot: &Bound<'_, PyAny>) -> PyResult<Vec<f64>> {
+        let slot = slot_value(&self.config, slot)?;
+        Ok(self
+            .arrivals()
+            .slots
+            .get(&slot)
+            .map(|latencies| latencies.iter().copied().map(seconds).collect())
+            .unwrap_or_default())
+    }
+
+    /// Return the latency distribution of `slot` in seconds, or `None` if
+    /// nothing arrived for it.
+    ///
+    /// Percentiles use the nearest-rank method, so they are always recorded
+    /// latencies.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `slot` is bound to a different config.
+    fn distribution(
+        &self,
+        py: Python<'_>,
+        slot: &Bound<'_, PyAny>,
+    ) -> PyResult<Option<Py<PyDict>>> {
+        let slot = slot_value(&self.config, slot)?;
+        let Some(mut sorted) = self.arrivals().slots.get(&slot).cloned() else {
+            return Ok(None);
+        };
+        sorted.sort_unstable();
+
+        let mean = sorted.iter().sum::<i128>() / i128::try_from(sorted.len())?;
+
+        let d = PyDict::new(py);
+        d.set_item("slot", slot)?;
+        d.set_item("count", sorted.len())?;
+        d.set_item("min", seconds(sorted[0]))?;
+        d.set_item("max", seconds(sorted[sorted.len() - 1]))?;
+        d.set_item("mean", seconds(mean))?;
+        d.set_item("p50", percentile(&sorted, 50))?;
+        d.set_item("p90", percentile(&sorted, 90))?;
+        d.set_item("p99", percentile(&sorted, 99))?;
+        Ok(Some(d.into()))
+    }
+
+    /// Forget the arrivals of slots before `slot`; returns how many roots
+    /// were removed.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `slot` is bound to a different config.
+    fn prune(&self, slot: &Bound<'_, PyAny>) -> PyResult<usize> {
+        let slot = slot_value(&self.config, slot)?;
+        let mut arrivals = self.arrivals();
+        arrivals.slots = arrivals.slots.split_off(&slot);
+
+        let before = arrivals.roots.len();
+        arrivals
+            .roots
+            .retain(|_, (arrival_slot, _)| *arrival_slot >= slot);
+        Ok(before - arrivals.roots.len())
+    }
+
+    fn __len__(&self) -> usize {
+        self.arrivals().roots.len()
+    }
+
+    fn __contains__(&self, root: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let root = parse_root(root)?;
+        Ok(self.arrivals().roots.contains_key(&root))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ArrivalTracker(config={:?}, roots={})",
+            self.config.name,
+            self.__len__()
+        )
+    }
+}
+
+/// Registers the slot clock, arrival tracker and slot timing function with
+/// the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PySlotClock>()?;
+    m.add_class::<PyArrivalTracker>()?;
+    m.add_function(wrap_pyfunction!(seconds_into_slot, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,195 @@
+//! Beacon committee size and attestation subnet load statistics.
+//!
+//! Committee sizes only depend on the number of active validators: the
+//! shuffling decides who is in a committee, but `compute_committee` always
+//! splits the active set into `committees_per_slot * SLOTS_PER_EPOCH` slices
+//! of (almost) equal size. The statistics can therefore be computed from the
+//! validator registry without the shuffling seed.
+//!
+//! Every active validator attests once per epoch, on the subnet of its
+//! committee (`compute_subnet_for_attestation`), so the number of expected
+//! attestations on a subnet is the total size of the committees mapped to it.
+
+use grandine_types::phase0::containers::Validator;
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use typenum::Unsigned as _;
+
+use crate::config::{resolve_config, PyConfig};
+use crate::electra::state::{PyBeaconStateGnosis, PyBeaconStateMainnet, PyBeaconStateMinimal};
+use crate::fulu::state::{
+    PyFuluBeaconStateGnosis, PyFuluBeaconStateMainnet, PyFuluBeaconStateMinimal,
+};
+use crate::Gnosis;
+
+/// `ATTESTATION_SUBNET_COUNT`, which is the same on all networks.
+const ATTESTATION_SUBNET_COUNT: u64 = 64;
+
+/// Committee statistics of one epoch.
+struct CommitteeStats {
+    epoch: u64,
+    active_validators: u64,
+    committees_per_slot: u64,
+    /// Committee sizes of each slot, indexed by committee index.
+    committee_sizes: Vec<Vec<u64>>,
+    /// Expected attestations on each subnet over the epoch.
+    subnet_attestations: Vec<u64>,
+}
+
+/// `get_committee_count_per_slot` from the consensus specs.
+fn committee_count_per_slot<P: Preset>(active_validators: u64) -> u64 {
+    (active_validators / P::SlotsPerEpoch::U64 / P::TARGET_COMMITTEE_SIZE.get())
+        .clamp(1, P::MaxCommitteesPerSlot::U64)
+}
+
+/// Computes the committee statistics of `epoch`, which must be within one
+/// epoch of `current_epoch` so that the active set is known.
+fn committee_stats<'a, P: Preset>(
+    validators: impl IntoIterator<Item = &'a Validator>,
+    current_epoch: u64,
+    epoch: u64,
+) -> Result<CommitteeStats, String> {
+    let after_previous = epoch
+        .checked_add(1)
+        .is_some_and(|next| next >= current_epoch);
+    let before_next = current_epoch
+        .checked_add(1)
+        .is_some_and(|next| epoch <= next);
+
+    if !(after_previous && before_next) {
+        return Err(format!(
+            "Epoch {epoch} is not within one epoch of the state's current epoch {current_epoch}"
+        ));
+    }
+
+    let active_validators = validators
+        .into_iter()
+        .filter(|validator| validator.activation_epoch <= epoch && epoch < validator.exit_epoch)
+        .count() as u64;
+
+    let slots_per_epoch = P::SlotsPerEpoch::U64;
+    let committees_per_slot = committee_count_per_slot::<P>(active_validators);
+    let committee_count = committees_per_slot * slots_per_epoch;
+
+    let mut subnet_attestations = vec![0; ATTESTATION_SUBNET_COUNT as usize];
+
+    let committee_sizes = (0..slots_per_epoch)
+        .map(|slot_index| {
+            (0..committees_per_slot)
+                .map(|committee_index| {
+                    // `compute_committee` slices the shuffled active set.
+                    let index = u128::from(slot_index * committees_per_slot + committee_index);
+                    let count = u128::from(committee_count);
+                    let total = u128::from(active_validators);
+                    let start = total * index / count;
+                    let end = total * (index + 1) / count;
+                    // Bounded by `active_validators`, which is a `u64`.
+                    #[allow(clippy::cast_possible_truncation)]
+                    let size = (end - start) as u64;
+
+                    // `compute_subnet_for_attestation`.
+                    #[allow(clippy::cast_possible_truncation)]
+                    let subnet = ((committees_per_slot * slot_index + committee_index)
+                        % ATTESTATION_SUBNET_COUNT) as usize;
+                    subnet_attestations[subnet] += size;
+
+                    size
+                })
+                .collect()
+        })
+        .collect();
+
+    Ok(CommitteeStats {
+        epoch,
+        active_validators,
+        committees_per_slot,
+        committee_sizes,
+        subnet_attestations,
+    })
+}
+
+impl CommitteeStats {
+    fn into_py<P: Preset>(self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let slots = PyList::empty(py);
+
+        for (slot, sizes) in (self.epoch * P::SlotsPerEpoch::U64..).zip(self.committee_sizes) {
+            let entry = PyDict::new(py);
+            entry.set_item("slot", slot)?;
+            entry.set_item("committee_count", sizes.len())?;
+            entry.set_item("committee_sizes", sizes)?;
+            slots.append(entry)?;
+        }
+
+        let d = PyDict::new(py);
+        d.set_item("epoch", self.epoch)?;
+        d.set_item("active_validators", self.active_validators)?;
+        d.set_item("committees_per_slot", self.committees_per_slot)?;
+        d.set_item("slots", slots)?;
+        d.set_item("subnet_attestations", self.subnet_attestations)?;
+        Ok(d.into())
+    }
+}
+
+/// Return committee counts and sizes for every slot of `epoch`, and the
+/// number of attestations expected on each attestation subnet.
+///
+/// `epoch` must be the state's previous, current or next epoch, for which the
+/// active validator set is determined by the state.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if `state` is not a beacon state, or `PyValueError`
+/// if `config` belongs to a different preset or `epoch` is out of range.
+#[pyfunction]
+#[pyo3(signature = (state, epoch, config = None))]
+pub fn committee_size_stats(
+    py: Python<'_>,
+    state: &Bound<'_, PyAny>,
+    epoch: u64,
+    config: Option<PyRef<'_, PyConfig>>,
+) -> PyResult<Py<PyDict>> {
+    macro_rules! try_states {
+        ($($class:ty => $preset:ty),* $(,)?) => {
+            $(
+                if let Ok(state) = state.cast::<$class>() {
+                    resolve_config::<$preset>(config.as_deref())?;
+                    let state = state.borrow();
+                    let inner = &state.inner;
+                    let current_epoch = inner.slot / <$preset as Preset>::SlotsPerEpoch::U64;
+                    return py
+                        .detach(|| {
+                            committee_stats::<$preset>(&inner.validators, current_epoch, epoch)
+                        })
+                        .map_err(PyValueError::new_err)?
+                        .into_py::<$preset>(py);
+                }
+            )*
+        };
+    }
+
+    try_states!(
+        PyBeaconStateMainnet => Mainnet,
+        PyBeaconStateMinimal => Minimal,
+        PyBeaconStateGnosis => Gnosis,
+        PyFuluBeaconStateMainnet => Mainnet,
+        PyFuluBeaconStateMinimal => Minimal,
+        PyFuluBeaconStateGnosis => Gnosis,
+    );
+
+    Err(PyTypeError::new_err(format!(
+        "Expected a beacon state, got {}",
+        state.get_type().name()?
+    )))
+}
+
+/// Registers the committee statistics function with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(committee_size_stats, m)?)?;
+    Ok(())
+}
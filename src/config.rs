@@ -0,0 +1,360 @@
+//! Chain configuration for the Python bindings.
+//!
+//! A `Config` ties a preset to the network-level timing parameters needed to
+//! interpret slots and epochs (genesis time and slot duration), to the Fulu
+//! fork epoch, from which blobs are sampled by column, and to the Electra
+//! blob limit. Built-in
+//! configs are provided for the networks listed in the README; custom devnet
+//! configs can be constructed directly, and `diff_configs` compares them with
+//! the built-in ones.
+
+use std::sync::Arc;
+
+use grandine_types::phase0::consts::FAR_FUTURE_EPOCH;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::presets::{diff_dict, NamedPreset, PresetKind};
+use crate::primitives::{PyEpoch, PySlot};
+
+/// Network configuration values used by the bindings.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ChainConfig {
+    pub name: String,
+    pub preset: PresetKind,
+    pub seconds_per_slot: u64,
+    pub genesis_time: u64,
+    pub fulu_fork_epoch: u64,
+    pub max_blobs_per_block_electra: u64,
+}
+
+impl ChainConfig {
+    /// Ethereum mainnet.
+    #[must_use]
+    pub fn mainnet() -> Self {
+        Self {
+            name: "mainnet".to_owned(),
+            preset: PresetKind::Mainnet,
+            seconds_per_slot: 12,
+            genesis_time: 1_606_824_023,
+            fulu_fork_epoch: 411_392,
+            max_blobs_per_block_electra: 9,
+        }
+    }
+
+    /// Hoodi testnet.
+    #[must_use]
+    pub fn hoodi() -> Self {
+        Self {
+            name: "hoodi".to_owned(),
+            preset: PresetKind::Mainnet,
+            seconds_per_slot: 12,
+            genesis_time: 1_742_213_400,
+            fulu_fork_epoch: 50_688,
+            max_blobs_per_block_electra: 9,
+        }
+    }
+
+    /// Gnosis Chain.
+    #[must_use]
+    pub fn gnosis() -> Self {
+        Self {
+            name: "gnosis".to_owned(),
+            preset: PresetKind::Gnosis,
+            seconds_per_slot: 5,
+            genesis_time: 1_638_993_340,
+            fulu_fork_epoch: FAR_FUTURE_EPOCH,
+            max_blobs_per_block_electra: 2,
+        }
+    }
+
+    /// Chiado testnet.
+    #[must_use]
+    pub fn chiado() -> Self {
+        Self {
+            name: "chiado".to_owned(),
+            preset: PresetKind::Gnosis,
+            seconds_per_slot: 5,
+            genesis_time: 1_665_396_300,
+            fulu_fork_epoch: FAR_FUTURE_EPOCH,
+            max_blobs_per_block_electra: 2,
+        }
+    }
+
+    /// Minimal preset devnet defaults (genesis time is devnet-specific).
+    #[must_use]
+    pub fn minimal() -> Self {
+        Self {
+            name: "minimal".to_owned(),
+            preset: PresetKind::Minimal,
+            seconds_per_slot: 6,
+            genesis_time: 0,
+            fulu_fork_epoch: FAR_FUTURE_EPOCH,
+            max_blobs_per_block_electra: 9,
+        }
+    }
+
+    /// Returns the canonical config for a preset.
+    #[must_use]
+    pub fn for_preset(preset: PresetKind) -> Self {
+        match preset {
+            PresetKind::Mainnet => Self::mainnet(),
+            PresetKind::Minimal => Self::minimal(),
+            PresetKind::Gnosis => Self::gnosis(),
+        }
+    }
+
+    /// Returns the config values as `(NAME, value)` pairs, named as in the
+    /// config files of the consensus specs where they appear there.
+    fn values(&self) -> [(&'static str, ConfigValue<'_>); 6] {
+        let Self {
+            name,
+            preset,
+            seconds_per_slot,
+            genesis_time,
+            fulu_fork_epoch,
+            max_blobs_per_block_electra,
+        } = self;
+
+        [
+            ("CONFIG_NAME", ConfigValue::Text(name)),
+            ("PRESET_BASE", ConfigValue::Text(preset.name())),
+            ("SECONDS_PER_SLOT", ConfigValue::Number(*seconds_per_slot)),
+            ("GENESIS_TIME", ConfigValue::Number(*genesis_time)),
+            ("FULU_FORK_EPOCH", ConfigValue::Number(*fulu_fork_epoch)),
+            (
+                "MAX_BLOBS_PER_BLOCK_ELECTRA",
+                ConfigValue::Number(*max_blobs_per_block_electra),
+            ),
+        ]
+    }
+
+    /// Returns `SLOTS_PER_EPOCH` of the config's preset.
+    #[must_use]
+    pub fn slots_per_epoch(&self) -> u64 {
+        self.preset.slots_per_epoch()
+    }
+
+    /// Returns whether Fulu is active at `slot`.
+    #[must_use]
+    pub fn is_fulu_slot(&self, slot: u64) -> bool {
+        slot / self.slots_per_epoch() >= self.fulu_fork_epoch
+    }
+}
+
+/// A config value, as returned by [`ChainConfig::values`].
+#[derive(Clone, Copy, PartialEq, Eq, IntoPyObject)]
+enum ConfigValue<'a> {
+    Text(&'a str),
+    Number(u64),
+}
+
+/// Python wrapper around [`ChainConfig`].
+#[pyclass(name = "Config", frozen)]
+pub struct PyConfig {
+    pub(crate) inner: Arc<ChainConfig>,
+}
+
+impl From<ChainConfig> for PyConfig {
+    fn from(config: ChainConfig) -> Self {
+        Self {
+            inner: Arc::new(config),
+        }
+    }
+}
+
+/// Resolves an optional user-supplied config against the preset of a class.
+///
+/// Falls back to the preset's canonical config when no config is given.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the config belongs to a different preset.
+pub fn resolve_config<P: NamedPreset>(config: Option<&PyConfig>) -> PyResult<Arc<ChainConfig>> {
+    match config {
+        Some(config) if config.inner.preset != P::KIND => Err(PyValueError::new_err(format!(
+            "Config {:?} uses the {} preset, expected {}",
+            config.inner.name,
+            config.inner.preset.name(),
+            P::KIND.name(),
+        ))),
+        Some(config) => Ok(config.inner.clone()),
+        None => Ok(Arc::new(ChainConfig::for_preset(P::KIND))),
+    }
+}
+
+#[pymethods]
+impl PyConfig {
+    #[new]
+    #[pyo3(signature = (
+        name,
+        preset,
+        seconds_per_slot,
+        genesis_time = 0,
+        fulu_fork_epoch = FAR_FUTURE_EPOCH,
+        max_blobs_per_block_electra = 9,
+    ))]
+    /// Create a custom config. Fulu is not scheduled unless `fulu_fork_epoch`
+    /// is given, and `max_blobs_per_block_electra` defaults to the value of
+    /// the consensus specs.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the preset is unknown or `seconds_per_slot` is 0.
+    fn new(
+        name: String,
+        preset: &str,
+        seconds_per_slot: u64,
+        genesis_time: u64,
+        fulu_fork_epoch: u64,
+        max_blobs_per_block_electra: u64,
+    ) -> PyResult<Self> {
+        let preset = PresetKind::parse(preset).map_err(PyValueError::new_err)?;
+
+        if seconds_per_slot == 0 {
+            return Err(PyValueError::new_err("seconds_per_slot must be positive"));
+        }
+
+        Ok(ChainConfig {
+            name,
+            preset,
+            seconds_per_slot,
+            genesis_time,
+            fulu_fork_epoch,
+            max_blobs_per_block_electra,
+        }
+        .into())
+    }
+
+    #[staticmethod]
+    fn mainnet() -> Self {
+        ChainConfig::mainnet().into()
+    }
+
+    #[staticmethod]
+    fn hoodi() -> Self {
+        ChainConfig::hoodi().into()
+    }
+
+    #[staticmethod]
+    fn gnosis() -> Self {
+        ChainConfig::gnosis().into()
+    }
+
+    #[staticmethod]
+    fn chiado() -> Self {
+        ChainConfig::chiado().into()
+    }
+
+    #[staticmethod]
+    fn minimal() -> Self {
+        ChainConfig::minimal().into()
+    }
+
+    #[getter]
+    fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    #[getter]
+    fn preset(&self) -> &'static str {
+        self.inner.preset.name()
+    }
+
+    #[getter]
+    fn seconds_per_slot(&self) -> u64 {
+        self.inner.seconds_per_slot
+    }
+
+    #[getter]
+    fn genesis_time(&self) -> u64 {
+        self.inner.genesis_time
+    }
+
+    #[getter]
+    fn fulu_fork_epoch(&self) -> u64 {
+        self.inner.fulu_fork_epoch
+    }
+
+    #[getter]
+    fn max_blobs_per_block_electra(&self) -> u64 {
+        self.inner.max_blobs_per_block_electra
+    }
+
+    #[getter]
+    fn slots_per_epoch(&self) -> u64 {
+        self.inner.slots_per_epoch()
+    }
+
+    /// Create a `Slot` bound to this config.
+    fn slot(&self, value: u64) -> PySlot {
+        PySlot::new(value, self.inner.clone())
+    }
+
+    /// Create an `Epoch` bound to this config.
+    fn epoch(&self, value: u64) -> PyEpoch {
+        PyEpoch::new(value, self.inner.clone())
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            concat!(
+                "Config(name={:?}, preset={:?}, seconds_per_slot={}, ",
+                "genesis_time={}, fulu_fork_epoch={}, max_blobs_per_block_electra={})",
+            ),
+            self.inner.name,
+            self.inner.preset.name(),
+            self.inner.seconds_per_slot,
+            self.inner.genesis_time,
+            self.inner.fulu_fork_epoch,
+            self.inner.max_blobs_per_block_electra,
+        )
+    }
+}
+
+/// Return the values that differ between two configs.
+///
+/// Every value a config holds is compared. Returns a dict mapping each
+/// differing value to its `(config_a, config_b)` values, named as in the
+/// consensus specs: `CONFIG_NAME`, `PRESET_BASE`, `SECONDS_PER_SLOT`,
+/// `GENESIS_TIME`, `FULU_FORK_EPOCH` and `MAX_BLOBS_PER_BLOCK_ELECTRA`.
+/// If the presets differ, the differing preset constants are included as
+/// well, as by `diff_presets`.
+#[pyfunction]
+pub fn diff_configs<'py>(
+    py: Python<'py>,
+    config_a: PyRef<'_, PyConfig>,
+    config_b: PyRef<'_, PyConfig>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let (a, b) = (&config_a.inner, &config_b.inner);
+
+    let differences = diff_dict(
+        py,
+        a.values()
+            .into_iter()
+            .zip(b.values())
+            .filter(|((_, a), (_, b))| a != b)
+            .map(|((name, a), (_, b))| (name, a, b)),
+    )?;
+
+    for (name, a, b) in a.preset.diff(b.preset) {
+        differences.set_item(name, (a, b))?;
+    }
+
+    Ok(differences)
+}
+
+/// Registers the config class with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyConfig>()?;
+    m.add_function(wrap_pyfunction!(diff_configs, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,341 @@
+//! Conversions between Grandine values and native Python objects.
+
+use grandine_ssz::H256;
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyInt, PyList, PyMemoryView, PyString, PyTuple};
+
+use crate::list::PyByteView;
+
+/// Formats an H256 hash as a hex string in the configured hex format (by
+/// default lowercase with a `0x` prefix).
+#[must_use]
+pub fn format_hash_tree_root(root: &H256) -> String {
+    crate::hex_format::format_hex(root.as_bytes())
+}
+
+/// Parses a 32-byte root given as `bytes` or a `0x`-prefixed hex string.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the value is not 32 bytes long or not valid hex,
+/// and `PyTypeError` if it is neither `bytes` nor `str`.
+pub fn parse_root(value: &Bound<'_, PyAny>) -> PyResult<H256> {
+    if let Ok(bytes) = value.cast::<PyBytes>() {
+        root_from_slice(bytes.as_bytes()).map_err(PyValueError::new_err)
+    } else if let Ok(string) = value.cast::<PyString>() {
+        parse_root_hex(string.to_str()?).map_err(PyValueError::new_err)
+    } else {
+        Err(PyTypeError::new_err("root must be bytes or a hex string"))
+    }
+}
+
+/// Parses a root from a hex string, with or without a `0x` prefix and in
+/// either case.
+///
+/// # Errors
+///
+/// Returns an error string if `hex` is not valid hex or not 32 bytes.
+pub fn parse_root_hex(hex: &str) -> Result<H256, String> {
+    let bytes =
+        hex::decode(hex.trim_start_matches("0x")).map_err(|e| format!("Invalid root hex: {e}"))?;
+    root_from_slice(&bytes)
+}
+
+fn root_from_slice(bytes: &[u8]) -> Result<H256, String> {
+    if bytes.len() != H256::len_bytes() {
+        return Err(format!("root must be 32 bytes, got {}", bytes.len()));
+    }
+
+    Ok(H256::from_slice(bytes))
+}
+
+/// Extracts an epoch given as an `int` or, as in the Beacon API and
+/// EIP-3076 interchange files, a decimal string.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if a string is not a decimal `u64`, or `PyErr` if
+/// the value is neither a string nor an `int` that fits in a `u64`.
+pub fn extract_epoch(value: &Bound<'_, PyAny>) -> PyResult<u64> {
+    if let Ok(string) = value.cast::<PyString>() {
+        let string = string.to_str()?;
+        return string
+            .parse()
+            .map_err(|_| PyValueError::new_err(format!("Invalid epoch: {string:?}")));
+    }
+
+    value.extract()
+}
+
+/// The bytes of an input object, read in place where that is safe.
+///
+/// Decoders read the bytes with the GIL released, while other threads may
+/// run Python code. The buffers of `bytes` and `ByteView`, and memoryviews of
+/// them, cannot change meanwhile and are read in place. Any other buffer is
+/// copied first: writable ones such as that of a `bytearray`, and read-only
+/// ones too, since a `memoryview(bytearray).toreadonly()` or a read-only
+/// NumPy view still changes with the object it was taken from.
+pub enum InputBytes {
+    /// A buffer of immutable bytes, held until they are no longer needed.
+    Buffer(PyBuffer<u8>),
+    /// A copy of any other buffer.
+    Copy(Vec<u8>),
+}
+
+impl InputBytes {
+    /// Reads the bytes of a C-contiguous `buffer` exported by `object`,
+    /// copying them unless they are immutable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PyErr` if copying the buffer fails.
+    pub fn new(object: &Bound<'_, PyAny>, buffer: PyBuffer<u8>) -> PyResult<Self> {
+        if buffer.readonly() && has_immutable_bytes(object) {
+            Ok(Self::Buffer(buffer))
+        } else {
+            Ok(Self::Copy(buffer.to_vec(object.py())?))
+        }
+    }
+}
+
+/// Returns whether the buffer of `object` holds bytes that never change:
+/// those of `bytes`, of a `ByteView`, or of a memoryview of either.
+fn has_immutable_bytes(object: &Bound<'_, PyAny>) -> bool {
+    if object.is_instance_of::<PyBytes>() || object.is_instance_of::<PyByteView>() {
+        return true;
+    }
+
+    object.cast::<PyMemoryView>().is_ok_and(|view| {
+        view.getattr("obj")
+            .is_ok_and(|exporter| !exporter.is(view) && has_immutable_bytes(&exporter))
+    })
+}
+
+/// Acquires the bytes of an input object such as `bytes`, `bytearray`,
+/// `memoryview` or a NumPy array of bytes (see `InputBytes`).
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if the object does not support the buffer protocol
+/// with byte items, and `PyValueError` if the buffer is not C-contiguous.
+pub fn input_buffer(object: &Bound<'_, PyAny>) -> PyResult<InputBytes> {
+    let Ok(buffer) = PyBuffer::<u8>::get(object) else {
+        return Err(PyTypeError::new_err(format!(
+            "Expected bytes or a buffer of bytes, got {}",
+            object.get_type().name()?
+        )));
+    };
+
+    if !buffer.is_c_contiguous() {
+        return Err(PyValueError::new_err("Input buffer is not contiguous"));
+    }
+
+    InputBytes::new(object, buffer)
+}
+
+/// The bytes of an input object.
+#[must_use]
+pub fn buffer_bytes(input: &InputBytes) -> &[u8] {
+    let buffer = match input {
+        InputBytes::Buffer(buffer) => buffer,
+        InputBytes::Copy(bytes) => return bytes,
+    };
+
+    if buffer.len_bytes() == 0 {
+        return &[];
+    }
+
+    // SAFETY: the buffer is C-contiguous, so its `len_bytes` bytes start at
+    // `buf_ptr`. They stay valid until `buffer` is released, and they do not
+    // change meanwhile because `InputBytes::new` only keeps buffers of
+    // immutable objects (`bytes` and `ByteView`).
+    unsafe { std::slice::from_raw_parts(buffer.buf_ptr().cast::<u8>(), buffer.len_bytes()) }
+}
+
+/// Converts a little-endian unsigned integer (e.g. an SSZ-encoded `uint256`)
+/// into a Python `int`.
+///
+/// Values that fit in 128 bits are converted natively; larger values go
+/// through `int.from_bytes`.
+///
+/// # Errors
+///
+/// Returns `PyErr` if the Python conversion fails.
+pub fn le_uint_to_py<'py>(py: Python<'py>, bytes: &[u8]) -> PyResult<Bound<'py, PyAny>> {
+    let (low, high) = bytes.split_at(bytes.len().min(16));
+
+    if high.iter().all(|byte| *byte == 0) {
+        let mut buffer = [0; 16];
+        buffer[..low.len()].copy_from_slice(low);
+        return Ok(u128::from_le_bytes(buffer).into_pyobject(py)?.into_any());
+    }
+
+    py.get_type::<PyInt>()
+        .call_method1("from_bytes", (bytes, "little"))
+}
+
+/// How JSON values are mapped to Python objects.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DictStyle {
+    /// Beacon API conventions: integers stay decimal strings.
+    Json,
+    /// `eth2spec` (`debug.encode`) conventions: integers of up to 64 bits
+    /// become Python ints, wider integers stay decimal strings.
+    Pyspec,
+}
+
+/// Fields wider than 64 bits, which `eth2spec` encodes as decimal strings.
+pub(crate) const UINT256_FIELDS: &[&str] = &["base_fee_per_gas"];
+
+pub(crate) fn is_decimal(string: &str) -> bool {
+    !string.is_empty() && string.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// Converts a JSON value into Python objects (dicts, lists, strings, ints,
+/// bools and `None`).
+///
+/// `field` is the name of the enclosing container field, if any; list
+/// elements inherit the name of the list field.
+///
+/// # Errors
+///
+/// Returns `PyErr` if a Python object cannot be created.
+pub fn json_to_py<'py>(
+    py: Python<'py>,
+    value: &serde_json::Value,
+    style: DictStyle,
+    field: Option<&str>,
+) -> PyResult<Bound<'py, PyAny>> {
+    use serde_json::Value;
+
+    Ok(match value {
+        Value::Null => py.None().into_bound(py),
+        Value::Bool(boolean) => boolean.into_pyobject(py)?.to_owned().into_any(),
+        Value::Number(number) => {
+            if let Some(integer) = number.as_u64() {
+                integer.into_pyobject(py)?.into_any()
+            } else if let Some(integer) = number.as_i64() {
+                integer.into_pyobject(py)?.into_any()
+            } else {
+                number
+                    .as_f64()
+                    .unwrap_or_default()
+                    .into_pyobject(py)?
+                    .into_any()
+            }
+        }
+        Value::String(string) => {
+            let wide = field.is_some_and(|field| UINT256_FIELDS.contains(&field));
+
+            match string.parse::<u64>() {
+                Ok(integer) if style == DictStyle::Pyspec && !wide && is_decimal(string) => {
+                    integer.into_pyobject(py)?.into_any()
+                }
+                _ => crate::hex_format::apply_to_str(string)
+                    .into_pyobject(py)?
+                    .into_any(),
+            }
+        }
+        Value::Array(items) => PyList::new(
+            py,
+            items
+                .iter()
+                .map(|item| json_to_py(py, item, style, field))
+                .collect::<PyResult<Vec<_>>>()?,
+        )?
+        .into_any(),
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (key, item) in map {
+                dict.set_item(key, json_to_py(py, item, style, Some(key))?)?;
+            }
+            dict.into_any()
+        }
+    })
+}
+
+/// Converts Python objects into a JSON value accepted by the consensus types'
+/// deserializers.
+///
+/// Accepts both conventions of [`DictStyle`]: ints are encoded as decimal
+/// strings and `bytes` as `0x`-prefixed hex, so dicts produced by `eth2spec`
+/// (or by hand) round-trip.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` for unsupported objects or non-string dict keys.
+pub fn py_to_json(value: &Bound<'_, PyAny>) -> PyResult<serde_json::Value> {
+    py_to_json_at(value, "")
+}
+
+/// [`py_to_json`] for the value at `path`, which errors name.
+fn py_to_json_at(value: &Bound<'_, PyAny>, path: &str) -> PyResult<serde_json::Value> {
+    use serde_json::{Map, Value};
+
+    if value.is_none() {
+        return Ok(Value::Null);
+    }
+
+    if let Ok(boolean) = value.cast::<PyBool>() {
+        return Ok(Value::Bool(boolean.is_true()));
+    }
+
+    if let Ok(integer) = value.cast::<PyInt>() {
+        return Ok(Value::String(integer.str()?.to_str()?.to_owned()));
+    }
+
+    if let Ok(string) = value.cast::<PyString>() {
+        return Ok(Value::String(string.to_str()?.to_owned()));
+    }
+
+    if let Ok(bytes) = value.cast::<PyBytes>() {
+        return Ok(Value::String(format!(
+            "0x{}",
+            hex::encode(bytes.as_bytes())
+        )));
+    }
+
+    let at = |path: &str| {
+        if path.is_empty() {
+            String::new()
+        } else {
+            format!(" at {path}")
+        }
+    };
+
+    if let Ok(dict) = value.cast::<PyDict>() {
+        let mut map = Map::new();
+        for (key, item) in dict.iter() {
+            let key = key
+                .cast::<PyString>()
+                .map_err(|_| {
+                    PyTypeError::new_err(format!("dict keys must be strings{}", at(path)))
+                })?
+                .to_str()?;
+            let item_path = if path.is_empty() {
+                key.to_owned()
+            } else {
+                format!("{path}.{key}")
+            };
+            map.insert(key.to_owned(), py_to_json_at(&item, &item_path)?);
+        }
+        return Ok(Value::Object(map));
+    }
+
+    if value.is_instance_of::<PyList>() || value.is_instance_of::<PyTuple>() {
+        return value
+            .try_iter()?
+            .enumerate()
+            .map(|(index, item)| py_to_json_at(&item?, &format!("{path}[{index}]")))
+            .collect::<PyResult<Vec<_>>>()
+            .map(Value::Array);
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "cannot convert {} to a consensus value{}",
+        value.get_type().name()?,
+        at(path)
+    )))
+}
@@ -0,0 +1,331 @@
+//! Fork-agnostic decoding of signed beacon blocks and beacon states.
+//!
+//! Era archives and Beacon API responses without a version header carry no
+//! fork identifier, so the class to decode with has to be inferred. The slot
+//! of a `SignedBeaconBlock` or `BeaconState` is at a fixed place in its SSZ
+//! encoding (a block's message starts at the offset in the first four bytes),
+//! so a fork schedule picks the fork without decoding anything else.
+//!
+//! Without a fork or a schedule, the fork of a block is told by the fixed size
+//! of its body, which the body's first offset holds and which changed with
+//! every fork but Fulu. Electra and Fulu blocks have the same SSZ layout, so
+//! such a block decodes as an Electra block. States are tried oldest first.
+//!
+//! Grandine stores blocks and states as snappy-compressed SSZ.
+//! `read_grandine_block_file` and `read_grandine_state_file` read such a value
+//! saved to a file, for instance exported from a node's database for a
+//! post-mortem, and decode it the same way. They do not open a node's data
+//! directory: the database there is libmdbx, which this crate has no reader
+//! for, so values have to be exported from it to files first.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use grandine_types::preset::{Mainnet, Minimal};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::envelope::{decode_as, Fork, TypeId};
+use crate::presets::PresetKind;
+use crate::ssz_size::block_body_fixed_size;
+use crate::Gnosis;
+
+/// The position of the body offset in a block, after `slot`,
+/// `proposer_index`, `parent_root` and `state_root`.
+const BODY_OFFSET: usize = 8 + 8 + 32 + 32;
+
+/// The position of the first offset in a block body, after `randao_reveal`,
+/// `eth1_data` and `graffiti`.
+const FIRST_BODY_OFFSET: usize = 96 + 72 + 32;
+
+/// Reads the SSZ offset at `position`.
+fn read_offset(bytes: &[u8], position: usize) -> Option<usize> {
+    let offset = bytes.get(position..position.checked_add(4)?)?;
+    usize::try_from(u32::from_le_bytes(offset.try_into().ok()?)).ok()
+}
+
+/// Reads the slot of a `SignedBeaconBlock` from its SSZ encoding.
+pub(crate) fn signed_block_slot(bytes: &[u8]) -> Option<u64> {
+    let start = read_offset(bytes, 0)?;
+    let slot = bytes.get(start..start.checked_add(8)?)?;
+    Some(u64::from_le_bytes(slot.try_into().ok()?))
+}
+
+/// Returns the forks whose layout a `SignedBeaconBlock` SSZ encoding may
+/// have, oldest first, from the fixed size of its body. Encodings too short
+/// to tell may be of any fork.
+fn signed_block_forks(bytes: &[u8], preset: PresetKind) -> Vec<Fork> {
+    let fixed_size = read_offset(bytes, 0).and_then(|message| {
+        let body = message.checked_add(read_offset(bytes, message.checked_add(BODY_OFFSET)?)?)?;
+        read_offset(bytes, body.checked_add(FIRST_BODY_OFFSET)?)
+    });
+
+    let Some(fixed_size) = fixed_size else {
+        return Fork::ALL.to_vec();
+    };
+
+    Fork::ALL
+        .into_iter()
+        .filter(|fork| {
+            let expected = match preset {
+                PresetKind::Mainnet => block_body_fixed_size::<Mainnet>(*fork),
+                PresetKind::Minimal => block_body_fixed_size::<Minimal>(*fork),
+                PresetKind::Gnosis => block_body_fixed_size::<Gnosis>(*fork),
+            };
+            u64::try_from(fixed_size) == Ok(expected)
+        })
+        .collect()
+}
+
+/// Reads the slot of a `BeaconState` from its SSZ encoding, which follows
+/// `genesis_time` and `genesis_validators_root`.
+pub(crate) fn state_slot(bytes: &[u8]) -> Option<u64> {
+    Some(u64::from_le_bytes(bytes.get(40..48)?.try_into().ok()?))
+}
+
+/// Returns the fork active at `epoch`: the one with the latest activation
+/// epoch not after it.
+fn fork_at_epoch(schedule: &HashMap<String, u64>, epoch: u64) -> PyResult<Option<Fork>> {
+    let mut forks = Vec::with_capacity(schedule.len());
+
+    for (name, fork_epoch) in schedule {
+        forks.push((
+            *fork_epoch,
+            Fork::parse(name).map_err(PyValueError::new_err)?,
+        ));
+    }
+
+    Ok(forks
+        .into_iter()
+        .filter(|(fork_epoch, _)| *fork_epoch <= epoch)
+        .max_by_key(|(fork_epoch, fork)| (*fork_epoch, *fork as u8))
+        .map(|(_, fork)| fork))
+}
+
+/// A type that can be decoded without knowing its fork.
+pub(crate) struct Forkless {
+    type_id: TypeId,
+    /// Name used in error messages, in the singular and plural.
+    name: (&'static str, &'static str),
+    /// Reads the slot from the SSZ encoding.
+    slot: fn(&[u8]) -> Option<u64>,
+    /// Returns the forks to try, oldest first, without a fork or a schedule.
+    forks: fn(&[u8], PresetKind) -> Vec<Fork>,
+}
+
+pub(crate) const SIGNED_BEACON_BLOCK: Forkless = Forkless {
+    type_id: TypeId::SignedBeaconBlock,
+    name: ("signed beacon block", "Signed beacon blocks"),
+    slot: signed_block_slot,
+    forks: signed_block_forks,
+};
+
+pub(crate) const BEACON_STATE: Forkless = Forkless {
+    type_id: TypeId::BeaconState,
+    name: ("beacon state", "Beacon states"),
+    slot: state_slot,
+    forks: |_, _| Fork::ALL.to_vec(),
+};
+
+/// Decodes `bytes` as `forkless` of `fork`, of the fork active at its slot
+/// according to `fork_schedule`, or of the oldest fork it decodes as.
+///
+/// Returns the decoded object and the name of its fork.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if a fork name in the schedule is unknown, the fork
+/// has no class for the type, or the bytes cannot be decoded.
+pub(crate) fn decode_forkless(
+    py: Python<'_>,
+    forkless: &Forkless,
+    bytes: &[u8],
+    preset: PresetKind,
+    fork: Option<Fork>,
+    fork_schedule: Option<&HashMap<String, u64>>,
+) -> PyResult<(Py<PyAny>, &'static str)> {
+    let (name, plural) = forkless.name;
+
+    let decode = |fork: Fork| {
+        decode_as(py, fork, Some(preset), forkless.type_id, bytes)
+            .map(|object| object.map(|object| (object, fork.name())))
+    };
+
+    let fork = match (fork, fork_schedule) {
+        (Some(fork), _) => fork,
+        (None, Some(schedule)) => {
+            let slot = (forkless.slot)(bytes)
+                .ok_or_else(|| PyValueError::new_err(format!("Data is too short for a {name}")))?;
+            let epoch = slot / preset.slots_per_epoch();
+
+            fork_at_epoch(schedule, epoch)?.ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "Slot {slot} is before every fork in the fork schedule"
+                ))
+            })?
+        }
+        (None, None) => {
+            let mut last_error = None;
+
+            for fork in (forkless.forks)(bytes, preset) {
+                match decode(fork) {
+                    Some(Ok(decoded)) => return Ok(decoded),
+                    Some(Err(error)) => last_error = Some(error),
+                    None => {}
+                }
+            }
+
+            return Err(last_error.unwrap_or_else(|| {
+                PyValueError::new_err(format!("Data is not a {name} of a supported fork"))
+            }));
+        }
+    };
+
+    decode(fork).unwrap_or_else(|| {
+        Err(PyValueError::new_err(format!(
+            "{plural} of fork {} are not supported",
+            fork.name()
+        )))
+    })
+}
+
+/// Decode a `SignedBeaconBlock` of unknown fork.
+///
+/// The fork is `fork` if given, otherwise the one active at the block's slot
+/// according to `fork_schedule`, a mapping of fork names to activation
+/// epochs (`{"electra": 364032, "fulu": 411392}`). Without either, the fork
+/// is the oldest one with the block's SSZ layout. Electra and Fulu blocks
+/// share their SSZ layout, so telling them apart needs a fork or a schedule.
+///
+/// Returns the decoded block and the name of its fork.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the preset or a fork name is unknown, the fork
+/// has no signed block class, or the bytes cannot be decoded.
+#[pyfunction]
+#[pyo3(signature = (data, preset = "mainnet", fork = None, fork_schedule = None))]
+pub fn decode_signed_beacon_block(
+    py: Python<'_>,
+    data: &Bound<'_, PyBytes>,
+    preset: &str,
+    fork: Option<&str>,
+    fork_schedule: Option<HashMap<String, u64>>,
+) -> PyResult<(Py<PyAny>, &'static str)> {
+    let preset = PresetKind::parse(preset).map_err(PyValueError::new_err)?;
+    let fork = fork
+        .map(|name| Fork::parse(name).map_err(PyValueError::new_err))
+        .transpose()?;
+
+    decode_forkless(
+        py,
+        &SIGNED_BEACON_BLOCK,
+        data.as_bytes(),
+        preset,
+        fork,
+        fork_schedule.as_ref(),
+    )
+}
+
+/// Reads a file of snappy-compressed SSZ, in the raw or framing format, and
+/// decodes it as `forkless`.
+fn read_grandine_file(
+    py: Python<'_>,
+    forkless: &Forkless,
+    path: &Path,
+    preset: &str,
+    fork: Option<&str>,
+    fork_schedule: Option<&HashMap<String, u64>>,
+) -> PyResult<(Py<PyAny>, &'static str)> {
+    let preset = PresetKind::parse(preset).map_err(PyValueError::new_err)?;
+    let fork = fork
+        .map(|name| Fork::parse(name).map_err(PyValueError::new_err))
+        .transpose()?;
+
+    let ssz = py.detach(|| {
+        let compressed =
+            std::fs::read(path).map_err(|e| pyo3::exceptions::PyOSError::new_err(e.to_string()))?;
+        crate::snappy::decompress(&compressed, None).map_err(PyValueError::new_err)
+    })?;
+
+    decode_forkless(py, forkless, &ssz, preset, fork, fork_schedule)
+}
+
+/// Read a `SignedBeaconBlock` from a file of snappy-compressed SSZ, as
+/// Grandine stores blocks.
+///
+/// The snappy format is detected, and the fork is found as in
+/// `decode_signed_beacon_block`. Returns the block and the name of its fork.
+///
+/// `path` is a single exported value, not a node's data directory or
+/// database file.
+///
+/// # Errors
+///
+/// Returns `OSError` if the file cannot be read, or `PyValueError` if the
+/// preset or a fork name is unknown or the file is not a compressed block.
+#[pyfunction]
+#[pyo3(signature = (path, preset = "mainnet", fork = None, fork_schedule = None))]
+pub fn read_grandine_block_file(
+    py: Python<'_>,
+    path: PathBuf,
+    preset: &str,
+    fork: Option<&str>,
+    fork_schedule: Option<HashMap<String, u64>>,
+) -> PyResult<(Py<PyAny>, &'static str)> {
+    read_grandine_file(
+        py,
+        &SIGNED_BEACON_BLOCK,
+        &path,
+        preset,
+        fork,
+        fork_schedule.as_ref(),
+    )
+}
+
+/// Read a `BeaconState` from a file of snappy-compressed SSZ, as Grandine
+/// stores states.
+///
+/// The snappy format is detected, and the fork is `fork` if given, otherwise
+/// the one active at the state's slot according to `fork_schedule`,
+/// otherwise the oldest one the state decodes as. Returns the state and the
+/// name of its fork.
+///
+/// `path` is a single exported value, not a node's data directory or
+/// database file.
+///
+/// # Errors
+///
+/// Returns `OSError` if the file cannot be read, or `PyValueError` if the
+/// preset or a fork name is unknown or the file is not a compressed state.
+#[pyfunction]
+#[pyo3(signature = (path, preset = "mainnet", fork = None, fork_schedule = None))]
+pub fn read_grandine_state_file(
+    py: Python<'_>,
+    path: PathBuf,
+    preset: &str,
+    fork: Option<&str>,
+    fork_schedule: Option<HashMap<String, u64>>,
+) -> PyResult<(Py<PyAny>, &'static str)> {
+    read_grandine_file(
+        py,
+        &BEACON_STATE,
+        &path,
+        preset,
+        fork,
+        fork_schedule.as_ref(),
+    )
+}
+
+/// Registers the fork-agnostic decoders with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(decode_signed_beacon_block, m)?)?;
+    m.add_function(wrap_pyfunction!(read_grandine_block_file, m)?)?;
+    m.add_function(wrap_pyfunction!(read_grandine_state_file, m)?)?;
+    Ok(())
+}
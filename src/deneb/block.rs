@@ -0,0 +1,188 @@
+//! Deneb block types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `SignedBeaconBlock` (Mainnet, Minimal, Gnosis)
+//! - `SignedBlindedBeaconBlock` (Mainnet, Minimal, Gnosis)
+//!
+//! Deneb blocks add the blob KZG commitments to the body and the blob gas
+//! fields to the execution payload, which kept this layout in Electra.
+//!
+//! The classes share the API of the Phase 0 block classes; see
+//! `define_signed_block_pyclass!`.
+
+use grandine_types::deneb::containers::{SignedBeaconBlock, SignedBlindedBeaconBlock};
+use grandine_types::preset::{Mainnet, Minimal};
+use pyo3::prelude::*;
+
+use crate::capella::bls_change::PySignedBlsToExecutionChange;
+use crate::list::PySszList;
+use crate::phase0::attestation::{
+    PyPhase0AttestationGnosis, PyPhase0AttestationMainnet, PyPhase0AttestationMinimal,
+};
+use crate::Gnosis;
+use crate::{define_signed_block_pyclass, ssz_list_view};
+
+define_signed_block_pyclass!(
+    PyDenebSignedBeaconBlockMainnet,
+    "DenebSignedBeaconBlockMainnet",
+    SignedBeaconBlock<Mainnet>,
+    preset = Mainnet,
+    attestation = PyPhase0AttestationMainnet,
+    extra_methods = {
+        pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.bls_to_execution_changes,
+                class = PySignedBlsToExecutionChange
+            )
+        }
+
+        pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.transactions,
+                bytes
+            )
+        }
+
+        pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.withdrawals,
+                json
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyDenebSignedBeaconBlockMinimal,
+    "DenebSignedBeaconBlockMinimal",
+    SignedBeaconBlock<Minimal>,
+    preset = Minimal,
+    attestation = PyPhase0AttestationMinimal,
+    extra_methods = {
+        pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.bls_to_execution_changes,
+                class = PySignedBlsToExecutionChange
+            )
+        }
+
+        pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.transactions,
+                bytes
+            )
+        }
+
+        pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.withdrawals,
+                json
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyDenebSignedBeaconBlockGnosis,
+    "DenebSignedBeaconBlockGnosis",
+    SignedBeaconBlock<Gnosis>,
+    preset = Gnosis,
+    attestation = PyPhase0AttestationGnosis,
+    extra_methods = {
+        pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.bls_to_execution_changes,
+                class = PySignedBlsToExecutionChange
+            )
+        }
+
+        pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.transactions,
+                bytes
+            )
+        }
+
+        pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.execution_payload.withdrawals,
+                json
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyDenebSignedBlindedBeaconBlockMainnet,
+    "DenebSignedBlindedBeaconBlockMainnet",
+    SignedBlindedBeaconBlock<Mainnet>,
+    preset = Mainnet,
+    attestation = PyPhase0AttestationMainnet,
+    extra_methods = {
+        pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.bls_to_execution_changes,
+                class = PySignedBlsToExecutionChange
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyDenebSignedBlindedBeaconBlockMinimal,
+    "DenebSignedBlindedBeaconBlockMinimal",
+    SignedBlindedBeaconBlock<Minimal>,
+    preset = Minimal,
+    attestation = PyPhase0AttestationMinimal,
+    extra_methods = {
+        pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.bls_to_execution_changes,
+                class = PySignedBlsToExecutionChange
+            )
+        }
+    }
+);
+
+define_signed_block_pyclass!(
+    PyDenebSignedBlindedBeaconBlockGnosis,
+    "DenebSignedBlindedBeaconBlockGnosis",
+    SignedBlindedBeaconBlock<Gnosis>,
+    preset = Gnosis,
+    attestation = PyPhase0AttestationGnosis,
+    extra_methods = {
+        pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+            ssz_list_view!(
+                slf,
+                |inner| inner.message.body.bls_to_execution_changes,
+                class = PySignedBlsToExecutionChange
+            )
+        }
+    }
+);
+
+/// Registers the Deneb block types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyDenebSignedBeaconBlockMainnet>()?;
+    m.add_class::<PyDenebSignedBeaconBlockMinimal>()?;
+    m.add_class::<PyDenebSignedBeaconBlockGnosis>()?;
+    m.add_class::<PyDenebSignedBlindedBeaconBlockMainnet>()?;
+    m.add_class::<PyDenebSignedBlindedBeaconBlockMinimal>()?;
+    m.add_class::<PyDenebSignedBlindedBeaconBlockGnosis>()?;
+    Ok(())
+}
@@ -0,0 +1,6 @@
+//! Deneb hardfork types support.
+//!
+//! This module contains the Deneb blocks, the first to carry blob KZG
+//! commitments, for decoding historical blocks.
+
+pub mod block;
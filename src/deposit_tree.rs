@@ -0,0 +1,490 @@
+//! EIP-4881 deposit tree snapshots.
+//!
+//! The deposit contract keeps an incremental Merkle tree of depth 32 over the
+//! `DepositData` roots of all deposits. Once deposits are finalized, only the
+//! roots of the full subtrees covering them are needed to keep extending the
+//! tree: one root per set bit of the deposit count, from the largest subtree
+//! to the smallest. A `DepositTreeSnapshot` holds these roots together with
+//! the finalized execution block, as served by the beacon API's
+//! `GET /eth/v1/beacon/deposit_snapshot`.
+//!
+//! `DepositTree` starts from a snapshot (or empty), is extended with the
+//! deposits that follow it, and produces deposit proofs for every deposit
+//! that is not finalized. Roots are computed as by the deposit contract's
+//! `get_deposit_root`, which mixes in the deposit count.
+
+use grandine_ssz::{SszHash as _, H256};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde::Deserialize;
+
+use crate::convert::{format_hash_tree_root, parse_root};
+use crate::merkle::{hash, zero_hashes};
+use crate::phase0::deposit::PyDepositData;
+use crate::{decode_json, JsonDataEnvelope};
+
+/// `DEPOSIT_CONTRACT_TREE_DEPTH`.
+const DEPTH: usize = 32;
+
+/// Heights of the full subtrees covering the first `count` deposits, from
+/// the largest subtree to the smallest.
+fn subtree_heights(count: u64) -> impl Iterator<Item = usize> {
+    (0..DEPTH)
+        .rev()
+        .filter(move |height| (count >> height) & 1 == 1)
+}
+
+/// The deposit contract's `branch` for `count` deposits, given the roots of
+/// the full subtrees covering them.
+fn branch_of(subtrees: &[H256], count: u64) -> [H256; DEPTH] {
+    let mut branch = [H256::zero(); DEPTH];
+    for (height, root) in subtree_heights(count).zip(subtrees) {
+        branch[height] = *root;
+    }
+    branch
+}
+
+/// The deposit contract's `get_deposit_root`.
+fn deposit_root(branch: &[H256; DEPTH], count: u64, zeros: &[H256]) -> H256 {
+    let mut node = H256::zero();
+    for height in 0..DEPTH {
+        node = if (count >> height) & 1 == 1 {
+            hash(&branch[height], &node)
+        } else {
+            hash(&node, &zeros[height])
+        };
+    }
+    hash(&node, &count_leaf(count))
+}
+
+/// The deposit count as mixed into the root.
+fn count_leaf(count: u64) -> H256 {
+    let mut leaf = H256::zero();
+    leaf[..8].copy_from_slice(&count.to_le_bytes());
+    leaf
+}
+
+/// `DepositTreeSnapshot` in the beacon API JSON format.
+#[derive(Deserialize)]
+struct JsonSnapshot {
+    finalized: Vec<String>,
+    deposit_root: String,
+    deposit_count: String,
+    execution_block_hash: String,
+    execution_block_height: String,
+}
+
+fn parse_hash(value: &str, name: &str) -> Result<H256, String> {
+    let bytes = hex::decode(value.trim_start_matches("0x"))
+        .map_err(|e| format!("Invalid {name} hex: {e}"))?;
+
+    if bytes.len() != H256::len_bytes() {
+        return Err(format!("{name} must be 32 bytes, got {}", bytes.len()));
+    }
+
+    Ok(H256::from_slice(&bytes))
+}
+
+fn parse_u64(value: &str, name: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|e| format!("Invalid {name} {value:?}: {e}"))
+}
+
+/// The finalized part of a deposit tree.
+#[pyclass(name = "DepositTreeSnapshot", frozen)]
+#[derive(Clone)]
+pub struct PyDepositTreeSnapshot {
+    finalized: Vec<H256>,
+    deposit_root: H256,
+    deposit_count: u64,
+    execution_block_hash: H256,
+    execution_block_height: u64,
+}
+
+impl PyDepositTreeSnapshot {
+    fn decode(json: &[u8]) -> Result<Self, String> {
+        let snapshot: JsonDataEnvelope<JsonSnapshot> = decode_json(json)?;
+        let snapshot = snapshot.data;
+
+        let deposit_count = parse_u64(&snapshot.deposit_count, "deposit_count")?;
+        let finalized = snapshot
+            .finalized
+            .iter()
+            .map(|root| parse_hash(root, "finalized root"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let expected = subtree_heights(deposit_count).count();
+        if finalized.len() != expected {
+            return Err(format!(
+                "A snapshot of {deposit_count} deposits needs {expected} finalized roots, got {}",
+                finalized.len()
+            ));
+        }
+
+        let deposit_root = parse_hash(&snapshot.deposit_root, "deposit_root")?;
+        let computed = deposit_root_of(&finalized, deposit_count);
+
+        if deposit_root != computed {
+            return Err(format!(
+                "Snapshot deposit root {} does not match the root {} of its finalized roots",
+                format_hash_tree_root(&deposit_root),
+                format_hash_tree_root(&computed)
+            ));
+        }
+
+        Ok(Self {
+            finalized,
+            deposit_root,
+            deposit_count,
+            execution_block_hash: parse_hash(
+                &snapshot.execution_block_hash,
+                "execution_block_hash",
+            )?,
+            execution_block_height: parse_u64(
+                &snapshot.execution_block_height,
+                "execution_block_height",
+            )?,
+        })
+    }
+}
+
+fn deposit_root_of(subtrees: &[H256], count: u64) -> H256 {
+    deposit_root(&branch_of(subtrees, count), count, &zero_hashes(DEPTH))
+}
+
+#[pymethods]
+impl PyDepositTreeSnapshot {
+    /// Parse a `GET /eth/v1/beacon/deposit_snapshot` response.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the JSON is invalid, or the finalized roots
+    /// do not match the deposit count or the deposit root.
+    #[staticmethod]
+    fn from_beacon_api_json(py: Python<'_>, json: &[u8]) -> PyResult<Self> {
+        py.detach(|| Self::decode(json))
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Serialize to JSON bytes in the beacon API format, without the `data`
+    /// envelope.
+    fn to_json<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let json = serde_json::json!({
+            "finalized": self.finalized(),
+            "deposit_root": self.deposit_root(),
+            "deposit_count": self.deposit_count.to_string(),
+            "execution_block_hash": self.execution_block_hash(),
+            "execution_block_height": self.execution_block_height.to_string(),
+        });
+        PyBytes::new(py, json.to_string().as_bytes())
+    }
+
+    #[getter]
+    fn finalized(&self) -> Vec<String> {
+        self.finalized.iter().map(format_hash_tree_root).collect()
+    }
+
+    #[getter]
+    fn deposit_root(&self) -> String {
+        format_hash_tree_root(&self.deposit_root)
+    }
+
+    #[getter]
+    fn deposit_count(&self) -> u64 {
+        self.deposit_count
+    }
+
+    #[getter]
+    fn execution_block_hash(&self) -> String {
+        format_hash_tree_root(&self.execution_block_hash)
+    }
+
+    #[getter]
+    fn execution_block_height(&self) -> u64 {
+        self.execution_block_height
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "DepositTreeSnapshot(deposit_count={}, execution_block_height={})",
+            self.deposit_count, self.execution_block_height
+        )
+    }
+}
+
+/// The deposit contract's Merkle tree, pruned below its finalized deposits.
+#[pyclass(name = "DepositTree")]
+pub struct PyDepositTree {
+    /// Roots of empty subtrees, indexed by height.
+    zeros: Vec<H256>,
+    /// Roots of the full subtrees covering the finalized deposits.
+    finalized: Vec<H256>,
+    finalized_count: u64,
+    /// Execution block of the last finalization.
+    finalized_block: Option<(H256, u64)>,
+    /// Roots of the deposits after the finalized ones.
+    leaves: Vec<H256>,
+    /// The deposit contract's `branch` of all deposits.
+    branch: [H256; DEPTH],
+    count: u64,
+}
+
+impl PyDepositTree {
+    fn new(finalized: Vec<H256>, count: u64, finalized_block: Option<(H256, u64)>) -> Self {
+        Self {
+            zeros: zero_hashes(DEPTH),
+            branch: branch_of(&finalized, count),
+            finalized,
+            finalized_count: count,
+            finalized_block,
+            leaves: Vec::new(),
+            count,
+        }
+    }
+
+    /// Appends a deposit as the deposit contract's `deposit` does.
+    fn push_leaf(&mut self, leaf: H256) -> Result<u64, String> {
+        let index = self.count;
+
+        if index >= (1 << DEPTH) - 1 {
+            return Err("Deposit tree is full".to_owned());
+        }
+
+        self.count += 1;
+        self.leaves.push(leaf);
+
+        let mut node = leaf;
+        let mut size = self.count;
+        for branch in &mut self.branch {
+            if size & 1 == 1 {
+                *branch = node;
+                break;
+            }
+            node = hash(branch, &node);
+            size >>= 1;
+        }
+
+        Ok(index)
+    }
+
+    /// Root of the subtree at `height` and `position`, where position 0 is
+    /// the leftmost subtree of that height.
+    fn node(&self, height: usize, position: u64) -> Result<H256, String> {
+        let start = position << height;
+        let end = start + (1 << height);
+
+        if start >= self.count {
+            return Ok(self.zeros[height]);
+        }
+
+        if start < self.finalized_count {
+            let mut subtree_start = 0;
+            for (subtree_height, root) in subtree_heights(self.finalized_count).zip(&self.finalized)
+            {
+                let subtree_end = subtree_start + (1 << subtree_height);
+                if start == subtree_start && height == subtree_height {
+                    return Ok(*root);
+                }
+                if subtree_start <= start && end <= subtree_end {
+                    return Err(format!("Deposit {start} is finalized"));
+                }
+                subtree_start = subtree_end;
+            }
+        }
+
+        if height == 0 {
+            // Leaves before `finalized_count` are handled above.
+            #[allow(clippy::cast_possible_truncation)]
+            let leaf = (start - self.finalized_count) as usize;
+            return Ok(self.leaves[leaf]);
+        }
+
+        Ok(hash(
+            &self.node(height - 1, position * 2)?,
+            &self.node(height - 1, position * 2 + 1)?,
+        ))
+    }
+
+    fn compute_proof(&self, index: u64) -> Result<Vec<H256>, String> {
+        if index >= self.count {
+            return Err(format!(
+                "Deposit {index} is not in the tree of {} deposits",
+                self.count
+            ));
+        }
+
+        if index < self.finalized_count {
+            return Err(format!(
+                "Deposit {index} is finalized, proofs are only available from deposit {}",
+                self.finalized_count
+            ));
+        }
+
+        let mut proof = (0..DEPTH)
+            .map(|height| self.node(height, (index >> height) ^ 1))
+            .collect::<Result<Vec<_>, _>>()?;
+        proof.push(count_leaf(self.count));
+        Ok(proof)
+    }
+
+    fn finalize_deposits(&mut self, count: u64, block: (H256, u64)) -> Result<(), String> {
+        if count < self.finalized_count || count > self.count {
+            return Err(format!(
+                "Cannot finalize {count} deposits: {} are finalized and the tree has {}",
+                self.finalized_count, self.count
+            ));
+        }
+
+        let mut start = 0;
+        let finalized = subtree_heights(count)
+            .map(|height| {
+                let root = self.node(height, start >> height);
+                start += 1 << height;
+                root
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Bounded by the number of leaves, which is a `usize`.
+        #[allow(clippy::cast_possible_truncation)]
+        let pruned = (count - self.finalized_count) as usize;
+        self.leaves.drain(..pruned);
+        self.finalized = finalized;
+        self.finalized_count = count;
+        self.finalized_block = Some(block);
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl PyDepositTree {
+    #[new]
+    fn py_new() -> Self {
+        Self::new(Vec::new(), 0, None)
+    }
+
+    /// Start a tree from a snapshot of its finalized deposits.
+    #[staticmethod]
+    fn from_snapshot(snapshot: &PyDepositTreeSnapshot) -> Self {
+        Self::new(
+            snapshot.finalized.clone(),
+            snapshot.deposit_count,
+            Some((
+                snapshot.execution_block_hash,
+                snapshot.execution_block_height,
+            )),
+        )
+    }
+
+    /// Append a deposit, returning its index.
+    ///
+    /// Passing the `index` of the deposit log checks that no deposit was
+    /// skipped.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `index` is not the next deposit index or the
+    /// tree is full.
+    #[pyo3(signature = (deposit_data, index = None))]
+    fn push(&mut self, deposit_data: &PyDepositData, index: Option<u64>) -> PyResult<u64> {
+        if let Some(index) = index.filter(|index| *index != self.count) {
+            return Err(PyValueError::new_err(format!(
+                "Expected deposit {}, got deposit {index}",
+                self.count
+            )));
+        }
+
+        self.push_leaf(deposit_data.inner.hash_tree_root())
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Append a deposit by its `DepositData` root, returning its index.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `root` is invalid or the tree is full.
+    fn push_root(&mut self, root: &Bound<'_, PyAny>) -> PyResult<u64> {
+        self.push_leaf(parse_root(root)?)
+            .map_err(PyValueError::new_err)
+    }
+
+    /// The deposit root, as returned by the deposit contract.
+    fn deposit_root(&self) -> String {
+        format_hash_tree_root(&deposit_root(&self.branch, self.count, &self.zeros))
+    }
+
+    fn deposit_count(&self) -> u64 {
+        self.count
+    }
+
+    /// Number of finalized deposits.
+    fn finalized_count(&self) -> u64 {
+        self.finalized_count
+    }
+
+    /// Return the proof of deposit `index` against `deposit_root()`: 32
+    /// sibling roots followed by the mixed-in deposit count, as in
+    /// `Deposit.proof`.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the deposit is not in the tree or is
+    /// finalized.
+    fn proof(&self, py: Python<'_>, index: u64) -> PyResult<Vec<String>> {
+        let proof = py
+            .detach(|| self.compute_proof(index))
+            .map_err(PyValueError::new_err)?;
+        Ok(proof.iter().map(format_hash_tree_root).collect())
+    }
+
+    /// Finalize the first `deposit_count` deposits, as included in the
+    /// execution block with the given hash and height, and prune them.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `execution_block_hash` is invalid, or
+    /// `deposit_count` is below the finalized count or above the deposit
+    /// count.
+    fn finalize(
+        &mut self,
+        deposit_count: u64,
+        execution_block_hash: &Bound<'_, PyAny>,
+        execution_block_height: u64,
+    ) -> PyResult<()> {
+        let block = (parse_root(execution_block_hash)?, execution_block_height);
+        self.finalize_deposits(deposit_count, block)
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Return the snapshot of the finalized deposits.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the tree was never finalized.
+    fn snapshot(&self) -> PyResult<PyDepositTreeSnapshot> {
+        let (execution_block_hash, execution_block_height) = self
+            .finalized_block
+            .ok_or_else(|| PyValueError::new_err("Deposit tree has not been finalized"))?;
+
+        Ok(PyDepositTreeSnapshot {
+            finalized: self.finalized.clone(),
+            deposit_root: deposit_root_of(&self.finalized, self.finalized_count),
+            deposit_count: self.finalized_count,
+            execution_block_hash,
+            execution_block_height,
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "DepositTree(deposit_count={}, finalized_count={})",
+            self.count, self.finalized_count
+        )
+    }
+}
+
+/// Registers the deposit tree types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyDepositTreeSnapshot>()?;
+    m.add_class::<PyDepositTree>()?;
+    Ok(())
+}
@@ -0,0 +1,203 @@
+//! Signature domains.
+//!
+//! Every signature in the protocol is over the signing root of an object and
+//! a domain, which mixes a 4-byte domain type with the fork version and the
+//! genesis validators root. Signatures are thus bound to the purpose they
+//! were made for, the fork and the chain: `compute_domain` derives a domain
+//! from these, and `compute_signing_root` pairs it with any SSZ object.
+//!
+//! Domain types are given as 4 bytes, or by their spec name such as
+//! `"DOMAIN_BEACON_PROPOSER"`.
+
+use grandine_ssz::H256;
+use grandine_types::phase0::containers::ForkData;
+use grandine_types::phase0::primitives::Version;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyString};
+
+use crate::convert::{format_hash_tree_root, parse_root};
+use crate::signing::signing_root;
+
+pub const DOMAIN_BEACON_PROPOSER: [u8; 4] = [0, 0, 0, 0];
+pub const DOMAIN_RANDAO: [u8; 4] = [2, 0, 0, 0];
+pub const DOMAIN_APPLICATION_BUILDER: [u8; 4] = [0, 0, 0, 1];
+
+/// The domain types of the spec by name.
+const DOMAIN_TYPES: &[(&str, [u8; 4])] = &[
+    ("DOMAIN_BEACON_PROPOSER", DOMAIN_BEACON_PROPOSER),
+    ("DOMAIN_BEACON_ATTESTER", [1, 0, 0, 0]),
+    ("DOMAIN_RANDAO", DOMAIN_RANDAO),
+    ("DOMAIN_DEPOSIT", [3, 0, 0, 0]),
+    ("DOMAIN_VOLUNTARY_EXIT", [4, 0, 0, 0]),
+    ("DOMAIN_SELECTION_PROOF", [5, 0, 0, 0]),
+    ("DOMAIN_AGGREGATE_AND_PROOF", [6, 0, 0, 0]),
+    ("DOMAIN_SYNC_COMMITTEE", [7, 0, 0, 0]),
+    ("DOMAIN_SYNC_COMMITTEE_SELECTION_PROOF", [8, 0, 0, 0]),
+    ("DOMAIN_CONTRIBUTION_AND_PROOF", [9, 0, 0, 0]),
+    ("DOMAIN_BLS_TO_EXECUTION_CHANGE", [10, 0, 0, 0]),
+    ("DOMAIN_APPLICATION_BUILDER", DOMAIN_APPLICATION_BUILDER),
+];
+
+/// Computes `compute_domain` for a domain type, fork version and genesis
+/// validators root.
+#[must_use]
+pub fn compute_domain(
+    domain_type: [u8; 4],
+    fork_version: Version,
+    genesis_validators_root: H256,
+) -> H256 {
+    let fork_data_root = grandine_ssz::SszHash::hash_tree_root(&ForkData {
+        current_version: fork_version,
+        genesis_validators_root,
+    });
+
+    let mut domain = H256::zero();
+    domain[..4].copy_from_slice(&domain_type);
+    domain[4..].copy_from_slice(&fork_data_root[..28]);
+    domain
+}
+
+/// Parses a 4-byte value given as `bytes` or a `0x`-prefixed hex string.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the value is not 4 bytes long or not valid hex,
+/// and `PyTypeError` if it is neither `bytes` nor `str`.
+pub fn parse_bytes4(value: &Bound<'_, PyAny>, name: &str) -> PyResult<[u8; 4]> {
+    let bytes = if let Ok(bytes) = value.cast::<PyBytes>() {
+        bytes.as_bytes().to_vec()
+    } else if let Ok(string) = value.cast::<PyString>() {
+        hex::decode(string.to_str()?.trim_start_matches("0x"))
+            .map_err(|e| PyValueError::new_err(format!("Invalid {name} hex: {e}")))?
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "{name} must be bytes or a hex string"
+        )));
+    };
+
+    bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| PyValueError::new_err(format!("{name} must be 4 bytes, got {}", bytes.len())))
+}
+
+/// Parses a domain type given as 4 bytes or by its spec name.
+fn parse_domain_type(value: &Bound<'_, PyAny>) -> PyResult<[u8; 4]> {
+    if let Ok(string) = value.cast::<PyString>() {
+        let name = string.to_str()?;
+        if name.starts_with("DOMAIN_") {
+            return DOMAIN_TYPES
+                .iter()
+                .find(|(known, _)| *known == name)
+                .map(|(_, domain_type)| *domain_type)
+                .ok_or_else(|| PyValueError::new_err(format!("Unknown domain type: {name}")));
+        }
+    }
+
+    parse_bytes4(value, "domain_type")
+}
+
+/// Parses a fork version given as `bytes` or a `0x`-prefixed hex string.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the value is not 4 bytes long or not valid hex,
+/// and `PyTypeError` if it is neither `bytes` nor `str`.
+pub fn parse_fork_version(value: &Bound<'_, PyAny>) -> PyResult<Version> {
+    parse_bytes4(value, "fork_version").map(Version::from)
+}
+
+/// Resolves the `domain` argument of the block `signing_root` methods: the
+/// `DOMAIN_BEACON_PROPOSER` domain itself, or the genesis validators root to
+/// compute it from if `fork_version` is given.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `domain` is not 32 bytes or `fork_version` is
+/// not 4 bytes.
+pub fn proposer_domain(
+    domain: &Bound<'_, PyAny>,
+    fork_version: Option<&Bound<'_, PyAny>>,
+) -> PyResult<H256> {
+    let root = parse_root(domain)?;
+
+    match fork_version {
+        Some(fork_version) => Ok(compute_domain(
+            DOMAIN_BEACON_PROPOSER,
+            parse_fork_version(fork_version)?,
+            root,
+        )),
+        None => Ok(root),
+    }
+}
+
+/// Return the domain of `domain_type` for `fork_version` and
+/// `genesis_validators_root`.
+///
+/// `domain_type` is 4 bytes or a spec name such as `"DOMAIN_RANDAO"`. The
+/// genesis validators root defaults to zero, as used for `DOMAIN_DEPOSIT`
+/// together with the genesis fork version.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `domain_type` or `fork_version` is not 4 bytes,
+/// the domain type name is unknown, or `genesis_validators_root` is not 32
+/// bytes.
+#[pyfunction(name = "compute_domain")]
+#[pyo3(signature = (domain_type, fork_version, genesis_validators_root = None))]
+pub fn py_compute_domain(
+    domain_type: &Bound<'_, PyAny>,
+    fork_version: &Bound<'_, PyAny>,
+    genesis_validators_root: Option<&Bound<'_, PyAny>>,
+) -> PyResult<String> {
+    let domain = compute_domain(
+        parse_domain_type(domain_type)?,
+        parse_fork_version(fork_version)?,
+        genesis_validators_root
+            .map(parse_root)
+            .transpose()?
+            .unwrap_or_default(),
+    );
+    Ok(format_hash_tree_root(&domain))
+}
+
+/// Return the signing root of `obj` for `domain`.
+///
+/// `obj` can be an object of any generated class. Signed containers are
+/// hashed as a whole, so the signing root of a block is that of its
+/// `message`.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if `obj` is not of a generated class, or
+/// `PyValueError` if `domain` is not 32 bytes.
+#[pyfunction]
+pub fn compute_signing_root(obj: &Bound<'_, PyAny>, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+    let domain = parse_root(domain)?;
+    let class = obj.get_type();
+
+    // Every generated class has `hash_tree_root_batch`, while only some have
+    // `hash_tree_root`.
+    if !class.hasattr("hash_tree_root_batch")? {
+        return Err(PyTypeError::new_err(format!(
+            "Expected an object of a generated class, got {}",
+            class.name()?
+        )));
+    }
+
+    let roots = class.call_method1("hash_tree_root_batch", (vec![obj.clone()],))?;
+    let object_root = parse_root(&roots.get_item(0)?)?;
+    Ok(format_hash_tree_root(&signing_root(object_root, domain)))
+}
+
+/// Registers the domain functions with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(py_compute_domain, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_signing_root, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,318 @@
+//! Execution layer commitment hashing.
+//!
+//! The beacon chain carries execution payloads in SSZ form, but several fields
+//! of the execution block header are commitments computed with execution layer
+//! rules instead: Merkle-Patricia trie roots over RLP-encoded items (keccak256)
+//! and the EIP-7685 requests hash (sha256). This module implements those rules
+//! so they can be cross-checked against EL block headers.
+
+use grandine_ssz::{SszWrite, H256};
+use grandine_types::capella::containers::Withdrawal;
+use grandine_types::deneb::containers::ExecutionPayload;
+use grandine_types::electra::containers::ExecutionRequests;
+use grandine_types::preset::Preset;
+use sha2::{Digest as _, Sha256};
+use tiny_keccak::{Hasher as _, Keccak};
+
+use crate::encode_ssz;
+
+/// keccak256 of the RLP encoding of an empty list, the `ommersHash` of every
+/// post-merge block.
+const EMPTY_OMMERS_HASH: [u8; 32] = [
+    0x1d, 0xcc, 0x4d, 0xe8, 0xde, 0xc7, 0x5d, 0x7a, 0xab, 0x85, 0xb5, 0x67, 0xb6, 0xcc, 0xd4, 0x1a,
+    0xd3, 0x12, 0x45, 0x1b, 0x94, 0x8a, 0x74, 0x13, 0xf0, 0xa1, 0x42, 0xfd, 0x40, 0xd4, 0x93, 0x47,
+];
+
+/// Hashes bytes with keccak256.
+#[must_use]
+pub fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut output = [0; 32];
+    hasher.update(bytes);
+    hasher.finalize(&mut output);
+    output
+}
+
+// =============================================================================
+// RLP encoding
+// =============================================================================
+
+fn rlp_length_prefix(length: usize, offset: u8) -> Vec<u8> {
+    if length < 56 {
+        // `length < 56` so the cast cannot truncate.
+        #[allow(clippy::cast_possible_truncation)]
+        return vec![offset + length as u8];
+    }
+
+    let length_bytes = trim_leading_zeros(&length.to_be_bytes()).to_vec();
+    // At most 8 length bytes, so the cast cannot truncate.
+    #[allow(clippy::cast_possible_truncation)]
+    let mut prefix = vec![offset + 55 + length_bytes.len() as u8];
+    prefix.extend(length_bytes);
+    prefix
+}
+
+fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+/// RLP-encodes a byte string.
+#[must_use]
+pub fn rlp_bytes(bytes: &[u8]) -> Vec<u8> {
+    if let [byte] = bytes {
+        if *byte < 0x80 {
+            return vec![*byte];
+        }
+    }
+
+    let mut encoded = rlp_length_prefix(bytes.len(), 0x80);
+    encoded.extend_from_slice(bytes);
+    encoded
+}
+
+/// RLP-encodes an unsigned integer given in big-endian byte order.
+#[must_use]
+pub fn rlp_uint(be_bytes: &[u8]) -> Vec<u8> {
+    rlp_bytes(trim_leading_zeros(be_bytes))
+}
+
+/// RLP-encodes a `u64`.
+#[must_use]
+pub fn rlp_u64(value: u64) -> Vec<u8> {
+    rlp_uint(&value.to_be_bytes())
+}
+
+/// RLP-encodes a list of already encoded items.
+#[must_use]
+pub fn rlp_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload_length = items.iter().map(Vec::len).sum();
+    let mut encoded = rlp_length_prefix(payload_length, 0xc0);
+    for item in items {
+        encoded.extend_from_slice(item);
+    }
+    encoded
+}
+
+// =============================================================================
+// Merkle-Patricia trie
+// =============================================================================
+
+const EMPTY_STRING: u8 = 0x80;
+
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes
+        .iter()
+        .flat_map(|byte| [byte >> 4, byte & 0x0f])
+        .collect()
+}
+
+/// Hex-prefix encoding of a nibble path (Yellow Paper, appendix C).
+fn hex_prefix(nibbles: &[u8], leaf: bool) -> Vec<u8> {
+    let flag = if leaf { 2 } else { 0 };
+    let mut encoded = Vec::with_capacity(nibbles.len() / 2 + 1);
+
+    let rest = if nibbles.len() % 2 == 1 {
+        encoded.push(((flag + 1) << 4) | nibbles[0]);
+        &nibbles[1..]
+    } else {
+        encoded.push(flag << 4);
+        nibbles
+    };
+
+    encoded.extend(rest.chunks_exact(2).map(|pair| (pair[0] << 4) | pair[1]));
+    encoded
+}
+
+/// Returns how a child node is referenced from its parent: inline if its
+/// encoding is shorter than 32 bytes, by hash otherwise.
+fn node_reference(encoded: Vec<u8>) -> Vec<u8> {
+    if encoded.len() < 32 {
+        encoded
+    } else {
+        rlp_bytes(&keccak256(&encoded))
+    }
+}
+
+/// Encodes the trie node covering `pairs` (sorted by key) below `depth` nibbles.
+fn encode_node(pairs: &[(Vec<u8>, &[u8])], depth: usize) -> Vec<u8> {
+    match pairs {
+        [] => vec![EMPTY_STRING],
+        [(key, value)] => rlp_list(&[
+            rlp_bytes(&hex_prefix(&key[depth..], true)),
+            rlp_bytes(value),
+        ]),
+        [(first, _), .., (last, _)] => {
+            let shared = first[depth..]
+                .iter()
+                .zip(&last[depth..])
+                .take_while(|(a, b)| a == b)
+                .count();
+
+            if shared > 0 {
+                let child = encode_node(pairs, depth + shared);
+                return rlp_list(&[
+                    rlp_bytes(&hex_prefix(&first[depth..depth + shared], false)),
+                    node_reference(child),
+                ]);
+            }
+
+            let mut items = Vec::with_capacity(17);
+            let mut branch_value: &[u8] = &[];
+            let mut rest = pairs;
+
+            if let Some(((key, value), tail)) = rest.split_first() {
+                if key.len() == depth {
+                    branch_value = value;
+                    rest = tail;
+                }
+            }
+
+            for nibble in 0..16 {
+                let end = rest
+                    .iter()
+                    .position(|(key, _)| key[depth] != nibble)
+                    .unwrap_or(rest.len());
+                let (group, tail) = rest.split_at(end);
+
+                items.push(if group.is_empty() {
+                    vec![EMPTY_STRING]
+                } else {
+                    node_reference(encode_node(group, depth + 1))
+                });
+
+                rest = tail;
+            }
+
+            items.push(rlp_bytes(branch_value));
+            rlp_list(&items)
+        }
+    }
+}
+
+/// Computes the root of a trie keyed by the RLP-encoded index of each value,
+/// as used for the `transactionsRoot` and `withdrawalsRoot` header fields.
+#[must_use]
+pub fn ordered_trie_root<V: AsRef<[u8]>>(values: &[V]) -> H256 {
+    let mut pairs = values
+        .iter()
+        .zip(0_u64..)
+        .map(|(value, index)| (to_nibbles(&rlp_u64(index)), value.as_ref()))
+        .collect::<Vec<_>>();
+
+    pairs.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+    H256(keccak256(&encode_node(&pairs, 0)))
+}
+
+// =============================================================================
+// Header commitments
+// =============================================================================
+
+/// Computes the EL `withdrawalsRoot` of a list of withdrawals.
+///
+/// Each withdrawal is RLP-encoded as `[index, validator_index, address, amount]`.
+#[must_use]
+pub fn withdrawals_root<'a>(withdrawals: impl IntoIterator<Item = &'a Withdrawal>) -> H256 {
+    let encoded = withdrawals
+        .into_iter()
+        .map(|withdrawal| {
+            rlp_list(&[
+                rlp_u64(withdrawal.index),
+                rlp_u64(withdrawal.validator_index),
+                rlp_bytes(withdrawal.address.as_bytes()),
+                rlp_u64(withdrawal.amount),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    ordered_trie_root(&encoded)
+}
+
+/// Computes the EIP-7685 `requestsHash` of Electra execution requests.
+///
+/// The hash is `sha256(sha256(type ++ data) for each non-empty request list)`,
+/// where `data` is the SSZ concatenation of the requests of that type.
+///
+/// # Errors
+///
+/// Returns an error string if a request list cannot be SSZ-encoded.
+pub fn requests_hash<P: Preset>(requests: &ExecutionRequests<P>) -> Result<H256, String> {
+    let lists = [
+        (0x00, requests.deposits.to_ssz()),
+        (0x01, requests.withdrawals.to_ssz()),
+        (0x02, requests.consolidations.to_ssz()),
+    ];
+
+    let mut outer = Sha256::new();
+
+    for (request_type, data) in lists {
+        let data = data.map_err(|e| e.to_string())?;
+
+        if data.is_empty() {
+            continue;
+        }
+
+        let mut inner = Sha256::new();
+        inner.update([request_type]);
+        inner.update(&data);
+        outer.update(inner.finalize());
+    }
+
+    Ok(H256(outer.finalize().into()))
+}
+
+/// Computes the EL block hash of an Electra execution payload.
+///
+/// This is the keccak256 of the RLP-encoded Prague block header, which
+/// commits to the payload fields, the trie roots of its transactions and
+/// withdrawals, the parent beacon block root and the requests hash. Fields
+/// fixed since the merge (ommers hash, difficulty and nonce) use their
+/// constant values.
+///
+/// # Errors
+///
+/// Returns an error string if a payload field cannot be SSZ-encoded.
+pub fn block_hash<P: Preset>(
+    payload: &ExecutionPayload<P>,
+    parent_beacon_block_root: H256,
+    requests_hash: H256,
+) -> Result<H256, String> {
+    let transactions = payload
+        .transactions
+        .iter()
+        .map(|transaction| -> &[u8] { transaction })
+        .collect::<Vec<_>>();
+
+    let mut base_fee_per_gas = encode_ssz(&payload.base_fee_per_gas)?;
+    base_fee_per_gas.reverse();
+
+    let header = [
+        rlp_bytes(payload.parent_hash.as_bytes()),
+        rlp_bytes(&EMPTY_OMMERS_HASH),
+        rlp_bytes(payload.fee_recipient.as_bytes()),
+        rlp_bytes(payload.state_root.as_bytes()),
+        rlp_bytes(ordered_trie_root(&transactions).as_bytes()),
+        rlp_bytes(payload.receipts_root.as_bytes()),
+        rlp_bytes(&encode_ssz(&payload.logs_bloom)?),
+        rlp_u64(0),
+        rlp_u64(payload.block_number),
+        rlp_u64(payload.gas_limit),
+        rlp_u64(payload.gas_used),
+        rlp_u64(payload.timestamp),
+        rlp_bytes(&encode_ssz(&payload.extra_data)?),
+        rlp_bytes(payload.prev_randao.as_bytes()),
+        rlp_bytes(&[0; 8]),
+        rlp_uint(&base_fee_per_gas),
+        rlp_bytes(withdrawals_root(payload.withdrawals.iter()).as_bytes()),
+        rlp_u64(payload.blob_gas_used),
+        rlp_u64(payload.excess_blob_gas),
+        rlp_bytes(parent_beacon_block_root.as_bytes()),
+        rlp_bytes(requests_hash.as_bytes()),
+    ];
+
+    Ok(H256(keccak256(&rlp_list(&header))))
+}
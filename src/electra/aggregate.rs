@@ -0,0 +1,404 @@
+//! Electra aggregate-and-proof types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `AggregateAndProof` (Mainnet, Minimal, Gnosis)
+//! - `SignedAggregateAndProof` (Mainnet, Minimal, Gnosis), as published on
+//!   the `beacon_aggregate_and_proof` gossip topic
+//!
+//! and `make_aggregate_and_proof`, which pairs an aggregate with the
+//! aggregator's selection proof. Since Electra the aggregate is an Electra
+//! `Attestation`, which must cover exactly one committee on gossip.
+//!
+//! Aggregators sign the `AggregateAndProof` with the
+//! `DOMAIN_AGGREGATE_AND_PROOF` domain of the aggregate's epoch, and attach
+//! the signature with `with_signature`.
+
+use grandine_bls::SignatureBytes;
+use grandine_ssz::SszHash as _;
+use grandine_types::electra::containers::{
+    AggregateAndProof, Attestation, SignedAggregateAndProof,
+};
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use paste::paste;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::bls::{extract_signature, parse_public_key, verify};
+use crate::convert::{format_hash_tree_root, parse_root};
+use crate::define_ssz_pyclass_for_preset;
+use crate::electra::attestation::{
+    PyAttestationGnosis, PyAttestationMainnet, PyAttestationMinimal,
+};
+use crate::hex_format::format_hex;
+use crate::signing::signing_root;
+use crate::Gnosis;
+
+/// Builds an `AggregateAndProof`, checking that the aggregate can be
+/// published on gossip.
+///
+/// # Errors
+///
+/// Returns an error string if the aggregate does not cover exactly one
+/// committee or has no aggregation bits set.
+pub fn aggregate_and_proof<P: Preset>(
+    aggregator_index: u64,
+    aggregate: Attestation<P>,
+    selection_proof: SignatureBytes,
+) -> Result<AggregateAndProof<P>, String> {
+    let committees = aggregate.committee_bits.count_ones();
+
+    if committees != 1 {
+        return Err(format!(
+            "Aggregate must cover exactly one committee, got {committees}"
+        ));
+    }
+
+    if aggregate.aggregation_bits.count_ones() == 0 {
+        return Err("Aggregate has no aggregation bits set".to_owned());
+    }
+
+    Ok(AggregateAndProof {
+        aggregator_index,
+        aggregate,
+        selection_proof,
+    })
+}
+
+paste! {
+    define_ssz_pyclass_for_preset!(
+        [<PyAggregateAndProofMainnet>],
+        "ElectraAggregateAndProofMainnet",
+        AggregateAndProof<Mainnet>,
+        extra_methods = {
+            pub fn aggregator_index(&self) -> u64 {
+                self.inner.aggregator_index
+            }
+
+            pub fn aggregate(&self) -> [<PyAttestationMainnet>] {
+                [<PyAttestationMainnet>] {
+                    inner: self.inner.aggregate.clone(),
+                }
+            }
+
+            pub fn selection_proof(&self) -> String {
+                format_hex(self.inner.selection_proof.as_bytes())
+            }
+
+            /// Signing root for the given `DOMAIN_AGGREGATE_AND_PROOF` domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes.
+            pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+                let root = signing_root(self.inner.hash_tree_root(), parse_root(domain)?);
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Attach the aggregator's signature.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `signature` is not a
+            /// valid signature.
+            pub fn with_signature(
+                &self,
+                signature: &Bound<'_, PyAny>,
+            ) -> PyResult<[<PySignedAggregateAndProofMainnet>]> {
+                Ok([<PySignedAggregateAndProofMainnet>] {
+                    inner: SignedAggregateAndProof {
+                        message: self.inner.clone(),
+                        signature: extract_signature(signature)?,
+                    },
+                })
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PySignedAggregateAndProofMainnet>],
+        "ElectraSignedAggregateAndProofMainnet",
+        SignedAggregateAndProof<Mainnet>,
+        extra_methods = {
+            pub fn message(&self) -> [<PyAggregateAndProofMainnet>] {
+                [<PyAggregateAndProofMainnet>] {
+                    inner: self.inner.message.clone(),
+                }
+            }
+
+            pub fn signature(&self) -> String {
+                format_hex(self.inner.signature.as_bytes())
+            }
+
+            /// Signing root of the message for the given
+            /// `DOMAIN_AGGREGATE_AND_PROOF` domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes.
+            pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+                let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Verify the signature against the aggregator's `pubkey` and the
+            /// `DOMAIN_AGGREGATE_AND_PROOF` domain of the aggregate's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a valid
+            /// pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: Python<'_>,
+                pubkey: &Bound<'_, PyAny>,
+                domain: &Bound<'_, PyAny>,
+            ) -> PyResult<bool> {
+                let pubkey = parse_public_key(pubkey)?;
+                let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+                let signature = self.inner.signature;
+                Ok(py.detach(|| verify(signature, root, &pubkey)))
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyAggregateAndProofMinimal>],
+        "ElectraAggregateAndProofMinimal",
+        AggregateAndProof<Minimal>,
+        extra_methods = {
+            pub fn aggregator_index(&self) -> u64 {
+                self.inner.aggregator_index
+            }
+
+            pub fn aggregate(&self) -> [<PyAttestationMinimal>] {
+                [<PyAttestationMinimal>] {
+                    inner: self.inner.aggregate.clone(),
+                }
+            }
+
+            pub fn selection_proof(&self) -> String {
+                format_hex(self.inner.selection_proof.as_bytes())
+            }
+
+            /// Signing root for the given `DOMAIN_AGGREGATE_AND_PROOF` domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes.
+            pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+                let root = signing_root(self.inner.hash_tree_root(), parse_root(domain)?);
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Attach the aggregator's signature.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `signature` is not a
+            /// valid signature.
+            pub fn with_signature(
+                &self,
+                signature: &Bound<'_, PyAny>,
+            ) -> PyResult<[<PySignedAggregateAndProofMinimal>]> {
+                Ok([<PySignedAggregateAndProofMinimal>] {
+                    inner: SignedAggregateAndProof {
+                        message: self.inner.clone(),
+                        signature: extract_signature(signature)?,
+                    },
+                })
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PySignedAggregateAndProofMinimal>],
+        "ElectraSignedAggregateAndProofMinimal",
+        SignedAggregateAndProof<Minimal>,
+        extra_methods = {
+            pub fn message(&self) -> [<PyAggregateAndProofMinimal>] {
+                [<PyAggregateAndProofMinimal>] {
+                    inner: self.inner.message.clone(),
+                }
+            }
+
+            pub fn signature(&self) -> String {
+                format_hex(self.inner.signature.as_bytes())
+            }
+
+            /// Signing root of the message for the given
+            /// `DOMAIN_AGGREGATE_AND_PROOF` domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes.
+            pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+                let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Verify the signature against the aggregator's `pubkey` and the
+            /// `DOMAIN_AGGREGATE_AND_PROOF` domain of the aggregate's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a valid
+            /// pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: Python<'_>,
+                pubkey: &Bound<'_, PyAny>,
+                domain: &Bound<'_, PyAny>,
+            ) -> PyResult<bool> {
+                let pubkey = parse_public_key(pubkey)?;
+                let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+                let signature = self.inner.signature;
+                Ok(py.detach(|| verify(signature, root, &pubkey)))
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyAggregateAndProofGnosis>],
+        "ElectraAggregateAndProofGnosis",
+        AggregateAndProof<Gnosis>,
+        extra_methods = {
+            pub fn aggregator_index(&self) -> u64 {
+                self.inner.aggregator_index
+            }
+
+            pub fn aggregate(&self) -> [<PyAttestationGnosis>] {
+                [<PyAttestationGnosis>] {
+                    inner: self.inner.aggregate.clone(),
+                }
+            }
+
+            pub fn selection_proof(&self) -> String {
+                format_hex(self.inner.selection_proof.as_bytes())
+            }
+
+            /// Signing root for the given `DOMAIN_AGGREGATE_AND_PROOF` domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes.
+            pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+                let root = signing_root(self.inner.hash_tree_root(), parse_root(domain)?);
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Attach the aggregator's signature.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `signature` is not a
+            /// valid signature.
+            pub fn with_signature(
+                &self,
+                signature: &Bound<'_, PyAny>,
+            ) -> PyResult<[<PySignedAggregateAndProofGnosis>]> {
+                Ok([<PySignedAggregateAndProofGnosis>] {
+                    inner: SignedAggregateAndProof {
+                        message: self.inner.clone(),
+                        signature: extract_signature(signature)?,
+                    },
+                })
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PySignedAggregateAndProofGnosis>],
+        "ElectraSignedAggregateAndProofGnosis",
+        SignedAggregateAndProof<Gnosis>,
+        extra_methods = {
+            pub fn message(&self) -> [<PyAggregateAndProofGnosis>] {
+                [<PyAggregateAndProofGnosis>] {
+                    inner: self.inner.message.clone(),
+                }
+            }
+
+            pub fn signature(&self) -> String {
+                format_hex(self.inner.signature.as_bytes())
+            }
+
+            /// Signing root of the message for the given
+            /// `DOMAIN_AGGREGATE_AND_PROOF` domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes.
+            pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+                let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Verify the signature against the aggregator's `pubkey` and the
+            /// `DOMAIN_AGGREGATE_AND_PROOF` domain of the aggregate's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a valid
+            /// pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: Python<'_>,
+                pubkey: &Bound<'_, PyAny>,
+                domain: &Bound<'_, PyAny>,
+            ) -> PyResult<bool> {
+                let pubkey = parse_public_key(pubkey)?;
+                let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+                let signature = self.inner.signature;
+                Ok(py.detach(|| verify(signature, root, &pubkey)))
+            }
+        }
+    );
+}
+
+/// Pair an aggregate attestation with the aggregator's selection proof.
+///
+/// Returns the `ElectraAggregateAndProof*` class of the aggregate's preset.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if `aggregate_attestation` is not an Electra
+/// `Attestation`, a `BlsEncodingError` subclass if `selection_proof` is not
+/// a valid signature, or `PyValueError` if the aggregate does not cover
+/// exactly one committee or has no aggregation bits set.
+#[pyfunction]
+pub fn make_aggregate_and_proof(
+    py: Python<'_>,
+    aggregator_index: u64,
+    aggregate_attestation: &Bound<'_, PyAny>,
+    selection_proof: &Bound<'_, PyAny>,
+) -> PyResult<Py<PyAny>> {
+    let selection_proof = extract_signature(selection_proof)?;
+
+    macro_rules! try_presets {
+        ($($attestation:ty => $aggregate:ident),* $(,)?) => {
+            $(
+                if let Ok(aggregate) = aggregate_attestation.cast::<$attestation>() {
+                    let aggregate = aggregate.borrow().inner.clone();
+                    let inner = aggregate_and_proof(aggregator_index, aggregate, selection_proof)
+                        .map_err(PyValueError::new_err)?;
+                    return Ok(Py::new(py, $aggregate { inner })?.into_any());
+                }
+            )*
+        };
+    }
+
+    try_presets!(
+        PyAttestationMainnet => PyAggregateAndProofMainnet,
+        PyAttestationMinimal => PyAggregateAndProofMinimal,
+        PyAttestationGnosis => PyAggregateAndProofGnosis,
+    );
+
+    Err(PyTypeError::new_err(format!(
+        "Expected an Electra attestation, got {}",
+        aggregate_attestation.get_type().name()?
+    )))
+}
+
+/// Registers the aggregate-and-proof types and constructor with the Python
+/// module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyAggregateAndProofMainnet>()?;
+    m.add_class::<PyAggregateAndProofMinimal>()?;
+    m.add_class::<PyAggregateAndProofGnosis>()?;
+    m.add_class::<PySignedAggregateAndProofMainnet>()?;
+    m.add_class::<PySignedAggregateAndProofMinimal>()?;
+    m.add_class::<PySignedAggregateAndProofGnosis>()?;
+    m.add_function(wrap_pyfunction!(make_aggregate_and_proof, m)?)?;
+    Ok(())
+}
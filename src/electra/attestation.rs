@@ -0,0 +1,524 @@
+//! Electra attestation types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `Attestation` (Mainnet, Minimal, Gnosis)
+//! - `SingleAttestation` (preset-independent, used on attestation subnets)
+//! - `IndexedAttestation` and `AttesterSlashing` (Mainnet, Minimal, Gnosis),
+//!   as included in blocks and held in slashing pools
+//!
+//! Each type supports SSZ and JSON serialization via `from_ssz`, `to_ssz`,
+//! `from_json`, and `to_json` methods. `Attestation` also exposes its
+//! `aggregation_bits` as a lazy sequence, splits them per committee following
+//! the EIP-7549 `committee_bits` layout (`committee_indices`, `for_committee`),
+//! and converts to the pre-Electra layout via `to_phase0`.
+
+use std::collections::BTreeSet;
+
+use paste::paste;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::phase0::attestation::{
+    PyPhase0AttestationGnosis, PyPhase0AttestationMainnet, PyPhase0AttestationMinimal,
+};
+use crate::Gnosis;
+use grandine_ssz::{BitList, BitVector, SszHash as _};
+use grandine_types::electra::containers::{
+    Attestation, AttesterSlashing, IndexedAttestation, SingleAttestation,
+};
+use grandine_types::phase0::containers::{Attestation as Phase0Attestation, AttestationData};
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use typenum::Unsigned as _;
+
+use crate::convert::format_hash_tree_root;
+use crate::list::PySszList;
+use crate::{define_ssz_pyclass_for_preset, ssz_list_view};
+
+/// Converts a pre-Electra attestation to the Electra layout.
+///
+/// The committee index moves from `data.index` into `committee_bits`, and
+/// `data.index` is set to 0 as required by EIP-7549.
+///
+/// # Errors
+///
+/// Returns an error string if `data.index` is not below
+/// `MAX_COMMITTEES_PER_SLOT`.
+pub fn attestation_from_phase0<P: Preset>(
+    attestation: &Phase0Attestation<P>,
+) -> Result<Attestation<P>, String> {
+    let data = attestation.data;
+    let committee_index = usize::try_from(data.index)
+        .ok()
+        .filter(|index| *index < P::MaxCommitteesPerSlot::USIZE)
+        .ok_or_else(|| format!("Committee index {} is out of range", data.index))?;
+
+    let mut committee_bits = BitVector::default();
+    committee_bits.set(committee_index, true);
+
+    let mut aggregation_bits = BitList::with_length(attestation.aggregation_bits.len());
+    for index in attestation.aggregation_bits.iter_ones() {
+        aggregation_bits.set(index, true);
+    }
+
+    Ok(Attestation {
+        aggregation_bits,
+        data: AttestationData { index: 0, ..data },
+        signature: attestation.signature,
+        committee_bits,
+    })
+}
+
+/// Converts an Electra attestation to the pre-Electra layout.
+///
+/// Only single-committee attestations can be converted: the aggregate
+/// signature of a multi-committee attestation cannot be split per committee.
+///
+/// # Errors
+///
+/// Returns an error string if the attestation does not have exactly one
+/// committee bit set.
+pub fn attestation_to_phase0<P: Preset>(
+    attestation: &Attestation<P>,
+) -> Result<Phase0Attestation<P>, String> {
+    let mut committees = attestation.committee_bits.iter_ones();
+
+    let (Some(committee_index), None) = (committees.next(), committees.next()) else {
+        return Err(format!(
+            "Expected exactly one committee bit, got {}",
+            attestation.committee_bits.count_ones()
+        ));
+    };
+
+    // A single committee never exceeds `MAX_VALIDATORS_PER_COMMITTEE`, but
+    // the Electra bitlist limit is larger, so check before copying.
+    let length = attestation.aggregation_bits.len();
+    if length > P::MaxValidatorsPerCommittee::USIZE {
+        return Err(format!(
+            "Aggregation bits of length {length} exceed MAX_VALIDATORS_PER_COMMITTEE"
+        ));
+    }
+
+    let mut aggregation_bits = BitList::with_length(length);
+    for index in attestation.aggregation_bits.iter_ones() {
+        aggregation_bits.set(index, true);
+    }
+
+    Ok(Phase0Attestation {
+        aggregation_bits,
+        data: AttestationData {
+            index: committee_index as u64,
+            ..attestation.data
+        },
+        signature: attestation.signature,
+    })
+}
+
+/// Returns the aggregation bits of one committee of an Electra attestation.
+///
+/// Per EIP-7549, `aggregation_bits` is the concatenation of the bits of every
+/// committee set in `committee_bits`, in increasing committee index order.
+/// `committee_sizes` gives the size of each committee at the attestation's
+/// slot, indexed by committee index; it may be omitted when only one
+/// committee is set.
+///
+/// # Errors
+///
+/// Returns an error string if `committee_index` is not set in
+/// `committee_bits`, a needed committee size is missing, or the sizes do not
+/// add up to the length of `aggregation_bits`.
+pub fn committee_aggregation_bits<P: Preset>(
+    attestation: &Attestation<P>,
+    committee_index: usize,
+    committee_sizes: Option<&[usize]>,
+) -> Result<Vec<bool>, String> {
+    let length = attestation.aggregation_bits.len();
+
+    let bit = |index: usize| {
+        attestation
+            .aggregation_bits
+            .get(index)
+            .is_some_and(|bit| *bit)
+    };
+
+    if !attestation
+        .committee_bits
+        .get(committee_index)
+        .is_some_and(|bit| *bit)
+    {
+        return Err(format!(
+            "Committee {committee_index} is not set in committee_bits"
+        ));
+    }
+
+    let Some(committee_sizes) = committee_sizes else {
+        if attestation.committee_bits.count_ones() != 1 {
+            return Err(
+                "committee_sizes is required for attestations covering several committees"
+                    .to_owned(),
+            );
+        }
+
+        return Ok((0..length).map(bit).collect());
+    };
+
+    let mut offset = 0;
+    let mut range = 0..0;
+
+    for committee in attestation.committee_bits.iter_ones() {
+        let size = *committee_sizes
+            .get(committee)
+            .ok_or_else(|| format!("No size given for committee {committee}"))?;
+
+        if committee == committee_index {
+            range = offset..offset + size;
+        }
+
+        offset += size;
+    }
+
+    if offset != length {
+        return Err(format!(
+            "Committee sizes add up to {offset}, but the attestation has {length} aggregation bits"
+        ));
+    }
+
+    Ok(range.map(bit).collect())
+}
+
+/// Returns the validators attesting in both attestations of a slashing, in
+/// ascending order.
+///
+/// These are the validators slashed by it, except for those already slashed
+/// or no longer slashable.
+fn slashable_indices<P: Preset>(slashing: &AttesterSlashing<P>) -> Vec<u64> {
+    let indices_1 = slashing
+        .attestation_1
+        .attesting_indices
+        .iter()
+        .collect::<BTreeSet<_>>();
+    let indices_2 = slashing
+        .attestation_2
+        .attesting_indices
+        .iter()
+        .collect::<BTreeSet<_>>();
+
+    indices_1
+        .intersection(&indices_2)
+        .map(|index| **index)
+        .collect()
+}
+
+paste! {
+    define_ssz_pyclass_for_preset!(
+        [<PyAttestationMainnet>],
+        "ElectraAttestationMainnet",
+        Attestation<Mainnet>,
+        extra_methods = {
+            pub fn aggregation_bits(slf: &Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.aggregation_bits, bool)
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+
+            /// Convert to the pre-Electra layout.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the attestation does not cover
+            /// exactly one committee.
+            pub fn to_phase0(&self) -> PyResult<PyPhase0AttestationMainnet> {
+                attestation_to_phase0(&self.inner)
+                    .map(|inner| PyPhase0AttestationMainnet { inner })
+                    .map_err(PyValueError::new_err)
+            }
+
+            /// Indices of the committees covered by the attestation, from
+            /// `committee_bits`.
+            pub fn committee_indices(&self) -> Vec<usize> {
+                self.inner.committee_bits.iter_ones().collect()
+            }
+
+            /// Aggregation bits of one committee.
+            ///
+            /// `committee_sizes` lists the size of every committee at the
+            /// attestation's slot, indexed by committee index, and is required
+            /// when the attestation covers more than one committee.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the committee is not covered or the
+            /// sizes do not match the aggregation bits.
+            #[pyo3(signature = (index, committee_sizes = None))]
+            pub fn for_committee(
+                &self,
+                index: usize,
+                committee_sizes: Option<Vec<usize>>,
+            ) -> PyResult<Vec<bool>> {
+                committee_aggregation_bits(&self.inner, index, committee_sizes.as_deref())
+                    .map_err(PyValueError::new_err)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyAttestationMinimal>],
+        "ElectraAttestationMinimal",
+        Attestation<Minimal>,
+        extra_methods = {
+            pub fn aggregation_bits(slf: &Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.aggregation_bits, bool)
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+
+            /// Convert to the pre-Electra layout.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the attestation does not cover
+            /// exactly one committee.
+            pub fn to_phase0(&self) -> PyResult<PyPhase0AttestationMinimal> {
+                attestation_to_phase0(&self.inner)
+                    .map(|inner| PyPhase0AttestationMinimal { inner })
+                    .map_err(PyValueError::new_err)
+            }
+
+            /// Indices of the committees covered by the attestation, from
+            /// `committee_bits`.
+            pub fn committee_indices(&self) -> Vec<usize> {
+                self.inner.committee_bits.iter_ones().collect()
+            }
+
+            /// Aggregation bits of one committee.
+            ///
+            /// `committee_sizes` lists the size of every committee at the
+            /// attestation's slot, indexed by committee index, and is required
+            /// when the attestation covers more than one committee.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the committee is not covered or the
+            /// sizes do not match the aggregation bits.
+            #[pyo3(signature = (index, committee_sizes = None))]
+            pub fn for_committee(
+                &self,
+                index: usize,
+                committee_sizes: Option<Vec<usize>>,
+            ) -> PyResult<Vec<bool>> {
+                committee_aggregation_bits(&self.inner, index, committee_sizes.as_deref())
+                    .map_err(PyValueError::new_err)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyAttestationGnosis>],
+        "ElectraAttestationGnosis",
+        Attestation<Gnosis>,
+        extra_methods = {
+            pub fn aggregation_bits(slf: &Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.aggregation_bits, bool)
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+
+            /// Convert to the pre-Electra layout.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the attestation does not cover
+            /// exactly one committee.
+            pub fn to_phase0(&self) -> PyResult<PyPhase0AttestationGnosis> {
+                attestation_to_phase0(&self.inner)
+                    .map(|inner| PyPhase0AttestationGnosis { inner })
+                    .map_err(PyValueError::new_err)
+            }
+
+            /// Indices of the committees covered by the attestation, from
+            /// `committee_bits`.
+            pub fn committee_indices(&self) -> Vec<usize> {
+                self.inner.committee_bits.iter_ones().collect()
+            }
+
+            /// Aggregation bits of one committee.
+            ///
+            /// `committee_sizes` lists the size of every committee at the
+            /// attestation's slot, indexed by committee index, and is required
+            /// when the attestation covers more than one committee.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the committee is not covered or the
+            /// sizes do not match the aggregation bits.
+            #[pyo3(signature = (index, committee_sizes = None))]
+            pub fn for_committee(
+                &self,
+                index: usize,
+                committee_sizes: Option<Vec<usize>>,
+            ) -> PyResult<Vec<bool>> {
+                committee_aggregation_bits(&self.inner, index, committee_sizes.as_deref())
+                    .map_err(PyValueError::new_err)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyIndexedAttestationMainnet>],
+        "ElectraIndexedAttestationMainnet",
+        IndexedAttestation<Mainnet>,
+        extra_methods = {
+            /// Indices of the attesting validators.
+            pub fn attesting_indices(&self) -> Vec<u64> {
+                self.inner.attesting_indices.to_vec()
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyAttesterSlashingMainnet>],
+        "ElectraAttesterSlashingMainnet",
+        AttesterSlashing<Mainnet>,
+        extra_methods = {
+            pub fn attestation_1(&self) -> [<PyIndexedAttestationMainnet>] {
+                [<PyIndexedAttestationMainnet>] {
+                    inner: self.inner.attestation_1.clone(),
+                }
+            }
+
+            pub fn attestation_2(&self) -> [<PyIndexedAttestationMainnet>] {
+                [<PyIndexedAttestationMainnet>] {
+                    inner: self.inner.attestation_2.clone(),
+                }
+            }
+
+            /// Indices of the validators attesting in both attestations, in
+            /// ascending order.
+            pub fn slashable_indices(&self) -> Vec<u64> {
+                slashable_indices(&self.inner)
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyIndexedAttestationMinimal>],
+        "ElectraIndexedAttestationMinimal",
+        IndexedAttestation<Minimal>,
+        extra_methods = {
+            /// Indices of the attesting validators.
+            pub fn attesting_indices(&self) -> Vec<u64> {
+                self.inner.attesting_indices.to_vec()
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyAttesterSlashingMinimal>],
+        "ElectraAttesterSlashingMinimal",
+        AttesterSlashing<Minimal>,
+        extra_methods = {
+            pub fn attestation_1(&self) -> [<PyIndexedAttestationMinimal>] {
+                [<PyIndexedAttestationMinimal>] {
+                    inner: self.inner.attestation_1.clone(),
+                }
+            }
+
+            pub fn attestation_2(&self) -> [<PyIndexedAttestationMinimal>] {
+                [<PyIndexedAttestationMinimal>] {
+                    inner: self.inner.attestation_2.clone(),
+                }
+            }
+
+            /// Indices of the validators attesting in both attestations, in
+            /// ascending order.
+            pub fn slashable_indices(&self) -> Vec<u64> {
+                slashable_indices(&self.inner)
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyIndexedAttestationGnosis>],
+        "ElectraIndexedAttestationGnosis",
+        IndexedAttestation<Gnosis>,
+        extra_methods = {
+            /// Indices of the attesting validators.
+            pub fn attesting_indices(&self) -> Vec<u64> {
+                self.inner.attesting_indices.to_vec()
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyAttesterSlashingGnosis>],
+        "ElectraAttesterSlashingGnosis",
+        AttesterSlashing<Gnosis>,
+        extra_methods = {
+            pub fn attestation_1(&self) -> [<PyIndexedAttestationGnosis>] {
+                [<PyIndexedAttestationGnosis>] {
+                    inner: self.inner.attestation_1.clone(),
+                }
+            }
+
+            pub fn attestation_2(&self) -> [<PyIndexedAttestationGnosis>] {
+                [<PyIndexedAttestationGnosis>] {
+                    inner: self.inner.attestation_2.clone(),
+                }
+            }
+
+            /// Indices of the validators attesting in both attestations, in
+            /// ascending order.
+            pub fn slashable_indices(&self) -> Vec<u64> {
+                slashable_indices(&self.inner)
+            }
+
+            pub fn hash_tree_root(&self) -> String {
+                format_hash_tree_root(&self.inner.hash_tree_root())
+            }
+        }
+    );
+}
+
+define_ssz_pyclass_for_preset!(
+    PySingleAttestation,
+    "ElectraSingleAttestation",
+    SingleAttestation
+);
+
+/// Registers all Electra attestation types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyAttestationMainnet>()?;
+    m.add_class::<PyAttestationMinimal>()?;
+    m.add_class::<PyAttestationGnosis>()?;
+    m.add_class::<PySingleAttestation>()?;
+    m.add_class::<PyIndexedAttestationMainnet>()?;
+    m.add_class::<PyIndexedAttestationMinimal>()?;
+    m.add_class::<PyIndexedAttestationGnosis>()?;
+    m.add_class::<PyAttesterSlashingMainnet>()?;
+    m.add_class::<PyAttesterSlashingMinimal>()?;
+    m.add_class::<PyAttesterSlashingGnosis>()?;
+    Ok(())
+}
@@ -8,27 +8,94 @@
 //! - `SignedBlindedBeaconBlock` (Mainnet, Minimal, Gnosis)
 //!
 //! Each type supports SSZ and JSON serialization via `from_ssz`, `to_ssz`,
-//! `from_json`, and `to_json` methods. Block contents and blinded blocks also
-//! provide `header_dict`, `sign`, and `block_hash_tree_root` helper methods.
+//! `from_json`, and `to_json` methods, plus a typed `slot` getter. Block
+//! contents and blinded blocks also provide `header_dict`, `header_tuple`,
+//! `sign`, and `block_hash_tree_root` helper methods. They cache the block
+//! root, and the body and body field roots it is built from, which
+//! `body_root` and `payload_root` return (see the `root_cache` module).
+//! Blocks carrying a full execution payload provide `payload_stats` and
+//! `el_withdrawals_root`, the execution layer trie root of the payload
+//! withdrawals, and all blocks provide the EIP-7685 `requests_hash`. Gnosis blocks with a full payload
+//! also list their withdrawals converted to GNO with `gno_withdrawals`. Body
+//! lists (`attestations`, and `transactions` and `withdrawals` for full
+//! payloads) are exposed as lazy `SszList` views, and block contents expose
+//! `blobs` and `kzg_proofs` as sequences of zero-copy `ByteView`s. Signed
+//! block contents can drop their blobs with `strip_blobs`, and signed blocks
+//! re-attach them with `with_blobs`. Signed blocks and signed blinded blocks
+//! reduce to a `SignedBeaconBlockHeader` with `signed_header`, and signed
+//! blocks can be sanity-checked without a state with `verify_signatures`
+//! and `check_structure` (see the `validate` module). Mutable
+//! copies of block contents and blinded blocks (`clone_mut`) can be patched
+//! before signing: they take a new graffiti with `set_graffiti`, state root
+//! with `set_state_root`, and execution payload (`set_execution_payload`) or
+//! payload header (`set_execution_payload_header`), and those of block
+//! contents also fix their state-independent roots with `recompute_roots`.
+//! Blinded blocks can also be parsed from `produceBlockV3` responses via
+//! `from_produce_block_v3`.
+//!
+//! For the builder flow, signed blocks swap their execution payload for its
+//! header with `blind`, and signed blinded blocks take back the payload and
+//! blobs bundle a relay returns with `unblind`. Both keep the body root, so
+//! the proposer's signature carries over. Builder bids provide their `value`
+//! and builder `pubkey`, and check their signature with `verify` (see the
+//! `builder_api` module).
 
 use paste::paste;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-
+use pyo3::types::PyBytes;
+
+use crate::bls::parse_signature;
+use crate::builder_api::{bid_pubkey, bid_value, verify_bid};
+use crate::capella::bls_change::PySignedBlsToExecutionChange;
+use crate::config::{resolve_config, PyConfig};
+use crate::convert::{format_hash_tree_root, parse_root};
+use crate::domains::proposer_domain;
+use crate::el_hashing;
+use crate::electra::attestation::{
+    PyAttestationGnosis, PyAttestationMainnet, PyAttestationMinimal, PyAttesterSlashingGnosis,
+    PyAttesterSlashingMainnet, PyAttesterSlashingMinimal,
+};
+use crate::electra::execution::{
+    el_withdrawals_root_impl, payload_header, payload_stats_impl, requests_hash_impl,
+    PyExecutionPayloadGnosis, PyExecutionPayloadHeaderGnosis, PyExecutionPayloadHeaderMainnet,
+    PyExecutionPayloadHeaderMinimal, PyExecutionPayloadMainnet, PyExecutionPayloadMinimal,
+};
+use crate::electra::produce::{
+    parse_produce_block_v3, Headers, ProducedBlockData, PyProducedBlock,
+};
+use crate::electra::sidecar::{
+    blob_sidecars, PyBlobSidecarGnosis, PyBlobSidecarMainnet, PyBlobSidecarMinimal,
+};
+use crate::gnosis::gno_withdrawals_impl;
+use crate::kzg::{resolve_setup, verify_blob_proofs, PyTrustedSetup};
+use crate::list::PySszList;
+use crate::merkle::{generalized_index_impl, prove_impl, prove_path_impl, PathElement};
+use crate::phase0::deposit::PyDeposit;
+use crate::phase0::exit::PySignedVoluntaryExit;
+use crate::phase0::header::{PyProposerSlashing, PySignedBeaconBlockHeader};
+use crate::presets::NamedPreset;
+use crate::primitives::PySlot;
+use crate::root_cache::{BlockRoots, EXECUTION_PAYLOAD, GRAFFITI};
+use crate::signing::{object_signing_root, signing_root, verify_object};
+use crate::validate::{check_structure_impl, verify_signatures_impl};
 use crate::Gnosis;
+use c_kzg::KzgSettings;
 use grandine_bls::SignatureBytes;
 use grandine_builder_api::combined::SignedBuilderBid;
 use grandine_ssz::{ContiguousList, Ssz, SszHash, SszRead, SszReadDefault};
-use grandine_types::deneb::primitives::{Blob, KzgProof};
+use grandine_types::deneb::containers::ExecutionPayload;
+use grandine_types::deneb::primitives::{Blob, KzgCommitment, KzgProof};
 use grandine_types::electra::containers::{
     BeaconBlock, BlindedBeaconBlock, SignedBeaconBlock, SignedBlindedBeaconBlock,
 };
 use grandine_types::nonstandard::Phase;
+use grandine_types::phase0::containers::{BeaconBlockHeader, SignedBeaconBlockHeader};
 use grandine_types::preset::{Mainnet, Minimal, Preset};
 use serde::{Deserialize, Serialize};
 
 // Bring the macros into scope (because they're #[macro_export], they're at crate root)
-use crate::{define_decodable_pyclass_for_preset, define_ssz_pyclass_for_preset};
+use crate::{define_decodable_pyclass_for_preset, define_ssz_pyclass_for_preset, ssz_list_view};
 
 // =============================================================================
 // Helper traits and functions to reduce duplication between block types
@@ -93,7 +160,7 @@ impl<P: Preset> BlockHeader for BlindedBeaconBlock<P> {
 ///
 /// Returns a `PyDict` with keys: `slot`, `proposer_index`, `parent_root`,
 /// `state_root`, and `body_root`. All hash values are hex-encoded with `0x` prefix.
-fn header_dict_impl(
+pub(crate) fn header_dict_impl(
     header: &impl BlockHeader,
     py: Python<'_>,
 ) -> PyResult<Py<pyo3::types::PyDict>> {
@@ -102,39 +169,110 @@ fn header_dict_impl(
     let d = PyDict::new(py);
     d.set_item("slot", header.slot().to_string())?;
     d.set_item("proposer_index", header.proposer_index().to_string())?;
-    d.set_item(
-        "parent_root",
-        format!("0x{}", hex::encode(header.parent_root().as_bytes())),
-    )?;
-    d.set_item(
-        "state_root",
-        format!("0x{}", hex::encode(header.state_root().as_bytes())),
-    )?;
-    d.set_item(
-        "body_root",
-        format!("0x{}", hex::encode(header.body_root().as_bytes())),
-    )?;
+    d.set_item("parent_root", format_hash_tree_root(header.parent_root()))?;
+    d.set_item("state_root", format_hash_tree_root(header.state_root()))?;
+    d.set_item("body_root", format_hash_tree_root(&header.body_root()))?;
     Ok(d.into())
 }
 
-/// Parses a hex-encoded BLS signature string.
+/// Block header fields as `(slot, proposer_index, parent_root, state_root,
+/// body_root)`.
+pub(crate) type HeaderTuple<'py> = (
+    u64,
+    u64,
+    Bound<'py, PyBytes>,
+    Bound<'py, PyBytes>,
+    Bound<'py, PyBytes>,
+);
+
+/// Returns the header fields as a tuple of ints and raw 32-byte roots.
+///
+/// A cheaper alternative to `header_dict_impl` for hot paths: no dict is
+/// built and no field is formatted as a string.
+pub(crate) fn header_tuple_impl<'py>(
+    header: &impl BlockHeader,
+    py: Python<'py>,
+) -> HeaderTuple<'py> {
+    (
+        header.slot(),
+        header.proposer_index(),
+        PyBytes::new(py, header.parent_root().as_bytes()),
+        PyBytes::new(py, header.state_root().as_bytes()),
+        PyBytes::new(py, header.body_root().as_bytes()),
+    )
+}
+
+/// Builds the signed header of a block from its header fields and signature.
+pub(crate) fn signed_header_impl(
+    header: &impl BlockHeader,
+    signature: SignatureBytes,
+) -> PySignedBeaconBlockHeader {
+    PySignedBeaconBlockHeader {
+        inner: SignedBeaconBlockHeader {
+            message: BeaconBlockHeader {
+                slot: header.slot(),
+                proposer_index: header.proposer_index(),
+                parent_root: *header.parent_root(),
+                state_root: *header.state_root(),
+                body_root: header.body_root(),
+            },
+            signature,
+        },
+    }
+}
+
+/// Wraps a block slot as a typed `Slot` bound to `config` or the preset's default config.
 ///
 /// # Errors
 ///
-/// Returns `PyValueError` if the hex string is invalid or the signature bytes
-/// cannot be decoded.
-fn parse_signature(signature: &str) -> PyResult<SignatureBytes> {
-    let signature_clean = signature.trim_start_matches("0x");
-    let signature_bytes = hex::decode(signature_clean)
-        .map_err(|e| PyValueError::new_err(format!("Invalid signature hex: {e}")))?;
-
-    SignatureBytes::from_ssz_default(&signature_bytes)
-        .map_err(|e| PyValueError::new_err(format!("Invalid signature bytes: {e:?}")))
+/// Returns `PyValueError` if `config` belongs to a different preset.
+pub(crate) fn typed_slot<P: NamedPreset>(
+    slot: u64,
+    config: Option<PyRef<'_, PyConfig>>,
+) -> PyResult<PySlot> {
+    let config = resolve_config::<P>(config.as_deref())?;
+    Ok(PySlot::new(slot, config))
 }
 
-/// Formats an H256 hash as a hex string with `0x` prefix.
-fn format_hash_tree_root(root: &H256) -> String {
-    format!("0x{}", hex::encode(root.as_bytes()))
+/// Recomputes the roots of a block that do not depend on the state and fixes
+/// them in place, returning the changed fields as `{path: (old, new)}` with
+/// paths relative to the object, prefixed with `prefix`.
+///
+/// Only the EL `block_hash` of the execution payload is recomputed: the state
+/// root needs the pre-state, and KZG commitments need the trusted setup.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the payload or requests cannot be SSZ-encoded.
+pub(crate) fn recompute_roots_impl<P: Preset>(
+    block: &mut BeaconBlock<P>,
+    prefix: &str,
+    py: Python<'_>,
+) -> PyResult<Py<pyo3::types::PyDict>> {
+    let changes = pyo3::types::PyDict::new(py);
+    let body = &block.body;
+
+    let block_hash = py
+        .detach(|| {
+            let requests_hash = el_hashing::requests_hash(&body.execution_requests)?;
+            el_hashing::block_hash(&body.execution_payload, block.parent_root, requests_hash)
+        })
+        .map_err(PyValueError::new_err)?;
+
+    let payload = &mut block.body.execution_payload;
+
+    if payload.block_hash != block_hash {
+        changes.set_item(
+            format!("{prefix}body.execution_payload.block_hash"),
+            (
+                format_hash_tree_root(&payload.block_hash),
+                format_hash_tree_root(&block_hash),
+            ),
+        )?;
+        payload.block_hash = block_hash;
+    }
+
+    Ok(changes.into())
 }
 
 fn blind_block_with_builder_bid<P: Preset>(
@@ -171,7 +309,7 @@ fn replace_blinded_block_builder_bid<P: Preset>(
 /// Block contents including the beacon block, KZG proofs, and blobs.
 ///
 /// This is used for the full block that includes blob data (Deneb/Electra).
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize, Ssz)]
+#[derive(Clone, PartialEq, Eq, Default, Debug, Deserialize, Serialize, Ssz)]
 #[serde(bound = "")]
 pub struct BeaconBlockContents<P: Preset> {
     pub block: BeaconBlock<P>,
@@ -180,7 +318,7 @@ pub struct BeaconBlockContents<P: Preset> {
 }
 
 /// Signed block contents including the signed beacon block, KZG proofs, and blobs.
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize, Ssz)]
+#[derive(Clone, PartialEq, Eq, Default, Debug, Deserialize, Serialize, Ssz)]
 #[serde(bound = "")]
 pub struct SignedBeaconBlockContents<P: Preset> {
     pub signed_block: SignedBeaconBlock<P>,
@@ -188,232 +326,2593 @@ pub struct SignedBeaconBlockContents<P: Preset> {
     pub blobs: ContiguousList<Blob<P>, P::MaxBlobCommitmentsPerBlock>,
 }
 
-paste! {
-    define_ssz_pyclass_for_preset!(
-        [<PySignedBeaconBlockMainnet>],
-        "ElectraSignedBeaconBlockMainnet",
-        SignedBeaconBlock<Mainnet>
-    );
+/// Decodes each item of a list of SSZ encodings, naming the failing item.
+///
+/// # Errors
+///
+/// Returns an error string if an item is not a valid encoding of `T`.
+pub(crate) fn decode_each<T: SszReadDefault>(
+    items: &[Vec<u8>],
+    what: &str,
+) -> Result<Vec<T>, String> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, bytes)| {
+            T::from_ssz_default(bytes).map_err(|e| format!("Invalid {what} {index}: {e}"))
+        })
+        .collect()
+}
 
-    define_decodable_pyclass_for_preset!(
-        [<PySignedBuilderBidMainnet>],
-        "ElectraSignedBuilderBidMainnet",
-        SignedBuilderBid<Mainnet>,
-        ssz_decoder = |bytes: &[u8]| SignedBuilderBid::<Mainnet>::from_ssz(&Phase::Electra, bytes)
-    );
+/// The KZG proof and blob lists of block contents.
+pub(crate) type BlobLists<P> = (
+    ContiguousList<KzgProof, <P as Preset>::MaxBlobCommitmentsPerBlock>,
+    ContiguousList<Blob<P>, <P as Preset>::MaxBlobCommitmentsPerBlock>,
+);
 
-    define_ssz_pyclass_for_preset!(
-        [<PySignedBeaconBlockMinimal>],
-        "ElectraSignedBeaconBlockMinimal",
-        SignedBeaconBlock<Minimal>
-    );
+/// Decodes the blobs and KZG proofs for a block with `commitments` KZG
+/// commitments.
+///
+/// # Errors
+///
+/// Returns an error string if the counts do not match the commitments or a
+/// blob or proof has the wrong length.
+pub(crate) fn blob_lists<P: Preset>(
+    commitments: usize,
+    blobs: &[Vec<u8>],
+    kzg_proofs: &[Vec<u8>],
+) -> Result<BlobLists<P>, String> {
+    if blobs.len() != commitments {
+        return Err(format!(
+            "Expected {commitments} blobs for {commitments} KZG commitments, got {}",
+            blobs.len()
+        ));
+    }
 
-    define_decodable_pyclass_for_preset!(
-        [<PySignedBuilderBidMinimal>],
-        "ElectraSignedBuilderBidMinimal",
-        SignedBuilderBid<Minimal>,
-        ssz_decoder = |bytes: &[u8]| SignedBuilderBid::<Minimal>::from_ssz(&Phase::Electra, bytes)
-    );
+    if kzg_proofs.len() != commitments {
+        return Err(format!(
+            "Expected {commitments} KZG proofs for {commitments} KZG commitments, got {}",
+            kzg_proofs.len()
+        ));
+    }
 
-    define_ssz_pyclass_for_preset!(
-        [<PySignedBeaconBlockGnosis>],
-        "ElectraSignedBeaconBlockGnosis",
-        SignedBeaconBlock<Gnosis>
-    );
+    let blobs = decode_each::<Blob<P>>(blobs, "blob")?;
+    let kzg_proofs = decode_each::<KzgProof>(kzg_proofs, "KZG proof")?;
 
-    define_decodable_pyclass_for_preset!(
-        [<PySignedBuilderBidGnosis>],
-        "ElectraSignedBuilderBidGnosis",
-        SignedBuilderBid<Gnosis>,
-        ssz_decoder = |bytes: &[u8]| SignedBuilderBid::<Gnosis>::from_ssz(&Phase::Electra, bytes)
-    );
+    Ok((
+        ContiguousList::try_from_iter(kzg_proofs).map_err(|e| e.to_string())?,
+        ContiguousList::try_from_iter(blobs).map_err(|e| e.to_string())?,
+    ))
+}
+
+/// Attaches blobs and KZG proofs to a signed block.
+///
+/// There must be exactly one blob and one proof per KZG commitment in the
+/// block body, in the same order. Proofs are not verified against the
+/// commitments.
+///
+/// # Errors
+///
+/// Returns an error string if the counts do not match the commitments or a
+/// blob or proof has the wrong length.
+pub fn attach_blobs<P: Preset>(
+    signed_block: SignedBeaconBlock<P>,
+    blobs: &[Vec<u8>],
+    kzg_proofs: &[Vec<u8>],
+) -> Result<SignedBeaconBlockContents<P>, String> {
+    let commitments = signed_block.message.body.blob_kzg_commitments.len();
+    let (kzg_proofs, blobs) = blob_lists::<P>(commitments, blobs, kzg_proofs)?;
+
+    Ok(SignedBeaconBlockContents {
+        signed_block,
+        kzg_proofs,
+        blobs,
+    })
+}
+
+/// The blobs bundle a relay returns with the execution payload of a
+/// submitted blinded block.
+#[derive(Clone, PartialEq, Eq, Default, Debug, Deserialize, Serialize, Ssz)]
+#[serde(bound = "")]
+pub struct BlobsBundle<P: Preset> {
+    pub commitments: ContiguousList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>,
+    pub proofs: ContiguousList<KzgProof, P::MaxBlobCommitmentsPerBlock>,
+    pub blobs: ContiguousList<Blob<P>, P::MaxBlobCommitmentsPerBlock>,
+}
+
+/// Replaces the execution payload of a signed block with its header. The
+/// body root, and so the signature, stays the same.
+pub(crate) fn blind_block<P: Preset>(
+    signed_block: &SignedBeaconBlock<P>,
+) -> SignedBlindedBeaconBlock<P> {
+    let mut block = signed_block.message.clone();
+    let header = payload_header(core::mem::take(&mut block.body.execution_payload));
+    let commitments = block.body.blob_kzg_commitments.clone();
+    let requests = block.body.execution_requests.clone();
+
+    SignedBlindedBeaconBlock {
+        message: block.with_execution_payload_header_and_kzg_commitments(
+            header,
+            Some(commitments),
+            Some(requests),
+        ),
+        signature: signed_block.signature,
+    }
+}
+
+/// Replaces the payload header of a signed blinded block with the execution
+/// payload it commits to.
+///
+/// # Errors
+///
+/// Returns an error string if the payload does not match the header or
+/// `commitments` do not match the block's KZG commitments.
+pub(crate) fn unblind_block<P: Preset>(
+    signed_blinded_block: &SignedBlindedBeaconBlock<P>,
+    execution_payload: ExecutionPayload<P>,
+    commitments: &ContiguousList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>,
+) -> Result<SignedBeaconBlock<P>, String> {
+    let body = &signed_blinded_block.message.body;
+    let header_root = payload_header(execution_payload.clone()).hash_tree_root();
+
+    if header_root != body.execution_payload_header.hash_tree_root() {
+        return Err(format!(
+            "Execution payload {} does not match the payload header of the block",
+            format_hash_tree_root(&execution_payload.block_hash)
+        ));
+    }
+
+    if *commitments != body.blob_kzg_commitments {
+        return Err("Blobs bundle commitments do not match the block's KZG commitments".to_owned());
+    }
+
+    Ok(SignedBeaconBlock {
+        message: signed_blinded_block
+            .message
+            .clone()
+            .with_execution_payload(execution_payload),
+        signature: signed_blinded_block.signature,
+    })
+}
+
+/// Unblinds a signed blinded block with the SSZ-encoded execution payload
+/// and blobs bundle a relay returned for it.
+///
+/// # Errors
+///
+/// Returns an error string if an encoding is invalid, the payload or the
+/// bundle does not match the block, or the bundle does not have one blob and
+/// one KZG proof per commitment.
+pub fn unblind<P: Preset>(
+    signed_blinded_block: &SignedBlindedBeaconBlock<P>,
+    execution_payload_ssz: &[u8],
+    blobs_bundle_ssz: &[u8],
+) -> Result<SignedBeaconBlockContents<P>, String> {
+    let execution_payload = ExecutionPayload::<P>::from_ssz_default(execution_payload_ssz)
+        .map_err(|e| format!("Invalid execution payload: {e}"))?;
+    let bundle = BlobsBundle::<P>::from_ssz_default(blobs_bundle_ssz)
+        .map_err(|e| format!("Invalid blobs bundle: {e}"))?;
+
+    if bundle.blobs.len() != bundle.commitments.len()
+        || bundle.proofs.len() != bundle.commitments.len()
+    {
+        return Err(format!(
+            "Expected one blob and one KZG proof per KZG commitment, got {} blobs and {} proofs \
+             for {} commitments",
+            bundle.blobs.len(),
+            bundle.proofs.len(),
+            bundle.commitments.len()
+        ));
+    }
+
+    Ok(SignedBeaconBlockContents {
+        signed_block: unblind_block(signed_blinded_block, execution_payload, &bundle.commitments)?,
+        kzg_proofs: bundle.proofs,
+        blobs: bundle.blobs,
+    })
+}
+
+/// Signature of the KZG proof checks in `crate::kzg`, taking blobs, KZG
+/// commitments and proofs.
+pub(crate) type VerifyProofs =
+    fn(&KzgSettings, &[&[u8]], &[&[u8]], &[&[u8]]) -> Result<bool, String>;
+
+/// Verifies the KZG proofs of block contents against the block's KZG
+/// commitments with the GIL released, using `setup` or the default trusted
+/// setup.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the counts do not match the commitments or a
+/// commitment or proof is not a valid point.
+pub(crate) fn verify_contents_proofs<P: Preset>(
+    py: Python<'_>,
+    commitments: &[KzgCommitment],
+    blobs: &[Blob<P>],
+    proofs: &[KzgProof],
+    setup: Option<&PyTrustedSetup>,
+    verify: VerifyProofs,
+) -> PyResult<bool> {
+    let setup = resolve_setup(setup);
+
+    py.detach(|| {
+        let blobs = blobs.iter().map(|blob| blob.as_bytes()).collect::<Vec<_>>();
+        let commitments = commitments
+            .iter()
+            .map(|commitment| commitment.as_bytes())
+            .collect::<Vec<_>>();
+        let proofs = proofs
+            .iter()
+            .map(|proof| proof.as_bytes())
+            .collect::<Vec<_>>();
+        verify(&setup, &blobs, &commitments, &proofs)
+    })
+    .map_err(PyValueError::new_err)
+}
 
+/// Checks the KZG proofs of block contents as [`verify_contents_proofs`]
+/// does, for `with_blobs(..., verify=True)`.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the proofs cannot be checked or do not verify.
+pub(crate) fn require_valid_proofs<P: Preset>(
+    py: Python<'_>,
+    commitments: &[KzgCommitment],
+    blobs: &[Blob<P>],
+    proofs: &[KzgProof],
+    setup: Option<&PyTrustedSetup>,
+    verify: VerifyProofs,
+) -> PyResult<()> {
+    if verify_contents_proofs(py, commitments, blobs, proofs, setup, verify)? {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(
+            "KZG proofs do not verify against the blobs and commitments",
+        ))
+    }
+}
+
+paste! {
     define_ssz_pyclass_for_preset!(
-        [<PyBeaconBlockContentsMainnet>],
-        "ElectraBeaconBlockContentsMainnet",
-        BeaconBlockContents<Mainnet>,
+        [<PySignedBeaconBlockMainnet>],
+        "ElectraSignedBeaconBlockMainnet",
+        SignedBeaconBlock<Mainnet>,
         extra_methods = {
-            pub fn header_dict(
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
                 &self,
                 py: pyo3::Python<'_>,
-            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
-                header_dict_impl(&self.inner.block, py)
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.message, domain))
             }
 
-            pub fn sign(
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
                 &self,
-                signature: &str,
-            ) -> pyo3::PyResult<[<PySignedBeaconBlockContentsMainnet>]> {
-                let signature = parse_signature(signature)?;
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.message, self.inner.signature, pubkey, domain)
+            }
 
-                let signed = SignedBeaconBlockContents::<Mainnet> {
-                    signed_block: SignedBeaconBlock::<Mainnet> {
-                        message: self.inner.block.clone(),
-                        signature,
-                    },
-                    kzg_proofs: self.inner.kzg_proofs.clone(),
-                    blobs: self.inner.blobs.clone(),
-                };
+            /// Verify the proposer, RANDAO reveal, attestation and sync
+            /// aggregate signatures in one batch, returning a dict of the
+            /// results under `proposer`, `randao_reveal`, `attestations` (one
+            /// per attestation) and `sync_aggregate`.
+            ///
+            /// `pubkey_provider` is a `PubkeyIndexMap` or a callable mapping
+            /// validator indices to pubkeys, `committees` a callable taking a
+            /// slot and committee index and returning the committee's
+            /// validator indices, and `sync_committee` the validator indices
+            /// of the sync committee. See the `validate` module documentation.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if an argument has the wrong length or a
+            /// pubkey is missing, or `PyTypeError` if `pubkey_provider` is
+            /// neither a `PubkeyIndexMap` nor callable.
+            #[pyo3(signature = (pubkey_provider, genesis_validators_root, fork_version, committees = None, sync_committee = None))]
+            pub fn verify_signatures<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                pubkey_provider: &pyo3::Bound<'py, pyo3::PyAny>,
+                genesis_validators_root: &pyo3::Bound<'py, pyo3::PyAny>,
+                fork_version: &pyo3::Bound<'py, pyo3::PyAny>,
+                committees: Option<&pyo3::Bound<'py, pyo3::PyAny>>,
+                sync_committee: Option<Vec<u64>>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyDict>> {
+                verify_signatures_impl(
+                    py,
+                    &self.inner,
+                    pubkey_provider,
+                    genesis_validators_root,
+                    fork_version,
+                    committees,
+                    sync_committee,
+                )
+            }
 
-                Ok([<PySignedBeaconBlockContentsMainnet>] { inner: signed })
+            /// Check the invariants of the body that SSZ decoding does not
+            /// enforce, returning the broken ones as messages; an empty list
+            /// means the block passes.
+            pub fn check_structure(&self, py: pyo3::Python<'_>) -> Vec<String> {
+                py.detach(|| check_structure_impl(&self.inner.message))
             }
 
-            pub fn block_hash_tree_root(
-                &self,
-                py: pyo3::Python<'_>,
-            ) -> String {
-                py.detach(|| format_hash_tree_root(&self.inner.block.hash_tree_root()))
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attestations, class = PyAttestationMainnet)
             }
 
-            pub fn replace_execution_payload_with_builder_bid(
-                &self,
-                signed_builder_bid: &[<PySignedBuilderBidMainnet>],
-            ) -> pyo3::PyResult<[<PyBlindedBeaconBlockMainnet>]> {
-                let blinded_block = blind_block_with_builder_bid(
-                    self.inner.block.clone(),
-                    &signed_builder_bid.inner,
-                )
-                .map_err(PyValueError::new_err)?;
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
 
-                Ok([<PyBlindedBeaconBlockMainnet>] { inner: blinded_block })
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attester_slashings, class = PyAttesterSlashingMainnet)
             }
-        }
-    );
 
-    define_ssz_pyclass_for_preset!(
-        [<PyBeaconBlockContentsGnosis>],
-        "ElectraBeaconBlockContentsGnosis",
-        BeaconBlockContents<Gnosis>,
-        extra_methods = {
-            pub fn header_dict(
-                &self,
-                py: pyo3::Python<'_>,
-            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
-                header_dict_impl(&self.inner.block, py)
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
             }
 
-            pub fn sign(
-                &self,
-                signature: &str,
-            ) -> pyo3::PyResult<[<PySignedBeaconBlockContentsGnosis>]> {
-                let signature = parse_signature(signature)?;
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
 
-                let signed = SignedBeaconBlockContents::<Gnosis> {
-                    signed_block: SignedBeaconBlock::<Gnosis> {
-                        message: self.inner.block.clone(),
-                        signature,
-                    },
-                    kzg_proofs: self.inner.kzg_proofs.clone(),
-                    blobs: self.inner.blobs.clone(),
-                };
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
 
-                Ok([<PySignedBeaconBlockContentsGnosis>] { inner: signed })
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.execution_payload.transactions, bytes)
             }
 
-            pub fn block_hash_tree_root(
-                &self,
-                py: pyo3::Python<'_>,
-            ) -> String {
-                py.detach(|| format_hash_tree_root(&self.inner.block.hash_tree_root()))
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.execution_payload.withdrawals, json)
             }
 
-            pub fn replace_execution_payload_with_builder_bid(
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
                 &self,
-                signed_builder_bid: &[<PySignedBuilderBidGnosis>],
-            ) -> pyo3::PyResult<[<PyBlindedBeaconBlockGnosis>]> {
-                let blinded_block = blind_block_with_builder_bid(
-                    self.inner.block.clone(),
-                    &signed_builder_bid.inner,
-                )
-                .map_err(PyValueError::new_err)?;
-
-                Ok([<PyBlindedBeaconBlockGnosis>] { inner: blinded_block })
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Mainnet>(self.inner.message.slot, config)
             }
-        }
-    );
 
-    define_ssz_pyclass_for_preset!(
-        [<PyBeaconBlockContentsMinimal>],
-        "ElectraBeaconBlockContentsMinimal",
-        BeaconBlockContents<Minimal>,
-        extra_methods = {
-            pub fn header_dict(
+            pub fn payload_stats(
                 &self,
                 py: pyo3::Python<'_>,
             ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
-                header_dict_impl(&self.inner.block, py)
+                payload_stats_impl(&self.inner.message.body.execution_payload, py)
             }
 
-            pub fn sign(
-                &self,
-                signature: &str,
-            ) -> pyo3::PyResult<[<PySignedBeaconBlockContentsMinimal>]> {
-                let signature = parse_signature(signature)?;
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.message.body.execution_payload, py)
+            }
 
-                let signed = SignedBeaconBlockContents::<Minimal> {
-                    signed_block: SignedBeaconBlock::<Minimal> {
-                        message: self.inner.block.clone(),
-                        signature,
-                    },
-                    kzg_proofs: self.inner.kzg_proofs.clone(),
-                    blobs: self.inner.blobs.clone(),
-                };
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.message.body.execution_requests, py)
+            }
 
-                Ok([<PySignedBeaconBlockContentsMinimal>] { inner: signed })
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                py.detach(|| signed_header_impl(&self.inner.message, self.inner.signature))
             }
 
-            pub fn block_hash_tree_root(
+            /// Attach blobs and KZG proofs, e.g. to restore block contents
+            /// saved with `strip_blobs`.
+            ///
+            /// With `verify`, the KZG proofs are checked against the
+            /// commitments, with `setup` or the default trusted setup.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob and one proof
+            /// per KZG commitment, a blob or proof has the wrong length, or
+            /// with `verify` a proof does not verify.
+            #[pyo3(signature = (blobs, proofs, verify = false, setup = None))]
+            pub fn with_blobs(
                 &self,
                 py: pyo3::Python<'_>,
-            ) -> String {
-                py.detach(|| format_hash_tree_root(&self.inner.block.hash_tree_root()))
+                blobs: Vec<Vec<u8>>,
+                proofs: Vec<Vec<u8>>,
+                verify: bool,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<[<PySignedBeaconBlockContentsMainnet>]> {
+                let inner = attach_blobs(self.inner.clone(), &blobs, &proofs)
+                    .map_err(PyValueError::new_err)?;
+
+                if verify {
+                    require_valid_proofs::<Mainnet>(
+                        py,
+                        &inner.signed_block.message.body.blob_kzg_commitments,
+                        &inner.blobs,
+                        &inner.kzg_proofs,
+                        setup.as_deref(),
+                        verify_blob_proofs,
+                    )?;
+                }
+
+                Ok([<PySignedBeaconBlockContentsMainnet>] { inner })
             }
 
-            pub fn replace_execution_payload_with_builder_bid(
+            /// Return the signed blinded block, with the execution payload
+            /// replaced by its header. The signature stays valid.
+            pub fn blind(&self, py: pyo3::Python<'_>) -> [<PySignedBlindedBeaconBlockMainnet>] {
+                [<PySignedBlindedBeaconBlockMainnet>] {
+                    inner: py.detach(|| blind_block(&self.inner)),
+                }
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
                 &self,
-                signed_builder_bid: &[<PySignedBuilderBidMinimal>],
-            ) -> pyo3::PyResult<[<PyBlindedBeaconBlockMinimal>]> {
-                let blinded_block = blind_block_with_builder_bid(
-                    self.inner.block.clone(),
-                    &signed_builder_bid.inner,
-                )
-                .map_err(PyValueError::new_err)?;
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.message, generalized_index)
+            }
 
-                Ok([<PyBlindedBeaconBlockMinimal>] { inner: blinded_block })
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.message, &path)
             }
         }
     );
 
-    define_ssz_pyclass_for_preset!(
-        [<PySignedBeaconBlockContentsMainnet>],
-        "ElectraSignedBeaconBlockContentsMainnet",
-        SignedBeaconBlockContents<Mainnet>
-    );
+    define_decodable_pyclass_for_preset!(
+        [<PySignedBuilderBidMainnet>],
+        "ElectraSignedBuilderBidMainnet",
+        SignedBuilderBid<Mainnet>,
+        ssz_decoder = |bytes: &[u8]| SignedBuilderBid::<Mainnet>::from_ssz(&Phase::Electra, bytes),
+        extra_methods = {
+            /// The value of the bid to the proposer, in wei.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the bid is not an Electra bid.
+            pub fn value<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                bid_value(py, &self.inner)
+            }
 
-    define_ssz_pyclass_for_preset!(
-        [<PySignedBeaconBlockContentsMinimal>],
-        "ElectraSignedBeaconBlockContentsMinimal",
-        SignedBeaconBlockContents<Minimal>
-    );
+            /// The pubkey of the builder that made the bid.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the bid is not an Electra bid.
+            pub fn pubkey(&self) -> pyo3::PyResult<String> {
+                bid_pubkey(&self.inner)
+            }
 
-    define_ssz_pyclass_for_preset!(
-        [<PySignedBeaconBlockContentsGnosis>],
-        "ElectraSignedBeaconBlockContentsGnosis",
-        SignedBeaconBlockContents<Gnosis>
+            /// Verify the signature against `builder_pubkey` and the
+            /// `DOMAIN_APPLICATION_BUILDER` domain of `genesis_fork_version`.
+            ///
+            /// Relays re-sign the bids they serve, so `builder_pubkey` is the
+            /// relay's pubkey, which the bid also includes.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `builder_pubkey` is not
+            /// a valid pubkey, or `PyValueError` if `genesis_fork_version` is
+            /// not 4 bytes or the bid is not an Electra bid.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                builder_pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                genesis_fork_version: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_bid(py, &self.inner, builder_pubkey, genesis_fork_version)
+            }
+        }
     );
 
     define_ssz_pyclass_for_preset!(
-        [<PyBlindedBeaconBlockMainnet>],
-        "ElectraBlindedBeaconBlockMainnet",
-        BlindedBeaconBlock<Mainnet>,
+        [<PySignedBeaconBlockMinimal>],
+        "ElectraSignedBeaconBlockMinimal",
+        SignedBeaconBlock<Minimal>,
         extra_methods = {
-            pub fn header_dict(
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.message, self.inner.signature, pubkey, domain)
+            }
+
+            /// Verify the proposer, RANDAO reveal, attestation and sync
+            /// aggregate signatures in one batch, returning a dict of the
+            /// results under `proposer`, `randao_reveal`, `attestations` (one
+            /// per attestation) and `sync_aggregate`.
+            ///
+            /// `pubkey_provider` is a `PubkeyIndexMap` or a callable mapping
+            /// validator indices to pubkeys, `committees` a callable taking a
+            /// slot and committee index and returning the committee's
+            /// validator indices, and `sync_committee` the validator indices
+            /// of the sync committee. See the `validate` module documentation.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if an argument has the wrong length or a
+            /// pubkey is missing, or `PyTypeError` if `pubkey_provider` is
+            /// neither a `PubkeyIndexMap` nor callable.
+            #[pyo3(signature = (pubkey_provider, genesis_validators_root, fork_version, committees = None, sync_committee = None))]
+            pub fn verify_signatures<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                pubkey_provider: &pyo3::Bound<'py, pyo3::PyAny>,
+                genesis_validators_root: &pyo3::Bound<'py, pyo3::PyAny>,
+                fork_version: &pyo3::Bound<'py, pyo3::PyAny>,
+                committees: Option<&pyo3::Bound<'py, pyo3::PyAny>>,
+                sync_committee: Option<Vec<u64>>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyDict>> {
+                verify_signatures_impl(
+                    py,
+                    &self.inner,
+                    pubkey_provider,
+                    genesis_validators_root,
+                    fork_version,
+                    committees,
+                    sync_committee,
+                )
+            }
+
+            /// Check the invariants of the body that SSZ decoding does not
+            /// enforce, returning the broken ones as messages; an empty list
+            /// means the block passes.
+            pub fn check_structure(&self, py: pyo3::Python<'_>) -> Vec<String> {
+                py.detach(|| check_structure_impl(&self.inner.message))
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attestations, class = PyAttestationMinimal)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attester_slashings, class = PyAttesterSlashingMinimal)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.execution_payload.withdrawals, json)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Minimal>(self.inner.message.slot, config)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.message.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.message.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                py.detach(|| signed_header_impl(&self.inner.message, self.inner.signature))
+            }
+
+            /// Attach blobs and KZG proofs, e.g. to restore block contents
+            /// saved with `strip_blobs`.
+            ///
+            /// With `verify`, the KZG proofs are checked against the
+            /// commitments, with `setup` or the default trusted setup.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob and one proof
+            /// per KZG commitment, a blob or proof has the wrong length, or
+            /// with `verify` a proof does not verify.
+            #[pyo3(signature = (blobs, proofs, verify = false, setup = None))]
+            pub fn with_blobs(
+                &self,
+                py: pyo3::Python<'_>,
+                blobs: Vec<Vec<u8>>,
+                proofs: Vec<Vec<u8>>,
+                verify: bool,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<[<PySignedBeaconBlockContentsMinimal>]> {
+                let inner = attach_blobs(self.inner.clone(), &blobs, &proofs)
+                    .map_err(PyValueError::new_err)?;
+
+                if verify {
+                    require_valid_proofs::<Minimal>(
+                        py,
+                        &inner.signed_block.message.body.blob_kzg_commitments,
+                        &inner.blobs,
+                        &inner.kzg_proofs,
+                        setup.as_deref(),
+                        verify_blob_proofs,
+                    )?;
+                }
+
+                Ok([<PySignedBeaconBlockContentsMinimal>] { inner })
+            }
+
+            /// Return the signed blinded block, with the execution payload
+            /// replaced by its header. The signature stays valid.
+            pub fn blind(&self, py: pyo3::Python<'_>) -> [<PySignedBlindedBeaconBlockMinimal>] {
+                [<PySignedBlindedBeaconBlockMinimal>] {
+                    inner: py.detach(|| blind_block(&self.inner)),
+                }
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.message, &path)
+            }
+        }
+    );
+
+    define_decodable_pyclass_for_preset!(
+        [<PySignedBuilderBidMinimal>],
+        "ElectraSignedBuilderBidMinimal",
+        SignedBuilderBid<Minimal>,
+        ssz_decoder = |bytes: &[u8]| SignedBuilderBid::<Minimal>::from_ssz(&Phase::Electra, bytes),
+        extra_methods = {
+            /// The value of the bid to the proposer, in wei.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the bid is not an Electra bid.
+            pub fn value<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                bid_value(py, &self.inner)
+            }
+
+            /// The pubkey of the builder that made the bid.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the bid is not an Electra bid.
+            pub fn pubkey(&self) -> pyo3::PyResult<String> {
+                bid_pubkey(&self.inner)
+            }
+
+            /// Verify the signature against `builder_pubkey` and the
+            /// `DOMAIN_APPLICATION_BUILDER` domain of `genesis_fork_version`.
+            ///
+            /// Relays re-sign the bids they serve, so `builder_pubkey` is the
+            /// relay's pubkey, which the bid also includes.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `builder_pubkey` is not
+            /// a valid pubkey, or `PyValueError` if `genesis_fork_version` is
+            /// not 4 bytes or the bid is not an Electra bid.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                builder_pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                genesis_fork_version: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_bid(py, &self.inner, builder_pubkey, genesis_fork_version)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PySignedBeaconBlockGnosis>],
+        "ElectraSignedBeaconBlockGnosis",
+        SignedBeaconBlock<Gnosis>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.message, self.inner.signature, pubkey, domain)
+            }
+
+            /// Verify the proposer, RANDAO reveal, attestation and sync
+            /// aggregate signatures in one batch, returning a dict of the
+            /// results under `proposer`, `randao_reveal`, `attestations` (one
+            /// per attestation) and `sync_aggregate`.
+            ///
+            /// `pubkey_provider` is a `PubkeyIndexMap` or a callable mapping
+            /// validator indices to pubkeys, `committees` a callable taking a
+            /// slot and committee index and returning the committee's
+            /// validator indices, and `sync_committee` the validator indices
+            /// of the sync committee. See the `validate` module documentation.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if an argument has the wrong length or a
+            /// pubkey is missing, or `PyTypeError` if `pubkey_provider` is
+            /// neither a `PubkeyIndexMap` nor callable.
+            #[pyo3(signature = (pubkey_provider, genesis_validators_root, fork_version, committees = None, sync_committee = None))]
+            pub fn verify_signatures<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                pubkey_provider: &pyo3::Bound<'py, pyo3::PyAny>,
+                genesis_validators_root: &pyo3::Bound<'py, pyo3::PyAny>,
+                fork_version: &pyo3::Bound<'py, pyo3::PyAny>,
+                committees: Option<&pyo3::Bound<'py, pyo3::PyAny>>,
+                sync_committee: Option<Vec<u64>>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyDict>> {
+                verify_signatures_impl(
+                    py,
+                    &self.inner,
+                    pubkey_provider,
+                    genesis_validators_root,
+                    fork_version,
+                    committees,
+                    sync_committee,
+                )
+            }
+
+            /// Check the invariants of the body that SSZ decoding does not
+            /// enforce, returning the broken ones as messages; an empty list
+            /// means the block passes.
+            pub fn check_structure(&self, py: pyo3::Python<'_>) -> Vec<String> {
+                py.detach(|| check_structure_impl(&self.inner.message))
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attestations, class = PyAttestationGnosis)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attester_slashings, class = PyAttesterSlashingGnosis)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.execution_payload.withdrawals, json)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Gnosis>(self.inner.message.slot, config)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.message.body.execution_payload, py)
+            }
+
+            /// Withdrawals with the GNO amount paid out by the withdrawal
+            /// contract, as dicts with `index`, `validator_index`,
+            /// `address`, `amount` (Gwei) and `amount_gno_wei`.
+            pub fn gno_withdrawals(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyList>> {
+                gno_withdrawals_impl(&self.inner.message.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.message.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                py.detach(|| signed_header_impl(&self.inner.message, self.inner.signature))
+            }
+
+            /// Attach blobs and KZG proofs, e.g. to restore block contents
+            /// saved with `strip_blobs`.
+            ///
+            /// With `verify`, the KZG proofs are checked against the
+            /// commitments, with `setup` or the default trusted setup.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob and one proof
+            /// per KZG commitment, a blob or proof has the wrong length, or
+            /// with `verify` a proof does not verify.
+            #[pyo3(signature = (blobs, proofs, verify = false, setup = None))]
+            pub fn with_blobs(
+                &self,
+                py: pyo3::Python<'_>,
+                blobs: Vec<Vec<u8>>,
+                proofs: Vec<Vec<u8>>,
+                verify: bool,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<[<PySignedBeaconBlockContentsGnosis>]> {
+                let inner = attach_blobs(self.inner.clone(), &blobs, &proofs)
+                    .map_err(PyValueError::new_err)?;
+
+                if verify {
+                    require_valid_proofs::<Gnosis>(
+                        py,
+                        &inner.signed_block.message.body.blob_kzg_commitments,
+                        &inner.blobs,
+                        &inner.kzg_proofs,
+                        setup.as_deref(),
+                        verify_blob_proofs,
+                    )?;
+                }
+
+                Ok([<PySignedBeaconBlockContentsGnosis>] { inner })
+            }
+
+            /// Return the signed blinded block, with the execution payload
+            /// replaced by its header. The signature stays valid.
+            pub fn blind(&self, py: pyo3::Python<'_>) -> [<PySignedBlindedBeaconBlockGnosis>] {
+                [<PySignedBlindedBeaconBlockGnosis>] {
+                    inner: py.detach(|| blind_block(&self.inner)),
+                }
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.message, &path)
+            }
+        }
+    );
+
+    define_decodable_pyclass_for_preset!(
+        [<PySignedBuilderBidGnosis>],
+        "ElectraSignedBuilderBidGnosis",
+        SignedBuilderBid<Gnosis>,
+        ssz_decoder = |bytes: &[u8]| SignedBuilderBid::<Gnosis>::from_ssz(&Phase::Electra, bytes),
+        extra_methods = {
+            /// The value of the bid to the proposer, in wei.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the bid is not an Electra bid.
+            pub fn value<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>> {
+                bid_value(py, &self.inner)
+            }
+
+            /// The pubkey of the builder that made the bid.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the bid is not an Electra bid.
+            pub fn pubkey(&self) -> pyo3::PyResult<String> {
+                bid_pubkey(&self.inner)
+            }
+
+            /// Verify the signature against `builder_pubkey` and the
+            /// `DOMAIN_APPLICATION_BUILDER` domain of `genesis_fork_version`.
+            ///
+            /// Relays re-sign the bids they serve, so `builder_pubkey` is the
+            /// relay's pubkey, which the bid also includes.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `builder_pubkey` is not
+            /// a valid pubkey, or `PyValueError` if `genesis_fork_version` is
+            /// not 4 bytes or the bid is not an Electra bid.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                builder_pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                genesis_fork_version: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_bid(py, &self.inner, builder_pubkey, genesis_fork_version)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyBeaconBlockContentsMainnet>],
+        "ElectraBeaconBlockContentsMainnet",
+        BeaconBlockContents<Mainnet>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                let root = py.detach(|| {
+                    signing_root(self.roots.block_root(&self.inner.block), domain)
+                });
+                Ok(format_hash_tree_root(&root))
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs, view)
+            }
+
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.kzg_proofs, view)
+            }
+
+            /// Verify the KZG proof of each blob against its commitment in the
+            /// block body.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob and one KZG
+            /// proof per KZG commitment, or a commitment or proof is not a
+            /// valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_blob_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                verify_contents_proofs::<Mainnet>(
+                    py,
+                    &self.inner.block.body.blob_kzg_commitments,
+                    &self.inner.blobs,
+                    &self.inner.kzg_proofs,
+                    setup.as_deref(),
+                    verify_blob_proofs,
+                )
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.attestations, class = PyAttestationMainnet)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.attester_slashings, class = PyAttesterSlashingMainnet)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.execution_payload.withdrawals, json)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Mainnet>(self.inner.block.slot, config)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.block.body.execution_requests, py)
+            }
+
+            pub fn header_dict(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                header_dict_impl(&self.inner.block, py)
+            }
+
+            pub fn header_tuple<'py>(&self, py: pyo3::Python<'py>) -> HeaderTuple<'py> {
+                header_tuple_impl(&self.inner.block, py)
+            }
+
+            pub fn sign(
+                &self,
+                signature: &str,
+            ) -> pyo3::PyResult<[<PySignedBeaconBlockContentsMainnet>]> {
+                let signature = parse_signature(signature)?;
+
+                let signed = SignedBeaconBlockContents::<Mainnet> {
+                    signed_block: SignedBeaconBlock::<Mainnet> {
+                        message: self.inner.block.clone(),
+                        signature,
+                    },
+                    kzg_proofs: self.inner.kzg_proofs.clone(),
+                    blobs: self.inner.blobs.clone(),
+                };
+
+                Ok([<PySignedBeaconBlockContentsMainnet>] { inner: signed })
+            }
+
+            pub fn block_hash_tree_root(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.block_root(&self.inner.block)))
+            }
+
+            /// Hash tree root of the block body, cached with the block root.
+            pub fn body_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.body_root(&self.inner.block)))
+            }
+
+            /// Hash tree root of the execution payload, cached with the block
+            /// root.
+            pub fn payload_root(&self, py: pyo3::Python<'_>) -> String {
+                let root = py.detach(|| {
+                    self.roots.field_root(&self.inner.block, EXECUTION_PAYLOAD)
+                });
+                format_hash_tree_root(&root)
+            }
+
+            pub fn replace_execution_payload_with_builder_bid(
+                &self,
+                signed_builder_bid: &[<PySignedBuilderBidMainnet>],
+            ) -> pyo3::PyResult<[<PyBlindedBeaconBlockMainnet>]> {
+                let blinded_block = blind_block_with_builder_bid(
+                    self.inner.block.clone(),
+                    &signed_builder_bid.inner,
+                )
+                .map_err(PyValueError::new_err)?;
+
+                Ok([<PyBlindedBeaconBlockMainnet>]::from(blinded_block))
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.block, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.block, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.block, &path)
+            }
+        },
+        mutable = (
+            [<PyMutableBeaconBlockContentsMainnet>],
+            "ElectraMutableBeaconBlockContentsMainnet",
+            {
+                /// Recompute the roots that do not depend on the state (the EL
+                /// `block_hash` of the execution payload), e.g. after editing a
+                /// test fixture, and return the fixed fields as
+                /// `{path: (old, new)}`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if the payload cannot be SSZ-encoded.
+                pub fn recompute_roots(
+                    &mut self,
+                    py: pyo3::Python<'_>,
+                ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                    let changes = recompute_roots_impl(&mut self.inner.block, "block.", py)?;
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                    Ok(changes)
+                }
+
+                /// Set the 32-byte graffiti, e.g. as rendered by
+                /// `render_graffiti`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+                pub fn set_graffiti(
+                    &mut self,
+                    graffiti: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.block.body.graffiti = parse_root(graffiti)?;
+                    self.roots.clear_field(GRAFFITI);
+                    Ok(())
+                }
+
+                /// Set the state root, e.g. as computed by the beacon node for
+                /// the patched block.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `state_root` is not 32 bytes.
+                pub fn set_state_root(
+                    &mut self,
+                    state_root: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.block.state_root = parse_root(state_root)?;
+                    self.roots.clear_block();
+                    Ok(())
+                }
+
+                /// Replace the execution payload, e.g. with one built by a
+                /// different execution client.
+                pub fn set_execution_payload(
+                    &mut self,
+                    payload: pyo3::PyRef<'_, [<PyExecutionPayloadMainnet>]>,
+                ) {
+                    self.inner.block.body.execution_payload = payload.inner.clone();
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                }
+            }
+        ),
+        root_cache = BlockRoots
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyBeaconBlockContentsGnosis>],
+        "ElectraBeaconBlockContentsGnosis",
+        BeaconBlockContents<Gnosis>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                let root = py.detach(|| {
+                    signing_root(self.roots.block_root(&self.inner.block), domain)
+                });
+                Ok(format_hash_tree_root(&root))
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs, view)
+            }
+
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.kzg_proofs, view)
+            }
+
+            /// Verify the KZG proof of each blob against its commitment in the
+            /// block body.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob and one KZG
+            /// proof per KZG commitment, or a commitment or proof is not a
+            /// valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_blob_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                verify_contents_proofs::<Gnosis>(
+                    py,
+                    &self.inner.block.body.blob_kzg_commitments,
+                    &self.inner.blobs,
+                    &self.inner.kzg_proofs,
+                    setup.as_deref(),
+                    verify_blob_proofs,
+                )
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.attestations, class = PyAttestationGnosis)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.attester_slashings, class = PyAttesterSlashingGnosis)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.execution_payload.withdrawals, json)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Gnosis>(self.inner.block.slot, config)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            /// Withdrawals with the GNO amount paid out by the withdrawal
+            /// contract, as dicts with `index`, `validator_index`,
+            /// `address`, `amount` (Gwei) and `amount_gno_wei`.
+            pub fn gno_withdrawals(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyList>> {
+                gno_withdrawals_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.block.body.execution_requests, py)
+            }
+
+            pub fn header_dict(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                header_dict_impl(&self.inner.block, py)
+            }
+
+            pub fn header_tuple<'py>(&self, py: pyo3::Python<'py>) -> HeaderTuple<'py> {
+                header_tuple_impl(&self.inner.block, py)
+            }
+
+            pub fn sign(
+                &self,
+                signature: &str,
+            ) -> pyo3::PyResult<[<PySignedBeaconBlockContentsGnosis>]> {
+                let signature = parse_signature(signature)?;
+
+                let signed = SignedBeaconBlockContents::<Gnosis> {
+                    signed_block: SignedBeaconBlock::<Gnosis> {
+                        message: self.inner.block.clone(),
+                        signature,
+                    },
+                    kzg_proofs: self.inner.kzg_proofs.clone(),
+                    blobs: self.inner.blobs.clone(),
+                };
+
+                Ok([<PySignedBeaconBlockContentsGnosis>] { inner: signed })
+            }
+
+            pub fn block_hash_tree_root(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.block_root(&self.inner.block)))
+            }
+
+            /// Hash tree root of the block body, cached with the block root.
+            pub fn body_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.body_root(&self.inner.block)))
+            }
+
+            /// Hash tree root of the execution payload, cached with the block
+            /// root.
+            pub fn payload_root(&self, py: pyo3::Python<'_>) -> String {
+                let root = py.detach(|| {
+                    self.roots.field_root(&self.inner.block, EXECUTION_PAYLOAD)
+                });
+                format_hash_tree_root(&root)
+            }
+
+            pub fn replace_execution_payload_with_builder_bid(
+                &self,
+                signed_builder_bid: &[<PySignedBuilderBidGnosis>],
+            ) -> pyo3::PyResult<[<PyBlindedBeaconBlockGnosis>]> {
+                let blinded_block = blind_block_with_builder_bid(
+                    self.inner.block.clone(),
+                    &signed_builder_bid.inner,
+                )
+                .map_err(PyValueError::new_err)?;
+
+                Ok([<PyBlindedBeaconBlockGnosis>]::from(blinded_block))
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.block, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.block, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.block, &path)
+            }
+        },
+        mutable = (
+            [<PyMutableBeaconBlockContentsGnosis>],
+            "ElectraMutableBeaconBlockContentsGnosis",
+            {
+                /// Recompute the roots that do not depend on the state (the EL
+                /// `block_hash` of the execution payload), e.g. after editing a
+                /// test fixture, and return the fixed fields as
+                /// `{path: (old, new)}`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if the payload cannot be SSZ-encoded.
+                pub fn recompute_roots(
+                    &mut self,
+                    py: pyo3::Python<'_>,
+                ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                    let changes = recompute_roots_impl(&mut self.inner.block, "block.", py)?;
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                    Ok(changes)
+                }
+
+                /// Set the 32-byte graffiti, e.g. as rendered by
+                /// `render_graffiti`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+                pub fn set_graffiti(
+                    &mut self,
+                    graffiti: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.block.body.graffiti = parse_root(graffiti)?;
+                    self.roots.clear_field(GRAFFITI);
+                    Ok(())
+                }
+
+                /// Set the state root, e.g. as computed by the beacon node for
+                /// the patched block.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `state_root` is not 32 bytes.
+                pub fn set_state_root(
+                    &mut self,
+                    state_root: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.block.state_root = parse_root(state_root)?;
+                    self.roots.clear_block();
+                    Ok(())
+                }
+
+                /// Replace the execution payload, e.g. with one built by a
+                /// different execution client.
+                pub fn set_execution_payload(
+                    &mut self,
+                    payload: pyo3::PyRef<'_, [<PyExecutionPayloadGnosis>]>,
+                ) {
+                    self.inner.block.body.execution_payload = payload.inner.clone();
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                }
+            }
+        ),
+        root_cache = BlockRoots
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyBeaconBlockContentsMinimal>],
+        "ElectraBeaconBlockContentsMinimal",
+        BeaconBlockContents<Minimal>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                let root = py.detach(|| {
+                    signing_root(self.roots.block_root(&self.inner.block), domain)
+                });
+                Ok(format_hash_tree_root(&root))
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs, view)
+            }
+
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.kzg_proofs, view)
+            }
+
+            /// Verify the KZG proof of each blob against its commitment in the
+            /// block body.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob and one KZG
+            /// proof per KZG commitment, or a commitment or proof is not a
+            /// valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_blob_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                verify_contents_proofs::<Minimal>(
+                    py,
+                    &self.inner.block.body.blob_kzg_commitments,
+                    &self.inner.blobs,
+                    &self.inner.kzg_proofs,
+                    setup.as_deref(),
+                    verify_blob_proofs,
+                )
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.attestations, class = PyAttestationMinimal)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.attester_slashings, class = PyAttesterSlashingMinimal)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.execution_payload.withdrawals, json)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Minimal>(self.inner.block.slot, config)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.block.body.execution_requests, py)
+            }
+
+            pub fn header_dict(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                header_dict_impl(&self.inner.block, py)
+            }
+
+            pub fn header_tuple<'py>(&self, py: pyo3::Python<'py>) -> HeaderTuple<'py> {
+                header_tuple_impl(&self.inner.block, py)
+            }
+
+            pub fn sign(
+                &self,
+                signature: &str,
+            ) -> pyo3::PyResult<[<PySignedBeaconBlockContentsMinimal>]> {
+                let signature = parse_signature(signature)?;
+
+                let signed = SignedBeaconBlockContents::<Minimal> {
+                    signed_block: SignedBeaconBlock::<Minimal> {
+                        message: self.inner.block.clone(),
+                        signature,
+                    },
+                    kzg_proofs: self.inner.kzg_proofs.clone(),
+                    blobs: self.inner.blobs.clone(),
+                };
+
+                Ok([<PySignedBeaconBlockContentsMinimal>] { inner: signed })
+            }
+
+            pub fn block_hash_tree_root(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.block_root(&self.inner.block)))
+            }
+
+            /// Hash tree root of the block body, cached with the block root.
+            pub fn body_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.body_root(&self.inner.block)))
+            }
+
+            /// Hash tree root of the execution payload, cached with the block
+            /// root.
+            pub fn payload_root(&self, py: pyo3::Python<'_>) -> String {
+                let root = py.detach(|| {
+                    self.roots.field_root(&self.inner.block, EXECUTION_PAYLOAD)
+                });
+                format_hash_tree_root(&root)
+            }
+
+            pub fn replace_execution_payload_with_builder_bid(
+                &self,
+                signed_builder_bid: &[<PySignedBuilderBidMinimal>],
+            ) -> pyo3::PyResult<[<PyBlindedBeaconBlockMinimal>]> {
+                let blinded_block = blind_block_with_builder_bid(
+                    self.inner.block.clone(),
+                    &signed_builder_bid.inner,
+                )
+                .map_err(PyValueError::new_err)?;
+
+                Ok([<PyBlindedBeaconBlockMinimal>]::from(blinded_block))
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.block, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.block, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.block, &path)
+            }
+        },
+        mutable = (
+            [<PyMutableBeaconBlockContentsMinimal>],
+            "ElectraMutableBeaconBlockContentsMinimal",
+            {
+                /// Recompute the roots that do not depend on the state (the EL
+                /// `block_hash` of the execution payload), e.g. after editing a
+                /// test fixture, and return the fixed fields as
+                /// `{path: (old, new)}`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if the payload cannot be SSZ-encoded.
+                pub fn recompute_roots(
+                    &mut self,
+                    py: pyo3::Python<'_>,
+                ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                    let changes = recompute_roots_impl(&mut self.inner.block, "block.", py)?;
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                    Ok(changes)
+                }
+
+                /// Set the 32-byte graffiti, e.g. as rendered by
+                /// `render_graffiti`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+                pub fn set_graffiti(
+                    &mut self,
+                    graffiti: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.block.body.graffiti = parse_root(graffiti)?;
+                    self.roots.clear_field(GRAFFITI);
+                    Ok(())
+                }
+
+                /// Set the state root, e.g. as computed by the beacon node for
+                /// the patched block.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `state_root` is not 32 bytes.
+                pub fn set_state_root(
+                    &mut self,
+                    state_root: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.block.state_root = parse_root(state_root)?;
+                    self.roots.clear_block();
+                    Ok(())
+                }
+
+                /// Replace the execution payload, e.g. with one built by a
+                /// different execution client.
+                pub fn set_execution_payload(
+                    &mut self,
+                    payload: pyo3::PyRef<'_, [<PyExecutionPayloadMinimal>]>,
+                ) {
+                    self.inner.block.body.execution_payload = payload.inner.clone();
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                }
+            }
+        ),
+        root_cache = BlockRoots
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PySignedBeaconBlockContentsMainnet>],
+        "ElectraSignedBeaconBlockContentsMainnet",
+        SignedBeaconBlockContents<Mainnet>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.signed_block.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.signed_block.message, self.inner.signed_block.signature, pubkey, domain)
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs, view)
+            }
+
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.kzg_proofs, view)
+            }
+
+            /// Verify the KZG proof of each blob against its commitment in the
+            /// block body.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob and one KZG
+            /// proof per KZG commitment, or a commitment or proof is not a
+            /// valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_blob_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                verify_contents_proofs::<Mainnet>(
+                    py,
+                    &self.inner.signed_block.message.body.blob_kzg_commitments,
+                    &self.inner.blobs,
+                    &self.inner.kzg_proofs,
+                    setup.as_deref(),
+                    verify_blob_proofs,
+                )
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.attestations, class = PyAttestationMainnet)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.attester_slashings, class = PyAttesterSlashingMainnet)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.execution_payload.withdrawals, json)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Mainnet>(self.inner.signed_block.message.slot, config)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.signed_block.message.body.execution_requests, py)
+            }
+
+            /// Return the signed block without its blobs and KZG proofs.
+            pub fn strip_blobs(&self) -> [<PySignedBeaconBlockMainnet>] {
+                [<PySignedBeaconBlockMainnet>] {
+                    inner: self.inner.signed_block.clone(),
+                }
+            }
+
+            /// Build the blob sidecar of each blob, with its KZG commitment
+            /// inclusion proof.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob and one KZG
+            /// proof per KZG commitment.
+            pub fn blob_sidecars(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<Vec<[<PyBlobSidecarMainnet>]>> {
+                let sidecars = py
+                    .detach(|| blob_sidecars(&self.inner))
+                    .map_err(PyValueError::new_err)?;
+                Ok(sidecars.into_iter().map(|inner| [<PyBlobSidecarMainnet>] { inner }).collect())
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.signed_block.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.signed_block.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.signed_block.message, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PySignedBeaconBlockContentsMinimal>],
+        "ElectraSignedBeaconBlockContentsMinimal",
+        SignedBeaconBlockContents<Minimal>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.signed_block.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.signed_block.message, self.inner.signed_block.signature, pubkey, domain)
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs, view)
+            }
+
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.kzg_proofs, view)
+            }
+
+            /// Verify the KZG proof of each blob against its commitment in the
+            /// block body.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob and one KZG
+            /// proof per KZG commitment, or a commitment or proof is not a
+            /// valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_blob_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                verify_contents_proofs::<Minimal>(
+                    py,
+                    &self.inner.signed_block.message.body.blob_kzg_commitments,
+                    &self.inner.blobs,
+                    &self.inner.kzg_proofs,
+                    setup.as_deref(),
+                    verify_blob_proofs,
+                )
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.attestations, class = PyAttestationMinimal)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.attester_slashings, class = PyAttesterSlashingMinimal)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.execution_payload.withdrawals, json)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Minimal>(self.inner.signed_block.message.slot, config)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.signed_block.message.body.execution_requests, py)
+            }
+
+            /// Return the signed block without its blobs and KZG proofs.
+            pub fn strip_blobs(&self) -> [<PySignedBeaconBlockMinimal>] {
+                [<PySignedBeaconBlockMinimal>] {
+                    inner: self.inner.signed_block.clone(),
+                }
+            }
+
+            /// Build the blob sidecar of each blob, with its KZG commitment
+            /// inclusion proof.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob and one KZG
+            /// proof per KZG commitment.
+            pub fn blob_sidecars(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<Vec<[<PyBlobSidecarMinimal>]>> {
+                let sidecars = py
+                    .detach(|| blob_sidecars(&self.inner))
+                    .map_err(PyValueError::new_err)?;
+                Ok(sidecars.into_iter().map(|inner| [<PyBlobSidecarMinimal>] { inner }).collect())
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.signed_block.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.signed_block.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.signed_block.message, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PySignedBeaconBlockContentsGnosis>],
+        "ElectraSignedBeaconBlockContentsGnosis",
+        SignedBeaconBlockContents<Gnosis>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.signed_block.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.signed_block.message, self.inner.signed_block.signature, pubkey, domain)
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs, view)
+            }
+
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.kzg_proofs, view)
+            }
+
+            /// Verify the KZG proof of each blob against its commitment in the
+            /// block body.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob and one KZG
+            /// proof per KZG commitment, or a commitment or proof is not a
+            /// valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_blob_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                verify_contents_proofs::<Gnosis>(
+                    py,
+                    &self.inner.signed_block.message.body.blob_kzg_commitments,
+                    &self.inner.blobs,
+                    &self.inner.kzg_proofs,
+                    setup.as_deref(),
+                    verify_blob_proofs,
+                )
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.attestations, class = PyAttestationGnosis)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.attester_slashings, class = PyAttesterSlashingGnosis)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.execution_payload.withdrawals, json)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Gnosis>(self.inner.signed_block.message.slot, config)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            /// Withdrawals with the GNO amount paid out by the withdrawal
+            /// contract, as dicts with `index`, `validator_index`,
+            /// `address`, `amount` (Gwei) and `amount_gno_wei`.
+            pub fn gno_withdrawals(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyList>> {
+                gno_withdrawals_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.signed_block.message.body.execution_requests, py)
+            }
+
+            /// Return the signed block without its blobs and KZG proofs.
+            pub fn strip_blobs(&self) -> [<PySignedBeaconBlockGnosis>] {
+                [<PySignedBeaconBlockGnosis>] {
+                    inner: self.inner.signed_block.clone(),
+                }
+            }
+
+            /// Build the blob sidecar of each blob, with its KZG commitment
+            /// inclusion proof.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob and one KZG
+            /// proof per KZG commitment.
+            pub fn blob_sidecars(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<Vec<[<PyBlobSidecarGnosis>]>> {
+                let sidecars = py
+                    .detach(|| blob_sidecars(&self.inner))
+                    .map_err(PyValueError::new_err)?;
+                Ok(sidecars.into_iter().map(|inner| [<PyBlobSidecarGnosis>] { inner }).collect())
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.signed_block.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.signed_block.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.signed_block.message, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyBlindedBeaconBlockMainnet>],
+        "ElectraBlindedBeaconBlockMainnet",
+        BlindedBeaconBlock<Mainnet>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                let root = py.detach(|| {
+                    signing_root(self.roots.block_root(&self.inner), domain)
+                });
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Parse a Beacon API `produceBlockV3` response.
+            ///
+            /// Returns a `ProducedBlock` whose `block` is an
+            /// `ElectraBlindedBeaconBlockMainnet` or an
+            /// `ElectraBeaconBlockContentsMainnet`, depending on the
+            /// `execution_payload_blinded` flag. `headers` are the response
+            /// headers, used for fields missing from the body.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the response is invalid or not for
+            /// the Electra fork.
+            #[staticmethod]
+            #[pyo3(signature = (json_bytes, headers = None))]
+            pub fn from_produce_block_v3(
+                py: pyo3::Python<'_>,
+                json_bytes: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+                headers: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<PyProducedBlock> {
+                let headers = Headers::from_py(headers)?;
+                let bytes = json_bytes.as_bytes().to_vec();
+                let produced = py
+                    .detach(|| parse_produce_block_v3::<Mainnet>(&bytes, &headers))
+                    .map_err(PyValueError::new_err)?;
+
+                produced.into_py(py, |data| match data {
+                    ProducedBlockData::Blinded(inner) => {
+                        Ok(pyo3::Py::new(py, Self::from(inner))?.into_any())
+                    }
+                    ProducedBlockData::Full(inner) => {
+                        Ok(pyo3::Py::new(py, [<PyBeaconBlockContentsMainnet>]::from(inner))?.into_any())
+                    }
+                })
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.attestations, class = PyAttestationMainnet)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.attester_slashings, class = PyAttesterSlashingMainnet)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Mainnet>(self.inner.slot, config)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.body.execution_requests, py)
+            }
+
+            pub fn header_dict(
                 &self,
                 py: pyo3::Python<'_>,
             ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
                 header_dict_impl(&self.inner, py)
             }
 
+            pub fn header_tuple<'py>(&self, py: pyo3::Python<'py>) -> HeaderTuple<'py> {
+                header_tuple_impl(&self.inner, py)
+            }
+
             pub fn sign(
                 &self,
                 signature: &str,
@@ -432,7 +2931,22 @@ paste! {
                 &self,
                 py: pyo3::Python<'_>,
             ) -> String {
-                py.detach(|| format_hash_tree_root(&self.inner.hash_tree_root()))
+                py.detach(|| format_hash_tree_root(&self.roots.block_root(&self.inner)))
+            }
+
+            /// Hash tree root of the block body, cached with the block root.
+            /// It is that of the full block body.
+            pub fn body_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.body_root(&self.inner)))
+            }
+
+            /// Hash tree root of the execution payload header, which is that
+            /// of the payload it stands for, cached with the block root.
+            pub fn payload_root(&self, py: pyo3::Python<'_>) -> String {
+                let root = py.detach(|| {
+                    self.roots.field_root(&self.inner, EXECUTION_PAYLOAD)
+                });
+                format_hash_tree_root(&root)
             }
 
             pub fn replace_execution_payload_with_builder_bid(
@@ -445,9 +2959,91 @@ paste! {
                 )
                 .map_err(PyValueError::new_err)?;
 
-                Ok([<PyBlindedBeaconBlockMainnet>] { inner: blinded_block })
+                Ok([<PyBlindedBeaconBlockMainnet>]::from(blinded_block))
             }
-        }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload_header", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner, &path)
+            }
+        },
+        mutable = (
+            [<PyMutableBlindedBeaconBlockMainnet>],
+            "ElectraMutableBlindedBeaconBlockMainnet",
+            {
+                /// Set the 32-byte graffiti, e.g. as rendered by
+                /// `render_graffiti`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+                pub fn set_graffiti(
+                    &mut self,
+                    graffiti: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.body.graffiti = parse_root(graffiti)?;
+                    self.roots.clear_field(GRAFFITI);
+                    Ok(())
+                }
+
+                /// Set the state root, e.g. as computed by the beacon node for
+                /// the patched block.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `state_root` is not 32 bytes.
+                pub fn set_state_root(
+                    &mut self,
+                    state_root: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.state_root = parse_root(state_root)?;
+                    self.roots.clear_block();
+                    Ok(())
+                }
+
+                /// Replace the execution payload header, e.g. with that of a
+                /// different builder bid.
+                pub fn set_execution_payload_header(
+                    &mut self,
+                    header: pyo3::PyRef<'_, [<PyExecutionPayloadHeaderMainnet>]>,
+                ) {
+                    self.inner.body.execution_payload_header = header.inner.clone();
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                }
+            }
+        ),
+        root_cache = BlockRoots
     );
 
     define_ssz_pyclass_for_preset!(
@@ -455,6 +3051,97 @@ paste! {
         "ElectraBlindedBeaconBlockGnosis",
         BlindedBeaconBlock<Gnosis>,
         extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                let root = py.detach(|| {
+                    signing_root(self.roots.block_root(&self.inner), domain)
+                });
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Parse a Beacon API `produceBlockV3` response.
+            ///
+            /// Returns a `ProducedBlock` whose `block` is an
+            /// `ElectraBlindedBeaconBlockGnosis` or an
+            /// `ElectraBeaconBlockContentsGnosis`, depending on the
+            /// `execution_payload_blinded` flag. `headers` are the response
+            /// headers, used for fields missing from the body.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the response is invalid or not for
+            /// the Electra fork.
+            #[staticmethod]
+            #[pyo3(signature = (json_bytes, headers = None))]
+            pub fn from_produce_block_v3(
+                py: pyo3::Python<'_>,
+                json_bytes: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+                headers: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<PyProducedBlock> {
+                let headers = Headers::from_py(headers)?;
+                let bytes = json_bytes.as_bytes().to_vec();
+                let produced = py
+                    .detach(|| parse_produce_block_v3::<Gnosis>(&bytes, &headers))
+                    .map_err(PyValueError::new_err)?;
+
+                produced.into_py(py, |data| match data {
+                    ProducedBlockData::Blinded(inner) => {
+                        Ok(pyo3::Py::new(py, Self::from(inner))?.into_any())
+                    }
+                    ProducedBlockData::Full(inner) => {
+                        Ok(pyo3::Py::new(py, [<PyBeaconBlockContentsGnosis>]::from(inner))?.into_any())
+                    }
+                })
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.attestations, class = PyAttestationGnosis)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.attester_slashings, class = PyAttesterSlashingGnosis)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Gnosis>(self.inner.slot, config)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.body.execution_requests, py)
+            }
+
             pub fn header_dict(
                 &self,
                 py: pyo3::Python<'_>,
@@ -462,6 +3149,10 @@ paste! {
                 header_dict_impl(&self.inner, py)
             }
 
+            pub fn header_tuple<'py>(&self, py: pyo3::Python<'py>) -> HeaderTuple<'py> {
+                header_tuple_impl(&self.inner, py)
+            }
+
             pub fn sign(
                 &self,
                 signature: &str,
@@ -480,7 +3171,22 @@ paste! {
                 &self,
                 py: pyo3::Python<'_>,
             ) -> String {
-                py.detach(|| format_hash_tree_root(&self.inner.hash_tree_root()))
+                py.detach(|| format_hash_tree_root(&self.roots.block_root(&self.inner)))
+            }
+
+            /// Hash tree root of the block body, cached with the block root.
+            /// It is that of the full block body.
+            pub fn body_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.body_root(&self.inner)))
+            }
+
+            /// Hash tree root of the execution payload header, which is that
+            /// of the payload it stands for, cached with the block root.
+            pub fn payload_root(&self, py: pyo3::Python<'_>) -> String {
+                let root = py.detach(|| {
+                    self.roots.field_root(&self.inner, EXECUTION_PAYLOAD)
+                });
+                format_hash_tree_root(&root)
             }
 
             pub fn replace_execution_payload_with_builder_bid(
@@ -493,9 +3199,91 @@ paste! {
                 )
                 .map_err(PyValueError::new_err)?;
 
-                Ok([<PyBlindedBeaconBlockGnosis>] { inner: blinded_block })
+                Ok([<PyBlindedBeaconBlockGnosis>]::from(blinded_block))
             }
-        }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload_header", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner, &path)
+            }
+        },
+        mutable = (
+            [<PyMutableBlindedBeaconBlockGnosis>],
+            "ElectraMutableBlindedBeaconBlockGnosis",
+            {
+                /// Set the 32-byte graffiti, e.g. as rendered by
+                /// `render_graffiti`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+                pub fn set_graffiti(
+                    &mut self,
+                    graffiti: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.body.graffiti = parse_root(graffiti)?;
+                    self.roots.clear_field(GRAFFITI);
+                    Ok(())
+                }
+
+                /// Set the state root, e.g. as computed by the beacon node for
+                /// the patched block.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `state_root` is not 32 bytes.
+                pub fn set_state_root(
+                    &mut self,
+                    state_root: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.state_root = parse_root(state_root)?;
+                    self.roots.clear_block();
+                    Ok(())
+                }
+
+                /// Replace the execution payload header, e.g. with that of a
+                /// different builder bid.
+                pub fn set_execution_payload_header(
+                    &mut self,
+                    header: pyo3::PyRef<'_, [<PyExecutionPayloadHeaderGnosis>]>,
+                ) {
+                    self.inner.body.execution_payload_header = header.inner.clone();
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                }
+            }
+        ),
+        root_cache = BlockRoots
     );
 
     define_ssz_pyclass_for_preset!(
@@ -503,6 +3291,97 @@ paste! {
         "ElectraBlindedBeaconBlockMinimal",
         BlindedBeaconBlock<Minimal>,
         extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                let root = py.detach(|| {
+                    signing_root(self.roots.block_root(&self.inner), domain)
+                });
+                Ok(format_hash_tree_root(&root))
+            }
+
+            /// Parse a Beacon API `produceBlockV3` response.
+            ///
+            /// Returns a `ProducedBlock` whose `block` is an
+            /// `ElectraBlindedBeaconBlockMinimal` or an
+            /// `ElectraBeaconBlockContentsMinimal`, depending on the
+            /// `execution_payload_blinded` flag. `headers` are the response
+            /// headers, used for fields missing from the body.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the response is invalid or not for
+            /// the Electra fork.
+            #[staticmethod]
+            #[pyo3(signature = (json_bytes, headers = None))]
+            pub fn from_produce_block_v3(
+                py: pyo3::Python<'_>,
+                json_bytes: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+                headers: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<PyProducedBlock> {
+                let headers = Headers::from_py(headers)?;
+                let bytes = json_bytes.as_bytes().to_vec();
+                let produced = py
+                    .detach(|| parse_produce_block_v3::<Minimal>(&bytes, &headers))
+                    .map_err(PyValueError::new_err)?;
+
+                produced.into_py(py, |data| match data {
+                    ProducedBlockData::Blinded(inner) => {
+                        Ok(pyo3::Py::new(py, Self::from(inner))?.into_any())
+                    }
+                    ProducedBlockData::Full(inner) => {
+                        Ok(pyo3::Py::new(py, [<PyBeaconBlockContentsMinimal>]::from(inner))?.into_any())
+                    }
+                })
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.attestations, class = PyAttestationMinimal)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.attester_slashings, class = PyAttesterSlashingMinimal)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Minimal>(self.inner.slot, config)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.body.execution_requests, py)
+            }
+
             pub fn header_dict(
                 &self,
                 py: pyo3::Python<'_>,
@@ -510,6 +3389,10 @@ paste! {
                 header_dict_impl(&self.inner, py)
             }
 
+            pub fn header_tuple<'py>(&self, py: pyo3::Python<'py>) -> HeaderTuple<'py> {
+                header_tuple_impl(&self.inner, py)
+            }
+
             pub fn sign(
                 &self,
                 signature: &str,
@@ -528,7 +3411,22 @@ paste! {
                 &self,
                 py: pyo3::Python<'_>,
             ) -> String {
-                py.detach(|| format_hash_tree_root(&self.inner.hash_tree_root()))
+                py.detach(|| format_hash_tree_root(&self.roots.block_root(&self.inner)))
+            }
+
+            /// Hash tree root of the block body, cached with the block root.
+            /// It is that of the full block body.
+            pub fn body_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.body_root(&self.inner)))
+            }
+
+            /// Hash tree root of the execution payload header, which is that
+            /// of the payload it stands for, cached with the block root.
+            pub fn payload_root(&self, py: pyo3::Python<'_>) -> String {
+                let root = py.detach(|| {
+                    self.roots.field_root(&self.inner, EXECUTION_PAYLOAD)
+                });
+                format_hash_tree_root(&root)
             }
 
             pub fn replace_execution_payload_with_builder_bid(
@@ -541,25 +3439,512 @@ paste! {
                 )
                 .map_err(PyValueError::new_err)?;
 
-                Ok([<PyBlindedBeaconBlockMinimal>] { inner: blinded_block })
+                Ok([<PyBlindedBeaconBlockMinimal>]::from(blinded_block))
             }
-        }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload_header", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner, &path)
+            }
+        },
+        mutable = (
+            [<PyMutableBlindedBeaconBlockMinimal>],
+            "ElectraMutableBlindedBeaconBlockMinimal",
+            {
+                /// Set the 32-byte graffiti, e.g. as rendered by
+                /// `render_graffiti`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+                pub fn set_graffiti(
+                    &mut self,
+                    graffiti: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.body.graffiti = parse_root(graffiti)?;
+                    self.roots.clear_field(GRAFFITI);
+                    Ok(())
+                }
+
+                /// Set the state root, e.g. as computed by the beacon node for
+                /// the patched block.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `state_root` is not 32 bytes.
+                pub fn set_state_root(
+                    &mut self,
+                    state_root: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.state_root = parse_root(state_root)?;
+                    self.roots.clear_block();
+                    Ok(())
+                }
+
+                /// Replace the execution payload header, e.g. with that of a
+                /// different builder bid.
+                pub fn set_execution_payload_header(
+                    &mut self,
+                    header: pyo3::PyRef<'_, [<PyExecutionPayloadHeaderMinimal>]>,
+                ) {
+                    self.inner.body.execution_payload_header = header.inner.clone();
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                }
+            }
+        ),
+        root_cache = BlockRoots
     );
     define_ssz_pyclass_for_preset!(
         [<PySignedBlindedBeaconBlockMainnet>],
         "ElectraSignedBlindedBeaconBlockMainnet",
-        SignedBlindedBeaconBlock<Mainnet>
+        SignedBlindedBeaconBlock<Mainnet>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.message, self.inner.signature, pubkey, domain)
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attestations, class = PyAttestationMainnet)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attester_slashings, class = PyAttesterSlashingMainnet)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Mainnet>(self.inner.message.slot, config)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                py.detach(|| signed_header_impl(&self.inner.message, self.inner.signature))
+            }
+
+            /// Return the signed block contents of the block from the
+            /// SSZ-encoded execution payload and blobs bundle the relay
+            /// returned for it.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if an encoding is invalid, the payload
+            /// does not match the payload header, or the bundle does not match
+            /// the KZG commitments.
+            pub fn unblind(
+                &self,
+                py: pyo3::Python<'_>,
+                execution_payload_ssz: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+                blobs_bundle_ssz: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+            ) -> pyo3::PyResult<[<PySignedBeaconBlockContentsMainnet>]> {
+                let execution_payload_ssz = execution_payload_ssz.as_bytes();
+                let blobs_bundle_ssz = blobs_bundle_ssz.as_bytes();
+                py.detach(|| unblind(&self.inner, execution_payload_ssz, blobs_bundle_ssz))
+                    .map(|inner| [<PySignedBeaconBlockContentsMainnet>] { inner })
+                    .map_err(PyValueError::new_err)
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload_header", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.message, &path)
+            }
+        }
     );
     define_ssz_pyclass_for_preset!(
         [<PySignedBlindedBeaconBlockMinimal>],
         "ElectraSignedBlindedBeaconBlockMinimal",
-        SignedBlindedBeaconBlock<Minimal>
+        SignedBlindedBeaconBlock<Minimal>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.message, self.inner.signature, pubkey, domain)
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attestations, class = PyAttestationMinimal)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attester_slashings, class = PyAttesterSlashingMinimal)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Minimal>(self.inner.message.slot, config)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                py.detach(|| signed_header_impl(&self.inner.message, self.inner.signature))
+            }
+
+            /// Return the signed block contents of the block from the
+            /// SSZ-encoded execution payload and blobs bundle the relay
+            /// returned for it.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if an encoding is invalid, the payload
+            /// does not match the payload header, or the bundle does not match
+            /// the KZG commitments.
+            pub fn unblind(
+                &self,
+                py: pyo3::Python<'_>,
+                execution_payload_ssz: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+                blobs_bundle_ssz: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+            ) -> pyo3::PyResult<[<PySignedBeaconBlockContentsMinimal>]> {
+                let execution_payload_ssz = execution_payload_ssz.as_bytes();
+                let blobs_bundle_ssz = blobs_bundle_ssz.as_bytes();
+                py.detach(|| unblind(&self.inner, execution_payload_ssz, blobs_bundle_ssz))
+                    .map(|inner| [<PySignedBeaconBlockContentsMinimal>] { inner })
+                    .map_err(PyValueError::new_err)
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload_header", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.message, &path)
+            }
+        }
     );
 
     define_ssz_pyclass_for_preset!(
         [<PySignedBlindedBeaconBlockGnosis>],
         "ElectraSignedBlindedBeaconBlockGnosis",
-        SignedBlindedBeaconBlock<Gnosis>
+        SignedBlindedBeaconBlock<Gnosis>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.message, self.inner.signature, pubkey, domain)
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attestations, class = PyAttestationGnosis)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attester_slashings, class = PyAttesterSlashingGnosis)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Gnosis>(self.inner.message.slot, config)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                py.detach(|| signed_header_impl(&self.inner.message, self.inner.signature))
+            }
+
+            /// Return the signed block contents of the block from the
+            /// SSZ-encoded execution payload and blobs bundle the relay
+            /// returned for it.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if an encoding is invalid, the payload
+            /// does not match the payload header, or the bundle does not match
+            /// the KZG commitments.
+            pub fn unblind(
+                &self,
+                py: pyo3::Python<'_>,
+                execution_payload_ssz: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+                blobs_bundle_ssz: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+            ) -> pyo3::PyResult<[<PySignedBeaconBlockContentsGnosis>]> {
+                let execution_payload_ssz = execution_payload_ssz.as_bytes();
+                let blobs_bundle_ssz = blobs_bundle_ssz.as_bytes();
+                py.detach(|| unblind(&self.inner, execution_payload_ssz, blobs_bundle_ssz))
+                    .map(|inner| [<PySignedBeaconBlockContentsGnosis>] { inner })
+                    .map_err(PyValueError::new_err)
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload_header", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.message, &path)
+            }
+        }
     );
 }
 
@@ -573,6 +3958,8 @@ pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PySignedBeaconBlockMainnet>()?;
     m.add_class::<PySignedBuilderBidMainnet>()?;
     m.add_class::<PyBeaconBlockContentsMainnet>()?;
+    m.add_class::<PyMutableBeaconBlockContentsMainnet>()?;
+    m.add_class::<PyMutableBlindedBeaconBlockMainnet>()?;
     m.add_class::<PySignedBeaconBlockContentsMainnet>()?;
     m.add_class::<PyBlindedBeaconBlockMainnet>()?;
     m.add_class::<PySignedBlindedBeaconBlockMainnet>()?;
@@ -581,6 +3968,8 @@ pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PySignedBeaconBlockMinimal>()?;
     m.add_class::<PySignedBuilderBidMinimal>()?;
     m.add_class::<PyBeaconBlockContentsMinimal>()?;
+    m.add_class::<PyMutableBeaconBlockContentsMinimal>()?;
+    m.add_class::<PyMutableBlindedBeaconBlockMinimal>()?;
     m.add_class::<PySignedBeaconBlockContentsMinimal>()?;
     m.add_class::<PyBlindedBeaconBlockMinimal>()?;
     m.add_class::<PySignedBlindedBeaconBlockMinimal>()?;
@@ -589,6 +3978,8 @@ pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PySignedBeaconBlockGnosis>()?;
     m.add_class::<PySignedBuilderBidGnosis>()?;
     m.add_class::<PyBeaconBlockContentsGnosis>()?;
+    m.add_class::<PyMutableBeaconBlockContentsGnosis>()?;
+    m.add_class::<PyMutableBlindedBeaconBlockGnosis>()?;
     m.add_class::<PySignedBeaconBlockContentsGnosis>()?;
     m.add_class::<PyBlindedBeaconBlockGnosis>()?;
     m.add_class::<PySignedBlindedBeaconBlockGnosis>()?;
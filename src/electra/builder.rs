@@ -0,0 +1,568 @@
+//! Incremental assembly of Electra block bodies.
+//!
+//! `BlockBodyBuilder` collects the parts of a block body one at a time and
+//! checks them together in `build()`, which returns block contents if a full
+//! execution payload was set and a blinded block if a payload header was set.
+//! Slashings, deposits, voluntary exits, BLS to execution changes and
+//! execution requests are left empty.
+
+use std::sync::Arc;
+
+use grandine_bls::SignatureBytes;
+use grandine_ssz::{BitVector, ContiguousList, SszReadDefault as _, H256};
+use grandine_types::altair::containers::SyncAggregate;
+use grandine_types::deneb::containers::{ExecutionPayload, ExecutionPayloadHeader};
+use grandine_types::deneb::primitives::KzgCommitment;
+use grandine_types::electra::containers::{
+    Attestation, BeaconBlock, BeaconBlockBody, BlindedBeaconBlock, BlindedBeaconBlockBody,
+};
+use grandine_types::phase0::containers::Eth1Data;
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use typenum::Unsigned as _;
+
+use crate::bls::parse_signature;
+use crate::config::{ChainConfig, PyConfig};
+use crate::convert::parse_root;
+use crate::electra::attestation::{
+    PyAttestationGnosis, PyAttestationMainnet, PyAttestationMinimal,
+};
+use crate::electra::block::{
+    blob_lists, decode_each, BeaconBlockContents, PyBeaconBlockContentsGnosis,
+    PyBeaconBlockContentsMainnet, PyBeaconBlockContentsMinimal, PyBlindedBeaconBlockGnosis,
+    PyBlindedBeaconBlockMainnet, PyBlindedBeaconBlockMinimal,
+};
+use crate::electra::execution::{
+    PyExecutionPayloadGnosis, PyExecutionPayloadHeaderGnosis, PyExecutionPayloadHeaderMainnet,
+    PyExecutionPayloadHeaderMinimal, PyExecutionPayloadMainnet, PyExecutionPayloadMinimal,
+};
+use crate::presets::PresetKind;
+use crate::Gnosis;
+
+/// `MIN_ATTESTATION_INCLUSION_DELAY`, which is the same in all presets.
+const MIN_ATTESTATION_INCLUSION_DELAY: u64 = 1;
+
+/// The compressed point at infinity, the signature of an empty sync aggregate.
+const INFINITY_SIGNATURE: [u8; 96] = {
+    let mut bytes = [0; 96];
+    bytes[0] = 0xc0;
+    bytes
+};
+
+enum Payload<P: Preset> {
+    Full(Box<ExecutionPayload<P>>),
+    Header(Box<ExecutionPayloadHeader<P>>),
+}
+
+/// A block built from the collected parts.
+enum Built<P: Preset> {
+    Contents(Box<BeaconBlockContents<P>>),
+    Blinded(Box<BlindedBeaconBlock<P>>),
+}
+
+/// The fields of a block that are set by the block header arguments of
+/// `build()`.
+struct Header {
+    slot: u64,
+    proposer_index: u64,
+    parent_root: H256,
+    state_root: H256,
+}
+
+struct BodyParts<P: Preset> {
+    randao_reveal: Option<SignatureBytes>,
+    eth1_data: Eth1Data,
+    graffiti: H256,
+    attestations: Vec<Attestation<P>>,
+    sync_aggregate: Option<SyncAggregate<P>>,
+    payload: Option<Payload<P>>,
+    blob_kzg_commitments: Vec<KzgCommitment>,
+    blobs: Vec<Vec<u8>>,
+    kzg_proofs: Vec<Vec<u8>>,
+}
+
+impl<P: Preset> Default for BodyParts<P> {
+    fn default() -> Self {
+        Self {
+            randao_reveal: None,
+            eth1_data: Eth1Data::default(),
+            graffiti: H256::zero(),
+            attestations: Vec::new(),
+            sync_aggregate: None,
+            payload: None,
+            blob_kzg_commitments: Vec::new(),
+            blobs: Vec::new(),
+            kzg_proofs: Vec::new(),
+        }
+    }
+}
+
+impl<P: Preset> BodyParts<P> {
+    fn set_sync_aggregate(&mut self, bits: &[u8], signature: &str) -> PyResult<()> {
+        let sync_committee_bits = BitVector::from_ssz_default(bits)
+            .map_err(|e| PyValueError::new_err(format!("Invalid sync committee bits: {e}")))?;
+
+        self.sync_aggregate = Some(SyncAggregate {
+            sync_committee_bits,
+            sync_committee_signature: parse_signature(signature)?,
+        });
+
+        Ok(())
+    }
+
+    fn check_attestations(&self, slot: u64) -> Result<(), String> {
+        let slots_per_epoch = P::SlotsPerEpoch::U64;
+        let previous_epoch = (slot / slots_per_epoch).saturating_sub(1);
+
+        for (index, attestation) in self.attestations.iter().enumerate() {
+            let data = &attestation.data;
+
+            if data.index != 0 {
+                return Err(format!(
+                    "Attestation {index} has committee index {}, which must be 0 from Electra on",
+                    data.index
+                ));
+            }
+
+            let epoch = data.slot / slots_per_epoch;
+
+            if data.target.epoch != epoch {
+                return Err(format!(
+                    "Attestation {index} for slot {} targets epoch {}, expected {epoch}",
+                    data.slot, data.target.epoch
+                ));
+            }
+
+            if data.slot + MIN_ATTESTATION_INCLUSION_DELAY > slot {
+                return Err(format!(
+                    "Attestation {index} for slot {} cannot be included at slot {slot}",
+                    data.slot
+                ));
+            }
+
+            if data.target.epoch < previous_epoch {
+                return Err(format!(
+                    "Attestation {index} targets epoch {}, which is before the previous epoch \
+                     {previous_epoch}",
+                    data.target.epoch
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn build(&self, header: Header, max_blobs_per_block: u64) -> Result<Built<P>, String> {
+        let randao_reveal = self.randao_reveal.ok_or("Missing randao reveal")?;
+        let payload = self
+            .payload
+            .as_ref()
+            .ok_or("Missing execution payload or payload header")?;
+
+        self.check_attestations(header.slot)?;
+
+        let commitments = self.blob_kzg_commitments.len();
+
+        if commitments as u64 > max_blobs_per_block {
+            return Err(format!(
+                "{commitments} KZG commitments exceed MAX_BLOBS_PER_BLOCK_ELECTRA \
+                 ({max_blobs_per_block})"
+            ));
+        }
+
+        let attestations = ContiguousList::try_from_iter(self.attestations.iter().cloned())
+            .map_err(|e| format!("Too many attestations: {e}"))?;
+        let blob_kzg_commitments =
+            ContiguousList::try_from_iter(self.blob_kzg_commitments.iter().copied())
+                .map_err(|e| format!("Too many KZG commitments: {e}"))?;
+
+        let sync_aggregate = match &self.sync_aggregate {
+            Some(sync_aggregate) => sync_aggregate.clone(),
+            None => SyncAggregate {
+                sync_committee_bits: BitVector::default(),
+                sync_committee_signature: SignatureBytes::from_ssz_default(&INFINITY_SIGNATURE)
+                    .map_err(|e| e.to_string())?,
+            },
+        };
+
+        let Header {
+            slot,
+            proposer_index,
+            parent_root,
+            state_root,
+        } = header;
+
+        match payload {
+            Payload::Full(execution_payload) => {
+                let (kzg_proofs, blobs) = blob_lists::<P>(
+                    self.blob_kzg_commitments.len(),
+                    &self.blobs,
+                    &self.kzg_proofs,
+                )?;
+
+                let block = BeaconBlock {
+                    slot,
+                    proposer_index,
+                    parent_root,
+                    state_root,
+                    body: BeaconBlockBody {
+                        randao_reveal,
+                        eth1_data: self.eth1_data,
+                        graffiti: self.graffiti,
+                        attestations,
+                        sync_aggregate,
+                        execution_payload: execution_payload.as_ref().clone(),
+                        blob_kzg_commitments,
+                        ..BeaconBlockBody::default()
+                    },
+                };
+
+                Ok(Built::Contents(Box::new(BeaconBlockContents {
+                    block,
+                    kzg_proofs,
+                    blobs,
+                })))
+            }
+            Payload::Header(execution_payload_header) => {
+                if !self.blobs.is_empty() {
+                    return Err("Blinded blocks cannot carry blobs".to_owned());
+                }
+
+                Ok(Built::Blinded(Box::new(BlindedBeaconBlock {
+                    slot,
+                    proposer_index,
+                    parent_root,
+                    state_root,
+                    body: BlindedBeaconBlockBody {
+                        randao_reveal,
+                        eth1_data: self.eth1_data,
+                        graffiti: self.graffiti,
+                        attestations,
+                        sync_aggregate,
+                        execution_payload_header: execution_payload_header.as_ref().clone(),
+                        blob_kzg_commitments,
+                        ..BlindedBeaconBlockBody::default()
+                    },
+                })))
+            }
+        }
+    }
+}
+
+enum Parts {
+    Mainnet(Box<BodyParts<Mainnet>>),
+    Minimal(Box<BodyParts<Minimal>>),
+    Gnosis(Box<BodyParts<Gnosis>>),
+}
+
+/// Runs `$body` with `$parts` bound to the parts of whichever preset the
+/// builder uses.
+macro_rules! each_preset {
+    ($self:expr, $parts:ident => $body:expr) => {
+        match &mut $self.parts {
+            Parts::Mainnet($parts) => $body,
+            Parts::Minimal($parts) => $body,
+            Parts::Gnosis($parts) => $body,
+        }
+    };
+}
+
+/// Clones the inner value of a Python object of the given class.
+macro_rules! inner_of {
+    ($value:expr, $class:ty, $name:literal) => {
+        match $value.cast::<$class>() {
+            Ok(value) => value.borrow().inner.clone(),
+            Err(_) => {
+                return Err(PyTypeError::new_err(format!(
+                    concat!("Expected ", $name, ", got {}"),
+                    $value.get_type().name()?
+                )))
+            }
+        }
+    };
+}
+
+/// Assembles an Electra block body piece by piece.
+#[pyclass(name = "BlockBodyBuilder")]
+pub struct PyBlockBodyBuilder {
+    parts: Parts,
+    max_blobs_per_block: u64,
+}
+
+#[pymethods]
+impl PyBlockBodyBuilder {
+    /// Create an empty builder for the preset and blob limit of `config`
+    /// (Mainnet by default).
+    #[new]
+    #[pyo3(signature = (config = None))]
+    fn py_new(config: Option<PyRef<'_, PyConfig>>) -> Self {
+        let config = config.map_or_else(
+            || Arc::new(ChainConfig::mainnet()),
+            |config| config.inner.clone(),
+        );
+
+        let parts = match config.preset {
+            PresetKind::Mainnet => Parts::Mainnet(Box::default()),
+            PresetKind::Minimal => Parts::Minimal(Box::default()),
+            PresetKind::Gnosis => Parts::Gnosis(Box::default()),
+        };
+
+        Self {
+            parts,
+            max_blobs_per_block: config.max_blobs_per_block_electra,
+        }
+    }
+
+    /// Set the randao reveal from a hex-encoded signature.
+    ///
+    /// # Errors
+    /// Returns a `BlsEncodingError` subclass if `signature` is not a valid
+    /// signature.
+    fn set_randao_reveal(&mut self, signature: &str) -> PyResult<()> {
+        let signature = parse_signature(signature)?;
+        each_preset!(self, parts => parts.randao_reveal = Some(signature));
+        Ok(())
+    }
+
+    /// Set the eth1 data vote.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if a root is not 32 bytes.
+    fn set_eth1_data(
+        &mut self,
+        deposit_root: &Bound<'_, PyAny>,
+        deposit_count: u64,
+        block_hash: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        let eth1_data = Eth1Data {
+            deposit_root: parse_root(deposit_root)?,
+            deposit_count,
+            block_hash: parse_root(block_hash)?,
+        };
+        each_preset!(self, parts => parts.eth1_data = eth1_data);
+        Ok(())
+    }
+
+    /// Set the 32-byte graffiti.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+    fn set_graffiti(&mut self, graffiti: &Bound<'_, PyAny>) -> PyResult<()> {
+        let graffiti = parse_root(graffiti)?;
+        each_preset!(self, parts => parts.graffiti = graffiti);
+        Ok(())
+    }
+
+    /// Set the sync aggregate from its SSZ-encoded participation bits and
+    /// hex-encoded signature.
+    ///
+    /// Without a sync aggregate the block carries an empty one.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the bits do not match the sync committee
+    /// size, or a `BlsEncodingError` subclass if `signature` is invalid.
+    fn set_sync_aggregate(&mut self, bits: &[u8], signature: &str) -> PyResult<()> {
+        each_preset!(self, parts => parts.set_sync_aggregate(bits, signature))
+    }
+
+    /// Append an attestation of the builder's preset.
+    ///
+    /// # Errors
+    /// Returns `PyTypeError` if `attestation` is not an Electra attestation
+    /// of the builder's preset.
+    fn add_attestation(&mut self, attestation: &Bound<'_, PyAny>) -> PyResult<()> {
+        match &mut self.parts {
+            Parts::Mainnet(parts) => parts.attestations.push(inner_of!(
+                attestation,
+                PyAttestationMainnet,
+                "ElectraAttestationMainnet"
+            )),
+            Parts::Minimal(parts) => parts.attestations.push(inner_of!(
+                attestation,
+                PyAttestationMinimal,
+                "ElectraAttestationMinimal"
+            )),
+            Parts::Gnosis(parts) => parts.attestations.push(inner_of!(
+                attestation,
+                PyAttestationGnosis,
+                "ElectraAttestationGnosis"
+            )),
+        }
+
+        Ok(())
+    }
+
+    /// Set a full execution payload, replacing any payload or header set
+    /// before. `build()` then returns block contents.
+    ///
+    /// # Errors
+    /// Returns `PyTypeError` if `payload` is not an execution payload of the
+    /// builder's preset.
+    fn set_execution_payload(&mut self, payload: &Bound<'_, PyAny>) -> PyResult<()> {
+        match &mut self.parts {
+            Parts::Mainnet(parts) => {
+                let payload = inner_of!(
+                    payload,
+                    PyExecutionPayloadMainnet,
+                    "ElectraExecutionPayloadMainnet"
+                );
+                parts.payload = Some(Payload::Full(Box::new(payload)));
+            }
+            Parts::Minimal(parts) => {
+                let payload = inner_of!(
+                    payload,
+                    PyExecutionPayloadMinimal,
+                    "ElectraExecutionPayloadMinimal"
+                );
+                parts.payload = Some(Payload::Full(Box::new(payload)));
+            }
+            Parts::Gnosis(parts) => {
+                let payload = inner_of!(
+                    payload,
+                    PyExecutionPayloadGnosis,
+                    "ElectraExecutionPayloadGnosis"
+                );
+                parts.payload = Some(Payload::Full(Box::new(payload)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set an execution payload header, replacing any payload or header set
+    /// before. `build()` then returns a blinded block.
+    ///
+    /// # Errors
+    /// Returns `PyTypeError` if `header` is not an execution payload header
+    /// of the builder's preset.
+    fn set_execution_payload_header(&mut self, header: &Bound<'_, PyAny>) -> PyResult<()> {
+        match &mut self.parts {
+            Parts::Mainnet(parts) => {
+                let header = inner_of!(
+                    header,
+                    PyExecutionPayloadHeaderMainnet,
+                    "ElectraExecutionPayloadHeaderMainnet"
+                );
+                parts.payload = Some(Payload::Header(Box::new(header)));
+            }
+            Parts::Minimal(parts) => {
+                let header = inner_of!(
+                    header,
+                    PyExecutionPayloadHeaderMinimal,
+                    "ElectraExecutionPayloadHeaderMinimal"
+                );
+                parts.payload = Some(Payload::Header(Box::new(header)));
+            }
+            Parts::Gnosis(parts) => {
+                let header = inner_of!(
+                    header,
+                    PyExecutionPayloadHeaderGnosis,
+                    "ElectraExecutionPayloadHeaderGnosis"
+                );
+                parts.payload = Some(Payload::Header(Box::new(header)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set the blob KZG commitments, each 48 bytes.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if a commitment is not 48 bytes.
+    fn set_blob_kzg_commitments(&mut self, commitments: Vec<Vec<u8>>) -> PyResult<()> {
+        let commitments = decode_each::<KzgCommitment>(&commitments, "KZG commitment")
+            .map_err(PyValueError::new_err)?;
+        each_preset!(self, parts => parts.blob_kzg_commitments = commitments);
+        Ok(())
+    }
+
+    /// Set the blobs and KZG proofs returned with block contents, one of each
+    /// per KZG commitment. Lengths are checked by `build()`.
+    fn set_blobs(&mut self, blobs: Vec<Vec<u8>>, kzg_proofs: Vec<Vec<u8>>) {
+        each_preset!(self, parts => {
+            parts.blobs = blobs;
+            parts.kzg_proofs = kzg_proofs;
+        });
+    }
+
+    /// Assemble the block.
+    ///
+    /// Returns `ElectraBeaconBlockContents*` if a full payload was set and
+    /// `ElectraBlindedBeaconBlock*` if a payload header was set.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the randao reveal or the payload is missing,
+    /// an attestation cannot be included at `slot`, has a committee index
+    /// other than 0 or does not target the epoch of its slot, there are more
+    /// KZG commitments than the `MAX_BLOBS_PER_BLOCK_ELECTRA` of the config,
+    /// a list exceeds its limit, or the blobs or KZG proofs do not match the
+    /// commitments.
+    #[pyo3(signature = (slot, proposer_index, parent_root, state_root = None))]
+    fn build(
+        &self,
+        py: Python<'_>,
+        slot: u64,
+        proposer_index: u64,
+        parent_root: &Bound<'_, PyAny>,
+        state_root: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        let header = Header {
+            slot,
+            proposer_index,
+            parent_root: parse_root(parent_root)?,
+            state_root: state_root.map(parse_root).transpose()?.unwrap_or_default(),
+        };
+
+        macro_rules! build {
+            ($parts:expr, $contents:ident, $blinded:ident) => {
+                match py
+                    .detach(|| $parts.build(header, self.max_blobs_per_block))
+                    .map_err(PyValueError::new_err)?
+                {
+                    Built::Contents(inner) => Ok(Py::new(py, $contents::from(*inner))?.into_any()),
+                    Built::Blinded(inner) => Ok(Py::new(py, $blinded::from(*inner))?.into_any()),
+                }
+            };
+        }
+
+        match &self.parts {
+            Parts::Mainnet(parts) => build!(
+                parts,
+                PyBeaconBlockContentsMainnet,
+                PyBlindedBeaconBlockMainnet
+            ),
+            Parts::Minimal(parts) => build!(
+                parts,
+                PyBeaconBlockContentsMinimal,
+                PyBlindedBeaconBlockMinimal
+            ),
+            Parts::Gnosis(parts) => build!(
+                parts,
+                PyBeaconBlockContentsGnosis,
+                PyBlindedBeaconBlockGnosis
+            ),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        let preset = match &self.parts {
+            Parts::Mainnet(_) => PresetKind::Mainnet,
+            Parts::Minimal(_) => PresetKind::Minimal,
+            Parts::Gnosis(_) => PresetKind::Gnosis,
+        };
+
+        format!("BlockBodyBuilder(preset={:?})", preset.name())
+    }
+}
+
+/// Registers the block body builder class with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyBlockBodyBuilder>()?;
+    Ok(())
+}
@@ -0,0 +1,244 @@
+//! Comparison of locally built block contents with a builder bid.
+//!
+//! `compare_candidates` reports the differences a proposer weighs, or wants
+//! to log, when choosing between its own execution payload and a relay's
+//! bid: the value delta, the execution payload header fields that differ,
+//! and the blob KZG commitments only one of the candidates includes. Header
+//! fields are compared in their Beacon API JSON encoding, which the report
+//! also uses for the differing values.
+
+use grandine_builder_api::combined::SignedBuilderBid;
+use grandine_types::deneb::containers::ExecutionPayloadHeader;
+use grandine_types::deneb::primitives::KzgCommitment;
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyInt, PyString};
+use serde_json::Value;
+
+use crate::config::{resolve_config, PyConfig};
+use crate::electra::block::{
+    BeaconBlockContents, PyBeaconBlockContentsGnosis, PyBeaconBlockContentsMainnet,
+    PyBeaconBlockContentsMinimal, PySignedBuilderBidGnosis, PySignedBuilderBidMainnet,
+    PySignedBuilderBidMinimal,
+};
+use crate::electra::execution::payload_header;
+use crate::electra::produce::PyProducedBlock;
+use crate::hex_format::format_hex;
+use crate::Gnosis;
+
+/// The differences between local block contents and a builder bid.
+struct Comparison {
+    bid_value: String,
+    header_differences: Vec<(String, String, String)>,
+    local_commitments: usize,
+    bid_commitments: usize,
+    only_local: Vec<KzgCommitment>,
+    only_bid: Vec<KzgCommitment>,
+}
+
+/// The fields of an execution payload header in their JSON encoding.
+fn header_fields<P: Preset>(
+    header: &ExecutionPayloadHeader<P>,
+) -> Result<serde_json::Map<String, Value>, String> {
+    match serde_json::to_value(header).map_err(|e| e.to_string())? {
+        Value::Object(fields) => Ok(fields),
+        _ => Err("Execution payload header is not a JSON object".to_owned()),
+    }
+}
+
+fn json_string(value: &Value) -> String {
+    value
+        .as_str()
+        .map_or_else(|| value.to_string(), str::to_owned)
+}
+
+/// Compares local block contents with an Electra builder bid.
+///
+/// # Errors
+///
+/// Returns an error string if the bid is not an Electra bid.
+fn compare<P: Preset>(
+    contents: &BeaconBlockContents<P>,
+    signed_builder_bid: &SignedBuilderBid<P>,
+) -> Result<Comparison, String> {
+    let SignedBuilderBid::Electra(signed_builder_bid) = signed_builder_bid else {
+        return Err("SignedBuilderBid must be an Electra bid".to_owned());
+    };
+
+    let bid = &signed_builder_bid.message;
+    let body = &contents.block.body;
+
+    let local_header = header_fields(&payload_header(body.execution_payload.clone()))?;
+    let bid_header = header_fields(&bid.header)?;
+
+    let header_differences = local_header
+        .iter()
+        .filter_map(|(field, local)| {
+            let bid = bid_header.get(field).unwrap_or(&Value::Null);
+            (local != bid).then(|| (field.clone(), json_string(local), json_string(bid)))
+        })
+        .collect();
+
+    let local_commitments = &body.blob_kzg_commitments;
+    let bid_commitments = &bid.blob_kzg_commitments;
+
+    Ok(Comparison {
+        bid_value: bid.value.to_string(),
+        header_differences,
+        local_commitments: local_commitments.len(),
+        bid_commitments: bid_commitments.len(),
+        only_local: local_commitments
+            .iter()
+            .filter(|commitment| !bid_commitments.contains(*commitment))
+            .copied()
+            .collect(),
+        only_bid: bid_commitments
+            .iter()
+            .filter(|commitment| !local_commitments.contains(*commitment))
+            .copied()
+            .collect(),
+    })
+}
+
+impl Comparison {
+    fn into_dict(
+        self,
+        py: Python<'_>,
+        local_value: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Py<PyDict>> {
+        let bid_value = py
+            .get_type::<PyInt>()
+            .call1((PyString::new(py, &self.bid_value),))?;
+        let value_delta = local_value
+            .as_ref()
+            .map(|local_value| bid_value.sub(local_value))
+            .transpose()?;
+
+        let header_differences = PyDict::new(py);
+        for (field, local, bid) in self.header_differences {
+            header_differences.set_item(field, (local, bid))?;
+        }
+
+        let format_all = |commitments: &[KzgCommitment]| {
+            commitments
+                .iter()
+                .map(|commitment| format_hex(commitment.as_bytes()))
+                .collect::<Vec<_>>()
+        };
+
+        let blob_commitments = PyDict::new(py);
+        blob_commitments.set_item("local", self.local_commitments)?;
+        blob_commitments.set_item("bid", self.bid_commitments)?;
+        blob_commitments.set_item("only_local", format_all(&self.only_local))?;
+        blob_commitments.set_item("only_bid", format_all(&self.only_bid))?;
+
+        let dict = PyDict::new(py);
+        dict.set_item("local_value", local_value)?;
+        dict.set_item("bid_value", bid_value)?;
+        dict.set_item("value_delta", value_delta)?;
+        dict.set_item("header_differences", header_differences)?;
+        dict.set_item("blob_commitments", blob_commitments)?;
+        Ok(dict.unbind())
+    }
+}
+
+/// Compare locally built block contents with a relay's signed builder bid.
+///
+/// `local_contents` is either Electra block contents or a full block
+/// `ProducedBlock` from `from_produce_block_v3`, whose
+/// `execution_payload_value` is the local value unless `local_value` is
+/// given. `config` must be of the preset of the contents and bid.
+///
+/// Returns a dict with the `local_value` (`None` if unknown), the
+/// `bid_value` and the `value_delta` of the bid over the local value in wei,
+/// the `header_differences` mapping each differing execution payload header
+/// field to its `(local, bid)` values, and the `blob_commitments` counts of
+/// both candidates with the commitments `only_local` or `only_bid` has.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if the arguments are not block contents and a
+/// builder bid of the same preset, or `PyValueError` if the produced block
+/// is blinded, the bid is not an Electra bid or `config` belongs to another
+/// preset.
+#[pyfunction]
+#[pyo3(signature = (local_contents, relay_bid, config = None, local_value = None))]
+pub fn compare_candidates(
+    py: Python<'_>,
+    local_contents: &Bound<'_, PyAny>,
+    relay_bid: &Bound<'_, PyAny>,
+    config: Option<PyRef<'_, PyConfig>>,
+    local_value: Option<Bound<'_, PyInt>>,
+) -> PyResult<Py<PyDict>> {
+    let mut local_value = local_value.map(Bound::into_any);
+
+    let local_contents = match local_contents.cast::<PyProducedBlock>() {
+        Ok(produced) => {
+            let produced = produced.get();
+
+            if produced.execution_payload_blinded {
+                return Err(PyValueError::new_err(
+                    "ProducedBlock is blinded, expected full block contents",
+                ));
+            }
+
+            if local_value.is_none() {
+                local_value = produced
+                    .execution_payload_value
+                    .as_ref()
+                    .map(|value| value.bind(py).clone());
+            }
+
+            produced.block.bind(py).clone()
+        }
+        Err(_) => local_contents.clone(),
+    };
+
+    macro_rules! try_presets {
+        ($($contents:ty, $bid:ty => $preset:ty),* $(,)?) => {
+            $(
+                if let Ok(contents) = local_contents.cast::<$contents>() {
+                    resolve_config::<$preset>(config.as_deref())?;
+
+                    let Ok(bid) = relay_bid.cast::<$bid>() else {
+                        return Err(PyTypeError::new_err(format!(
+                            "Expected a builder bid of the contents' preset, got {}",
+                            relay_bid.get_type().name()?
+                        )));
+                    };
+
+                    let contents = contents.borrow();
+                    let bid = bid.borrow();
+                    let (contents, bid) = (&contents.inner, &bid.inner);
+
+                    return py
+                        .detach(|| compare::<$preset>(contents, bid))
+                        .map_err(PyValueError::new_err)?
+                        .into_dict(py, local_value);
+                }
+            )*
+        };
+    }
+
+    try_presets!(
+        PyBeaconBlockContentsMainnet, PySignedBuilderBidMainnet => Mainnet,
+        PyBeaconBlockContentsMinimal, PySignedBuilderBidMinimal => Minimal,
+        PyBeaconBlockContentsGnosis, PySignedBuilderBidGnosis => Gnosis,
+    );
+
+    Err(PyTypeError::new_err(format!(
+        "Expected block contents or a ProducedBlock, got {}",
+        local_contents.get_type().name()?
+    )))
+}
+
+/// Registers the candidate comparison function with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(compare_candidates, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,770 @@
+//! Electra execution payload types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `ExecutionPayload` (Mainnet, Minimal, Gnosis)
+//! - `ExecutionPayloadHeader` (Mainnet, Minimal, Gnosis)
+//! - `ExecutionPayloadAndBlobsBundle` (Mainnet, Minimal, Gnosis), the builder
+//!   API response to a submitted blinded block
+//!
+//! Electra reuses the Deneb execution payload containers. Each type supports
+//! SSZ and JSON serialization; full payloads also provide `payload_stats`,
+//! `transactions_by_type`, and lazy `transactions` and `withdrawals` views,
+//! and headers can be built from execution layer JSON-RPC blocks with
+//! `from_el_header_json`. Gnosis payloads also provide `gno_withdrawals`.
+//!
+//! All three types provide `block_hash`. Full payloads and payload bundles
+//! provide `el_transactions_root` and `el_withdrawals_root`, the execution
+//! layer trie roots of their lists, which the execution block header commits
+//! to. Headers instead provide `transactions_root` and `withdrawals_root`,
+//! the SSZ hash tree roots they store under those names.
+
+use paste::paste;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::convert::{
+    buffer_bytes, format_hash_tree_root, input_buffer, le_uint_to_py, parse_root_hex,
+};
+use crate::el_hashing;
+use crate::electra::block::BlobsBundle;
+use crate::gnosis::gno_withdrawals_impl;
+use crate::Gnosis;
+use grandine_ssz::{Ssz, SszHash as _, H256};
+use grandine_types::deneb::containers::{ExecutionPayload, ExecutionPayloadHeader};
+use grandine_types::electra::containers::ExecutionRequests;
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+
+use crate::list::PySszList;
+use crate::{define_ssz_pyclass_for_preset, encode_ssz, ssz_list_view};
+
+/// The execution payload and blobs bundle a relay returns for a submitted
+/// blinded block.
+#[derive(Clone, PartialEq, Eq, Default, Debug, Deserialize, Serialize, Ssz)]
+#[serde(bound = "")]
+pub struct ExecutionPayloadAndBlobsBundle<P: Preset> {
+    pub execution_payload: ExecutionPayload<P>,
+    pub blobs_bundle: BlobsBundle<P>,
+}
+
+/// EIP-2718 type byte of EIP-4844 blob transactions.
+const BLOB_TX_TYPE: u8 = 0x03;
+
+/// Transaction groups of `transactions_by_type`. Typed transactions are
+/// listed by their EIP-2718 type byte.
+const TX_GROUPS: [(Option<u8>, &str); 6] = [
+    (None, "legacy"),
+    (Some(0x01), "eip2930"),
+    (Some(0x02), "eip1559"),
+    (Some(BLOB_TX_TYPE), "eip4844"),
+    (Some(0x04), "eip7702"),
+    (None, "unknown"),
+];
+
+/// Summary statistics of an execution payload.
+struct PayloadStats {
+    gas_used: u64,
+    gas_limit: u64,
+    base_fee_per_gas: Vec<u8>,
+    blob_gas_used: u64,
+    excess_blob_gas: u64,
+    tx_count: usize,
+    blob_tx_count: usize,
+    payload_size: usize,
+}
+
+impl PayloadStats {
+    fn compute<P: Preset>(payload: &ExecutionPayload<P>) -> Result<Self, String> {
+        Ok(Self {
+            gas_used: payload.gas_used,
+            gas_limit: payload.gas_limit,
+            base_fee_per_gas: encode_ssz(&payload.base_fee_per_gas)?,
+            blob_gas_used: payload.blob_gas_used,
+            excess_blob_gas: payload.excess_blob_gas,
+            tx_count: payload.transactions.len(),
+            blob_tx_count: payload
+                .transactions
+                .iter()
+                .filter(|transaction| transaction.first() == Some(&BLOB_TX_TYPE))
+                .count(),
+            payload_size: encode_ssz(payload)?.len(),
+        })
+    }
+}
+
+/// Creates a Python dict with gas, fee, and size statistics of a payload.
+///
+/// Returns a `PyDict` with keys: `gas_used`, `gas_limit`, `base_fee_per_gas`,
+/// `blob_gas_used`, `excess_blob_gas`, `tx_count`, `blob_tx_count`, and
+/// `payload_size` (SSZ-encoded size in bytes). All values are Python ints.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the payload cannot be SSZ-encoded.
+pub fn payload_stats_impl<P: Preset>(
+    payload: &ExecutionPayload<P>,
+    py: Python<'_>,
+) -> PyResult<Py<PyDict>> {
+    let stats = py
+        .detach(|| PayloadStats::compute(payload))
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+    let d = PyDict::new(py);
+    d.set_item("gas_used", stats.gas_used)?;
+    d.set_item("gas_limit", stats.gas_limit)?;
+    d.set_item(
+        "base_fee_per_gas",
+        le_uint_to_py(py, &stats.base_fee_per_gas)?,
+    )?;
+    d.set_item("blob_gas_used", stats.blob_gas_used)?;
+    d.set_item("excess_blob_gas", stats.excess_blob_gas)?;
+    d.set_item("tx_count", stats.tx_count)?;
+    d.set_item("blob_tx_count", stats.blob_tx_count)?;
+    d.set_item("payload_size", stats.payload_size)?;
+    Ok(d.into())
+}
+
+/// Returns the index in `TX_GROUPS` of a transaction's group.
+///
+/// Legacy transactions are RLP lists, whose first byte is at least `0xc0`;
+/// anything else starts with its EIP-2718 type byte.
+fn transaction_group(transaction: &[u8]) -> usize {
+    match transaction.first() {
+        Some(0xc0..=0xff) => 0,
+        Some(type_byte) => TX_GROUPS
+            .iter()
+            .position(|(group_type, _)| *group_type == Some(*type_byte))
+            .unwrap_or(TX_GROUPS.len() - 1),
+        None => TX_GROUPS.len() - 1,
+    }
+}
+
+/// Groups the transactions of a payload by EIP-2718 type.
+///
+/// Returns a `PyDict` mapping each of `legacy`, `eip2930`, `eip1559`,
+/// `eip4844`, `eip7702` and `unknown` to a dict with the `count` of
+/// transactions and their raw `transactions` bytes, in payload order.
+///
+/// # Errors
+///
+/// Returns `PyErr` if the Python conversion fails.
+pub fn transactions_by_type_impl<P: Preset>(
+    payload: &ExecutionPayload<P>,
+    py: Python<'_>,
+) -> PyResult<Py<PyDict>> {
+    let groups = py.detach(|| {
+        let mut groups = vec![Vec::new(); TX_GROUPS.len()];
+        for transaction in payload.transactions.iter() {
+            let transaction: &[u8] = transaction;
+            groups[transaction_group(transaction)].push(transaction);
+        }
+        groups
+    });
+
+    let d = PyDict::new(py);
+    for ((_, name), transactions) in TX_GROUPS.iter().zip(groups) {
+        let group = PyDict::new(py);
+        group.set_item("count", transactions.len())?;
+        group.set_item(
+            "transactions",
+            transactions
+                .into_iter()
+                .map(|transaction| PyBytes::new(py, transaction))
+                .collect::<Vec<_>>(),
+        )?;
+        d.set_item(name, group)?;
+    }
+    Ok(d.into())
+}
+
+/// Computes the EL `withdrawalsRoot` of a payload as a `0x`-prefixed hex string.
+///
+/// This is the Merkle-Patricia trie root used in execution block headers, not
+/// the SSZ hash tree root of the withdrawals list.
+pub fn el_withdrawals_root_impl<P: Preset>(
+    payload: &ExecutionPayload<P>,
+    py: Python<'_>,
+) -> String {
+    py.detach(|| format_hash_tree_root(&el_hashing::withdrawals_root(payload.withdrawals.iter())))
+}
+
+/// Computes the EL `transactionsRoot` of a payload as a `0x`-prefixed hex
+/// string.
+///
+/// This is the Merkle-Patricia trie root used in execution block headers, not
+/// the SSZ hash tree root of the transaction list.
+pub fn el_transactions_root_impl<P: Preset>(
+    payload: &ExecutionPayload<P>,
+    py: Python<'_>,
+) -> String {
+    py.detach(|| {
+        let transactions = payload
+            .transactions
+            .iter()
+            .map(|transaction| -> &[u8] { transaction })
+            .collect::<Vec<_>>();
+        format_hash_tree_root(&el_hashing::ordered_trie_root(&transactions))
+    })
+}
+
+/// Computes the EIP-7685 `requestsHash` of execution requests as a
+/// `0x`-prefixed hex string.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the requests cannot be SSZ-encoded.
+pub fn requests_hash_impl<P: Preset>(
+    requests: &ExecutionRequests<P>,
+    py: Python<'_>,
+) -> PyResult<String> {
+    py.detach(|| el_hashing::requests_hash(requests))
+        .map(|hash| format_hash_tree_root(&hash))
+        .map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+// =============================================================================
+// Execution layer JSON-RPC conversion
+// =============================================================================
+
+fn rpc_field<'a>(block: &'a Map<String, Value>, key: &str) -> Result<&'a str, String> {
+    block
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("EL block is missing {key:?}"))
+}
+
+/// Converts a hex `QUANTITY` field into the decimal string the CL JSON uses.
+fn rpc_quantity(block: &Map<String, Value>, key: &str) -> Result<Value, String> {
+    let quantity = rpc_field(block, key)?;
+    let digits = quantity.strip_prefix("0x").unwrap_or(quantity);
+    u128::from_str_radix(digits, 16)
+        .map(|value| Value::String(value.to_string()))
+        .map_err(|e| format!("Invalid quantity {key:?}: {e}"))
+}
+
+fn rpc_data(block: &Map<String, Value>, key: &str) -> Result<Value, String> {
+    rpc_field(block, key).map(|data| Value::String(data.to_owned()))
+}
+
+fn check_el_root(block: &Map<String, Value>, key: &str, computed: &H256) -> Result<(), String> {
+    let Ok(expected) = rpc_field(block, key) else {
+        return Ok(());
+    };
+
+    if parse_root_hex(expected).map_err(|e| format!("Invalid {key}: {e}"))? == *computed {
+        Ok(())
+    } else {
+        Err(format!(
+            "{key} mismatch: EL block has {expected}, computed 0x{}",
+            hex::encode(computed.as_bytes())
+        ))
+    }
+}
+
+/// Builds an execution payload from an `eth_getBlockByNumber` /
+/// `eth_getBlockByHash` response.
+///
+/// Accepts either the bare block object or the full JSON-RPC response. Raw
+/// transactions are not part of the RPC block, so they must be supplied
+/// separately (e.g. from `debug_getRawTransactions`) unless the block is
+/// empty. When the block contains `transactionsRoot` and `withdrawalsRoot`,
+/// they are checked against the supplied transactions and withdrawals.
+fn payload_from_el_json<P: Preset>(
+    json: &[u8],
+    raw_transactions: Option<&[Vec<u8>]>,
+) -> Result<ExecutionPayload<P>, String> {
+    let response: Value = crate::decode_json(json)?;
+    let block = response
+        .get("result")
+        .unwrap_or(&response)
+        .as_object()
+        .ok_or("EL block must be a JSON object")?;
+
+    let transaction_count = block
+        .get("transactions")
+        .and_then(Value::as_array)
+        .map_or(0, Vec::len);
+
+    let raw_transactions = match raw_transactions {
+        Some(transactions) => transactions,
+        None if transaction_count == 0 => &[],
+        None => {
+            return Err(format!(
+                "EL block has {transaction_count} transactions; pass raw_transactions"
+            ))
+        }
+    };
+
+    let withdrawals = block
+        .get("withdrawals")
+        .and_then(Value::as_array)
+        .ok_or("EL block is missing \"withdrawals\"")?
+        .iter()
+        .map(|withdrawal| {
+            let withdrawal = withdrawal.as_object().ok_or("Invalid withdrawal")?;
+            Ok(json!({
+                "index": rpc_quantity(withdrawal, "index")?,
+                "validator_index": rpc_quantity(withdrawal, "validatorIndex")?,
+                "address": rpc_data(withdrawal, "address")?,
+                "amount": rpc_quantity(withdrawal, "amount")?,
+            }))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let payload = json!({
+        "parent_hash": rpc_data(block, "parentHash")?,
+        "fee_recipient": rpc_data(block, "miner")?,
+        "state_root": rpc_data(block, "stateRoot")?,
+        "receipts_root": rpc_data(block, "receiptsRoot")?,
+        "logs_bloom": rpc_data(block, "logsBloom")?,
+        "prev_randao": rpc_data(block, "mixHash")?,
+        "block_number": rpc_quantity(block, "number")?,
+        "gas_limit": rpc_quantity(block, "gasLimit")?,
+        "gas_used": rpc_quantity(block, "gasUsed")?,
+        "timestamp": rpc_quantity(block, "timestamp")?,
+        "extra_data": rpc_data(block, "extraData")?,
+        "base_fee_per_gas": rpc_quantity(block, "baseFeePerGas")?,
+        "block_hash": rpc_data(block, "hash")?,
+        "transactions": raw_transactions
+            .iter()
+            .map(|transaction| format!("0x{}", hex::encode(transaction)))
+            .collect::<Vec<_>>(),
+        "withdrawals": withdrawals,
+        "blob_gas_used": rpc_quantity(block, "blobGasUsed")?,
+        "excess_blob_gas": rpc_quantity(block, "excessBlobGas")?,
+    });
+
+    let payload: ExecutionPayload<P> =
+        serde_json::from_value(payload).map_err(|e| e.to_string())?;
+
+    check_el_root(
+        block,
+        "transactionsRoot",
+        &el_hashing::ordered_trie_root(raw_transactions),
+    )?;
+    check_el_root(
+        block,
+        "withdrawalsRoot",
+        &el_hashing::withdrawals_root(payload.withdrawals.iter()),
+    )?;
+
+    Ok(payload)
+}
+
+/// Converts a full payload into its header by replacing the transaction and
+/// withdrawal lists with their hash tree roots.
+pub(crate) fn payload_header<P: Preset>(payload: ExecutionPayload<P>) -> ExecutionPayloadHeader<P> {
+    ExecutionPayloadHeader {
+        transactions_root: payload.transactions.hash_tree_root(),
+        withdrawals_root: payload.withdrawals.hash_tree_root(),
+        parent_hash: payload.parent_hash,
+        fee_recipient: payload.fee_recipient,
+        state_root: payload.state_root,
+        receipts_root: payload.receipts_root,
+        logs_bloom: payload.logs_bloom,
+        prev_randao: payload.prev_randao,
+        block_number: payload.block_number,
+        gas_limit: payload.gas_limit,
+        gas_used: payload.gas_used,
+        timestamp: payload.timestamp,
+        extra_data: payload.extra_data,
+        base_fee_per_gas: payload.base_fee_per_gas,
+        block_hash: payload.block_hash,
+        blob_gas_used: payload.blob_gas_used,
+        excess_blob_gas: payload.excess_blob_gas,
+    }
+}
+
+/// Builds an execution payload header from an EL JSON-RPC block, given as
+/// any buffer of bytes.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if `rpc_block_json` is not a buffer, and
+/// `PyValueError` if the block is malformed, raw transactions are missing,
+/// or the EL transaction/withdrawal roots do not match.
+pub fn header_from_el_json_impl<P: Preset>(
+    py: Python<'_>,
+    rpc_block_json: &Bound<'_, PyAny>,
+    raw_transactions: Option<Vec<Vec<u8>>>,
+) -> PyResult<ExecutionPayloadHeader<P>> {
+    let buffer = input_buffer(rpc_block_json)?;
+    py.detach(|| {
+        payload_from_el_json::<P>(buffer_bytes(&buffer), raw_transactions.as_deref())
+            .map(payload_header)
+    })
+    .map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+paste! {
+    define_ssz_pyclass_for_preset!(
+        [<PyExecutionPayloadMainnet>],
+        "ElectraExecutionPayloadMainnet",
+        ExecutionPayload<Mainnet>,
+        extra_methods = {
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.withdrawals, json)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner, py)
+            }
+
+            pub fn transactions_by_type(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                transactions_by_type_impl(&self.inner, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner, py)
+            }
+
+            pub fn block_hash(&self) -> String {
+                format_hash_tree_root(&self.inner.block_hash)
+            }
+
+            pub fn el_transactions_root(&self, py: pyo3::Python<'_>) -> String {
+                el_transactions_root_impl(&self.inner, py)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyExecutionPayloadMinimal>],
+        "ElectraExecutionPayloadMinimal",
+        ExecutionPayload<Minimal>,
+        extra_methods = {
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.withdrawals, json)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner, py)
+            }
+
+            pub fn transactions_by_type(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                transactions_by_type_impl(&self.inner, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner, py)
+            }
+
+            pub fn block_hash(&self) -> String {
+                format_hash_tree_root(&self.inner.block_hash)
+            }
+
+            pub fn el_transactions_root(&self, py: pyo3::Python<'_>) -> String {
+                el_transactions_root_impl(&self.inner, py)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyExecutionPayloadGnosis>],
+        "ElectraExecutionPayloadGnosis",
+        ExecutionPayload<Gnosis>,
+        extra_methods = {
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.withdrawals, json)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner, py)
+            }
+
+            /// Withdrawals with the GNO amount paid out by the withdrawal
+            /// contract, as dicts with `index`, `validator_index`,
+            /// `address`, `amount` (Gwei) and `amount_gno_wei`.
+            pub fn gno_withdrawals(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyList>> {
+                gno_withdrawals_impl(&self.inner, py)
+            }
+
+            pub fn transactions_by_type(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                transactions_by_type_impl(&self.inner, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner, py)
+            }
+
+            pub fn block_hash(&self) -> String {
+                format_hash_tree_root(&self.inner.block_hash)
+            }
+
+            pub fn el_transactions_root(&self, py: pyo3::Python<'_>) -> String {
+                el_transactions_root_impl(&self.inner, py)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyExecutionPayloadHeaderMainnet>],
+        "ElectraExecutionPayloadHeaderMainnet",
+        ExecutionPayloadHeader<Mainnet>,
+        extra_methods = {
+            #[staticmethod]
+            #[pyo3(signature = (rpc_block_json, raw_transactions = None))]
+            pub fn from_el_header_json(
+                py: pyo3::Python<'_>,
+                rpc_block_json: &pyo3::Bound<'_, pyo3::PyAny>,
+                raw_transactions: Option<Vec<Vec<u8>>>,
+            ) -> pyo3::PyResult<Self> {
+                header_from_el_json_impl(py, rpc_block_json, raw_transactions)
+                    .map(|inner| Self { inner })
+            }
+
+            pub fn block_hash(&self) -> String {
+                format_hash_tree_root(&self.inner.block_hash)
+            }
+
+            /// The SSZ hash tree root of the transactions, as stored in the
+            /// header.
+            pub fn transactions_root(&self) -> String {
+                format_hash_tree_root(&self.inner.transactions_root)
+            }
+
+            /// The SSZ hash tree root of the withdrawals, as stored in the
+            /// header.
+            pub fn withdrawals_root(&self) -> String {
+                format_hash_tree_root(&self.inner.withdrawals_root)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyExecutionPayloadHeaderMinimal>],
+        "ElectraExecutionPayloadHeaderMinimal",
+        ExecutionPayloadHeader<Minimal>,
+        extra_methods = {
+            #[staticmethod]
+            #[pyo3(signature = (rpc_block_json, raw_transactions = None))]
+            pub fn from_el_header_json(
+                py: pyo3::Python<'_>,
+                rpc_block_json: &pyo3::Bound<'_, pyo3::PyAny>,
+                raw_transactions: Option<Vec<Vec<u8>>>,
+            ) -> pyo3::PyResult<Self> {
+                header_from_el_json_impl(py, rpc_block_json, raw_transactions)
+                    .map(|inner| Self { inner })
+            }
+
+            pub fn block_hash(&self) -> String {
+                format_hash_tree_root(&self.inner.block_hash)
+            }
+
+            /// The SSZ hash tree root of the transactions, as stored in the
+            /// header.
+            pub fn transactions_root(&self) -> String {
+                format_hash_tree_root(&self.inner.transactions_root)
+            }
+
+            /// The SSZ hash tree root of the withdrawals, as stored in the
+            /// header.
+            pub fn withdrawals_root(&self) -> String {
+                format_hash_tree_root(&self.inner.withdrawals_root)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyExecutionPayloadHeaderGnosis>],
+        "ElectraExecutionPayloadHeaderGnosis",
+        ExecutionPayloadHeader<Gnosis>,
+        extra_methods = {
+            #[staticmethod]
+            #[pyo3(signature = (rpc_block_json, raw_transactions = None))]
+            pub fn from_el_header_json(
+                py: pyo3::Python<'_>,
+                rpc_block_json: &pyo3::Bound<'_, pyo3::PyAny>,
+                raw_transactions: Option<Vec<Vec<u8>>>,
+            ) -> pyo3::PyResult<Self> {
+                header_from_el_json_impl(py, rpc_block_json, raw_transactions)
+                    .map(|inner| Self { inner })
+            }
+
+            pub fn block_hash(&self) -> String {
+                format_hash_tree_root(&self.inner.block_hash)
+            }
+
+            /// The SSZ hash tree root of the transactions, as stored in the
+            /// header.
+            pub fn transactions_root(&self) -> String {
+                format_hash_tree_root(&self.inner.transactions_root)
+            }
+
+            /// The SSZ hash tree root of the withdrawals, as stored in the
+            /// header.
+            pub fn withdrawals_root(&self) -> String {
+                format_hash_tree_root(&self.inner.withdrawals_root)
+            }
+        }
+    );
+    define_ssz_pyclass_for_preset!(
+        [<PyExecutionPayloadAndBlobsBundleMainnet>],
+        "ElectraExecutionPayloadAndBlobsBundleMainnet",
+        ExecutionPayloadAndBlobsBundle<Mainnet>,
+        extra_methods = {
+            pub fn execution_payload(&self) -> [<PyExecutionPayloadMainnet>] {
+                [<PyExecutionPayloadMainnet>] {
+                    inner: self.inner.execution_payload.clone(),
+                }
+            }
+
+            pub fn kzg_commitments(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs_bundle.commitments, view)
+            }
+
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs_bundle.proofs, view)
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs_bundle.blobs, view)
+            }
+
+            pub fn block_hash(&self) -> String {
+                format_hash_tree_root(&self.inner.execution_payload.block_hash)
+            }
+
+            pub fn el_transactions_root(&self, py: pyo3::Python<'_>) -> String {
+                el_transactions_root_impl(&self.inner.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.execution_payload, py)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyExecutionPayloadAndBlobsBundleMinimal>],
+        "ElectraExecutionPayloadAndBlobsBundleMinimal",
+        ExecutionPayloadAndBlobsBundle<Minimal>,
+        extra_methods = {
+            pub fn execution_payload(&self) -> [<PyExecutionPayloadMinimal>] {
+                [<PyExecutionPayloadMinimal>] {
+                    inner: self.inner.execution_payload.clone(),
+                }
+            }
+
+            pub fn kzg_commitments(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs_bundle.commitments, view)
+            }
+
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs_bundle.proofs, view)
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs_bundle.blobs, view)
+            }
+
+            pub fn block_hash(&self) -> String {
+                format_hash_tree_root(&self.inner.execution_payload.block_hash)
+            }
+
+            pub fn el_transactions_root(&self, py: pyo3::Python<'_>) -> String {
+                el_transactions_root_impl(&self.inner.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.execution_payload, py)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyExecutionPayloadAndBlobsBundleGnosis>],
+        "ElectraExecutionPayloadAndBlobsBundleGnosis",
+        ExecutionPayloadAndBlobsBundle<Gnosis>,
+        extra_methods = {
+            pub fn execution_payload(&self) -> [<PyExecutionPayloadGnosis>] {
+                [<PyExecutionPayloadGnosis>] {
+                    inner: self.inner.execution_payload.clone(),
+                }
+            }
+
+            pub fn kzg_commitments(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs_bundle.commitments, view)
+            }
+
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs_bundle.proofs, view)
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs_bundle.blobs, view)
+            }
+
+            pub fn block_hash(&self) -> String {
+                format_hash_tree_root(&self.inner.execution_payload.block_hash)
+            }
+
+            pub fn el_transactions_root(&self, py: pyo3::Python<'_>) -> String {
+                el_transactions_root_impl(&self.inner.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.execution_payload, py)
+            }
+        }
+    );
+
+}
+
+/// Registers all Electra execution payload types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    // Mainnet classes
+    m.add_class::<PyExecutionPayloadMainnet>()?;
+    m.add_class::<PyExecutionPayloadHeaderMainnet>()?;
+    m.add_class::<PyExecutionPayloadAndBlobsBundleMainnet>()?;
+
+    // Minimal classes
+    m.add_class::<PyExecutionPayloadMinimal>()?;
+    m.add_class::<PyExecutionPayloadHeaderMinimal>()?;
+    m.add_class::<PyExecutionPayloadAndBlobsBundleMinimal>()?;
+
+    // Gnosis classes
+    m.add_class::<PyExecutionPayloadGnosis>()?;
+    m.add_class::<PyExecutionPayloadHeaderGnosis>()?;
+    m.add_class::<PyExecutionPayloadAndBlobsBundleGnosis>()?;
+
+    Ok(())
+}
@@ -1,6 +1,18 @@
 //! Electra hardfork types support.
 //!
 //! This module contains Electra consensus types for `BeaconBlock`, `BlindedBeaconBlock`,
-//! and their associated signed variants and contents.
+//! and their associated signed variants and contents, as well as the execution
+//! payload and attestation types embedded in them, aggregate-and-proof
+//! messages, blob sidecars, the `BeaconState`, an incremental block body
+//! builder, parsing of `produceBlockV3` responses, and comparison of local
+//! block contents with builder bids.
 
+pub mod aggregate;
+pub mod attestation;
 pub mod block;
+pub mod builder;
+pub mod compare;
+pub mod execution;
+pub mod produce;
+pub mod sidecar;
+pub mod state;
@@ -0,0 +1,241 @@
+//! Parsing of Beacon API `produceBlockV3` responses.
+//!
+//! `GET /eth/v3/validator/blocks/{slot}` returns either a blinded block or
+//! full block contents, tagged by the `execution_payload_blinded` flag. The
+//! flag, the fork `version` and the block values are sent both in the JSON
+//! body and as `Eth-*` response headers; body fields take precedence and the
+//! headers are used when a field is missing from the body.
+
+use std::collections::HashMap;
+
+use grandine_types::electra::containers::BlindedBeaconBlock;
+use grandine_types::preset::Preset;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyInt, PyString};
+use serde::Deserialize;
+
+use crate::electra::block::BeaconBlockContents;
+
+const VERSION: &str = "electra";
+
+/// A `produceBlockV3` response body.
+#[derive(Deserialize)]
+#[serde(bound = "")]
+struct Response<P: Preset> {
+    version: Option<String>,
+    execution_payload_blinded: Option<bool>,
+    execution_payload_value: Option<String>,
+    consensus_block_value: Option<String>,
+    data: ProducedBlockData<P>,
+}
+
+/// The block of a `produceBlockV3` response.
+///
+/// The blinded flag may come after the block in the body, or only in the
+/// headers, so the block is parsed as whichever of the two it is and then
+/// checked against the flag.
+#[derive(Deserialize)]
+#[serde(bound = "", untagged)]
+pub enum ProducedBlockData<P: Preset> {
+    Blinded(BlindedBeaconBlock<P>),
+    Full(BeaconBlockContents<P>),
+}
+
+/// A parsed `produceBlockV3` response, before conversion to Python objects.
+pub struct ProducedBlockV3<P: Preset> {
+    blinded: bool,
+    execution_payload_value: Option<String>,
+    consensus_block_value: Option<String>,
+    data: ProducedBlockData<P>,
+}
+
+/// Response headers relevant to `produceBlockV3`, keyed by lowercase name.
+pub struct Headers(HashMap<String, String>);
+
+impl Headers {
+    /// Reads headers from a mapping such as a `dict` or a `requests`
+    /// `CaseInsensitiveDict`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PyErr` if the mapping has no `items()` or its keys and values
+    /// are not strings.
+    pub fn from_py(headers: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let mut values = HashMap::new();
+
+        if let Some(headers) = headers {
+            for item in headers.call_method0("items")?.try_iter()? {
+                let (name, value): (String, String) = item?.extract()?;
+                values.insert(name.to_ascii_lowercase(), value);
+            }
+        }
+
+        Ok(Self(values))
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+}
+
+/// Parses a `produceBlockV3` response body.
+///
+/// # Errors
+///
+/// Returns an error string if the body exceeds the decode limits or is not
+/// a valid response, the fork version is missing or not Electra, the blinded
+/// flag is missing, or the block does not match the flag.
+pub fn parse_produce_block_v3<P: Preset>(
+    bytes: &[u8],
+    headers: &Headers,
+) -> Result<ProducedBlockV3<P>, String> {
+    let response: Response<P> = crate::decode_json(bytes)?;
+
+    let version = response
+        .version
+        .as_deref()
+        .or_else(|| headers.get("eth-consensus-version"))
+        .ok_or("Missing fork version")?;
+
+    if !version.eq_ignore_ascii_case(VERSION) {
+        return Err(format!(
+            "Unsupported fork version {version:?}, expected {VERSION:?}"
+        ));
+    }
+
+    let blinded = match response.execution_payload_blinded {
+        Some(blinded) => blinded,
+        None => match headers.get("eth-execution-payload-blinded") {
+            Some(value) if value.eq_ignore_ascii_case("true") => true,
+            Some(value) if value.eq_ignore_ascii_case("false") => false,
+            Some(value) => return Err(format!("Invalid Eth-Execution-Payload-Blinded {value:?}")),
+            None => return Err("Missing execution_payload_blinded".to_owned()),
+        },
+    };
+
+    match (&response.data, blinded) {
+        (ProducedBlockData::Blinded(_), false) => {
+            return Err("Expected block contents, got a blinded block".to_owned());
+        }
+        (ProducedBlockData::Full(_), true) => {
+            return Err("Expected a blinded block, got block contents".to_owned());
+        }
+        _ => {}
+    }
+
+    let header = |name: &str| headers.get(name).map(str::to_owned);
+
+    Ok(ProducedBlockV3 {
+        blinded,
+        execution_payload_value: response
+            .execution_payload_value
+            .or_else(|| header("eth-execution-payload-value")),
+        consensus_block_value: response
+            .consensus_block_value
+            .or_else(|| header("eth-consensus-block-value")),
+        data: response.data,
+    })
+}
+
+impl<P: Preset> ProducedBlockV3<P> {
+    /// Builds the Python result, wrapping the block with `wrap`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PyValueError` if a block value is not a decimal integer, or
+    /// any error returned by `wrap`.
+    pub fn into_py(
+        self,
+        py: Python<'_>,
+        wrap: impl FnOnce(ProducedBlockData<P>) -> PyResult<Py<PyAny>>,
+    ) -> PyResult<PyProducedBlock> {
+        let value = |value: Option<String>| {
+            value
+                .map(|value| {
+                    py.get_type::<PyInt>()
+                        .call1((PyString::new(py, &value),))
+                        .map(Bound::unbind)
+                        .map_err(|_| {
+                            PyValueError::new_err(format!("Invalid block value {value:?}"))
+                        })
+                })
+                .transpose()
+        };
+
+        Ok(PyProducedBlock {
+            execution_payload_blinded: self.blinded,
+            execution_payload_value: value(self.execution_payload_value)?,
+            consensus_block_value: value(self.consensus_block_value)?,
+            block: wrap(self.data)?,
+        })
+    }
+}
+
+/// A parsed `produceBlockV3` response.
+///
+/// `block` is a blinded block if `execution_payload_blinded` is true and
+/// block contents otherwise.
+#[pyclass(name = "ProducedBlock", frozen)]
+pub struct PyProducedBlock {
+    pub(crate) execution_payload_blinded: bool,
+    pub(crate) execution_payload_value: Option<Py<PyAny>>,
+    consensus_block_value: Option<Py<PyAny>>,
+    pub(crate) block: Py<PyAny>,
+}
+
+#[pymethods]
+impl PyProducedBlock {
+    #[getter]
+    fn version(&self) -> &'static str {
+        VERSION
+    }
+
+    #[getter]
+    fn execution_payload_blinded(&self) -> bool {
+        self.execution_payload_blinded
+    }
+
+    /// Execution payload value in wei, if the response included it.
+    #[getter]
+    fn execution_payload_value(&self, py: Python<'_>) -> Option<Py<PyAny>> {
+        self.execution_payload_value
+            .as_ref()
+            .map(|value| value.clone_ref(py))
+    }
+
+    /// Consensus block value in wei, if the response included it.
+    #[getter]
+    fn consensus_block_value(&self, py: Python<'_>) -> Option<Py<PyAny>> {
+        self.consensus_block_value
+            .as_ref()
+            .map(|value| value.clone_ref(py))
+    }
+
+    #[getter]
+    fn block(&self, py: Python<'_>) -> Py<PyAny> {
+        self.block.clone_ref(py)
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "ProducedBlock(version={VERSION:?}, execution_payload_blinded={}, block={})",
+            if self.execution_payload_blinded {
+                "True"
+            } else {
+                "False"
+            },
+            self.block.bind(py).get_type().name()?,
+        ))
+    }
+}
+
+/// Registers the `produceBlockV3` result class with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyProducedBlock>()?;
+    Ok(())
+}
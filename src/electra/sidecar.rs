@@ -0,0 +1,258 @@
+//! Blob sidecar types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `BlobSidecar` (Mainnet, Minimal, Gnosis), as published on the
+//!   `blob_sidecar_{subnet_id}` gossip topics and served by
+//!   `BlobSidecarsByRange` and `BlobSidecarsByRoot`
+//!
+//! The container is unchanged since Deneb. The KZG proof is checked against
+//! a trusted setup, by default the one set with `set_trusted_setup`.
+//!
+//! `blob_sidecars` builds the sidecars of signed block contents. The KZG
+//! commitment inclusion proof of a sidecar is the Merkle branch of its
+//! commitment in the block body, from the leaf up: the branch in the
+//! `blob_kzg_commitments` list, the list length mixed into the list root,
+//! then the branch of the list root among the body fields. It is computed by
+//! the same `prove` as the `prove` methods of blocks.
+
+use grandine_ssz::{ContiguousVector, SszHash};
+use grandine_types::deneb::containers::BlobSidecar;
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use paste::paste;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::convert::format_hash_tree_root;
+use crate::define_ssz_pyclass_for_preset;
+use crate::electra::block::{signed_header_impl, SignedBeaconBlockContents};
+use crate::hex_format::format_hex;
+use crate::kzg::{resolve_setup, verify_blob_proof, PyTrustedSetup};
+use crate::merkle::{generalized_index, prove, PathElement};
+use crate::phase0::header::PySignedBeaconBlockHeader;
+use crate::Gnosis;
+
+/// Verifies the KZG proof of a sidecar's blob against its commitment.
+///
+/// # Errors
+///
+/// Returns an error string if the commitment or proof is not a valid point.
+pub fn verify_sidecar_proof<P: Preset>(
+    sidecar: &BlobSidecar<P>,
+    setup: &c_kzg::KzgSettings,
+) -> Result<bool, String> {
+    verify_blob_proof(
+        setup,
+        sidecar.blob.as_bytes(),
+        sidecar.kzg_commitment.as_bytes(),
+        sidecar.kzg_proof.as_bytes(),
+    )
+}
+
+/// Builds the blob sidecars of signed block contents, one per KZG
+/// commitment, with their inclusion proofs.
+///
+/// # Errors
+///
+/// Returns an error string if there is not one blob and one KZG proof per
+/// commitment.
+pub fn blob_sidecars<P: Preset>(
+    contents: &SignedBeaconBlockContents<P>,
+) -> Result<Vec<BlobSidecar<P>>, String> {
+    let block = &contents.signed_block.message;
+    let commitments = &block.body.blob_kzg_commitments;
+
+    if contents.blobs.len() != commitments.len() || contents.kzg_proofs.len() != commitments.len() {
+        return Err(format!(
+            "Expected one blob and one KZG proof per KZG commitment, got {} blobs and {} proofs \
+             for {} commitments",
+            contents.blobs.len(),
+            contents.kzg_proofs.len(),
+            commitments.len()
+        ));
+    }
+
+    let signed_block_header = signed_header_impl(block, contents.signed_block.signature).inner;
+    // The commitments are leaves of the same subtree, numbered from the first.
+    let first_index = generalized_index(
+        &block.body,
+        &[
+            PathElement::Name("blob_kzg_commitments".to_owned()),
+            PathElement::Index(0),
+        ],
+    )?;
+
+    commitments
+        .iter()
+        .zip(contents.blobs.iter())
+        .zip(contents.kzg_proofs.iter())
+        .enumerate()
+        .map(|(index, ((kzg_commitment, blob), kzg_proof))| {
+            let branch = prove(&block.body, first_index + index as u64)?;
+
+            Ok(BlobSidecar {
+                index: index as u64,
+                blob: blob.clone(),
+                kzg_commitment: *kzg_commitment,
+                kzg_proof: *kzg_proof,
+                signed_block_header,
+                kzg_commitment_inclusion_proof: ContiguousVector::try_from_iter(branch)
+                    .map_err(|e| e.to_string())?,
+            })
+        })
+        .collect()
+}
+
+paste! {
+    define_ssz_pyclass_for_preset!(
+        [<PyBlobSidecarMainnet>],
+        "ElectraBlobSidecarMainnet",
+        BlobSidecar<Mainnet>,
+        extra_methods = {
+            pub fn index(&self) -> u64 {
+                self.inner.index
+            }
+
+            pub fn kzg_commitment(&self) -> String {
+                format_hex(self.inner.kzg_commitment.as_bytes())
+            }
+
+            pub fn kzg_proof(&self) -> String {
+                format_hex(self.inner.kzg_proof.as_bytes())
+            }
+
+            pub fn signed_block_header(&self) -> PySignedBeaconBlockHeader {
+                PySignedBeaconBlockHeader {
+                    inner: self.inner.signed_block_header,
+                }
+            }
+
+            /// Root of the block the blob belongs to.
+            pub fn block_root(&self) -> String {
+                format_hash_tree_root(&self.inner.signed_block_header.message.hash_tree_root())
+            }
+
+            /// Verify the KZG proof of the blob against its commitment.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the commitment or proof is not a
+            /// valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_kzg_proof(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                let setup = resolve_setup(setup.as_deref());
+                let inner = &self.inner;
+                py.detach(|| verify_sidecar_proof(inner, &setup))
+                    .map_err(PyValueError::new_err)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyBlobSidecarMinimal>],
+        "ElectraBlobSidecarMinimal",
+        BlobSidecar<Minimal>,
+        extra_methods = {
+            pub fn index(&self) -> u64 {
+                self.inner.index
+            }
+
+            pub fn kzg_commitment(&self) -> String {
+                format_hex(self.inner.kzg_commitment.as_bytes())
+            }
+
+            pub fn kzg_proof(&self) -> String {
+                format_hex(self.inner.kzg_proof.as_bytes())
+            }
+
+            pub fn signed_block_header(&self) -> PySignedBeaconBlockHeader {
+                PySignedBeaconBlockHeader {
+                    inner: self.inner.signed_block_header,
+                }
+            }
+
+            /// Root of the block the blob belongs to.
+            pub fn block_root(&self) -> String {
+                format_hash_tree_root(&self.inner.signed_block_header.message.hash_tree_root())
+            }
+
+            /// Verify the KZG proof of the blob against its commitment.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the commitment or proof is not a
+            /// valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_kzg_proof(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                let setup = resolve_setup(setup.as_deref());
+                let inner = &self.inner;
+                py.detach(|| verify_sidecar_proof(inner, &setup))
+                    .map_err(PyValueError::new_err)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyBlobSidecarGnosis>],
+        "ElectraBlobSidecarGnosis",
+        BlobSidecar<Gnosis>,
+        extra_methods = {
+            pub fn index(&self) -> u64 {
+                self.inner.index
+            }
+
+            pub fn kzg_commitment(&self) -> String {
+                format_hex(self.inner.kzg_commitment.as_bytes())
+            }
+
+            pub fn kzg_proof(&self) -> String {
+                format_hex(self.inner.kzg_proof.as_bytes())
+            }
+
+            pub fn signed_block_header(&self) -> PySignedBeaconBlockHeader {
+                PySignedBeaconBlockHeader {
+                    inner: self.inner.signed_block_header,
+                }
+            }
+
+            /// Root of the block the blob belongs to.
+            pub fn block_root(&self) -> String {
+                format_hash_tree_root(&self.inner.signed_block_header.message.hash_tree_root())
+            }
+
+            /// Verify the KZG proof of the blob against its commitment.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the commitment or proof is not a
+            /// valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_kzg_proof(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                let setup = resolve_setup(setup.as_deref());
+                let inner = &self.inner;
+                py.detach(|| verify_sidecar_proof(inner, &setup))
+                    .map_err(PyValueError::new_err)
+            }
+        }
+    );
+}
+
+/// Registers all blob sidecar types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyBlobSidecarMainnet>()?;
+    m.add_class::<PyBlobSidecarMinimal>()?;
+    m.add_class::<PyBlobSidecarGnosis>()?;
+    Ok(())
+}
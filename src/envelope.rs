@@ -0,0 +1,774 @@
+//! Self-describing SSZ envelopes.
+//!
+//! SSZ bytes do not say which type they encode. An envelope prefixes them with
+//! enough information to decode them again without out-of-band knowledge:
+//!
+//! ```text
+//! envelope = "GPSZ" fork:u8 preset:u8 type_id:u16 ssz
+//! ```
+//!
+//! `type_id` is little-endian. Fork, preset and type identifiers are listed in
+//! [`Fork`], [`preset_byte`] and [`TypeId`]; they are part of the format and
+//! must never be renumbered. Preset-independent types use preset `0xff`.
+//!
+//! Content-addressed stores key objects by content keys instead, in the
+//! style of Portal network content keys, with the same fork and preset bytes:
+//!
+//! ```text
+//! content_key = fork:u8 preset:u8 hash_tree_root:bytes32
+//! ```
+//!
+//! The root is that of the object itself, so a signed block and its block
+//! contents have different keys.
+
+use grandine_ssz::{SszHash as _, H256};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde_json::Value;
+
+use crate::altair::block::{
+    PyAltairSignedBeaconBlockGnosis, PyAltairSignedBeaconBlockMainnet,
+    PyAltairSignedBeaconBlockMinimal,
+};
+use crate::bellatrix::block::{
+    PyBellatrixSignedBeaconBlockGnosis, PyBellatrixSignedBeaconBlockMainnet,
+    PyBellatrixSignedBeaconBlockMinimal, PyBellatrixSignedBlindedBeaconBlockGnosis,
+    PyBellatrixSignedBlindedBeaconBlockMainnet, PyBellatrixSignedBlindedBeaconBlockMinimal,
+};
+use crate::capella::block::{
+    PyCapellaSignedBeaconBlockGnosis, PyCapellaSignedBeaconBlockMainnet,
+    PyCapellaSignedBeaconBlockMinimal, PyCapellaSignedBlindedBeaconBlockGnosis,
+    PyCapellaSignedBlindedBeaconBlockMainnet, PyCapellaSignedBlindedBeaconBlockMinimal,
+};
+use crate::convert::format_hash_tree_root;
+use crate::deneb::block::{
+    PyDenebSignedBeaconBlockGnosis, PyDenebSignedBeaconBlockMainnet,
+    PyDenebSignedBeaconBlockMinimal, PyDenebSignedBlindedBeaconBlockGnosis,
+    PyDenebSignedBlindedBeaconBlockMainnet, PyDenebSignedBlindedBeaconBlockMinimal,
+};
+use crate::electra::attestation::{
+    PyAttestationGnosis, PyAttestationMainnet, PyAttestationMinimal, PySingleAttestation,
+};
+use crate::electra::block::{
+    PyBeaconBlockContentsGnosis, PyBeaconBlockContentsMainnet, PyBeaconBlockContentsMinimal,
+    PyBlindedBeaconBlockGnosis, PyBlindedBeaconBlockMainnet, PyBlindedBeaconBlockMinimal,
+    PySignedBeaconBlockContentsGnosis, PySignedBeaconBlockContentsMainnet,
+    PySignedBeaconBlockContentsMinimal, PySignedBeaconBlockGnosis, PySignedBeaconBlockMainnet,
+    PySignedBeaconBlockMinimal, PySignedBlindedBeaconBlockGnosis,
+    PySignedBlindedBeaconBlockMainnet, PySignedBlindedBeaconBlockMinimal,
+};
+use crate::electra::execution::{
+    PyExecutionPayloadGnosis, PyExecutionPayloadHeaderGnosis, PyExecutionPayloadHeaderMainnet,
+    PyExecutionPayloadHeaderMinimal, PyExecutionPayloadMainnet, PyExecutionPayloadMinimal,
+};
+use crate::electra::state::{PyBeaconStateGnosis, PyBeaconStateMainnet, PyBeaconStateMinimal};
+use crate::fulu::block::{
+    PyFuluBeaconBlockContentsGnosis, PyFuluBeaconBlockContentsMainnet,
+    PyFuluBeaconBlockContentsMinimal, PyFuluBlindedBeaconBlockGnosis,
+    PyFuluBlindedBeaconBlockMainnet, PyFuluBlindedBeaconBlockMinimal,
+    PyFuluSignedBeaconBlockContentsGnosis, PyFuluSignedBeaconBlockContentsMainnet,
+    PyFuluSignedBeaconBlockContentsMinimal, PyFuluSignedBeaconBlockGnosis,
+    PyFuluSignedBeaconBlockMainnet, PyFuluSignedBeaconBlockMinimal,
+    PyFuluSignedBlindedBeaconBlockGnosis, PyFuluSignedBlindedBeaconBlockMainnet,
+    PyFuluSignedBlindedBeaconBlockMinimal,
+};
+use crate::fulu::state::{
+    PyFuluBeaconStateGnosis, PyFuluBeaconStateMainnet, PyFuluBeaconStateMinimal,
+};
+use crate::phase0::attestation::{
+    PyPhase0AttestationGnosis, PyPhase0AttestationMainnet, PyPhase0AttestationMinimal,
+};
+use crate::phase0::block::{
+    PyPhase0SignedBeaconBlockGnosis, PyPhase0SignedBeaconBlockMainnet,
+    PyPhase0SignedBeaconBlockMinimal,
+};
+use crate::presets::PresetKind;
+
+const MAGIC: &[u8; 4] = b"GPSZ";
+const HEADER_LEN: usize = 8;
+const PRESET_INDEPENDENT: u8 = 0xff;
+const CONTENT_KEY_LEN: usize = 2 + 32;
+
+/// Fork identifiers used in envelopes, ordered oldest first.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[repr(u8)]
+pub enum Fork {
+    Phase0 = 0,
+    Altair = 1,
+    Bellatrix = 2,
+    Capella = 3,
+    Deneb = 4,
+    Electra = 5,
+    Fulu = 6,
+}
+
+impl Fork {
+    /// All forks, oldest first.
+    pub const ALL: [Self; 7] = [
+        Self::Phase0,
+        Self::Altair,
+        Self::Bellatrix,
+        Self::Capella,
+        Self::Deneb,
+        Self::Electra,
+        Self::Fulu,
+    ];
+
+    /// Returns the lowercase fork name, as used in the consensus specs.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Phase0 => "phase0",
+            Self::Altair => "altair",
+            Self::Bellatrix => "bellatrix",
+            Self::Capella => "capella",
+            Self::Deneb => "deneb",
+            Self::Electra => "electra",
+            Self::Fulu => "fulu",
+        }
+    }
+
+    /// Parses a fork name, ignoring ASCII case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if the name is not a known fork.
+    pub fn parse(name: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|fork| fork.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("Unknown fork: {name:?}"))
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        Self::ALL.into_iter().find(|fork| *fork as u8 == byte)
+    }
+}
+
+/// Returns the envelope byte of a preset.
+#[must_use]
+pub const fn preset_byte(preset: Option<PresetKind>) -> u8 {
+    match preset {
+        Some(PresetKind::Mainnet) => 0,
+        Some(PresetKind::Minimal) => 1,
+        Some(PresetKind::Gnosis) => 2,
+        None => PRESET_INDEPENDENT,
+    }
+}
+
+/// Returns the preset of an envelope byte, `Some(None)` for preset-independent
+/// types and `None` for unknown bytes.
+fn preset_from_byte(byte: u8) -> Option<Option<PresetKind>> {
+    if byte == PRESET_INDEPENDENT {
+        return Some(None);
+    }
+
+    PresetKind::ALL
+        .into_iter()
+        .find(|preset| preset_byte(Some(*preset)) == byte)
+        .map(Some)
+}
+
+/// Type identifiers used in envelopes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u16)]
+pub enum TypeId {
+    SignedBeaconBlock = 1,
+    BeaconBlockContents = 2,
+    SignedBeaconBlockContents = 3,
+    BlindedBeaconBlock = 4,
+    SignedBlindedBeaconBlock = 5,
+    ExecutionPayload = 6,
+    ExecutionPayloadHeader = 7,
+    Attestation = 8,
+    SingleAttestation = 9,
+    BeaconState = 10,
+}
+
+/// A class that can be stored in an envelope.
+struct Entry {
+    fork: Fork,
+    preset: Option<PresetKind>,
+    type_id: TypeId,
+    /// Returns the SSZ encoding if the object is an instance of the class.
+    encode: fn(&Bound<'_, PyAny>) -> Option<Result<Vec<u8>, String>>,
+    /// Returns the hash tree root if the object is an instance of the class.
+    root: fn(&Bound<'_, PyAny>) -> Option<H256>,
+    /// Returns the JSON form if the object is an instance of the class.
+    json: fn(&Bound<'_, PyAny>) -> Option<Result<Value, String>>,
+    decode: fn(Python<'_>, &[u8]) -> PyResult<Py<PyAny>>,
+}
+
+impl Entry {
+    fn header(&self) -> [u8; HEADER_LEN] {
+        let mut header = [0; HEADER_LEN];
+        header[..4].copy_from_slice(MAGIC);
+        header[4] = self.fork as u8;
+        header[5] = preset_byte(self.preset);
+        header[6..].copy_from_slice(&(self.type_id as u16).to_le_bytes());
+        header
+    }
+}
+
+macro_rules! entry {
+    ($class:ident, $fork:ident, $preset:expr, $type_id:ident) => {
+        Entry {
+            fork: Fork::$fork,
+            preset: $preset,
+            type_id: TypeId::$type_id,
+            encode: |object| {
+                object
+                    .cast_exact::<$class>()
+                    .ok()
+                    .map(|object| crate::encode_ssz(&object.borrow().inner))
+            },
+            root: |object| {
+                let object = object.cast_exact::<$class>().ok()?.borrow();
+                let inner = &object.inner;
+                Some(object.py().detach(|| inner.hash_tree_root()))
+            },
+            json: |object| {
+                let object = object.cast_exact::<$class>().ok()?.borrow();
+                let inner = &object.inner;
+                Some(
+                    object
+                        .py()
+                        .detach(|| serde_json::to_value(inner).map_err(|e| e.to_string())),
+                )
+            },
+            decode: |py, bytes| {
+                let inner = py
+                    .detach(|| crate::decode_ssz(bytes))
+                    .map_err(PyValueError::new_err)?;
+                Ok(Py::new(py, $class::from(inner))?.into_any())
+            },
+        }
+    };
+}
+
+const MAINNET: Option<PresetKind> = Some(PresetKind::Mainnet);
+const MINIMAL: Option<PresetKind> = Some(PresetKind::Minimal);
+const GNOSIS: Option<PresetKind> = Some(PresetKind::Gnosis);
+
+static ENTRIES: &[Entry] = &[
+    entry!(
+        PySignedBeaconBlockMainnet,
+        Electra,
+        MAINNET,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PySignedBeaconBlockMinimal,
+        Electra,
+        MINIMAL,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PySignedBeaconBlockGnosis,
+        Electra,
+        GNOSIS,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyBeaconBlockContentsMainnet,
+        Electra,
+        MAINNET,
+        BeaconBlockContents
+    ),
+    entry!(
+        PyBeaconBlockContentsMinimal,
+        Electra,
+        MINIMAL,
+        BeaconBlockContents
+    ),
+    entry!(
+        PyBeaconBlockContentsGnosis,
+        Electra,
+        GNOSIS,
+        BeaconBlockContents
+    ),
+    entry!(
+        PySignedBeaconBlockContentsMainnet,
+        Electra,
+        MAINNET,
+        SignedBeaconBlockContents
+    ),
+    entry!(
+        PySignedBeaconBlockContentsMinimal,
+        Electra,
+        MINIMAL,
+        SignedBeaconBlockContents
+    ),
+    entry!(
+        PySignedBeaconBlockContentsGnosis,
+        Electra,
+        GNOSIS,
+        SignedBeaconBlockContents
+    ),
+    entry!(
+        PyBlindedBeaconBlockMainnet,
+        Electra,
+        MAINNET,
+        BlindedBeaconBlock
+    ),
+    entry!(
+        PyBlindedBeaconBlockMinimal,
+        Electra,
+        MINIMAL,
+        BlindedBeaconBlock
+    ),
+    entry!(
+        PyBlindedBeaconBlockGnosis,
+        Electra,
+        GNOSIS,
+        BlindedBeaconBlock
+    ),
+    entry!(
+        PySignedBlindedBeaconBlockMainnet,
+        Electra,
+        MAINNET,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PySignedBlindedBeaconBlockMinimal,
+        Electra,
+        MINIMAL,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PySignedBlindedBeaconBlockGnosis,
+        Electra,
+        GNOSIS,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PyExecutionPayloadMainnet,
+        Electra,
+        MAINNET,
+        ExecutionPayload
+    ),
+    entry!(
+        PyExecutionPayloadMinimal,
+        Electra,
+        MINIMAL,
+        ExecutionPayload
+    ),
+    entry!(PyExecutionPayloadGnosis, Electra, GNOSIS, ExecutionPayload),
+    entry!(
+        PyExecutionPayloadHeaderMainnet,
+        Electra,
+        MAINNET,
+        ExecutionPayloadHeader
+    ),
+    entry!(
+        PyExecutionPayloadHeaderMinimal,
+        Electra,
+        MINIMAL,
+        ExecutionPayloadHeader
+    ),
+    entry!(
+        PyExecutionPayloadHeaderGnosis,
+        Electra,
+        GNOSIS,
+        ExecutionPayloadHeader
+    ),
+    entry!(PyAttestationMainnet, Electra, MAINNET, Attestation),
+    entry!(PyAttestationMinimal, Electra, MINIMAL, Attestation),
+    entry!(PyAttestationGnosis, Electra, GNOSIS, Attestation),
+    entry!(PySingleAttestation, Electra, None, SingleAttestation),
+    entry!(PyBeaconStateMainnet, Electra, MAINNET, BeaconState),
+    entry!(PyBeaconStateMinimal, Electra, MINIMAL, BeaconState),
+    entry!(PyBeaconStateGnosis, Electra, GNOSIS, BeaconState),
+    entry!(
+        PyFuluSignedBeaconBlockMainnet,
+        Fulu,
+        MAINNET,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyFuluSignedBeaconBlockMinimal,
+        Fulu,
+        MINIMAL,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyFuluSignedBeaconBlockGnosis,
+        Fulu,
+        GNOSIS,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyFuluBeaconBlockContentsMainnet,
+        Fulu,
+        MAINNET,
+        BeaconBlockContents
+    ),
+    entry!(
+        PyFuluBeaconBlockContentsMinimal,
+        Fulu,
+        MINIMAL,
+        BeaconBlockContents
+    ),
+    entry!(
+        PyFuluBeaconBlockContentsGnosis,
+        Fulu,
+        GNOSIS,
+        BeaconBlockContents
+    ),
+    entry!(
+        PyFuluSignedBeaconBlockContentsMainnet,
+        Fulu,
+        MAINNET,
+        SignedBeaconBlockContents
+    ),
+    entry!(
+        PyFuluSignedBeaconBlockContentsMinimal,
+        Fulu,
+        MINIMAL,
+        SignedBeaconBlockContents
+    ),
+    entry!(
+        PyFuluSignedBeaconBlockContentsGnosis,
+        Fulu,
+        GNOSIS,
+        SignedBeaconBlockContents
+    ),
+    entry!(
+        PyFuluBlindedBeaconBlockMainnet,
+        Fulu,
+        MAINNET,
+        BlindedBeaconBlock
+    ),
+    entry!(
+        PyFuluBlindedBeaconBlockMinimal,
+        Fulu,
+        MINIMAL,
+        BlindedBeaconBlock
+    ),
+    entry!(
+        PyFuluBlindedBeaconBlockGnosis,
+        Fulu,
+        GNOSIS,
+        BlindedBeaconBlock
+    ),
+    entry!(
+        PyFuluSignedBlindedBeaconBlockMainnet,
+        Fulu,
+        MAINNET,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PyFuluSignedBlindedBeaconBlockMinimal,
+        Fulu,
+        MINIMAL,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PyFuluSignedBlindedBeaconBlockGnosis,
+        Fulu,
+        GNOSIS,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(PyFuluBeaconStateMainnet, Fulu, MAINNET, BeaconState),
+    entry!(PyFuluBeaconStateMinimal, Fulu, MINIMAL, BeaconState),
+    entry!(PyFuluBeaconStateGnosis, Fulu, GNOSIS, BeaconState),
+    entry!(
+        PyPhase0SignedBeaconBlockMainnet,
+        Phase0,
+        MAINNET,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyPhase0SignedBeaconBlockMinimal,
+        Phase0,
+        MINIMAL,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyPhase0SignedBeaconBlockGnosis,
+        Phase0,
+        GNOSIS,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyAltairSignedBeaconBlockMainnet,
+        Altair,
+        MAINNET,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyAltairSignedBeaconBlockMinimal,
+        Altair,
+        MINIMAL,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyAltairSignedBeaconBlockGnosis,
+        Altair,
+        GNOSIS,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyBellatrixSignedBeaconBlockMainnet,
+        Bellatrix,
+        MAINNET,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyBellatrixSignedBeaconBlockMinimal,
+        Bellatrix,
+        MINIMAL,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyBellatrixSignedBeaconBlockGnosis,
+        Bellatrix,
+        GNOSIS,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyBellatrixSignedBlindedBeaconBlockMainnet,
+        Bellatrix,
+        MAINNET,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PyBellatrixSignedBlindedBeaconBlockMinimal,
+        Bellatrix,
+        MINIMAL,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PyBellatrixSignedBlindedBeaconBlockGnosis,
+        Bellatrix,
+        GNOSIS,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PyCapellaSignedBeaconBlockMainnet,
+        Capella,
+        MAINNET,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyCapellaSignedBeaconBlockMinimal,
+        Capella,
+        MINIMAL,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyCapellaSignedBeaconBlockGnosis,
+        Capella,
+        GNOSIS,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyCapellaSignedBlindedBeaconBlockMainnet,
+        Capella,
+        MAINNET,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PyCapellaSignedBlindedBeaconBlockMinimal,
+        Capella,
+        MINIMAL,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PyCapellaSignedBlindedBeaconBlockGnosis,
+        Capella,
+        GNOSIS,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PyDenebSignedBeaconBlockMainnet,
+        Deneb,
+        MAINNET,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyDenebSignedBeaconBlockMinimal,
+        Deneb,
+        MINIMAL,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyDenebSignedBeaconBlockGnosis,
+        Deneb,
+        GNOSIS,
+        SignedBeaconBlock
+    ),
+    entry!(
+        PyDenebSignedBlindedBeaconBlockMainnet,
+        Deneb,
+        MAINNET,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PyDenebSignedBlindedBeaconBlockMinimal,
+        Deneb,
+        MINIMAL,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(
+        PyDenebSignedBlindedBeaconBlockGnosis,
+        Deneb,
+        GNOSIS,
+        SignedBlindedBeaconBlock
+    ),
+    entry!(PyPhase0AttestationMainnet, Phase0, MAINNET, Attestation),
+    entry!(PyPhase0AttestationMinimal, Phase0, MINIMAL, Attestation),
+    entry!(PyPhase0AttestationGnosis, Phase0, GNOSIS, Attestation),
+];
+
+/// Decodes SSZ bytes as the `type_id` class of `fork` and `preset`, or
+/// returns `None` if there is no such class.
+pub fn decode_as(
+    py: Python<'_>,
+    fork: Fork,
+    preset: Option<PresetKind>,
+    type_id: TypeId,
+    bytes: &[u8],
+) -> Option<PyResult<Py<PyAny>>> {
+    ENTRIES
+        .iter()
+        .find(|entry| entry.fork == fork && entry.preset == preset && entry.type_id == type_id)
+        .map(|entry| (entry.decode)(py, bytes))
+}
+
+/// Returns the JSON form of an object, serialized with the GIL released, or
+/// `None` if its class cannot be enveloped.
+///
+/// This is the value `to_json_canonical` writes out, without the round trip
+/// through bytes.
+pub fn json_value(object: &Bound<'_, PyAny>) -> Option<Result<Value, String>> {
+    ENTRIES.iter().find_map(|entry| (entry.json)(object))
+}
+
+/// Wrap an object's SSZ encoding in a self-describing envelope.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if the object's class cannot be enveloped, or
+/// `PyValueError` if it cannot be SSZ-encoded.
+#[pyfunction]
+pub fn wrap<'py>(py: Python<'py>, object: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyBytes>> {
+    for entry in ENTRIES {
+        if let Some(ssz) = (entry.encode)(object) {
+            let ssz = ssz.map_err(PyValueError::new_err)?;
+            let mut out = Vec::with_capacity(HEADER_LEN + ssz.len());
+            out.extend_from_slice(&entry.header());
+            out.extend_from_slice(&ssz);
+            return Ok(PyBytes::new(py, &out));
+        }
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "{} cannot be wrapped in an envelope",
+        object.get_type().name()?
+    )))
+}
+
+/// Decode an envelope produced by `wrap` into an object of the right class.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the data is not an envelope, names an unknown
+/// type, or its payload cannot be decoded.
+#[pyfunction]
+pub fn unwrap(py: Python<'_>, data: &Bound<'_, PyBytes>) -> PyResult<Py<PyAny>> {
+    let data = data.as_bytes();
+
+    let (header, ssz) = data
+        .split_at_checked(HEADER_LEN)
+        .filter(|(header, _)| header.starts_with(MAGIC))
+        .ok_or_else(|| PyValueError::new_err("Not a grandine_py envelope"))?;
+
+    let entry = ENTRIES
+        .iter()
+        .find(|entry| entry.header() == header)
+        .ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Unknown envelope type (fork {}, preset {}, type {})",
+                header[4],
+                header[5],
+                u16::from_le_bytes([header[6], header[7]]),
+            ))
+        })?;
+
+    (entry.decode)(py, ssz)
+}
+
+/// Return the content key of an object: its fork and preset bytes followed
+/// by its hash tree root.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if the object's class cannot be enveloped.
+#[pyfunction]
+pub fn content_key<'py>(
+    py: Python<'py>,
+    object: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    for entry in ENTRIES {
+        if let Some(root) = (entry.root)(object) {
+            let mut key = Vec::with_capacity(CONTENT_KEY_LEN);
+            key.push(entry.fork as u8);
+            key.push(preset_byte(entry.preset));
+            key.extend_from_slice(root.as_bytes());
+            return Ok(PyBytes::new(py, &key));
+        }
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "{} has no content key",
+        object.get_type().name()?
+    )))
+}
+
+/// Split a content key produced by `content_key` into the fork name, the
+/// preset name (`None` for preset-independent types) and the hash tree root.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the key is not 34 bytes long or names an
+/// unknown fork or preset.
+#[pyfunction]
+pub fn parse_content_key(
+    key: &Bound<'_, PyBytes>,
+) -> PyResult<(&'static str, Option<&'static str>, String)> {
+    let key = key.as_bytes();
+
+    if key.len() != CONTENT_KEY_LEN {
+        return Err(PyValueError::new_err(format!(
+            "Content key must be {CONTENT_KEY_LEN} bytes, got {}",
+            key.len()
+        )));
+    }
+
+    let fork = Fork::from_byte(key[0])
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown fork byte {}", key[0])))?;
+    let preset = preset_from_byte(key[1])
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown preset byte {}", key[1])))?;
+    let root = H256::from_slice(&key[2..]);
+
+    Ok((
+        fork.name(),
+        preset.map(PresetKind::name),
+        format_hash_tree_root(&root),
+    ))
+}
+
+/// Registers the envelope functions with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(wrap, m)?)?;
+    m.add_function(wrap_pyfunction!(unwrap, m)?)?;
+    m.add_function(wrap_pyfunction!(content_key, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_content_key, m)?)?;
+    Ok(())
+}
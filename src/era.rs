@@ -0,0 +1,620 @@
+//! Era archives of finalized history.
+//!
+//! An era file holds the blocks of `SLOTS_PER_HISTORICAL_ROOT` slots and the
+//! state at the end of them, in the e2store format used by beacon nodes to
+//! export and import history. The blocks of era `n` are those of slots
+//! `(n - 1) * SLOTS_PER_HISTORICAL_ROOT` up to the state's slot,
+//! `n * SLOTS_PER_HISTORICAL_ROOT`. Era 0 only holds the genesis state.
+//!
+//! # File format
+//!
+//! All integers are little-endian. Records are typed and length-prefixed:
+//!
+//! ```text
+//! era        = version block* state block-index? state-index
+//! record     = type:[u8; 2] length:u32 reserved:u16 data
+//! version    = record with type "e2" and no data
+//! block      = record with type 0x0100 and a framed snappy SSZ block
+//! state      = record with type 0x0200 and a framed snappy SSZ state
+//! slot-index = record with type "i2" and data
+//!              starting_slot:i64 offset:i64* count:i64
+//! ```
+//!
+//! `offset` is the position of a slot's record relative to the start of the
+//! index record, or 0 for a slot without a block. The block index has an
+//! entry per slot of the era and is left out of era 0; the state index has a
+//! single entry.
+//!
+//! Blocks of every fork can be read and written, so history can be backfilled
+//! from genesis, but states only of Electra and Fulu.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::decode::{
+    decode_forkless, signed_block_slot, state_slot, Forkless, BEACON_STATE, SIGNED_BEACON_BLOCK,
+};
+use crate::envelope::Fork;
+use crate::presets::PresetKind;
+
+const VERSION: [u8; 2] = *b"e2";
+const COMPRESSED_SIGNED_BEACON_BLOCK: [u8; 2] = [0x01, 0x00];
+const COMPRESSED_BEACON_STATE: [u8; 2] = [0x02, 0x00];
+const SLOT_INDEX: [u8; 2] = *b"i2";
+const HEADER_LEN: u64 = 8;
+
+fn io_error(error: &std::io::Error) -> PyErr {
+    pyo3::exceptions::PyOSError::new_err(error.to_string())
+}
+
+fn invalid(message: &str) -> PyErr {
+    PyValueError::new_err(format!("Invalid era file: {message}"))
+}
+
+fn record_header(kind: [u8; 2], length: u32) -> [u8; 8] {
+    let mut header = [0; 8];
+    header[..2].copy_from_slice(&kind);
+    header[2..6].copy_from_slice(&length.to_le_bytes());
+    header
+}
+
+/// Reads the header of the record at `position`, returning its type and
+/// data length.
+fn read_header(file: &mut (impl Read + Seek), position: u64) -> std::io::Result<([u8; 2], u64)> {
+    let mut header = [0; 8];
+    file.seek(SeekFrom::Start(position))?;
+    file.read_exact(&mut header)?;
+
+    let length = u32::from_le_bytes(header[2..6].try_into().expect("4 bytes"));
+    Ok(([header[0], header[1]], u64::from(length)))
+}
+
+fn read_i64(file: &mut (impl Read + Seek), position: u64) -> std::io::Result<i64> {
+    let mut bytes = [0; 8];
+    file.seek(SeekFrom::Start(position))?;
+    file.read_exact(&mut bytes)?;
+    Ok(i64::from_le_bytes(bytes))
+}
+
+/// A slot index record.
+struct SlotIndex {
+    /// Position of the index record in the file.
+    position: u64,
+    starting_slot: u64,
+    /// Positions of the records of each slot, `None` for empty slots.
+    records: Vec<Option<u64>>,
+}
+
+/// Reads the slot index that ends at `end`, or returns `None` if the bytes
+/// before `end` are not one.
+fn read_slot_index(file: &mut (impl Read + Seek), end: u64) -> PyResult<Option<SlotIndex>> {
+    if end < 2 * HEADER_LEN + 16 {
+        return Ok(None);
+    }
+
+    let count = read_i64(file, end - 8).map_err(|e| io_error(&e))?;
+    let Some(length) = u64::try_from(count)
+        .ok()
+        .and_then(|count| count.checked_mul(8)?.checked_add(16))
+    else {
+        return Ok(None);
+    };
+    let Some(position) = end
+        .checked_sub(HEADER_LEN + length)
+        .filter(|position| *position >= HEADER_LEN)
+    else {
+        return Ok(None);
+    };
+
+    if read_header(file, position).map_err(|e| io_error(&e))? != (SLOT_INDEX, length) {
+        return Ok(None);
+    }
+
+    let mut data = vec![0; usize::try_from(length - 8).map_err(|_| invalid("index too large"))?];
+    file.read_exact(&mut data).map_err(|e| io_error(&e))?;
+
+    let mut values = data
+        .chunks_exact(8)
+        .map(|chunk| i64::from_le_bytes(chunk.try_into().expect("8 bytes")));
+    let starting_slot = values
+        .next()
+        .and_then(|slot| u64::try_from(slot).ok())
+        .ok_or_else(|| invalid("negative starting slot"))?;
+
+    let records = values
+        .map(|offset| match offset {
+            0 => Ok(None),
+            offset => position
+                .checked_add_signed(offset)
+                .filter(|record| (HEADER_LEN..position).contains(record))
+                .map(Some)
+                .ok_or_else(|| invalid("index offset out of range")),
+        })
+        .collect::<PyResult<_>>()?;
+
+    Ok(Some(SlotIndex {
+        position,
+        starting_slot,
+        records,
+    }))
+}
+
+/// Returns the SSZ encoding of an object of a generated class.
+fn to_ssz(object: &Bound<'_, PyAny>) -> PyResult<Vec<u8>> {
+    Ok(object
+        .call_method0("to_ssz")?
+        .cast_into::<PyBytes>()?
+        .as_bytes()
+        .to_vec())
+}
+
+// =============================================================================
+// Writer
+// =============================================================================
+
+struct WriterState {
+    file: BufWriter<File>,
+    position: u64,
+    /// Slots and record positions of the blocks written so far.
+    blocks: Vec<(u64, u64)>,
+}
+
+impl WriterState {
+    fn write_record(&mut self, kind: [u8; 2], data: &[u8]) -> PyResult<u64> {
+        let length =
+            u32::try_from(data.len()).map_err(|_| PyValueError::new_err("Record is too large"))?;
+        let position = self.position;
+
+        let mut write = || -> std::io::Result<()> {
+            self.file.write_all(&record_header(kind, length))?;
+            self.file.write_all(data)
+        };
+
+        write().map_err(|e| io_error(&e))?;
+        self.position += HEADER_LEN + u64::from(length);
+        Ok(position)
+    }
+
+    fn write_slot_index(&mut self, starting_slot: u64, records: &[Option<u64>]) -> PyResult<()> {
+        let position = self.position;
+        let mut data = Vec::with_capacity(8 * (records.len() + 2));
+        data.extend_from_slice(&starting_slot.to_le_bytes());
+
+        for record in records {
+            // Records precede the index, so the offsets are negative.
+            #[allow(clippy::cast_possible_wrap)]
+            let offset = record.map_or(0, |record| -((position - record) as i64));
+            data.extend_from_slice(&offset.to_le_bytes());
+        }
+
+        data.extend_from_slice(&(records.len() as u64).to_le_bytes());
+        self.write_record(SLOT_INDEX, &data).map(|_| ())
+    }
+}
+
+/// Writes an era file.
+///
+/// Blocks are added in slot order with `add_block`, and `finish` adds the
+/// state at the end of the era along with the indices.
+#[pyclass(name = "EraWriter", frozen)]
+pub struct PyEraWriter {
+    state: Mutex<Option<WriterState>>,
+    preset: PresetKind,
+}
+
+impl PyEraWriter {
+    fn lock(&self) -> PyResult<MutexGuard<'_, Option<WriterState>>> {
+        self.state
+            .lock()
+            .map_err(|_| PyRuntimeError::new_err("EraWriter lock is poisoned"))
+    }
+}
+
+#[pymethods]
+impl PyEraWriter {
+    #[new]
+    #[pyo3(signature = (path, preset = "mainnet"))]
+    /// Create an era file, replacing any existing file at `path`.
+    ///
+    /// `preset` sets the length of an era.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the preset is unknown, or `OSError` if the
+    /// file cannot be created.
+    fn new(path: PathBuf, preset: &str) -> PyResult<Self> {
+        let preset = PresetKind::parse(preset).map_err(PyValueError::new_err)?;
+        let file = File::create(&path).map_err(|e| io_error(&e))?;
+
+        let mut state = WriterState {
+            file: BufWriter::new(file),
+            position: 0,
+            blocks: Vec::new(),
+        };
+        state.write_record(VERSION, &[])?;
+
+        Ok(Self {
+            state: Mutex::new(Some(state)),
+            preset,
+        })
+    }
+
+    /// Append a `SignedBeaconBlock`. Blocks must be added in slot order.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the writer is closed or the block is not
+    /// after the previous one, and `OSError` if writing fails.
+    fn add_block(&self, py: Python<'_>, block: &Bound<'_, PyAny>) -> PyResult<()> {
+        let ssz = to_ssz(block)?;
+        let slot = signed_block_slot(&ssz)
+            .ok_or_else(|| PyValueError::new_err("Data is too short for a signed beacon block"))?;
+
+        let mut guard = self.lock()?;
+        let state = guard
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("EraWriter is closed"))?;
+
+        if let Some((last, _)) = state.blocks.last().filter(|(last, _)| *last >= slot) {
+            return Err(PyValueError::new_err(format!(
+                "Block at slot {slot} is not after the previous block at slot {last}"
+            )));
+        }
+
+        let compressed = py
+            .detach(|| crate::snappy::compress(&ssz, true))
+            .map_err(PyValueError::new_err)?;
+        let position = state.write_record(COMPRESSED_SIGNED_BEACON_BLOCK, &compressed)?;
+        state.blocks.push((slot, position));
+        Ok(())
+    }
+
+    /// Append the `BeaconState` at the end of the era and the indices, then
+    /// close the file. Returns the era number.
+    ///
+    /// The state's slot must be a multiple of `SLOTS_PER_HISTORICAL_ROOT`,
+    /// and every block must be in the era it ends.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the writer is closed or a block is outside
+    /// the era, and `OSError` if writing fails.
+    fn finish(&self, py: Python<'_>, state: &Bound<'_, PyAny>) -> PyResult<u64> {
+        let ssz = to_ssz(state)?;
+        let slot = state_slot(&ssz)
+            .ok_or_else(|| PyValueError::new_err("Data is too short for a beacon state"))?;
+        let slots_per_era = self.preset.slots_per_historical_root();
+
+        if slot % slots_per_era != 0 {
+            return Err(PyValueError::new_err(format!(
+                "State slot {slot} is not a multiple of SLOTS_PER_HISTORICAL_ROOT ({slots_per_era})"
+            )));
+        }
+
+        let era = slot / slots_per_era;
+        let starting_slot = slot.saturating_sub(slots_per_era);
+
+        let mut guard = self.lock()?;
+        let writer = guard
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("EraWriter is closed"))?;
+
+        if let Some((block_slot, _)) = writer
+            .blocks
+            .iter()
+            .find(|(block_slot, _)| era == 0 || !(starting_slot..slot).contains(block_slot))
+        {
+            return Err(PyValueError::new_err(format!(
+                "Block at slot {block_slot} is not in era {era}"
+            )));
+        }
+
+        let compressed = py
+            .detach(|| crate::snappy::compress(&ssz, true))
+            .map_err(PyValueError::new_err)?;
+        let state_position = writer.write_record(COMPRESSED_BEACON_STATE, &compressed)?;
+
+        if era > 0 {
+            #[allow(clippy::cast_possible_truncation)]
+            let mut records = vec![None; slots_per_era as usize];
+
+            for (block_slot, position) in &writer.blocks {
+                #[allow(clippy::cast_possible_truncation)]
+                records[(block_slot - starting_slot) as usize] = Some(*position);
+            }
+
+            writer.write_slot_index(starting_slot, &records)?;
+        }
+
+        writer.write_slot_index(slot, &[Some(state_position)])?;
+        writer.file.flush().map_err(|e| io_error(&e))?;
+        guard.take();
+        Ok(era)
+    }
+
+    /// Close the file. An era file closed before `finish` is incomplete.
+    ///
+    /// # Errors
+    /// Returns `OSError` if flushing fails.
+    fn close(&self) -> PyResult<()> {
+        if let Some(mut state) = self.lock()?.take() {
+            state.file.flush().map_err(|e| io_error(&e))?;
+        }
+        Ok(())
+    }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    fn __exit__(
+        &self,
+        _exc_type: Option<&Bound<'_, PyAny>>,
+        _exc_value: Option<&Bound<'_, PyAny>>,
+        _traceback: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<()> {
+        self.close()
+    }
+}
+
+// =============================================================================
+// Reader
+// =============================================================================
+
+/// Reads an era file.
+///
+/// Supports `len()` and iteration over the blocks, decoded as the crate's
+/// `SignedBeaconBlock` classes, and `state()` for the state at the end of the
+/// era.
+#[pyclass(name = "EraReader", frozen)]
+pub struct PyEraReader {
+    file: Mutex<BufReader<File>>,
+    preset: PresetKind,
+    fork: Option<Fork>,
+    fork_schedule: Option<HashMap<String, u64>>,
+    /// The first slot of the block index, if there is one.
+    starting_slot: Option<u64>,
+    /// Slots and record positions of the blocks.
+    blocks: Vec<(u64, u64)>,
+    state_slot: u64,
+    state_position: u64,
+}
+
+impl PyEraReader {
+    fn lock(&self) -> PyResult<MutexGuard<'_, BufReader<File>>> {
+        self.file
+            .lock()
+            .map_err(|_| PyRuntimeError::new_err("EraReader lock is poisoned"))
+    }
+
+    /// Reads the record at `position` and returns its decompressed data.
+    fn read_record(&self, position: u64, kind: [u8; 2]) -> PyResult<Vec<u8>> {
+        let compressed = {
+            let mut file = self.lock()?;
+            let (actual, length) = read_header(&mut *file, position).map_err(|e| io_error(&e))?;
+
+            if actual != kind {
+                return Err(invalid(&format!(
+                    "expected a record of type {kind:02x?} at {position}, found {actual:02x?}"
+                )));
+            }
+
+            let mut data =
+                vec![0; usize::try_from(length).map_err(|_| invalid("record too large"))?];
+            file.read_exact(&mut data).map_err(|e| io_error(&e))?;
+            data
+        };
+
+        crate::snappy::decompress(&compressed, Some(true)).map_err(PyValueError::new_err)
+    }
+
+    fn block_position(&self, slot: u64) -> Option<u64> {
+        self.blocks
+            .binary_search_by_key(&slot, |(block_slot, _)| *block_slot)
+            .ok()
+            .map(|index| self.blocks[index].1)
+    }
+
+    fn decode(&self, py: Python<'_>, forkless: &Forkless, ssz: &[u8]) -> PyResult<Py<PyAny>> {
+        decode_forkless(
+            py,
+            forkless,
+            ssz,
+            self.preset,
+            self.fork,
+            self.fork_schedule.as_ref(),
+        )
+        .map(|(object, _)| object)
+    }
+}
+
+#[pymethods]
+impl PyEraReader {
+    #[new]
+    #[pyo3(signature = (path, preset = "mainnet", fork = None, fork_schedule = None))]
+    /// Open an era file.
+    ///
+    /// Blocks and the state are decoded as in `decode_signed_beacon_block`:
+    /// as `fork` if given, otherwise as the fork active at their slot
+    /// according to `fork_schedule`, otherwise as the oldest fork they decode
+    /// as.
+    ///
+    /// # Errors
+    /// Returns `OSError` if the file cannot be read, or `PyValueError` if the
+    /// preset or fork is unknown or the file is not an era file.
+    fn new(
+        py: Python<'_>,
+        path: PathBuf,
+        preset: &str,
+        fork: Option<&str>,
+        fork_schedule: Option<HashMap<String, u64>>,
+    ) -> PyResult<Self> {
+        let preset = PresetKind::parse(preset).map_err(PyValueError::new_err)?;
+        let fork = fork
+            .map(|name| Fork::parse(name).map_err(PyValueError::new_err))
+            .transpose()?;
+
+        py.detach(|| {
+            let file = File::open(&path).map_err(|e| io_error(&e))?;
+            let file_len = file.metadata().map_err(|e| io_error(&e))?.len();
+            let mut file = BufReader::new(file);
+
+            if file_len < HEADER_LEN
+                || read_header(&mut file, 0).map_err(|e| io_error(&e))? != (VERSION, 0)
+            {
+                return Err(invalid("missing version record"));
+            }
+
+            let state_index = read_slot_index(&mut file, file_len)?
+                .ok_or_else(|| invalid("missing state index"))?;
+
+            let [Some(state_position)] = state_index.records[..] else {
+                return Err(invalid("state index must have a single entry"));
+            };
+
+            let block_index = read_slot_index(&mut file, state_index.position)?;
+
+            let blocks = block_index.as_ref().map_or_else(Vec::new, |index| {
+                (index.starting_slot..)
+                    .zip(&index.records)
+                    .filter_map(|(slot, record)| Some((slot, (*record)?)))
+                    .collect()
+            });
+
+            Ok(Self {
+                file: Mutex::new(file),
+                preset,
+                fork,
+                fork_schedule,
+                starting_slot: block_index.map(|index| index.starting_slot),
+                blocks,
+                state_slot: state_index.starting_slot,
+                state_position,
+            })
+        })
+    }
+
+    /// The era number, the state's slot divided by `SLOTS_PER_HISTORICAL_ROOT`.
+    #[getter]
+    fn era(&self) -> u64 {
+        self.state_slot / self.preset.slots_per_historical_root()
+    }
+
+    /// The first slot of the era's blocks, `None` for the genesis era.
+    #[getter]
+    fn starting_slot(&self) -> Option<u64> {
+        self.starting_slot
+    }
+
+    #[getter]
+    fn state_slot(&self) -> u64 {
+        self.state_slot
+    }
+
+    fn __len__(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Return the slots that have a block, in order.
+    fn slots(&self) -> Vec<u64> {
+        self.blocks.iter().map(|(slot, _)| *slot).collect()
+    }
+
+    /// Return the SSZ encoding of the block at `slot`, or `None` if the slot
+    /// has no block in the file.
+    ///
+    /// # Errors
+    /// Returns `OSError` if reading fails, or `PyValueError` if the record is
+    /// invalid.
+    fn block_ssz<'py>(&self, py: Python<'py>, slot: u64) -> PyResult<Option<Bound<'py, PyBytes>>> {
+        let Some(position) = self.block_position(slot) else {
+            return Ok(None);
+        };
+
+        let ssz = py.detach(|| self.read_record(position, COMPRESSED_SIGNED_BEACON_BLOCK))?;
+        Ok(Some(PyBytes::new(py, &ssz)))
+    }
+
+    /// Return the block at `slot`, or `None` if the slot has no block in the
+    /// file.
+    ///
+    /// # Errors
+    /// Returns `OSError` if reading fails, or `PyValueError` if the block
+    /// cannot be decoded.
+    fn block(&self, py: Python<'_>, slot: u64) -> PyResult<Option<Py<PyAny>>> {
+        let Some(position) = self.block_position(slot) else {
+            return Ok(None);
+        };
+
+        let ssz = py.detach(|| self.read_record(position, COMPRESSED_SIGNED_BEACON_BLOCK))?;
+        self.decode(py, &SIGNED_BEACON_BLOCK, &ssz).map(Some)
+    }
+
+    /// Return the SSZ encoding of the state at the end of the era.
+    ///
+    /// # Errors
+    /// Returns `OSError` if reading fails, or `PyValueError` if the record is
+    /// invalid.
+    fn state_ssz<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let ssz = py.detach(|| self.read_record(self.state_position, COMPRESSED_BEACON_STATE))?;
+        Ok(PyBytes::new(py, &ssz))
+    }
+
+    /// Return the state at the end of the era.
+    ///
+    /// # Errors
+    /// Returns `OSError` if reading fails, or `PyValueError` if the state
+    /// cannot be decoded.
+    fn state(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let ssz = py.detach(|| self.read_record(self.state_position, COMPRESSED_BEACON_STATE))?;
+        self.decode(py, &BEACON_STATE, &ssz)
+    }
+
+    fn __iter__(slf: Py<Self>) -> PyEraBlocks {
+        PyEraBlocks {
+            reader: slf,
+            position: 0,
+        }
+    }
+}
+
+/// Iterator over the blocks of an era file.
+#[pyclass(name = "EraBlocks")]
+pub struct PyEraBlocks {
+    reader: Py<PyEraReader>,
+    position: usize,
+}
+
+#[pymethods]
+impl PyEraBlocks {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        let reader = self.reader.get();
+
+        let Some(&(slot, _)) = reader.blocks.get(self.position) else {
+            return Ok(None);
+        };
+
+        self.position += 1;
+        reader.block(py, slot)
+    }
+}
+
+/// Registers the era file classes with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyEraWriter>()?;
+    m.add_class::<PyEraReader>()?;
+    m.add_class::<PyEraBlocks>()?;
+    Ok(())
+}
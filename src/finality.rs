@@ -0,0 +1,404 @@
+//! Justified and finalized checkpoint tracking from raw data feeds.
+//!
+//! `FinalityTracker` keeps the latest justified and finalized checkpoints it
+//! has seen and the slot of the latest block or state, so that monitoring can
+//! alert on finality lag without running a node. Checkpoints come from:
+//! - beacon states, which carry both checkpoints
+//! - Beacon API `finality_checkpoints` responses
+//! - blocks and attestations, whose attestation sources are the justified
+//!   checkpoint of the attesters
+//!
+//! Justification itself needs validator balances, so blocks and attestations
+//! only report checkpoints that are already justified, about an epoch after a
+//! state would, and never finalization.
+//!
+//! Checkpoints only move forward: one replaces the tracked checkpoint if its
+//! epoch is later, and every replacement is returned as an event. Only
+//! attestations that have been validated or included in blocks should be
+//! fed, as the source of any other attestation is unchecked.
+
+use grandine_types::phase0::containers::Checkpoint;
+use grandine_types::preset::{Mainnet, Minimal};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::aggregation::{attestation_data, attestation_preset};
+use crate::convert::{extract_epoch, format_hash_tree_root, parse_root};
+use crate::electra::block::{
+    PySignedBeaconBlockGnosis, PySignedBeaconBlockMainnet, PySignedBeaconBlockMinimal,
+};
+use crate::electra::state::{PyBeaconStateGnosis, PyBeaconStateMainnet, PyBeaconStateMinimal};
+use crate::fulu::block::{
+    PyFuluSignedBeaconBlockGnosis, PyFuluSignedBeaconBlockMainnet, PyFuluSignedBeaconBlockMinimal,
+};
+use crate::fulu::state::{
+    PyFuluBeaconStateGnosis, PyFuluBeaconStateMainnet, PyFuluBeaconStateMinimal,
+};
+use crate::presets::{NamedPreset, PresetKind};
+use crate::Gnosis;
+
+/// Which checkpoint an event is about.
+#[derive(Clone, Copy)]
+enum Kind {
+    Justified,
+    Finalized,
+}
+
+impl Kind {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Justified => "justified",
+            Self::Finalized => "finalized",
+        }
+    }
+}
+
+/// A replacement of a tracked checkpoint.
+struct Event {
+    kind: Kind,
+    checkpoint: Checkpoint,
+    previous: Option<Checkpoint>,
+    slot: Option<u64>,
+}
+
+impl Event {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("kind", self.kind.name())?;
+        dict.set_item("epoch", self.checkpoint.epoch)?;
+        dict.set_item("root", format_hash_tree_root(&self.checkpoint.root))?;
+        dict.set_item("previous_epoch", self.previous.map(|c| c.epoch))?;
+        dict.set_item(
+            "previous_root",
+            self.previous
+                .as_ref()
+                .map(|c| format_hash_tree_root(&c.root)),
+        )?;
+        dict.set_item("slot", self.slot)?;
+        Ok(dict)
+    }
+}
+
+/// Extracts a checkpoint dict with an `epoch` and a `root`.
+fn extract_checkpoint(value: &Bound<'_, PyAny>) -> PyResult<Checkpoint> {
+    let dict = value
+        .cast::<PyDict>()
+        .map_err(|_| PyTypeError::new_err("Expected a checkpoint dict with an epoch and a root"))?;
+    let field = |name: &str| {
+        dict.get_item(name)?
+            .ok_or_else(|| PyValueError::new_err(format!("Checkpoint is missing {name:?}")))
+    };
+
+    Ok(Checkpoint {
+        epoch: extract_epoch(&field("epoch")?)?,
+        root: parse_root(&field("root")?)?,
+    })
+}
+
+/// Tracks the justified and finalized checkpoints of a chain from its
+/// states, blocks and attestations.
+#[pyclass(name = "FinalityTracker")]
+pub struct PyFinalityTracker {
+    preset: PresetKind,
+    justified: Option<Checkpoint>,
+    finalized: Option<Checkpoint>,
+    head_slot: Option<u64>,
+}
+
+impl PyFinalityTracker {
+    fn check_preset(&self, preset: PresetKind) -> PyResult<()> {
+        if preset == self.preset {
+            Ok(())
+        } else {
+            Err(PyValueError::new_err(format!(
+                "Expected a {} object, got a {} one",
+                self.preset.name(),
+                preset.name()
+            )))
+        }
+    }
+
+    fn observe_slot(&mut self, slot: u64) {
+        self.head_slot = Some(self.head_slot.map_or(slot, |head| head.max(slot)));
+    }
+
+    /// Replaces a tracked checkpoint if `checkpoint` is later.
+    fn advance(&mut self, kind: Kind, checkpoint: Checkpoint, events: &mut Vec<Event>) {
+        let tracked = match kind {
+            Kind::Justified => &mut self.justified,
+            Kind::Finalized => &mut self.finalized,
+        };
+
+        if tracked.is_some_and(|tracked| checkpoint.epoch <= tracked.epoch) {
+            return;
+        }
+
+        events.push(Event {
+            kind,
+            checkpoint,
+            previous: tracked.replace(checkpoint),
+            slot: self.head_slot,
+        });
+    }
+
+    /// Applies the checkpoints of a state or response. The finalized
+    /// checkpoint goes first, as it is the older one.
+    fn apply(
+        &mut self,
+        justified: Option<Checkpoint>,
+        finalized: Option<Checkpoint>,
+    ) -> Vec<Event> {
+        let mut events = Vec::new();
+        if let Some(finalized) = finalized {
+            self.advance(Kind::Finalized, finalized, &mut events);
+        }
+        if let Some(justified) = justified {
+            self.advance(Kind::Justified, justified, &mut events);
+        }
+        events
+    }
+
+    fn state_checkpoints(
+        &self,
+        state: &Bound<'_, PyAny>,
+    ) -> PyResult<Option<(u64, Checkpoint, Checkpoint)>> {
+        macro_rules! try_states {
+            ($($class:ty => $preset:ty),* $(,)?) => {
+                $(
+                    if let Ok(state) = state.cast::<$class>() {
+                        self.check_preset(<$preset as NamedPreset>::KIND)?;
+                        let state = state.borrow();
+                        let inner = &state.inner;
+                        return Ok(Some((
+                            inner.slot,
+                            inner.current_justified_checkpoint,
+                            inner.finalized_checkpoint,
+                        )));
+                    }
+                )*
+            };
+        }
+
+        try_states!(
+            PyBeaconStateMainnet => Mainnet,
+            PyBeaconStateMinimal => Minimal,
+            PyBeaconStateGnosis => Gnosis,
+            PyFuluBeaconStateMainnet => Mainnet,
+            PyFuluBeaconStateMinimal => Minimal,
+            PyFuluBeaconStateGnosis => Gnosis,
+        );
+
+        Ok(None)
+    }
+
+    fn block_sources(&self, block: &Bound<'_, PyAny>) -> PyResult<Option<(u64, Vec<Checkpoint>)>> {
+        macro_rules! try_blocks {
+            ($($class:ty => $preset:ty),* $(,)?) => {
+                $(
+                    if let Ok(block) = block.cast::<$class>() {
+                        self.check_preset(<$preset as NamedPreset>::KIND)?;
+                        let block = block.borrow();
+                        let message = &block.inner.message;
+                        let sources = message
+                            .body
+                            .attestations
+                            .iter()
+                            .map(|attestation| attestation.data.source)
+                            .collect();
+                        return Ok(Some((message.slot, sources)));
+                    }
+                )*
+            };
+        }
+
+        try_blocks!(
+            PySignedBeaconBlockMainnet => Mainnet,
+            PySignedBeaconBlockMinimal => Minimal,
+            PySignedBeaconBlockGnosis => Gnosis,
+            PyFuluSignedBeaconBlockMainnet => Mainnet,
+            PyFuluSignedBeaconBlockMinimal => Minimal,
+            PyFuluSignedBeaconBlockGnosis => Gnosis,
+        );
+
+        Ok(None)
+    }
+}
+
+fn into_py_events(py: Python<'_>, events: &[Event]) -> PyResult<Py<PyList>> {
+    let list = PyList::empty(py);
+    for event in events {
+        list.append(event.to_dict(py)?)?;
+    }
+    Ok(list.unbind())
+}
+
+#[pymethods]
+impl PyFinalityTracker {
+    /// Create a tracker for chains of `preset`.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the preset is unknown.
+    #[new]
+    #[pyo3(signature = (preset = "mainnet"))]
+    fn py_new(preset: &str) -> PyResult<Self> {
+        Ok(Self {
+            preset: PresetKind::parse(preset).map_err(PyValueError::new_err)?,
+            justified: None,
+            finalized: None,
+            head_slot: None,
+        })
+    }
+
+    /// Take the checkpoints and slot of a beacon state, returning the
+    /// resulting events.
+    ///
+    /// # Errors
+    /// Returns `PyTypeError` if `state` is not a beacon state, or
+    /// `PyValueError` if it is of another preset.
+    fn update_from_state(
+        &mut self,
+        py: Python<'_>,
+        state: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyList>> {
+        let Some((slot, justified, finalized)) = self.state_checkpoints(state)? else {
+            return Err(PyTypeError::new_err(format!(
+                "Expected a beacon state, got {}",
+                state.get_type().name()?
+            )));
+        };
+
+        self.observe_slot(slot);
+        into_py_events(py, &self.apply(Some(justified), Some(finalized)))
+    }
+
+    /// Take the slot of a signed beacon block and the justified checkpoint
+    /// its attestations vote from, returning the resulting events.
+    ///
+    /// # Errors
+    /// Returns `PyTypeError` if `block` is not a signed beacon block, or
+    /// `PyValueError` if it is of another preset.
+    fn update_from_block(
+        &mut self,
+        py: Python<'_>,
+        block: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyList>> {
+        let Some((slot, sources)) = self.block_sources(block)? else {
+            return Err(PyTypeError::new_err(format!(
+                "Expected a signed beacon block, got {}",
+                block.get_type().name()?
+            )));
+        };
+
+        self.observe_slot(slot);
+        let latest = sources.into_iter().max_by_key(|source| source.epoch);
+        into_py_events(py, &self.apply(latest, None))
+    }
+
+    /// Take the justified checkpoint an attestation votes from, returning
+    /// the resulting events.
+    ///
+    /// # Errors
+    /// Returns `PyTypeError` if `attestation` is not an attestation, or
+    /// `PyValueError` if it is of another preset.
+    fn update_from_attestation(
+        &mut self,
+        py: Python<'_>,
+        attestation: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyList>> {
+        let data = attestation_data(attestation)?;
+        if let Some(preset) = attestation_preset(attestation) {
+            self.check_preset(preset)?;
+        }
+
+        into_py_events(py, &self.apply(Some(data.source), None))
+    }
+
+    /// Take the checkpoints of a Beacon API `finality_checkpoints` response,
+    /// returning the resulting events.
+    ///
+    /// Accepts the parsed response or its `data`, with `current_justified`
+    /// and `finalized` checkpoint dicts of an `epoch` and a `root`. A
+    /// `previous_justified` checkpoint is ignored.
+    ///
+    /// # Errors
+    /// Returns `PyTypeError` or `PyValueError` if a checkpoint is missing or
+    /// invalid.
+    fn update_from_finality_checkpoints(
+        &mut self,
+        py: Python<'_>,
+        response: &Bound<'_, PyDict>,
+    ) -> PyResult<Py<PyList>> {
+        let data = match response.get_item("data")? {
+            Some(data) => data.cast_into::<PyDict>().map_err(|_| {
+                PyTypeError::new_err("Expected the data of the response to be a dict")
+            })?,
+            None => response.clone(),
+        };
+        let field = |name: &str| {
+            data.get_item(name)?.ok_or_else(|| {
+                PyValueError::new_err(format!("Finality checkpoints are missing {name:?}"))
+            })
+        };
+
+        let justified = extract_checkpoint(&field("current_justified")?)?;
+        let finalized = extract_checkpoint(&field("finalized")?)?;
+        into_py_events(py, &self.apply(Some(justified), Some(finalized)))
+    }
+
+    /// The latest justified checkpoint as `(epoch, root)`, or `None` before
+    /// any was seen.
+    #[getter]
+    fn justified(&self) -> Option<(u64, String)> {
+        self.justified
+            .map(|c| (c.epoch, format_hash_tree_root(&c.root)))
+    }
+
+    /// The latest finalized checkpoint as `(epoch, root)`, or `None` before
+    /// any was seen.
+    #[getter]
+    fn finalized(&self) -> Option<(u64, String)> {
+        self.finalized
+            .map(|c| (c.epoch, format_hash_tree_root(&c.root)))
+    }
+
+    /// The latest slot of a state or block, or `None` before any was seen.
+    #[getter]
+    fn head_slot(&self) -> Option<u64> {
+        self.head_slot
+    }
+
+    /// Return the number of epochs from the finalized checkpoint to
+    /// `current_epoch`, by default the epoch of the head slot.
+    ///
+    /// Returns `None` if no checkpoint was finalized or, without
+    /// `current_epoch`, no slot was seen. A healthy chain has a lag of 2.
+    #[pyo3(signature = (current_epoch = None))]
+    fn finality_lag(&self, current_epoch: Option<u64>) -> Option<u64> {
+        let current_epoch =
+            current_epoch.or_else(|| Some(self.head_slot? / self.preset.slots_per_epoch()))?;
+        Some(current_epoch.saturating_sub(self.finalized?.epoch))
+    }
+
+    fn __repr__(&self) -> String {
+        let epoch = |checkpoint: Option<Checkpoint>| {
+            checkpoint.map_or_else(|| "None".to_owned(), |c| c.epoch.to_string())
+        };
+        format!(
+            "FinalityTracker(preset={:?}, justified_epoch={}, finalized_epoch={})",
+            self.preset.name(),
+            epoch(self.justified),
+            epoch(self.finalized)
+        )
+    }
+}
+
+/// Registers `FinalityTracker` with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyFinalityTracker>()?;
+    Ok(())
+}
@@ -0,0 +1,3190 @@
+//! Fulu beacon block types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `SignedBeaconBlock` (Mainnet, Minimal, Gnosis)
+//! - `BeaconBlockContents` (Mainnet, Minimal, Gnosis), carrying cell proofs
+//! - `SignedBeaconBlockContents` (Mainnet, Minimal, Gnosis)
+//! - `BlindedBeaconBlock` (Mainnet, Minimal, Gnosis)
+//! - `SignedBlindedBeaconBlock` (Mainnet, Minimal, Gnosis)
+//!
+//! Fulu blocks have the Electra layout, so the block types wrap the Electra
+//! containers and only differ in their class names. Block contents do
+//! change: with PeerDAS (EIP-7594) the proposer publishes a KZG proof for
+//! every cell of the extended blobs instead of one per blob, so `kzg_proofs`
+//! holds `CELLS_PER_EXT_BLOB` (128) proofs per blob, blob by blob.
+//!
+//! As in Electra, mutable copies of block contents and blinded blocks
+//! (`clone_mut`) take a new graffiti, state root and execution payload or
+//! payload header before signing, and block contents and blinded blocks
+//! cache their block root and the body and payload roots below it.
+
+use grandine_ssz::{ContiguousList, Ssz, SszReadDefault as _};
+use grandine_types::deneb::containers::ExecutionPayload;
+use grandine_types::deneb::primitives::{Blob, KzgCommitment, KzgProof};
+use grandine_types::electra::containers::{
+    BeaconBlock, BlindedBeaconBlock, SignedBeaconBlock, SignedBlindedBeaconBlock,
+};
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use paste::paste;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use typenum::Unsigned as _;
+
+use crate::bls::parse_signature;
+use crate::capella::bls_change::PySignedBlsToExecutionChange;
+use crate::config::PyConfig;
+use crate::convert::{format_hash_tree_root, parse_root};
+use crate::domains::proposer_domain;
+use crate::electra::attestation::{
+    PyAttestationGnosis, PyAttestationMainnet, PyAttestationMinimal, PyAttesterSlashingGnosis,
+    PyAttesterSlashingMainnet, PyAttesterSlashingMinimal,
+};
+use crate::electra::block::{
+    blind_block, decode_each, header_dict_impl, header_tuple_impl, recompute_roots_impl,
+    require_valid_proofs, signed_header_impl, typed_slot, unblind_block, verify_contents_proofs,
+    HeaderTuple,
+};
+use crate::electra::execution::{
+    el_withdrawals_root_impl, payload_stats_impl, requests_hash_impl, PyExecutionPayloadGnosis,
+    PyExecutionPayloadHeaderGnosis, PyExecutionPayloadHeaderMainnet,
+    PyExecutionPayloadHeaderMinimal, PyExecutionPayloadMainnet, PyExecutionPayloadMinimal,
+};
+use crate::kzg::{verify_blob_cell_proofs, PyTrustedSetup};
+use crate::list::PySszList;
+use crate::merkle::{generalized_index_impl, prove_impl, prove_path_impl, PathElement};
+use crate::phase0::deposit::PyDeposit;
+use crate::phase0::exit::PySignedVoluntaryExit;
+use crate::phase0::header::{PyProposerSlashing, PySignedBeaconBlockHeader};
+use crate::primitives::PySlot;
+use crate::root_cache::{BlockRoots, EXECUTION_PAYLOAD, GRAFFITI};
+use crate::signing::{object_signing_root, signing_root, verify_object};
+use crate::validate::{check_structure_impl, verify_signatures_impl};
+use crate::Gnosis;
+use crate::{define_ssz_pyclass_for_preset, ssz_list_view};
+
+/// Block contents with the cell proofs of the blobs.
+#[derive(Clone, PartialEq, Eq, Default, Debug, Deserialize, Serialize, Ssz)]
+#[serde(bound = "")]
+pub struct BeaconBlockContents<P: Preset> {
+    pub block: BeaconBlock<P>,
+    pub kzg_proofs: ContiguousList<KzgProof, P::MaxCellProofsPerBlock>,
+    pub blobs: ContiguousList<Blob<P>, P::MaxBlobCommitmentsPerBlock>,
+}
+
+/// Signed block contents with the cell proofs of the blobs.
+#[derive(Clone, PartialEq, Eq, Default, Debug, Deserialize, Serialize, Ssz)]
+#[serde(bound = "")]
+pub struct SignedBeaconBlockContents<P: Preset> {
+    pub signed_block: SignedBeaconBlock<P>,
+    pub kzg_proofs: ContiguousList<KzgProof, P::MaxCellProofsPerBlock>,
+    pub blobs: ContiguousList<Blob<P>, P::MaxBlobCommitmentsPerBlock>,
+}
+
+/// Attaches blobs and their cell proofs to a signed block.
+///
+/// There must be one blob per KZG commitment in the block body and
+/// `CELLS_PER_EXT_BLOB` proofs per blob, in the same order. Proofs are not
+/// verified against the commitments.
+///
+/// # Errors
+///
+/// Returns an error string if the counts do not match the commitments or a
+/// blob or proof has the wrong length.
+pub fn attach_blobs<P: Preset>(
+    signed_block: SignedBeaconBlock<P>,
+    blobs: &[Vec<u8>],
+    cell_proofs: &[Vec<u8>],
+) -> Result<SignedBeaconBlockContents<P>, String> {
+    let commitments = signed_block.message.body.blob_kzg_commitments.len();
+    let proofs = commitments * P::CellsPerExtBlob::USIZE;
+
+    if blobs.len() != commitments {
+        return Err(format!(
+            "Expected {commitments} blobs for {commitments} KZG commitments, got {}",
+            blobs.len()
+        ));
+    }
+
+    if cell_proofs.len() != proofs {
+        return Err(format!(
+            "Expected {proofs} cell proofs for {commitments} KZG commitments, got {}",
+            cell_proofs.len()
+        ));
+    }
+
+    let blobs = decode_each::<Blob<P>>(blobs, "blob")?;
+    let kzg_proofs = decode_each::<KzgProof>(cell_proofs, "cell proof")?;
+
+    Ok(SignedBeaconBlockContents {
+        signed_block,
+        kzg_proofs: ContiguousList::try_from_iter(kzg_proofs).map_err(|e| e.to_string())?,
+        blobs: ContiguousList::try_from_iter(blobs).map_err(|e| e.to_string())?,
+    })
+}
+
+/// The blobs bundle a relay returns with the execution payload of a
+/// submitted blinded block, with the cell proofs of the blobs.
+#[derive(Clone, PartialEq, Eq, Default, Debug, Deserialize, Serialize, Ssz)]
+#[serde(bound = "")]
+pub struct BlobsBundle<P: Preset> {
+    pub commitments: ContiguousList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>,
+    pub proofs: ContiguousList<KzgProof, P::MaxCellProofsPerBlock>,
+    pub blobs: ContiguousList<Blob<P>, P::MaxBlobCommitmentsPerBlock>,
+}
+
+/// Unblinds a signed blinded block with the SSZ-encoded execution payload
+/// and blobs bundle a relay returned for it.
+///
+/// # Errors
+///
+/// Returns an error string if an encoding is invalid, the payload or the
+/// bundle does not match the block, or the bundle does not have one blob and
+/// `CELLS_PER_EXT_BLOB` cell proofs per commitment.
+pub fn unblind<P: Preset>(
+    signed_blinded_block: &SignedBlindedBeaconBlock<P>,
+    execution_payload_ssz: &[u8],
+    blobs_bundle_ssz: &[u8],
+) -> Result<SignedBeaconBlockContents<P>, String> {
+    let execution_payload = ExecutionPayload::<P>::from_ssz_default(execution_payload_ssz)
+        .map_err(|e| format!("Invalid execution payload: {e}"))?;
+    let bundle = BlobsBundle::<P>::from_ssz_default(blobs_bundle_ssz)
+        .map_err(|e| format!("Invalid blobs bundle: {e}"))?;
+    let commitments = bundle.commitments.len();
+    let proofs = commitments * P::CellsPerExtBlob::USIZE;
+
+    if bundle.blobs.len() != commitments || bundle.proofs.len() != proofs {
+        return Err(format!(
+            "Expected {commitments} blobs and {proofs} cell proofs for {commitments} KZG \
+             commitments, got {} blobs and {} proofs",
+            bundle.blobs.len(),
+            bundle.proofs.len()
+        ));
+    }
+
+    Ok(SignedBeaconBlockContents {
+        signed_block: unblind_block(signed_blinded_block, execution_payload, &bundle.commitments)?,
+        kzg_proofs: bundle.proofs,
+        blobs: bundle.blobs,
+    })
+}
+
+paste! {
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluSignedBeaconBlockMainnet>],
+        "FuluSignedBeaconBlockMainnet",
+        SignedBeaconBlock<Mainnet>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.message, self.inner.signature, pubkey, domain)
+            }
+
+            /// Verify the proposer, RANDAO reveal, attestation and sync
+            /// aggregate signatures in one batch, returning a dict of the
+            /// results under `proposer`, `randao_reveal`, `attestations` (one
+            /// per attestation) and `sync_aggregate`.
+            ///
+            /// `pubkey_provider` is a `PubkeyIndexMap` or a callable mapping
+            /// validator indices to pubkeys, `committees` a callable taking a
+            /// slot and committee index and returning the committee's
+            /// validator indices, and `sync_committee` the validator indices
+            /// of the sync committee. See the `validate` module documentation.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if an argument has the wrong length or a
+            /// pubkey is missing, or `PyTypeError` if `pubkey_provider` is
+            /// neither a `PubkeyIndexMap` nor callable.
+            #[pyo3(signature = (pubkey_provider, genesis_validators_root, fork_version, committees = None, sync_committee = None))]
+            pub fn verify_signatures<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                pubkey_provider: &pyo3::Bound<'py, pyo3::PyAny>,
+                genesis_validators_root: &pyo3::Bound<'py, pyo3::PyAny>,
+                fork_version: &pyo3::Bound<'py, pyo3::PyAny>,
+                committees: Option<&pyo3::Bound<'py, pyo3::PyAny>>,
+                sync_committee: Option<Vec<u64>>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyDict>> {
+                verify_signatures_impl(
+                    py,
+                    &self.inner,
+                    pubkey_provider,
+                    genesis_validators_root,
+                    fork_version,
+                    committees,
+                    sync_committee,
+                )
+            }
+
+            /// Check the invariants of the body that SSZ decoding does not
+            /// enforce, returning the broken ones as messages; an empty list
+            /// means the block passes.
+            pub fn check_structure(&self, py: pyo3::Python<'_>) -> Vec<String> {
+                py.detach(|| check_structure_impl(&self.inner.message))
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attestations, class = PyAttestationMainnet)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attester_slashings, class = PyAttesterSlashingMainnet)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.execution_payload.withdrawals, json)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Mainnet>(self.inner.message.slot, config)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.message.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.message.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                py.detach(|| signed_header_impl(&self.inner.message, self.inner.signature))
+            }
+
+            /// Attach blobs and their cell proofs, e.g. to restore block
+            /// contents saved with `strip_blobs`.
+            ///
+            /// With `verify`, the KZG proofs are checked against the
+            /// commitments, with `setup` or the default trusted setup.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob per KZG
+            /// commitment and 128 cell proofs per blob, a blob or proof has
+            /// the wrong length, or with `verify` a proof does not verify.
+            #[pyo3(signature = (blobs, cell_proofs, verify = false, setup = None))]
+            pub fn with_blobs(
+                &self,
+                py: pyo3::Python<'_>,
+                blobs: Vec<Vec<u8>>,
+                cell_proofs: Vec<Vec<u8>>,
+                verify: bool,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<[<PyFuluSignedBeaconBlockContentsMainnet>]> {
+                let inner = attach_blobs(self.inner.clone(), &blobs, &cell_proofs)
+                    .map_err(PyValueError::new_err)?;
+
+                if verify {
+                    require_valid_proofs::<Mainnet>(
+                        py,
+                        &inner.signed_block.message.body.blob_kzg_commitments,
+                        &inner.blobs,
+                        &inner.kzg_proofs,
+                        setup.as_deref(),
+                        verify_blob_cell_proofs,
+                    )?;
+                }
+
+                Ok([<PyFuluSignedBeaconBlockContentsMainnet>] { inner })
+            }
+
+            /// Return the signed blinded block, with the execution payload
+            /// replaced by its header. The signature stays valid.
+            pub fn blind(&self, py: pyo3::Python<'_>) -> [<PyFuluSignedBlindedBeaconBlockMainnet>] {
+                [<PyFuluSignedBlindedBeaconBlockMainnet>] {
+                    inner: py.detach(|| blind_block(&self.inner)),
+                }
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.message, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluBeaconBlockContentsMainnet>],
+        "FuluBeaconBlockContentsMainnet",
+        BeaconBlockContents<Mainnet>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                let root = py.detach(|| {
+                    signing_root(self.roots.block_root(&self.inner.block), domain)
+                });
+                Ok(format_hash_tree_root(&root))
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs, view)
+            }
+
+            /// The cell proofs, `CELLS_PER_EXT_BLOB` (128) per blob.
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.kzg_proofs, view)
+            }
+
+            /// Verify the cell proofs of each blob against its commitment in
+            /// the block body. The cells are computed from the blobs.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob per KZG
+            /// commitment and 128 cell proofs per blob, or a commitment or
+            /// proof is not a valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_blob_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                verify_contents_proofs::<Mainnet>(
+                    py,
+                    &self.inner.block.body.blob_kzg_commitments,
+                    &self.inner.blobs,
+                    &self.inner.kzg_proofs,
+                    setup.as_deref(),
+                    verify_blob_cell_proofs,
+                )
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.attestations, class = PyAttestationMainnet)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.attester_slashings, class = PyAttesterSlashingMainnet)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Mainnet>(self.inner.block.slot, config)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.execution_payload.withdrawals, json)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.block.body.execution_requests, py)
+            }
+
+            pub fn header_dict(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                header_dict_impl(&self.inner.block, py)
+            }
+
+            pub fn header_tuple<'py>(&self, py: pyo3::Python<'py>) -> HeaderTuple<'py> {
+                header_tuple_impl(&self.inner.block, py)
+            }
+
+            pub fn sign(
+                &self,
+                signature: &str,
+            ) -> pyo3::PyResult<[<PyFuluSignedBeaconBlockContentsMainnet>]> {
+                let signature = parse_signature(signature)?;
+
+                let signed = SignedBeaconBlockContents::<Mainnet> {
+                    signed_block: SignedBeaconBlock::<Mainnet> {
+                        message: self.inner.block.clone(),
+                        signature,
+                    },
+                    kzg_proofs: self.inner.kzg_proofs.clone(),
+                    blobs: self.inner.blobs.clone(),
+                };
+
+                Ok([<PyFuluSignedBeaconBlockContentsMainnet>] { inner: signed })
+            }
+
+            pub fn block_hash_tree_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.block_root(&self.inner.block)))
+            }
+
+            /// Hash tree root of the block body, cached with the block root.
+            pub fn body_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.body_root(&self.inner.block)))
+            }
+
+            /// Hash tree root of the execution payload, cached with the block
+            /// root.
+            pub fn payload_root(&self, py: pyo3::Python<'_>) -> String {
+                let root = py.detach(|| {
+                    self.roots.field_root(&self.inner.block, EXECUTION_PAYLOAD)
+                });
+                format_hash_tree_root(&root)
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.block, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.block, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.block, &path)
+            }
+        },
+        mutable = (
+            [<PyFuluMutableBeaconBlockContentsMainnet>],
+            "FuluMutableBeaconBlockContentsMainnet",
+            {
+                /// Recompute the roots that do not depend on the state (the EL
+                /// `block_hash` of the execution payload), e.g. after editing a
+                /// test fixture, and return the fixed fields as
+                /// `{path: (old, new)}`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if the payload cannot be SSZ-encoded.
+                pub fn recompute_roots(
+                    &mut self,
+                    py: pyo3::Python<'_>,
+                ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                    let changes = recompute_roots_impl(&mut self.inner.block, "block.", py)?;
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                    Ok(changes)
+                }
+
+                /// Set the 32-byte graffiti, e.g. as rendered by
+                /// `render_graffiti`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+                pub fn set_graffiti(
+                    &mut self,
+                    graffiti: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.block.body.graffiti = parse_root(graffiti)?;
+                    self.roots.clear_field(GRAFFITI);
+                    Ok(())
+                }
+
+                /// Set the state root, e.g. as computed by the beacon node for
+                /// the patched block.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `state_root` is not 32 bytes.
+                pub fn set_state_root(
+                    &mut self,
+                    state_root: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.block.state_root = parse_root(state_root)?;
+                    self.roots.clear_block();
+                    Ok(())
+                }
+
+                /// Replace the execution payload, e.g. with one built by a
+                /// different execution client.
+                pub fn set_execution_payload(
+                    &mut self,
+                    payload: pyo3::PyRef<'_, [<PyExecutionPayloadMainnet>]>,
+                ) {
+                    self.inner.block.body.execution_payload = payload.inner.clone();
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                }
+            }
+        ),
+        root_cache = BlockRoots
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluSignedBeaconBlockContentsMainnet>],
+        "FuluSignedBeaconBlockContentsMainnet",
+        SignedBeaconBlockContents<Mainnet>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.signed_block.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.signed_block.message, self.inner.signed_block.signature, pubkey, domain)
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs, view)
+            }
+
+            /// The cell proofs, `CELLS_PER_EXT_BLOB` (128) per blob.
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.kzg_proofs, view)
+            }
+
+            /// Verify the cell proofs of each blob against its commitment in
+            /// the block body. The cells are computed from the blobs.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob per KZG
+            /// commitment and 128 cell proofs per blob, or a commitment or
+            /// proof is not a valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_blob_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                verify_contents_proofs::<Mainnet>(
+                    py,
+                    &self.inner.signed_block.message.body.blob_kzg_commitments,
+                    &self.inner.blobs,
+                    &self.inner.kzg_proofs,
+                    setup.as_deref(),
+                    verify_blob_cell_proofs,
+                )
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Mainnet>(self.inner.signed_block.message.slot, config)
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.attestations, class = PyAttestationMainnet)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.attester_slashings, class = PyAttesterSlashingMainnet)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.execution_payload.withdrawals, json)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.signed_block.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                let signed_block = &self.inner.signed_block;
+                py.detach(|| signed_header_impl(&signed_block.message, signed_block.signature))
+            }
+
+            /// Return the signed block without its blobs and cell proofs.
+            pub fn strip_blobs(&self) -> [<PyFuluSignedBeaconBlockMainnet>] {
+                [<PyFuluSignedBeaconBlockMainnet>] {
+                    inner: self.inner.signed_block.clone(),
+                }
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.signed_block.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.signed_block.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.signed_block.message, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluBlindedBeaconBlockMainnet>],
+        "FuluBlindedBeaconBlockMainnet",
+        BlindedBeaconBlock<Mainnet>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                let root = py.detach(|| {
+                    signing_root(self.roots.block_root(&self.inner), domain)
+                });
+                Ok(format_hash_tree_root(&root))
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.attestations, class = PyAttestationMainnet)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.attester_slashings, class = PyAttesterSlashingMainnet)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Mainnet>(self.inner.slot, config)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.body.execution_requests, py)
+            }
+
+            pub fn header_dict(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                header_dict_impl(&self.inner, py)
+            }
+
+            pub fn header_tuple<'py>(&self, py: pyo3::Python<'py>) -> HeaderTuple<'py> {
+                header_tuple_impl(&self.inner, py)
+            }
+
+            pub fn sign(
+                &self,
+                signature: &str,
+            ) -> pyo3::PyResult<[<PyFuluSignedBlindedBeaconBlockMainnet>]> {
+                let signature = parse_signature(signature)?;
+
+                Ok([<PyFuluSignedBlindedBeaconBlockMainnet>] {
+                    inner: SignedBlindedBeaconBlock {
+                        message: self.inner.clone(),
+                        signature,
+                    },
+                })
+            }
+
+            pub fn block_hash_tree_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.block_root(&self.inner)))
+            }
+
+            /// Hash tree root of the block body, cached with the block root.
+            /// It is that of the full block body.
+            pub fn body_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.body_root(&self.inner)))
+            }
+
+            /// Hash tree root of the execution payload header, which is that
+            /// of the payload it stands for, cached with the block root.
+            pub fn payload_root(&self, py: pyo3::Python<'_>) -> String {
+                let root = py.detach(|| {
+                    self.roots.field_root(&self.inner, EXECUTION_PAYLOAD)
+                });
+                format_hash_tree_root(&root)
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload_header", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner, &path)
+            }
+        },
+        mutable = (
+            [<PyFuluMutableBlindedBeaconBlockMainnet>],
+            "FuluMutableBlindedBeaconBlockMainnet",
+            {
+                /// Set the 32-byte graffiti, e.g. as rendered by
+                /// `render_graffiti`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+                pub fn set_graffiti(
+                    &mut self,
+                    graffiti: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.body.graffiti = parse_root(graffiti)?;
+                    self.roots.clear_field(GRAFFITI);
+                    Ok(())
+                }
+
+                /// Set the state root, e.g. as computed by the beacon node for
+                /// the patched block.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `state_root` is not 32 bytes.
+                pub fn set_state_root(
+                    &mut self,
+                    state_root: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.state_root = parse_root(state_root)?;
+                    self.roots.clear_block();
+                    Ok(())
+                }
+
+                /// Replace the execution payload header, e.g. with that of a
+                /// different builder bid.
+                pub fn set_execution_payload_header(
+                    &mut self,
+                    header: pyo3::PyRef<'_, [<PyExecutionPayloadHeaderMainnet>]>,
+                ) {
+                    self.inner.body.execution_payload_header = header.inner.clone();
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                }
+            }
+        ),
+        root_cache = BlockRoots
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluSignedBlindedBeaconBlockMainnet>],
+        "FuluSignedBlindedBeaconBlockMainnet",
+        SignedBlindedBeaconBlock<Mainnet>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.message, self.inner.signature, pubkey, domain)
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attestations, class = PyAttestationMainnet)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attester_slashings, class = PyAttesterSlashingMainnet)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Mainnet>(self.inner.message.slot, config)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                py.detach(|| signed_header_impl(&self.inner.message, self.inner.signature))
+            }
+
+            /// Return the signed block contents of the block from the
+            /// SSZ-encoded execution payload and blobs bundle, with cell
+            /// proofs, the relay returned for it.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if an encoding is invalid, the payload
+            /// does not match the payload header, or the bundle does not match
+            /// the KZG commitments.
+            pub fn unblind(
+                &self,
+                py: pyo3::Python<'_>,
+                execution_payload_ssz: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+                blobs_bundle_ssz: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+            ) -> pyo3::PyResult<[<PyFuluSignedBeaconBlockContentsMainnet>]> {
+                let execution_payload_ssz = execution_payload_ssz.as_bytes();
+                let blobs_bundle_ssz = blobs_bundle_ssz.as_bytes();
+                py.detach(|| unblind(&self.inner, execution_payload_ssz, blobs_bundle_ssz))
+                    .map(|inner| [<PyFuluSignedBeaconBlockContentsMainnet>] { inner })
+                    .map_err(PyValueError::new_err)
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload_header", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.message, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluSignedBeaconBlockMinimal>],
+        "FuluSignedBeaconBlockMinimal",
+        SignedBeaconBlock<Minimal>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.message, self.inner.signature, pubkey, domain)
+            }
+
+            /// Verify the proposer, RANDAO reveal, attestation and sync
+            /// aggregate signatures in one batch, returning a dict of the
+            /// results under `proposer`, `randao_reveal`, `attestations` (one
+            /// per attestation) and `sync_aggregate`.
+            ///
+            /// `pubkey_provider` is a `PubkeyIndexMap` or a callable mapping
+            /// validator indices to pubkeys, `committees` a callable taking a
+            /// slot and committee index and returning the committee's
+            /// validator indices, and `sync_committee` the validator indices
+            /// of the sync committee. See the `validate` module documentation.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if an argument has the wrong length or a
+            /// pubkey is missing, or `PyTypeError` if `pubkey_provider` is
+            /// neither a `PubkeyIndexMap` nor callable.
+            #[pyo3(signature = (pubkey_provider, genesis_validators_root, fork_version, committees = None, sync_committee = None))]
+            pub fn verify_signatures<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                pubkey_provider: &pyo3::Bound<'py, pyo3::PyAny>,
+                genesis_validators_root: &pyo3::Bound<'py, pyo3::PyAny>,
+                fork_version: &pyo3::Bound<'py, pyo3::PyAny>,
+                committees: Option<&pyo3::Bound<'py, pyo3::PyAny>>,
+                sync_committee: Option<Vec<u64>>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyDict>> {
+                verify_signatures_impl(
+                    py,
+                    &self.inner,
+                    pubkey_provider,
+                    genesis_validators_root,
+                    fork_version,
+                    committees,
+                    sync_committee,
+                )
+            }
+
+            /// Check the invariants of the body that SSZ decoding does not
+            /// enforce, returning the broken ones as messages; an empty list
+            /// means the block passes.
+            pub fn check_structure(&self, py: pyo3::Python<'_>) -> Vec<String> {
+                py.detach(|| check_structure_impl(&self.inner.message))
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attestations, class = PyAttestationMinimal)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attester_slashings, class = PyAttesterSlashingMinimal)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.execution_payload.withdrawals, json)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Minimal>(self.inner.message.slot, config)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.message.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.message.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                py.detach(|| signed_header_impl(&self.inner.message, self.inner.signature))
+            }
+
+            /// Attach blobs and their cell proofs, e.g. to restore block
+            /// contents saved with `strip_blobs`.
+            ///
+            /// With `verify`, the KZG proofs are checked against the
+            /// commitments, with `setup` or the default trusted setup.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob per KZG
+            /// commitment and 128 cell proofs per blob, a blob or proof has
+            /// the wrong length, or with `verify` a proof does not verify.
+            #[pyo3(signature = (blobs, cell_proofs, verify = false, setup = None))]
+            pub fn with_blobs(
+                &self,
+                py: pyo3::Python<'_>,
+                blobs: Vec<Vec<u8>>,
+                cell_proofs: Vec<Vec<u8>>,
+                verify: bool,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<[<PyFuluSignedBeaconBlockContentsMinimal>]> {
+                let inner = attach_blobs(self.inner.clone(), &blobs, &cell_proofs)
+                    .map_err(PyValueError::new_err)?;
+
+                if verify {
+                    require_valid_proofs::<Minimal>(
+                        py,
+                        &inner.signed_block.message.body.blob_kzg_commitments,
+                        &inner.blobs,
+                        &inner.kzg_proofs,
+                        setup.as_deref(),
+                        verify_blob_cell_proofs,
+                    )?;
+                }
+
+                Ok([<PyFuluSignedBeaconBlockContentsMinimal>] { inner })
+            }
+
+            /// Return the signed blinded block, with the execution payload
+            /// replaced by its header. The signature stays valid.
+            pub fn blind(&self, py: pyo3::Python<'_>) -> [<PyFuluSignedBlindedBeaconBlockMinimal>] {
+                [<PyFuluSignedBlindedBeaconBlockMinimal>] {
+                    inner: py.detach(|| blind_block(&self.inner)),
+                }
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.message, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluBeaconBlockContentsMinimal>],
+        "FuluBeaconBlockContentsMinimal",
+        BeaconBlockContents<Minimal>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                let root = py.detach(|| {
+                    signing_root(self.roots.block_root(&self.inner.block), domain)
+                });
+                Ok(format_hash_tree_root(&root))
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs, view)
+            }
+
+            /// The cell proofs, `CELLS_PER_EXT_BLOB` (128) per blob.
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.kzg_proofs, view)
+            }
+
+            /// Verify the cell proofs of each blob against its commitment in
+            /// the block body. The cells are computed from the blobs.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob per KZG
+            /// commitment and 128 cell proofs per blob, or a commitment or
+            /// proof is not a valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_blob_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                verify_contents_proofs::<Minimal>(
+                    py,
+                    &self.inner.block.body.blob_kzg_commitments,
+                    &self.inner.blobs,
+                    &self.inner.kzg_proofs,
+                    setup.as_deref(),
+                    verify_blob_cell_proofs,
+                )
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.attestations, class = PyAttestationMinimal)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.attester_slashings, class = PyAttesterSlashingMinimal)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Minimal>(self.inner.block.slot, config)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.execution_payload.withdrawals, json)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.block.body.execution_requests, py)
+            }
+
+            pub fn header_dict(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                header_dict_impl(&self.inner.block, py)
+            }
+
+            pub fn header_tuple<'py>(&self, py: pyo3::Python<'py>) -> HeaderTuple<'py> {
+                header_tuple_impl(&self.inner.block, py)
+            }
+
+            pub fn sign(
+                &self,
+                signature: &str,
+            ) -> pyo3::PyResult<[<PyFuluSignedBeaconBlockContentsMinimal>]> {
+                let signature = parse_signature(signature)?;
+
+                let signed = SignedBeaconBlockContents::<Minimal> {
+                    signed_block: SignedBeaconBlock::<Minimal> {
+                        message: self.inner.block.clone(),
+                        signature,
+                    },
+                    kzg_proofs: self.inner.kzg_proofs.clone(),
+                    blobs: self.inner.blobs.clone(),
+                };
+
+                Ok([<PyFuluSignedBeaconBlockContentsMinimal>] { inner: signed })
+            }
+
+            pub fn block_hash_tree_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.block_root(&self.inner.block)))
+            }
+
+            /// Hash tree root of the block body, cached with the block root.
+            pub fn body_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.body_root(&self.inner.block)))
+            }
+
+            /// Hash tree root of the execution payload, cached with the block
+            /// root.
+            pub fn payload_root(&self, py: pyo3::Python<'_>) -> String {
+                let root = py.detach(|| {
+                    self.roots.field_root(&self.inner.block, EXECUTION_PAYLOAD)
+                });
+                format_hash_tree_root(&root)
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.block, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.block, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.block, &path)
+            }
+        },
+        mutable = (
+            [<PyFuluMutableBeaconBlockContentsMinimal>],
+            "FuluMutableBeaconBlockContentsMinimal",
+            {
+                /// Recompute the roots that do not depend on the state (the EL
+                /// `block_hash` of the execution payload), e.g. after editing a
+                /// test fixture, and return the fixed fields as
+                /// `{path: (old, new)}`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if the payload cannot be SSZ-encoded.
+                pub fn recompute_roots(
+                    &mut self,
+                    py: pyo3::Python<'_>,
+                ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                    let changes = recompute_roots_impl(&mut self.inner.block, "block.", py)?;
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                    Ok(changes)
+                }
+
+                /// Set the 32-byte graffiti, e.g. as rendered by
+                /// `render_graffiti`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+                pub fn set_graffiti(
+                    &mut self,
+                    graffiti: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.block.body.graffiti = parse_root(graffiti)?;
+                    self.roots.clear_field(GRAFFITI);
+                    Ok(())
+                }
+
+                /// Set the state root, e.g. as computed by the beacon node for
+                /// the patched block.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `state_root` is not 32 bytes.
+                pub fn set_state_root(
+                    &mut self,
+                    state_root: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.block.state_root = parse_root(state_root)?;
+                    self.roots.clear_block();
+                    Ok(())
+                }
+
+                /// Replace the execution payload, e.g. with one built by a
+                /// different execution client.
+                pub fn set_execution_payload(
+                    &mut self,
+                    payload: pyo3::PyRef<'_, [<PyExecutionPayloadMinimal>]>,
+                ) {
+                    self.inner.block.body.execution_payload = payload.inner.clone();
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                }
+            }
+        ),
+        root_cache = BlockRoots
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluSignedBeaconBlockContentsMinimal>],
+        "FuluSignedBeaconBlockContentsMinimal",
+        SignedBeaconBlockContents<Minimal>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.signed_block.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.signed_block.message, self.inner.signed_block.signature, pubkey, domain)
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs, view)
+            }
+
+            /// The cell proofs, `CELLS_PER_EXT_BLOB` (128) per blob.
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.kzg_proofs, view)
+            }
+
+            /// Verify the cell proofs of each blob against its commitment in
+            /// the block body. The cells are computed from the blobs.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob per KZG
+            /// commitment and 128 cell proofs per blob, or a commitment or
+            /// proof is not a valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_blob_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                verify_contents_proofs::<Minimal>(
+                    py,
+                    &self.inner.signed_block.message.body.blob_kzg_commitments,
+                    &self.inner.blobs,
+                    &self.inner.kzg_proofs,
+                    setup.as_deref(),
+                    verify_blob_cell_proofs,
+                )
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Minimal>(self.inner.signed_block.message.slot, config)
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.attestations, class = PyAttestationMinimal)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.attester_slashings, class = PyAttesterSlashingMinimal)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.execution_payload.withdrawals, json)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.signed_block.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                let signed_block = &self.inner.signed_block;
+                py.detach(|| signed_header_impl(&signed_block.message, signed_block.signature))
+            }
+
+            /// Return the signed block without its blobs and cell proofs.
+            pub fn strip_blobs(&self) -> [<PyFuluSignedBeaconBlockMinimal>] {
+                [<PyFuluSignedBeaconBlockMinimal>] {
+                    inner: self.inner.signed_block.clone(),
+                }
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.signed_block.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.signed_block.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.signed_block.message, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluBlindedBeaconBlockMinimal>],
+        "FuluBlindedBeaconBlockMinimal",
+        BlindedBeaconBlock<Minimal>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                let root = py.detach(|| {
+                    signing_root(self.roots.block_root(&self.inner), domain)
+                });
+                Ok(format_hash_tree_root(&root))
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.attestations, class = PyAttestationMinimal)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.attester_slashings, class = PyAttesterSlashingMinimal)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Minimal>(self.inner.slot, config)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.body.execution_requests, py)
+            }
+
+            pub fn header_dict(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                header_dict_impl(&self.inner, py)
+            }
+
+            pub fn header_tuple<'py>(&self, py: pyo3::Python<'py>) -> HeaderTuple<'py> {
+                header_tuple_impl(&self.inner, py)
+            }
+
+            pub fn sign(
+                &self,
+                signature: &str,
+            ) -> pyo3::PyResult<[<PyFuluSignedBlindedBeaconBlockMinimal>]> {
+                let signature = parse_signature(signature)?;
+
+                Ok([<PyFuluSignedBlindedBeaconBlockMinimal>] {
+                    inner: SignedBlindedBeaconBlock {
+                        message: self.inner.clone(),
+                        signature,
+                    },
+                })
+            }
+
+            pub fn block_hash_tree_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.block_root(&self.inner)))
+            }
+
+            /// Hash tree root of the block body, cached with the block root.
+            /// It is that of the full block body.
+            pub fn body_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.body_root(&self.inner)))
+            }
+
+            /// Hash tree root of the execution payload header, which is that
+            /// of the payload it stands for, cached with the block root.
+            pub fn payload_root(&self, py: pyo3::Python<'_>) -> String {
+                let root = py.detach(|| {
+                    self.roots.field_root(&self.inner, EXECUTION_PAYLOAD)
+                });
+                format_hash_tree_root(&root)
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload_header", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner, &path)
+            }
+        },
+        mutable = (
+            [<PyFuluMutableBlindedBeaconBlockMinimal>],
+            "FuluMutableBlindedBeaconBlockMinimal",
+            {
+                /// Set the 32-byte graffiti, e.g. as rendered by
+                /// `render_graffiti`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+                pub fn set_graffiti(
+                    &mut self,
+                    graffiti: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.body.graffiti = parse_root(graffiti)?;
+                    self.roots.clear_field(GRAFFITI);
+                    Ok(())
+                }
+
+                /// Set the state root, e.g. as computed by the beacon node for
+                /// the patched block.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `state_root` is not 32 bytes.
+                pub fn set_state_root(
+                    &mut self,
+                    state_root: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.state_root = parse_root(state_root)?;
+                    self.roots.clear_block();
+                    Ok(())
+                }
+
+                /// Replace the execution payload header, e.g. with that of a
+                /// different builder bid.
+                pub fn set_execution_payload_header(
+                    &mut self,
+                    header: pyo3::PyRef<'_, [<PyExecutionPayloadHeaderMinimal>]>,
+                ) {
+                    self.inner.body.execution_payload_header = header.inner.clone();
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                }
+            }
+        ),
+        root_cache = BlockRoots
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluSignedBlindedBeaconBlockMinimal>],
+        "FuluSignedBlindedBeaconBlockMinimal",
+        SignedBlindedBeaconBlock<Minimal>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.message, self.inner.signature, pubkey, domain)
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attestations, class = PyAttestationMinimal)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attester_slashings, class = PyAttesterSlashingMinimal)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Minimal>(self.inner.message.slot, config)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                py.detach(|| signed_header_impl(&self.inner.message, self.inner.signature))
+            }
+
+            /// Return the signed block contents of the block from the
+            /// SSZ-encoded execution payload and blobs bundle, with cell
+            /// proofs, the relay returned for it.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if an encoding is invalid, the payload
+            /// does not match the payload header, or the bundle does not match
+            /// the KZG commitments.
+            pub fn unblind(
+                &self,
+                py: pyo3::Python<'_>,
+                execution_payload_ssz: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+                blobs_bundle_ssz: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+            ) -> pyo3::PyResult<[<PyFuluSignedBeaconBlockContentsMinimal>]> {
+                let execution_payload_ssz = execution_payload_ssz.as_bytes();
+                let blobs_bundle_ssz = blobs_bundle_ssz.as_bytes();
+                py.detach(|| unblind(&self.inner, execution_payload_ssz, blobs_bundle_ssz))
+                    .map(|inner| [<PyFuluSignedBeaconBlockContentsMinimal>] { inner })
+                    .map_err(PyValueError::new_err)
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload_header", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.message, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluSignedBeaconBlockGnosis>],
+        "FuluSignedBeaconBlockGnosis",
+        SignedBeaconBlock<Gnosis>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.message, self.inner.signature, pubkey, domain)
+            }
+
+            /// Verify the proposer, RANDAO reveal, attestation and sync
+            /// aggregate signatures in one batch, returning a dict of the
+            /// results under `proposer`, `randao_reveal`, `attestations` (one
+            /// per attestation) and `sync_aggregate`.
+            ///
+            /// `pubkey_provider` is a `PubkeyIndexMap` or a callable mapping
+            /// validator indices to pubkeys, `committees` a callable taking a
+            /// slot and committee index and returning the committee's
+            /// validator indices, and `sync_committee` the validator indices
+            /// of the sync committee. See the `validate` module documentation.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if an argument has the wrong length or a
+            /// pubkey is missing, or `PyTypeError` if `pubkey_provider` is
+            /// neither a `PubkeyIndexMap` nor callable.
+            #[pyo3(signature = (pubkey_provider, genesis_validators_root, fork_version, committees = None, sync_committee = None))]
+            pub fn verify_signatures<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                pubkey_provider: &pyo3::Bound<'py, pyo3::PyAny>,
+                genesis_validators_root: &pyo3::Bound<'py, pyo3::PyAny>,
+                fork_version: &pyo3::Bound<'py, pyo3::PyAny>,
+                committees: Option<&pyo3::Bound<'py, pyo3::PyAny>>,
+                sync_committee: Option<Vec<u64>>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyDict>> {
+                verify_signatures_impl(
+                    py,
+                    &self.inner,
+                    pubkey_provider,
+                    genesis_validators_root,
+                    fork_version,
+                    committees,
+                    sync_committee,
+                )
+            }
+
+            /// Check the invariants of the body that SSZ decoding does not
+            /// enforce, returning the broken ones as messages; an empty list
+            /// means the block passes.
+            pub fn check_structure(&self, py: pyo3::Python<'_>) -> Vec<String> {
+                py.detach(|| check_structure_impl(&self.inner.message))
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attestations, class = PyAttestationGnosis)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attester_slashings, class = PyAttesterSlashingGnosis)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.execution_payload.withdrawals, json)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Gnosis>(self.inner.message.slot, config)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.message.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.message.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                py.detach(|| signed_header_impl(&self.inner.message, self.inner.signature))
+            }
+
+            /// Attach blobs and their cell proofs, e.g. to restore block
+            /// contents saved with `strip_blobs`.
+            ///
+            /// With `verify`, the KZG proofs are checked against the
+            /// commitments, with `setup` or the default trusted setup.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob per KZG
+            /// commitment and 128 cell proofs per blob, a blob or proof has
+            /// the wrong length, or with `verify` a proof does not verify.
+            #[pyo3(signature = (blobs, cell_proofs, verify = false, setup = None))]
+            pub fn with_blobs(
+                &self,
+                py: pyo3::Python<'_>,
+                blobs: Vec<Vec<u8>>,
+                cell_proofs: Vec<Vec<u8>>,
+                verify: bool,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<[<PyFuluSignedBeaconBlockContentsGnosis>]> {
+                let inner = attach_blobs(self.inner.clone(), &blobs, &cell_proofs)
+                    .map_err(PyValueError::new_err)?;
+
+                if verify {
+                    require_valid_proofs::<Gnosis>(
+                        py,
+                        &inner.signed_block.message.body.blob_kzg_commitments,
+                        &inner.blobs,
+                        &inner.kzg_proofs,
+                        setup.as_deref(),
+                        verify_blob_cell_proofs,
+                    )?;
+                }
+
+                Ok([<PyFuluSignedBeaconBlockContentsGnosis>] { inner })
+            }
+
+            /// Return the signed blinded block, with the execution payload
+            /// replaced by its header. The signature stays valid.
+            pub fn blind(&self, py: pyo3::Python<'_>) -> [<PyFuluSignedBlindedBeaconBlockGnosis>] {
+                [<PyFuluSignedBlindedBeaconBlockGnosis>] {
+                    inner: py.detach(|| blind_block(&self.inner)),
+                }
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.message, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluBeaconBlockContentsGnosis>],
+        "FuluBeaconBlockContentsGnosis",
+        BeaconBlockContents<Gnosis>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                let root = py.detach(|| {
+                    signing_root(self.roots.block_root(&self.inner.block), domain)
+                });
+                Ok(format_hash_tree_root(&root))
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs, view)
+            }
+
+            /// The cell proofs, `CELLS_PER_EXT_BLOB` (128) per blob.
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.kzg_proofs, view)
+            }
+
+            /// Verify the cell proofs of each blob against its commitment in
+            /// the block body. The cells are computed from the blobs.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob per KZG
+            /// commitment and 128 cell proofs per blob, or a commitment or
+            /// proof is not a valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_blob_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                verify_contents_proofs::<Gnosis>(
+                    py,
+                    &self.inner.block.body.blob_kzg_commitments,
+                    &self.inner.blobs,
+                    &self.inner.kzg_proofs,
+                    setup.as_deref(),
+                    verify_blob_cell_proofs,
+                )
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.attestations, class = PyAttestationGnosis)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.attester_slashings, class = PyAttesterSlashingGnosis)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Gnosis>(self.inner.block.slot, config)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.block.body.execution_payload.withdrawals, json)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.block.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.block.body.execution_requests, py)
+            }
+
+            pub fn header_dict(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                header_dict_impl(&self.inner.block, py)
+            }
+
+            pub fn header_tuple<'py>(&self, py: pyo3::Python<'py>) -> HeaderTuple<'py> {
+                header_tuple_impl(&self.inner.block, py)
+            }
+
+            pub fn sign(
+                &self,
+                signature: &str,
+            ) -> pyo3::PyResult<[<PyFuluSignedBeaconBlockContentsGnosis>]> {
+                let signature = parse_signature(signature)?;
+
+                let signed = SignedBeaconBlockContents::<Gnosis> {
+                    signed_block: SignedBeaconBlock::<Gnosis> {
+                        message: self.inner.block.clone(),
+                        signature,
+                    },
+                    kzg_proofs: self.inner.kzg_proofs.clone(),
+                    blobs: self.inner.blobs.clone(),
+                };
+
+                Ok([<PyFuluSignedBeaconBlockContentsGnosis>] { inner: signed })
+            }
+
+            pub fn block_hash_tree_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.block_root(&self.inner.block)))
+            }
+
+            /// Hash tree root of the block body, cached with the block root.
+            pub fn body_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.body_root(&self.inner.block)))
+            }
+
+            /// Hash tree root of the execution payload, cached with the block
+            /// root.
+            pub fn payload_root(&self, py: pyo3::Python<'_>) -> String {
+                let root = py.detach(|| {
+                    self.roots.field_root(&self.inner.block, EXECUTION_PAYLOAD)
+                });
+                format_hash_tree_root(&root)
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.block, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.block, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.block, &path)
+            }
+        },
+        mutable = (
+            [<PyFuluMutableBeaconBlockContentsGnosis>],
+            "FuluMutableBeaconBlockContentsGnosis",
+            {
+                /// Recompute the roots that do not depend on the state (the EL
+                /// `block_hash` of the execution payload), e.g. after editing a
+                /// test fixture, and return the fixed fields as
+                /// `{path: (old, new)}`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if the payload cannot be SSZ-encoded.
+                pub fn recompute_roots(
+                    &mut self,
+                    py: pyo3::Python<'_>,
+                ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                    let changes = recompute_roots_impl(&mut self.inner.block, "block.", py)?;
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                    Ok(changes)
+                }
+
+                /// Set the 32-byte graffiti, e.g. as rendered by
+                /// `render_graffiti`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+                pub fn set_graffiti(
+                    &mut self,
+                    graffiti: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.block.body.graffiti = parse_root(graffiti)?;
+                    self.roots.clear_field(GRAFFITI);
+                    Ok(())
+                }
+
+                /// Set the state root, e.g. as computed by the beacon node for
+                /// the patched block.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `state_root` is not 32 bytes.
+                pub fn set_state_root(
+                    &mut self,
+                    state_root: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.block.state_root = parse_root(state_root)?;
+                    self.roots.clear_block();
+                    Ok(())
+                }
+
+                /// Replace the execution payload, e.g. with one built by a
+                /// different execution client.
+                pub fn set_execution_payload(
+                    &mut self,
+                    payload: pyo3::PyRef<'_, [<PyExecutionPayloadGnosis>]>,
+                ) {
+                    self.inner.block.body.execution_payload = payload.inner.clone();
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                }
+            }
+        ),
+        root_cache = BlockRoots
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluSignedBeaconBlockContentsGnosis>],
+        "FuluSignedBeaconBlockContentsGnosis",
+        SignedBeaconBlockContents<Gnosis>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.signed_block.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.signed_block.message, self.inner.signed_block.signature, pubkey, domain)
+            }
+
+            pub fn blobs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.blobs, view)
+            }
+
+            /// The cell proofs, `CELLS_PER_EXT_BLOB` (128) per blob.
+            pub fn kzg_proofs(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.kzg_proofs, view)
+            }
+
+            /// Verify the cell proofs of each blob against its commitment in
+            /// the block body. The cells are computed from the blobs.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one blob per KZG
+            /// commitment and 128 cell proofs per blob, or a commitment or
+            /// proof is not a valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_blob_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                verify_contents_proofs::<Gnosis>(
+                    py,
+                    &self.inner.signed_block.message.body.blob_kzg_commitments,
+                    &self.inner.blobs,
+                    &self.inner.kzg_proofs,
+                    setup.as_deref(),
+                    verify_blob_cell_proofs,
+                )
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Gnosis>(self.inner.signed_block.message.slot, config)
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.attestations, class = PyAttestationGnosis)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.attester_slashings, class = PyAttesterSlashingGnosis)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            pub fn transactions(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.execution_payload.transactions, bytes)
+            }
+
+            pub fn withdrawals(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.signed_block.message.body.execution_payload.withdrawals, json)
+            }
+
+            pub fn payload_stats(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                payload_stats_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            pub fn el_withdrawals_root(&self, py: pyo3::Python<'_>) -> String {
+                el_withdrawals_root_impl(&self.inner.signed_block.message.body.execution_payload, py)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.signed_block.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                let signed_block = &self.inner.signed_block;
+                py.detach(|| signed_header_impl(&signed_block.message, signed_block.signature))
+            }
+
+            /// Return the signed block without its blobs and cell proofs.
+            pub fn strip_blobs(&self) -> [<PyFuluSignedBeaconBlockGnosis>] {
+                [<PyFuluSignedBeaconBlockGnosis>] {
+                    inner: self.inner.signed_block.clone(),
+                }
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.signed_block.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.signed_block.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.signed_block.message, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluBlindedBeaconBlockGnosis>],
+        "FuluBlindedBeaconBlockGnosis",
+        BlindedBeaconBlock<Gnosis>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                let root = py.detach(|| {
+                    signing_root(self.roots.block_root(&self.inner), domain)
+                });
+                Ok(format_hash_tree_root(&root))
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.attestations, class = PyAttestationGnosis)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.attester_slashings, class = PyAttesterSlashingGnosis)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Gnosis>(self.inner.slot, config)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.body.execution_requests, py)
+            }
+
+            pub fn header_dict(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyDict>> {
+                header_dict_impl(&self.inner, py)
+            }
+
+            pub fn header_tuple<'py>(&self, py: pyo3::Python<'py>) -> HeaderTuple<'py> {
+                header_tuple_impl(&self.inner, py)
+            }
+
+            pub fn sign(
+                &self,
+                signature: &str,
+            ) -> pyo3::PyResult<[<PyFuluSignedBlindedBeaconBlockGnosis>]> {
+                let signature = parse_signature(signature)?;
+
+                Ok([<PyFuluSignedBlindedBeaconBlockGnosis>] {
+                    inner: SignedBlindedBeaconBlock {
+                        message: self.inner.clone(),
+                        signature,
+                    },
+                })
+            }
+
+            pub fn block_hash_tree_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.block_root(&self.inner)))
+            }
+
+            /// Hash tree root of the block body, cached with the block root.
+            /// It is that of the full block body.
+            pub fn body_root(&self, py: pyo3::Python<'_>) -> String {
+                py.detach(|| format_hash_tree_root(&self.roots.body_root(&self.inner)))
+            }
+
+            /// Hash tree root of the execution payload header, which is that
+            /// of the payload it stands for, cached with the block root.
+            pub fn payload_root(&self, py: pyo3::Python<'_>) -> String {
+                let root = py.detach(|| {
+                    self.roots.field_root(&self.inner, EXECUTION_PAYLOAD)
+                });
+                format_hash_tree_root(&root)
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload_header", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner, &path)
+            }
+        },
+        mutable = (
+            [<PyFuluMutableBlindedBeaconBlockGnosis>],
+            "FuluMutableBlindedBeaconBlockGnosis",
+            {
+                /// Set the 32-byte graffiti, e.g. as rendered by
+                /// `render_graffiti`.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `graffiti` is not 32 bytes.
+                pub fn set_graffiti(
+                    &mut self,
+                    graffiti: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.body.graffiti = parse_root(graffiti)?;
+                    self.roots.clear_field(GRAFFITI);
+                    Ok(())
+                }
+
+                /// Set the state root, e.g. as computed by the beacon node for
+                /// the patched block.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `state_root` is not 32 bytes.
+                pub fn set_state_root(
+                    &mut self,
+                    state_root: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<()> {
+                    self.inner.state_root = parse_root(state_root)?;
+                    self.roots.clear_block();
+                    Ok(())
+                }
+
+                /// Replace the execution payload header, e.g. with that of a
+                /// different builder bid.
+                pub fn set_execution_payload_header(
+                    &mut self,
+                    header: pyo3::PyRef<'_, [<PyExecutionPayloadHeaderGnosis>]>,
+                ) {
+                    self.inner.body.execution_payload_header = header.inner.clone();
+                    self.roots.clear_field(EXECUTION_PAYLOAD);
+                }
+            }
+        ),
+        root_cache = BlockRoots
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluSignedBlindedBeaconBlockGnosis>],
+        "FuluSignedBlindedBeaconBlockGnosis",
+        SignedBlindedBeaconBlock<Gnosis>,
+        extra_methods = {
+            #[pyo3(signature = (domain, fork_version = None, /))]
+            /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+            /// domain, or with `fork_version` for the domain computed from it
+            /// and the genesis validators root passed in place of the domain.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `domain` is not 32 bytes or
+            /// `fork_version` is not 4 bytes.
+            pub fn signing_root(
+                &self,
+                py: pyo3::Python<'_>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<String> {
+                let domain = proposer_domain(domain, fork_version)?;
+                Ok(object_signing_root(py, &self.inner.message, domain))
+            }
+
+            /// Verify the signature against the proposer's `pubkey` and the
+            /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+            ///
+            /// # Errors
+            /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+            /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+            pub fn verify(
+                &self,
+                py: pyo3::Python<'_>,
+                pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                domain: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<bool> {
+                verify_object(py, &self.inner.message, self.inner.signature, pubkey, domain)
+            }
+
+            pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attestations, class = PyAttestationGnosis)
+            }
+
+            pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+            }
+
+            pub fn attester_slashings(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.attester_slashings, class = PyAttesterSlashingGnosis)
+            }
+
+            pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
+            }
+
+            pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+            }
+
+            pub fn bls_to_execution_changes(slf: &pyo3::Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.message.body.bls_to_execution_changes, class = PySignedBlsToExecutionChange)
+            }
+
+            #[pyo3(signature = (config = None))]
+            pub fn slot(
+                &self,
+                config: Option<pyo3::PyRef<'_, PyConfig>>,
+            ) -> pyo3::PyResult<PySlot> {
+                typed_slot::<Gnosis>(self.inner.message.slot, config)
+            }
+
+            pub fn requests_hash(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String> {
+                requests_hash_impl(&self.inner.message.body.execution_requests, py)
+            }
+
+            /// The signed header of the block, with the body replaced by its
+            /// root.
+            pub fn signed_header(&self, py: pyo3::Python<'_>) -> PySignedBeaconBlockHeader {
+                py.detach(|| signed_header_impl(&self.inner.message, self.inner.signature))
+            }
+
+            /// Return the signed block contents of the block from the
+            /// SSZ-encoded execution payload and blobs bundle, with cell
+            /// proofs, the relay returned for it.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if an encoding is invalid, the payload
+            /// does not match the payload header, or the bundle does not match
+            /// the KZG commitments.
+            pub fn unblind(
+                &self,
+                py: pyo3::Python<'_>,
+                execution_payload_ssz: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+                blobs_bundle_ssz: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+            ) -> pyo3::PyResult<[<PyFuluSignedBeaconBlockContentsGnosis>]> {
+                let execution_payload_ssz = execution_payload_ssz.as_bytes();
+                let blobs_bundle_ssz = blobs_bundle_ssz.as_bytes();
+                py.detach(|| unblind(&self.inner, execution_payload_ssz, blobs_bundle_ssz))
+                    .map(|inner| [<PyFuluSignedBeaconBlockContentsGnosis>] { inner })
+                    .map_err(PyValueError::new_err)
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the block, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the block root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner.message, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the block down, such as
+            /// `["body", "execution_payload_header", "state_root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner.message, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// block.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner.message, &path)
+            }
+        }
+    );
+}
+
+/// Registers all Fulu block types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyFuluSignedBeaconBlockMainnet>()?;
+    m.add_class::<PyFuluBeaconBlockContentsMainnet>()?;
+    m.add_class::<PyFuluMutableBeaconBlockContentsMainnet>()?;
+    m.add_class::<PyFuluSignedBeaconBlockContentsMainnet>()?;
+    m.add_class::<PyFuluBlindedBeaconBlockMainnet>()?;
+    m.add_class::<PyFuluMutableBlindedBeaconBlockMainnet>()?;
+    m.add_class::<PyFuluSignedBlindedBeaconBlockMainnet>()?;
+    m.add_class::<PyFuluSignedBeaconBlockMinimal>()?;
+    m.add_class::<PyFuluBeaconBlockContentsMinimal>()?;
+    m.add_class::<PyFuluMutableBeaconBlockContentsMinimal>()?;
+    m.add_class::<PyFuluSignedBeaconBlockContentsMinimal>()?;
+    m.add_class::<PyFuluBlindedBeaconBlockMinimal>()?;
+    m.add_class::<PyFuluMutableBlindedBeaconBlockMinimal>()?;
+    m.add_class::<PyFuluSignedBlindedBeaconBlockMinimal>()?;
+    m.add_class::<PyFuluSignedBeaconBlockGnosis>()?;
+    m.add_class::<PyFuluBeaconBlockContentsGnosis>()?;
+    m.add_class::<PyFuluMutableBeaconBlockContentsGnosis>()?;
+    m.add_class::<PyFuluSignedBeaconBlockContentsGnosis>()?;
+    m.add_class::<PyFuluBlindedBeaconBlockGnosis>()?;
+    m.add_class::<PyFuluMutableBlindedBeaconBlockGnosis>()?;
+    m.add_class::<PyFuluSignedBlindedBeaconBlockGnosis>()?;
+    Ok(())
+}
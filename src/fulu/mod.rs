@@ -0,0 +1,10 @@
+//! Fulu hardfork types support.
+//!
+//! Fulu blocks have the Electra layout, so the block classes wrap the Electra
+//! containers under Fulu names. The types that changed are the
+//! `BeaconState`, block contents (which carry cell proofs) and the data
+//! column sidecar.
+
+pub mod block;
+pub mod sidecar;
+pub mod state;
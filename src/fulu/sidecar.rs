@@ -0,0 +1,224 @@
+//! Fulu data column sidecar types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `DataColumnSidecar` (Mainnet, Minimal, Gnosis), as published on the
+//!   `data_column_sidecar_{subnet_id}` gossip topics and served by
+//!   `DataColumnSidecarsByRange` and `DataColumnSidecarsByRoot`
+//!
+//! A data column sidecar carries one cell of every blob of a block, with a
+//! KZG proof per cell. The proofs are checked against a trusted setup, by
+//! default the one set with `set_trusted_setup`.
+
+use grandine_ssz::SszHash as _;
+use grandine_types::fulu::containers::DataColumnSidecar;
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use paste::paste;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::convert::format_hash_tree_root;
+use crate::define_ssz_pyclass_for_preset;
+use crate::hex_format::format_hex;
+use crate::kzg::{resolve_setup, verify_cell_proofs, PyTrustedSetup};
+use crate::phase0::header::PySignedBeaconBlockHeader;
+use crate::Gnosis;
+
+/// Verifies the KZG proofs of a sidecar's cells against the commitments.
+///
+/// # Errors
+///
+/// Returns an error string if the numbers of cells, commitments and proofs
+/// differ, or a commitment or proof is not a valid point.
+pub fn verify_column_proofs<P: Preset>(
+    sidecar: &DataColumnSidecar<P>,
+    setup: &c_kzg::KzgSettings,
+) -> Result<bool, String> {
+    let cells = sidecar.column.len();
+
+    if sidecar.kzg_commitments.len() != cells || sidecar.kzg_proofs.len() != cells {
+        return Err(format!(
+            "Expected one KZG commitment and proof per cell, got {cells} cells, {} commitments and {} proofs",
+            sidecar.kzg_commitments.len(),
+            sidecar.kzg_proofs.len(),
+        ));
+    }
+
+    let commitments = sidecar
+        .kzg_commitments
+        .iter()
+        .map(|commitment| commitment.as_bytes())
+        .collect::<Vec<_>>();
+    let column = sidecar
+        .column
+        .iter()
+        .map(|cell| cell.as_bytes())
+        .collect::<Vec<_>>();
+    let proofs = sidecar
+        .kzg_proofs
+        .iter()
+        .map(|proof| proof.as_bytes())
+        .collect::<Vec<_>>();
+
+    verify_cell_proofs(
+        setup,
+        &commitments,
+        &vec![sidecar.index; cells],
+        &column,
+        &proofs,
+    )
+}
+
+paste! {
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluDataColumnSidecarMainnet>],
+        "FuluDataColumnSidecarMainnet",
+        DataColumnSidecar<Mainnet>,
+        extra_methods = {
+            pub fn index(&self) -> u64 {
+                self.inner.index
+            }
+
+            pub fn kzg_commitments(&self) -> Vec<String> {
+                self.inner
+                    .kzg_commitments
+                    .iter()
+                    .map(|commitment| format_hex(commitment.as_bytes()))
+                    .collect()
+            }
+
+            pub fn signed_block_header(&self) -> PySignedBeaconBlockHeader {
+                PySignedBeaconBlockHeader {
+                    inner: self.inner.signed_block_header,
+                }
+            }
+
+            /// Root of the block the column belongs to.
+            pub fn block_root(&self) -> String {
+                format_hash_tree_root(&self.inner.signed_block_header.message.hash_tree_root())
+            }
+
+            /// Verify the KZG proofs of the cells against the commitments.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one commitment and
+            /// proof per cell, or a commitment or proof is not a valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                let setup = resolve_setup(setup.as_deref());
+                let inner = &self.inner;
+                py.detach(|| verify_column_proofs(inner, &setup))
+                    .map_err(PyValueError::new_err)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluDataColumnSidecarMinimal>],
+        "FuluDataColumnSidecarMinimal",
+        DataColumnSidecar<Minimal>,
+        extra_methods = {
+            pub fn index(&self) -> u64 {
+                self.inner.index
+            }
+
+            pub fn kzg_commitments(&self) -> Vec<String> {
+                self.inner
+                    .kzg_commitments
+                    .iter()
+                    .map(|commitment| format_hex(commitment.as_bytes()))
+                    .collect()
+            }
+
+            pub fn signed_block_header(&self) -> PySignedBeaconBlockHeader {
+                PySignedBeaconBlockHeader {
+                    inner: self.inner.signed_block_header,
+                }
+            }
+
+            /// Root of the block the column belongs to.
+            pub fn block_root(&self) -> String {
+                format_hash_tree_root(&self.inner.signed_block_header.message.hash_tree_root())
+            }
+
+            /// Verify the KZG proofs of the cells against the commitments.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one commitment and
+            /// proof per cell, or a commitment or proof is not a valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                let setup = resolve_setup(setup.as_deref());
+                let inner = &self.inner;
+                py.detach(|| verify_column_proofs(inner, &setup))
+                    .map_err(PyValueError::new_err)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluDataColumnSidecarGnosis>],
+        "FuluDataColumnSidecarGnosis",
+        DataColumnSidecar<Gnosis>,
+        extra_methods = {
+            pub fn index(&self) -> u64 {
+                self.inner.index
+            }
+
+            pub fn kzg_commitments(&self) -> Vec<String> {
+                self.inner
+                    .kzg_commitments
+                    .iter()
+                    .map(|commitment| format_hex(commitment.as_bytes()))
+                    .collect()
+            }
+
+            pub fn signed_block_header(&self) -> PySignedBeaconBlockHeader {
+                PySignedBeaconBlockHeader {
+                    inner: self.inner.signed_block_header,
+                }
+            }
+
+            /// Root of the block the column belongs to.
+            pub fn block_root(&self) -> String {
+                format_hash_tree_root(&self.inner.signed_block_header.message.hash_tree_root())
+            }
+
+            /// Verify the KZG proofs of the cells against the commitments.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if there is not one commitment and
+            /// proof per cell, or a commitment or proof is not a valid point.
+            #[pyo3(signature = (setup = None))]
+            pub fn verify_kzg_proofs(
+                &self,
+                py: pyo3::Python<'_>,
+                setup: Option<pyo3::PyRef<'_, PyTrustedSetup>>,
+            ) -> pyo3::PyResult<bool> {
+                let setup = resolve_setup(setup.as_deref());
+                let inner = &self.inner;
+                py.detach(|| verify_column_proofs(inner, &setup))
+                    .map_err(PyValueError::new_err)
+            }
+        }
+    );
+}
+
+/// Registers all Fulu data column sidecar types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyFuluDataColumnSidecarMainnet>()?;
+    m.add_class::<PyFuluDataColumnSidecarMinimal>()?;
+    m.add_class::<PyFuluDataColumnSidecarGnosis>()?;
+    Ok(())
+}
@@ -0,0 +1,182 @@
+//! Fulu beacon state types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `BeaconState` (Mainnet, Minimal, Gnosis)
+//!
+//! Each type supports SSZ and JSON serialization via `from_ssz`, `to_ssz`,
+//! `from_json`, and `to_json` methods, and exposes the EIP-7917
+//! `proposer_lookahead`.
+
+use paste::paste;
+use pyo3::prelude::*;
+
+use crate::Gnosis;
+use grandine_types::fulu::beacon_state::BeaconState;
+use grandine_types::preset::{Mainnet, Minimal};
+
+use crate::define_ssz_pyclass_for_preset;
+use crate::merkle::{generalized_index_impl, prove_impl, prove_path_impl, PathElement};
+
+paste! {
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluBeaconStateMainnet>],
+        "FuluBeaconStateMainnet",
+        BeaconState<Mainnet>,
+        extra_methods = {
+            /// Proposer indices for every slot of the current and next epoch.
+            pub fn proposer_lookahead(&self) -> Vec<u64> {
+                (&self.inner.proposer_lookahead).into_iter().copied().collect()
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the state, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the state root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the state down, such as
+            /// `["finalized_checkpoint", "root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// state.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluBeaconStateMinimal>],
+        "FuluBeaconStateMinimal",
+        BeaconState<Minimal>,
+        extra_methods = {
+            /// Proposer indices for every slot of the current and next epoch.
+            pub fn proposer_lookahead(&self) -> Vec<u64> {
+                (&self.inner.proposer_lookahead).into_iter().copied().collect()
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the state, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the state root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the state down, such as
+            /// `["finalized_checkpoint", "root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// state.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner, &path)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyFuluBeaconStateGnosis>],
+        "FuluBeaconStateGnosis",
+        BeaconState<Gnosis>,
+        extra_methods = {
+            /// Proposer indices for every slot of the current and next epoch.
+            pub fn proposer_lookahead(&self) -> Vec<u64> {
+                (&self.inner.proposer_lookahead).into_iter().copied().collect()
+            }
+
+            /// Merkle branch of the node at `generalized_index` in the tree of
+            /// the state, from the node's sibling up, to check with
+            /// `verify_merkle_proof` against the state root.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the index is 0 or goes below a node
+            /// proofs do not descend into.
+            pub fn prove<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                generalized_index: u64,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_impl(py, &self.inner, generalized_index)
+            }
+
+            /// Merkle branch of the node at `path`, field names and list
+            /// indices from the state down, such as
+            /// `["finalized_checkpoint", "root"]`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn prove_path<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                path: Vec<PathElement>,
+            ) -> pyo3::PyResult<Vec<pyo3::Bound<'py, pyo3::types::PyBytes>>> {
+                prove_path_impl(py, &self.inner, &path)
+            }
+
+            /// Generalized index of the node at `path` in the tree of the
+            /// state.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the path is invalid.
+            pub fn generalized_index(&self, path: Vec<PathElement>) -> pyo3::PyResult<u64> {
+                generalized_index_impl(&self.inner, &path)
+            }
+        }
+    );
+}
+
+/// Registers all Fulu state types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyFuluBeaconStateMainnet>()?;
+    m.add_class::<PyFuluBeaconStateMinimal>()?;
+    m.add_class::<PyFuluBeaconStateGnosis>()?;
+    Ok(())
+}
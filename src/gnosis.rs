@@ -0,0 +1,59 @@
+//! Gnosis Chain withdrawal amount conversion.
+//!
+//! Gnosis Chain validators are funded with mGNO, of which 32 make up 1 GNO,
+//! so the 32 "ether" of a full validator balance are worth 1 GNO. Beacon
+//! balances and withdrawal amounts are denominated in Gwei of mGNO, and the
+//! withdrawal contract pays out `amount * 10**9 / 32` wei of GNO.
+
+use grandine_types::deneb::containers::ExecutionPayload;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::hex_format::format_hex;
+use crate::Gnosis;
+
+/// Wei of GNO paid out per Gwei of a withdrawal amount (`10**9 / 32`).
+pub const GNO_WEI_PER_GWEI: u128 = 31_250_000;
+
+/// Convert a Gnosis Chain beacon amount in Gwei (e.g. a withdrawal amount or
+/// validator balance) to wei of GNO.
+#[pyfunction]
+#[must_use]
+pub fn gwei_to_gno_wei(amount: u64) -> u128 {
+    u128::from(amount) * GNO_WEI_PER_GWEI
+}
+
+/// Lists the withdrawals of a Gnosis payload as dicts annotated with the
+/// `amount_gno_wei` paid out by the withdrawal contract.
+///
+/// # Errors
+///
+/// Returns `PyErr` if the Python conversion fails.
+pub fn gno_withdrawals_impl(
+    payload: &ExecutionPayload<Gnosis>,
+    py: Python<'_>,
+) -> PyResult<Py<PyList>> {
+    let list = PyList::empty(py);
+
+    for withdrawal in payload.withdrawals.iter() {
+        let d = PyDict::new(py);
+        d.set_item("index", withdrawal.index)?;
+        d.set_item("validator_index", withdrawal.validator_index)?;
+        d.set_item("address", format_hex(withdrawal.address.as_bytes()))?;
+        d.set_item("amount", withdrawal.amount)?;
+        d.set_item("amount_gno_wei", gwei_to_gno_wei(withdrawal.amount))?;
+        list.append(d)?;
+    }
+
+    Ok(list.into())
+}
+
+/// Registers the Gnosis conversion function with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(gwei_to_gno_wei, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,772 @@
+//! Gossip message IDs and topics, and append-only capture files for gossip
+//! messages.
+//!
+//! `compute_message_id` derives the gossipsub message ID of a message from
+//! its topic and data as the consensus specs do from Altair on, and
+//! `fork_digest` and `topic_for` build the topics messages are published on,
+//! so messages seen on the wire can be matched with the objects they decode
+//! to.
+//!
+//! A capture stores messages exactly as received from gossipsub (SSZ encoded
+//! and snappy block compressed), each tagged with its topic and arrival time.
+//!
+//! # File format
+//!
+//! All integers are little-endian.
+//!
+//! ```text
+//! capture = "GPGC" version:u8 reserved:[u8; 3] record*
+//! record  = timestamp_ns:u64 topic_len:u16 data_len:u32 topic data
+//! index   = "GPGI" version:u8 reserved:[u8; 3] offset:u64*
+//! ```
+//!
+//! `timestamp_ns` is nanoseconds since the Unix epoch and `offset` is the
+//! position of a record in the capture file. The index lives next to the
+//! capture in `<path>.idx`. It only speeds up opening: a missing or stale
+//! index is rebuilt from the capture by the reader. A writer opening a
+//! capture that ends in a torn record, as left by a crashed writer, cuts the
+//! record off and rewrites the index first, so appended records stay
+//! readable.
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use grandine_ssz::{SszHash as _, H256};
+use grandine_types::phase0::containers::ForkData;
+use pyo3::exceptions::{PyIndexError, PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use sha2::{Digest as _, Sha256};
+
+use crate::config::PyConfig;
+use crate::convert::parse_root;
+use crate::domains::{parse_bytes4, parse_fork_version};
+use crate::electra::attestation::PySingleAttestation;
+use crate::electra::block::{
+    PySignedBeaconBlockGnosis, PySignedBeaconBlockMainnet, PySignedBeaconBlockMinimal,
+};
+use crate::hex_format::format_hex;
+use crate::phase0::header::PyProposerSlashing;
+use crate::presets::PresetKind;
+
+const CAPTURE_MAGIC: &[u8; 4] = b"GPGC";
+const INDEX_MAGIC: &[u8; 4] = b"GPGI";
+const VERSION: u8 = 1;
+const HEADER_LEN: u64 = 8;
+const RECORD_HEADER_LEN: u64 = 14;
+
+const MESSAGE_DOMAIN_INVALID_SNAPPY: [u8; 4] = [0, 0, 0, 0];
+const MESSAGE_DOMAIN_VALID_SNAPPY: [u8; 4] = [1, 0, 0, 0];
+
+/// Computes the 20-byte message ID of a message on `topic` with the
+/// compressed `data`.
+///
+/// Messages that decompress are hashed decompressed, under
+/// `MESSAGE_DOMAIN_VALID_SNAPPY`, and others as they are, under
+/// `MESSAGE_DOMAIN_INVALID_SNAPPY`, so invalid messages still get an ID.
+fn message_id(topic: &str, data: &[u8]) -> [u8; 20] {
+    let decompressed = crate::snappy::decompress(data, Some(false)).ok();
+    let (domain, payload) = match &decompressed {
+        Some(ssz) => (MESSAGE_DOMAIN_VALID_SNAPPY, ssz.as_slice()),
+        None => (MESSAGE_DOMAIN_INVALID_SNAPPY, data),
+    };
+
+    let digest = Sha256::new()
+        .chain_update(domain)
+        .chain_update((topic.len() as u64).to_le_bytes())
+        .chain_update(topic)
+        .chain_update(payload)
+        .finalize();
+
+    let mut id = [0; 20];
+    id.copy_from_slice(&digest[..20]);
+    id
+}
+
+/// Return the gossipsub message ID of a message on `topic` with the snappy
+/// compressed `compressed_data`, as a hex string in the `set_hex_format`
+/// format.
+///
+/// This is the `message-id` function of the consensus specs from Altair on:
+/// the first 20 bytes of the SHA-256 of a message domain, the length of the
+/// topic, the topic and the decompressed data, or the data as it is if it is
+/// not valid snappy.
+#[must_use]
+#[pyfunction]
+pub fn compute_message_id(topic: &str, compressed_data: &[u8]) -> String {
+    format_hex(&message_id(topic, compressed_data))
+}
+
+/// Return the fork digest of `fork_version` on the chain with
+/// `genesis_validators_root`, as a hex string in the `set_hex_format` format.
+///
+/// This is `compute_fork_digest` of the consensus specs up to Electra, the
+/// first 4 bytes of the fork data root. From Fulu the digest also mixes in
+/// the blob parameters of the epoch, which are not covered.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `genesis_validators_root` is not 32 bytes or
+/// `fork_version` is not 4 bytes.
+#[pyfunction]
+pub fn fork_digest(
+    genesis_validators_root: &Bound<'_, PyAny>,
+    fork_version: &Bound<'_, PyAny>,
+) -> PyResult<String> {
+    let fork_data_root: H256 = ForkData {
+        current_version: parse_fork_version(fork_version)?,
+        genesis_validators_root: parse_root(genesis_validators_root)?,
+    }
+    .hash_tree_root();
+
+    Ok(format_hex(&fork_data_root[..4]))
+}
+
+/// Return the topic of the messages named `name` for `fork_digest`, as in
+/// `/eth2/<fork_digest>/beacon_block/ssz_snappy`.
+///
+/// Subnet topics are named with their subnet, such as
+/// `beacon_attestation_5`, or given `subnet_id`, which is appended to the
+/// name.
+///
+/// The digest in the topic is always lowercase hex without a `0x` prefix, as
+/// gossip topics are spelled, whatever `set_hex_format` is set to.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `fork_digest` is not 4 bytes or `name` is empty
+/// or contains `/`.
+#[pyfunction]
+#[pyo3(signature = (fork_digest, name, subnet_id = None))]
+pub fn topic_for(
+    fork_digest: &Bound<'_, PyAny>,
+    name: &str,
+    subnet_id: Option<u64>,
+) -> PyResult<String> {
+    let fork_digest = parse_bytes4(fork_digest, "fork_digest")?;
+
+    if name.is_empty() || name.contains('/') {
+        return Err(PyValueError::new_err(format!(
+            "Invalid gossip topic name: {name:?}"
+        )));
+    }
+
+    let name = match subnet_id {
+        Some(subnet_id) => format!("{name}_{subnet_id}"),
+        None => name.to_owned(),
+    };
+
+    Ok(format!(
+        "/eth2/{}/{name}/ssz_snappy",
+        hex::encode(fork_digest)
+    ))
+}
+
+fn io_error(error: &std::io::Error) -> PyErr {
+    pyo3::exceptions::PyOSError::new_err(error.to_string())
+}
+
+fn file_header(magic: &[u8; 4]) -> [u8; 8] {
+    let mut header = [0; 8];
+    header[..4].copy_from_slice(magic);
+    header[4] = VERSION;
+    header
+}
+
+fn check_header(file: &mut impl Read, magic: &[u8; 4], kind: &str) -> Result<(), String> {
+    let mut header = [0; 8];
+    file.read_exact(&mut header)
+        .map_err(|e| format!("Invalid {kind} header: {e}"))?;
+
+    if header[..4] != magic[..] {
+        return Err(format!("Not a gossip {kind} file"));
+    }
+
+    if header[4] != VERSION {
+        return Err(format!("Unsupported gossip {kind} version {}", header[4]));
+    }
+
+    Ok(())
+}
+
+fn index_path(path: &Path) -> PathBuf {
+    let mut index = path.as_os_str().to_owned();
+    index.push(".idx");
+    PathBuf::from(index)
+}
+
+/// A captured gossip message.
+#[pyclass(name = "GossipMessage", frozen)]
+pub struct PyGossipMessage {
+    timestamp_ns: u64,
+    topic: String,
+    data: Vec<u8>,
+}
+
+impl PyGossipMessage {
+    fn decompress(&self) -> Result<Vec<u8>, String> {
+        crate::snappy::decompress(&self.data, Some(false))
+    }
+
+    /// Returns the gossip topic name, e.g. `beacon_block` for
+    /// `/eth2/<fork_digest>/beacon_block/ssz_snappy`.
+    fn topic_name(&self) -> &str {
+        self.topic.split('/').nth(3).unwrap_or(&self.topic)
+    }
+}
+
+#[pymethods]
+impl PyGossipMessage {
+    /// Arrival time in seconds since the Unix epoch.
+    #[getter]
+    fn timestamp(&self) -> f64 {
+        Duration::from_nanos(self.timestamp_ns).as_secs_f64()
+    }
+
+    #[getter]
+    fn timestamp_ns(&self) -> u64 {
+        self.timestamp_ns
+    }
+
+    #[getter]
+    fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// The message as received (`ssz_snappy`).
+    #[getter]
+    fn data<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.data)
+    }
+
+    /// Decompress the message into SSZ bytes.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the data is not valid snappy or decompresses
+    /// past the `max_input_size` decode limit.
+    fn ssz<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let ssz = py
+            .detach(|| self.decompress())
+            .map_err(PyValueError::new_err)?;
+        Ok(PyBytes::new(py, &ssz))
+    }
+
+    /// The gossipsub message ID, as by `compute_message_id`.
+    fn message_id(&self, py: Python<'_>) -> String {
+        py.detach(|| compute_message_id(&self.topic, &self.data))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "GossipMessage(topic={:?}, timestamp_ns={}, size={})",
+            self.topic,
+            self.timestamp_ns,
+            self.data.len()
+        )
+    }
+}
+
+/// Decodes a message into the class matching its topic.
+///
+/// Blocks decode to `ElectraSignedBeaconBlock*` of the given preset,
+/// attestation subnet messages to `ElectraSingleAttestation` and proposer
+/// slashings to `ProposerSlashing`. Messages on other topics are returned as
+/// decompressed SSZ bytes.
+fn decode_message(
+    py: Python<'_>,
+    message: &PyGossipMessage,
+    preset: PresetKind,
+) -> PyResult<Py<PyAny>> {
+    let ssz = py
+        .detach(|| message.decompress())
+        .map_err(PyValueError::new_err)?;
+
+    macro_rules! decode {
+        ($class:ident) => {{
+            let inner = py
+                .detach(|| crate::decode_ssz(&ssz))
+                .map_err(PyValueError::new_err)?;
+            Ok(Py::new(py, $class { inner })?.into_any())
+        }};
+    }
+
+    match (message.topic_name(), preset) {
+        ("beacon_block", PresetKind::Mainnet) => decode!(PySignedBeaconBlockMainnet),
+        ("beacon_block", PresetKind::Minimal) => decode!(PySignedBeaconBlockMinimal),
+        ("beacon_block", PresetKind::Gnosis) => decode!(PySignedBeaconBlockGnosis),
+        (name, _) if name.starts_with("beacon_attestation_") => decode!(PySingleAttestation),
+        ("proposer_slashing", _) => decode!(PyProposerSlashing),
+        _ => Ok(PyBytes::new(py, &ssz).into_any().unbind()),
+    }
+}
+
+fn preset_of(config: Option<PyRef<'_, PyConfig>>) -> PresetKind {
+    config.map_or(PresetKind::Mainnet, |config| config.inner.preset)
+}
+
+// =============================================================================
+// Writer
+// =============================================================================
+
+struct WriterFiles {
+    capture: BufWriter<File>,
+    index: BufWriter<File>,
+    position: u64,
+}
+
+/// Appends gossip messages to a capture file and its index.
+#[pyclass(name = "GossipCaptureWriter", frozen)]
+pub struct PyGossipCaptureWriter {
+    files: Mutex<Option<WriterFiles>>,
+}
+
+impl PyGossipCaptureWriter {
+    /// Opens the files for appending after the last complete record of the
+    /// capture, cutting off a torn record and rewriting a missing or stale
+    /// index.
+    fn open(path: &Path) -> std::io::Result<WriterFiles> {
+        let mut capture = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .write(true)
+            .truncate(false)
+            .open(path)?;
+        let file_len = capture.metadata()?.len();
+
+        if file_len == 0 {
+            capture.write_all(&file_header(CAPTURE_MAGIC))?;
+        }
+
+        let indexed = read_index(path, file_len);
+        let (offsets, position) =
+            scan_records(&mut BufReader::new(&capture), indexed.clone(), file_len)?;
+
+        if position < file_len {
+            capture.set_len(position)?;
+        }
+        capture.seek(SeekFrom::Start(position))?;
+
+        let index_path = index_path(path);
+        let index_len = index_path.metadata().map_or(0, |metadata| metadata.len());
+
+        let index = if offsets == indexed && index_len == HEADER_LEN + 8 * indexed.len() as u64 {
+            OpenOptions::new().append(true).open(index_path)?
+        } else {
+            let mut index = BufWriter::new(File::create(index_path)?);
+            index.write_all(&file_header(INDEX_MAGIC))?;
+            for offset in &offsets {
+                index.write_all(&offset.to_le_bytes())?;
+            }
+            index
+                .into_inner()
+                .map_err(std::io::IntoInnerError::into_error)?
+        };
+
+        Ok(WriterFiles {
+            capture: BufWriter::new(capture),
+            index: BufWriter::new(index),
+            position,
+        })
+    }
+
+    fn lock(&self) -> PyResult<MutexGuard<'_, Option<WriterFiles>>> {
+        self.files
+            .lock()
+            .map_err(|_| PyRuntimeError::new_err("GossipCaptureWriter lock is poisoned"))
+    }
+}
+
+#[pymethods]
+impl PyGossipCaptureWriter {
+    #[new]
+    /// Open a capture file for appending, creating it if needed.
+    ///
+    /// A torn last record, e.g. from a crashed writer, is cut off, so new
+    /// records follow the last complete one.
+    ///
+    /// # Errors
+    /// Returns `OSError` if the files cannot be opened, or `PyValueError` if an
+    /// existing file is not a capture.
+    fn new(path: PathBuf) -> PyResult<Self> {
+        if path.metadata().is_ok_and(|metadata| metadata.len() > 0) {
+            let mut file = File::open(&path).map_err(|e| io_error(&e))?;
+            check_header(&mut file, CAPTURE_MAGIC, "capture").map_err(PyValueError::new_err)?;
+        }
+
+        let files = Self::open(&path).map_err(|e| io_error(&e))?;
+
+        Ok(Self {
+            files: Mutex::new(Some(files)),
+        })
+    }
+
+    #[pyo3(signature = (topic, data, timestamp = None))]
+    /// Append a message as received from gossip (`ssz_snappy`).
+    ///
+    /// `timestamp` is in seconds since the Unix epoch and defaults to now.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the writer is closed or the topic or message
+    /// is too large, and `OSError` if writing fails.
+    fn write(&self, topic: &str, data: &[u8], timestamp: Option<f64>) -> PyResult<()> {
+        let timestamp_ns = match timestamp {
+            Some(seconds) => Duration::try_from_secs_f64(seconds)
+                .map_err(|e| PyValueError::new_err(format!("Invalid timestamp: {e}")))?,
+            None => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?,
+        };
+        let timestamp_ns = u64::try_from(timestamp_ns.as_nanos())
+            .map_err(|_| PyValueError::new_err("timestamp out of range"))?;
+        let topic_len =
+            u16::try_from(topic.len()).map_err(|_| PyValueError::new_err("topic is too long"))?;
+        let data_len =
+            u32::try_from(data.len()).map_err(|_| PyValueError::new_err("message is too large"))?;
+
+        let mut guard = self.lock()?;
+        let files = guard
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("GossipCaptureWriter is closed"))?;
+
+        let write = |files: &mut WriterFiles| -> std::io::Result<()> {
+            files.capture.write_all(&timestamp_ns.to_le_bytes())?;
+            files.capture.write_all(&topic_len.to_le_bytes())?;
+            files.capture.write_all(&data_len.to_le_bytes())?;
+            files.capture.write_all(topic.as_bytes())?;
+            files.capture.write_all(data)?;
+            files.index.write_all(&files.position.to_le_bytes())?;
+            files.position += RECORD_HEADER_LEN + u64::from(topic_len) + u64::from(data_len);
+            Ok(())
+        };
+
+        write(files).map_err(|e| io_error(&e))
+    }
+
+    /// Flush buffered messages to disk.
+    ///
+    /// # Errors
+    /// Returns `OSError` if flushing fails.
+    fn flush(&self) -> PyResult<()> {
+        if let Some(files) = self.lock()?.as_mut() {
+            files.capture.flush().map_err(|e| io_error(&e))?;
+            files.index.flush().map_err(|e| io_error(&e))?;
+        }
+        Ok(())
+    }
+
+    /// Flush and close the files. Further writes raise `ValueError`.
+    ///
+    /// # Errors
+    /// Returns `OSError` if flushing fails.
+    fn close(&self) -> PyResult<()> {
+        self.flush()?;
+        self.lock()?.take();
+        Ok(())
+    }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    fn __exit__(
+        &self,
+        _exc_type: Option<&Bound<'_, PyAny>>,
+        _exc_value: Option<&Bound<'_, PyAny>>,
+        _traceback: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<()> {
+        self.close()
+    }
+}
+
+// =============================================================================
+// Reader
+// =============================================================================
+
+struct ReaderState {
+    file: BufReader<File>,
+    offsets: Vec<u64>,
+}
+
+impl ReaderState {
+    fn read_record(&mut self, offset: u64) -> std::io::Result<PyGossipMessage> {
+        self.file.seek(SeekFrom::Start(offset))?;
+
+        let mut header = [0; 14];
+        self.file.read_exact(&mut header)?;
+
+        let timestamp_ns = u64::from_le_bytes(header[..8].try_into().expect("8 bytes"));
+        let topic_len = u16::from_le_bytes(header[8..10].try_into().expect("2 bytes"));
+        let data_len = u32::from_le_bytes(header[10..].try_into().expect("4 bytes"));
+
+        let mut topic = vec![0; usize::from(topic_len)];
+        self.file.read_exact(&mut topic)?;
+
+        let mut data = vec![0; data_len as usize];
+        self.file.read_exact(&mut data)?;
+
+        Ok(PyGossipMessage {
+            timestamp_ns,
+            topic: String::from_utf8_lossy(&topic).into_owned(),
+            data,
+        })
+    }
+}
+
+/// Returns the offset of the record following the one at `offset`, or
+/// `None` if the record is truncated.
+fn next_offset(
+    file: &mut (impl Read + Seek),
+    offset: u64,
+    file_len: u64,
+) -> std::io::Result<Option<u64>> {
+    if offset + RECORD_HEADER_LEN > file_len {
+        return Ok(None);
+    }
+
+    file.seek(SeekFrom::Start(offset + 8))?;
+
+    let mut lengths = [0; 6];
+    file.read_exact(&mut lengths)?;
+
+    let topic_len = u16::from_le_bytes(lengths[..2].try_into().expect("2 bytes"));
+    let data_len = u32::from_le_bytes(lengths[2..].try_into().expect("4 bytes"));
+    let next = offset + RECORD_HEADER_LEN + u64::from(topic_len) + u64::from(data_len);
+
+    Ok((next <= file_len).then_some(next))
+}
+
+/// Returns the offsets of the complete records of a capture and the end of
+/// the last one, extending the `indexed` offsets of a missing or stale index
+/// by scanning the remaining records.
+fn scan_records(
+    file: &mut (impl Read + Seek),
+    mut offsets: Vec<u64>,
+    file_len: u64,
+) -> std::io::Result<(Vec<u64>, u64)> {
+    let mut offset = offsets.pop().unwrap_or(HEADER_LEN);
+
+    while let Some(next) = next_offset(file, offset, file_len)? {
+        offsets.push(offset);
+        offset = next;
+    }
+
+    Ok((offsets, offset))
+}
+
+fn read_index(path: &Path, file_len: u64) -> Vec<u64> {
+    let Ok(mut file) = File::open(index_path(path)) else {
+        return Vec::new();
+    };
+
+    if check_header(&mut file, INDEX_MAGIC, "index").is_err() {
+        return Vec::new();
+    }
+
+    let mut bytes = Vec::new();
+
+    if file.read_to_end(&mut bytes).is_err() {
+        return Vec::new();
+    }
+
+    let offsets = bytes
+        .chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().expect("8 bytes")))
+        .collect::<Vec<_>>();
+
+    let valid = offsets.first().is_none_or(|first| *first == HEADER_LEN)
+        && offsets.windows(2).all(|pair| pair[0] < pair[1])
+        && offsets.last().is_none_or(|last| *last < file_len);
+
+    if valid {
+        offsets
+    } else {
+        Vec::new()
+    }
+}
+
+/// Reads a gossip capture file written by `GossipCaptureWriter`.
+///
+/// Supports `len()`, indexing, and iteration over `GossipMessage`s.
+#[pyclass(name = "GossipCaptureReader", frozen)]
+pub struct PyGossipCaptureReader {
+    state: Mutex<ReaderState>,
+    preset: PresetKind,
+}
+
+impl PyGossipCaptureReader {
+    fn lock(&self) -> PyResult<MutexGuard<'_, ReaderState>> {
+        self.state
+            .lock()
+            .map_err(|_| PyRuntimeError::new_err("GossipCaptureReader lock is poisoned"))
+    }
+
+    fn message(&self, index: usize) -> PyResult<PyGossipMessage> {
+        let mut state = self.lock()?;
+        let offset = *state
+            .offsets
+            .get(index)
+            .ok_or_else(|| PyIndexError::new_err("capture index out of range"))?;
+        state.read_record(offset).map_err(|e| io_error(&e))
+    }
+}
+
+#[pymethods]
+impl PyGossipCaptureReader {
+    #[new]
+    #[pyo3(signature = (path, config = None))]
+    /// Open a capture file.
+    ///
+    /// `config` selects the preset used by `decode` and defaults to mainnet.
+    /// A truncated last record (e.g. from a crashed writer) is ignored.
+    ///
+    /// # Errors
+    /// Returns `OSError` if the file cannot be read, or `PyValueError` if it is
+    /// not a capture.
+    fn new(py: Python<'_>, path: PathBuf, config: Option<PyRef<'_, PyConfig>>) -> PyResult<Self> {
+        let preset = preset_of(config);
+
+        let state = py.detach(|| -> PyResult<ReaderState> {
+            let mut file = File::open(&path).map_err(|e| io_error(&e))?;
+            let file_len = file.metadata().map_err(|e| io_error(&e))?.len();
+            check_header(&mut file, CAPTURE_MAGIC, "capture").map_err(PyValueError::new_err)?;
+
+            let mut file = BufReader::new(file);
+            let (offsets, _) = scan_records(&mut file, read_index(&path, file_len), file_len)
+                .map_err(|e| io_error(&e))?;
+
+            Ok(ReaderState { file, offsets })
+        })?;
+
+        Ok(Self {
+            state: Mutex::new(state),
+            preset,
+        })
+    }
+
+    fn __len__(&self) -> PyResult<usize> {
+        Ok(self.lock()?.offsets.len())
+    }
+
+    /// Return the message at `index` (negative indices count from the end).
+    ///
+    /// # Errors
+    /// Returns `PyIndexError` if the index is out of range.
+    fn __getitem__(&self, index: isize) -> PyResult<PyGossipMessage> {
+        let len = self.lock()?.offsets.len();
+        let index = if index < 0 {
+            len.checked_sub(index.unsigned_abs())
+        } else {
+            Some(index.unsigned_abs())
+        }
+        .ok_or_else(|| PyIndexError::new_err("capture index out of range"))?;
+
+        self.message(index)
+    }
+
+    fn __iter__(slf: Py<Self>) -> PyGossipReplay {
+        PyGossipReplay::new(slf, None)
+    }
+
+    /// Iterate over messages, sleeping between them to reproduce their
+    /// original timing divided by `speed`.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `speed` is not positive.
+    #[pyo3(signature = (speed = 1.0))]
+    fn replay(slf: Py<Self>, speed: f64) -> PyResult<PyGossipReplay> {
+        if speed.is_nan() || speed <= 0.0 {
+            return Err(PyValueError::new_err("speed must be positive"));
+        }
+
+        Ok(PyGossipReplay::new(slf, Some(speed)))
+    }
+
+    /// Decode a message into the class matching its topic.
+    ///
+    /// `beacon_block` messages decode to `ElectraSignedBeaconBlock*` of the
+    /// reader's preset, `beacon_attestation_*` messages to
+    /// `ElectraSingleAttestation` and `proposer_slashing` messages to
+    /// `ProposerSlashing`. Other topics return the decompressed SSZ bytes.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the message cannot be decompressed or decoded.
+    fn decode(&self, py: Python<'_>, message: PyRef<'_, PyGossipMessage>) -> PyResult<Py<PyAny>> {
+        decode_message(py, &message, self.preset)
+    }
+}
+
+/// Iterator over a capture, optionally paced to the original timing.
+#[pyclass(name = "GossipReplay")]
+pub struct PyGossipReplay {
+    reader: Py<PyGossipCaptureReader>,
+    position: usize,
+    speed: Option<f64>,
+    /// Wall-clock start and first message timestamp of a paced replay.
+    origin: Option<(Instant, u64)>,
+}
+
+impl PyGossipReplay {
+    fn new(reader: Py<PyGossipCaptureReader>, speed: Option<f64>) -> Self {
+        Self {
+            reader,
+            position: 0,
+            speed,
+            origin: None,
+        }
+    }
+}
+
+#[pymethods]
+impl PyGossipReplay {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyGossipMessage>> {
+        let reader = self.reader.get();
+
+        if self.position >= reader.lock()?.offsets.len() {
+            return Ok(None);
+        }
+
+        let position = self.position;
+        let message = py.detach(|| reader.message(position))?;
+        self.position += 1;
+
+        if let Some(speed) = self.speed {
+            let (started, first_ns) = *self
+                .origin
+                .get_or_insert_with(|| (Instant::now(), message.timestamp_ns));
+            let offset = Duration::from_nanos(message.timestamp_ns.saturating_sub(first_ns));
+            let due = started + offset.div_f64(speed);
+
+            // Sleep in short steps so that Ctrl-C is handled promptly.
+            while let Some(remaining) = due.checked_duration_since(Instant::now()) {
+                py.detach(|| std::thread::sleep(remaining.min(Duration::from_millis(100))));
+                py.check_signals()?;
+            }
+        }
+
+        Ok(Some(message))
+    }
+}
+
+/// Registers the gossip functions and capture classes with the Python
+/// module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(compute_message_id, m)?)?;
+    m.add_function(wrap_pyfunction!(fork_digest, m)?)?;
+    m.add_function(wrap_pyfunction!(topic_for, m)?)?;
+    m.add_class::<PyGossipMessage>()?;
+    m.add_class::<PyGossipCaptureWriter>()?;
+    m.add_class::<PyGossipCaptureReader>()?;
+    m.add_class::<PyGossipReplay>()?;
+    Ok(())
+}
@@ -0,0 +1,126 @@
+//! Graffiti rendering.
+//!
+//! Graffiti is a 32-byte field of the block body that clients fill with
+//! UTF-8 text, zero-padded. Templates let one setting cover every proposal:
+//! `render_graffiti` substitutes the slot, validator index and client info,
+//! then truncates the text to 32 bytes without splitting a UTF-8 character,
+//! which byte slicing of the encoded text would do.
+
+use grandine_ssz::H256;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+/// Length of the graffiti field, in bytes.
+const GRAFFITI_LEN: usize = 32;
+
+/// Substitutes the `{slot}`, `{validator_index}` and `{client}` placeholders
+/// of a template. `{{` and `}}` stand for literal braces.
+///
+/// # Errors
+///
+/// Returns an error string if the template has an unknown placeholder or an
+/// unmatched brace.
+fn render(
+    template: &str,
+    slot: u64,
+    validator_index: u64,
+    client_info: &str,
+) -> Result<String, String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut chars = template.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' if chars.as_str().starts_with('{') => {
+                chars.next();
+                rendered.push('{');
+            }
+            '{' => {
+                let rest = chars.as_str();
+                let end = rest
+                    .find('}')
+                    .ok_or_else(|| "Unmatched '{' in graffiti template".to_owned())?;
+
+                match &rest[..end] {
+                    "slot" => rendered.push_str(&slot.to_string()),
+                    "validator_index" => rendered.push_str(&validator_index.to_string()),
+                    "client" => rendered.push_str(client_info),
+                    name => return Err(format!("Unknown graffiti placeholder {{{name}}}")),
+                }
+
+                chars = rest[end + 1..].chars();
+            }
+            '}' if chars.as_str().starts_with('}') => {
+                chars.next();
+                rendered.push('}');
+            }
+            '}' => return Err("Unmatched '}' in graffiti template".to_owned()),
+            ch => rendered.push(ch),
+        }
+    }
+
+    Ok(rendered)
+}
+
+/// Returns the longest prefix of `text` of at most `max_len` bytes that ends
+/// on a character boundary.
+fn truncate_utf8(text: &str, max_len: usize) -> &str {
+    let end = text
+        .char_indices()
+        .map(|(index, ch)| index + ch.len_utf8())
+        .take_while(|end| *end <= max_len)
+        .last()
+        .unwrap_or(0);
+
+    &text[..end]
+}
+
+/// Encodes text as a graffiti field, truncated on a character boundary and
+/// zero-padded.
+fn graffiti_from_text(text: &str) -> H256 {
+    let text = truncate_utf8(text, GRAFFITI_LEN);
+    let mut graffiti = H256::zero();
+    graffiti[..text.len()].copy_from_slice(text.as_bytes());
+    graffiti
+}
+
+/// Render a graffiti template into the 32-byte graffiti field.
+///
+/// `{slot}`, `{validator_index}` and `{client}` are replaced with the slot,
+/// the proposer's validator index and `client_info` (empty if not given);
+/// `{{` and `}}` are literal braces. Text longer than 32 bytes is truncated
+/// without splitting a UTF-8 character, and shorter text is zero-padded.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the template has an unknown placeholder or an
+/// unmatched brace.
+#[pyfunction]
+#[pyo3(signature = (template, slot, validator_index, client_info = None))]
+pub fn render_graffiti<'py>(
+    py: Python<'py>,
+    template: &str,
+    slot: u64,
+    validator_index: u64,
+    client_info: Option<&str>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let text = render(
+        template,
+        slot,
+        validator_index,
+        client_info.unwrap_or_default(),
+    )
+    .map_err(PyValueError::new_err)?;
+    Ok(PyBytes::new(py, graffiti_from_text(&text).as_bytes()))
+}
+
+/// Registers the graffiti functions with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(render_graffiti, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,152 @@
+//! Process-wide formatting of hex strings in generated output.
+//!
+//! By default hex strings are lowercase with a `0x` prefix, as in the Beacon
+//! API. `set_hex_format` switches to uppercase digits and/or drops the prefix
+//! for `to_json`, `to_pyspec_dict`, `header_dict`, roots returned as strings
+//! and the other dicts built from JSON values.
+//!
+//! `to_json_canonical` and `to_json_delta` are not affected: canonical output
+//! must not depend on process state, and deltas must round-trip through
+//! `from_json_delta`. Input parsing is not affected either, so JSON written
+//! without prefixes is not accepted by `from_json`.
+
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde_json::Value;
+
+static UPPERCASE: AtomicBool = AtomicBool::new(false);
+static PREFIX: AtomicBool = AtomicBool::new(true);
+
+fn is_default() -> bool {
+    !UPPERCASE.load(Ordering::Relaxed) && PREFIX.load(Ordering::Relaxed)
+}
+
+/// Formats bytes as a hex string following the configured format.
+#[must_use]
+pub fn format_hex(bytes: &[u8]) -> String {
+    let digits = if UPPERCASE.load(Ordering::Relaxed) {
+        hex::encode_upper(bytes)
+    } else {
+        hex::encode(bytes)
+    };
+
+    if PREFIX.load(Ordering::Relaxed) {
+        format!("0x{digits}")
+    } else {
+        digits
+    }
+}
+
+/// Reformats a `0x`-prefixed hex string produced by a serializer following
+/// the configured format. Other strings are returned unchanged.
+#[must_use]
+pub fn apply_to_str(string: &str) -> Cow<'_, str> {
+    if is_default() {
+        return Cow::Borrowed(string);
+    }
+
+    match string.strip_prefix("0x") {
+        Some(digits) if digits.bytes().all(|byte| byte.is_ascii_hexdigit()) => {
+            let digits = if UPPERCASE.load(Ordering::Relaxed) {
+                digits.to_ascii_uppercase()
+            } else {
+                digits.to_ascii_lowercase()
+            };
+
+            if PREFIX.load(Ordering::Relaxed) {
+                Cow::Owned(format!("0x{digits}"))
+            } else {
+                Cow::Owned(digits)
+            }
+        }
+        _ => Cow::Borrowed(string),
+    }
+}
+
+fn apply(value: &mut Value) {
+    match value {
+        Value::String(string) => {
+            if let Cow::Owned(formatted) = apply_to_str(string) {
+                *string = formatted;
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(apply),
+        Value::Object(map) => map.values_mut().for_each(apply),
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+/// Serializes a value to JSON bytes with hex strings in the configured format.
+///
+/// # Errors
+///
+/// Returns an error string if the value cannot be serialized.
+pub fn to_json_vec<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, String> {
+    if is_default() {
+        return serde_json::to_vec(value).map_err(|e| e.to_string());
+    }
+
+    let mut value = serde_json::to_value(value).map_err(|e| e.to_string())?;
+    apply(&mut value);
+    serde_json::to_vec(&value).map_err(|e| e.to_string())
+}
+
+/// Serializes a value as JSON to `writer` with hex strings in the configured
+/// format.
+///
+/// The default format is written as the value is serialized; other formats
+/// go through an intermediate `serde_json::Value`.
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be serialized or writing fails.
+pub fn write_json<T: serde::Serialize, W: std::io::Write>(
+    writer: W,
+    value: &T,
+) -> serde_json::Result<()> {
+    if is_default() {
+        return serde_json::to_writer(writer, value);
+    }
+
+    let mut value = serde_json::to_value(value)?;
+    apply(&mut value);
+    serde_json::to_writer(writer, &value)
+}
+
+/// Set how hex strings are formatted in generated output.
+///
+/// Arguments that are not given are reset to their defaults (lowercase with a
+/// `0x` prefix).
+#[pyfunction]
+#[pyo3(signature = (*, uppercase = false, prefix = true))]
+pub fn set_hex_format(uppercase: bool, prefix: bool) {
+    UPPERCASE.store(uppercase, Ordering::Relaxed);
+    PREFIX.store(prefix, Ordering::Relaxed);
+}
+
+/// Return the current hex format as a dict.
+///
+/// # Errors
+///
+/// Returns `PyErr` if the dict cannot be built.
+#[pyfunction]
+pub fn get_hex_format(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let format = PyDict::new(py);
+    format.set_item("uppercase", UPPERCASE.load(Ordering::Relaxed))?;
+    format.set_item("prefix", PREFIX.load(Ordering::Relaxed))?;
+    Ok(format)
+}
+
+/// Registers the hex format functions with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(set_hex_format, m)?)?;
+    m.add_function(wrap_pyfunction!(get_hex_format, m)?)?;
+    Ok(())
+}
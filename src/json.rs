@@ -0,0 +1,483 @@
+//! JSON helpers shared by the generated classes.
+//!
+//! # Canonical format
+//!
+//! `to_json_canonical` output is compact UTF-8 JSON with no insignificant
+//! whitespace and object keys sorted by their UTF-8 bytes at every level.
+//! Strings are escaped the way `serde_json` escapes them (only `"`, `\\` and
+//! control characters), and numbers use their shortest `serde_json` form. For
+//! consensus types, whose keys are ASCII and whose integers are encoded as
+//! strings, this coincides with the JSON Canonicalization Scheme ([RFC 8785]).
+//! The format does not depend on field declaration order or on `serde_json`
+//! features enabled elsewhere in the dependency graph.
+//!
+//! # Delta format
+//!
+//! `to_json_delta` emits a JSON Merge Patch ([RFC 7386]) that turns the base
+//! object's JSON into the target object's JSON:
+//!
+//! - An object in the patch is merged key by key into the base object; keys
+//!   absent from the patch are unchanged.
+//! - Any other value (string, number, array) replaces the base value whole.
+//!   Arrays are never diffed element by element.
+//! - `null` removes a key. Consensus types always serialize every field, so
+//!   deltas between two objects of the same type never contain `null`.
+//!
+//! Identical objects produce the empty patch `{}`.
+//!
+//! # Masked comparison
+//!
+//! `equals_ignoring` compares the JSON of two objects, skipping masked
+//! fields. A mask without dots (`signature`) skips every field of that name,
+//! at any depth; a dotted mask (`message.state_root`) skips the field at
+//! that path from the root. Array elements have the path of their array, so
+//! `message.body.attestations.signature` masks the signature of every
+//! attestation.
+//!
+//! # Error paths
+//!
+//! `from_value_with_path` deserializes like `serde_json::from_value` but
+//! names the value an error is about, as a path of field names and array
+//! indices from the root: `message.body.attestations[2].data.slot`. Errors
+//! about a missing or unknown field name the container the field belongs to.
+//!
+//! [RFC 7386]: https://www.rfc-editor.org/rfc/rfc7386
+//! [RFC 8785]: https://www.rfc-editor.org/rfc/rfc8785
+
+use std::cell::RefCell;
+
+use serde::de::{
+    self, DeserializeSeed, Deserializer, IntoDeserializer as _, MapAccess, SeqAccess, Visitor,
+};
+use serde_json::{Map, Value};
+
+/// Computes the merge patch turning `base` into `target`.
+///
+/// Returns `None` if the values are equal.
+#[must_use]
+pub fn merge_diff(base: &Value, target: &Value) -> Option<Value> {
+    match (base, target) {
+        (Value::Object(base), Value::Object(target)) => {
+            let mut patch = Map::new();
+
+            for (key, target_value) in target {
+                match base.get(key) {
+                    Some(base_value) => {
+                        if let Some(value) = merge_diff(base_value, target_value) {
+                            patch.insert(key.clone(), value);
+                        }
+                    }
+                    None => {
+                        patch.insert(key.clone(), target_value.clone());
+                    }
+                }
+            }
+
+            for key in base.keys() {
+                if !target.contains_key(key) {
+                    patch.insert(key.clone(), Value::Null);
+                }
+            }
+
+            (!patch.is_empty()).then_some(Value::Object(patch))
+        }
+        _ if base == target => None,
+        _ => Some(target.clone()),
+    }
+}
+
+/// Applies a merge patch to `target` in place.
+pub fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+/// Serializes `target` as a merge patch relative to `base`.
+///
+/// # Errors
+///
+/// Returns an error string if either value cannot be serialized.
+pub fn to_json_delta<T: serde::Serialize>(base: &T, target: &T) -> Result<Vec<u8>, String> {
+    let base = serde_json::to_value(base).map_err(|e| e.to_string())?;
+    let target = serde_json::to_value(target).map_err(|e| e.to_string())?;
+    let patch = merge_diff(&base, &target).unwrap_or_else(|| Value::Object(Map::new()));
+    serde_json::to_vec(&patch).map_err(|e| e.to_string())
+}
+
+/// Reconstructs a value by applying a merge patch to the JSON of `base`.
+///
+/// # Errors
+///
+/// Returns an error string if the patch exceeds the decode limits, is not
+/// valid JSON, or the patched JSON does not deserialize as the target type.
+pub fn from_json_delta<T: serde::Serialize + serde::de::DeserializeOwned>(
+    base: &T,
+    delta: &[u8],
+) -> Result<T, String> {
+    let patch: Value = crate::decode_json(delta)?;
+    let mut value = serde_json::to_value(base).map_err(|e| e.to_string())?;
+    merge_patch(&mut value, &patch);
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+fn is_masked(path: &[&str], masks: &[&str]) -> bool {
+    masks.iter().any(|mask| {
+        if mask.contains('.') {
+            mask.split('.').eq(path.iter().copied())
+        } else {
+            path.last() == Some(mask)
+        }
+    })
+}
+
+fn equal_masked<'a>(a: &'a Value, b: &'a Value, path: &mut Vec<&'a str>, masks: &[&str]) -> bool {
+    match (a, b) {
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter().all(|(key, a_value)| {
+                    path.push(key);
+                    let equal = is_masked(path, masks)
+                        || b.get(key)
+                            .is_some_and(|b_value| equal_masked(a_value, b_value, path, masks));
+                    path.pop();
+                    equal
+                })
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .zip(b)
+                    .all(|(a, b)| equal_masked(a, b, path, masks))
+        }
+        _ => a == b,
+    }
+}
+
+/// Compares the JSON of two values, skipping the fields matched by `masks`
+/// (see the module documentation).
+///
+/// # Errors
+///
+/// Returns an error string if either value cannot be serialized.
+pub fn equals_ignoring<T: serde::Serialize>(a: &T, b: &T, masks: &[&str]) -> Result<bool, String> {
+    let a = serde_json::to_value(a).map_err(|e| e.to_string())?;
+    let b = serde_json::to_value(b).map_err(|e| e.to_string())?;
+    Ok(equal_masked(&a, &b, &mut Vec::new(), masks))
+}
+
+/// The path of the value that failed to deserialize, recorded by the
+/// innermost failing [`Tracked`] deserializer.
+#[derive(Default)]
+struct ErrorPath(RefCell<Option<String>>);
+
+impl ErrorPath {
+    fn record<T>(
+        &self,
+        path: &str,
+        result: Result<T, serde_json::Error>,
+    ) -> Result<T, serde_json::Error> {
+        if result.is_err() {
+            self.0.borrow_mut().get_or_insert_with(|| path.to_owned());
+        }
+        result
+    }
+}
+
+fn field_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{parent}.{key}")
+    }
+}
+
+/// Deserializes a JSON value like `serde_json`, tracking the path of each
+/// nested value so that errors can name it.
+struct Tracked<'a> {
+    value: Value,
+    path: String,
+    error_path: &'a ErrorPath,
+}
+
+impl Tracked<'_> {
+    /// Visits objects and arrays with tracked entries, and delegates every
+    /// other value to `serde_json`.
+    fn dispatch<'de, V: Visitor<'de>>(
+        self,
+        visitor: V,
+        delegate: impl FnOnce(Value, V) -> Result<V::Value, serde_json::Error>,
+    ) -> Result<V::Value, serde_json::Error> {
+        let Self {
+            value,
+            path,
+            error_path,
+        } = self;
+
+        let result = match value {
+            Value::Object(map) => {
+                let len = map.len();
+                let mut access = TrackedMap {
+                    entries: map.into_iter(),
+                    value: None,
+                    path: &path,
+                    error_path,
+                };
+                visitor.visit_map(&mut access).and_then(|result| {
+                    if access.entries.len() == 0 {
+                        Ok(result)
+                    } else {
+                        Err(de::Error::invalid_length(len, &"fewer elements in map"))
+                    }
+                })
+            }
+            Value::Array(array) => {
+                let len = array.len();
+                let mut access = TrackedSeq {
+                    elements: array.into_iter().enumerate(),
+                    path: &path,
+                    error_path,
+                };
+                visitor.visit_seq(&mut access).and_then(|result| {
+                    if access.elements.len() == 0 {
+                        Ok(result)
+                    } else {
+                        Err(de::Error::invalid_length(len, &"fewer elements in array"))
+                    }
+                })
+            }
+            value => delegate(value, visitor),
+        };
+
+        error_path.record(&path, result)
+    }
+}
+
+struct TrackedMap<'a> {
+    entries: serde_json::map::IntoIter,
+    value: Option<(String, Value)>,
+    path: &'a str,
+    error_path: &'a ErrorPath,
+}
+
+impl<'de> MapAccess<'de> for TrackedMap<'_> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        let result = seed.deserialize(key.as_str().into_deserializer()).map(Some);
+        self.value = Some((key, value));
+        result
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let (key, value) = self
+            .value
+            .take()
+            .ok_or_else(|| de::Error::custom("value is missing"))?;
+
+        seed.deserialize(Tracked {
+            value,
+            path: field_path(self.path, &key),
+            error_path: self.error_path,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+struct TrackedSeq<'a> {
+    elements: std::iter::Enumerate<std::vec::IntoIter<Value>>,
+    path: &'a str,
+    error_path: &'a ErrorPath,
+}
+
+impl<'de> SeqAccess<'de> for TrackedSeq<'_> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.elements
+            .next()
+            .map(|(index, value)| {
+                seed.deserialize(Tracked {
+                    value,
+                    path: format!("{}[{index}]", self.path),
+                    error_path: self.error_path,
+                })
+            })
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+macro_rules! forward_to_dispatch {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(
+                self,
+                $($arg: $ty,)*
+                visitor: V,
+            ) -> Result<V::Value, Self::Error> {
+                self.dispatch(visitor, |value, visitor| value.$method($($arg,)* visitor))
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Tracked<'_> {
+    type Error = serde_json::Error;
+
+    forward_to_dispatch!(
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    );
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.value.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    // Enum variants are not tracked: errors inside them name the enum.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let result = self.value.deserialize_enum(name, variants, visitor);
+        self.error_path.record(&self.path, result)
+    }
+}
+
+/// Deserializes a JSON value, naming the path of the offending value in
+/// errors (see the module documentation).
+///
+/// # Errors
+///
+/// Returns an error string if the value does not deserialize as `T`.
+pub fn from_value_with_path<T: serde::de::DeserializeOwned>(value: Value) -> Result<T, String> {
+    let error_path = ErrorPath::default();
+    let tracked = Tracked {
+        value,
+        path: String::new(),
+        error_path: &error_path,
+    };
+
+    T::deserialize(tracked).map_err(|error| match error_path.0.take() {
+        Some(path) if !path.is_empty() => format!("{error} at {path}"),
+        _ => error.to_string(),
+    })
+}
+
+fn write_canonical(value: &Value, out: &mut Vec<u8>) -> Result<(), String> {
+    match value {
+        Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_unstable_by(|(a, _), (b, _)| a.as_bytes().cmp(b.as_bytes()));
+
+            out.push(b'{');
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(b',');
+                }
+                serde_json::to_writer(&mut *out, key).map_err(|e| e.to_string())?;
+                out.push(b':');
+                write_canonical(value, out)?;
+            }
+            out.push(b'}');
+        }
+        Value::Array(items) => {
+            out.push(b'[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(b',');
+                }
+                write_canonical(item, out)?;
+            }
+            out.push(b']');
+        }
+        scalar => serde_json::to_writer(&mut *out, scalar).map_err(|e| e.to_string())?,
+    }
+
+    Ok(())
+}
+
+/// Serializes a value to canonical JSON (see the module documentation).
+///
+/// # Errors
+///
+/// Returns an error string if the value cannot be serialized.
+pub fn to_json_canonical<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, String> {
+    let value = serde_json::to_value(value).map_err(|e| e.to_string())?;
+    let mut out = Vec::new();
+    write_canonical(&value, &mut out)?;
+    Ok(out)
+}
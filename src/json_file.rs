@@ -0,0 +1,194 @@
+//! Streaming JSON file input and output.
+//!
+//! `from_json_file` and `to_json_file` parse and serialize directly from and
+//! to a file, so documents the size of a mainnet beacon state are never held
+//! in memory as bytes next to the decoded object. Files are given as a path
+//! or as a binary file object, such as an open file or a `gzip.GzipFile`,
+//! which is read or written in chunks of `CHUNK_SIZE` bytes with the GIL
+//! taken only for each call.
+//!
+//! Input is parsed as it is read, and the decode limits of `from_json` are
+//! checked on each chunk before the parser sees it, so reading stops once
+//! the document exceeds `max_input_size`, `max_json_depth` or
+//! `max_list_length`.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::limits::{max_input_size, JsonScan};
+
+/// The buffer size of file reads and writes.
+const CHUNK_SIZE: usize = 1 << 20;
+
+/// A binary Python file object, called through its `read` or `write` method.
+struct PyFile(Py<PyAny>);
+
+impl Read for PyFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Python::attach(|py| -> PyResult<usize> {
+            let chunk = self.0.bind(py).call_method1("read", (buf.len(),))?;
+            let chunk = chunk
+                .cast::<PyBytes>()
+                .map_err(|_| PyTypeError::new_err("File object must be opened in binary mode"))?;
+            let bytes = chunk.as_bytes();
+            let len = bytes.len().min(buf.len());
+            buf[..len].copy_from_slice(&bytes[..len]);
+            Ok(len)
+        })
+        .map_err(io::Error::from)
+    }
+}
+
+impl Write for PyFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Python::attach(|py| -> PyResult<usize> {
+            let written = self
+                .0
+                .bind(py)
+                .call_method1("write", (PyBytes::new(py, buf),))?;
+
+            // Buffered files return `None` once everything is written.
+            if written.is_none() {
+                Ok(buf.len())
+            } else {
+                written.extract()
+            }
+        })
+        .map_err(io::Error::from)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Python::attach(|py| self.0.bind(py).call_method0("flush").map(drop))
+            .map_err(io::Error::from)
+    }
+}
+
+/// A reader that fails once more than `max` bytes have been read, or the
+/// JSON read so far fails the `scan` of the other decode limits.
+struct Limited<R> {
+    inner: R,
+    read: usize,
+    max: Option<usize>,
+    scan: Option<JsonScan>,
+}
+
+impl<R: Read> Read for Limited<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.inner.read(buf)?;
+        self.read += len;
+
+        if let Some(max) = self.max.filter(|max| self.read > *max) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Input exceeds the decode limit of {max} bytes"),
+            ));
+        }
+
+        if let Some(scan) = &mut self.scan {
+            scan.feed(&buf[..len])
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+
+        Ok(len)
+    }
+}
+
+enum Target {
+    Path(PathBuf),
+    File(Py<PyAny>),
+}
+
+/// Resolves a path, or a file object with a `method` method.
+fn target(value: &Bound<'_, PyAny>, method: &str) -> PyResult<Target> {
+    if let Ok(path) = value.extract::<PathBuf>() {
+        return Ok(Target::Path(path));
+    }
+
+    if value.hasattr(method)? {
+        return Ok(Target::File(value.clone().unbind()));
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "Expected a path or a file object with a {method} method, got {}",
+        value.get_type().name()?
+    )))
+}
+
+/// Raises JSON and limit errors as `ValueError`, and the errors of the file
+/// as they are.
+fn to_py_err(error: io::Error) -> PyErr {
+    match error.kind() {
+        io::ErrorKind::InvalidData | io::ErrorKind::UnexpectedEof => {
+            PyValueError::new_err(error.to_string())
+        }
+        _ => error.into(),
+    }
+}
+
+/// Deserializes a value from a JSON file, parsing it as it is read with the
+/// GIL released.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if `source` is neither a path nor a file object,
+/// `OSError` (or the error of the file object) if reading fails, and
+/// `PyValueError` if the input is not valid JSON for the type or exceeds the
+/// decode limits.
+pub fn read_json<T: DeserializeOwned + Send>(
+    py: Python<'_>,
+    source: &Bound<'_, PyAny>,
+) -> PyResult<T> {
+    let reader: Box<dyn Read + Send> = match target(source, "read")? {
+        Target::Path(path) => Box::new(File::open(path)?),
+        Target::File(file) => Box::new(PyFile(file)),
+    };
+
+    let reader = BufReader::with_capacity(
+        CHUNK_SIZE,
+        Limited {
+            inner: reader,
+            read: 0,
+            max: max_input_size(),
+            scan: JsonScan::new(),
+        },
+    );
+
+    py.detach(|| serde_json::from_reader(reader).map_err(io::Error::from))
+        .map_err(to_py_err)
+}
+
+/// Serializes a value to a JSON file as it would be by `to_json`, with the
+/// GIL released.
+///
+/// A file at `destination` is created or truncated. File objects are
+/// flushed, but not closed.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if `destination` is neither a path nor a file
+/// object, `OSError` (or the error of the file object) if writing fails, and
+/// `PyValueError` if the value cannot be serialized.
+pub fn write_json<T: Serialize + Sync>(
+    py: Python<'_>,
+    value: &T,
+    destination: &Bound<'_, PyAny>,
+) -> PyResult<()> {
+    let writer: Box<dyn Write + Send> = match target(destination, "write")? {
+        Target::Path(path) => Box::new(File::create(path)?),
+        Target::File(file) => Box::new(PyFile(file)),
+    };
+
+    py.detach(|| {
+        let mut writer = BufWriter::with_capacity(CHUNK_SIZE, writer);
+        crate::hex_format::write_json(&mut writer, value).map_err(io::Error::from)?;
+        writer.flush()
+    })
+    .map_err(to_py_err)
+}
@@ -0,0 +1,499 @@
+//! Process-wide KZG trusted setup.
+//!
+//! KZG commitments and proofs are checked against a trusted setup. The
+//! Ethereum mainnet setup is embedded and used by default; devnets with a
+//! custom setup can load it with `load_trusted_setup` and make it the default
+//! with `set_trusted_setup`. Functions verifying KZG data take an optional
+//! `setup` argument and fall back to the default.
+//!
+//! Setups are accepted in the `trusted_setup.txt` format read by `c-kzg` and
+//! in the `trusted_setup_4096.json` format of the consensus specs.
+//!
+//! `verify_blob_kzg_proof_batch` reads blobs held in `bytes` or `ByteView`
+//! in place, so an epoch's blobs can be verified without copying them. Blobs
+//! that lie next to each other in memory, such as slices of one buffer, are
+//! checked with the batch verification of `c-kzg`, split across threads.
+//!
+//! Block contents verify their blobs with `verify_blob_proofs` (a proof per
+//! blob, up to Electra) or `verify_blob_cell_proofs` (a proof per cell of
+//! the extended blob, from Fulu).
+
+use std::fmt::Write as _;
+use std::ops::{Deref, Range};
+use std::path::PathBuf;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use c_kzg::{
+    Blob, Bytes48, Cell, KzgSettings, BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_PROOF,
+    CELLS_PER_EXT_BLOB,
+};
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+use serde::Deserialize;
+
+use crate::convert::{buffer_bytes, InputBytes};
+
+/// Precomputation level of the multi-scalar multiplication tables. Tables
+/// only speed up cell proof computation, which verification does not need.
+const PRECOMPUTE: u64 = 0;
+
+/// A trusted setup, either the embedded mainnet one or a loaded one.
+#[derive(Clone)]
+pub enum TrustedSetup {
+    Mainnet,
+    Custom(Arc<KzgSettings>),
+}
+
+impl Deref for TrustedSetup {
+    type Target = KzgSettings;
+
+    fn deref(&self) -> &KzgSettings {
+        match self {
+            Self::Mainnet => c_kzg::ethereum_kzg_settings(PRECOMPUTE),
+            Self::Custom(settings) => settings,
+        }
+    }
+}
+
+static DEFAULT: RwLock<TrustedSetup> = RwLock::new(TrustedSetup::Mainnet);
+
+/// Returns the default trusted setup.
+#[must_use]
+pub fn default_setup() -> TrustedSetup {
+    DEFAULT
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// Returns `setup`, or the default trusted setup if it is not given.
+#[must_use]
+pub fn resolve_setup(setup: Option<&PyTrustedSetup>) -> TrustedSetup {
+    setup.map_or_else(default_setup, |setup| setup.inner.clone())
+}
+
+/// Verifies a blob's KZG proof against its commitment.
+///
+/// # Errors
+///
+/// Returns an error string if an input has the wrong length or does not
+/// encode a valid point or field element.
+pub fn verify_blob_proof(
+    setup: &KzgSettings,
+    blob: &[u8],
+    commitment: &[u8],
+    proof: &[u8],
+) -> Result<bool, String> {
+    let blob = Blob::from_bytes(blob).map_err(|e| format!("Invalid blob: {e}"))?;
+    let commitment =
+        Bytes48::from_bytes(commitment).map_err(|e| format!("Invalid KZG commitment: {e}"))?;
+    let proof = Bytes48::from_bytes(proof).map_err(|e| format!("Invalid KZG proof: {e}"))?;
+
+    setup
+        .verify_blob_kzg_proof(&blob, &commitment, &proof)
+        .map_err(|e| format!("KZG proof verification failed: {e}"))
+}
+
+/// Verifies a batch of cell KZG proofs, the `i`th cell being cell
+/// `cell_indices[i]` of the blob committed to by `commitments[i]`.
+///
+/// # Errors
+///
+/// Returns an error string if an input has the wrong length or does not
+/// encode a valid point or field element.
+pub fn verify_cell_proofs(
+    setup: &KzgSettings,
+    commitments: &[&[u8]],
+    cell_indices: &[u64],
+    cells: &[&[u8]],
+    proofs: &[&[u8]],
+) -> Result<bool, String> {
+    let commitments = commitments
+        .iter()
+        .map(|commitment| Bytes48::from_bytes(commitment))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Invalid KZG commitment: {e}"))?;
+    let cells = cells
+        .iter()
+        .map(|cell| Cell::from_bytes(cell))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Invalid cell: {e}"))?;
+    let proofs = proofs
+        .iter()
+        .map(|proof| Bytes48::from_bytes(proof))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Invalid KZG proof: {e}"))?;
+
+    setup
+        .verify_cell_kzg_proof_batch(&commitments, cell_indices, &cells, &proofs)
+        .map_err(|e| format!("KZG proof verification failed: {e}"))
+}
+
+/// Verifies the KZG proofs of many blobs against their commitments, the
+/// blobs in parallel.
+///
+/// # Errors
+///
+/// Returns an error string if the inputs differ in length, or an input has
+/// the wrong length or does not encode a valid point or field element.
+pub fn verify_blob_proofs(
+    setup: &KzgSettings,
+    blobs: &[&[u8]],
+    commitments: &[&[u8]],
+    proofs: &[&[u8]],
+) -> Result<bool, String> {
+    if commitments.len() != blobs.len() || proofs.len() != blobs.len() {
+        return Err(format!(
+            "Expected one KZG commitment and proof per blob, got {} blobs, {} commitments and {} \
+             proofs",
+            blobs.len(),
+            commitments.len(),
+            proofs.len(),
+        ));
+    }
+
+    let results = (0..blobs.len())
+        .into_par_iter()
+        .map(|index| {
+            verify_blob_proof(setup, blobs[index], commitments[index], proofs[index])
+                .map_err(|e| format!("Blob {index}: {e}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(results.into_iter().all(|valid| valid))
+}
+
+/// Verifies the cell KZG proofs of many blobs against their commitments,
+/// `CELLS_PER_EXT_BLOB` proofs per blob, blob by blob. The cells are
+/// computed from the blobs.
+///
+/// # Errors
+///
+/// Returns an error string if the inputs differ in length, or an input has
+/// the wrong length or does not encode a valid point or field element.
+pub fn verify_blob_cell_proofs(
+    setup: &KzgSettings,
+    blobs: &[&[u8]],
+    commitments: &[&[u8]],
+    cell_proofs: &[&[u8]],
+) -> Result<bool, String> {
+    if commitments.len() != blobs.len() || cell_proofs.len() != blobs.len() * CELLS_PER_EXT_BLOB {
+        return Err(format!(
+            "Expected one KZG commitment and {CELLS_PER_EXT_BLOB} cell proofs per blob, got {} \
+             blobs, {} commitments and {} proofs",
+            blobs.len(),
+            commitments.len(),
+            cell_proofs.len(),
+        ));
+    }
+
+    let cells = blobs
+        .par_iter()
+        .enumerate()
+        .map(|(index, blob)| {
+            let blob = Blob::from_bytes(blob).map_err(|e| format!("Invalid blob {index}: {e}"))?;
+            setup
+                .compute_cells(&blob)
+                .map_err(|e| format!("Blob {index}: {e}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let commitments = commitments
+        .iter()
+        .flat_map(|commitment| std::iter::repeat_n(*commitment, CELLS_PER_EXT_BLOB))
+        .collect::<Vec<_>>();
+    let cell_indices = (0..blobs.len())
+        .flat_map(|_| 0..CELLS_PER_EXT_BLOB as u64)
+        .collect::<Vec<_>>();
+    let cells = cells
+        .iter()
+        .flat_map(|cells| cells.iter().map(|cell| cell.to_bytes()))
+        .collect::<Vec<_>>();
+    let cells = cells.iter().map(|cell| cell.as_slice()).collect::<Vec<_>>();
+
+    verify_cell_proofs(setup, &commitments, &cell_indices, &cells, cell_proofs)
+}
+
+/// The consensus specs' JSON trusted setup.
+#[derive(Deserialize)]
+struct JsonSetup {
+    g1_monomial: Vec<String>,
+    g1_lagrange: Vec<String>,
+    g2_monomial: Vec<String>,
+}
+
+/// Converts a JSON trusted setup to the text format parsed by `c-kzg`: the
+/// G1 and G2 point counts followed by the G1 Lagrange, G2 monomial and G1
+/// monomial points, one hex string per line.
+fn json_to_text(json: &[u8]) -> Result<String, String> {
+    let setup: JsonSetup =
+        serde_json::from_slice(json).map_err(|e| format!("Invalid trusted setup JSON: {e}"))?;
+
+    let mut text = format!("{}\n{}\n", setup.g1_lagrange.len(), setup.g2_monomial.len());
+
+    for point in setup
+        .g1_lagrange
+        .iter()
+        .chain(&setup.g2_monomial)
+        .chain(&setup.g1_monomial)
+    {
+        writeln!(text, "{}", point.trim_start_matches("0x")).expect("writing to a String");
+    }
+
+    Ok(text)
+}
+
+/// Parses a trusted setup in either supported format.
+fn parse_setup(bytes: &[u8]) -> Result<KzgSettings, String> {
+    let text = if bytes.trim_ascii_start().starts_with(b"{") {
+        json_to_text(bytes)?
+    } else {
+        String::from_utf8(bytes.to_vec()).map_err(|_| "Trusted setup is not text".to_owned())?
+    };
+
+    KzgSettings::parse_kzg_trusted_setup(&text, PRECOMPUTE)
+        .map_err(|e| format!("Invalid trusted setup: {e}"))
+}
+
+/// Trusted setup file contents or the path of a trusted setup file.
+#[derive(FromPyObject)]
+pub enum SetupSource {
+    Bytes(Vec<u8>),
+    Path(PathBuf),
+}
+
+/// A KZG trusted setup for verifying blob commitments and proofs.
+#[pyclass(name = "TrustedSetup", frozen)]
+pub struct PyTrustedSetup {
+    pub inner: TrustedSetup,
+}
+
+#[pymethods]
+impl PyTrustedSetup {
+    /// The embedded Ethereum mainnet trusted setup.
+    #[staticmethod]
+    fn mainnet() -> Self {
+        Self {
+            inner: TrustedSetup::Mainnet,
+        }
+    }
+
+    /// Whether this is the embedded mainnet trusted setup.
+    #[getter]
+    fn is_mainnet(&self) -> bool {
+        matches!(self.inner, TrustedSetup::Mainnet)
+    }
+
+    fn __repr__(&self) -> &'static str {
+        match self.inner {
+            TrustedSetup::Mainnet => "TrustedSetup(mainnet)",
+            TrustedSetup::Custom(_) => "TrustedSetup(custom)",
+        }
+    }
+}
+
+/// Load a trusted setup from a file path or from the file contents.
+///
+/// # Errors
+///
+/// Returns `OSError` if the file cannot be read, or `PyValueError` if it is
+/// not a valid trusted setup.
+#[pyfunction]
+pub fn load_trusted_setup(py: Python<'_>, path_or_bytes: SetupSource) -> PyResult<PyTrustedSetup> {
+    let settings = py.detach(|| -> PyResult<KzgSettings> {
+        let bytes = match path_or_bytes {
+            SetupSource::Bytes(bytes) => bytes,
+            SetupSource::Path(path) => std::fs::read(path)?,
+        };
+        parse_setup(&bytes).map_err(PyValueError::new_err)
+    })?;
+
+    Ok(PyTrustedSetup {
+        inner: TrustedSetup::Custom(Arc::new(settings)),
+    })
+}
+
+/// Set the trusted setup used when a KZG function is not given one.
+///
+/// Passing `None` restores the embedded mainnet setup.
+#[pyfunction]
+#[pyo3(signature = (setup = None))]
+pub fn set_trusted_setup(setup: Option<PyRef<'_, PyTrustedSetup>>) {
+    let setup = setup.map_or(TrustedSetup::Mainnet, |setup| setup.inner.clone());
+    *DEFAULT.write().unwrap_or_else(PoisonError::into_inner) = setup;
+}
+
+/// Return the trusted setup used when a KZG function is not given one.
+#[pyfunction]
+pub fn get_trusted_setup() -> PyTrustedSetup {
+    PyTrustedSetup {
+        inner: default_setup(),
+    }
+}
+
+/// Acquires the bytes of `object`, checking that its buffer is C-contiguous
+/// and `length` bytes long.
+fn byte_buffer(
+    object: &Bound<'_, PyAny>,
+    what: &str,
+    index: usize,
+    length: usize,
+) -> PyResult<InputBytes> {
+    let buffer = PyBuffer::<u8>::get(object)?;
+
+    if !buffer.is_c_contiguous() {
+        return Err(PyValueError::new_err(format!(
+            "{what} {index} is not a contiguous buffer"
+        )));
+    }
+
+    if buffer.len_bytes() != length {
+        return Err(PyValueError::new_err(format!(
+            "{what} {index} must be {length} bytes, got {}",
+            buffer.len_bytes()
+        )));
+    }
+
+    InputBytes::new(object, buffer)
+}
+
+/// Verify the KZG proofs of many blobs against their commitments.
+///
+/// `blobs`, `commitments` and `proofs` are sequences of objects supporting
+/// the buffer protocol, such as `bytes`, `memoryview` or `ByteView`. Blobs
+/// are verified in parallel with the GIL released, reading the bytes of
+/// `bytes`, `ByteView` and memoryviews of them in place rather than copying
+/// them (see `convert::InputBytes`). Runs of blobs that are adjacent in
+/// memory, such as slices of one buffer, are verified in batches.
+///
+/// Returns whether every proof is valid.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the sequences differ in length, an item is not
+/// a contiguous buffer of the right length, or a commitment or proof is not
+/// a valid point.
+#[pyfunction]
+#[pyo3(signature = (blobs, commitments, proofs, setup = None))]
+pub fn verify_blob_kzg_proof_batch(
+    py: Python<'_>,
+    blobs: Vec<Bound<'_, PyAny>>,
+    commitments: Vec<Bound<'_, PyAny>>,
+    proofs: Vec<Bound<'_, PyAny>>,
+    setup: Option<PyRef<'_, PyTrustedSetup>>,
+) -> PyResult<bool> {
+    if commitments.len() != blobs.len() || proofs.len() != blobs.len() {
+        return Err(PyValueError::new_err(format!(
+            "Expected one commitment and proof per blob, got {} blobs, {} commitments and {} proofs",
+            blobs.len(),
+            commitments.len(),
+            proofs.len(),
+        )));
+    }
+
+    let acquire = |objects: &[Bound<'_, PyAny>], what: &str, length: usize| {
+        objects
+            .iter()
+            .enumerate()
+            .map(|(index, object)| byte_buffer(object, what, index, length))
+            .collect::<PyResult<Vec<_>>>()
+    };
+
+    let blobs = acquire(&blobs, "Blob", BYTES_PER_BLOB)?;
+    let commitments = acquire(&commitments, "Commitment", BYTES_PER_COMMITMENT)?;
+    let proofs = acquire(&proofs, "Proof", BYTES_PER_PROOF)?;
+    let setup = resolve_setup(setup.as_deref());
+
+    let results = py.detach(|| {
+        let blobs = blobs.iter().map(buffer_bytes).collect::<Vec<_>>();
+        let commitments = commitments
+            .iter()
+            .map(|commitment| Bytes48::from_bytes(buffer_bytes(commitment)))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Invalid KZG commitment: {e}"))?;
+        let proofs = proofs
+            .iter()
+            .map(|proof| Bytes48::from_bytes(buffer_bytes(proof)))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Invalid KZG proof: {e}"))?;
+
+        let batch_size = blobs.len().div_ceil(rayon::current_num_threads()).max(1);
+
+        adjacent_runs(&blobs, batch_size)
+            .into_par_iter()
+            .map(|run| {
+                // SAFETY: `Blob` is a `repr(C)` wrapper of a byte array of
+                // `BYTES_PER_BLOB` bytes, which is the length of every blob,
+                // and the blobs of a run follow each other in memory.
+                let run_blobs = unsafe {
+                    std::slice::from_raw_parts(blobs[run.start].as_ptr().cast::<Blob>(), run.len())
+                };
+
+                setup
+                    .verify_blob_kzg_proof_batch(
+                        run_blobs,
+                        &commitments[run.clone()],
+                        &proofs[run.clone()],
+                    )
+                    .map_err(|error| {
+                        // The batch error does not name the blob, so the run
+                        // is checked blob by blob to find the invalid one.
+                        run.clone()
+                            .zip(run_blobs)
+                            .find_map(|(index, blob)| {
+                                setup
+                                    .verify_blob_kzg_proof(
+                                        blob,
+                                        &commitments[index],
+                                        &proofs[index],
+                                    )
+                                    .err()
+                                    .map(|e| format!("Blob {index}: {e}"))
+                            })
+                            .unwrap_or_else(|| error.to_string())
+                    })
+            })
+            .collect::<Result<Vec<_>, String>>()
+    });
+
+    Ok(results
+        .map_err(PyValueError::new_err)?
+        .into_iter()
+        .all(|valid| valid))
+}
+
+/// Splits `blobs` into runs of at most `batch_size` blobs, each blob of a run
+/// starting where the previous one ends.
+fn adjacent_runs(blobs: &[&[u8]], batch_size: usize) -> Vec<Range<usize>> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+
+    for index in 1..=blobs.len() {
+        let adjacent = blobs
+            .get(index)
+            .is_some_and(|blob| std::ptr::eq(blob.as_ptr(), blobs[index - 1].as_ptr_range().end));
+
+        if !adjacent || index - start == batch_size {
+            runs.push(start..index);
+            start = index;
+        }
+    }
+
+    runs
+}
+
+/// Registers the trusted setup type and functions with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyTrustedSetup>()?;
+    m.add_function(wrap_pyfunction!(load_trusted_setup, m)?)?;
+    m.add_function(wrap_pyfunction!(set_trusted_setup, m)?)?;
+    m.add_function(wrap_pyfunction!(get_trusted_setup, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_blob_kzg_proof_batch, m)?)?;
+    Ok(())
+}
@@ -23,15 +23,133 @@
 
 use pyo3::prelude::*;
 
+mod aggregation;
+mod altair;
+mod availability;
+mod bellatrix;
+mod bls;
+mod builder_api;
+mod cache;
+mod capella;
+mod chain_index;
+mod clock;
+mod committees;
+mod config;
+mod convert;
+mod decode;
+mod deneb;
+mod deposit_tree;
+mod domains;
+mod el_hashing;
 mod electra;
+mod envelope;
+mod era;
+mod finality;
+mod fulu;
+mod gnosis;
+mod gossip;
+mod graffiti;
+mod hex_format;
+mod json;
+mod json_file;
+mod kzg;
+mod light_client;
+mod limits;
+mod list;
 mod macros;
+mod merkle;
+mod namespaces;
+mod native;
+mod participation;
+mod phase0;
 mod preset_gnosis;
+mod presets;
+mod primitives;
+mod proposers;
+mod pubkey_index;
+mod publish;
+mod query;
+mod range_sync;
+mod root_cache;
+mod signing;
+mod slashing;
+mod snappy;
+#[cfg(feature = "spec_tests")]
+mod spec_tests;
+mod ssz_size;
+mod test_vectors;
+mod validate;
+mod withdrawals;
 
-pub use macros::{decode_ssz, encode_ssz, JsonDataEnvelope};
+pub use macros::{
+    decode_json, decode_json_batch, decode_ssz, decode_ssz_batch, encode_ssz, encode_ssz_batch,
+    hash_tree_root_batch, JsonApiResponse, JsonDataEnvelope,
+};
 pub use preset_gnosis::Gnosis;
 
 #[pymodule]
 fn grandine_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    config::register(m)?;
+    presets::register(m)?;
+    limits::register(m)?;
+    hex_format::register(m)?;
+    bls::register(m)?;
+    kzg::register(m)?;
+    primitives::register(m)?;
+    clock::register(m)?;
+    list::register(m)?;
     electra::block::register(m)?;
+    electra::execution::register(m)?;
+    gnosis::register(m)?;
+    electra::produce::register(m)?;
+    electra::builder::register(m)?;
+    electra::compare::register(m)?;
+    builder_api::register(m)?;
+    electra::attestation::register(m)?;
+    electra::aggregate::register(m)?;
+    electra::sidecar::register(m)?;
+    electra::state::register(m)?;
+    fulu::block::register(m)?;
+    fulu::state::register(m)?;
+    fulu::sidecar::register(m)?;
+    altair::sync_committee::register(m)?;
+    light_client::register(m)?;
+    phase0::attestation::register(m)?;
+    phase0::header::register(m)?;
+    phase0::deposit::register(m)?;
+    phase0::exit::register(m)?;
+    capella::bls_change::register(m)?;
+    phase0::block::register(m)?;
+    altair::block::register(m)?;
+    bellatrix::block::register(m)?;
+    capella::block::register(m)?;
+    deneb::block::register(m)?;
+    deposit_tree::register(m)?;
+    aggregation::register(m)?;
+    participation::register(m)?;
+    proposers::register(m)?;
+    committees::register(m)?;
+    withdrawals::register(m)?;
+    range_sync::register(m)?;
+    pubkey_index::register(m)?;
+    chain_index::register(m)?;
+    finality::register(m)?;
+    signing::register(m)?;
+    domains::register(m)?;
+    slashing::register(m)?;
+    publish::register(m)?;
+    availability::register(m)?;
+    query::register(m)?;
+    test_vectors::register(m)?;
+    #[cfg(feature = "spec_tests")]
+    spec_tests::register(m)?;
+    cache::register(m)?;
+    gossip::register(m)?;
+    graffiti::register(m)?;
+    envelope::register(m)?;
+    decode::register(m)?;
+    merkle::register(m)?;
+    era::register(m)?;
+    namespaces::register(m)?;
     Ok(())
 }
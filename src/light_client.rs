@@ -0,0 +1,588 @@
+//! Light client types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `LightClientBootstrap`, the header and current sync committee a light
+//!   client starts from
+//! - `LightClientUpdate`, which moves a light client to the next sync
+//!   committee period
+//! - `LightClientFinalityUpdate` and `LightClientOptimisticUpdate`, the
+//!   latest finalized and attested headers
+//!
+//! for Altair, Capella, Deneb and Electra (Mainnet, Minimal, Gnosis). Capella
+//! added the execution payload header to the light client header, and
+//! Electra deepened the beacon state tree, so the Merkle branches of Electra
+//! objects are one node longer.
+//!
+//! Besides SSZ and JSON serialization, each type provides the slots of its
+//! headers and its Merkle branches as hex strings.
+//! `verify_current_sync_committee`, `verify_next_sync_committee` and
+//! `verify_finality_branch` check the branches against the state root of the
+//! header they are proven from, as `is_valid_merkle_branch` does in the light
+//! client specs. They do not check the sync committee signature.
+
+use grandine_ssz::{SszHash as _, H256};
+use grandine_types::preset::{Mainnet, Minimal};
+use pyo3::prelude::*;
+
+use crate::convert::format_hash_tree_root;
+use crate::merkle::is_valid_merkle_branch;
+use crate::Gnosis;
+
+/// Generalized indices of the sync committees and the finalized checkpoint
+/// root in the beacon state, from Altair through Deneb.
+const CURRENT_SYNC_COMMITTEE_GINDEX: u64 = 54;
+const NEXT_SYNC_COMMITTEE_GINDEX: u64 = 55;
+const FINALIZED_ROOT_GINDEX: u64 = 105;
+
+/// The same generalized indices from Electra on.
+const CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA: u64 = 86;
+const NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA: u64 = 87;
+const FINALIZED_ROOT_GINDEX_ELECTRA: u64 = 169;
+
+fn format_branch<'a>(branch: impl IntoIterator<Item = &'a H256>) -> Vec<String> {
+    branch.into_iter().map(format_hash_tree_root).collect()
+}
+
+/// The leaf the finality branch proves: the root of the finalized header, or
+/// zero while the finalized checkpoint is the genesis one.
+fn finalized_root(slot: u64, root: H256) -> H256 {
+    if slot == 0 {
+        H256::zero()
+    } else {
+        root
+    }
+}
+
+/// Defines the light client classes of a fork and preset.
+///
+/// `fork` is the `grandine_types` module of the fork, and `gindices` are its
+/// current sync committee, next sync committee and finalized root
+/// generalized indices.
+macro_rules! define_light_client_pyclasses {
+    (
+        $fork:ident,
+        $preset:ty,
+        bootstrap = (
+            $bootstrap:ident,
+            $bootstrap_name:literal $(,)?
+        ),
+        update = (
+            $update:ident,
+            $update_name:literal $(,)?
+        ),
+        finality_update = (
+            $finality_update:ident,
+            $finality_update_name:literal $(,)?
+        ),
+        optimistic_update = (
+            $optimistic_update:ident,
+            $optimistic_update_name:literal $(,)?
+        ),
+        gindices = (
+            $current_gindex:expr,
+            $next_gindex:expr,
+            $finalized_gindex:expr $(,)?
+        ) $(,)?
+    ) => {
+        $crate::define_ssz_pyclass_for_preset!(
+            $bootstrap,
+            $bootstrap_name,
+            grandine_types::$fork::containers::LightClientBootstrap<$preset>,
+            extra_methods = {
+                pub fn slot(&self) -> u64 {
+                    self.inner.header.beacon.slot
+                }
+
+                /// The root of the header's block, which the light client
+                /// must already trust.
+                pub fn block_root(&self) -> String {
+                    format_hash_tree_root(&self.inner.header.beacon.hash_tree_root())
+                }
+
+                pub fn current_sync_committee_branch(&self) -> Vec<String> {
+                    format_branch(self.inner.current_sync_committee_branch.iter())
+                }
+
+                /// Check the current sync committee against the state root of
+                /// the header.
+                pub fn verify_current_sync_committee(&self) -> bool {
+                    is_valid_merkle_branch(
+                        self.inner.current_sync_committee.hash_tree_root(),
+                        self.inner.current_sync_committee_branch.iter(),
+                        $current_gindex,
+                        self.inner.header.beacon.state_root,
+                    )
+                }
+            }
+        );
+
+        $crate::define_ssz_pyclass_for_preset!(
+            $update,
+            $update_name,
+            grandine_types::$fork::containers::LightClientUpdate<$preset>,
+            extra_methods = {
+                pub fn attested_slot(&self) -> u64 {
+                    self.inner.attested_header.beacon.slot
+                }
+
+                pub fn finalized_slot(&self) -> u64 {
+                    self.inner.finalized_header.beacon.slot
+                }
+
+                pub fn signature_slot(&self) -> u64 {
+                    self.inner.signature_slot
+                }
+
+                pub fn next_sync_committee_branch(&self) -> Vec<String> {
+                    format_branch(self.inner.next_sync_committee_branch.iter())
+                }
+
+                /// Check the next sync committee against the state root of the
+                /// attested header.
+                pub fn verify_next_sync_committee(&self) -> bool {
+                    is_valid_merkle_branch(
+                        self.inner.next_sync_committee.hash_tree_root(),
+                        self.inner.next_sync_committee_branch.iter(),
+                        $next_gindex,
+                        self.inner.attested_header.beacon.state_root,
+                    )
+                }
+
+                pub fn finality_branch(&self) -> Vec<String> {
+                    format_branch(self.inner.finality_branch.iter())
+                }
+
+                /// Check the finalized header against the state root of the
+                /// attested header.
+                pub fn verify_finality_branch(&self) -> bool {
+                    let finalized = &self.inner.finalized_header.beacon;
+                    is_valid_merkle_branch(
+                        finalized_root(finalized.slot, finalized.hash_tree_root()),
+                        self.inner.finality_branch.iter(),
+                        $finalized_gindex,
+                        self.inner.attested_header.beacon.state_root,
+                    )
+                }
+            }
+        );
+
+        $crate::define_ssz_pyclass_for_preset!(
+            $finality_update,
+            $finality_update_name,
+            grandine_types::$fork::containers::LightClientFinalityUpdate<$preset>,
+            extra_methods = {
+                pub fn attested_slot(&self) -> u64 {
+                    self.inner.attested_header.beacon.slot
+                }
+
+                pub fn finalized_slot(&self) -> u64 {
+                    self.inner.finalized_header.beacon.slot
+                }
+
+                pub fn signature_slot(&self) -> u64 {
+                    self.inner.signature_slot
+                }
+
+                pub fn finality_branch(&self) -> Vec<String> {
+                    format_branch(self.inner.finality_branch.iter())
+                }
+
+                /// Check the finalized header against the state root of the
+                /// attested header.
+                pub fn verify_finality_branch(&self) -> bool {
+                    let finalized = &self.inner.finalized_header.beacon;
+                    is_valid_merkle_branch(
+                        finalized_root(finalized.slot, finalized.hash_tree_root()),
+                        self.inner.finality_branch.iter(),
+                        $finalized_gindex,
+                        self.inner.attested_header.beacon.state_root,
+                    )
+                }
+            }
+        );
+
+        $crate::define_ssz_pyclass_for_preset!(
+            $optimistic_update,
+            $optimistic_update_name,
+            grandine_types::$fork::containers::LightClientOptimisticUpdate<$preset>,
+            extra_methods = {
+                pub fn attested_slot(&self) -> u64 {
+                    self.inner.attested_header.beacon.slot
+                }
+
+                pub fn signature_slot(&self) -> u64 {
+                    self.inner.signature_slot
+                }
+            }
+        );
+    };
+}
+
+define_light_client_pyclasses!(
+    altair,
+    Mainnet,
+    bootstrap = (
+        PyAltairLightClientBootstrapMainnet,
+        "AltairLightClientBootstrapMainnet",
+    ),
+    update = (
+        PyAltairLightClientUpdateMainnet,
+        "AltairLightClientUpdateMainnet",
+    ),
+    finality_update = (
+        PyAltairLightClientFinalityUpdateMainnet,
+        "AltairLightClientFinalityUpdateMainnet",
+    ),
+    optimistic_update = (
+        PyAltairLightClientOptimisticUpdateMainnet,
+        "AltairLightClientOptimisticUpdateMainnet",
+    ),
+    gindices = (
+        CURRENT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        FINALIZED_ROOT_GINDEX,
+    ),
+);
+
+define_light_client_pyclasses!(
+    altair,
+    Minimal,
+    bootstrap = (
+        PyAltairLightClientBootstrapMinimal,
+        "AltairLightClientBootstrapMinimal",
+    ),
+    update = (
+        PyAltairLightClientUpdateMinimal,
+        "AltairLightClientUpdateMinimal",
+    ),
+    finality_update = (
+        PyAltairLightClientFinalityUpdateMinimal,
+        "AltairLightClientFinalityUpdateMinimal",
+    ),
+    optimistic_update = (
+        PyAltairLightClientOptimisticUpdateMinimal,
+        "AltairLightClientOptimisticUpdateMinimal",
+    ),
+    gindices = (
+        CURRENT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        FINALIZED_ROOT_GINDEX,
+    ),
+);
+
+define_light_client_pyclasses!(
+    altair,
+    Gnosis,
+    bootstrap = (
+        PyAltairLightClientBootstrapGnosis,
+        "AltairLightClientBootstrapGnosis",
+    ),
+    update = (
+        PyAltairLightClientUpdateGnosis,
+        "AltairLightClientUpdateGnosis",
+    ),
+    finality_update = (
+        PyAltairLightClientFinalityUpdateGnosis,
+        "AltairLightClientFinalityUpdateGnosis",
+    ),
+    optimistic_update = (
+        PyAltairLightClientOptimisticUpdateGnosis,
+        "AltairLightClientOptimisticUpdateGnosis",
+    ),
+    gindices = (
+        CURRENT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        FINALIZED_ROOT_GINDEX,
+    ),
+);
+
+define_light_client_pyclasses!(
+    capella,
+    Mainnet,
+    bootstrap = (
+        PyCapellaLightClientBootstrapMainnet,
+        "CapellaLightClientBootstrapMainnet",
+    ),
+    update = (
+        PyCapellaLightClientUpdateMainnet,
+        "CapellaLightClientUpdateMainnet",
+    ),
+    finality_update = (
+        PyCapellaLightClientFinalityUpdateMainnet,
+        "CapellaLightClientFinalityUpdateMainnet",
+    ),
+    optimistic_update = (
+        PyCapellaLightClientOptimisticUpdateMainnet,
+        "CapellaLightClientOptimisticUpdateMainnet",
+    ),
+    gindices = (
+        CURRENT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        FINALIZED_ROOT_GINDEX,
+    ),
+);
+
+define_light_client_pyclasses!(
+    capella,
+    Minimal,
+    bootstrap = (
+        PyCapellaLightClientBootstrapMinimal,
+        "CapellaLightClientBootstrapMinimal",
+    ),
+    update = (
+        PyCapellaLightClientUpdateMinimal,
+        "CapellaLightClientUpdateMinimal",
+    ),
+    finality_update = (
+        PyCapellaLightClientFinalityUpdateMinimal,
+        "CapellaLightClientFinalityUpdateMinimal",
+    ),
+    optimistic_update = (
+        PyCapellaLightClientOptimisticUpdateMinimal,
+        "CapellaLightClientOptimisticUpdateMinimal",
+    ),
+    gindices = (
+        CURRENT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        FINALIZED_ROOT_GINDEX,
+    ),
+);
+
+define_light_client_pyclasses!(
+    capella,
+    Gnosis,
+    bootstrap = (
+        PyCapellaLightClientBootstrapGnosis,
+        "CapellaLightClientBootstrapGnosis",
+    ),
+    update = (
+        PyCapellaLightClientUpdateGnosis,
+        "CapellaLightClientUpdateGnosis",
+    ),
+    finality_update = (
+        PyCapellaLightClientFinalityUpdateGnosis,
+        "CapellaLightClientFinalityUpdateGnosis",
+    ),
+    optimistic_update = (
+        PyCapellaLightClientOptimisticUpdateGnosis,
+        "CapellaLightClientOptimisticUpdateGnosis",
+    ),
+    gindices = (
+        CURRENT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        FINALIZED_ROOT_GINDEX,
+    ),
+);
+
+define_light_client_pyclasses!(
+    deneb,
+    Mainnet,
+    bootstrap = (
+        PyDenebLightClientBootstrapMainnet,
+        "DenebLightClientBootstrapMainnet",
+    ),
+    update = (
+        PyDenebLightClientUpdateMainnet,
+        "DenebLightClientUpdateMainnet",
+    ),
+    finality_update = (
+        PyDenebLightClientFinalityUpdateMainnet,
+        "DenebLightClientFinalityUpdateMainnet",
+    ),
+    optimistic_update = (
+        PyDenebLightClientOptimisticUpdateMainnet,
+        "DenebLightClientOptimisticUpdateMainnet",
+    ),
+    gindices = (
+        CURRENT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        FINALIZED_ROOT_GINDEX,
+    ),
+);
+
+define_light_client_pyclasses!(
+    deneb,
+    Minimal,
+    bootstrap = (
+        PyDenebLightClientBootstrapMinimal,
+        "DenebLightClientBootstrapMinimal",
+    ),
+    update = (
+        PyDenebLightClientUpdateMinimal,
+        "DenebLightClientUpdateMinimal",
+    ),
+    finality_update = (
+        PyDenebLightClientFinalityUpdateMinimal,
+        "DenebLightClientFinalityUpdateMinimal",
+    ),
+    optimistic_update = (
+        PyDenebLightClientOptimisticUpdateMinimal,
+        "DenebLightClientOptimisticUpdateMinimal",
+    ),
+    gindices = (
+        CURRENT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        FINALIZED_ROOT_GINDEX,
+    ),
+);
+
+define_light_client_pyclasses!(
+    deneb,
+    Gnosis,
+    bootstrap = (
+        PyDenebLightClientBootstrapGnosis,
+        "DenebLightClientBootstrapGnosis",
+    ),
+    update = (
+        PyDenebLightClientUpdateGnosis,
+        "DenebLightClientUpdateGnosis",
+    ),
+    finality_update = (
+        PyDenebLightClientFinalityUpdateGnosis,
+        "DenebLightClientFinalityUpdateGnosis",
+    ),
+    optimistic_update = (
+        PyDenebLightClientOptimisticUpdateGnosis,
+        "DenebLightClientOptimisticUpdateGnosis",
+    ),
+    gindices = (
+        CURRENT_SYNC_COMMITTEE_GINDEX,
+        NEXT_SYNC_COMMITTEE_GINDEX,
+        FINALIZED_ROOT_GINDEX,
+    ),
+);
+
+define_light_client_pyclasses!(
+    electra,
+    Mainnet,
+    bootstrap = (
+        PyElectraLightClientBootstrapMainnet,
+        "ElectraLightClientBootstrapMainnet",
+    ),
+    update = (
+        PyElectraLightClientUpdateMainnet,
+        "ElectraLightClientUpdateMainnet",
+    ),
+    finality_update = (
+        PyElectraLightClientFinalityUpdateMainnet,
+        "ElectraLightClientFinalityUpdateMainnet",
+    ),
+    optimistic_update = (
+        PyElectraLightClientOptimisticUpdateMainnet,
+        "ElectraLightClientOptimisticUpdateMainnet",
+    ),
+    gindices = (
+        CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA,
+        NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA,
+        FINALIZED_ROOT_GINDEX_ELECTRA,
+    ),
+);
+
+define_light_client_pyclasses!(
+    electra,
+    Minimal,
+    bootstrap = (
+        PyElectraLightClientBootstrapMinimal,
+        "ElectraLightClientBootstrapMinimal",
+    ),
+    update = (
+        PyElectraLightClientUpdateMinimal,
+        "ElectraLightClientUpdateMinimal",
+    ),
+    finality_update = (
+        PyElectraLightClientFinalityUpdateMinimal,
+        "ElectraLightClientFinalityUpdateMinimal",
+    ),
+    optimistic_update = (
+        PyElectraLightClientOptimisticUpdateMinimal,
+        "ElectraLightClientOptimisticUpdateMinimal",
+    ),
+    gindices = (
+        CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA,
+        NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA,
+        FINALIZED_ROOT_GINDEX_ELECTRA,
+    ),
+);
+
+define_light_client_pyclasses!(
+    electra,
+    Gnosis,
+    bootstrap = (
+        PyElectraLightClientBootstrapGnosis,
+        "ElectraLightClientBootstrapGnosis",
+    ),
+    update = (
+        PyElectraLightClientUpdateGnosis,
+        "ElectraLightClientUpdateGnosis",
+    ),
+    finality_update = (
+        PyElectraLightClientFinalityUpdateGnosis,
+        "ElectraLightClientFinalityUpdateGnosis",
+    ),
+    optimistic_update = (
+        PyElectraLightClientOptimisticUpdateGnosis,
+        "ElectraLightClientOptimisticUpdateGnosis",
+    ),
+    gindices = (
+        CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA,
+        NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA,
+        FINALIZED_ROOT_GINDEX_ELECTRA,
+    ),
+);
+
+/// Registers the light client types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyAltairLightClientBootstrapMainnet>()?;
+    m.add_class::<PyAltairLightClientUpdateMainnet>()?;
+    m.add_class::<PyAltairLightClientFinalityUpdateMainnet>()?;
+    m.add_class::<PyAltairLightClientOptimisticUpdateMainnet>()?;
+    m.add_class::<PyAltairLightClientBootstrapMinimal>()?;
+    m.add_class::<PyAltairLightClientUpdateMinimal>()?;
+    m.add_class::<PyAltairLightClientFinalityUpdateMinimal>()?;
+    m.add_class::<PyAltairLightClientOptimisticUpdateMinimal>()?;
+    m.add_class::<PyAltairLightClientBootstrapGnosis>()?;
+    m.add_class::<PyAltairLightClientUpdateGnosis>()?;
+    m.add_class::<PyAltairLightClientFinalityUpdateGnosis>()?;
+    m.add_class::<PyAltairLightClientOptimisticUpdateGnosis>()?;
+    m.add_class::<PyCapellaLightClientBootstrapMainnet>()?;
+    m.add_class::<PyCapellaLightClientUpdateMainnet>()?;
+    m.add_class::<PyCapellaLightClientFinalityUpdateMainnet>()?;
+    m.add_class::<PyCapellaLightClientOptimisticUpdateMainnet>()?;
+    m.add_class::<PyCapellaLightClientBootstrapMinimal>()?;
+    m.add_class::<PyCapellaLightClientUpdateMinimal>()?;
+    m.add_class::<PyCapellaLightClientFinalityUpdateMinimal>()?;
+    m.add_class::<PyCapellaLightClientOptimisticUpdateMinimal>()?;
+    m.add_class::<PyCapellaLightClientBootstrapGnosis>()?;
+    m.add_class::<PyCapellaLightClientUpdateGnosis>()?;
+    m.add_class::<PyCapellaLightClientFinalityUpdateGnosis>()?;
+    m.add_class::<PyCapellaLightClientOptimisticUpdateGnosis>()?;
+    m.add_class::<PyDenebLightClientBootstrapMainnet>()?;
+    m.add_class::<PyDenebLightClientUpdateMainnet>()?;
+    m.add_class::<PyDenebLightClientFinalityUpdateMainnet>()?;
+    m.add_class::<PyDenebLightClientOptimisticUpdateMainnet>()?;
+    m.add_class::<PyDenebLightClientBootstrapMinimal>()?;
+    m.add_class::<PyDenebLightClientUpdateMinimal>()?;
+    m.add_class::<PyDenebLightClientFinalityUpdateMinimal>()?;
+    m.add_class::<PyDenebLightClientOptimisticUpdateMinimal>()?;
+    m.add_class::<PyDenebLightClientBootstrapGnosis>()?;
+    m.add_class::<PyDenebLightClientUpdateGnosis>()?;
+    m.add_class::<PyDenebLightClientFinalityUpdateGnosis>()?;
+    m.add_class::<PyDenebLightClientOptimisticUpdateGnosis>()?;
+    m.add_class::<PyElectraLightClientBootstrapMainnet>()?;
+    m.add_class::<PyElectraLightClientUpdateMainnet>()?;
+    m.add_class::<PyElectraLightClientFinalityUpdateMainnet>()?;
+    m.add_class::<PyElectraLightClientOptimisticUpdateMainnet>()?;
+    m.add_class::<PyElectraLightClientBootstrapMinimal>()?;
+    m.add_class::<PyElectraLightClientUpdateMinimal>()?;
+    m.add_class::<PyElectraLightClientFinalityUpdateMinimal>()?;
+    m.add_class::<PyElectraLightClientOptimisticUpdateMinimal>()?;
+    m.add_class::<PyElectraLightClientBootstrapGnosis>()?;
+    m.add_class::<PyElectraLightClientUpdateGnosis>()?;
+    m.add_class::<PyElectraLightClientFinalityUpdateGnosis>()?;
+    m.add_class::<PyElectraLightClientOptimisticUpdateGnosis>()?;
+    Ok(())
+}
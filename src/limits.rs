@@ -0,0 +1,211 @@
+//! Process-wide limits on untrusted decoder input.
+//!
+//! Every `from_ssz` and `from_json` call checks its input against these limits
+//! before decoding starts. JSON input is pre-scanned without allocating, so an
+//! oversized list or a deeply nested document is rejected before `serde_json`
+//! builds anything from it. SSZ lists need no separate check: their lengths are
+//! derived from the input size and capped by the type's maximum length before
+//! any elements are allocated.
+//!
+//! All limits are disabled by default.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Sentinel for a disabled limit.
+const UNLIMITED: usize = usize::MAX;
+
+static MAX_INPUT_SIZE: AtomicUsize = AtomicUsize::new(UNLIMITED);
+static MAX_LIST_LENGTH: AtomicUsize = AtomicUsize::new(UNLIMITED);
+static MAX_JSON_DEPTH: AtomicUsize = AtomicUsize::new(UNLIMITED);
+
+fn load(limit: &AtomicUsize) -> Option<usize> {
+    Some(limit.load(Ordering::Relaxed)).filter(|value| *value != UNLIMITED)
+}
+
+fn store(limit: &AtomicUsize, value: Option<usize>) {
+    limit.store(value.unwrap_or(UNLIMITED), Ordering::Relaxed);
+}
+
+fn check_size(len: usize) -> Result<(), String> {
+    match load(&MAX_INPUT_SIZE) {
+        Some(max) if len > max => Err(format!(
+            "Input of {len} bytes exceeds the decode limit of {max} bytes"
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// The configured `max_input_size`, if any.
+pub fn max_input_size() -> Option<usize> {
+    load(&MAX_INPUT_SIZE)
+}
+
+/// Checks SSZ input against the configured limits.
+///
+/// # Errors
+///
+/// Returns an error string if the input is larger than `max_input_size`.
+pub fn check_ssz(bytes: &[u8]) -> Result<(), String> {
+    check_size(bytes.len())
+}
+
+/// Checks JSON input against the configured limits without parsing it.
+///
+/// The scan only tracks brackets outside of strings, so it accepts some
+/// malformed documents; those are rejected by the parser afterwards.
+///
+/// # Errors
+///
+/// Returns an error string if the input is larger than `max_input_size`,
+/// nests deeper than `max_json_depth`, or contains an array with more than
+/// `max_list_length` elements.
+pub fn check_json(bytes: &[u8]) -> Result<(), String> {
+    check_size(bytes.len())?;
+
+    match JsonScan::new() {
+        Some(mut scan) => scan.feed(bytes),
+        None => Ok(()),
+    }
+}
+
+/// An incremental [`check_json`] scan of the `max_json_depth` and
+/// `max_list_length` limits, for input that is read in chunks.
+pub struct JsonScan {
+    max_depth: usize,
+    max_list_length: usize,
+    // One entry per open container: `Some(elements)` for arrays, `None` for
+    // objects. An array's element count is its comma count plus one once a
+    // value has been seen.
+    stack: Vec<Option<usize>>,
+    in_string: bool,
+    escaped: bool,
+}
+
+impl JsonScan {
+    /// Starts a scan with the configured limits, or returns `None` if both
+    /// are disabled.
+    #[must_use]
+    pub fn new() -> Option<Self> {
+        let max_depth = load(&MAX_JSON_DEPTH);
+        let max_list_length = load(&MAX_LIST_LENGTH);
+
+        if max_depth.is_none() && max_list_length.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            max_depth: max_depth.unwrap_or(UNLIMITED),
+            max_list_length: max_list_length.unwrap_or(UNLIMITED),
+            stack: Vec::new(),
+            in_string: false,
+            escaped: false,
+        })
+    }
+
+    /// Scans the next chunk of the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if the input so far nests deeper than
+    /// `max_json_depth` or contains an array with more than
+    /// `max_list_length` elements.
+    pub fn feed(&mut self, bytes: &[u8]) -> Result<(), String> {
+        for &byte in bytes {
+            if self.in_string {
+                match byte {
+                    _ if self.escaped => self.escaped = false,
+                    b'\\' => self.escaped = true,
+                    b'"' => self.in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+
+            if byte.is_ascii_whitespace() {
+                continue;
+            }
+
+            if let Some(Some(elements)) = self.stack.last_mut() {
+                match byte {
+                    b',' => *elements += 1,
+                    b']' => {}
+                    _ => *elements = (*elements).max(1),
+                }
+
+                if *elements > self.max_list_length {
+                    return Err(format!(
+                        "JSON array exceeds the decode limit of {} elements",
+                        self.max_list_length
+                    ));
+                }
+            }
+
+            match byte {
+                b'"' => self.in_string = true,
+                b'[' | b'{' => {
+                    if self.stack.len() >= self.max_depth {
+                        return Err(format!(
+                            "JSON nesting exceeds the decode limit of {} levels",
+                            self.max_depth
+                        ));
+                    }
+                    self.stack.push((byte == b'[').then_some(0));
+                }
+                b']' | b'}' => {
+                    self.stack.pop();
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Configure limits applied to all subsequent `from_ssz` and `from_json`
+/// calls. Passing `None` for a limit disables it, so calling this with no
+/// arguments removes all limits.
+///
+/// * `max_input_size` - maximum input size in bytes (SSZ and JSON)
+/// * `max_list_length` - maximum number of elements in any JSON array
+/// * `max_json_depth` - maximum nesting depth of JSON arrays and objects
+#[pyfunction]
+#[pyo3(signature = (*, max_input_size = None, max_list_length = None, max_json_depth = None))]
+pub fn set_decode_limits(
+    max_input_size: Option<usize>,
+    max_list_length: Option<usize>,
+    max_json_depth: Option<usize>,
+) {
+    store(&MAX_INPUT_SIZE, max_input_size);
+    store(&MAX_LIST_LENGTH, max_list_length);
+    store(&MAX_JSON_DEPTH, max_json_depth);
+}
+
+/// Return the current decode limits as a dict, with `None` for disabled
+/// limits.
+///
+/// # Errors
+///
+/// Returns `PyErr` if the dict cannot be built.
+#[pyfunction]
+pub fn get_decode_limits(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let limits = PyDict::new(py);
+    limits.set_item("max_input_size", load(&MAX_INPUT_SIZE))?;
+    limits.set_item("max_list_length", load(&MAX_LIST_LENGTH))?;
+    limits.set_item("max_json_depth", load(&MAX_JSON_DEPTH))?;
+    Ok(limits)
+}
+
+/// Registers the decode limit functions with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(set_decode_limits, m)?)?;
+    m.add_function(wrap_pyfunction!(get_decode_limits, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,286 @@
+//! Lazy sequence views over SSZ lists.
+//!
+//! Methods such as `attestations()` or `transactions()` return an `SszList`
+//! instead of a Python list. The view keeps the owning object alive and only
+//! converts the elements that are actually accessed, so taking the length of
+//! a large list or reading a few elements costs the same regardless of its
+//! size. Slicing returns another view without copying.
+//!
+//! Large byte vectors such as blobs are returned as `ByteView`s, which expose
+//! the owner's bytes through the buffer protocol, so `memoryview(view)` and
+//! `numpy.frombuffer(view)` do not copy them.
+
+use std::ffi::c_int;
+
+use pyo3::exceptions::{PyIndexError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PySlice};
+
+/// Converts the element at an index of the list owned by an object.
+///
+/// Returns `PyIndexError` if the index is out of range.
+pub type ItemGetter = fn(&Bound<'_, PyAny>, usize) -> PyResult<Py<PyAny>>;
+
+/// Read-only sequence view over an SSZ list owned by another object.
+#[pyclass(name = "SszList", frozen, sequence)]
+pub struct PySszList {
+    owner: Py<PyAny>,
+    getter: ItemGetter,
+    start: isize,
+    step: isize,
+    len: usize,
+}
+
+impl PySszList {
+    /// Creates a view over all `len` elements of a list owned by `owner`.
+    #[must_use]
+    pub fn new(owner: &Bound<'_, PyAny>, len: usize, getter: ItemGetter) -> Self {
+        Self {
+            owner: owner.clone().unbind(),
+            getter,
+            start: 0,
+            step: 1,
+            len,
+        }
+    }
+
+    fn item(&self, py: Python<'_>, index: usize) -> PyResult<Py<PyAny>> {
+        // `index < len` keeps the position within the owner's list.
+        #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+        let position = (self.start + index as isize * self.step) as usize;
+        (self.getter)(self.owner.bind(py), position)
+    }
+}
+
+#[pymethods]
+impl PySszList {
+    fn __len__(&self) -> usize {
+        self.len
+    }
+
+    /// Return the element at an index, or a view over a slice.
+    ///
+    /// # Errors
+    /// Returns `PyIndexError` if the index is out of range, or `PyTypeError`
+    /// if the key is neither an integer nor a slice.
+    fn __getitem__(&self, py: Python<'_>, key: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        if let Ok(slice) = key.cast::<PySlice>() {
+            let indices = slice.indices(isize::try_from(self.len)?)?;
+            let view = Self {
+                owner: self.owner.clone_ref(py),
+                getter: self.getter,
+                start: self.start + indices.start * self.step,
+                step: self.step * indices.step,
+                len: indices.slicelength,
+            };
+            return Ok(Py::new(py, view)?.into_any());
+        }
+
+        let index: isize = key
+            .extract()
+            .map_err(|_| PyTypeError::new_err("SszList indices must be integers or slices"))?;
+
+        let index = if index < 0 {
+            index.checked_add_unsigned(self.len)
+        } else {
+            Some(index)
+        };
+
+        match index.and_then(|index| usize::try_from(index).ok()) {
+            Some(index) if index < self.len => self.item(py, index),
+            _ => Err(PyIndexError::new_err("SszList index out of range")),
+        }
+    }
+
+    fn __iter__(slf: Bound<'_, Self>) -> PySszListIterator {
+        PySszListIterator {
+            list: slf.unbind(),
+            position: 0,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SszList(len={})", self.len)
+    }
+}
+
+/// Iterator over an [`PySszList`].
+#[pyclass(name = "SszListIterator")]
+pub struct PySszListIterator {
+    list: Py<PySszList>,
+    position: usize,
+}
+
+#[pymethods]
+impl PySszListIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        let list = self.list.get();
+
+        if self.position >= list.len {
+            return Ok(None);
+        }
+
+        let item = list.item(py, self.position)?;
+        self.position += 1;
+        Ok(Some(item))
+    }
+}
+
+/// Read-only, zero-copy view over bytes owned by another object.
+///
+/// The view does not copy the bytes; it keeps the owner alive and points into
+/// its wrapped value. This relies on wrapper classes never mutating `inner` in
+/// place, which holds for every class in this crate: methods that change a
+/// value return a new object instead.
+#[pyclass(name = "ByteView", frozen)]
+pub struct PyByteView {
+    owner: Py<PyAny>,
+    address: usize,
+    len: usize,
+}
+
+impl PyByteView {
+    /// Creates a view over `bytes`, which must be owned by `owner`'s wrapped
+    /// value.
+    #[must_use]
+    pub fn new(owner: &Bound<'_, PyAny>, bytes: &[u8]) -> Self {
+        Self {
+            owner: owner.clone().unbind(),
+            address: bytes.as_ptr() as usize,
+            len: bytes.len(),
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `address` and `len` describe bytes inside the owner's
+        // wrapped value, which `self.owner` keeps alive and which is never
+        // mutated in place.
+        unsafe { std::slice::from_raw_parts(self.address as *const u8, self.len) }
+    }
+}
+
+#[pymethods]
+impl PyByteView {
+    fn __len__(&self) -> usize {
+        self.len
+    }
+
+    /// Copy the viewed bytes into a `bytes` object.
+    fn __bytes__<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, self.as_bytes())
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        if let Ok(other) = other.cast::<Self>() {
+            return self.as_bytes() == other.get().as_bytes();
+        }
+
+        other
+            .cast::<PyBytes>()
+            .is_ok_and(|other| self.as_bytes() == other.as_bytes())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ByteView(len={})", self.len)
+    }
+
+    /// Export the viewed bytes as a read-only buffer.
+    ///
+    /// # Safety
+    /// Called by the Python buffer protocol with a valid `view` pointer.
+    unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut pyo3::ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let this = slf.get();
+        let len = isize::try_from(this.len)?;
+
+        // `PyBuffer_FillInfo` takes a reference to `slf`, which in turn keeps
+        // the owner alive until the buffer is released.
+        let result = unsafe {
+            pyo3::ffi::PyBuffer_FillInfo(
+                view,
+                slf.as_ptr(),
+                this.address as *mut std::ffi::c_void,
+                len,
+                1,
+                flags,
+            )
+        };
+
+        if result == -1 {
+            return Err(PyErr::fetch(slf.py()));
+        }
+
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(&self, _view: *mut pyo3::ffi::Py_buffer) {}
+}
+
+/// Builds an [`PySszList`] over a list field of a pyclass's `inner` value.
+///
+/// Must be invoked inside a method of the owning pyclass, with `$slf` bound
+/// to `&Bound<'_, Self>`. `|inner| $list` projects the wrapped value to the
+/// list, and the last argument selects the element conversion:
+///
+/// * `class = Class` - wrap a clone of the element in `Class { inner }`
+/// * `bytes` - copy byte-list elements into `bytes`
+/// * `bool` - convert bitlist elements to `bool`
+/// * `json` - convert elements to Python values via their JSON form
+/// * `view` - wrap byte-vector elements in a zero-copy [`PyByteView`]
+#[macro_export]
+macro_rules! ssz_list_view {
+    (@convert $py:ident, $owner:ident, $item:ident, view) => {
+        Ok(pyo3::Py::new($py, $crate::list::PyByteView::new($owner, $item.as_bytes()))?.into_any())
+    };
+    (@convert $py:ident, $owner:ident, $item:ident, class = $class:ident) => {
+        // Called as a function so that `Copy` elements pass `clone_on_copy`.
+        Ok(pyo3::Py::new($py, $class { inner: Clone::clone($item) })?.into_any())
+    };
+    (@convert $py:ident, $owner:ident, $item:ident, bytes) => {
+        Ok(pyo3::types::PyBytes::new($py, $item).into_any().unbind())
+    };
+    (@convert $py:ident, $owner:ident, $item:ident, bool) => {
+        Ok(pyo3::types::PyBool::new($py, *$item).to_owned().into_any().unbind())
+    };
+    (@convert $py:ident, $owner:ident, $item:ident, json) => {{
+        let value = serde_json::to_value($item)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        $crate::convert::json_to_py($py, &value, $crate::convert::DictStyle::Json, None)
+            .map(pyo3::Bound::unbind)
+    }};
+    ($slf:expr, |$inner:ident| $list:expr, $($convert:tt)+) => {{
+        let len = {
+            let $inner = &$slf.borrow().inner;
+            $list.len()
+        };
+
+        $crate::list::PySszList::new($slf.as_any(), len, |owner, index| {
+            let py = owner.py();
+            let borrowed = owner.cast::<Self>()?.borrow();
+            let $inner = &borrowed.inner;
+            let item = $list.get(index).ok_or_else(|| {
+                pyo3::exceptions::PyIndexError::new_err("SszList index out of range")
+            })?;
+            $crate::ssz_list_view!(@convert py, owner, item, $($convert)+)
+        })
+    }};
+}
+
+/// Registers the list view classes with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PySszList>()?;
+    m.add_class::<PySszListIterator>()?;
+    m.add_class::<PyByteView>()?;
+    Ok(())
+}
@@ -3,24 +3,158 @@
 //! This module provides the `define_ssz_pyclass_for_preset!` macro which generates
 //! Python class definitions for SSZ-serializable types with support for different
 //! Ethereum presets (Mainnet, Minimal, Gnosis).
+//!
+//! The generated classes are frozen: their methods only read the wrapped
+//! value, so an object can be shared between Python threads without locks or
+//! copying its SSZ bytes, and memory exposed through `ByteView`s never
+//! changes. Types with methods that edit the value in place also get a
+//! mutable companion class, created with `clone_mut()` and turned back into
+//! a frozen object with `freeze()`.
+//!
+//! Decoders accept any object supporting the buffer protocol and read it in
+//! place, and decoding and encoding run with the GIL released, so other
+//! Python threads keep running while a large state is decoded.
 
-use grandine_ssz::{SszRead, SszReadDefault as _, SszWrite};
-use serde::Deserialize;
+use grandine_ssz::{SszHash, SszRead, SszReadDefault as _, SszWrite, H256};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use rayon::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 
+/// A Beacon API response body: the object under `data`, with the metadata
+/// fields that responses carry next to it.
 #[derive(Deserialize)]
 pub struct JsonDataEnvelope<T> {
     pub data: T,
+    pub version: Option<String>,
+    pub execution_optimistic: Option<bool>,
+    pub finalized: Option<bool>,
+}
+
+impl<T> JsonDataEnvelope<T> {
+    /// Returns the metadata fields present in the response as a dict.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PyErr` if the dict cannot be built.
+    pub fn metadata<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let metadata = PyDict::new(py);
+
+        if let Some(version) = &self.version {
+            metadata.set_item("version", version)?;
+        }
+        if let Some(execution_optimistic) = self.execution_optimistic {
+            metadata.set_item("execution_optimistic", execution_optimistic)?;
+        }
+        if let Some(finalized) = self.finalized {
+            metadata.set_item("finalized", finalized)?;
+        }
+
+        Ok(metadata)
+    }
+}
+
+/// A Beacon API response body as written by `to_json_api`.
+#[derive(Serialize)]
+pub struct JsonApiResponse<'a, T> {
+    pub version: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_optimistic: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finalized: Option<bool>,
+    pub data: &'a T,
 }
 
 /// Decodes SSZ-encoded bytes into a type.
 ///
 /// # Errors
 ///
-/// Returns an error string if the bytes cannot be decoded as the target type.
+/// Returns an error string if the bytes exceed the decode limits or cannot be
+/// decoded as the target type.
 pub fn decode_ssz<T: SszRead<()>>(bytes: &[u8]) -> Result<T, String> {
+    crate::limits::check_ssz(bytes)?;
     T::from_ssz_default(bytes).map_err(|e| e.to_string())
 }
 
+/// Decodes JSON-encoded bytes into a type.
+///
+/// # Errors
+///
+/// Returns an error string if the bytes exceed the decode limits or cannot be
+/// decoded as the target type.
+pub fn decode_json<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, String> {
+    crate::limits::check_json(bytes)?;
+    serde_json::from_slice(bytes).map_err(|e| e.to_string())
+}
+
+/// Decodes many JSON-encoded inputs into a type in parallel.
+///
+/// Runs on the rayon thread pool, so callers should release the GIL first.
+///
+/// # Errors
+///
+/// Returns an error string naming the first input, by position, that exceeds
+/// the decode limits or cannot be decoded as the target type.
+pub fn decode_json_batch<T: DeserializeOwned + Send>(inputs: &[&[u8]]) -> Result<Vec<T>, String> {
+    inputs
+        .par_iter()
+        .map(|bytes| decode_json(bytes))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .enumerate()
+        .map(|(index, result)| result.map_err(|e| format!("Item {index}: {e}")))
+        .collect()
+}
+
+/// Decodes many SSZ-encoded inputs into a type in parallel.
+///
+/// Runs on the rayon thread pool, so callers should release the GIL first.
+///
+/// # Errors
+///
+/// Returns an error string naming the first input, by position, that exceeds
+/// the decode limits or cannot be decoded as the target type.
+pub fn decode_ssz_batch<T: SszRead<()> + Send>(inputs: &[&[u8]]) -> Result<Vec<T>, String> {
+    inputs
+        .par_iter()
+        .map(|bytes| decode_ssz(bytes))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .enumerate()
+        .map(|(index, result)| result.map_err(|e| format!("Item {index}: {e}")))
+        .collect()
+}
+
+/// Encodes many values to SSZ bytes in parallel.
+///
+/// Runs on the rayon thread pool, so callers should release the GIL first.
+///
+/// # Errors
+///
+/// Returns an error string naming the first value, by position, that cannot
+/// be encoded.
+pub fn encode_ssz_batch<T: SszWrite + Sync>(values: &[&T]) -> Result<Vec<Vec<u8>>, String> {
+    values
+        .par_iter()
+        .map(|value| encode_ssz(*value))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .enumerate()
+        .map(|(index, result)| result.map_err(|e| format!("Item {index}: {e}")))
+        .collect()
+}
+
+/// Computes the hash tree roots of many values in parallel.
+///
+/// Runs on the rayon thread pool, so callers should release the GIL first.
+pub fn hash_tree_root_batch<T: SszHash + Sync>(values: &[&T]) -> Vec<H256> {
+    values
+        .par_iter()
+        .map(|value| value.hash_tree_root())
+        .collect()
+}
+
 /// Encodes a value to SSZ bytes.
 ///
 /// # Errors
@@ -41,13 +175,54 @@ pub fn encode_ssz<T: SszWrite>(value: &T) -> Result<Vec<u8>, String> {
 /// * `$py_name` - The Python class name (as a string literal)
 /// * `$rust_ty` - The underlying Rust type being wrapped
 /// * `extra_methods` (optional) - Additional methods to add to the class
+/// * `mutable` (optional) - The Rust struct and Python class name of a
+///   mutable companion class, and the methods that edit the value in place
 ///
 /// # Generated Methods
 ///
-/// * `from_ssz` - Deserialize from SSZ bytes
+/// * `from_ssz` - Deserialize from SSZ bytes, optionally ignoring trailing
+///   bytes after fixed-size encodings (requires `SszBounds`)
+/// * `from_ssz_snappy` - Deserialize from raw or framed snappy-compressed SSZ
+///   bytes
 /// * `to_ssz` - Serialize to SSZ bytes
+/// * `to_ssz_snappy` - Serialize to raw or framed snappy-compressed SSZ bytes
+/// * `hash_tree_root_of_ssz` - Compute the hash tree root of SSZ bytes without
+///   creating a Python object (requires `SszHash`)
 /// * `from_json` - Deserialize from JSON bytes (requires `DeserializeOwned`)
+/// * `from_json_batch` - Deserialize many JSON inputs in parallel
+/// * `from_ssz_batch`, `to_ssz_batch` - Deserialize and serialize many SSZ
+///   encodings in parallel
+/// * `hash_tree_root_batch` - Compute many hash tree roots in parallel
+///   (requires `SszHash`)
+/// * `ssz_fixed_size`, `ssz_min_size`, `ssz_max_size` - SSZ size bounds
+///   (requires `SszBounds`)
 /// * `to_json` - Serialize to JSON bytes (requires `Serialize`)
+/// * `from_json_file`, `to_json_file` - Stream JSON from and to a path or
+///   file object (see the `json_file` module)
+/// * `from_json_api`, `to_json_api` - Read and write Beacon API response
+///   bodies with their `version` and other metadata fields
+/// * `to_json_canonical` - Serialize to canonical JSON bytes (sorted keys, compact)
+/// * `to_pyspec_dict` - Convert to a dict following `eth2spec` conventions
+/// * `from_pyspec_dict` - Construct from an `eth2spec`-style dict
+/// * `to_dict` - Convert to native Python objects without JSON serialization
+/// * `__getattr__` - Read a top-level field as by `to_dict` (`block.message`)
+/// * `from_dict` - Construct from native Python objects, naming the failing field
+/// * `to_json_delta` - Serialize as a JSON Merge Patch against a base object
+/// * `from_json_delta` - Reconstruct from a base object and a JSON delta
+/// * `equals_ignoring` - Compare with another object, masking fields
+/// * `clone_mut` - Copy into the mutable companion class (with `mutable`)
+/// * `__eq__`, `__hash__` - Compare values, and hash the hash tree root
+/// * `__repr__` - The class name, slot (if the object has one) and root
+/// * `__copy__`, `__deepcopy__`, `__reduce__` - Copy (objects are immutable,
+///   so copies are the object itself) and pickle as SSZ bytes
+///
+/// The mutable companion class has `freeze`, `to_ssz` and `to_json`, plus
+/// the `mutable` methods.
+///
+/// With `root_cache = Type`, both classes also carry a `roots: Type` field
+/// for roots computed from the value (see the `root_cache` module), which
+/// starts out empty and is copied by `clone_mut` and `freeze`. Objects are
+/// built from their value with `From`, which works with or without one.
 ///
 /// # Example
 ///
@@ -65,28 +240,356 @@ macro_rules! define_ssz_pyclass_for_preset {
         $py_name:literal,
         $rust_ty:ty
         $(, extra_methods = { $($extra:tt)* } )?
+        $(, mutable = ($mut_struct:ident, $mut_py_name:literal, { $($mut_methods:tt)* }) )?
+        , root_cache = $roots_ty:ty
     ) => {
-        #[pyo3::prelude::pyclass(name = $py_name)]
+        #[pyo3::prelude::pyclass(name = $py_name, frozen, module = "grandine_py")]
         pub struct $rust_struct {
             pub(crate) inner: $rust_ty,
+            pub(crate) roots: $roots_ty,
+        }
+
+        impl From<$rust_ty> for $rust_struct {
+            fn from(inner: $rust_ty) -> Self {
+                Self {
+                    inner,
+                    roots: <$roots_ty>::default(),
+                }
+            }
         }
 
+        $(
+            #[pyo3::prelude::pyclass(name = $mut_py_name)]
+            pub struct $mut_struct {
+                pub(crate) inner: $rust_ty,
+                pub(crate) roots: $roots_ty,
+            }
+
+            impl From<&$mut_struct> for $rust_struct {
+                fn from(value: &$mut_struct) -> Self {
+                    Self {
+                        inner: value.inner.clone(),
+                        roots: value.roots.clone(),
+                    }
+                }
+            }
+
+            impl From<&$rust_struct> for $mut_struct {
+                fn from(value: &$rust_struct) -> Self {
+                    Self {
+                        inner: value.inner.clone(),
+                        roots: value.roots.clone(),
+                    }
+                }
+            }
+        )?
+
+        $crate::define_ssz_pyclass_for_preset!(
+            @methods $rust_struct,
+            $py_name,
+            $rust_ty
+            $(, extra_methods = { $($extra)* })?
+            $(, mutable = ($mut_struct, $mut_py_name, { $($mut_methods)* }))?
+        );
+    };
+    (
+        $rust_struct:ident,
+        $py_name:literal,
+        $rust_ty:ty
+        $(, extra_methods = { $($extra:tt)* } )?
+        $(, mutable = ($mut_struct:ident, $mut_py_name:literal, { $($mut_methods:tt)* }) )?
+    ) => {
+        #[pyo3::prelude::pyclass(name = $py_name, frozen, module = "grandine_py")]
+        pub struct $rust_struct {
+            pub(crate) inner: $rust_ty,
+        }
+
+        impl From<$rust_ty> for $rust_struct {
+            fn from(inner: $rust_ty) -> Self {
+                Self { inner }
+            }
+        }
+
+        $(
+            #[pyo3::prelude::pyclass(name = $mut_py_name)]
+            pub struct $mut_struct {
+                pub(crate) inner: $rust_ty,
+            }
+
+            impl From<&$mut_struct> for $rust_struct {
+                fn from(value: &$mut_struct) -> Self {
+                    Self {
+                        inner: value.inner.clone(),
+                    }
+                }
+            }
+
+            impl From<&$rust_struct> for $mut_struct {
+                fn from(value: &$rust_struct) -> Self {
+                    Self {
+                        inner: value.inner.clone(),
+                    }
+                }
+            }
+        )?
+
+        $crate::define_ssz_pyclass_for_preset!(
+            @methods $rust_struct,
+            $py_name,
+            $rust_ty
+            $(, extra_methods = { $($extra)* })?
+            $(, mutable = ($mut_struct, $mut_py_name, { $($mut_methods)* }))?
+        );
+    };
+    (
+        @methods $rust_struct:ident,
+        $py_name:literal,
+        $rust_ty:ty
+        $(, extra_methods = { $($extra:tt)* } )?
+        $(, mutable = ($mut_struct:ident, $mut_py_name:literal, { $($mut_methods:tt)* }) )?
+    ) => {
+        $(
+            #[pyo3::prelude::pymethods]
+            impl $mut_struct {
+                /// Return a frozen copy, which can be shared between threads.
+                pub fn freeze(&self) -> $rust_struct {
+                    $rust_struct::from(self)
+                }
+
+                /// Serialize to SSZ-encoded bytes.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if serialization fails.
+                pub fn to_ssz(
+                    &self,
+                    py: pyo3::Python<'_>,
+                ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyBytes>> {
+                    let inner_ref = &self.inner;
+                    let out: Vec<u8> = py
+                        .detach(|| $crate::encode_ssz(inner_ref))
+                        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                    Ok(pyo3::types::PyBytes::new(py, &out).into())
+                }
+
+                /// Serialize to JSON-encoded bytes.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if serialization fails.
+                pub fn to_json(
+                    &self,
+                    py: pyo3::Python<'_>,
+                ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyBytes>>
+                where
+                    $rust_ty: serde::Serialize,
+                {
+                    let inner_ref = &self.inner;
+                    let out: Vec<u8> = py
+                        .detach(|| $crate::hex_format::to_json_vec(inner_ref))
+                        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                    Ok(pyo3::types::PyBytes::new(py, &out).into())
+                }
+
+                $($mut_methods)*
+            }
+        )?
+
         #[pyo3::prelude::pymethods]
         impl $rust_struct {
+            $(
+                /// Return a mutable copy, for the methods that edit the value
+                /// in place. `freeze()` turns it back into a frozen object.
+                pub fn clone_mut(&self) -> $mut_struct {
+                    $mut_struct::from(self)
+                }
+            )?
+
             #[staticmethod]
+            #[pyo3(signature = (b, strict = true))]
             /// Deserialize from SSZ-encoded bytes.
             ///
+            /// `b` is `bytes` or any other object supporting the buffer
+            /// protocol, such as a `bytearray`, `memoryview` or NumPy array.
+            /// It is decoded with the GIL released. The bytes of `bytes`, of a
+            /// `ByteView` and of memoryviews of them are read in place; any
+            /// other buffer, including writable ones, is copied first.
+            ///
+            /// With `strict=False`, bytes after the encoding are ignored and a
+            /// `(value, consumed)` tuple is returned, for objects embedded in
+            /// larger buffers. Only fixed-size types support this: the
+            /// encoding of a variable-size type extends to the end of the
+            /// buffer, so its length cannot be told from the bytes.
+            ///
             /// # Errors
-            /// Returns `PyValueError` if deserialization fails.
+            /// Returns `PyValueError` if deserialization fails, or `strict` is
+            /// false and the type is variable-size.
             pub fn from_ssz(
                 py: pyo3::Python<'_>,
-                b: &pyo3::Bound<'_, pyo3::types::PyBytes>,
-            ) -> pyo3::PyResult<Self> {
-                let bytes = b.as_bytes().to_vec();
+                b: &pyo3::Bound<'_, pyo3::PyAny>,
+                strict: bool,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>>
+            where
+                $rust_ty: $crate::ssz_size::SszBounds,
+            {
+                let buffer = $crate::convert::input_buffer(b)?;
+                let mut bytes = $crate::convert::buffer_bytes(&buffer);
+
+                if !strict {
+                    let bounds = <$rust_ty as $crate::ssz_size::SszBounds>::ssz_bounds();
+
+                    if bounds.variable {
+                        return Err(pyo3::exceptions::PyValueError::new_err(concat!(
+                            $py_name,
+                            " is variable-size, so trailing bytes cannot be told apart from ",
+                            "its encoding; slice the buffer and use strict=True",
+                        )));
+                    }
+
+                    let fixed = usize::try_from(bounds.fixed).unwrap_or(usize::MAX);
+                    bytes = &bytes[..bytes.len().min(fixed)];
+                }
+
                 let inner: $rust_ty = py
                     .detach(|| $crate::decode_ssz(&bytes))
                     .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
-                Ok(Self { inner })
+                let value = pyo3::Py::new(py, Self::from(inner))?.into_any();
+
+                if strict {
+                    return Ok(value);
+                }
+
+                let consumed = bytes.len();
+                Ok(pyo3::IntoPyObject::into_pyobject((value, consumed), py)?
+                    .into_any()
+                    .unbind())
+            }
+
+            #[staticmethod]
+            /// Deserialize many SSZ-encoded inputs in parallel.
+            ///
+            /// Each input is any object accepted by `from_ssz`. Decoding runs
+            /// on a thread pool with the GIL released, so the whole batch
+            /// crosses into Rust once.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` naming the first input that fails to
+            /// decode.
+            pub fn from_ssz_batch(
+                py: pyo3::Python<'_>,
+                items: Vec<pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<Vec<Self>>
+            where
+                $rust_ty: Send,
+            {
+                let buffers = items
+                    .iter()
+                    .map($crate::convert::input_buffer)
+                    .collect::<pyo3::PyResult<Vec<_>>>()?;
+                let inputs: Vec<&[u8]> =
+                    buffers.iter().map($crate::convert::buffer_bytes).collect();
+                let values: Vec<$rust_ty> = py
+                    .detach(|| $crate::decode_ssz_batch(&inputs))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+
+                Ok(values.into_iter().map(Self::from).collect())
+            }
+
+            #[staticmethod]
+            /// Serialize many objects to SSZ-encoded bytes in parallel, with
+            /// the GIL released.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` naming the first object that fails to
+            /// serialize.
+            pub fn to_ssz_batch(
+                py: pyo3::Python<'_>,
+                objects: Vec<pyo3::Bound<'_, Self>>,
+            ) -> pyo3::PyResult<Vec<pyo3::Py<pyo3::types::PyBytes>>>
+            where
+                $rust_ty: Sync,
+            {
+                let values: Vec<&$rust_ty> =
+                    objects.iter().map(|object| &object.get().inner).collect();
+                let encoded = py
+                    .detach(|| $crate::encode_ssz_batch(&values))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+
+                Ok(encoded
+                    .iter()
+                    .map(|out| pyo3::types::PyBytes::new(py, out).unbind())
+                    .collect())
+            }
+
+            #[staticmethod]
+            /// Hash tree roots of many objects, computed in parallel with the
+            /// GIL released.
+            pub fn hash_tree_root_batch(
+                py: pyo3::Python<'_>,
+                objects: Vec<pyo3::Bound<'_, Self>>,
+            ) -> Vec<String>
+            where
+                $rust_ty: grandine_ssz::SszHash + Sync,
+            {
+                let values: Vec<&$rust_ty> =
+                    objects.iter().map(|object| &object.get().inner).collect();
+                let roots = py.detach(|| $crate::hash_tree_root_batch(&values));
+                roots.iter().map($crate::convert::format_hash_tree_root).collect()
+            }
+
+            #[staticmethod]
+            #[pyo3(signature = (b, framed = None))]
+            /// Deserialize from snappy-compressed SSZ bytes, as exchanged by
+            /// beacon nodes.
+            ///
+            /// `framed=True` expects the framing format of req/resp chunks and
+            /// `framed=False` the raw format of gossip messages; by default the
+            /// format is detected from the stream identifier of framed data.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if decompression or deserialization
+            /// fails.
+            pub fn from_ssz_snappy(
+                py: pyo3::Python<'_>,
+                b: &pyo3::Bound<'_, pyo3::PyAny>,
+                framed: Option<bool>,
+            ) -> pyo3::PyResult<Self> {
+                let buffer = $crate::convert::input_buffer(b)?;
+                let bytes = $crate::convert::buffer_bytes(&buffer);
+                let inner: $rust_ty = py
+                    .detach(|| {
+                        $crate::snappy::decompress(bytes, framed)
+                            .and_then(|ssz| $crate::decode_ssz(&ssz))
+                    })
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                Ok(Self::from(inner))
+            }
+
+            #[staticmethod]
+            /// Compute the hash tree root of SSZ-encoded bytes without
+            /// creating a Python object.
+            ///
+            /// Decoding and hashing both run with the GIL released. For signed
+            /// containers this is the root of the signed container, which
+            /// differs from the root of the unsigned message.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if deserialization fails.
+            pub fn hash_tree_root_of_ssz(
+                py: pyo3::Python<'_>,
+                b: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<String>
+            where
+                $rust_ty: grandine_ssz::SszHash,
+            {
+                let buffer = $crate::convert::input_buffer(b)?;
+                let bytes = $crate::convert::buffer_bytes(&buffer);
+                py.detach(|| {
+                    $crate::decode_ssz::<$rust_ty>(&bytes).map(|inner| {
+                        $crate::convert::format_hash_tree_root(
+                            &grandine_ssz::SszHash::hash_tree_root(&inner),
+                        )
+                    })
+                })
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))
             }
 
             #[staticmethod]
@@ -98,17 +601,126 @@ macro_rules! define_ssz_pyclass_for_preset {
             /// Returns `PyValueError` if deserialization fails.
             pub fn from_json(
                 py: pyo3::Python<'_>,
-                b: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+                b: &pyo3::Bound<'_, pyo3::PyAny>,
             ) -> pyo3::PyResult<Self>
             where
                 $rust_ty: serde::de::DeserializeOwned,
             {
-                let bytes = b.as_bytes().to_vec();
+                let buffer = $crate::convert::input_buffer(b)?;
+                let bytes = $crate::convert::buffer_bytes(&buffer);
                 let env: $crate::JsonDataEnvelope<$rust_ty> = py
-                    .detach(|| serde_json::from_slice(&bytes))
-                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+                    .detach(|| $crate::decode_json(&bytes))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
 
-                Ok(Self { inner: env.data })
+                Ok(Self::from(env.data))
+            }
+
+            #[staticmethod]
+            /// Deserialize from a JSON file in the format `{"data": <value>}`,
+            /// parsing it as it is read instead of loading it into memory.
+            ///
+            /// `source` is a path or a binary file object. The decode limits
+            /// of `from_json` apply.
+            ///
+            /// # Errors
+            /// Returns `OSError` if the file cannot be read, `PyValueError` if
+            /// deserialization fails, or `PyTypeError` if `source` is neither
+            /// a path nor a file object.
+            pub fn from_json_file(
+                py: pyo3::Python<'_>,
+                source: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<Self>
+            where
+                $rust_ty: serde::de::DeserializeOwned + Send,
+            {
+                let env: $crate::JsonDataEnvelope<$rust_ty> =
+                    $crate::json_file::read_json(py, source)?;
+
+                Ok(Self::from(env.data))
+            }
+
+            #[staticmethod]
+            /// Deserialize a Beacon API response body, returning the object
+            /// and the response metadata.
+            ///
+            /// The metadata dict holds whichever of `version`,
+            /// `execution_optimistic` and `finalized` the response has.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if deserialization fails.
+            pub fn from_json_api<'py>(
+                py: pyo3::Python<'py>,
+                b: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<(Self, pyo3::Bound<'py, pyo3::types::PyDict>)>
+            where
+                $rust_ty: serde::de::DeserializeOwned,
+            {
+                let buffer = $crate::convert::input_buffer(b)?;
+                let bytes = $crate::convert::buffer_bytes(&buffer);
+                let env: $crate::JsonDataEnvelope<$rust_ty> = py
+                    .detach(|| $crate::decode_json(&bytes))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+
+                let metadata = env.metadata(py)?;
+                Ok((Self::from(env.data), metadata))
+            }
+
+            #[staticmethod]
+            /// Deserialize many JSON-encoded inputs in parallel.
+            ///
+            /// Each input has the format expected by `from_json`. Decoding
+            /// runs on a thread pool with the GIL released.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` naming the first input that fails to
+            /// decode.
+            pub fn from_json_batch(
+                py: pyo3::Python<'_>,
+                items: Vec<pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<Vec<Self>>
+            where
+                $rust_ty: serde::de::DeserializeOwned + Send,
+            {
+                let buffers = items
+                    .iter()
+                    .map($crate::convert::input_buffer)
+                    .collect::<pyo3::PyResult<Vec<_>>>()?;
+                let inputs: Vec<&[u8]> =
+                    buffers.iter().map($crate::convert::buffer_bytes).collect();
+                let envs: Vec<$crate::JsonDataEnvelope<$rust_ty>> = py
+                    .detach(|| $crate::decode_json_batch(&inputs))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+
+                Ok(envs.into_iter().map(|env| Self::from(env.data)).collect())
+            }
+
+            #[staticmethod]
+            /// Size of the fixed part of the SSZ encoding, in bytes.
+            ///
+            /// This is the whole encoding for fixed-size types.
+            pub fn ssz_fixed_size() -> u64
+            where
+                $rust_ty: $crate::ssz_size::SszBounds,
+            {
+                <$rust_ty as $crate::ssz_size::SszBounds>::ssz_bounds().fixed
+            }
+
+            #[staticmethod]
+            /// Smallest possible SSZ encoding, in bytes.
+            pub fn ssz_min_size() -> u64
+            where
+                $rust_ty: $crate::ssz_size::SszBounds,
+            {
+                <$rust_ty as $crate::ssz_size::SszBounds>::ssz_bounds().min
+            }
+
+            #[staticmethod]
+            /// Largest possible SSZ encoding under the preset's limits, in bytes.
+            pub fn ssz_max_size() -> u64
+            where
+                $rust_ty: $crate::ssz_size::SszBounds,
+            {
+                <$rust_ty as $crate::ssz_size::SszBounds>::ssz_bounds().max
             }
 
             /// Serialize to SSZ-encoded bytes.
@@ -126,6 +738,28 @@ macro_rules! define_ssz_pyclass_for_preset {
                 Ok(pyo3::types::PyBytes::new(py, &out).into())
             }
 
+            /// Serialize to snappy-compressed SSZ bytes, in the raw format of
+            /// gossip messages or, with `framed=True`, the framing format of
+            /// req/resp chunks.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if serialization fails.
+            #[pyo3(signature = (framed = false))]
+            pub fn to_ssz_snappy(
+                &self,
+                py: pyo3::Python<'_>,
+                framed: bool,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyBytes>> {
+                let inner_ref = &self.inner;
+                let out: Vec<u8> = py
+                    .detach(|| {
+                        $crate::encode_ssz(inner_ref)
+                            .and_then(|ssz| $crate::snappy::compress(&ssz, framed))
+                    })
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                Ok(pyo3::types::PyBytes::new(py, &out).into())
+            }
+
             /// Serialize to JSON-encoded bytes.
             ///
             /// # Errors
@@ -139,11 +773,363 @@ macro_rules! define_ssz_pyclass_for_preset {
             {
                 let inner_ref = &self.inner;
                 let out: Vec<u8> = py
-                    .detach(|| serde_json::to_vec(inner_ref))
+                    .detach(|| $crate::hex_format::to_json_vec(inner_ref))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                Ok(pyo3::types::PyBytes::new(py, &out).into())
+            }
+
+            /// Serialize to a JSON file with the output of `to_json`, writing
+            /// it as it is serialized instead of building it in memory.
+            ///
+            /// `destination` is a path, which is created or truncated, or a
+            /// binary file object, which is flushed but not closed.
+            ///
+            /// # Errors
+            /// Returns `OSError` if the file cannot be written, `PyValueError`
+            /// if serialization fails, or `PyTypeError` if `destination` is
+            /// neither a path nor a file object.
+            pub fn to_json_file(
+                &self,
+                py: pyo3::Python<'_>,
+                destination: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<()>
+            where
+                $rust_ty: serde::Serialize + Sync,
+            {
+                $crate::json_file::write_json(py, &self.inner, destination)
+            }
+
+            #[pyo3(signature = (version, execution_optimistic = None, finalized = None))]
+            /// Serialize to a Beacon API response body,
+            /// `{"version": ..., "data": ...}`, as read by `from_json_api`.
+            ///
+            /// `version` is the fork name, case-insensitively. The
+            /// `execution_optimistic` and `finalized` fields are only written
+            /// when given.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `version` is not a known fork or
+            /// serialization fails.
+            pub fn to_json_api(
+                &self,
+                py: pyo3::Python<'_>,
+                version: &str,
+                execution_optimistic: Option<bool>,
+                finalized: Option<bool>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyBytes>>
+            where
+                $rust_ty: serde::Serialize,
+            {
+                let version = $crate::envelope::Fork::parse(version)
+                    .map_err(pyo3::exceptions::PyValueError::new_err)?
+                    .name();
+                let response = $crate::JsonApiResponse {
+                    version,
+                    execution_optimistic,
+                    finalized,
+                    data: &self.inner,
+                };
+                let out: Vec<u8> = py
+                    .detach(|| $crate::hex_format::to_json_vec(&response))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                Ok(pyo3::types::PyBytes::new(py, &out).into())
+            }
+
+            /// Serialize to canonical JSON-encoded bytes.
+            ///
+            /// The output has sorted keys and no whitespace, so it is stable
+            /// across runs and platforms; see the `json` module for details.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if serialization fails.
+            pub fn to_json_canonical(
+                &self,
+                py: pyo3::Python<'_>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyBytes>>
+            where
+                $rust_ty: serde::Serialize,
+            {
+                let inner_ref = &self.inner;
+                let out: Vec<u8> = py
+                    .detach(|| $crate::json::to_json_canonical(inner_ref))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                Ok(pyo3::types::PyBytes::new(py, &out).into())
+            }
+
+            /// Convert to a dict following the conventions of the `eth2spec`
+            /// package (`eth2spec.debug.encode`).
+            ///
+            /// Field names match the spec. Integers of up to 64 bits are
+            /// Python ints, `uint256` values are decimal strings, and byte
+            /// and bit fields are `0x`-prefixed hex strings.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if serialization fails.
+            pub fn to_pyspec_dict<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>>
+            where
+                $rust_ty: serde::Serialize,
+            {
+                let inner_ref = &self.inner;
+                let value = py
+                    .detach(|| serde_json::to_value(inner_ref))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+                $crate::convert::json_to_py(py, &value, $crate::convert::DictStyle::Pyspec, None)
+            }
+
+            /// Convert to native Python objects without JSON serialization.
+            ///
+            /// Containers become dicts, integers Python ints, and byte and
+            /// bit fields `bytes`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if conversion fails.
+            pub fn to_dict<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>>
+            where
+                $rust_ty: serde::Serialize,
+            {
+                $crate::native::to_native(py, &self.inner)
+            }
+
+            /// Look up a top-level field, converted as by `to_dict`.
+            ///
+            /// # Errors
+            /// Returns `PyAttributeError` if there is no such field.
+            pub fn __getattr__<'py>(
+                &self,
+                py: pyo3::Python<'py>,
+                name: &str,
+            ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::PyAny>>
+            where
+                $rust_ty: serde::Serialize,
+            {
+                let field = if name.starts_with("__") {
+                    None
+                } else {
+                    $crate::native::field_to_native(py, &self.inner, name)?
+                };
+
+                field.ok_or_else(|| {
+                    pyo3::exceptions::PyAttributeError::new_err(format!(
+                        "'{}' object has no attribute '{name}'",
+                        $py_name
+                    ))
+                })
+            }
+
+            #[staticmethod]
+            /// Construct from native Python objects, as produced by `to_dict`.
+            ///
+            /// Integers may be given as ints or decimal strings, and byte
+            /// fields as `bytes` or hex strings. Errors name the path of the
+            /// offending field (`message.body.attestations[2].data.slot`).
+            ///
+            /// # Errors
+            /// Returns `PyTypeError` if the dict contains unsupported values
+            /// and `PyValueError` if it does not describe a valid object.
+            pub fn from_dict(
+                py: pyo3::Python<'_>,
+                d: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<Self>
+            where
+                $rust_ty: serde::de::DeserializeOwned,
+            {
+                let value = $crate::convert::py_to_json(d)?;
+                let inner: $rust_ty = py
+                    .detach(|| $crate::json::from_value_with_path(value))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                Ok(Self::from(inner))
+            }
+
+            #[staticmethod]
+            /// Construct from a dict following the conventions of the
+            /// `eth2spec` package, as produced by `to_pyspec_dict`.
+            ///
+            /// Integers may be given as ints or decimal strings, and byte
+            /// fields as `bytes` or hex strings.
+            ///
+            /// # Errors
+            /// Returns `PyTypeError` if the dict contains unsupported values
+            /// and `PyValueError` if it does not describe a valid object.
+            pub fn from_pyspec_dict(
+                py: pyo3::Python<'_>,
+                d: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<Self>
+            where
+                $rust_ty: serde::de::DeserializeOwned,
+            {
+                let value = $crate::convert::py_to_json(d)?;
+                let inner: $rust_ty = py
+                    .detach(|| serde_json::from_value(value))
                     .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+                Ok(Self::from(inner))
+            }
+
+            /// Serialize to a JSON delta containing only the fields that
+            /// differ from `base`.
+            ///
+            /// The delta is a JSON Merge Patch (RFC 7386); see the `json`
+            /// module for the exact format.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if serialization fails.
+            pub fn to_json_delta(
+                &self,
+                py: pyo3::Python<'_>,
+                base: pyo3::PyRef<'_, Self>,
+            ) -> pyo3::PyResult<pyo3::Py<pyo3::types::PyBytes>>
+            where
+                $rust_ty: serde::Serialize,
+            {
+                let inner_ref = &self.inner;
+                let base_ref = &base.inner;
+                let out: Vec<u8> = py
+                    .detach(|| $crate::json::to_json_delta(base_ref, inner_ref))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
                 Ok(pyo3::types::PyBytes::new(py, &out).into())
             }
 
+            /// Compare with `other`, ignoring the masked `fields`.
+            ///
+            /// A name (`signature`) masks every field of that name and a
+            /// dotted path (`message.state_root`) the field at that path; see
+            /// the `json` module for details.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if serialization fails.
+            #[pyo3(signature = (other, fields = vec!["signature".to_owned(), "state_root".to_owned()]))]
+            pub fn equals_ignoring(
+                &self,
+                py: pyo3::Python<'_>,
+                other: pyo3::PyRef<'_, Self>,
+                fields: Vec<String>,
+            ) -> pyo3::PyResult<bool>
+            where
+                $rust_ty: serde::Serialize,
+            {
+                let inner_ref = &self.inner;
+                let other_ref = &other.inner;
+                let masks = fields.iter().map(String::as_str).collect::<Vec<_>>();
+                py.detach(|| $crate::json::equals_ignoring(inner_ref, other_ref, &masks))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))
+            }
+
+            #[staticmethod]
+            /// Reconstruct an object by applying a JSON delta produced by
+            /// `to_json_delta` to `base`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the delta is invalid or the patched
+            /// JSON does not describe a valid object.
+            pub fn from_json_delta(
+                py: pyo3::Python<'_>,
+                base: pyo3::PyRef<'_, Self>,
+                delta: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<Self>
+            where
+                $rust_ty: serde::Serialize + serde::de::DeserializeOwned,
+            {
+                let buffer = $crate::convert::input_buffer(delta)?;
+                let bytes = $crate::convert::buffer_bytes(&buffer);
+                let base_ref = &base.inner;
+                let inner: $rust_ty = py
+                    .detach(|| $crate::json::from_json_delta(base_ref, &bytes))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                Ok(Self::from(inner))
+            }
+
+            /// Compare the values, which is faster than comparing roots.
+            pub fn __eq__(
+                &self,
+                py: pyo3::Python<'_>,
+                other: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> bool
+            where
+                $rust_ty: PartialEq + Sync,
+            {
+                use pyo3::types::PyAnyMethods as _;
+
+                let Ok(other) = other.cast::<Self>() else {
+                    return false;
+                };
+                let (inner_ref, other_ref) = (&self.inner, &other.get().inner);
+                py.detach(|| inner_ref == other_ref)
+            }
+
+            /// Hash of the hash tree root, so equal values hash equally.
+            pub fn __hash__(&self, py: pyo3::Python<'_>) -> u64
+            where
+                $rust_ty: grandine_ssz::SszHash + Sync,
+            {
+                let inner_ref = &self.inner;
+                py.detach(|| grandine_ssz::SszHash::hash_tree_root(inner_ref).to_low_u64_le())
+            }
+
+            /// `Name(slot=..., root=0x...)`, with the slot of objects that
+            /// have one (see `native::slot_to_native`).
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if the slot cannot be serialized.
+            pub fn __repr__(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<String>
+            where
+                $rust_ty: serde::Serialize + grandine_ssz::SszHash + Sync,
+            {
+                let inner_ref = &self.inner;
+                let root = $crate::convert::format_hash_tree_root(
+                    &py.detach(|| grandine_ssz::SszHash::hash_tree_root(inner_ref)),
+                );
+
+                Ok(match $crate::native::slot_to_native(py, &self.inner)? {
+                    Some(slot) => format!("{}(slot={slot}, root={root})", $py_name),
+                    None => format!("{}(root={root})", $py_name),
+                })
+            }
+
+            /// Objects are immutable, so a copy is the object itself.
+            pub fn __copy__(slf: pyo3::Bound<'_, Self>) -> pyo3::Bound<'_, Self> {
+                slf
+            }
+
+            /// Objects are immutable, so a deep copy is the object itself.
+            pub fn __deepcopy__<'py>(
+                slf: pyo3::Bound<'py, Self>,
+                _memo: &pyo3::Bound<'py, pyo3::PyAny>,
+            ) -> pyo3::Bound<'py, Self> {
+                slf
+            }
+
+            /// Pickle as the SSZ encoding, decoded by `from_ssz`.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if serialization fails.
+            pub fn __reduce__<'py>(
+                slf: &pyo3::Bound<'py, Self>,
+            ) -> pyo3::PyResult<(
+                pyo3::Bound<'py, pyo3::PyAny>,
+                (pyo3::Bound<'py, pyo3::types::PyType>,),
+            )> {
+                use pyo3::types::PyAnyMethods as _;
+
+                let py = slf.py();
+                let inner_ref = &slf.get().inner;
+                let out: Vec<u8> = py
+                    .detach(|| $crate::encode_ssz(inner_ref))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+
+                // Static methods cannot be pickled, so `from_ssz` is called on
+                // the class, which is pickled by name, by a `methodcaller`.
+                let from_ssz = py
+                    .import("operator")?
+                    .getattr("methodcaller")?
+                    .call1(("from_ssz", pyo3::types::PyBytes::new(py, &out)))?;
+                Ok((from_ssz, (slf.get_type(),)))
+            }
+
             $($($extra)*)?
         }
     };
@@ -158,7 +1144,7 @@ macro_rules! define_decodable_pyclass_for_preset {
         ssz_decoder = $ssz_decoder:expr
         $(, extra_methods = { $($extra:tt)* } )?
     ) => {
-        #[pyo3::prelude::pyclass(name = $py_name)]
+        #[pyo3::prelude::pyclass(name = $py_name, frozen)]
         pub struct $rust_struct {
             pub(crate) inner: $rust_ty,
         }
@@ -172,12 +1158,44 @@ macro_rules! define_decodable_pyclass_for_preset {
             /// Returns `PyValueError` if deserialization fails.
             pub fn from_ssz(
                 py: pyo3::Python<'_>,
-                b: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+                b: &pyo3::Bound<'_, pyo3::PyAny>,
             ) -> pyo3::PyResult<Self> {
-                let bytes = b.as_bytes().to_vec();
+                let buffer = $crate::convert::input_buffer(b)?;
+                let bytes = $crate::convert::buffer_bytes(&buffer);
                 let inner: $rust_ty = py
-                    .detach(|| ($ssz_decoder)(&bytes))
-                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+                    .detach(|| {
+                        $crate::limits::check_ssz(&bytes)?;
+                        ($ssz_decoder)(&bytes).map_err(|e| e.to_string())
+                    })
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                Ok(Self { inner })
+            }
+
+            #[staticmethod]
+            #[pyo3(signature = (b, framed = None))]
+            /// Deserialize from snappy-compressed SSZ bytes, as exchanged by
+            /// beacon nodes.
+            ///
+            /// `framed=True` expects the framing format of req/resp chunks and
+            /// `framed=False` the raw format of gossip messages; by default the
+            /// format is detected from the stream identifier of framed data.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if decompression or deserialization
+            /// fails.
+            pub fn from_ssz_snappy(
+                py: pyo3::Python<'_>,
+                b: &pyo3::Bound<'_, pyo3::PyAny>,
+                framed: Option<bool>,
+            ) -> pyo3::PyResult<Self> {
+                let buffer = $crate::convert::input_buffer(b)?;
+                let bytes = $crate::convert::buffer_bytes(&buffer);
+                let inner: $rust_ty = py
+                    .detach(|| {
+                        let ssz = $crate::snappy::decompress(bytes, framed)?;
+                        ($ssz_decoder)(&ssz).map_err(|e| e.to_string())
+                    })
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
                 Ok(Self { inner })
             }
 
@@ -188,15 +1206,128 @@ macro_rules! define_decodable_pyclass_for_preset {
             /// Returns `PyValueError` if deserialization fails.
             pub fn from_json(
                 py: pyo3::Python<'_>,
-                b: &pyo3::Bound<'_, pyo3::types::PyBytes>,
+                b: &pyo3::Bound<'_, pyo3::PyAny>,
             ) -> pyo3::PyResult<Self> {
-                let bytes = b.as_bytes().to_vec();
+                let buffer = $crate::convert::input_buffer(b)?;
+                let bytes = $crate::convert::buffer_bytes(&buffer);
                 let env: $crate::JsonDataEnvelope<$rust_ty> = py
-                    .detach(|| serde_json::from_slice(&bytes))
-                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+                    .detach(|| $crate::decode_json(&bytes))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                Ok(Self { inner: env.data })
+            }
+
+            #[staticmethod]
+            /// Deserialize from a JSON file in the format `{"data": <value>}`,
+            /// parsing it as it is read instead of loading it into memory.
+            ///
+            /// `source` is a path or a binary file object. The decode limits
+            /// of `from_json` apply.
+            ///
+            /// # Errors
+            /// Returns `OSError` if the file cannot be read, `PyValueError` if
+            /// deserialization fails, or `PyTypeError` if `source` is neither
+            /// a path nor a file object.
+            pub fn from_json_file(
+                py: pyo3::Python<'_>,
+                source: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<Self>
+            where
+                $rust_ty: serde::de::DeserializeOwned + Send,
+            {
+                let env: $crate::JsonDataEnvelope<$rust_ty> =
+                    $crate::json_file::read_json(py, source)?;
+
                 Ok(Self { inner: env.data })
             }
 
+            #[staticmethod]
+            /// Deserialize a Beacon API response body, returning the object
+            /// and the response metadata.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if deserialization fails.
+            pub fn from_json_api<'py>(
+                py: pyo3::Python<'py>,
+                b: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<(Self, pyo3::Bound<'py, pyo3::types::PyDict>)> {
+                let buffer = $crate::convert::input_buffer(b)?;
+                let bytes = $crate::convert::buffer_bytes(&buffer);
+                let env: $crate::JsonDataEnvelope<$rust_ty> = py
+                    .detach(|| $crate::decode_json(&bytes))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                let metadata = env.metadata(py)?;
+                Ok((Self { inner: env.data }, metadata))
+            }
+
+            #[staticmethod]
+            /// Deserialize many JSON-encoded inputs in parallel.
+            ///
+            /// Each input has the format expected by `from_json`. Decoding
+            /// runs on a thread pool with the GIL released.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` naming the first input that fails to
+            /// decode.
+            pub fn from_json_batch(
+                py: pyo3::Python<'_>,
+                items: Vec<pyo3::Bound<'_, pyo3::PyAny>>,
+            ) -> pyo3::PyResult<Vec<Self>> {
+                let buffers = items
+                    .iter()
+                    .map($crate::convert::input_buffer)
+                    .collect::<pyo3::PyResult<Vec<_>>>()?;
+                let inputs: Vec<&[u8]> =
+                    buffers.iter().map($crate::convert::buffer_bytes).collect();
+                let envs: Vec<$crate::JsonDataEnvelope<$rust_ty>> = py
+                    .detach(|| $crate::decode_json_batch(&inputs))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                Ok(envs.into_iter().map(|env| Self { inner: env.data }).collect())
+            }
+
+            #[staticmethod]
+            /// Construct from native Python objects, as produced by `to_dict`.
+            ///
+            /// Integers may be given as ints or decimal strings, and byte
+            /// fields as `bytes` or hex strings. Errors name the path of the
+            /// offending field (`message.body.attestations[2].data.slot`).
+            ///
+            /// # Errors
+            /// Returns `PyTypeError` if the dict contains unsupported values
+            /// and `PyValueError` if it does not describe a valid object.
+            pub fn from_dict(
+                py: pyo3::Python<'_>,
+                d: &pyo3::Bound<'_, pyo3::PyAny>,
+            ) -> pyo3::PyResult<Self>
+            where
+                $rust_ty: serde::de::DeserializeOwned,
+            {
+                let value = $crate::convert::py_to_json(d)?;
+                let inner: $rust_ty = py
+                    .detach(|| $crate::json::from_value_with_path(value))
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e))?;
+                Ok(Self { inner })
+            }
+
+            #[staticmethod]
+            /// Size of the fixed part of the SSZ encoding, in bytes.
+            ///
+            /// This is the whole encoding for fixed-size types.
+            pub fn ssz_fixed_size() -> u64 {
+                <$rust_ty as $crate::ssz_size::SszBounds>::ssz_bounds().fixed
+            }
+
+            #[staticmethod]
+            /// Smallest possible SSZ encoding, in bytes.
+            pub fn ssz_min_size() -> u64 {
+                <$rust_ty as $crate::ssz_size::SszBounds>::ssz_bounds().min
+            }
+
+            #[staticmethod]
+            /// Largest possible SSZ encoding under the preset's limits, in bytes.
+            pub fn ssz_max_size() -> u64 {
+                <$rust_ty as $crate::ssz_size::SszBounds>::ssz_bounds().max
+            }
+
             $($($extra)*)?
         }
     };
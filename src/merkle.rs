@@ -0,0 +1,639 @@
+//! SSZ Merkle proofs.
+//!
+//! The hash tree root of an SSZ object is the root of a binary Merkle tree
+//! over its fields, and a generalized index numbers the nodes of that tree:
+//! the root is 1 and the children of node `i` are `2i` and `2i + 1`. `prove`
+//! returns the Merkle branch of the node at a generalized index, the sibling
+//! of each node on the way from it up to the root, and `verify_merkle_proof`
+//! checks a branch as `is_valid_merkle_branch` of the consensus specs does,
+//! with the depth and index taken from the generalized index.
+//!
+//! Proofs descend through the containers of blocks and states and into the
+//! lists and vectors of composite values, whose elements are the roots of
+//! their trees. Lists of basic values (such as `balances`), bitfields and the
+//! fields of list elements are leaves: a proof can end at them but not go
+//! inside. Paths name container fields and index list and vector elements,
+//! as in `["body", "execution_payload", "state_root"]` or
+//! `["validators", 5]`.
+
+use grandine_ssz::{SszHash, H256};
+use grandine_types::deneb::containers::{ExecutionPayload, ExecutionPayloadHeader};
+use grandine_types::electra::beacon_state::BeaconState as ElectraBeaconState;
+use grandine_types::electra::containers::{
+    BeaconBlock, BeaconBlockBody, BlindedBeaconBlock, BlindedBeaconBlockBody,
+};
+use grandine_types::fulu::beacon_state::BeaconState as FuluBeaconState;
+use grandine_types::phase0::containers::{BeaconBlockHeader, Checkpoint};
+use grandine_types::preset::Preset;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use sha2::{Digest as _, Sha256};
+use typenum::Unsigned as _;
+
+use crate::convert::parse_root;
+
+/// A node of the Merkle tree of an SSZ object, as far as proofs descend.
+pub enum Node<'a> {
+    /// A subtree proofs do not descend into, given by its root.
+    Leaf(H256),
+    /// A container, with its root.
+    Container(H256, &'a dyn Merkleize),
+    /// A list or vector of composite values.
+    Elements {
+        root: H256,
+        /// Depth of the tree of elements, from the limit or length.
+        depth: u32,
+        /// Whether the length is mixed into the root, as it is for lists.
+        is_list: bool,
+        /// Computes the roots of the elements, only when a proof needs them.
+        roots: Box<dyn Fn() -> Vec<H256> + 'a>,
+    },
+}
+
+impl Node<'_> {
+    const fn root(&self) -> H256 {
+        match self {
+            Self::Leaf(root) | Self::Container(root, _) | Self::Elements { root, .. } => *root,
+        }
+    }
+}
+
+/// An SSZ container that proofs can descend into.
+pub trait Merkleize {
+    /// The fields of the container with their names, in order.
+    fn fields(&self) -> Vec<(&'static str, Node<'_>)>;
+}
+
+/// A field name or an element index of a path.
+#[derive(FromPyObject)]
+pub enum PathElement {
+    Index(u64),
+    Name(String),
+}
+
+/// Depth of a tree with `count` leaves, padded to a power of two.
+const fn depth_of(count: u64) -> u32 {
+    count.next_power_of_two().trailing_zeros()
+}
+
+macro_rules! node {
+    ($field:expr, leaf) => {
+        Node::Leaf($field.hash_tree_root())
+    };
+    ($field:expr, container) => {
+        Node::Container($field.hash_tree_root(), &$field)
+    };
+    // A `ContiguousList` of composite values.
+    ($field:expr, list($limit:expr)) => {{
+        let field = &$field;
+        Node::Elements {
+            root: field.hash_tree_root(),
+            depth: depth_of($limit),
+            is_list: true,
+            roots: Box::new(move || field.iter().map(SszHash::hash_tree_root).collect()),
+        }
+    }};
+    // A persistent list or vector of composite values in a state.
+    ($field:expr, persistent($length:expr, $is_list:expr)) => {{
+        let field = &$field;
+        Node::Elements {
+            root: field.hash_tree_root(),
+            depth: depth_of($length),
+            is_list: $is_list,
+            roots: Box::new(move || field.into_iter().map(SszHash::hash_tree_root).collect()),
+        }
+    }};
+}
+
+/// Implements `Merkleize` for a container from its fields, in order, and
+/// how proofs descend into each.
+macro_rules! merkleize {
+    ([$($generics:tt)*] $ty:ty { $($field:ident: $kind:ident $(($($arg:expr),*))?),* $(,)? }) => {
+        impl<$($generics)*> Merkleize for $ty {
+            fn fields(&self) -> Vec<(&'static str, Node<'_>)> {
+                vec![$((stringify!($field), node!(self.$field, $kind $(($($arg),*))?))),*]
+            }
+        }
+    };
+}
+
+merkleize!([] Checkpoint { epoch: leaf, root: leaf });
+
+merkleize!([] BeaconBlockHeader {
+    slot: leaf,
+    proposer_index: leaf,
+    parent_root: leaf,
+    state_root: leaf,
+    body_root: leaf,
+});
+
+merkleize!([P: Preset] BeaconBlock<P> {
+    slot: leaf,
+    proposer_index: leaf,
+    parent_root: leaf,
+    state_root: leaf,
+    body: container,
+});
+
+merkleize!([P: Preset] BlindedBeaconBlock<P> {
+    slot: leaf,
+    proposer_index: leaf,
+    parent_root: leaf,
+    state_root: leaf,
+    body: container,
+});
+
+merkleize!([P: Preset] BeaconBlockBody<P> {
+    randao_reveal: leaf,
+    eth1_data: leaf,
+    graffiti: leaf,
+    proposer_slashings: list(P::MaxProposerSlashings::U64),
+    attester_slashings: list(P::MaxAttesterSlashingsElectra::U64),
+    attestations: list(P::MaxAttestationsElectra::U64),
+    deposits: list(P::MaxDeposits::U64),
+    voluntary_exits: list(P::MaxVoluntaryExits::U64),
+    sync_aggregate: leaf,
+    execution_payload: container,
+    bls_to_execution_changes: list(P::MaxBlsToExecutionChanges::U64),
+    blob_kzg_commitments: list(P::MaxBlobCommitmentsPerBlock::U64),
+    execution_requests: leaf,
+});
+
+merkleize!([P: Preset] BlindedBeaconBlockBody<P> {
+    randao_reveal: leaf,
+    eth1_data: leaf,
+    graffiti: leaf,
+    proposer_slashings: list(P::MaxProposerSlashings::U64),
+    attester_slashings: list(P::MaxAttesterSlashingsElectra::U64),
+    attestations: list(P::MaxAttestationsElectra::U64),
+    deposits: list(P::MaxDeposits::U64),
+    voluntary_exits: list(P::MaxVoluntaryExits::U64),
+    sync_aggregate: leaf,
+    execution_payload_header: container,
+    bls_to_execution_changes: list(P::MaxBlsToExecutionChanges::U64),
+    blob_kzg_commitments: list(P::MaxBlobCommitmentsPerBlock::U64),
+    execution_requests: leaf,
+});
+
+merkleize!([P: Preset] ExecutionPayload<P> {
+    parent_hash: leaf,
+    fee_recipient: leaf,
+    state_root: leaf,
+    receipts_root: leaf,
+    logs_bloom: leaf,
+    prev_randao: leaf,
+    block_number: leaf,
+    gas_limit: leaf,
+    gas_used: leaf,
+    timestamp: leaf,
+    extra_data: leaf,
+    base_fee_per_gas: leaf,
+    block_hash: leaf,
+    transactions: list(P::MaxTransactionsPerPayload::U64),
+    withdrawals: list(P::MaxWithdrawalsPerPayload::U64),
+    blob_gas_used: leaf,
+    excess_blob_gas: leaf,
+});
+
+merkleize!([P: Preset] ExecutionPayloadHeader<P> {
+    parent_hash: leaf,
+    fee_recipient: leaf,
+    state_root: leaf,
+    receipts_root: leaf,
+    logs_bloom: leaf,
+    prev_randao: leaf,
+    block_number: leaf,
+    gas_limit: leaf,
+    gas_used: leaf,
+    timestamp: leaf,
+    extra_data: leaf,
+    base_fee_per_gas: leaf,
+    block_hash: leaf,
+    transactions_root: leaf,
+    withdrawals_root: leaf,
+    blob_gas_used: leaf,
+    excess_blob_gas: leaf,
+});
+
+/// Implements `Merkleize` for a beacon state with the Electra fields,
+/// followed by the leaf fields `$extra` of later forks.
+macro_rules! merkleize_state {
+    ($ty:ty $(, $extra:ident)*) => {
+        merkleize!([P: Preset] $ty {
+            genesis_time: leaf,
+            genesis_validators_root: leaf,
+            slot: leaf,
+            fork: leaf,
+            latest_block_header: container,
+            block_roots: persistent(P::EpochsPerHistoricalRoot::U64 * P::SlotsPerEpoch::U64, false),
+            state_roots: persistent(P::EpochsPerHistoricalRoot::U64 * P::SlotsPerEpoch::U64, false),
+            historical_roots: persistent(P::HistoricalRootsLimit::U64, true),
+            eth1_data: leaf,
+            eth1_data_votes: persistent(
+                P::EpochsPerEth1VotingPeriod::U64 * P::SlotsPerEpoch::U64,
+                true
+            ),
+            eth1_deposit_index: leaf,
+            validators: persistent(P::ValidatorRegistryLimit::U64, true),
+            balances: leaf,
+            randao_mixes: persistent(P::EpochsPerHistoricalVector::U64, false),
+            slashings: leaf,
+            previous_epoch_participation: leaf,
+            current_epoch_participation: leaf,
+            justification_bits: leaf,
+            previous_justified_checkpoint: container,
+            current_justified_checkpoint: container,
+            finalized_checkpoint: container,
+            inactivity_scores: leaf,
+            current_sync_committee: leaf,
+            next_sync_committee: leaf,
+            latest_execution_payload_header: container,
+            next_withdrawal_index: leaf,
+            next_withdrawal_validator_index: leaf,
+            historical_summaries: persistent(P::HistoricalRootsLimit::U64, true),
+            deposit_requests_start_index: leaf,
+            deposit_balance_to_consume: leaf,
+            exit_balance_to_consume: leaf,
+            earliest_exit_epoch: leaf,
+            consolidation_balance_to_consume: leaf,
+            earliest_consolidation_epoch: leaf,
+            pending_deposits: persistent(P::PendingDepositsLimit::U64, true),
+            pending_partial_withdrawals: persistent(P::PendingPartialWithdrawalsLimit::U64, true),
+            pending_consolidations: persistent(P::PendingConsolidationsLimit::U64, true),
+            $($extra: leaf,)*
+        });
+    };
+}
+
+merkleize_state!(ElectraBeaconState<P>);
+merkleize_state!(FuluBeaconState<P>, proposer_lookahead);
+
+/// The parent of two nodes of a Merkle tree.
+#[must_use]
+pub fn hash(left: &H256, right: &H256) -> H256 {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    H256::from_slice(&hasher.finalize())
+}
+
+/// Roots of empty subtrees, indexed by height, up to `depth`.
+#[must_use]
+pub fn zero_hashes(depth: usize) -> Vec<H256> {
+    let mut zeros = vec![H256::zero()];
+    for height in 0..depth {
+        zeros.push(hash(&zeros[height], &zeros[height]));
+    }
+    zeros
+}
+
+/// The layers of the tree over `leaves`, as deep as `zeros` is long, from the
+/// leaves up. Nodes right of the last leaf are zero and left out.
+fn layers(leaves: Vec<H256>, zeros: &[H256]) -> Vec<Vec<H256>> {
+    let mut layers = vec![leaves];
+
+    for height in 0..zeros.len() - 1 {
+        let next = layers[height]
+            .chunks(2)
+            .map(|pair| hash(&pair[0], pair.get(1).unwrap_or(&zeros[height])))
+            .collect();
+        layers.push(next);
+    }
+
+    layers
+}
+
+/// The node of a subtree at `(height, index)`, zero right of the leaves.
+fn node_at(layers: &[Vec<H256>], zeros: &[H256], height: usize, index: usize) -> H256 {
+    layers[height].get(index).copied().unwrap_or(zeros[height])
+}
+
+/// The remaining bits of a generalized index below the current node.
+struct Path {
+    bits: u64,
+    depth: u32,
+}
+
+impl Path {
+    /// Takes the next `count` bits, from the top of the tree down.
+    fn take(&mut self, count: u32) -> u64 {
+        self.depth -= count;
+        (self.bits >> self.depth) & ((1 << count) - 1)
+    }
+}
+
+/// Appends the siblings on the way down a subtree of `layers` to `branch`,
+/// from the top. Returns the index of the leaf reached, or `None` if the
+/// path ends inside the subtree.
+fn subtree_branch(
+    layers: &[Vec<H256>],
+    zeros: &[H256],
+    path: &mut Path,
+    branch: &mut Vec<H256>,
+) -> Option<usize> {
+    let depth = layers.len() - 1;
+    let steps = depth.min(path.depth as usize);
+    let index = usize::try_from(path.take(steps as u32)).expect("at most 64 bits");
+
+    for step in 1..=steps {
+        let node = index >> (steps - step);
+        branch.push(node_at(layers, zeros, depth - step, node ^ 1));
+    }
+
+    (steps == depth).then_some(index)
+}
+
+/// Appends the siblings on the way down from `node` along `path` to
+/// `branch`, from the top.
+fn descend(node: Node<'_>, path: &mut Path, branch: &mut Vec<H256>) -> Result<(), String> {
+    if path.depth == 0 {
+        return Ok(());
+    }
+
+    match node {
+        Node::Leaf(_) => Err(
+            "Generalized index goes below a leaf: a list of basic values, a bitfield or a \
+             list element"
+                .to_owned(),
+        ),
+        Node::Container(_, container) => {
+            let mut fields = container.fields();
+            let roots = fields.iter().map(|(_, node)| node.root()).collect();
+            let zeros = zero_hashes(depth_of(fields.len() as u64) as usize);
+            let layers = layers(roots, &zeros);
+
+            match subtree_branch(&layers, &zeros, path, branch) {
+                Some(index) if index < fields.len() => {
+                    descend(fields.swap_remove(index).1, path, branch)
+                }
+                Some(_) if path.depth > 0 => {
+                    Err("Generalized index goes below the padding of a container".to_owned())
+                }
+                _ => Ok(()),
+            }
+        }
+        Node::Elements {
+            depth,
+            is_list,
+            roots,
+            ..
+        } => {
+            let roots = roots();
+            let length = roots.len();
+            let zeros = zero_hashes(depth as usize);
+            let layers = layers(roots, &zeros);
+
+            if is_list {
+                if path.take(1) == 1 {
+                    branch.push(node_at(&layers, &zeros, depth as usize, 0));
+                    return if path.depth == 0 {
+                        Ok(())
+                    } else {
+                        Err("Generalized index goes below the length of a list".to_owned())
+                    };
+                }
+
+                let mut length_chunk = H256::zero();
+                length_chunk[..8].copy_from_slice(&(length as u64).to_le_bytes());
+                branch.push(length_chunk);
+            }
+
+            match subtree_branch(&layers, &zeros, path, branch) {
+                Some(_) if path.depth > 0 => Err(
+                    "Generalized index goes below a list element, whose fields proofs do not \
+                     descend into"
+                        .to_owned(),
+                ),
+                _ => Ok(()),
+            }
+        }
+    }
+}
+
+/// Returns the Merkle branch of the node at `generalized_index` of the tree
+/// of `value`, from the node's sibling up.
+///
+/// # Errors
+///
+/// Returns an error string if the index is 0 or goes below a node proofs do
+/// not descend into.
+pub fn prove(value: &dyn Merkleize, generalized_index: u64) -> Result<Vec<H256>, String> {
+    if generalized_index == 0 {
+        return Err("Generalized index must be at least 1".to_owned());
+    }
+
+    let mut path = Path {
+        bits: generalized_index,
+        depth: generalized_index.ilog2(),
+    };
+    let mut branch = Vec::with_capacity(path.depth as usize);
+
+    // The root of the top node is never needed.
+    descend(Node::Container(H256::zero(), value), &mut path, &mut branch)?;
+
+    branch.reverse();
+    Ok(branch)
+}
+
+/// Appends `depth` bits of `index` to a generalized index.
+fn extend(generalized_index: u64, depth: u32, index: u64) -> Result<u64, String> {
+    1_u64
+        .checked_shl(depth)
+        .and_then(|width| generalized_index.checked_mul(width))
+        .map(|extended| extended | index)
+        .ok_or_else(|| "Path is too deep for a 64-bit generalized index".to_owned())
+}
+
+/// Returns the generalized index of the node at `path` in the tree of
+/// `value`.
+///
+/// # Errors
+///
+/// Returns an error string if a field name is unknown, an index is out of
+/// range, or the path goes below a node proofs do not descend into.
+pub fn generalized_index(value: &dyn Merkleize, path: &[PathElement]) -> Result<u64, String> {
+    let mut generalized_index = 1;
+    let mut node = Node::Container(H256::zero(), value);
+
+    for element in path {
+        node = match (node, element) {
+            (Node::Container(_, container), PathElement::Name(name)) => {
+                let mut fields = container.fields();
+                let index = fields
+                    .iter()
+                    .position(|(field, _)| *field == name.as_str())
+                    .ok_or_else(|| {
+                        let names = fields.iter().map(|(field, _)| *field).collect::<Vec<_>>();
+                        format!("Unknown field '{name}', expected one of {names:?}")
+                    })?;
+                let depth = depth_of(fields.len() as u64);
+                generalized_index = extend(generalized_index, depth, index as u64)?;
+                fields.swap_remove(index).1
+            }
+            (Node::Elements { depth, is_list, .. }, PathElement::Index(index)) => {
+                if depth < u64::BITS && index >> depth != 0 {
+                    return Err(format!(
+                        "Index {index} is out of range for a capacity of {}",
+                        1_u64 << depth
+                    ));
+                }
+
+                if is_list {
+                    generalized_index = extend(generalized_index, 1, 0)?;
+                }
+
+                generalized_index = extend(generalized_index, depth, *index)?;
+                Node::Leaf(H256::zero())
+            }
+            (Node::Container(..), PathElement::Index(index)) => {
+                return Err(format!("Expected a field name, got index {index}"));
+            }
+            (Node::Elements { .. }, PathElement::Name(name)) => {
+                return Err(format!("Expected an element index, got '{name}'"));
+            }
+            (Node::Leaf(_), _) => {
+                return Err(
+                    "Path goes below a leaf: a list of basic values, a bitfield or a list \
+                     element"
+                        .to_owned(),
+                );
+            }
+        };
+    }
+
+    Ok(generalized_index)
+}
+
+/// The Merkle branch of the node at `generalized_index`, as `bytes`, with
+/// the GIL released while it is computed.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the index is 0 or goes below a node proofs do
+/// not descend into.
+pub fn prove_impl<'py>(
+    py: Python<'py>,
+    value: &(impl Merkleize + Sync),
+    generalized_index: u64,
+) -> PyResult<Vec<Bound<'py, PyBytes>>> {
+    let branch = py
+        .detach(|| prove(value, generalized_index))
+        .map_err(PyValueError::new_err)?;
+
+    Ok(branch
+        .iter()
+        .map(|node| PyBytes::new(py, node.as_bytes()))
+        .collect())
+}
+
+/// The Merkle branch of the node at `path`, as `bytes`.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the path is invalid.
+pub fn prove_path_impl<'py>(
+    py: Python<'py>,
+    value: &(impl Merkleize + Sync),
+    path: &[PathElement],
+) -> PyResult<Vec<Bound<'py, PyBytes>>> {
+    prove_impl(py, value, generalized_index_impl(value, path)?)
+}
+
+/// The generalized index of the node at `path`.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the path is invalid.
+pub fn generalized_index_impl(value: &impl Merkleize, path: &[PathElement]) -> PyResult<u64> {
+    generalized_index(value, path).map_err(PyValueError::new_err)
+}
+
+/// Verify a Merkle branch of `leaf` at `generalized_index` against `root`.
+///
+/// `branch` goes from the leaf's sibling up, as returned by `prove`. `leaf`,
+/// `root` and the branch nodes are 32-byte `bytes` or hex strings.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if a node is not 32 bytes, the generalized index is
+/// 0, or the branch length does not match its depth.
+#[pyfunction]
+pub fn verify_merkle_proof(
+    leaf: &Bound<'_, PyAny>,
+    branch: Vec<Bound<'_, PyAny>>,
+    generalized_index: u64,
+    root: &Bound<'_, PyAny>,
+) -> PyResult<bool> {
+    if generalized_index == 0 {
+        return Err(PyValueError::new_err(
+            "Generalized index must be at least 1",
+        ));
+    }
+
+    let depth = generalized_index.ilog2() as usize;
+
+    if branch.len() != depth {
+        return Err(PyValueError::new_err(format!(
+            "Expected a branch of {depth} nodes for generalized index {generalized_index}, got {}",
+            branch.len()
+        )));
+    }
+
+    let branch = branch
+        .iter()
+        .map(parse_root)
+        .collect::<PyResult<Vec<_>>>()?;
+
+    Ok(is_valid_merkle_branch(
+        parse_root(leaf)?,
+        &branch,
+        generalized_index,
+        parse_root(root)?,
+    ))
+}
+
+/// Checks a Merkle branch as `is_valid_merkle_branch` of the consensus specs
+/// does, with the depth and index taken from `generalized_index`.
+///
+/// The branch must have one node per level below the root, as checked by
+/// `verify_merkle_proof`.
+#[must_use]
+pub fn is_valid_merkle_branch<'a>(
+    leaf: H256,
+    branch: impl IntoIterator<Item = &'a H256>,
+    generalized_index: u64,
+    root: H256,
+) -> bool {
+    let node = branch
+        .into_iter()
+        .enumerate()
+        .fold(leaf, |node, (height, sibling)| {
+            if (generalized_index >> height) & 1 == 1 {
+                hash(sibling, &node)
+            } else {
+                hash(&node, sibling)
+            }
+        });
+
+    node == root
+}
+
+/// The root of a tree over `leaves`, padded with zero leaves to a power of
+/// two, as the root of a container is over the roots of its fields.
+#[must_use]
+pub fn merkle_root(leaves: Vec<H256>) -> H256 {
+    let depth = depth_of(leaves.len() as u64);
+    let zeros = zero_hashes(depth as usize);
+    node_at(&layers(leaves, &zeros), &zeros, depth as usize, 0)
+}
+
+/// Registers the Merkle proof functions with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(verify_merkle_proof, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! Fork and preset submodules.
+//!
+//! Classes are registered at the top level of the module with their fork and
+//! preset in the name, as `ElectraSignedBeaconBlockMainnet`. `register` also
+//! files each class under its fork and preset with both left out, as
+//! `grandine_py.electra.mainnet.SignedBeaconBlock`, and the classes of a fork
+//! that do not depend on the preset under the fork, as
+//! `grandine_py.electra.SingleAttestation`. Classes of no fork, such as
+//! `SignedBeaconBlockHeader`, are only at the top level.
+//!
+//! Submodules hold the same class objects as the top level, so objects
+//! pickle, compare and pass `isinstance` checks the same under either name,
+//! and they are added to `sys.modules`, so `import grandine_py.electra.mainnet`
+//! and `from grandine_py.electra.mainnet import ...` work.
+
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+
+/// The class name prefix of each fork, with the name of its submodule.
+const FORKS: &[(&str, &str)] = &[
+    ("Phase0", "phase0"),
+    ("Altair", "altair"),
+    ("Bellatrix", "bellatrix"),
+    ("Capella", "capella"),
+    ("Deneb", "deneb"),
+    ("Electra", "electra"),
+    ("Fulu", "fulu"),
+];
+
+/// The class name suffix of each preset, with the name of its submodule.
+const PRESETS: &[(&str, &str)] = &[
+    ("Mainnet", "mainnet"),
+    ("Minimal", "minimal"),
+    ("Gnosis", "gnosis"),
+];
+
+/// Creates a submodule `name` of `parent`, registered in `sys.modules`.
+fn submodule<'py>(parent: &Bound<'py, PyModule>, name: &str) -> PyResult<Bound<'py, PyModule>> {
+    let py = parent.py();
+    let qualified_name = format!("{}.{name}", parent.name()?);
+    let module = PyModule::new(py, &qualified_name)?;
+
+    py.import("sys")?
+        .getattr("modules")?
+        .set_item(&qualified_name, &module)?;
+    parent.add(name, &module)?;
+    Ok(module)
+}
+
+/// Adds the fork and preset submodules for the classes registered so far, so
+/// it must be called after every other `register`.
+///
+/// # Errors
+///
+/// Returns `PyErr` if a submodule cannot be created or registered.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let classes = m
+        .dict()
+        .iter()
+        .filter(|(_, value)| value.is_instance_of::<PyType>())
+        .map(|(name, value)| Ok((name.extract::<String>()?, value)))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    for (prefix, fork_name) in FORKS {
+        let fork = submodule(m, fork_name)?;
+        let presets = PRESETS
+            .iter()
+            .map(|(suffix, preset_name)| Ok((*suffix, submodule(&fork, preset_name)?)))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        for (name, class) in &classes {
+            let Some(name) = name.strip_prefix(prefix) else {
+                continue;
+            };
+
+            let preset = presets
+                .iter()
+                .find_map(|(suffix, preset)| Some((name.strip_suffix(suffix)?, preset)));
+
+            match preset {
+                Some((name, preset)) => preset.add(name, class)?,
+                None => fork.add(name, class)?,
+            }
+        }
+    }
+
+    Ok(())
+}
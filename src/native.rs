@@ -0,0 +1,586 @@
+//! Conversion of wrapped values into native Python objects.
+//!
+//! `to_dict` builds Python objects directly from the `Serialize`
+//! implementations of the Grandine types, with no JSON text or
+//! `serde_json::Value` in between, which makes it much cheaper than
+//! `json.loads(obj.to_json())` for large blocks and states. The types
+//! serialize in their Beacon API encoding, which the conversion maps back
+//! to native values:
+//!
+//! - containers become dicts and lists and vectors become lists;
+//! - decimal strings, the encoding of integers, become Python ints;
+//! - `0x`-prefixed hex strings, the encoding of byte and bit fields, become
+//!   `bytes` (bitlists keep their SSZ encoding, with the delimiting bit).
+//!
+//! Attribute access on the wrapper classes (`block.message`) converts a
+//! single top-level field: every other field is skipped without being
+//! converted. `repr` finds the slot of an object in the same way.
+
+use std::fmt::{self, Display};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyInt, PyList, PyString};
+use pyo3::IntoPyObjectExt as _;
+use serde::ser::{self, Impossible, Serialize};
+
+use crate::convert::is_decimal;
+
+/// An error raised while converting a value.
+#[derive(Debug)]
+pub enum Error {
+    Python(PyErr),
+    Custom(String),
+    /// The value is not a struct, so it has no fields.
+    NotAStruct,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Python(error) => Display::fmt(error, f),
+            Self::Custom(message) => f.write_str(message),
+            Self::NotAStruct => f.write_str("Value is not a container"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: Display>(message: T) -> Self {
+        Self::Custom(message.to_string())
+    }
+}
+
+impl From<PyErr> for Error {
+    fn from(error: PyErr) -> Self {
+        Self::Python(error)
+    }
+}
+
+impl From<Error> for PyErr {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::Python(error) => error,
+            error => PyValueError::new_err(error.to_string()),
+        }
+    }
+}
+
+/// Converts a string of the Beacon API encoding to a native value.
+fn convert_str<'py>(py: Python<'py>, string: &str) -> PyResult<Bound<'py, PyAny>> {
+    if is_decimal(string) {
+        return match string.parse::<u64>() {
+            Ok(integer) => Ok(integer.into_pyobject(py)?.into_any()),
+            // `uint256` values do not fit into a `u64`.
+            Err(_) => py.get_type::<PyInt>().call1((string,)),
+        };
+    }
+
+    if let Some(bytes) = string
+        .strip_prefix("0x")
+        .and_then(|digits| hex::decode(digits).ok())
+    {
+        return Ok(PyBytes::new(py, &bytes).into_any());
+    }
+
+    Ok(PyString::new(py, string).into_any())
+}
+
+/// Wraps a value in a single-entry dict keyed by an enum variant.
+fn variant<'py>(
+    py: Python<'py>,
+    name: &'static str,
+    value: Bound<'py, PyAny>,
+) -> Result<Bound<'py, PyAny>, Error> {
+    let dict = PyDict::new(py);
+    dict.set_item(name, value)?;
+    Ok(dict.into_any())
+}
+
+/// A serializer producing native Python objects.
+#[derive(Clone, Copy)]
+struct Serializer<'py> {
+    py: Python<'py>,
+}
+
+impl<'py> Serializer<'py> {
+    fn native(self, value: impl IntoPyObject<'py>) -> Result<Bound<'py, PyAny>, Error> {
+        Ok(value.into_bound_py_any(self.py)?)
+    }
+}
+
+/// Collects the elements of a sequence, optionally as an enum variant.
+struct Seq<'py> {
+    py: Python<'py>,
+    items: Vec<Bound<'py, PyAny>>,
+    variant: Option<&'static str>,
+}
+
+impl<'py> Seq<'py> {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.items
+            .push(value.serialize(Serializer { py: self.py })?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Bound<'py, PyAny>, Error> {
+        let list = PyList::new(self.py, self.items)?.into_any();
+        match self.variant {
+            Some(name) => variant(self.py, name, list),
+            None => Ok(list),
+        }
+    }
+}
+
+/// Collects the entries of a map or the fields of a struct, optionally as an
+/// enum variant.
+struct Map<'py> {
+    py: Python<'py>,
+    dict: Bound<'py, PyDict>,
+    key: Option<Bound<'py, PyAny>>,
+    variant: Option<&'static str>,
+}
+
+impl<'py> Map<'py> {
+    fn new(py: Python<'py>, variant: Option<&'static str>) -> Self {
+        Self {
+            py,
+            dict: PyDict::new(py),
+            key: None,
+            variant,
+        }
+    }
+
+    fn field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Error> {
+        let value = value.serialize(Serializer { py: self.py })?;
+        self.dict.set_item(key, value)?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Bound<'py, PyAny>, Error> {
+        match self.variant {
+            Some(name) => variant(self.py, name, self.dict.into_any()),
+            None => Ok(self.dict.into_any()),
+        }
+    }
+}
+
+macro_rules! impl_seq {
+    ($($trait:ident :: $method:ident),* $(,)?) => {
+        $(
+            impl<'py> ser::$trait for Seq<'py> {
+                type Ok = Bound<'py, PyAny>;
+                type Error = Error;
+
+                fn $method<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+                    self.push(value)
+                }
+
+                fn end(self) -> Result<Self::Ok, Error> {
+                    self.finish()
+                }
+            }
+        )*
+    };
+}
+
+impl_seq!(
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field,
+);
+
+macro_rules! impl_struct {
+    ($($trait:ident),* $(,)?) => {
+        $(
+            impl<'py> ser::$trait for Map<'py> {
+                type Ok = Bound<'py, PyAny>;
+                type Error = Error;
+
+                fn serialize_field<T: Serialize + ?Sized>(
+                    &mut self,
+                    key: &'static str,
+                    value: &T,
+                ) -> Result<(), Error> {
+                    self.field(key, value)
+                }
+
+                fn end(self) -> Result<Self::Ok, Error> {
+                    self.finish()
+                }
+            }
+        )*
+    };
+}
+
+impl_struct!(SerializeStruct, SerializeStructVariant);
+
+impl<'py> ser::SerializeMap for Map<'py> {
+    type Ok = Bound<'py, PyAny>;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(key.serialize(Serializer { py: self.py })?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error::Custom("Map value without a key".to_owned()))?;
+        let value = value.serialize(Serializer { py: self.py })?;
+        self.dict.set_item(key, value)?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+macro_rules! serialize_natives {
+    ($($method:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method(self, value: $ty) -> Result<Self::Ok, Error> {
+                self.native(value)
+            }
+        )*
+    };
+}
+
+impl<'py> ser::Serializer for Serializer<'py> {
+    type Ok = Bound<'py, PyAny>;
+    type Error = Error;
+    type SerializeSeq = Seq<'py>;
+    type SerializeTuple = Seq<'py>;
+    type SerializeTupleStruct = Seq<'py>;
+    type SerializeTupleVariant = Seq<'py>;
+    type SerializeMap = Map<'py>;
+    type SerializeStruct = Map<'py>;
+    type SerializeStructVariant = Map<'py>;
+
+    serialize_natives!(
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_f32: f32,
+        serialize_f64: f64,
+    );
+
+    fn serialize_char(self, value: char) -> Result<Self::Ok, Error> {
+        self.serialize_str(value.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Self::Ok, Error> {
+        Ok(convert_str(self.py, value)?)
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Self::Ok, Error> {
+        Ok(PyBytes::new(self.py, value).into_any())
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Error> {
+        Ok(self.py.None().into_bound(self.py))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Error> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Error> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Error> {
+        Ok(PyString::new(self.py, variant).into_any())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        variant(self.py, name, value.serialize(self)?)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Ok(Seq {
+            py: self.py,
+            items: Vec::with_capacity(len.unwrap_or_default()),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Ok(Seq {
+            variant: Some(name),
+            ..self.serialize_seq(Some(len))?
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Ok(Map::new(self.py, None))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Ok(Map::new(self.py, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Ok(Map::new(self.py, Some(name)))
+    }
+}
+
+/// A serializer converting only the field at `path` of nested structs.
+struct FieldSerializer<'py, 'name> {
+    py: Python<'py>,
+    path: &'name [&'name str],
+}
+
+/// Converts the field matching the first name of `path` and skips the
+/// others.
+struct Field<'py, 'name> {
+    py: Python<'py>,
+    path: &'name [&'name str],
+    value: Option<Bound<'py, PyAny>>,
+}
+
+impl<'py> ser::SerializeStruct for Field<'py, '_> {
+    type Ok = Option<Bound<'py, PyAny>>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        let py = self.py;
+
+        self.value = match self.path {
+            [name] if key == *name => Some(value.serialize(Serializer { py })?),
+            [name, rest @ ..] if key == *name => {
+                match value.serialize(FieldSerializer { py, path: rest }) {
+                    Ok(field) => field,
+                    Err(Error::NotAStruct) => None,
+                    Err(error) => return Err(error),
+                }
+            }
+            _ => return Ok(()),
+        };
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.value)
+    }
+}
+
+macro_rules! not_a_struct {
+    ($($method:ident($($arg:ty),*) -> $ok:ty),* $(,)?) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<$ok, Error> {
+                Err(Error::NotAStruct)
+            }
+        )*
+    };
+}
+
+impl<'py, 'name> ser::Serializer for FieldSerializer<'py, 'name> {
+    type Ok = Option<Bound<'py, PyAny>>;
+    type Error = Error;
+    type SerializeSeq = Impossible<Self::Ok, Error>;
+    type SerializeTuple = Impossible<Self::Ok, Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Error>;
+    type SerializeMap = Impossible<Self::Ok, Error>;
+    type SerializeStruct = Field<'py, 'name>;
+    type SerializeStructVariant = Impossible<Self::Ok, Error>;
+
+    not_a_struct!(
+        serialize_bool(bool) -> Self::Ok,
+        serialize_i8(i8) -> Self::Ok,
+        serialize_i16(i16) -> Self::Ok,
+        serialize_i32(i32) -> Self::Ok,
+        serialize_i64(i64) -> Self::Ok,
+        serialize_u8(u8) -> Self::Ok,
+        serialize_u16(u16) -> Self::Ok,
+        serialize_u32(u32) -> Self::Ok,
+        serialize_u64(u64) -> Self::Ok,
+        serialize_f32(f32) -> Self::Ok,
+        serialize_f64(f64) -> Self::Ok,
+        serialize_char(char) -> Self::Ok,
+        serialize_str(&str) -> Self::Ok,
+        serialize_bytes(&[u8]) -> Self::Ok,
+        serialize_none() -> Self::Ok,
+        serialize_unit() -> Self::Ok,
+        serialize_unit_struct(&'static str) -> Self::Ok,
+        serialize_unit_variant(&'static str, u32, &'static str) -> Self::Ok,
+        serialize_seq(Option<usize>) -> Self::SerializeSeq,
+        serialize_tuple(usize) -> Self::SerializeTuple,
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct,
+        serialize_tuple_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeTupleVariant,
+        serialize_map(Option<usize>) -> Self::SerializeMap,
+        serialize_struct_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeStructVariant,
+    );
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Ok(Field {
+            py: self.py,
+            path: self.path,
+            value: None,
+        })
+    }
+}
+
+/// Converts a value into native Python objects; see the module docs.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the value cannot be serialized.
+pub fn to_native<'py, T: Serialize>(py: Python<'py>, value: &T) -> PyResult<Bound<'py, PyAny>> {
+    Ok(value.serialize(Serializer { py })?)
+}
+
+/// Converts the top-level field `name` of a value into native Python
+/// objects, or returns `None` if the value has no such field.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the field cannot be serialized.
+pub fn field_to_native<'py, T: Serialize>(
+    py: Python<'py>,
+    value: &T,
+    name: &str,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    field_path_to_native(py, value, &[name])
+}
+
+/// Converts the field at `path` of nested containers into native Python
+/// objects, or returns `None` if there is no such field.
+fn field_path_to_native<'py, T: Serialize>(
+    py: Python<'py>,
+    value: &T,
+    path: &[&str],
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    match value.serialize(FieldSerializer { py, path }) {
+        Ok(field) => Ok(field),
+        Err(Error::NotAStruct) => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Where objects keep their slot: states and headers at the top level, and
+/// other objects in the message, attestation data, block or header they
+/// wrap.
+const SLOT_PATHS: &[&[&str]] = &[
+    &["slot"],
+    &["message", "slot"],
+    &["data", "slot"],
+    &["block", "slot"],
+    &["signed_block", "message", "slot"],
+    &["signed_block_header", "message", "slot"],
+];
+
+/// Returns the slot of a value as a Python int, or `None` if it has none.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the slot cannot be serialized.
+pub fn slot_to_native<'py, T: Serialize>(
+    py: Python<'py>,
+    value: &T,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    for path in SLOT_PATHS {
+        if let Some(slot) = field_path_to_native(py, value, path)? {
+            return Ok(Some(slot));
+        }
+    }
+    Ok(None)
+}
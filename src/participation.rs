@@ -0,0 +1,224 @@
+//! Attestation correctness classification for reward analysis.
+//!
+//! An attestation votes for a source checkpoint, a target checkpoint, and a
+//! head block. Given the canonical chain, each vote is either correct or not,
+//! and together with the inclusion delay this determines which Altair
+//! participation flags the attestation earns.
+//!
+//! The canonical chain is supplied by a *chain provider*: either a dict mapping
+//! slots to block roots, or a callable taking a slot and returning a block
+//! root. Roots follow `get_block_root_at_slot` semantics, i.e. an empty slot
+//! maps to the root of the latest block before it. A missing dict entry or a
+//! `None` return means the root is unknown, and votes depending on it are
+//! classified as incorrect.
+
+use std::collections::HashMap;
+
+use grandine_ssz::H256;
+use grandine_types::phase0::containers::AttestationData;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::aggregation::{attestation_data, attestation_preset};
+use crate::config::PyConfig;
+use crate::convert::parse_root;
+use crate::presets::PresetKind;
+
+/// `MIN_ATTESTATION_INCLUSION_DELAY`, which is the same in all presets.
+const MIN_ATTESTATION_INCLUSION_DELAY: u64 = 1;
+
+/// Looks up canonical block roots, remembering each slot's answer.
+struct ChainProvider<'a, 'py> {
+    provider: &'a Bound<'py, PyAny>,
+    roots: HashMap<u64, Option<H256>>,
+}
+
+impl<'a, 'py> ChainProvider<'a, 'py> {
+    fn new(provider: &'a Bound<'py, PyAny>) -> PyResult<Self> {
+        if !provider.is_instance_of::<PyDict>() && !provider.is_callable() {
+            return Err(PyTypeError::new_err(
+                "chain_provider must be a dict or a callable",
+            ));
+        }
+
+        Ok(Self {
+            provider,
+            roots: HashMap::new(),
+        })
+    }
+
+    fn block_root(&mut self, slot: u64) -> PyResult<Option<H256>> {
+        if let Some(root) = self.roots.get(&slot) {
+            return Ok(*root);
+        }
+
+        let value = if let Ok(dict) = self.provider.cast::<PyDict>() {
+            dict.get_item(slot)?
+        } else {
+            Some(self.provider.call1((slot,))?)
+        };
+
+        let root = value
+            .filter(|value| !value.is_none())
+            .map(|value| parse_root(&value))
+            .transpose()?;
+
+        self.roots.insert(slot, root);
+        Ok(root)
+    }
+}
+
+/// Correctness and timeliness of an attestation's votes.
+struct Classification {
+    source: bool,
+    target: bool,
+    head: bool,
+    inclusion_delay: Option<u64>,
+}
+
+impl Classification {
+    fn compute(
+        data: &AttestationData,
+        slots_per_epoch: u64,
+        inclusion_slot: Option<u64>,
+        chain: &mut ChainProvider<'_, '_>,
+    ) -> PyResult<Self> {
+        let epoch_start = |epoch: u64| epoch.saturating_mul(slots_per_epoch);
+
+        let source = chain.block_root(epoch_start(data.source.epoch))? == Some(data.source.root);
+        let target =
+            source && chain.block_root(epoch_start(data.target.epoch))? == Some(data.target.root);
+        let head = target && chain.block_root(data.slot)? == Some(data.beacon_block_root);
+
+        Ok(Self {
+            source,
+            target,
+            head,
+            inclusion_delay: inclusion_slot.map(|slot| slot.saturating_sub(data.slot)),
+        })
+    }
+
+    /// Builds the result dict. Timeliness flags follow the Deneb rules
+    /// (EIP-7045: no inclusion deadline for target votes) and are `None`
+    /// when the inclusion slot is unknown.
+    fn to_dict<'py>(&self, py: Python<'py>, slots_per_epoch: u64) -> PyResult<Bound<'py, PyDict>> {
+        let timely = |correct: bool, max_delay: u64| {
+            self.inclusion_delay.map(|delay| {
+                correct && (MIN_ATTESTATION_INCLUSION_DELAY..=max_delay).contains(&delay)
+            })
+        };
+
+        let result = PyDict::new(py);
+        result.set_item("source", self.source)?;
+        result.set_item("target", self.target)?;
+        result.set_item("head", self.head)?;
+        result.set_item("inclusion_delay", self.inclusion_delay)?;
+        result.set_item(
+            "timely_source",
+            timely(self.source, integer_sqrt(slots_per_epoch)),
+        )?;
+        result.set_item("timely_target", timely(self.target, u64::MAX))?;
+        result.set_item(
+            "timely_head",
+            timely(self.head, MIN_ATTESTATION_INCLUSION_DELAY),
+        )?;
+        Ok(result)
+    }
+}
+
+/// `integer_squareroot` from the consensus specs.
+fn integer_sqrt(n: u64) -> u64 {
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+fn slots_per_epoch(attestation: &Bound<'_, PyAny>, config: Option<&PyConfig>) -> u64 {
+    config
+        .map(|config| config.inner.preset)
+        .or_else(|| attestation_preset(attestation))
+        .unwrap_or(PresetKind::Mainnet)
+        .slots_per_epoch()
+}
+
+/// Classify an attestation's source, target and head votes against the
+/// canonical chain.
+///
+/// Returns a dict with `source`, `target` and `head` correctness flags. As in
+/// the specs, a correct target requires a correct source and a correct head
+/// requires a correct target. When `inclusion_slot` is given, the dict also
+/// contains the `inclusion_delay` and the `timely_source`, `timely_target`
+/// and `timely_head` participation flags; otherwise those are `None`.
+///
+/// The preset (for `SLOTS_PER_EPOCH`) is taken from `config`, then from the
+/// attestation's class, and defaults to mainnet.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if the attestation or chain provider has the wrong
+/// type, `PyValueError` if the provider returns an invalid root, or any error
+/// raised by the provider.
+#[pyfunction]
+#[pyo3(signature = (attestation, chain_provider, inclusion_slot = None, config = None))]
+pub fn classify_attestation<'py>(
+    py: Python<'py>,
+    attestation: &Bound<'py, PyAny>,
+    chain_provider: &Bound<'py, PyAny>,
+    inclusion_slot: Option<u64>,
+    config: Option<PyRef<'py, PyConfig>>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let data = attestation_data(attestation)?;
+    let slots_per_epoch = slots_per_epoch(attestation, config.as_deref());
+    let mut chain = ChainProvider::new(chain_provider)?;
+
+    Classification::compute(&data, slots_per_epoch, inclusion_slot, &mut chain)?
+        .to_dict(py, slots_per_epoch)
+}
+
+/// Classify many attestations against the same chain provider.
+///
+/// Equivalent to calling `classify_attestation` on each attestation, but the
+/// provider is queried at most once per slot.
+///
+/// # Errors
+///
+/// Same as `classify_attestation`.
+#[pyfunction]
+#[pyo3(signature = (attestations, chain_provider, inclusion_slot = None, config = None))]
+pub fn classify_attestations<'py>(
+    py: Python<'py>,
+    attestations: &Bound<'py, PyAny>,
+    chain_provider: &Bound<'py, PyAny>,
+    inclusion_slot: Option<u64>,
+    config: Option<PyRef<'py, PyConfig>>,
+) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    let mut chain = ChainProvider::new(chain_provider)?;
+
+    attestations
+        .try_iter()?
+        .map(|attestation| {
+            let attestation = attestation?;
+            let data = attestation_data(&attestation)?;
+            let slots_per_epoch = slots_per_epoch(&attestation, config.as_deref());
+
+            Classification::compute(&data, slots_per_epoch, inclusion_slot, &mut chain)?
+                .to_dict(py, slots_per_epoch)
+        })
+        .collect()
+}
+
+/// Registers the participation helpers with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(classify_attestation, m)?)?;
+    m.add_function(wrap_pyfunction!(classify_attestations, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,115 @@
+//! Phase 0 attestation types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `Attestation` (Mainnet, Minimal, Gnosis), the layout used from Phase 0
+//!   through Deneb
+//!
+//! Each type supports SSZ and JSON serialization via `from_ssz`, `to_ssz`,
+//! `from_json`, and `to_json` methods, and conversion to the Electra layout
+//! via `to_electra`.
+
+use paste::paste;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::electra::attestation::{
+    attestation_from_phase0, PyAttestationGnosis, PyAttestationMainnet, PyAttestationMinimal,
+};
+use crate::Gnosis;
+use grandine_types::phase0::containers::Attestation;
+use grandine_types::preset::{Mainnet, Minimal};
+
+use crate::list::PySszList;
+use crate::{define_ssz_pyclass_for_preset, ssz_list_view};
+
+paste! {
+    define_ssz_pyclass_for_preset!(
+        [<PyPhase0AttestationMainnet>],
+        "Phase0AttestationMainnet",
+        Attestation<Mainnet>,
+        extra_methods = {
+            pub fn aggregation_bits(slf: &Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.aggregation_bits, bool)
+            }
+
+            /// Convert to the Electra layout.
+            ///
+            /// The committee index moves from `data.index` into
+            /// `committee_bits` and `data.index` becomes 0. The aggregation
+            /// bits and signature are unchanged.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `data.index` is not a valid committee
+            /// index for the preset.
+            pub fn to_electra(&self) -> PyResult<PyAttestationMainnet> {
+                attestation_from_phase0(&self.inner)
+                    .map(|inner| PyAttestationMainnet { inner })
+                    .map_err(PyValueError::new_err)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyPhase0AttestationMinimal>],
+        "Phase0AttestationMinimal",
+        Attestation<Minimal>,
+        extra_methods = {
+            pub fn aggregation_bits(slf: &Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.aggregation_bits, bool)
+            }
+
+            /// Convert to the Electra layout.
+            ///
+            /// The committee index moves from `data.index` into
+            /// `committee_bits` and `data.index` becomes 0. The aggregation
+            /// bits and signature are unchanged.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `data.index` is not a valid committee
+            /// index for the preset.
+            pub fn to_electra(&self) -> PyResult<PyAttestationMinimal> {
+                attestation_from_phase0(&self.inner)
+                    .map(|inner| PyAttestationMinimal { inner })
+                    .map_err(PyValueError::new_err)
+            }
+        }
+    );
+
+    define_ssz_pyclass_for_preset!(
+        [<PyPhase0AttestationGnosis>],
+        "Phase0AttestationGnosis",
+        Attestation<Gnosis>,
+        extra_methods = {
+            pub fn aggregation_bits(slf: &Bound<'_, Self>) -> PySszList {
+                ssz_list_view!(slf, |inner| inner.aggregation_bits, bool)
+            }
+
+            /// Convert to the Electra layout.
+            ///
+            /// The committee index moves from `data.index` into
+            /// `committee_bits` and `data.index` becomes 0. The aggregation
+            /// bits and signature are unchanged.
+            ///
+            /// # Errors
+            /// Returns `PyValueError` if `data.index` is not a valid committee
+            /// index for the preset.
+            pub fn to_electra(&self) -> PyResult<PyAttestationGnosis> {
+                attestation_from_phase0(&self.inner)
+                    .map(|inner| PyAttestationGnosis { inner })
+                    .map_err(PyValueError::new_err)
+            }
+        }
+    );
+}
+
+/// Registers all Phase 0 attestation types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyPhase0AttestationMainnet>()?;
+    m.add_class::<PyPhase0AttestationMinimal>()?;
+    m.add_class::<PyPhase0AttestationGnosis>()?;
+    Ok(())
+}
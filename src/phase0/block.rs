@@ -0,0 +1,173 @@
+//! Phase 0 block types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `SignedBeaconBlock` (Mainnet, Minimal, Gnosis)
+//!
+//! It also defines `define_signed_block_pyclass!`, which the block classes of
+//! every fork from Phase 0 through Deneb are built with, so historical blocks
+//! decode into classes with the same API whatever their fork. Besides SSZ and
+//! JSON serialization, they provide a typed `slot` getter, `signing_root`,
+//! `verify` and `signed_header`, and lazy `SszList` views of the operations
+//! every fork has (`attestations`, `proposer_slashings`, `deposits` and
+//! `voluntary_exits`). The attestations have the Phase 0 layout, which was
+//! kept until Electra.
+
+use grandine_types::phase0::containers::SignedBeaconBlock;
+use grandine_types::preset::{Mainnet, Minimal};
+use pyo3::prelude::*;
+
+use crate::phase0::attestation::{
+    PyPhase0AttestationGnosis, PyPhase0AttestationMainnet, PyPhase0AttestationMinimal,
+};
+use crate::Gnosis;
+
+/// Defines a Python class for the signed block or signed blinded block of a
+/// fork before Electra with the methods shared by all of them.
+///
+/// `attestation` is the Phase 0 attestation class of the preset, and
+/// `extra_methods` adds the methods of the fork's own body fields.
+#[macro_export]
+macro_rules! define_signed_block_pyclass {
+    (
+        $rust_struct:ident,
+        $py_name:literal,
+        $rust_ty:ty,
+        preset = $preset:ty,
+        attestation = $attestation:ident
+        $(, extra_methods = { $($extra:tt)* } )?
+    ) => {
+        $crate::define_ssz_pyclass_for_preset!(
+            $rust_struct,
+            $py_name,
+            $rust_ty,
+            extra_methods = {
+                #[pyo3(signature = (config = None))]
+                pub fn slot(
+                    &self,
+                    config: Option<pyo3::PyRef<'_, $crate::config::PyConfig>>,
+                ) -> pyo3::PyResult<$crate::primitives::PySlot> {
+                    $crate::electra::block::typed_slot::<$preset>(self.inner.message.slot, config)
+                }
+
+                #[pyo3(signature = (domain, fork_version = None, /))]
+                /// Signing root of the block for the given `DOMAIN_BEACON_PROPOSER`
+                /// domain, or with `fork_version` for the domain computed from it
+                /// and the genesis validators root passed in place of the domain.
+                ///
+                /// # Errors
+                /// Returns `PyValueError` if `domain` is not 32 bytes or
+                /// `fork_version` is not 4 bytes.
+                pub fn signing_root(
+                    &self,
+                    py: pyo3::Python<'_>,
+                    domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                    fork_version: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+                ) -> pyo3::PyResult<String> {
+                    let domain = $crate::domains::proposer_domain(domain, fork_version)?;
+                    Ok($crate::signing::object_signing_root(py, &self.inner.message, domain))
+                }
+
+                /// Verify the signature against the proposer's `pubkey` and the
+                /// `DOMAIN_BEACON_PROPOSER` domain of the block's epoch.
+                ///
+                /// # Errors
+                /// Returns a `BlsEncodingError` subclass if `pubkey` is not a
+                /// valid pubkey, or `PyValueError` if `domain` is not 32 bytes.
+                pub fn verify(
+                    &self,
+                    py: pyo3::Python<'_>,
+                    pubkey: &pyo3::Bound<'_, pyo3::PyAny>,
+                    domain: &pyo3::Bound<'_, pyo3::PyAny>,
+                ) -> pyo3::PyResult<bool> {
+                    $crate::signing::verify_object(
+                        py,
+                        &self.inner.message,
+                        self.inner.signature,
+                        pubkey,
+                        domain,
+                    )
+                }
+
+                /// The signed header of the block, with the body replaced by its
+                /// root.
+                pub fn signed_header(
+                    &self,
+                    py: pyo3::Python<'_>,
+                ) -> $crate::phase0::header::PySignedBeaconBlockHeader {
+                    let message = &self.inner.message;
+                    let body_root = py.detach(|| grandine_ssz::SszHash::hash_tree_root(&message.body));
+
+                    $crate::phase0::header::PySignedBeaconBlockHeader {
+                        inner: grandine_types::phase0::containers::SignedBeaconBlockHeader {
+                            message: grandine_types::phase0::containers::BeaconBlockHeader {
+                                slot: message.slot,
+                                proposer_index: message.proposer_index,
+                                parent_root: message.parent_root,
+                                state_root: message.state_root,
+                                body_root,
+                            },
+                            signature: self.inner.signature,
+                        },
+                    }
+                }
+
+                pub fn attestations(slf: &pyo3::Bound<'_, Self>) -> $crate::list::PySszList {
+                    $crate::ssz_list_view!(slf, |inner| inner.message.body.attestations, class = $attestation)
+                }
+
+                pub fn proposer_slashings(slf: &pyo3::Bound<'_, Self>) -> $crate::list::PySszList {
+                    use $crate::phase0::header::PyProposerSlashing;
+                    $crate::ssz_list_view!(slf, |inner| inner.message.body.proposer_slashings, class = PyProposerSlashing)
+                }
+
+                pub fn deposits(slf: &pyo3::Bound<'_, Self>) -> $crate::list::PySszList {
+                    use $crate::phase0::deposit::PyDeposit;
+                    $crate::ssz_list_view!(slf, |inner| inner.message.body.deposits, class = PyDeposit)
+                }
+
+                pub fn voluntary_exits(slf: &pyo3::Bound<'_, Self>) -> $crate::list::PySszList {
+                    use $crate::phase0::exit::PySignedVoluntaryExit;
+                    $crate::ssz_list_view!(slf, |inner| inner.message.body.voluntary_exits, class = PySignedVoluntaryExit)
+                }
+
+                $($($extra)*)?
+            }
+        );
+    };
+}
+
+define_signed_block_pyclass!(
+    PyPhase0SignedBeaconBlockMainnet,
+    "Phase0SignedBeaconBlockMainnet",
+    SignedBeaconBlock<Mainnet>,
+    preset = Mainnet,
+    attestation = PyPhase0AttestationMainnet
+);
+
+define_signed_block_pyclass!(
+    PyPhase0SignedBeaconBlockMinimal,
+    "Phase0SignedBeaconBlockMinimal",
+    SignedBeaconBlock<Minimal>,
+    preset = Minimal,
+    attestation = PyPhase0AttestationMinimal
+);
+
+define_signed_block_pyclass!(
+    PyPhase0SignedBeaconBlockGnosis,
+    "Phase0SignedBeaconBlockGnosis",
+    SignedBeaconBlock<Gnosis>,
+    preset = Gnosis,
+    attestation = PyPhase0AttestationGnosis
+);
+
+/// Registers the Phase 0 block types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyPhase0SignedBeaconBlockMainnet>()?;
+    m.add_class::<PyPhase0SignedBeaconBlockMinimal>()?;
+    m.add_class::<PyPhase0SignedBeaconBlockGnosis>()?;
+    Ok(())
+}
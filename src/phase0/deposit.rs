@@ -0,0 +1,179 @@
+//! Deposit data types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `DepositData` (preset-independent), the deposit message and signature
+//!   sent to the deposit contract
+//! - `Deposit` (preset-independent), deposit data with its Merkle proof
+//!   against the deposit root, as included in blocks
+//!
+//! and `parse_deposit_log`, which decodes the data of a `DepositEvent` log
+//! emitted by the deposit contract:
+//!
+//! ```text
+//! event DepositEvent(bytes pubkey, bytes withdrawal_credentials, bytes amount,
+//!                    bytes signature, bytes index)
+//! ```
+//!
+//! The event data is ABI-encoded: one 32-byte big-endian offset per field,
+//! followed by each field as a 32-byte big-endian length and the bytes padded
+//! with zeros to a multiple of 32 bytes. `amount` and `index` are 8-byte
+//! little-endian integers.
+
+use grandine_bls::{PublicKeyBytes, SignatureBytes};
+use grandine_ssz::{SszHash as _, SszReadDefault as _, H256};
+use grandine_types::phase0::containers::{Deposit, DepositData};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::convert::format_hash_tree_root;
+use crate::define_ssz_pyclass_for_preset;
+
+const WORD: usize = 32;
+
+/// Names and lengths of the `DepositEvent` fields, in order.
+const FIELDS: [(&str, usize); 5] = [
+    ("pubkey", 48),
+    ("withdrawal_credentials", 32),
+    ("amount", 8),
+    ("signature", 96),
+    ("index", 8),
+];
+
+/// Reads the ABI word at `position` as an offset or length.
+fn read_word(data: &[u8], position: usize, name: &str) -> Result<usize, String> {
+    let word = position
+        .checked_add(WORD)
+        .and_then(|end| data.get(position..end))
+        .ok_or_else(|| format!("Truncated {name} header"))?;
+
+    let (high, low) = word.split_at(WORD - 8);
+
+    if high.iter().any(|byte| *byte != 0) {
+        return Err(format!("Invalid {name} offset or length"));
+    }
+
+    usize::try_from(u64::from_be_bytes(low.try_into().expect("8 bytes")))
+        .map_err(|_| format!("Invalid {name} offset or length"))
+}
+
+/// Reads the ABI-encoded `bytes` field at `offset`, checking its length and
+/// padding.
+fn read_field<'a>(
+    data: &'a [u8],
+    offset: usize,
+    name: &str,
+    length: usize,
+) -> Result<&'a [u8], String> {
+    let actual = read_word(data, offset, name)?;
+
+    if actual != length {
+        return Err(format!("{name} must be {length} bytes, got {actual}"));
+    }
+
+    let start = offset + WORD;
+    let padded = data
+        .get(start..start + length.div_ceil(WORD) * WORD)
+        .ok_or_else(|| format!("Truncated {name}"))?;
+    let (field, padding) = padded.split_at(length);
+
+    if padding.iter().any(|byte| *byte != 0) {
+        return Err(format!("Non-zero padding after {name}"));
+    }
+
+    Ok(field)
+}
+
+/// Decodes the data of a `DepositEvent` log into the deposit data and the
+/// deposit index.
+///
+/// # Errors
+///
+/// Returns an error string if an offset points outside the data, a field has
+/// the wrong length, or the padding of a field is not zero.
+pub fn decode_deposit_log(data: &[u8]) -> Result<(DepositData, u64), String> {
+    let mut fields = [&[][..]; FIELDS.len()];
+
+    for (index, (name, length)) in FIELDS.into_iter().enumerate() {
+        let offset = read_word(data, index * WORD, name)?;
+        fields[index] = read_field(data, offset, name, length)?;
+    }
+
+    let [pubkey, withdrawal_credentials, amount, signature, index] = fields;
+
+    let deposit_data = DepositData {
+        pubkey: PublicKeyBytes::from_ssz_default(pubkey).map_err(|e| e.to_string())?,
+        withdrawal_credentials: H256::from_slice(withdrawal_credentials),
+        amount: u64::from_le_bytes(amount.try_into().expect("8 bytes")),
+        signature: SignatureBytes::from_ssz_default(signature).map_err(|e| e.to_string())?,
+    };
+
+    Ok((
+        deposit_data,
+        u64::from_le_bytes(index.try_into().expect("8 bytes")),
+    ))
+}
+
+define_ssz_pyclass_for_preset!(
+    PyDepositData,
+    "DepositData",
+    DepositData,
+    extra_methods = {
+        /// Deposit amount in Gwei.
+        pub fn amount(&self) -> u64 {
+            self.inner.amount
+        }
+
+        /// Hash tree root of the deposit data, as checked by the deposit
+        /// contract.
+        pub fn hash_tree_root(&self) -> String {
+            format_hash_tree_root(&self.inner.hash_tree_root())
+        }
+    }
+);
+
+define_ssz_pyclass_for_preset!(
+    PyDeposit,
+    "Deposit",
+    Deposit,
+    extra_methods = {
+        pub fn data(&self) -> PyDepositData {
+            PyDepositData {
+                inner: self.inner.data,
+            }
+        }
+
+        /// Merkle branch of the deposit data in the deposit tree, with the
+        /// deposit count as the last node.
+        pub fn proof(&self) -> Vec<String> {
+            self.inner.proof.iter().map(format_hash_tree_root).collect()
+        }
+
+        pub fn hash_tree_root(&self) -> String {
+            format_hash_tree_root(&self.inner.hash_tree_root())
+        }
+    }
+);
+
+/// Decode the data of a deposit contract `DepositEvent` log into
+/// `(DepositData, index)`.
+///
+/// # Errors
+/// Returns `PyValueError` if the data is not a valid `DepositEvent` encoding.
+#[pyfunction]
+pub fn parse_deposit_log(py: Python<'_>, data: &[u8]) -> PyResult<(PyDepositData, u64)> {
+    py.detach(|| decode_deposit_log(data))
+        .map(|(inner, index)| (PyDepositData { inner }, index))
+        .map_err(PyValueError::new_err)
+}
+
+/// Registers the deposit types and log parser with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyDepositData>()?;
+    m.add_class::<PyDeposit>()?;
+    m.add_function(wrap_pyfunction!(parse_deposit_log, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! Voluntary exit types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `VoluntaryExit` (preset-independent), a validator's request to exit
+//! - `SignedVoluntaryExit` (preset-independent), as published on the
+//!   `voluntary_exit` gossip topic and included in blocks
+//!
+//! Exits are signed with the `DOMAIN_VOLUNTARY_EXIT` domain, see
+//! `compute_voluntary_exit_signing_root`.
+
+use grandine_ssz::SszHash as _;
+use grandine_types::phase0::containers::{SignedVoluntaryExit, VoluntaryExit};
+use pyo3::prelude::*;
+
+use crate::convert::format_hash_tree_root;
+use crate::define_ssz_pyclass_for_preset;
+use crate::hex_format::format_hex;
+
+define_ssz_pyclass_for_preset!(
+    PyVoluntaryExit,
+    "VoluntaryExit",
+    VoluntaryExit,
+    extra_methods = {
+        pub fn epoch(&self) -> u64 {
+            self.inner.epoch
+        }
+
+        pub fn validator_index(&self) -> u64 {
+            self.inner.validator_index
+        }
+
+        pub fn hash_tree_root(&self) -> String {
+            format_hash_tree_root(&self.inner.hash_tree_root())
+        }
+    }
+);
+
+define_ssz_pyclass_for_preset!(
+    PySignedVoluntaryExit,
+    "SignedVoluntaryExit",
+    SignedVoluntaryExit,
+    extra_methods = {
+        pub fn message(&self) -> PyVoluntaryExit {
+            PyVoluntaryExit {
+                inner: self.inner.message,
+            }
+        }
+
+        pub fn signature(&self) -> String {
+            format_hex(self.inner.signature.as_bytes())
+        }
+
+        pub fn hash_tree_root(&self) -> String {
+            format_hash_tree_root(&self.inner.hash_tree_root())
+        }
+    }
+);
+
+/// Registers the voluntary exit types with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyVoluntaryExit>()?;
+    m.add_class::<PySignedVoluntaryExit>()?;
+    Ok(())
+}
@@ -0,0 +1,141 @@
+//! Signed block header and proposer slashing types for Python bindings.
+//!
+//! This module provides Python-exposed types for:
+//! - `SignedBeaconBlockHeader` (preset-independent), as found in proposer
+//!   slashings and used by header-only monitoring
+//! - `ProposerSlashing` (preset-independent), as published on the
+//!   `proposer_slashing` gossip topic and held in slashing pools
+//!
+//! Neither type changed layout after Phase 0.
+
+use grandine_ssz::SszHash as _;
+use grandine_types::phase0::containers::{ProposerSlashing, SignedBeaconBlockHeader};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::bls::{parse_public_key, verify};
+use crate::convert::{format_hash_tree_root, parse_root};
+use crate::define_ssz_pyclass_for_preset;
+use crate::signing::signing_root;
+
+define_ssz_pyclass_for_preset!(
+    PySignedBeaconBlockHeader,
+    "SignedBeaconBlockHeader",
+    SignedBeaconBlockHeader,
+    extra_methods = {
+        pub fn slot(&self) -> u64 {
+            self.inner.message.slot
+        }
+
+        pub fn proposer_index(&self) -> u64 {
+            self.inner.message.proposer_index
+        }
+
+        /// Hash tree root of the unsigned header, i.e. the block root.
+        pub fn block_root(&self) -> String {
+            format_hash_tree_root(&self.inner.message.hash_tree_root())
+        }
+
+        /// Signing root of the header for the given `DOMAIN_BEACON_PROPOSER`
+        /// domain.
+        ///
+        /// # Errors
+        /// Returns `PyValueError` if `domain` is not 32 bytes.
+        pub fn signing_root(&self, domain: &Bound<'_, PyAny>) -> PyResult<String> {
+            let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+            Ok(format_hash_tree_root(&root))
+        }
+
+        /// Verify the signature against the proposer's `pubkey` and the
+        /// `DOMAIN_BEACON_PROPOSER` domain of the header's epoch.
+        ///
+        /// # Errors
+        /// Returns a `BlsEncodingError` subclass if `pubkey` is not a valid
+        /// pubkey, or `PyValueError` if `domain` is not 32 bytes.
+        pub fn verify(
+            &self,
+            py: Python<'_>,
+            pubkey: &Bound<'_, PyAny>,
+            domain: &Bound<'_, PyAny>,
+        ) -> PyResult<bool> {
+            let pubkey = parse_public_key(pubkey)?;
+            let root = signing_root(self.inner.message.hash_tree_root(), parse_root(domain)?);
+            let signature = self.inner.signature;
+            Ok(py.detach(|| verify(signature, root, &pubkey)))
+        }
+    }
+);
+
+define_ssz_pyclass_for_preset!(
+    PyProposerSlashing,
+    "ProposerSlashing",
+    ProposerSlashing,
+    extra_methods = {
+        /// Pair two conflicting signed headers into a proposer slashing.
+        ///
+        /// # Errors
+        /// Returns `PyValueError` if the headers are for different slots or
+        /// proposers, or are the same header.
+        #[new]
+        pub fn py_new(
+            signed_header_1: &PySignedBeaconBlockHeader,
+            signed_header_2: &PySignedBeaconBlockHeader,
+        ) -> PyResult<Self> {
+            let header_1 = &signed_header_1.inner.message;
+            let header_2 = &signed_header_2.inner.message;
+
+            if header_1.slot != header_2.slot {
+                return Err(PyValueError::new_err(format!(
+                    "Headers are for different slots ({} and {})",
+                    header_1.slot, header_2.slot
+                )));
+            }
+
+            if header_1.proposer_index != header_2.proposer_index {
+                return Err(PyValueError::new_err(format!(
+                    "Headers are from different proposers ({} and {})",
+                    header_1.proposer_index, header_2.proposer_index
+                )));
+            }
+
+            if header_1 == header_2 {
+                return Err(PyValueError::new_err("Headers are the same"));
+            }
+
+            Ok(Self {
+                inner: ProposerSlashing {
+                    signed_header_1: signed_header_1.inner,
+                    signed_header_2: signed_header_2.inner,
+                },
+            })
+        }
+
+        pub fn signed_header_1(&self) -> PySignedBeaconBlockHeader {
+            PySignedBeaconBlockHeader {
+                inner: self.inner.signed_header_1,
+            }
+        }
+
+        pub fn signed_header_2(&self) -> PySignedBeaconBlockHeader {
+            PySignedBeaconBlockHeader {
+                inner: self.inner.signed_header_2,
+            }
+        }
+
+        pub fn hash_tree_root(&self) -> String {
+            format_hash_tree_root(&self.inner.hash_tree_root())
+        }
+    }
+);
+
+/// Registers the signed header and proposer slashing types with the Python
+/// module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PySignedBeaconBlockHeader>()?;
+    m.add_class::<PyProposerSlashing>()?;
+    Ok(())
+}
@@ -0,0 +1,13 @@
+//! Phase 0 types support.
+//!
+//! This module contains consensus types that were introduced in Phase 0 and
+//! kept their layout until Electra, so that pre-Electra data can be decoded and
+//! converted to the Electra layout, signed block headers, proposer
+//! slashings, deposits and voluntary exits, which never changed layout, and
+//! the Phase 0 blocks.
+
+pub mod attestation;
+pub mod block;
+pub mod deposit;
+pub mod exit;
+pub mod header;
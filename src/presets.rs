@@ -0,0 +1,460 @@
+//! Preset identification helpers.
+//!
+//! Grandine presets are zero-sized marker types, which is all the generated
+//! classes need. Runtime code (configs, typed slots, dispatch) additionally
+//! needs to know *which* preset it is dealing with, so this module provides a
+//! plain enum mirroring the supported presets and a trait tying each marker
+//! type to its enum variant.
+//!
+//! The preset constants are also exposed to Python as the `grandine_py.presets`
+//! submodule, with one `PresetConstants` namespace per preset
+//! (`presets.GNOSIS.SLOTS_PER_EPOCH`). `diff_presets` lists the constants
+//! that differ between two presets.
+
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use pyo3::exceptions::{PyAttributeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use typenum::Unsigned as _;
+
+use crate::Gnosis;
+
+/// Runtime identifier of a supported preset.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum PresetKind {
+    Mainnet,
+    Minimal,
+    Gnosis,
+}
+
+impl PresetKind {
+    /// All supported presets, in a stable order.
+    pub const ALL: [Self; 3] = [Self::Mainnet, Self::Minimal, Self::Gnosis];
+
+    /// Returns the lowercase preset name (`mainnet`, `minimal`, `gnosis`).
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Mainnet => "mainnet",
+            Self::Minimal => "minimal",
+            Self::Gnosis => "gnosis",
+        }
+    }
+
+    /// Parses a preset name, ignoring ASCII case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string if the name does not match a supported preset.
+    pub fn parse(name: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| {
+                format!("Unknown preset: {name:?} (expected mainnet, minimal or gnosis)")
+            })
+    }
+
+    /// Returns `SLOTS_PER_EPOCH` for the preset.
+    #[must_use]
+    pub fn slots_per_epoch(self) -> u64 {
+        match self {
+            Self::Mainnet => <Mainnet as Preset>::SlotsPerEpoch::U64,
+            Self::Minimal => <Minimal as Preset>::SlotsPerEpoch::U64,
+            Self::Gnosis => <Gnosis as Preset>::SlotsPerEpoch::U64,
+        }
+    }
+
+    /// Returns `SLOTS_PER_HISTORICAL_ROOT` for the preset, the number of
+    /// slots in an era.
+    #[must_use]
+    pub fn slots_per_historical_root(self) -> u64 {
+        match self {
+            Self::Mainnet => slots_per_historical_root::<Mainnet>(),
+            Self::Minimal => slots_per_historical_root::<Minimal>(),
+            Self::Gnosis => slots_per_historical_root::<Gnosis>(),
+        }
+    }
+
+    /// Returns the preset's constants as `(NAME, value)` pairs, using the
+    /// names from the consensus specs.
+    #[must_use]
+    pub fn constants(self) -> Vec<(&'static str, u64)> {
+        match self {
+            Self::Mainnet => preset_constants::<Mainnet>(),
+            Self::Minimal => preset_constants::<Minimal>(),
+            Self::Gnosis => preset_constants::<Gnosis>(),
+        }
+    }
+
+    /// Returns the constants whose values differ between `self` and `other`
+    /// as `(NAME, self value, other value)` triples.
+    #[must_use]
+    pub fn diff(self, other: Self) -> Vec<(&'static str, u64, u64)> {
+        // Both lists come from `preset_constants`, so they are in the same
+        // order.
+        self.constants()
+            .into_iter()
+            .zip(other.constants())
+            .filter(|((_, a), (_, b))| a != b)
+            .map(|((name, a), (_, b))| (name, a, b))
+            .collect()
+    }
+}
+
+fn slots_per_historical_root<P: Preset>() -> u64 {
+    P::EpochsPerHistoricalRoot::U64 * P::SlotsPerEpoch::U64
+}
+
+fn preset_constants<P: Preset>() -> Vec<(&'static str, u64)> {
+    vec![
+        // Phase 0
+        ("MAX_COMMITTEES_PER_SLOT", P::MaxCommitteesPerSlot::U64),
+        ("TARGET_COMMITTEE_SIZE", P::TARGET_COMMITTEE_SIZE.get()),
+        (
+            "MAX_VALIDATORS_PER_COMMITTEE",
+            P::MaxValidatorsPerCommittee::U64,
+        ),
+        ("SHUFFLE_ROUND_COUNT", u64::from(P::SHUFFLE_ROUND_COUNT)),
+        ("HYSTERESIS_QUOTIENT", P::HYSTERESIS_QUOTIENT.get()),
+        (
+            "HYSTERESIS_DOWNWARD_MULTIPLIER",
+            P::HYSTERESIS_DOWNWARD_MULTIPLIER,
+        ),
+        (
+            "HYSTERESIS_UPWARD_MULTIPLIER",
+            P::HYSTERESIS_UPWARD_MULTIPLIER,
+        ),
+        ("MIN_DEPOSIT_AMOUNT", P::MIN_DEPOSIT_AMOUNT),
+        ("MAX_EFFECTIVE_BALANCE", P::MAX_EFFECTIVE_BALANCE),
+        (
+            "EFFECTIVE_BALANCE_INCREMENT",
+            P::EFFECTIVE_BALANCE_INCREMENT.get(),
+        ),
+        (
+            "MIN_ATTESTATION_INCLUSION_DELAY",
+            P::MIN_ATTESTATION_INCLUSION_DELAY.get(),
+        ),
+        ("SLOTS_PER_EPOCH", P::SlotsPerEpoch::U64),
+        ("MIN_SEED_LOOKAHEAD", P::MinSeedLookahead::U64),
+        ("MAX_SEED_LOOKAHEAD", P::MAX_SEED_LOOKAHEAD),
+        (
+            "EPOCHS_PER_ETH1_VOTING_PERIOD",
+            P::EpochsPerEth1VotingPeriod::U64,
+        ),
+        (
+            "SLOTS_PER_HISTORICAL_ROOT",
+            slots_per_historical_root::<P>(),
+        ),
+        (
+            "MIN_EPOCHS_TO_INACTIVITY_PENALTY",
+            P::MIN_EPOCHS_TO_INACTIVITY_PENALTY,
+        ),
+        (
+            "EPOCHS_PER_HISTORICAL_VECTOR",
+            P::EpochsPerHistoricalVector::U64,
+        ),
+        (
+            "EPOCHS_PER_SLASHINGS_VECTOR",
+            P::EpochsPerSlashingsVector::U64,
+        ),
+        ("HISTORICAL_ROOTS_LIMIT", P::HistoricalRootsLimit::U64),
+        ("VALIDATOR_REGISTRY_LIMIT", P::ValidatorRegistryLimit::U64),
+        ("BASE_REWARD_FACTOR", P::BASE_REWARD_FACTOR),
+        (
+            "WHISTLEBLOWER_REWARD_QUOTIENT",
+            P::WHISTLEBLOWER_REWARD_QUOTIENT.get(),
+        ),
+        (
+            "PROPOSER_REWARD_QUOTIENT",
+            P::PROPOSER_REWARD_QUOTIENT.get(),
+        ),
+        (
+            "INACTIVITY_PENALTY_QUOTIENT",
+            P::INACTIVITY_PENALTY_QUOTIENT.get(),
+        ),
+        (
+            "MIN_SLASHING_PENALTY_QUOTIENT",
+            P::MIN_SLASHING_PENALTY_QUOTIENT.get(),
+        ),
+        (
+            "PROPORTIONAL_SLASHING_MULTIPLIER",
+            P::PROPORTIONAL_SLASHING_MULTIPLIER,
+        ),
+        ("MAX_PROPOSER_SLASHINGS", P::MaxProposerSlashings::U64),
+        ("MAX_ATTESTER_SLASHINGS", P::MaxAttesterSlashings::U64),
+        ("MAX_ATTESTATIONS", P::MaxAttestations::U64),
+        ("MAX_DEPOSITS", P::MaxDeposits::U64),
+        ("MAX_VOLUNTARY_EXITS", P::MaxVoluntaryExits::U64),
+        // Altair
+        ("SYNC_COMMITTEE_SIZE", P::SyncCommitteeSize::U64),
+        (
+            "EPOCHS_PER_SYNC_COMMITTEE_PERIOD",
+            P::EPOCHS_PER_SYNC_COMMITTEE_PERIOD.get(),
+        ),
+        (
+            "MIN_SYNC_COMMITTEE_PARTICIPANTS",
+            P::MIN_SYNC_COMMITTEE_PARTICIPANTS as u64,
+        ),
+        (
+            "INACTIVITY_PENALTY_QUOTIENT_ALTAIR",
+            P::INACTIVITY_PENALTY_QUOTIENT_ALTAIR.get(),
+        ),
+        (
+            "MIN_SLASHING_PENALTY_QUOTIENT_ALTAIR",
+            P::MIN_SLASHING_PENALTY_QUOTIENT_ALTAIR.get(),
+        ),
+        (
+            "PROPORTIONAL_SLASHING_MULTIPLIER_ALTAIR",
+            P::PROPORTIONAL_SLASHING_MULTIPLIER_ALTAIR,
+        ),
+        // Bellatrix
+        (
+            "INACTIVITY_PENALTY_QUOTIENT_BELLATRIX",
+            P::INACTIVITY_PENALTY_QUOTIENT_BELLATRIX.get(),
+        ),
+        (
+            "MIN_SLASHING_PENALTY_QUOTIENT_BELLATRIX",
+            P::MIN_SLASHING_PENALTY_QUOTIENT_BELLATRIX.get(),
+        ),
+        (
+            "PROPORTIONAL_SLASHING_MULTIPLIER_BELLATRIX",
+            P::PROPORTIONAL_SLASHING_MULTIPLIER_BELLATRIX,
+        ),
+        ("MAX_BYTES_PER_TRANSACTION", P::MaxBytesPerTransaction::U64),
+        (
+            "MAX_TRANSACTIONS_PER_PAYLOAD",
+            P::MaxTransactionsPerPayload::U64,
+        ),
+        ("BYTES_PER_LOGS_BLOOM", P::BytesPerLogsBloom::U64),
+        ("MAX_EXTRA_DATA_BYTES", P::MaxExtraDataBytes::U64),
+        // Capella
+        (
+            "MAX_BLS_TO_EXECUTION_CHANGES",
+            P::MaxBlsToExecutionChanges::U64,
+        ),
+        (
+            "MAX_WITHDRAWALS_PER_PAYLOAD",
+            P::MaxWithdrawalsPerPayload::U64,
+        ),
+        (
+            "MAX_VALIDATORS_PER_WITHDRAWALS_SWEEP",
+            P::MAX_VALIDATORS_PER_WITHDRAWALS_SWEEP,
+        ),
+        // Deneb
+        ("FIELD_ELEMENTS_PER_BLOB", P::FieldElementsPerBlob::U64),
+        (
+            "MAX_BLOB_COMMITMENTS_PER_BLOCK",
+            P::MaxBlobCommitmentsPerBlock::U64,
+        ),
+        (
+            "KZG_COMMITMENT_INCLUSION_PROOF_DEPTH",
+            P::KzgCommitmentInclusionProofDepth::U64,
+        ),
+        // Electra
+        ("MIN_ACTIVATION_BALANCE", P::MIN_ACTIVATION_BALANCE),
+        (
+            "MAX_EFFECTIVE_BALANCE_ELECTRA",
+            P::MAX_EFFECTIVE_BALANCE_ELECTRA,
+        ),
+        (
+            "MIN_SLASHING_PENALTY_QUOTIENT_ELECTRA",
+            P::MIN_SLASHING_PENALTY_QUOTIENT_ELECTRA.get(),
+        ),
+        (
+            "WHISTLEBLOWER_REWARD_QUOTIENT_ELECTRA",
+            P::WHISTLEBLOWER_REWARD_QUOTIENT_ELECTRA.get(),
+        ),
+        ("PENDING_DEPOSITS_LIMIT", P::PendingDepositsLimit::U64),
+        (
+            "PENDING_PARTIAL_WITHDRAWALS_LIMIT",
+            P::PendingPartialWithdrawalsLimit::U64,
+        ),
+        (
+            "PENDING_CONSOLIDATIONS_LIMIT",
+            P::PendingConsolidationsLimit::U64,
+        ),
+        (
+            "MAX_ATTESTER_SLASHINGS_ELECTRA",
+            P::MaxAttesterSlashingsElectra::U64,
+        ),
+        ("MAX_ATTESTATIONS_ELECTRA", P::MaxAttestationsElectra::U64),
+        (
+            "MAX_DEPOSIT_REQUESTS_PER_PAYLOAD",
+            P::MaxDepositRequestsPerPayload::U64,
+        ),
+        (
+            "MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD",
+            P::MaxWithdrawalRequestsPerPayload::U64,
+        ),
+        (
+            "MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD",
+            P::MaxConsolidationRequestsPerPayload::U64,
+        ),
+        (
+            "MAX_PENDING_PARTIALS_PER_WITHDRAWALS_SWEEP",
+            P::MAX_PENDING_PARTIALS_PER_WITHDRAWALS_SWEEP,
+        ),
+        (
+            "MAX_PENDING_DEPOSITS_PER_EPOCH",
+            P::MAX_PENDING_DEPOSITS_PER_EPOCH,
+        ),
+        // Fulu
+        ("FIELD_ELEMENTS_PER_CELL", P::FieldElementsPerCell::U64),
+        (
+            "FIELD_ELEMENTS_PER_EXT_BLOB",
+            P::FieldElementsPerExtBlob::U64,
+        ),
+        (
+            "KZG_COMMITMENTS_INCLUSION_PROOF_DEPTH",
+            P::KzgCommitmentsInclusionProofDepth::U64,
+        ),
+        ("NUMBER_OF_COLUMNS", P::NumberOfColumns::U64),
+    ]
+}
+
+/// A Grandine preset that can be identified at runtime.
+pub trait NamedPreset: Preset {
+    /// The runtime identifier of this preset.
+    const KIND: PresetKind;
+}
+
+impl NamedPreset for Mainnet {
+    const KIND: PresetKind = PresetKind::Mainnet;
+}
+
+impl NamedPreset for Minimal {
+    const KIND: PresetKind = PresetKind::Minimal;
+}
+
+impl NamedPreset for Gnosis {
+    const KIND: PresetKind = PresetKind::Gnosis;
+}
+
+/// Read-only namespace of a preset's constants.
+#[pyclass(name = "PresetConstants", frozen)]
+pub struct PyPresetConstants {
+    kind: PresetKind,
+    constants: Vec<(&'static str, u64)>,
+}
+
+#[pymethods]
+impl PyPresetConstants {
+    /// The lowercase preset name.
+    #[getter]
+    fn preset(&self) -> &'static str {
+        self.kind.name()
+    }
+
+    fn __getattr__(&self, name: &str) -> PyResult<u64> {
+        self.constants
+            .iter()
+            .find(|(constant, _)| *constant == name)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| {
+                PyAttributeError::new_err(format!(
+                    "{} preset has no constant {name:?}",
+                    self.kind.name()
+                ))
+            })
+    }
+
+    fn __dir__(&self) -> Vec<&'static str> {
+        let mut names = vec!["preset", "as_dict"];
+        names.extend(self.constants.iter().map(|(name, _)| *name));
+        names
+    }
+
+    /// Return all constants as a dict.
+    fn as_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let constants = PyDict::new(py);
+        for (name, value) in &self.constants {
+            constants.set_item(name, value)?;
+        }
+        Ok(constants)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PresetConstants({})", self.kind.name())
+    }
+}
+
+/// A preset given by name or by its constants namespace.
+#[derive(FromPyObject)]
+pub enum PresetArg<'py> {
+    Name(String),
+    Constants(PyRef<'py, PyPresetConstants>),
+}
+
+impl PresetArg<'_> {
+    fn kind(&self) -> PyResult<PresetKind> {
+        match self {
+            Self::Name(name) => PresetKind::parse(name).map_err(PyValueError::new_err),
+            Self::Constants(constants) => Ok(constants.kind),
+        }
+    }
+}
+
+/// Builds a `{NAME: (a, b)}` dict from differing values.
+pub fn diff_dict<'py, A, B>(
+    py: Python<'py>,
+    differences: impl IntoIterator<Item = (&'static str, A, B)>,
+) -> PyResult<Bound<'py, PyDict>>
+where
+    A: IntoPyObject<'py>,
+    B: IntoPyObject<'py>,
+{
+    let dict = PyDict::new(py);
+    for (name, a, b) in differences {
+        dict.set_item(name, (a, b))?;
+    }
+    Ok(dict)
+}
+
+/// Return the constants that differ between two presets.
+///
+/// Presets are given by name (`"mainnet"`, `"minimal"`, `"gnosis"`) or as
+/// `presets` namespaces such as `presets.GNOSIS`. Returns a dict mapping
+/// each differing constant to its `(preset_a, preset_b)` values.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if a preset name is unknown.
+#[pyfunction]
+pub fn diff_presets<'py>(
+    py: Python<'py>,
+    preset_a: PresetArg<'_>,
+    preset_b: PresetArg<'_>,
+) -> PyResult<Bound<'py, PyDict>> {
+    diff_dict(py, preset_a.kind()?.diff(preset_b.kind()?))
+}
+
+/// Registers the `presets` submodule with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if the submodule cannot be created or registered.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    let presets = PyModule::new(py, "presets")?;
+
+    presets.add_class::<PyPresetConstants>()?;
+
+    for kind in PresetKind::ALL {
+        let constants = PyPresetConstants {
+            kind,
+            constants: kind.constants(),
+        };
+        presets.add(kind.name().to_ascii_uppercase(), constants)?;
+    }
+
+    m.add_submodule(&presets)?;
+    m.add_function(wrap_pyfunction!(diff_presets, m)?)?;
+
+    // Make `import grandine_py.presets` work, not just attribute access.
+    py.import("sys")?
+        .getattr("modules")?
+        .set_item("grandine_py.presets", &presets)?;
+
+    Ok(())
+}
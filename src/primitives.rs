@@ -0,0 +1,269 @@
+//! Typed slot and epoch values.
+//!
+//! Raw integers make it easy to mix up slots and epochs, or to convert between
+//! them with the wrong `SLOTS_PER_EPOCH` (16 on Gnosis, 32 on Mainnet). `Slot`
+//! and `Epoch` carry the `Config` they belong to, convert between each other
+//! using that config, and refuse to be combined with values bound to a
+//! different config.
+
+use std::sync::Arc;
+
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyInt;
+
+use crate::config::{ChainConfig, PyConfig};
+
+/// Modulus CPython uses when hashing integers, so that `hash(Slot(n)) == hash(n)`.
+const PY_HASH_MODULUS: u64 = (1 << 61) - 1;
+
+/// Extracts a plain Python `int` operand, rejecting other typed values.
+fn plain_int(other: &Bound<'_, PyAny>, op: &str, kind: &str) -> PyResult<u64> {
+    if other.is_instance_of::<PySlot>() || other.is_instance_of::<PyEpoch>() {
+        return Err(PyTypeError::new_err(format!(
+            "unsupported operand types for {op}: '{kind}' and '{}'",
+            other.get_type().name()?,
+        )));
+    }
+
+    other
+        .cast::<PyInt>()
+        .map_err(|_| {
+            PyTypeError::new_err(format!("unsupported operand type for {op} with '{kind}'"))
+        })?
+        .extract()
+}
+
+pub(crate) fn ensure_same_config(a: &ChainConfig, b: &ChainConfig, kind: &str) -> PyResult<()> {
+    if a == b {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(format!(
+            "cannot combine {kind} values bound to different configs ({:?} and {:?})",
+            a.name, b.name,
+        )))
+    }
+}
+
+pub(crate) fn resolve_optional_config(config: Option<PyRef<'_, PyConfig>>) -> Arc<ChainConfig> {
+    config.map_or_else(
+        || Arc::new(ChainConfig::mainnet()),
+        |config| config.inner.clone(),
+    )
+}
+
+macro_rules! define_typed_u64_pyclass {
+    (
+        $rust_struct:ident,
+        $py_name:literal
+        $(, extra_methods = { $($extra:tt)* } )?
+    ) => {
+        #[pyclass(name = $py_name, frozen)]
+        #[derive(Clone)]
+        pub struct $rust_struct {
+            pub(crate) value: u64,
+            pub(crate) config: Arc<ChainConfig>,
+        }
+
+        impl $rust_struct {
+            #[must_use]
+            pub fn new(value: u64, config: Arc<ChainConfig>) -> Self {
+                Self { value, config }
+            }
+
+            fn with_value(&self, value: Option<u64>, op: &str) -> PyResult<Self> {
+                let value = value.ok_or_else(|| {
+                    PyOverflowError::new_err(format!("{} {op} out of range", $py_name))
+                })?;
+                Ok(Self::new(value, self.config.clone()))
+            }
+        }
+
+        #[pymethods]
+        impl $rust_struct {
+            #[new]
+            #[pyo3(signature = (value, config = None))]
+            fn py_new(value: u64, config: Option<PyRef<'_, PyConfig>>) -> Self {
+                Self::new(value, resolve_optional_config(config))
+            }
+
+            #[getter]
+            fn value(&self) -> u64 {
+                self.value
+            }
+
+            #[getter]
+            fn config(&self) -> PyConfig {
+                PyConfig {
+                    inner: self.config.clone(),
+                }
+            }
+
+            fn __int__(&self) -> u64 {
+                self.value
+            }
+
+            fn __index__(&self) -> u64 {
+                self.value
+            }
+
+            fn __hash__(&self) -> u64 {
+                self.value % PY_HASH_MODULUS
+            }
+
+            fn __str__(&self) -> String {
+                self.value.to_string()
+            }
+
+            fn __repr__(&self) -> String {
+                format!("{}({}, config={:?})", $py_name, self.value, self.config.name)
+            }
+
+            fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<bool> {
+                let other_value = if let Ok(other) = other.cast::<Self>() {
+                    let other = other.get();
+                    ensure_same_config(&self.config, &other.config, $py_name)?;
+                    other.value
+                } else if other.is_instance_of::<PyInt>()
+                    && !other.is_instance_of::<PySlot>()
+                    && !other.is_instance_of::<PyEpoch>()
+                {
+                    other.extract()?
+                } else {
+                    return match op {
+                        CompareOp::Eq => Ok(false),
+                        CompareOp::Ne => Ok(true),
+                        _ => Err(PyTypeError::new_err(format!(
+                            "'{}' cannot be ordered against '{}'",
+                            $py_name,
+                            other.get_type().name()?,
+                        ))),
+                    };
+                };
+
+                Ok(op.matches(self.value.cmp(&other_value)))
+            }
+
+            fn __add__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+                let offset = plain_int(other, "+", $py_name)?;
+                self.with_value(self.value.checked_add(offset), "+")
+            }
+
+            fn __radd__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+                self.__add__(other)
+            }
+
+            /// Subtracting an `int` yields a new value; subtracting a value of
+            /// the same type yields the distance between them as an `int`.
+            fn __sub__(&self, py: Python<'_>, other: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+                if let Ok(other) = other.cast::<Self>() {
+                    let other = other.get();
+                    ensure_same_config(&self.config, &other.config, $py_name)?;
+                    let distance = self.value.checked_sub(other.value).ok_or_else(|| {
+                        PyValueError::new_err(format!("{} difference would be negative", $py_name))
+                    })?;
+                    return Ok(distance.into_pyobject(py)?.into_any().unbind());
+                }
+
+                let offset = plain_int(other, "-", $py_name)?;
+                let result = self.with_value(self.value.checked_sub(offset), "-")?;
+                Ok(Py::new(py, result)?.into_any())
+            }
+
+            $($($extra)*)?
+        }
+    };
+}
+
+define_typed_u64_pyclass!(
+    PySlot,
+    "Slot",
+    extra_methods = {
+        /// Return the epoch containing this slot.
+        fn epoch(&self) -> PyEpoch {
+            PyEpoch::new(
+                self.value / self.config.slots_per_epoch(),
+                self.config.clone(),
+            )
+        }
+
+        /// Return the position of this slot within its epoch.
+        fn index_in_epoch(&self) -> u64 {
+            self.value % self.config.slots_per_epoch()
+        }
+
+        /// Return whether this is the first slot of an epoch.
+        fn is_epoch_start(&self) -> bool {
+            self.index_in_epoch() == 0
+        }
+
+        /// Return the UNIX timestamp at which this slot starts.
+        ///
+        /// # Errors
+        /// Returns `PyOverflowError` if the timestamp does not fit in 64 bits.
+        fn start_time(&self) -> PyResult<u64> {
+            self.value
+                .checked_mul(self.config.seconds_per_slot)
+                .and_then(|offset| offset.checked_add(self.config.genesis_time))
+                .ok_or_else(|| PyOverflowError::new_err("slot start time out of range"))
+        }
+    }
+);
+
+define_typed_u64_pyclass!(
+    PyEpoch,
+    "Epoch",
+    extra_methods = {
+        /// Return the first slot of this epoch.
+        ///
+        /// # Errors
+        /// Returns `PyOverflowError` if the slot does not fit in 64 bits.
+        fn start_slot(&self) -> PyResult<PySlot> {
+            self.value
+                .checked_mul(self.config.slots_per_epoch())
+                .map(|slot| PySlot::new(slot, self.config.clone()))
+                .ok_or_else(|| PyOverflowError::new_err("epoch start slot out of range"))
+        }
+
+        /// Return the last slot of this epoch.
+        ///
+        /// # Errors
+        /// Returns `PyOverflowError` if the slot does not fit in 64 bits.
+        fn end_slot(&self) -> PyResult<PySlot> {
+            self.start_slot()?
+                .value
+                .checked_add(self.config.slots_per_epoch() - 1)
+                .map(|slot| PySlot::new(slot, self.config.clone()))
+                .ok_or_else(|| PyOverflowError::new_err("epoch end slot out of range"))
+        }
+
+        /// Return the UNIX timestamp at which this epoch starts.
+        ///
+        /// # Errors
+        /// Returns `PyOverflowError` if the timestamp does not fit in 64 bits.
+        fn start_time(&self) -> PyResult<u64> {
+            self.start_slot()?.start_time()
+        }
+
+        /// Return whether `slot` falls within this epoch.
+        ///
+        /// # Errors
+        /// Returns `PyValueError` if `slot` is bound to a different config.
+        fn __contains__(&self, slot: PyRef<'_, PySlot>) -> PyResult<bool> {
+            ensure_same_config(&self.config, &slot.config, "Slot")?;
+            Ok(slot.value / self.config.slots_per_epoch() == self.value)
+        }
+    }
+);
+
+/// Registers the typed primitive classes with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PySlot>()?;
+    m.add_class::<PyEpoch>()?;
+    Ok(())
+}
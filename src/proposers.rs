@@ -0,0 +1,497 @@
+//! Native beacon proposer selection.
+//!
+//! Implements the Electra `compute_proposer_index` (16-bit random values,
+//! weighted by effective balance up to `MAX_EFFECTIVE_BALANCE_ELECTRA`) and
+//! `compute_shuffled_index` from the consensus specs, so that proposer
+//! schedules can be derived from a decoded state without a beacon node.
+//!
+//! Fork-specific lookahead rules:
+//! - Fulu states carry the schedule for the current and next epoch in
+//!   `proposer_lookahead` (EIP-7917), which is used as is.
+//! - Electra states have no lookahead field, so the next epoch's proposers are
+//!   computed from the validator set and RANDAO mix already in the state.
+//!   Effective balance updates at the epoch boundary can still change the
+//!   result, so this is a prediction rather than a guarantee.
+//!
+//! `verify_proposer_schedule` compares the same schedule against a beacon
+//! node's proposer duties, to detect nodes serving wrong duties.
+
+use std::collections::BTreeMap;
+
+use grandine_ssz::H256;
+use grandine_types::phase0::containers::Validator;
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use serde::Deserialize;
+use sha2::{Digest as _, Sha256};
+use typenum::Unsigned as _;
+
+use crate::config::{resolve_config, PyConfig};
+use crate::electra::state::{PyBeaconStateGnosis, PyBeaconStateMainnet, PyBeaconStateMinimal};
+use crate::fulu::state::{
+    PyFuluBeaconStateGnosis, PyFuluBeaconStateMainnet, PyFuluBeaconStateMinimal,
+};
+use crate::{decode_json, Gnosis, JsonDataEnvelope};
+
+const DOMAIN_BEACON_PROPOSER: [u8; 4] = [0; 4];
+const MAX_RANDOM_VALUE: u64 = (1 << 16) - 1;
+
+fn hash(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// `compute_shuffled_index` from the consensus specs.
+#[must_use]
+pub fn compute_shuffled_index<P: Preset>(mut index: u64, index_count: u64, seed: &[u8; 32]) -> u64 {
+    for round in 0..P::SHUFFLE_ROUND_COUNT {
+        let pivot_hash = hash(&[seed, &[round]]);
+        let mut pivot_bytes = [0; 8];
+        pivot_bytes.copy_from_slice(&pivot_hash[..8]);
+        let pivot = u64::from_le_bytes(pivot_bytes) % index_count;
+
+        let flip = (pivot + index_count - index) % index_count;
+        let position = index.max(flip);
+
+        // `position / 256` fits in a `u32` because validator indices are
+        // bounded by `VALIDATOR_REGISTRY_LIMIT` (2**40).
+        #[allow(clippy::cast_possible_truncation)]
+        let source = hash(&[seed, &[round], &((position / 256) as u32).to_le_bytes()]);
+        #[allow(clippy::cast_possible_truncation)]
+        let byte = source[((position % 256) / 8) as usize];
+
+        if (byte >> (position % 8)) & 1 == 1 {
+            index = flip;
+        }
+    }
+
+    index
+}
+
+/// `compute_proposer_index` from the Electra specs.
+///
+/// `effective_balances` is indexed by validator index. Returns `None` if there
+/// are no active validators.
+#[must_use]
+pub fn compute_proposer_index<P: Preset>(
+    active_indices: &[u64],
+    effective_balances: &[u64],
+    seed: &[u8; 32],
+) -> Option<u64> {
+    let total = active_indices.len() as u64;
+
+    if total == 0 {
+        return None;
+    }
+
+    let mut i = 0_u64;
+
+    loop {
+        #[allow(clippy::cast_possible_truncation)]
+        let candidate =
+            active_indices[compute_shuffled_index::<P>(i % total, total, seed) as usize];
+
+        let random_bytes = hash(&[seed, &(i / 16).to_le_bytes()]);
+        #[allow(clippy::cast_possible_truncation)]
+        let offset = (i % 16 * 2) as usize;
+        let random_value = u64::from(u16::from_le_bytes([
+            random_bytes[offset],
+            random_bytes[offset + 1],
+        ]));
+
+        #[allow(clippy::cast_possible_truncation)]
+        let effective_balance = effective_balances[candidate as usize];
+
+        if effective_balance * MAX_RANDOM_VALUE >= P::MAX_EFFECTIVE_BALANCE_ELECTRA * random_value {
+            return Some(candidate);
+        }
+
+        i += 1;
+    }
+}
+
+/// Computes the proposer of every slot in `epoch`.
+///
+/// `randao_mix` must be the mix returned by `get_randao_mix(state, epoch +
+/// EPOCHS_PER_HISTORICAL_VECTOR - MIN_SEED_LOOKAHEAD - 1)`.
+///
+/// # Errors
+///
+/// Returns an error string if no validator is active in `epoch`.
+pub fn epoch_proposers<'a, P: Preset>(
+    validators: impl IntoIterator<Item = &'a Validator>,
+    randao_mix: &H256,
+    epoch: u64,
+) -> Result<Vec<u64>, String> {
+    let mut active_indices = Vec::new();
+    let mut effective_balances = Vec::new();
+
+    for (index, validator) in (0_u64..).zip(validators) {
+        if validator.activation_epoch <= epoch && epoch < validator.exit_epoch {
+            active_indices.push(index);
+        }
+        effective_balances.push(validator.effective_balance);
+    }
+
+    let epoch_seed = hash(&[
+        &DOMAIN_BEACON_PROPOSER,
+        &epoch.to_le_bytes(),
+        randao_mix.as_bytes(),
+    ]);
+
+    let slots_per_epoch = P::SlotsPerEpoch::U64;
+
+    (epoch * slots_per_epoch..(epoch + 1) * slots_per_epoch)
+        .map(|slot| {
+            let seed = hash(&[&epoch_seed, &slot.to_le_bytes()]);
+            compute_proposer_index::<P>(&active_indices, &effective_balances, &seed)
+                .ok_or_else(|| format!("No active validators in epoch {epoch}"))
+        })
+        .collect()
+}
+
+/// Returns the offset of `epoch` from the current epoch of a state at
+/// `slot`, which must be 0 or 1.
+fn lookahead_offset<P: Preset>(slot: u64, epoch: u64) -> Result<u64, String> {
+    let current_epoch = slot / P::SlotsPerEpoch::U64;
+
+    match epoch.checked_sub(current_epoch) {
+        Some(offset @ (0 | 1)) => Ok(offset),
+        _ => Err(format!(
+            "Proposers of epoch {epoch} cannot be determined from a state in epoch {current_epoch}"
+        )),
+    }
+}
+
+/// Computes the proposers of the current or next epoch from an Electra state.
+pub(crate) fn electra_epoch_proposers<P: Preset>(
+    state: &grandine_types::electra::beacon_state::BeaconState<P>,
+    epoch: u64,
+) -> Result<Vec<u64>, String> {
+    lookahead_offset::<P>(state.slot, epoch)?;
+
+    let randao_mix = state
+        .randao_mixes
+        .mod_index(epoch + P::EpochsPerHistoricalVector::U64 - P::MinSeedLookahead::U64 - 1);
+
+    epoch_proposers::<P>(&state.validators, randao_mix, epoch)
+}
+
+/// Reads the proposers of the current or next epoch from a Fulu state's
+/// lookahead.
+pub(crate) fn fulu_epoch_proposers<P: Preset>(
+    state: &grandine_types::fulu::beacon_state::BeaconState<P>,
+    epoch: u64,
+) -> Result<Vec<u64>, String> {
+    let slots_per_epoch = P::SlotsPerEpoch::USIZE;
+    // `lookahead_offset` is 0 or 1.
+    #[allow(clippy::cast_possible_truncation)]
+    let offset = lookahead_offset::<P>(state.slot, epoch)? as usize;
+
+    Ok((&state.proposer_lookahead)
+        .into_iter()
+        .skip(offset * slots_per_epoch)
+        .take(slots_per_epoch)
+        .copied()
+        .collect())
+}
+
+/// Return the proposer index of every slot in the epoch after the state's
+/// current epoch.
+///
+/// Fulu states answer from their `proposer_lookahead`. For Electra states the
+/// proposers are computed natively from the state; effective balance updates
+/// at the epoch boundary may still change them.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if `state` is not a beacon state, or `PyValueError`
+/// if `config` belongs to a different preset or no validator is active.
+#[pyfunction]
+#[pyo3(signature = (state, config = None))]
+pub fn next_epoch_proposers(
+    py: Python<'_>,
+    state: &Bound<'_, PyAny>,
+    config: Option<PyRef<'_, PyConfig>>,
+) -> PyResult<Vec<u64>> {
+    macro_rules! try_electra {
+        ($($class:ty => $preset:ty),* $(,)?) => {
+            $(
+                if let Ok(state) = state.cast::<$class>() {
+                    resolve_config::<$preset>(config.as_deref())?;
+                    let state = state.borrow();
+                    let inner = &state.inner;
+                    let epoch = inner.slot / <$preset as Preset>::SlotsPerEpoch::U64 + 1;
+                    return py
+                        .detach(|| electra_epoch_proposers(inner, epoch))
+                        .map_err(PyValueError::new_err);
+                }
+            )*
+        };
+    }
+
+    macro_rules! try_fulu {
+        ($($class:ty => $preset:ty),* $(,)?) => {
+            $(
+                if let Ok(state) = state.cast::<$class>() {
+                    resolve_config::<$preset>(config.as_deref())?;
+                    let state = state.borrow();
+                    let inner = &state.inner;
+                    let epoch = inner.slot / <$preset as Preset>::SlotsPerEpoch::U64 + 1;
+                    return fulu_epoch_proposers(inner, epoch)
+                        .map_err(PyValueError::new_err);
+                }
+            )*
+        };
+    }
+
+    try_fulu!(
+        PyFuluBeaconStateMainnet => Mainnet,
+        PyFuluBeaconStateMinimal => Minimal,
+        PyFuluBeaconStateGnosis => Gnosis,
+    );
+
+    try_electra!(
+        PyBeaconStateMainnet => Mainnet,
+        PyBeaconStateMinimal => Minimal,
+        PyBeaconStateGnosis => Gnosis,
+    );
+
+    Err(PyTypeError::new_err(format!(
+        "Expected a beacon state, got {}",
+        state.get_type().name()?
+    )))
+}
+
+/// An entry of a Beacon API proposer duties response.
+#[derive(Deserialize)]
+struct ProposerDuty {
+    pubkey: String,
+    validator_index: String,
+    slot: String,
+}
+
+/// A difference between claimed and recomputed proposer duties.
+struct Discrepancy {
+    slot: u64,
+    kind: &'static str,
+    expected_validator_index: Option<u64>,
+    claimed_validator_index: Option<u64>,
+}
+
+fn parse_decimal(value: &str, name: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid {name} in proposer duty: {value:?}"))
+}
+
+/// Compares claimed duties against the recomputed `expected` proposers of
+/// `epoch`, checking claimed pubkeys against the validator registry.
+///
+/// # Errors
+///
+/// Returns an error string if a duty has a malformed field.
+fn diff_duties<'a, P: Preset>(
+    validators: impl IntoIterator<Item = &'a Validator>,
+    expected: &[u64],
+    epoch: u64,
+    duties: Vec<ProposerDuty>,
+) -> Result<Vec<Discrepancy>, String> {
+    let start_slot = epoch * P::SlotsPerEpoch::U64;
+    let mut claimed = vec![false; expected.len()];
+    let mut discrepancies = Vec::new();
+    // Claimed `(slot, expected proposer, pubkey)` by validator index.
+    let mut pubkeys = BTreeMap::<u64, Vec<_>>::new();
+
+    for duty in duties {
+        let slot = parse_decimal(&duty.slot, "slot")?;
+        let validator_index = parse_decimal(&duty.validator_index, "validator_index")?;
+        let pubkey = hex::decode(duty.pubkey.trim_start_matches("0x"))
+            .map_err(|_| format!("Invalid pubkey in proposer duty: {:?}", duty.pubkey))?;
+
+        let Some(position) = slot
+            .checked_sub(start_slot)
+            .and_then(|offset| usize::try_from(offset).ok())
+            .filter(|offset| *offset < expected.len())
+        else {
+            discrepancies.push(Discrepancy {
+                slot,
+                kind: "wrong_epoch",
+                expected_validator_index: None,
+                claimed_validator_index: Some(validator_index),
+            });
+            continue;
+        };
+
+        if core::mem::replace(&mut claimed[position], true) {
+            discrepancies.push(Discrepancy {
+                slot,
+                kind: "duplicate",
+                expected_validator_index: Some(expected[position]),
+                claimed_validator_index: Some(validator_index),
+            });
+            continue;
+        }
+
+        if validator_index != expected[position] {
+            discrepancies.push(Discrepancy {
+                slot,
+                kind: "wrong_proposer",
+                expected_validator_index: Some(expected[position]),
+                claimed_validator_index: Some(validator_index),
+            });
+        }
+
+        pubkeys
+            .entry(validator_index)
+            .or_default()
+            .push((slot, expected[position], pubkey));
+    }
+
+    for (index, validator) in (0_u64..).zip(validators) {
+        if pubkeys.is_empty() {
+            break;
+        }
+
+        for (slot, expected, pubkey) in pubkeys.remove(&index).unwrap_or_default() {
+            if pubkey.as_slice() != validator.pubkey.as_bytes() {
+                discrepancies.push(Discrepancy {
+                    slot,
+                    kind: "wrong_pubkey",
+                    expected_validator_index: Some(expected),
+                    claimed_validator_index: Some(index),
+                });
+            }
+        }
+    }
+
+    // Whatever is left was claimed for validators not in the registry.
+    for (index, claims) in pubkeys {
+        for (slot, expected, _) in claims {
+            discrepancies.push(Discrepancy {
+                slot,
+                kind: "unknown_validator",
+                expected_validator_index: Some(expected),
+                claimed_validator_index: Some(index),
+            });
+        }
+    }
+
+    for ((slot, expected), claimed) in (start_slot..).zip(expected).zip(claimed) {
+        if !claimed {
+            discrepancies.push(Discrepancy {
+                slot,
+                kind: "missing",
+                expected_validator_index: Some(*expected),
+                claimed_validator_index: None,
+            });
+        }
+    }
+
+    discrepancies.sort_by_key(|discrepancy| discrepancy.slot);
+
+    Ok(discrepancies)
+}
+
+/// Recompute the proposer duties of `epoch` and compare them with a beacon
+/// node's `GET /eth/v1/validator/duties/proposer/{epoch}` response.
+///
+/// `epoch` must be the state's current or next epoch. For Electra states the
+/// next epoch's proposers are a prediction (see `next_epoch_proposers`), so
+/// a state from the last slot of the previous epoch gives the most reliable
+/// result. Returns one dict per discrepancy, sorted by slot, with `slot`,
+/// `kind` (`missing`, `duplicate`, `wrong_epoch`, `wrong_proposer`,
+/// `wrong_pubkey` or `unknown_validator`), `expected_validator_index` and
+/// `claimed_validator_index`. An empty list means the duties match.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if `state` is not a beacon state, or `PyValueError`
+/// if `config` belongs to a different preset, the duties cannot be parsed, or
+/// the proposers of `epoch` cannot be determined from the state.
+#[pyfunction]
+#[pyo3(signature = (state, epoch, claimed_duties_json, config = None))]
+pub fn verify_proposer_schedule(
+    py: Python<'_>,
+    state: &Bound<'_, PyAny>,
+    epoch: u64,
+    claimed_duties_json: &[u8],
+    config: Option<PyRef<'_, PyConfig>>,
+) -> PyResult<Py<PyList>> {
+    macro_rules! try_states {
+        ($epoch_proposers:ident: $($class:ty => $preset:ty),* $(,)?) => {
+            $(
+                if let Ok(state) = state.cast::<$class>() {
+                    resolve_config::<$preset>(config.as_deref())?;
+                    let state = state.borrow();
+                    let inner = &state.inner;
+                    let discrepancies = py
+                        .detach(|| {
+                            let duties: JsonDataEnvelope<Vec<ProposerDuty>> =
+                                decode_json(claimed_duties_json)?;
+                            let expected = $epoch_proposers(inner, epoch)?;
+                            diff_duties::<$preset>(&inner.validators, &expected, epoch, duties.data)
+                        })
+                        .map_err(PyValueError::new_err)?;
+                    return discrepancies_to_py(py, discrepancies);
+                }
+            )*
+        };
+    }
+
+    try_states!(
+        electra_epoch_proposers:
+        PyBeaconStateMainnet => Mainnet,
+        PyBeaconStateMinimal => Minimal,
+        PyBeaconStateGnosis => Gnosis,
+    );
+
+    try_states!(
+        fulu_epoch_proposers:
+        PyFuluBeaconStateMainnet => Mainnet,
+        PyFuluBeaconStateMinimal => Minimal,
+        PyFuluBeaconStateGnosis => Gnosis,
+    );
+
+    Err(PyTypeError::new_err(format!(
+        "Expected a beacon state, got {}",
+        state.get_type().name()?
+    )))
+}
+
+fn discrepancies_to_py(py: Python<'_>, discrepancies: Vec<Discrepancy>) -> PyResult<Py<PyList>> {
+    let list = PyList::empty(py);
+
+    for discrepancy in discrepancies {
+        let d = PyDict::new(py);
+        d.set_item("slot", discrepancy.slot)?;
+        d.set_item("kind", discrepancy.kind)?;
+        d.set_item(
+            "expected_validator_index",
+            discrepancy.expected_validator_index,
+        )?;
+        d.set_item(
+            "claimed_validator_index",
+            discrepancy.claimed_validator_index,
+        )?;
+        list.append(d)?;
+    }
+
+    Ok(list.into())
+}
+
+/// Registers the proposer helpers with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(next_epoch_proposers, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_proposer_schedule, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,309 @@
+//! Validator pubkey to index lookups.
+//!
+//! Validators are only ever appended to a beacon state, in deposit order, so
+//! a map built from one state stays valid for every later state of the same
+//! chain. `PubkeyIndexMap` is built once from a state, extended with the
+//! validators added by later states or deposits, and saved to disk so it does
+//! not have to be rebuilt on startup.
+//!
+//! The file format is
+//!
+//! ```text
+//! map = "GPKI" count:u64 pubkey[count]
+//! ```
+//!
+//! with `count` little-endian and each pubkey 48 bytes, in index order.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use grandine_types::phase0::containers::Validator;
+use pyo3::exceptions::{PyIndexError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyString};
+
+use crate::electra::state::{PyBeaconStateGnosis, PyBeaconStateMainnet, PyBeaconStateMinimal};
+use crate::fulu::state::{
+    PyFuluBeaconStateGnosis, PyFuluBeaconStateMainnet, PyFuluBeaconStateMinimal,
+};
+
+const MAGIC: &[u8; 4] = b"GPKI";
+const HEADER_LEN: usize = 12;
+const PUBKEY_LEN: usize = 48;
+
+pub type Pubkey = [u8; PUBKEY_LEN];
+
+/// Parses a pubkey given as `bytes` or a `0x`-prefixed hex string, without
+/// checking that it is a valid point.
+pub fn parse_pubkey(value: &Bound<'_, PyAny>) -> PyResult<Pubkey> {
+    let bytes = if let Ok(bytes) = value.cast::<PyBytes>() {
+        bytes.as_bytes().to_vec()
+    } else if let Ok(string) = value.cast::<PyString>() {
+        hex::decode(string.to_str()?.trim_start_matches("0x"))
+            .map_err(|e| PyValueError::new_err(format!("Invalid pubkey hex: {e}")))?
+    } else {
+        return Err(PyTypeError::new_err("pubkey must be bytes or a hex string"));
+    };
+
+    let length = bytes.len();
+    bytes.try_into().map_err(|_| {
+        PyValueError::new_err(format!("pubkey must be {PUBKEY_LEN} bytes, got {length}"))
+    })
+}
+
+/// Maps validator pubkeys to validator indices.
+#[pyclass(name = "PubkeyIndexMap")]
+#[derive(Default)]
+pub struct PyPubkeyIndexMap {
+    pubkeys: Vec<Pubkey>,
+    indices: HashMap<Pubkey, u64>,
+}
+
+impl PyPubkeyIndexMap {
+    /// Adds a pubkey unless it is already mapped, returning its index.
+    fn insert(&mut self, pubkey: Pubkey) -> u64 {
+        let next = self.pubkeys.len() as u64;
+        let index = *self.indices.entry(pubkey).or_insert(next);
+        if index == next {
+            self.pubkeys.push(pubkey);
+        }
+        index
+    }
+
+    /// Returns the pubkey of validator `index`, if it is mapped.
+    pub(crate) fn pubkey(&self, index: u64) -> Option<&Pubkey> {
+        self.pubkeys.get(usize::try_from(index).ok()?)
+    }
+
+    /// Adds the validators of a state that are not mapped yet, returning how
+    /// many were added.
+    fn extend<'a>(
+        &mut self,
+        validators: impl IntoIterator<Item = &'a Validator>,
+    ) -> Result<usize, String> {
+        let known = self.pubkeys.len();
+        let mut count = 0;
+
+        for (index, validator) in validators.into_iter().enumerate() {
+            count = index + 1;
+            let pubkey = validator.pubkey.as_bytes();
+
+            // Validators are append-only, so checking the last mapped
+            // validator is enough to catch states of another chain.
+            if index + 1 == known && pubkey != self.pubkeys[index].as_slice() {
+                return Err(format!(
+                    "Validator {index} of the state does not match the map"
+                ));
+            }
+
+            if index >= known {
+                let pubkey = pubkey
+                    .try_into()
+                    .map_err(|_| format!("Invalid pubkey of validator {index}"))?;
+                self.insert(pubkey);
+            }
+        }
+
+        if count < known {
+            return Err(format!(
+                "State has {count} validators, but the map already has {known}"
+            ));
+        }
+
+        Ok(self.pubkeys.len() - known)
+    }
+
+    /// Adds the validators of any beacon state class.
+    fn extend_from_state(&mut self, py: Python<'_>, state: &Bound<'_, PyAny>) -> PyResult<usize> {
+        macro_rules! try_states {
+            ($($class:ty),* $(,)?) => {
+                $(
+                    if let Ok(state) = state.cast::<$class>() {
+                        let state = state.borrow();
+                        let validators = &state.inner.validators;
+                        return py
+                            .detach(|| self.extend(validators))
+                            .map_err(PyValueError::new_err);
+                    }
+                )*
+            };
+        }
+
+        try_states!(
+            PyBeaconStateMainnet,
+            PyBeaconStateMinimal,
+            PyBeaconStateGnosis,
+            PyFuluBeaconStateMainnet,
+            PyFuluBeaconStateMinimal,
+            PyFuluBeaconStateGnosis,
+        );
+
+        Err(PyTypeError::new_err(format!(
+            "Expected a beacon state, got {}",
+            state.get_type().name()?
+        )))
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let (header, pubkeys) = bytes
+            .split_at_checked(HEADER_LEN)
+            .filter(|(header, _)| header.starts_with(MAGIC))
+            .ok_or("Not a PubkeyIndexMap")?;
+
+        let count = u64::from_le_bytes(header[4..].try_into().map_err(|_| "Truncated header")?);
+
+        if Some(pubkeys.len())
+            != usize::try_from(count)
+                .ok()
+                .and_then(|c| c.checked_mul(PUBKEY_LEN))
+        {
+            return Err(format!(
+                "Expected {count} pubkeys, got {} bytes",
+                pubkeys.len()
+            ));
+        }
+
+        let mut map = Self::default();
+        map.pubkeys.reserve(pubkeys.len() / PUBKEY_LEN);
+        map.indices.reserve(pubkeys.len() / PUBKEY_LEN);
+
+        for (index, pubkey) in pubkeys.chunks_exact(PUBKEY_LEN).enumerate() {
+            let pubkey = pubkey.try_into().map_err(|_| "Truncated pubkey")?;
+            if map.insert(pubkey) != index as u64 {
+                return Err(format!("Duplicate pubkey at index {index}"));
+            }
+        }
+
+        Ok(map)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(HEADER_LEN + self.pubkeys.len() * PUBKEY_LEN);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&(self.pubkeys.len() as u64).to_le_bytes());
+        for pubkey in &self.pubkeys {
+            out.extend_from_slice(pubkey);
+        }
+        out
+    }
+}
+
+#[pymethods]
+impl PyPubkeyIndexMap {
+    #[new]
+    fn py_new() -> Self {
+        Self::default()
+    }
+
+    /// Build a map from the validators of a beacon state.
+    ///
+    /// # Errors
+    /// Returns `PyTypeError` if `state` is not a beacon state.
+    #[staticmethod]
+    fn from_state(py: Python<'_>, state: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let mut map = Self::default();
+        map.extend_from_state(py, state)?;
+        Ok(map)
+    }
+
+    /// Add the validators of a later state of the same chain, returning how
+    /// many were added.
+    ///
+    /// # Errors
+    /// Returns `PyTypeError` if `state` is not a beacon state, or
+    /// `PyValueError` if it has fewer validators than the map or belongs to
+    /// another chain.
+    fn update(&mut self, py: Python<'_>, state: &Bound<'_, PyAny>) -> PyResult<usize> {
+        self.extend_from_state(py, state)
+    }
+
+    /// Add the pubkey of a processed deposit, returning its validator index.
+    ///
+    /// Deposits for a known pubkey top up the existing validator, so its
+    /// index is returned unchanged. Deposits must be added in the order the
+    /// state processes them.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `pubkey` is not 48 bytes.
+    fn add(&mut self, pubkey: &Bound<'_, PyAny>) -> PyResult<u64> {
+        Ok(self.insert(parse_pubkey(pubkey)?))
+    }
+
+    /// Return the validator index of `pubkey`, or `None` if it is unknown.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `pubkey` is not 48 bytes.
+    fn index_of(&self, pubkey: &Bound<'_, PyAny>) -> PyResult<Option<u64>> {
+        Ok(self.indices.get(&parse_pubkey(pubkey)?).copied())
+    }
+
+    /// Return the pubkey of validator `index` as a hex string.
+    ///
+    /// # Errors
+    /// Returns `PyIndexError` if `index` is not mapped.
+    fn pubkey_at(&self, index: usize) -> PyResult<String> {
+        self.pubkeys
+            .get(index)
+            .map(|pubkey| crate::hex_format::format_hex(pubkey))
+            .ok_or_else(|| PyIndexError::new_err(format!("Validator index {index} out of range")))
+    }
+
+    /// Serialize the map to bytes.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.encode())
+    }
+
+    /// Deserialize a map produced by `to_bytes`.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if the data is not a valid map.
+    #[staticmethod]
+    fn from_bytes(py: Python<'_>, data: &Bound<'_, PyBytes>) -> PyResult<Self> {
+        let bytes = data.as_bytes();
+        py.detach(|| Self::decode(bytes))
+            .map_err(PyValueError::new_err)
+    }
+
+    /// Write the map to a file.
+    ///
+    /// # Errors
+    /// Returns `OSError` if the file cannot be written.
+    fn save(&self, py: Python<'_>, path: PathBuf) -> PyResult<()> {
+        py.detach(|| std::fs::write(path, self.encode()))?;
+        Ok(())
+    }
+
+    /// Read a map written by `save`.
+    ///
+    /// # Errors
+    /// Returns `OSError` if the file cannot be read, or `PyValueError` if it
+    /// is not a valid map.
+    #[staticmethod]
+    fn load(py: Python<'_>, path: PathBuf) -> PyResult<Self> {
+        let bytes = py.detach(|| std::fs::read(path))?;
+        py.detach(|| Self::decode(&bytes))
+            .map_err(PyValueError::new_err)
+    }
+
+    fn __len__(&self) -> usize {
+        self.pubkeys.len()
+    }
+
+    fn __contains__(&self, pubkey: &Bound<'_, PyAny>) -> PyResult<bool> {
+        Ok(self.indices.contains_key(&parse_pubkey(pubkey)?))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PubkeyIndexMap(len={})", self.pubkeys.len())
+    }
+}
+
+/// Registers the pubkey index map class with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if class registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyPubkeyIndexMap>()?;
+    Ok(())
+}
@@ -0,0 +1,448 @@
+//! Local pre-publication checks mirroring Beacon API `broadcast_validation`.
+//!
+//! `POST /eth/v2/beacon/blocks` accepts a `broadcast_validation` level and
+//! rejects blocks failing it. `validate_for_publish` runs the checks of each
+//! level that can be decided from the block and its pre-state alone, so
+//! proposers can catch mistakes before submitting:
+//!
+//! - `gossip`: the `beacon_block` gossip checks: the slot is after the
+//!   parent's, the proposer is the expected one for the slot, the proposer
+//!   signature is valid, the execution payload timestamp matches the slot, and
+//!   block contents carry one blob and proof per commitment.
+//! - `consensus`: additionally the `process_block` checks that only read the
+//!   pre-state: the parent root, the proposer not being slashed, the RANDAO
+//!   reveal, and the payload's parent hash and `prev_randao`.
+//! - `consensus_and_equivocation`: additionally that none of the caller's
+//!   already seen headers is another block by the same proposer for the slot.
+//!
+//! The state transition itself is not run, so the state root, operations and
+//! KZG proofs are not verified. The pre-state must be in the block's epoch or
+//! the one before it, as required to determine the proposer.
+
+use grandine_bls::{PublicKey, PublicKeyBytes, SignatureBytes};
+use grandine_ssz::{SszHash as _, H256};
+use grandine_types::electra::containers::SignedBeaconBlock;
+use grandine_types::phase0::containers::{BeaconBlockHeader, Fork, SignedBeaconBlockHeader};
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use typenum::Unsigned as _;
+
+use crate::bls::verify;
+use crate::config::{resolve_config, PyConfig};
+use crate::convert::format_hash_tree_root;
+use crate::domains::{compute_domain, DOMAIN_BEACON_PROPOSER, DOMAIN_RANDAO};
+use crate::electra::block::{
+    PySignedBeaconBlockContentsGnosis, PySignedBeaconBlockContentsMainnet,
+    PySignedBeaconBlockContentsMinimal, PySignedBeaconBlockGnosis, PySignedBeaconBlockMainnet,
+    PySignedBeaconBlockMinimal,
+};
+use crate::electra::state::{PyBeaconStateGnosis, PyBeaconStateMainnet, PyBeaconStateMinimal};
+use crate::fulu::state::{
+    PyFuluBeaconStateGnosis, PyFuluBeaconStateMainnet, PyFuluBeaconStateMinimal,
+};
+use crate::phase0::header::PySignedBeaconBlockHeader;
+use crate::presets::{NamedPreset, PresetKind};
+use crate::proposers::{electra_epoch_proposers, fulu_epoch_proposers};
+use crate::signing::signing_root;
+use crate::Gnosis;
+
+/// A `broadcast_validation` level.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Level {
+    Gossip,
+    Consensus,
+    ConsensusAndEquivocation,
+}
+
+impl Level {
+    fn parse(level: &str) -> PyResult<Self> {
+        match level {
+            "gossip" => Ok(Self::Gossip),
+            "consensus" => Ok(Self::Consensus),
+            "consensus_and_equivocation" => Ok(Self::ConsensusAndEquivocation),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown broadcast validation level {level:?} \
+                 (expected gossip, consensus or consensus_and_equivocation)"
+            ))),
+        }
+    }
+}
+
+/// The parts of a pre-state the checks read.
+struct PreState {
+    preset: PresetKind,
+    slot: u64,
+    genesis_time: u64,
+    fork: Fork,
+    genesis_validators_root: H256,
+    latest_block_header: BeaconBlockHeader,
+    latest_block_hash: H256,
+    /// `get_randao_mix` of the block's epoch, which equals the state's
+    /// current mix until the epoch is processed.
+    randao_mix: H256,
+    /// Proposers of the block's epoch, or why they cannot be determined.
+    proposers: Result<Vec<u64>, String>,
+    /// Pubkey and slashing status of the block's claimed proposer.
+    proposer: Option<(PublicKeyBytes, bool)>,
+}
+
+impl PreState {
+    /// Reads the pre-state fields for a block at `slot` by `proposer_index`.
+    fn read(state: &Bound<'_, PyAny>, slot: u64, proposer_index: u64) -> PyResult<Self> {
+        macro_rules! try_states {
+            ($epoch_proposers:ident: $($class:ty => $preset:ty),* $(,)?) => {
+                $(
+                    if let Ok(state) = state.cast::<$class>() {
+                        let state = state.borrow();
+                        let inner = &state.inner;
+                        let epoch = slot / <$preset as Preset>::SlotsPerEpoch::U64;
+                        let state_epoch = inner.slot / <$preset as Preset>::SlotsPerEpoch::U64;
+
+                        let mut latest_block_header = inner.latest_block_header;
+                        if latest_block_header.state_root.is_zero() {
+                            latest_block_header.state_root = inner.hash_tree_root();
+                        }
+
+                        let proposer = usize::try_from(proposer_index)
+                            .ok()
+                            .and_then(|index| (&inner.validators).into_iter().nth(index))
+                            .map(|validator| (validator.pubkey, validator.slashed));
+
+                        return Ok(Self {
+                            preset: <$preset as NamedPreset>::KIND,
+                            slot: inner.slot,
+                            genesis_time: inner.genesis_time,
+                            fork: inner.fork,
+                            genesis_validators_root: inner.genesis_validators_root,
+                            latest_block_header,
+                            latest_block_hash: inner.latest_execution_payload_header.block_hash,
+                            randao_mix: *inner.randao_mixes.mod_index(state_epoch),
+                            proposers: $epoch_proposers(inner, epoch),
+                            proposer,
+                        });
+                    }
+                )*
+            };
+        }
+
+        try_states!(
+            electra_epoch_proposers:
+            PyBeaconStateMainnet => Mainnet,
+            PyBeaconStateMinimal => Minimal,
+            PyBeaconStateGnosis => Gnosis,
+        );
+
+        try_states!(
+            fulu_epoch_proposers:
+            PyFuluBeaconStateMainnet => Mainnet,
+            PyFuluBeaconStateMinimal => Minimal,
+            PyFuluBeaconStateGnosis => Gnosis,
+        );
+
+        Err(PyTypeError::new_err(format!(
+            "state_provider must be a beacon state or return one, got {}",
+            state.get_type().name()?
+        )))
+    }
+
+    /// Computes the domain of `domain_type` for `epoch` from the state's fork.
+    fn domain(&self, domain_type: [u8; 4], epoch: u64) -> H256 {
+        let version = if epoch < self.fork.epoch {
+            self.fork.previous_version
+        } else {
+            self.fork.current_version
+        };
+
+        compute_domain(domain_type, version, self.genesis_validators_root)
+    }
+}
+
+/// Verifies a signature by a validator whose pubkey may not decompress.
+fn verify_bytes(signature: SignatureBytes, message: H256, pubkey: PublicKeyBytes) -> bool {
+    PublicKey::try_from(pubkey).is_ok_and(|pubkey| verify(signature, message, &pubkey))
+}
+
+/// Runs the checks of `level` on a signed block and returns the failures.
+///
+/// `blobs` holds the numbers of blobs and KZG proofs for block contents.
+fn check_block<P: Preset>(
+    signed_block: &SignedBeaconBlock<P>,
+    blobs: Option<(usize, usize)>,
+    state: &PreState,
+    seconds_per_slot: u64,
+    level: Level,
+    seen_headers: &[SignedBeaconBlockHeader],
+) -> Vec<String> {
+    let block = &signed_block.message;
+    let body = &block.body;
+    let payload = &body.execution_payload;
+    let epoch = block.slot / P::SlotsPerEpoch::U64;
+    let mut failures = Vec::new();
+
+    // Gossip checks.
+    if block.slot <= state.latest_block_header.slot {
+        failures.push(format!(
+            "Block slot {} is not after the parent block slot {}",
+            block.slot, state.latest_block_header.slot
+        ));
+    }
+
+    match &state.proposers {
+        Ok(proposers) => {
+            #[allow(clippy::cast_possible_truncation)]
+            let expected = proposers[(block.slot % P::SlotsPerEpoch::U64) as usize];
+            if block.proposer_index != expected {
+                failures.push(format!(
+                    "Proposer {} is not the expected proposer {expected} of slot {}",
+                    block.proposer_index, block.slot
+                ));
+            }
+        }
+        Err(error) => failures.push(format!("Cannot determine the proposer: {error}")),
+    }
+
+    match state.proposer {
+        Some((pubkey, slashed)) => {
+            let domain = state.domain(DOMAIN_BEACON_PROPOSER, epoch);
+            let root = signing_root(block.hash_tree_root(), domain);
+            if !verify_bytes(signed_block.signature, root, pubkey) {
+                failures.push("Invalid proposer signature".to_owned());
+            }
+
+            if level >= Level::Consensus {
+                if slashed {
+                    failures.push(format!("Proposer {} is slashed", block.proposer_index));
+                }
+
+                let domain = state.domain(DOMAIN_RANDAO, epoch);
+                let root = signing_root(epoch.hash_tree_root(), domain);
+                if !verify_bytes(body.randao_reveal, root, pubkey) {
+                    failures.push("Invalid RANDAO reveal".to_owned());
+                }
+            }
+        }
+        None => failures.push(format!(
+            "Proposer {} is not in the validator registry",
+            block.proposer_index
+        )),
+    }
+
+    let timestamp = state.genesis_time + block.slot * seconds_per_slot;
+    if payload.timestamp != timestamp {
+        failures.push(format!(
+            "Execution payload timestamp {} does not match slot timestamp {timestamp}",
+            payload.timestamp
+        ));
+    }
+
+    if let Some((blob_count, proof_count)) = blobs {
+        let commitments = body.blob_kzg_commitments.len();
+        if blob_count != commitments || proof_count != commitments {
+            failures.push(format!(
+                "Expected {commitments} blobs and proofs, got {blob_count} blobs and \
+                 {proof_count} proofs"
+            ));
+        }
+    }
+
+    if level == Level::Gossip {
+        return failures;
+    }
+
+    // Consensus checks.
+    let parent_root = state.latest_block_header.hash_tree_root();
+    if block.parent_root != parent_root {
+        failures.push(format!(
+            "Parent root {} does not match the pre-state's latest block {}",
+            format_hash_tree_root(&block.parent_root),
+            format_hash_tree_root(&parent_root)
+        ));
+    }
+
+    if payload.parent_hash != state.latest_block_hash {
+        failures.push(format!(
+            "Execution payload parent hash {} does not match the pre-state's {}",
+            format_hash_tree_root(&payload.parent_hash),
+            format_hash_tree_root(&state.latest_block_hash)
+        ));
+    }
+
+    if payload.prev_randao != state.randao_mix {
+        failures.push(format!(
+            "Execution payload prev_randao {} does not match the RANDAO mix {}",
+            format_hash_tree_root(&payload.prev_randao),
+            format_hash_tree_root(&state.randao_mix)
+        ));
+    }
+
+    if level == Level::Consensus {
+        return failures;
+    }
+
+    // Equivocation checks.
+    let block_root = block.hash_tree_root();
+    for header in seen_headers {
+        let seen = &header.message;
+        if seen.slot == block.slot
+            && seen.proposer_index == block.proposer_index
+            && seen.hash_tree_root() != block_root
+        {
+            failures.push(format!(
+                "Proposer {} already proposed block {} for slot {}",
+                block.proposer_index,
+                format_hash_tree_root(&seen.hash_tree_root()),
+                block.slot
+            ));
+        }
+    }
+
+    failures
+}
+
+/// Resolves the pre-state and runs the checks for a signed block.
+fn validate<P: NamedPreset>(
+    py: Python<'_>,
+    signed_block: &SignedBeaconBlock<P>,
+    blobs: Option<(usize, usize)>,
+    state_provider: &Bound<'_, PyAny>,
+    level: Level,
+    seen_headers: &[SignedBeaconBlockHeader],
+    config: Option<&PyConfig>,
+) -> PyResult<Vec<String>> {
+    let config = resolve_config::<P>(config)?;
+    let block = &signed_block.message;
+
+    let state = if state_provider.is_callable() {
+        state_provider.call1((format_hash_tree_root(&block.parent_root),))?
+    } else {
+        state_provider.clone()
+    };
+
+    let state = PreState::read(&state, block.slot, block.proposer_index)?;
+
+    if state.preset != P::KIND {
+        return Err(PyValueError::new_err(format!(
+            "Pre-state uses the {} preset, expected {}",
+            state.preset.name(),
+            P::KIND.name()
+        )));
+    }
+
+    if state.slot > block.slot {
+        return Err(PyValueError::new_err(format!(
+            "Pre-state slot {} is after block slot {}",
+            state.slot, block.slot
+        )));
+    }
+
+    let seconds_per_slot = config.seconds_per_slot;
+    Ok(py.detach(|| {
+        check_block(
+            signed_block,
+            blobs,
+            &state,
+            seconds_per_slot,
+            level,
+            seen_headers,
+        )
+    }))
+}
+
+/// Check a signed block or signed block contents against a Beacon API
+/// `broadcast_validation` level before publishing it.
+///
+/// `state_provider` is the block's pre-state, or a callable returning it
+/// for the block's parent root as a hex string. `seen_headers` are headers
+/// of blocks already seen, for the `consensus_and_equivocation` level.
+/// Returns the failed checks as messages; an empty list means the block
+/// passes. See the module documentation for the checks of each level.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if `contents` or the pre-state have an unsupported
+/// type, or `PyValueError` if `level` is unknown, the pre-state is for a
+/// different preset or after the block, or `config` belongs to a different
+/// preset.
+#[pyfunction]
+#[pyo3(signature = (contents, state_provider, level = "gossip", seen_headers = None, config = None))]
+pub fn validate_for_publish(
+    py: Python<'_>,
+    contents: &Bound<'_, PyAny>,
+    state_provider: &Bound<'_, PyAny>,
+    level: &str,
+    seen_headers: Option<Vec<PyRef<'_, PySignedBeaconBlockHeader>>>,
+    config: Option<PyRef<'_, PyConfig>>,
+) -> PyResult<Vec<String>> {
+    let level = Level::parse(level)?;
+    let seen_headers = seen_headers
+        .unwrap_or_default()
+        .iter()
+        .map(|header| header.inner)
+        .collect::<Vec<_>>();
+
+    macro_rules! try_blocks {
+        ($($class:ty => $preset:ty),* $(,)?) => {
+            $(
+                if let Ok(block) = contents.cast::<$class>() {
+                    return validate::<$preset>(
+                        py,
+                        &block.borrow().inner,
+                        None,
+                        state_provider,
+                        level,
+                        &seen_headers,
+                        config.as_deref(),
+                    );
+                }
+            )*
+        };
+    }
+
+    macro_rules! try_contents {
+        ($($class:ty => $preset:ty),* $(,)?) => {
+            $(
+                if let Ok(contents) = contents.cast::<$class>() {
+                    let contents = contents.borrow();
+                    let inner = &contents.inner;
+                    return validate::<$preset>(
+                        py,
+                        &inner.signed_block,
+                        Some((inner.blobs.len(), inner.kzg_proofs.len())),
+                        state_provider,
+                        level,
+                        &seen_headers,
+                        config.as_deref(),
+                    );
+                }
+            )*
+        };
+    }
+
+    try_blocks!(
+        PySignedBeaconBlockMainnet => Mainnet,
+        PySignedBeaconBlockMinimal => Minimal,
+        PySignedBeaconBlockGnosis => Gnosis,
+    );
+
+    try_contents!(
+        PySignedBeaconBlockContentsMainnet => Mainnet,
+        PySignedBeaconBlockContentsMinimal => Minimal,
+        PySignedBeaconBlockContentsGnosis => Gnosis,
+    );
+
+    Err(PyTypeError::new_err(format!(
+        "Expected a signed block or signed block contents, got {}",
+        contents.get_type().name()?
+    )))
+}
+
+/// Registers the publish validation function with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(validate_for_publish, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,629 @@
+//! Path and filter expressions evaluated over decoded objects.
+//!
+//! `extract` evaluates a small JMESPath-like expression against the JSON form
+//! of each object (the same structure `to_json` produces), so bulk extraction
+//! does not need a Python loop over nested dicts. Expressions see hex strings
+//! in the default format whatever `set_hex_format` was given, so hex values
+//! are never mistaken for integers; the format is applied to the results.
+//!
+//! # Expression language
+//!
+//! - `a.b.c` selects nested fields. A missing field yields `None`.
+//! - `[2]` selects a list element; negative indices count from the end.
+//! - `[*]` projects the rest of the expression over every list element, and
+//!   `.*` over every value of a container. Elements for which the rest of the
+//!   expression yields `None` are dropped.
+//! - `[]` flattens one level of nested lists and then projects like `[*]`.
+//! - `[?filter]` projects over the elements for which `filter` holds. Filters
+//!   compare paths relative to the element (`@` is the element itself) with
+//!   literals using `==`, `!=`, `<`, `<=`, `>` and `>=`, and combine them with
+//!   `&&`, `||`, `!` and parentheses. A path on its own tests whether the value
+//!   is present and non-empty.
+//! - Literals are integers, `'single-quoted'` strings, `true`, `false` and
+//!   `null`. Decimal strings compare as integers, so `[?data.index == 3]`
+//!   matches the `"3"` used by the JSON encoding.
+//!
+//! Projections nest: `body.attestations[*].committee_bits` yields a list per
+//! object, and a second projection inside it yields a list of lists.
+//!
+//! `extract_columns` evaluates several expressions per block in one pass and
+//! returns integer columns as buffers that numpy can wrap without copying.
+
+use std::cmp::Ordering;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+use serde_json::Value;
+
+use crate::convert::{json_to_py, DictStyle, UINT256_FIELDS};
+
+#[derive(Debug)]
+enum Step {
+    Field(String),
+    Index(i64),
+    Wildcard,
+    Flatten,
+    Filter(Filter),
+}
+
+#[derive(Debug)]
+enum Filter {
+    Or(Box<Filter>, Box<Filter>),
+    And(Box<Filter>, Box<Filter>),
+    Not(Box<Filter>),
+    Compare(Operand, Comparison, Operand),
+    Truthy(Operand),
+}
+
+#[derive(Debug)]
+enum Operand {
+    Path(Vec<Step>),
+    Literal(Value),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    const fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Eq => ordering.is_eq(),
+            Self::Ne => ordering.is_ne(),
+            Self::Lt => ordering.is_lt(),
+            Self::Le => ordering.is_le(),
+            Self::Gt => ordering.is_gt(),
+            Self::Ge => ordering.is_ge(),
+        }
+    }
+}
+
+/// A parsed expression.
+#[derive(Debug)]
+pub struct Expression {
+    steps: Vec<Step>,
+}
+
+impl Expression {
+    /// Parses an expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string describing the first syntax error.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut parser = Parser {
+            source,
+            position: 0,
+        };
+
+        let steps = parser.path()?;
+        parser.skip_whitespace();
+
+        match parser.peek() {
+            None => Ok(Self { steps }),
+            Some(character) => Err(parser.error(&format!("unexpected {character:?}"))),
+        }
+    }
+
+    /// Evaluates the expression against a JSON value.
+    #[must_use]
+    pub fn evaluate(&self, value: &Value) -> Value {
+        evaluate(&self.steps, value)
+    }
+
+    /// Name of the last field the expression selects, if any.
+    fn last_field(&self) -> Option<&str> {
+        self.steps.iter().rev().find_map(|step| match step {
+            Step::Field(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> String {
+        format!("{message} at position {}", self.position)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let character = self.peek()?;
+        self.position += character.len_utf8();
+        Some(character)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+
+        if self.source[self.position..].starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected {token:?}")))
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &str {
+        let start = self.position;
+        while self.peek().is_some_and(&predicate) {
+            self.bump();
+        }
+        &self.source[start..self.position]
+    }
+
+    fn identifier(&mut self) -> Result<String, String> {
+        self.skip_whitespace();
+
+        if !self
+            .peek()
+            .is_some_and(|character| character.is_ascii_alphabetic() || character == '_')
+        {
+            return Err(self.error("expected a field name"));
+        }
+
+        Ok(self
+            .take_while(|character| character.is_ascii_alphanumeric() || character == '_')
+            .to_owned())
+    }
+
+    fn integer(&mut self) -> Result<i64, String> {
+        self.skip_whitespace();
+        let start = self.position;
+
+        if self.peek() == Some('-') {
+            self.bump();
+        }
+        self.take_while(|character| character.is_ascii_digit());
+
+        self.source[start..self.position]
+            .parse()
+            .map_err(|_| self.error("expected an integer"))
+    }
+
+    /// Parses `field`, `@` or a bracket, followed by any number of `.field`,
+    /// `.*` and brackets.
+    fn path(&mut self) -> Result<Vec<Step>, String> {
+        let mut steps = Vec::new();
+        self.skip_whitespace();
+
+        // `@` is the current value itself and adds no step.
+        if !self.eat("@") {
+            if self.eat("*") {
+                steps.push(Step::Wildcard);
+            } else if self.peek() != Some('[') {
+                steps.push(Step::Field(self.identifier()?));
+            }
+        }
+
+        loop {
+            if self.eat(".") {
+                if self.eat("*") {
+                    steps.push(Step::Wildcard);
+                } else {
+                    steps.push(Step::Field(self.identifier()?));
+                }
+            } else if self.eat("[") {
+                steps.push(self.bracket()?);
+            } else {
+                return Ok(steps);
+            }
+        }
+    }
+
+    /// Parses the contents of a bracket after the opening `[`.
+    fn bracket(&mut self) -> Result<Step, String> {
+        if self.eat("]") {
+            return Ok(Step::Flatten);
+        }
+
+        let step = if self.eat("*") {
+            Step::Wildcard
+        } else if self.eat("?") {
+            Step::Filter(self.or()?)
+        } else {
+            Step::Index(self.integer()?)
+        };
+
+        self.expect("]")?;
+        Ok(step)
+    }
+
+    fn or(&mut self) -> Result<Filter, String> {
+        let mut filter = self.and()?;
+        while self.eat("||") {
+            filter = Filter::Or(Box::new(filter), Box::new(self.and()?));
+        }
+        Ok(filter)
+    }
+
+    fn and(&mut self) -> Result<Filter, String> {
+        let mut filter = self.not()?;
+        while self.eat("&&") {
+            filter = Filter::And(Box::new(filter), Box::new(self.not()?));
+        }
+        Ok(filter)
+    }
+
+    fn not(&mut self) -> Result<Filter, String> {
+        if self.eat("!") {
+            return Ok(Filter::Not(Box::new(self.not()?)));
+        }
+
+        if self.eat("(") {
+            let filter = self.or()?;
+            self.expect(")")?;
+            return Ok(filter);
+        }
+
+        let left = self.operand()?;
+
+        // Two-character operators must be tried before their prefixes.
+        let comparison = [
+            ("==", Comparison::Eq),
+            ("!=", Comparison::Ne),
+            ("<=", Comparison::Le),
+            (">=", Comparison::Ge),
+            ("<", Comparison::Lt),
+            (">", Comparison::Gt),
+        ]
+        .into_iter()
+        .find_map(|(token, comparison)| self.eat(token).then_some(comparison));
+
+        Ok(match comparison {
+            Some(comparison) => Filter::Compare(left, comparison, self.operand()?),
+            None => Filter::Truthy(left),
+        })
+    }
+
+    fn operand(&mut self) -> Result<Operand, String> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('\'') => {
+                self.bump();
+                let string = self.take_while(|character| character != '\'').to_owned();
+                self.expect("'")?;
+                Ok(Operand::Literal(Value::String(string)))
+            }
+            Some(character) if character == '-' || character.is_ascii_digit() => {
+                Ok(Operand::Literal(Value::from(self.integer()?)))
+            }
+            _ => {
+                let start = self.position;
+                let literal = match self.identifier() {
+                    Ok(word) if word == "true" => Some(Value::Bool(true)),
+                    Ok(word) if word == "false" => Some(Value::Bool(false)),
+                    Ok(word) if word == "null" => Some(Value::Null),
+                    _ => None,
+                };
+
+                if let Some(literal) = literal {
+                    return Ok(Operand::Literal(literal));
+                }
+
+                self.position = start;
+                self.path().map(Operand::Path)
+            }
+        }
+    }
+}
+
+fn evaluate(steps: &[Step], value: &Value) -> Value {
+    let Some((step, rest)) = steps.split_first() else {
+        return value.clone();
+    };
+
+    match (step, value) {
+        (Step::Field(name), Value::Object(map)) => map
+            .get(name)
+            .map_or(Value::Null, |item| evaluate(rest, item)),
+        (Step::Index(index), Value::Array(items)) => {
+            let index = if *index < 0 {
+                usize::try_from(index.unsigned_abs())
+                    .ok()
+                    .and_then(|offset| items.len().checked_sub(offset))
+            } else {
+                usize::try_from(*index).ok()
+            };
+
+            index
+                .and_then(|index| items.get(index))
+                .map_or(Value::Null, |item| evaluate(rest, item))
+        }
+        (Step::Wildcard, Value::Array(items)) => project(items.iter(), rest),
+        (Step::Wildcard, Value::Object(map)) => project(map.values(), rest),
+        (Step::Flatten, Value::Array(items)) => project(
+            items.iter().flat_map(|item| match item {
+                Value::Array(inner) => inner.iter().collect(),
+                _ => vec![item],
+            }),
+            rest,
+        ),
+        (Step::Filter(filter), Value::Array(items)) => {
+            project(items.iter().filter(|item| filter.matches(item)), rest)
+        }
+        _ => Value::Null,
+    }
+}
+
+fn project<'a>(items: impl Iterator<Item = &'a Value>, rest: &[Step]) -> Value {
+    Value::Array(
+        items
+            .map(|item| evaluate(rest, item))
+            .filter(|item| !item.is_null())
+            .collect(),
+    )
+}
+
+impl Filter {
+    fn matches(&self, value: &Value) -> bool {
+        match self {
+            Self::Or(left, right) => left.matches(value) || right.matches(value),
+            Self::And(left, right) => left.matches(value) && right.matches(value),
+            Self::Not(filter) => !filter.matches(value),
+            Self::Compare(left, comparison, right) => {
+                compare(&left.resolve(value), *comparison, &right.resolve(value))
+            }
+            Self::Truthy(operand) => match operand.resolve(value) {
+                Value::Null | Value::Bool(false) => false,
+                Value::String(string) => !string.is_empty(),
+                Value::Array(items) => !items.is_empty(),
+                Value::Object(map) => !map.is_empty(),
+                Value::Bool(true) | Value::Number(_) => true,
+            },
+        }
+    }
+}
+
+impl Operand {
+    fn resolve(&self, value: &Value) -> Value {
+        match self {
+            Self::Path(steps) => evaluate(steps, value),
+            Self::Literal(literal) => literal.clone(),
+        }
+    }
+}
+
+/// Interprets numbers and decimal strings as integers.
+fn integer(value: &Value) -> Option<i128> {
+    match value {
+        Value::Number(number) => number
+            .as_u64()
+            .map(i128::from)
+            .or_else(|| number.as_i64().map(i128::from)),
+        Value::String(string) if string.bytes().all(|byte| byte.is_ascii_digit()) => {
+            string.parse().ok()
+        }
+        _ => None,
+    }
+}
+
+fn compare(left: &Value, comparison: Comparison, right: &Value) -> bool {
+    if let (Some(left), Some(right)) = (integer(left), integer(right)) {
+        return comparison.holds(left.cmp(&right));
+    }
+
+    match (comparison, left, right) {
+        (Comparison::Eq, _, _) => left == right,
+        (Comparison::Ne, _, _) => left != right,
+        (_, Value::String(left), Value::String(right)) => comparison.holds(left.cmp(right)),
+        _ => false,
+    }
+}
+
+/// Returns the block inside a signed block or block contents, if `value` is
+/// one.
+fn unwrap_block(value: &Value) -> Option<&Value> {
+    let mut block = value;
+
+    for key in ["signed_block", "block", "message"] {
+        if let Some(inner) = block.get(key) {
+            block = inner;
+        }
+    }
+
+    (!std::ptr::eq(block, value)).then_some(block)
+}
+
+/// Evaluates expressions against an object's JSON form with the GIL released.
+///
+/// The JSON form is that of `to_json_canonical`, which unlike `to_json` does
+/// not follow `set_hex_format`. It is serialized once per object and shared
+/// by all expressions: directly to a value for the classes `envelope::wrap`
+/// accepts, and through `to_json_canonical` for other classes.
+///
+/// With `unwrap` set, an expression yielding `None` is retried against the
+/// block inside a signed block or block contents.
+fn evaluate_object(
+    py: Python<'_>,
+    object: &Bound<'_, PyAny>,
+    expressions: &[Expression],
+    unwrap: bool,
+) -> PyResult<Vec<Value>> {
+    let value = match crate::envelope::json_value(object) {
+        Some(value) => value.map_err(PyValueError::new_err)?,
+        None => canonical_json_value(py, object)?,
+    };
+
+    py.detach(|| {
+        let block = unwrap.then(|| unwrap_block(&value)).flatten();
+
+        expressions
+            .iter()
+            .map(|expression| match (expression.evaluate(&value), block) {
+                (Value::Null, Some(block)) => expression.evaluate(block),
+                (result, _) => result,
+            })
+            .collect()
+    })
+}
+
+/// Parses the output of an object's `to_json_canonical` method.
+fn canonical_json_value(py: Python<'_>, object: &Bound<'_, PyAny>) -> PyResult<Value> {
+    let json = match object.call_method0("to_json_canonical") {
+        Ok(json) => json.cast_into::<PyBytes>().ok(),
+        Err(_) => None,
+    };
+    let Some(json) = json else {
+        return Err(PyTypeError::new_err(format!(
+            "Expected an object with a to_json_canonical method, got {}",
+            object.get_type().name()?
+        )));
+    };
+
+    let bytes = json.as_bytes();
+    py.detach(|| serde_json::from_slice(bytes))
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+fn parse_expression(source: &str) -> PyResult<Expression> {
+    Expression::parse(source)
+        .map_err(|e| PyValueError::new_err(format!("Invalid expression {source:?}: {e}")))
+}
+
+/// Evaluate an expression against each object and return the results.
+///
+/// `objects` is an iterable of objects with a `to_json` method, such as
+/// decoded blocks or attestations. The expression is evaluated against the
+/// object's JSON form, so paths start below any `{"data": ...}` envelope: a
+/// signed block's slot is `message.slot`. See the `query` module for the
+/// expression language. Results are converted as in `to_pyspec_dict`, so
+/// 64-bit integers are Python ints and hex strings follow `set_hex_format`;
+/// a path that does not exist yields `None`. Filters compare hex strings in
+/// the default format, lowercase with a `0x` prefix.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the expression is invalid, or `PyTypeError` if
+/// an object cannot be serialized to JSON.
+#[pyfunction]
+pub fn extract<'py>(
+    py: Python<'py>,
+    objects: &Bound<'py, PyAny>,
+    expression: &str,
+) -> PyResult<Vec<Bound<'py, PyAny>>> {
+    let expressions = [parse_expression(expression)?];
+    let field = expressions[0].last_field();
+
+    objects
+        .try_iter()?
+        .map(|object| {
+            let mut results = evaluate_object(py, &object?, &expressions, false)?;
+            json_to_py(py, &results.remove(0), DictStyle::Pyspec, field)
+        })
+        .collect()
+}
+
+/// Converts a column to an `array.array("Q")` if every value is a 64-bit
+/// unsigned integer, or to a list otherwise.
+fn column_to_py<'py>(
+    py: Python<'py>,
+    values: &[Value],
+    field: Option<&str>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let integers = values
+        .iter()
+        .map(|value| integer(value).and_then(|integer| u64::try_from(integer).ok()))
+        .collect::<Option<Vec<u64>>>();
+
+    // Columns of wide integers such as `base_fee_per_gas` stay lists of
+    // strings, matching `to_pyspec_dict`.
+    let wide = field.is_some_and(|field| UINT256_FIELDS.contains(&field));
+
+    match integers {
+        Some(integers) if !wide => {
+            let bytes = integers
+                .iter()
+                .flat_map(|integer| integer.to_ne_bytes())
+                .collect::<Vec<_>>();
+
+            py.import("array")?
+                .getattr("array")?
+                .call1(("Q", PyBytes::new(py, &bytes)))
+        }
+        _ => json_to_py(py, &Value::Array(values.to_vec()), DictStyle::Pyspec, field),
+    }
+}
+
+/// Extract several columns from many blocks in a single pass.
+///
+/// Returns a dict mapping each column expression to its values, one per
+/// block. Columns whose values are all 64-bit unsigned integers are returned
+/// as `array.array("Q")`, which `numpy.asarray` and `numpy.frombuffer` accept
+/// without copying; other columns are lists converted as in `extract`.
+///
+/// Expressions use the language of `extract`. For signed blocks and block
+/// contents, an expression that does not match the outer object is
+/// evaluated against the inner block, so `slot` and
+/// `body.execution_payload.gas_used` work for every block class.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if an expression is invalid, or `PyTypeError` if a
+/// block cannot be serialized to JSON.
+#[pyfunction]
+pub fn extract_columns<'py>(
+    py: Python<'py>,
+    blocks: &Bound<'py, PyAny>,
+    columns: Vec<String>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let expressions = columns
+        .iter()
+        .map(|column| parse_expression(column))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let mut values = vec![Vec::new(); expressions.len()];
+
+    for block in blocks.try_iter()? {
+        let results = evaluate_object(py, &block?, &expressions, true)?;
+        for (column, result) in values.iter_mut().zip(results) {
+            column.push(result);
+        }
+    }
+
+    let result = PyDict::new(py);
+    for ((column, expression), values) in columns.iter().zip(&expressions).zip(&values) {
+        result.set_item(column, column_to_py(py, values, expression.last_field())?)?;
+    }
+    Ok(result)
+}
+
+/// Registers the query helpers with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(extract, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_columns, m)?)?;
+    Ok(())
+}
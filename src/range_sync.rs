@@ -0,0 +1,176 @@
+//! Planning of `BeaconBlocksByRange` requests for range sync.
+//!
+//! `plan_block_requests` splits a slot range into `BeaconBlocksByRange`
+//! requests that start and end on epoch boundaries wherever the range allows:
+//! the first request runs up to the last epoch boundary within its size limit,
+//! the following ones cover whole epochs, and the last one ends at the end of
+//! the range. A request that cannot hold a whole epoch is never split across an
+//! epoch boundary. Ranges are half-open like Python's `range`, so `end_slot`
+//! itself is not requested.
+//!
+//! The request container is encoded as in the phase 0 networking specs.
+//! `step` is deprecated since Deneb and always 1 in planned requests.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::config::PyConfig;
+use crate::presets::PresetKind;
+
+/// `MAX_REQUEST_BLOCKS_DENEB`, the most blocks a peer serves per request.
+const MAX_REQUEST_BLOCKS_DENEB: u64 = 128;
+
+/// SSZ size of `BeaconBlocksByRangeRequest`: three `uint64` fields.
+const REQUEST_SIZE: usize = 24;
+
+/// A `BeaconBlocksByRange` request.
+#[pyclass(name = "BeaconBlocksByRangeRequest", eq, hash, frozen)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PyBeaconBlocksByRangeRequest {
+    #[pyo3(get)]
+    start_slot: u64,
+    #[pyo3(get)]
+    count: u64,
+    #[pyo3(get)]
+    step: u64,
+}
+
+#[pymethods]
+impl PyBeaconBlocksByRangeRequest {
+    #[new]
+    #[pyo3(signature = (start_slot, count, step = 1))]
+    fn new(start_slot: u64, count: u64, step: u64) -> Self {
+        Self {
+            start_slot,
+            count,
+            step,
+        }
+    }
+
+    /// The first slot after the requested range, for `step == 1`.
+    #[getter]
+    fn end_slot(&self) -> u64 {
+        self.start_slot.saturating_add(self.count)
+    }
+
+    /// Decode a request from its 24-byte SSZ encoding.
+    ///
+    /// # Errors
+    /// Returns `PyValueError` if `bytes` is not 24 bytes long.
+    #[staticmethod]
+    fn from_ssz(bytes: &[u8]) -> PyResult<Self> {
+        if bytes.len() != REQUEST_SIZE {
+            return Err(PyValueError::new_err(format!(
+                "BeaconBlocksByRangeRequest must be {REQUEST_SIZE} bytes, got {}",
+                bytes.len()
+            )));
+        }
+
+        let [start_slot, count, step] = [0, 8, 16].map(|offset| {
+            u64::from_le_bytes(bytes[offset..offset + 8].try_into().expect("8 bytes"))
+        });
+
+        Ok(Self {
+            start_slot,
+            count,
+            step,
+        })
+    }
+
+    fn to_ssz<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut bytes = [0; REQUEST_SIZE];
+        bytes[..8].copy_from_slice(&self.start_slot.to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.count.to_le_bytes());
+        bytes[16..].copy_from_slice(&self.step.to_le_bytes());
+        PyBytes::new(py, &bytes)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "BeaconBlocksByRangeRequest(start_slot={}, count={}, step={})",
+            self.start_slot, self.count, self.step
+        )
+    }
+}
+
+/// Splits `start_slot..end_slot` into epoch-aligned requests of at most
+/// `max_per_request` blocks.
+fn plan(
+    start_slot: u64,
+    end_slot: u64,
+    max_per_request: u64,
+    slots_per_epoch: u64,
+) -> Vec<PyBeaconBlocksByRangeRequest> {
+    let mut requests = Vec::new();
+    let mut slot = start_slot;
+
+    while slot < end_slot {
+        let limit = slot.saturating_add(max_per_request);
+        // The last epoch boundary within reach, if the request crosses one.
+        let boundary = limit / slots_per_epoch * slots_per_epoch;
+        let end = (if boundary > slot { boundary } else { limit }).min(end_slot);
+
+        requests.push(PyBeaconBlocksByRangeRequest {
+            start_slot: slot,
+            count: end - slot,
+            step: 1,
+        });
+        slot = end;
+    }
+
+    requests
+}
+
+/// Plan the `BeaconBlocksByRange` requests fetching the blocks of
+/// `start_slot..end_slot`, with request boundaries on epoch boundaries.
+///
+/// `max_per_request` defaults to `MAX_REQUEST_BLOCKS_DENEB` (128). `config`
+/// selects `SLOTS_PER_EPOCH` and defaults to mainnet.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `end_slot` is before `start_slot` or
+/// `max_per_request` is not between 1 and `MAX_REQUEST_BLOCKS_DENEB`.
+#[pyfunction]
+#[pyo3(signature = (start_slot, end_slot, max_per_request = None, config = None))]
+pub fn plan_block_requests(
+    start_slot: u64,
+    end_slot: u64,
+    max_per_request: Option<u64>,
+    config: Option<PyRef<'_, PyConfig>>,
+) -> PyResult<Vec<PyBeaconBlocksByRangeRequest>> {
+    let max_per_request = max_per_request.unwrap_or(MAX_REQUEST_BLOCKS_DENEB);
+
+    if end_slot < start_slot {
+        return Err(PyValueError::new_err(format!(
+            "end_slot {end_slot} is before start_slot {start_slot}"
+        )));
+    }
+
+    if !(1..=MAX_REQUEST_BLOCKS_DENEB).contains(&max_per_request) {
+        return Err(PyValueError::new_err(format!(
+            "max_per_request must be between 1 and {MAX_REQUEST_BLOCKS_DENEB}, got {max_per_request}"
+        )));
+    }
+
+    let preset = config.map_or(PresetKind::Mainnet, |config| config.inner.preset);
+
+    Ok(plan(
+        start_slot,
+        end_slot,
+        max_per_request,
+        preset.slots_per_epoch(),
+    ))
+}
+
+/// Registers the range sync request type and planner with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyBeaconBlocksByRangeRequest>()?;
+    m.add_function(wrap_pyfunction!(plan_block_requests, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,145 @@
+//! Cached hash tree roots of blocks.
+//!
+//! Rooting a block hashes its whole body, most of it the execution payload
+//! and the attestations, and proposers root the same block several times:
+//! for its signing root, for the signed block and for logging. `BlockRoots`
+//! keeps the root of each body field, of the body and of the block once they
+//! are computed. Frozen blocks never change, so their roots never go stale.
+//! The setters of mutable blocks clear the roots above the field they edit,
+//! and the roots of the other fields carry over through `freeze()`, so
+//! rooting a patched block hashes only the path from the edited field up.
+
+use std::sync::OnceLock;
+
+use grandine_ssz::{SszHash as _, H256};
+use grandine_types::electra::containers::{BeaconBlock, BlindedBeaconBlock};
+use grandine_types::phase0::containers::BeaconBlockHeader;
+use grandine_types::preset::Preset;
+
+use crate::merkle::merkle_root;
+
+/// The number of fields of an Electra block body.
+const BODY_FIELDS: usize = 13;
+
+/// The index of `graffiti` in the block body.
+pub const GRAFFITI: usize = 2;
+
+/// The index of `execution_payload`, or `execution_payload_header` in blinded
+/// blocks, in the block body.
+pub const EXECUTION_PAYLOAD: usize = 9;
+
+/// A block that `BlockRoots` roots one body field at a time.
+pub trait RootedBlock {
+    /// The header of the block, with `body_root` as the root of its body.
+    fn header(&self, body_root: H256) -> BeaconBlockHeader;
+
+    /// The hash tree root of the body field at `index`.
+    fn body_field_root(&self, index: usize) -> H256;
+}
+
+/// Implements `RootedBlock` for a block from the fields of its body, in
+/// order.
+macro_rules! rooted_block {
+    ($ty:ident { $($field:ident),* $(,)? }) => {
+        impl<P: Preset> RootedBlock for $ty<P> {
+            fn header(&self, body_root: H256) -> BeaconBlockHeader {
+                BeaconBlockHeader {
+                    slot: self.slot,
+                    proposer_index: self.proposer_index,
+                    parent_root: self.parent_root,
+                    state_root: self.state_root,
+                    body_root,
+                }
+            }
+
+            fn body_field_root(&self, index: usize) -> H256 {
+                let roots: [fn(&Self) -> H256; BODY_FIELDS] =
+                    [$(|block| block.body.$field.hash_tree_root()),*];
+                roots[index](self)
+            }
+        }
+    };
+}
+
+rooted_block!(BeaconBlock {
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload,
+    bls_to_execution_changes,
+    blob_kzg_commitments,
+    execution_requests,
+});
+
+rooted_block!(BlindedBeaconBlock {
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload_header,
+    bls_to_execution_changes,
+    blob_kzg_commitments,
+    execution_requests,
+});
+
+/// The roots of a block computed so far.
+///
+/// Roots are computed on first use, by whichever thread asks first, and
+/// cleared through `&mut self`, so only mutable blocks, which are not shared
+/// between threads, can clear them.
+#[derive(Clone, Default)]
+pub struct BlockRoots {
+    fields: [OnceLock<H256>; BODY_FIELDS],
+    body: OnceLock<H256>,
+    block: OnceLock<H256>,
+}
+
+impl BlockRoots {
+    /// The hash tree root of `block`.
+    #[must_use]
+    pub fn block_root(&self, block: &impl RootedBlock) -> H256 {
+        *self
+            .block
+            .get_or_init(|| block.header(self.body_root(block)).hash_tree_root())
+    }
+
+    /// The hash tree root of the body of `block`.
+    #[must_use]
+    pub fn body_root(&self, block: &impl RootedBlock) -> H256 {
+        *self.body.get_or_init(|| {
+            let fields = (0..BODY_FIELDS)
+                .map(|index| self.field_root(block, index))
+                .collect();
+            merkle_root(fields)
+        })
+    }
+
+    /// The hash tree root of the body field of `block` at `index`.
+    #[must_use]
+    pub fn field_root(&self, block: &impl RootedBlock, index: usize) -> H256 {
+        *self.fields[index].get_or_init(|| block.body_field_root(index))
+    }
+
+    /// Clears the roots that depend on the body field at `index`.
+    pub fn clear_field(&mut self, index: usize) {
+        self.fields[index] = OnceLock::new();
+        self.body = OnceLock::new();
+        self.block = OnceLock::new();
+    }
+
+    /// Clears the block root, after an edit outside the body.
+    pub fn clear_block(&mut self) {
+        self.block = OnceLock::new();
+    }
+}
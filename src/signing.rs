@@ -0,0 +1,229 @@
+//! Signing roots for validator duties.
+//!
+//! Validator clients sign `compute_signing_root(object, domain)`, the hash
+//! tree root of a `SigningData` container pairing the object root with the
+//! signature domain. The domain itself depends on the fork and genesis
+//! validators root, so it is passed in as computed by the caller, for
+//! example with `compute_domain`.
+//!
+//! `generate_presigned_exits` also signs voluntary exits, for operators who
+//! keep exits for their validators in cold storage, and can write each exit
+//! to a directory as JSON and SSZ files. It takes decrypted secret keys and
+//! an explicit domain: the crate has no keystore decryption, and `Config`
+//! carries neither fork versions nor a genesis validators root to derive the
+//! exit domain from.
+
+use std::path::{Path, PathBuf};
+
+use grandine_bls::SignatureBytes;
+use grandine_ssz::{SszHash, H256};
+use grandine_types::altair::containers::SyncAggregatorSelectionData;
+use grandine_types::phase0::containers::{SignedVoluntaryExit, SigningData, VoluntaryExit};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::bls::{parse_public_key, parse_secret_key, verify};
+use crate::convert::{format_hash_tree_root, parse_root};
+use crate::encode_ssz;
+use crate::hex_format::to_json_vec;
+use crate::phase0::exit::PySignedVoluntaryExit;
+
+/// Computes `compute_signing_root` for an object with the given root.
+#[must_use]
+pub fn signing_root(object_root: H256, domain: H256) -> H256 {
+    SigningData {
+        object_root,
+        domain,
+    }
+    .hash_tree_root()
+}
+
+/// Returns the signing root of `object` for `domain`, hashing the object
+/// with the GIL released.
+pub fn object_signing_root<T: SszHash + Sync>(py: Python<'_>, object: &T, domain: H256) -> String {
+    let root = py.detach(|| signing_root(object.hash_tree_root(), domain));
+    format_hash_tree_root(&root)
+}
+
+/// Verifies `signature` over the signing root of `object` for `domain`
+/// against `pubkey`, with the GIL released.
+///
+/// # Errors
+///
+/// Returns a `BlsEncodingError` subclass if `pubkey` is not a valid pubkey,
+/// or `PyValueError` if `domain` is not 32 bytes.
+pub fn verify_object<T: SszHash + Sync>(
+    py: Python<'_>,
+    object: &T,
+    signature: SignatureBytes,
+    pubkey: &Bound<'_, PyAny>,
+    domain: &Bound<'_, PyAny>,
+) -> PyResult<bool> {
+    let pubkey = parse_public_key(pubkey)?;
+    let domain = parse_root(domain)?;
+    Ok(py.detach(|| {
+        verify(
+            signature,
+            signing_root(object.hash_tree_root(), domain),
+            &pubkey,
+        )
+    }))
+}
+
+/// Return the signing root of the selection proof for attestation
+/// aggregation at `slot`.
+///
+/// `domain` is the `DOMAIN_SELECTION_PROOF` domain of the slot's epoch.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `domain` is not 32 bytes.
+#[pyfunction]
+pub fn compute_selection_proof_signing_root(
+    slot: u64,
+    domain: &Bound<'_, PyAny>,
+) -> PyResult<String> {
+    let root = signing_root(slot.hash_tree_root(), parse_root(domain)?);
+    Ok(format_hash_tree_root(&root))
+}
+
+/// Return the signing root of the selection proof for sync committee
+/// aggregation of `subcommittee_index` at `slot`.
+///
+/// `domain` is the `DOMAIN_SYNC_COMMITTEE_SELECTION_PROOF` domain of the
+/// slot's epoch.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `domain` is not 32 bytes.
+#[pyfunction]
+pub fn compute_sync_selection_proof_signing_root(
+    slot: u64,
+    subcommittee_index: u64,
+    domain: &Bound<'_, PyAny>,
+) -> PyResult<String> {
+    let data = SyncAggregatorSelectionData {
+        slot,
+        subcommittee_index,
+    };
+    let root = signing_root(data.hash_tree_root(), parse_root(domain)?);
+    Ok(format_hash_tree_root(&root))
+}
+
+/// Return the signing root of a `VoluntaryExit` of `validator_index` at
+/// `epoch`.
+///
+/// `domain` is the `DOMAIN_VOLUNTARY_EXIT` domain of the Capella fork, which
+/// EIP-7044 fixes for exits signed in any later fork, so presigned exits stay
+/// valid across forks. Sign the root with `bls_sign`, or use
+/// `generate_presigned_exits` to sign exits for many validators at once.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `domain` is not 32 bytes.
+#[pyfunction]
+pub fn compute_voluntary_exit_signing_root(
+    epoch: u64,
+    validator_index: u64,
+    domain: &Bound<'_, PyAny>,
+) -> PyResult<String> {
+    let exit = VoluntaryExit {
+        epoch,
+        validator_index,
+    };
+    let root = signing_root(exit.hash_tree_root(), parse_root(domain)?);
+    Ok(format_hash_tree_root(&root))
+}
+
+/// Sign a `VoluntaryExit` for each `(validator_index, secret_key)` pair in
+/// `validators` at each of `epochs`, returning `SignedVoluntaryExit`s to
+/// store offline until they are published.
+///
+/// The exits of each validator follow one another, in the order of `epochs`.
+/// `domain` is as for `compute_voluntary_exit_signing_root`. Secret keys are
+/// bytes or hex strings as for `bls_sign`; keystores are not read, so they
+/// are decrypted by the caller. All keys are parsed before any exit is
+/// signed, and the exits are signed with the GIL released.
+///
+/// If `output_dir` is given, it is created if needed and each exit is also
+/// written to it as `exit_<validator_index>_<epoch>.json` and `.ssz`,
+/// replacing existing files of the same name.
+///
+/// # Errors
+///
+/// Same as `bls_sign` for each secret key, `PyValueError` if `domain` is not
+/// 32 bytes or an exit fails to serialize, or `OSError` if the files cannot
+/// be written.
+#[pyfunction]
+#[pyo3(signature = (validators, epochs, domain, output_dir=None))]
+pub fn generate_presigned_exits(
+    py: Python<'_>,
+    validators: Vec<(u64, Bound<'_, PyAny>)>,
+    epochs: Vec<u64>,
+    domain: &Bound<'_, PyAny>,
+    output_dir: Option<PathBuf>,
+) -> PyResult<Vec<PySignedVoluntaryExit>> {
+    let domain = parse_root(domain)?;
+    let keys = validators
+        .iter()
+        .map(|(validator_index, secret_key)| Ok((*validator_index, parse_secret_key(secret_key)?)))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let exits = py.detach(|| {
+        keys.iter()
+            .flat_map(|(validator_index, secret_key)| {
+                epochs.iter().map(move |&epoch| {
+                    let message = VoluntaryExit {
+                        epoch,
+                        validator_index: *validator_index,
+                    };
+                    let root = signing_root(message.hash_tree_root(), domain);
+                    SignedVoluntaryExit {
+                        message,
+                        signature: secret_key.sign(root).into(),
+                    }
+                })
+            })
+            .collect::<Vec<_>>()
+    });
+
+    if let Some(dir) = output_dir {
+        py.detach(|| write_exits(&dir, &exits))?;
+    }
+
+    Ok(exits.into_iter().map(PySignedVoluntaryExit::from).collect())
+}
+
+/// Writes each exit to `dir` as `exit_<validator_index>_<epoch>.json` and
+/// `.ssz`.
+fn write_exits(dir: &Path, exits: &[SignedVoluntaryExit]) -> PyResult<()> {
+    std::fs::create_dir_all(dir)?;
+    for exit in exits {
+        let VoluntaryExit {
+            epoch,
+            validator_index,
+        } = exit.message;
+        let stem = format!("exit_{validator_index}_{epoch}");
+        let json = to_json_vec(exit).map_err(PyValueError::new_err)?;
+        let ssz = encode_ssz(exit).map_err(PyValueError::new_err)?;
+        std::fs::write(dir.join(format!("{stem}.json")), json)?;
+        std::fs::write(dir.join(format!("{stem}.ssz")), ssz)?;
+    }
+    Ok(())
+}
+
+/// Registers the signing functions with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(compute_selection_proof_signing_root, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        compute_sync_selection_proof_signing_root,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(compute_voluntary_exit_signing_root, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_presigned_exits, m)?)?;
+    Ok(())
+}
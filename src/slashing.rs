@@ -0,0 +1,287 @@
+//! Attester slashing exposure of validator keys.
+//!
+//! Before keys are moved to another validator client, their signing history
+//! should be free of slashable attestations, and the new client has to be
+//! told the highest source and target epochs signed so far. The history
+//! comes from slashing protection databases, typically through EIP-3076
+//! interchange files, and can span months of epochs for thousands of keys.
+//!
+//! `slashing_exposure` reads the history once, groups it by key and checks
+//! the keys in parallel. Sorting each key's attestations makes the checks
+//! `O(n log n)` per key:
+//! - a double vote is two different attestations with the same target epoch
+//! - a surround vote is an attestation whose source and target epochs are
+//!   strictly within those of another attestation of the key
+
+use std::collections::HashMap;
+
+use grandine_ssz::H256;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+use rayon::prelude::*;
+
+use crate::convert::{extract_epoch, format_hash_tree_root, parse_root};
+use crate::hex_format::format_hex;
+use crate::pubkey_index::{parse_pubkey, Pubkey};
+
+/// A signed attestation of a slashing protection history.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct SignedAttestation {
+    source_epoch: u64,
+    target_epoch: u64,
+    signing_root: Option<H256>,
+}
+
+impl SignedAttestation {
+    /// Whether two attestations with the same target epoch are different
+    /// messages. Without both signing roots only the source epochs can tell.
+    fn conflicts_with(&self, other: &Self) -> bool {
+        self.source_epoch != other.source_epoch
+            || matches!(
+                (self.signing_root, other.signing_root),
+                (Some(a), Some(b)) if a != b
+            )
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("source_epoch", self.source_epoch)?;
+        dict.set_item("target_epoch", self.target_epoch)?;
+        dict.set_item(
+            "signing_root",
+            self.signing_root.as_ref().map(format_hash_tree_root),
+        )?;
+        Ok(dict)
+    }
+}
+
+/// The slashing exposure of one key.
+#[derive(Default)]
+struct KeyExposure {
+    attestations: usize,
+    max_source_epoch: Option<u64>,
+    max_target_epoch: Option<u64>,
+    /// Pairs of different attestations with the same target epoch.
+    double_votes: Vec<(SignedAttestation, SignedAttestation)>,
+    /// `(surrounding, surrounded)` pairs.
+    surround_votes: Vec<(SignedAttestation, SignedAttestation)>,
+}
+
+/// Checks the signing history of one key.
+fn check_key(mut attestations: Vec<SignedAttestation>) -> KeyExposure {
+    let mut exposure = KeyExposure {
+        attestations: attestations.len(),
+        max_source_epoch: attestations.iter().map(|a| a.source_epoch).max(),
+        max_target_epoch: attestations.iter().map(|a| a.target_epoch).max(),
+        ..KeyExposure::default()
+    };
+
+    // Double votes: report every attestation that differs from one signed
+    // earlier for its target epoch. Repeats of a message are not slashable.
+    attestations.sort_by_key(|a| (a.target_epoch, a.source_epoch));
+    attestations.dedup();
+
+    for group in attestations.chunk_by(|a, b| a.target_epoch == b.target_epoch) {
+        for (index, second) in group.iter().enumerate().skip(1) {
+            if let Some(first) = group[..index].iter().find(|a| a.conflicts_with(second)) {
+                exposure.double_votes.push((*first, *second));
+            }
+        }
+    }
+
+    // Surround votes: in source epoch order, an attestation is surrounded if
+    // one with a smaller source epoch has a larger target epoch. The one with
+    // the largest target epoch is reported as surrounding it.
+    attestations.sort_by_key(|a| (a.source_epoch, a.target_epoch));
+
+    let mut surrounding: Option<SignedAttestation> = None;
+    for group in attestations.chunk_by(|a, b| a.source_epoch == b.source_epoch) {
+        if let Some(outer) = surrounding {
+            exposure.surround_votes.extend(
+                group
+                    .iter()
+                    .filter(|inner| inner.target_epoch < outer.target_epoch)
+                    .map(|inner| (outer, *inner)),
+            );
+        }
+
+        // Groups are sorted by target epoch, so the last is the widest.
+        if let Some(widest) = group.last() {
+            if surrounding.is_none_or(|outer| widest.target_epoch > outer.target_epoch) {
+                surrounding = Some(*widest);
+            }
+        }
+    }
+
+    exposure
+}
+
+/// Extracts a signed attestation record: a dict with `pubkey`,
+/// `source_epoch`, `target_epoch` and an optional `signing_root`, or a
+/// `(pubkey, source_epoch, target_epoch[, signing_root])` tuple.
+fn extract_record(record: &Bound<'_, PyAny>) -> PyResult<(Pubkey, SignedAttestation)> {
+    let (pubkey, source_epoch, target_epoch, signing_root) =
+        if let Ok(dict) = record.cast::<PyDict>() {
+            let field = |name: &str| {
+                dict.get_item(name)?.ok_or_else(|| {
+                    PyValueError::new_err(format!("Signed attestation is missing {name:?}"))
+                })
+            };
+            (
+                field("pubkey")?,
+                field("source_epoch")?,
+                field("target_epoch")?,
+                dict.get_item("signing_root")?,
+            )
+        } else if let Ok(tuple) = record.cast::<PyTuple>() {
+            match tuple.len() {
+                3 | 4 => (
+                    tuple.get_item(0)?,
+                    tuple.get_item(1)?,
+                    tuple.get_item(2)?,
+                    tuple.get_item(3).ok(),
+                ),
+                length => {
+                    return Err(PyValueError::new_err(format!(
+                        "Signed attestation tuple must have 3 or 4 items, got {length}"
+                    )))
+                }
+            }
+        } else {
+            return Err(PyTypeError::new_err(format!(
+                "Expected a signed attestation dict or tuple, got {}",
+                record.get_type().name()?
+            )));
+        };
+
+    let signing_root = signing_root
+        .filter(|root| !root.is_none())
+        .map(|root| parse_root(&root))
+        .transpose()?;
+
+    Ok((
+        parse_pubkey(&pubkey)?,
+        SignedAttestation {
+            source_epoch: extract_epoch(&source_epoch)?,
+            target_epoch: extract_epoch(&target_epoch)?,
+            signing_root,
+        },
+    ))
+}
+
+/// Scan signed attestations for slashing risks of a set of keys.
+///
+/// `signed_attestations_iter` yields the signed attestations of a slashing
+/// protection history, each a dict with `pubkey`, `source_epoch`,
+/// `target_epoch` and an optional `signing_root` (the EIP-3076 interchange
+/// fields), or a `(pubkey, source_epoch, target_epoch[, signing_root])`
+/// tuple. Attestations of keys not in `pubkeys` are skipped. Two
+/// attestations with the same target epoch are a double vote if their
+/// source epochs differ or both have signing roots and those differ.
+///
+/// Returns a dict with the number of `attestations` scanned and `skipped`,
+/// the `keys` mapping each pubkey to its number of `attestations` and its
+/// `max_source_epoch` and `max_target_epoch` (`None` without history), the
+/// `double_votes` and `surround_votes` found, and whether the keys are
+/// `safe` to migrate. Each double vote has the `pubkey`, `target_epoch` and
+/// the `first` and `second` attestation, and each surround vote the
+/// `pubkey` and the `surrounding` and `surrounded` attestation, as dicts of
+/// `source_epoch`, `target_epoch` and `signing_root`.
+///
+/// # Errors
+///
+/// Returns `PyValueError` or `PyTypeError` if a pubkey or record is invalid.
+#[pyfunction]
+pub fn slashing_exposure(
+    py: Python<'_>,
+    signed_attestations_iter: &Bound<'_, PyAny>,
+    pubkeys: Vec<Bound<'_, PyAny>>,
+) -> PyResult<Py<PyDict>> {
+    let pubkeys = pubkeys
+        .iter()
+        .map(parse_pubkey)
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let mut histories = pubkeys
+        .iter()
+        .map(|pubkey| (*pubkey, Vec::new()))
+        .collect::<HashMap<_, _>>();
+    let mut scanned = 0_usize;
+    let mut skipped = 0_usize;
+
+    for record in signed_attestations_iter.try_iter()? {
+        let (pubkey, attestation) = extract_record(&record?)?;
+
+        match histories.get_mut(&pubkey) {
+            Some(history) => {
+                history.push(attestation);
+                scanned += 1;
+            }
+            None => skipped += 1,
+        }
+    }
+
+    let exposures = py.detach(|| {
+        histories
+            .into_par_iter()
+            .map(|(pubkey, history)| (pubkey, check_key(history)))
+            .collect::<HashMap<_, _>>()
+    });
+
+    let keys = PyDict::new(py);
+    let double_votes = PyList::empty(py);
+    let surround_votes = PyList::empty(py);
+
+    // Report in the order the keys were given, skipping repeated ones.
+    for pubkey in &pubkeys {
+        let formatted = format_hex(pubkey.as_slice());
+        if keys.contains(&formatted)? {
+            continue;
+        }
+
+        let exposure = &exposures[pubkey];
+
+        let key = PyDict::new(py);
+        key.set_item("attestations", exposure.attestations)?;
+        key.set_item("max_source_epoch", exposure.max_source_epoch)?;
+        key.set_item("max_target_epoch", exposure.max_target_epoch)?;
+        keys.set_item(&formatted, key)?;
+
+        for (first, second) in &exposure.double_votes {
+            let vote = PyDict::new(py);
+            vote.set_item("pubkey", &formatted)?;
+            vote.set_item("target_epoch", first.target_epoch)?;
+            vote.set_item("first", first.to_dict(py)?)?;
+            vote.set_item("second", second.to_dict(py)?)?;
+            double_votes.append(vote)?;
+        }
+
+        for (surrounding, surrounded) in &exposure.surround_votes {
+            let vote = PyDict::new(py);
+            vote.set_item("pubkey", &formatted)?;
+            vote.set_item("surrounding", surrounding.to_dict(py)?)?;
+            vote.set_item("surrounded", surrounded.to_dict(py)?)?;
+            surround_votes.append(vote)?;
+        }
+    }
+
+    let dict = PyDict::new(py);
+    dict.set_item("attestations", scanned)?;
+    dict.set_item("skipped", skipped)?;
+    dict.set_item("keys", keys)?;
+    dict.set_item("safe", double_votes.is_empty() && surround_votes.is_empty())?;
+    dict.set_item("double_votes", double_votes)?;
+    dict.set_item("surround_votes", surround_votes)?;
+    Ok(dict.unbind())
+}
+
+/// Registers the slashing exposure scan with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(slashing_exposure, m)?)?;
+    Ok(())
+}
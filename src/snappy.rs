@@ -0,0 +1,80 @@
+//! Snappy compression of SSZ payloads.
+//!
+//! Beacon nodes exchange SSZ objects snappy-compressed in two formats: gossip
+//! messages are in the raw (block) format, and req/resp chunks in the framing
+//! format, a stream of CRC-checked chunks that starts with a stream
+//! identifier. Decompression tells the two apart by that identifier unless
+//! told which to expect, and checks the decompressed size against the decode
+//! limits before the output grows past them.
+
+use std::io::{Read as _, Write as _};
+
+/// The chunk that starts every stream in the framing format.
+const STREAM_IDENTIFIER: &[u8] = b"\xff\x06\x00\x00sNaPpY";
+
+/// Whether `bytes` starts like a stream in the framing format.
+fn is_framed(bytes: &[u8]) -> bool {
+    bytes.starts_with(STREAM_IDENTIFIER)
+}
+
+/// Decompresses snappy data in the framing format if `framed` is true, the
+/// raw format if it is false, or the format detected from the stream
+/// identifier if it is `None`.
+///
+/// # Errors
+///
+/// Returns an error string if the data is not valid snappy of that format
+/// or decompresses to more than the `max_input_size` decode limit.
+pub fn decompress(bytes: &[u8], framed: Option<bool>) -> Result<Vec<u8>, String> {
+    let max = crate::limits::max_input_size();
+
+    if framed.unwrap_or_else(|| is_framed(bytes)) {
+        let mut decoded = Vec::new();
+
+        // The length is only known once the stream ends, so stop reading one
+        // byte past the limit.
+        snap::read::FrameDecoder::new(bytes)
+            .take(max.map_or(u64::MAX, |max| (max as u64).saturating_add(1)))
+            .read_to_end(&mut decoded)
+            .map_err(|e| format!("Invalid framed snappy data: {e}"))?;
+
+        if let Some(max) = max.filter(|max| decoded.len() > *max) {
+            return Err(format!(
+                "Decompressed size exceeds the decode limit of {max} bytes"
+            ));
+        }
+
+        return Ok(decoded);
+    }
+
+    let length =
+        snap::raw::decompress_len(bytes).map_err(|e| format!("Invalid raw snappy data: {e}"))?;
+
+    if let Some(max) = max.filter(|max| length > *max) {
+        return Err(format!(
+            "Decompressed size of {length} bytes exceeds the decode limit of {max} bytes"
+        ));
+    }
+
+    snap::raw::Decoder::new()
+        .decompress_vec(bytes)
+        .map_err(|e| format!("Invalid raw snappy data: {e}"))
+}
+
+/// Compresses data in the framing format if `framed` is true and the raw
+/// format otherwise.
+///
+/// # Errors
+///
+/// Returns an error string if compression fails.
+pub fn compress(bytes: &[u8], framed: bool) -> Result<Vec<u8>, String> {
+    if !framed {
+        return snap::raw::Encoder::new()
+            .compress_vec(bytes)
+            .map_err(|e| e.to_string());
+    }
+
+    let mut encoder = snap::write::FrameEncoder::new(Vec::new());
+    encoder.write_all(bytes).map_err(|e| e.to_string())?;
+    encoder.into_inner().map_err(|e| e.to_string())
+}
@@ -0,0 +1,274 @@
+//! Runner for the `ssz_static` cases of the consensus-spec tests.
+//!
+//! The official test vectors (`consensus-spec-tests`) lay out every case as
+//!
+//! ```text
+//! <preset>/<fork>/ssz_static/<Type>/<handler>/<case>/
+//!     serialized.ssz_snappy  the SSZ encoding, snappy block compressed
+//!     roots.yaml             `root: '0x…'`, the hash tree root
+//!     value.yaml             the value, with uints as YAML ints
+//! ```
+//!
+//! `run_ssz_static_test` runs one case against the type registered for its
+//! preset, fork and name in the `test_vectors` table, so vectors written by
+//! `export_test_vectors` can be run as well. A type is looked up under the
+//! latest fork up to the case's fork that lists it: a Deneb `Checkpoint`
+//! case runs against the Phase 0 `Checkpoint`.
+//!
+//! A case passes if `serialized.ssz_snappy` decodes and re-encodes to the same
+//! bytes (`"serialized"`), its hash tree root matches `roots.yaml`
+//! (`"root"`), and `value.yaml` decodes to a value with the same encoding
+//! (`"value"`). Failed checks are reported rather than raised, so a test
+//! suite can collect the results of every case.
+//!
+//! Built with the `spec_tests` feature, which adds the YAML parser.
+
+use std::path::{Path, PathBuf};
+
+use grandine_ssz::{SszHash, SszRead, SszWrite};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::envelope::Fork;
+use crate::test_vectors::{entries, Entry};
+
+/// A failed check of a case.
+pub(crate) struct Failure {
+    check: &'static str,
+    message: String,
+}
+
+/// The contents of `roots.yaml`.
+#[derive(Deserialize)]
+struct Roots {
+    root: String,
+}
+
+/// Quotes the integers of a YAML document, which are all uints in test
+/// vectors, so that `uint256` values are not parsed as floats and every
+/// uint deserializes from a decimal string as in JSON.
+fn quote_integers(yaml: &str) -> String {
+    let mut quoted = String::with_capacity(yaml.len());
+    let mut chars = yaml.chars().peekable();
+    let mut quote = None;
+    let mut previous = ' ';
+
+    while let Some(character) = chars.next() {
+        match quote {
+            Some(open) if character == open => quote = None,
+            Some(_) => {}
+            None if character == '\'' || character == '"' => quote = Some(character),
+            None if character.is_ascii_digit()
+                && !(previous.is_alphanumeric() || previous == '_') =>
+            {
+                let mut digits = character.to_string();
+                while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(digit);
+                }
+
+                // Digits followed by letters are part of a plain string.
+                let plain = chars
+                    .peek()
+                    .is_some_and(|next| next.is_alphanumeric() || *next == '_');
+
+                if plain {
+                    quoted.push_str(&digits);
+                } else {
+                    quoted.push('"');
+                    quoted.push_str(&digits);
+                    quoted.push('"');
+                }
+
+                previous = '0';
+                continue;
+            }
+            None => {}
+        }
+
+        quoted.push(character);
+        previous = character;
+    }
+
+    quoted
+}
+
+/// Decodes `value.yaml` as `T`.
+fn decode_value<T: DeserializeOwned>(yaml: &str) -> Result<T, String> {
+    let value: serde_json::Value =
+        serde_yaml::from_str(&quote_integers(yaml)).map_err(|e| format!("Invalid YAML: {e}"))?;
+    crate::json::from_value_with_path(value)
+}
+
+/// Runs the `ssz_static` case in `case_dir` against `T`.
+///
+/// # Errors
+///
+/// Returns an I/O error if a file of the case cannot be read.
+pub(crate) fn run_case<T>(case_dir: &Path) -> std::io::Result<Vec<Failure>>
+where
+    T: DeserializeOwned + SszRead<()> + SszWrite + SszHash,
+{
+    let compressed = std::fs::read(case_dir.join("serialized.ssz_snappy"))?;
+    let roots = std::fs::read_to_string(case_dir.join("roots.yaml"))?;
+    let value = std::fs::read_to_string(case_dir.join("value.yaml"))?;
+
+    let mut failures = Vec::new();
+    let mut fail = |check, message| failures.push(Failure { check, message });
+
+    let serialized = crate::snappy::decompress(&compressed, Some(false));
+    let decoded = serialized
+        .as_deref()
+        .map_err(Clone::clone)
+        .and_then(crate::decode_ssz::<T>);
+
+    if let Err(error) = &decoded {
+        fail("serialized", error.clone());
+    }
+
+    if let (Ok(serialized), Ok(decoded)) = (&serialized, &decoded) {
+        match crate::encode_ssz(decoded) {
+            Ok(encoded) if encoded == *serialized => {}
+            Ok(_) => fail(
+                "serialized",
+                "Re-encoding differs from the input".to_owned(),
+            ),
+            Err(error) => fail("serialized", error),
+        }
+
+        let root = format!("0x{}", hex::encode(decoded.hash_tree_root().as_bytes()));
+
+        match serde_yaml::from_str::<Roots>(&roots) {
+            Ok(expected) if expected.root.eq_ignore_ascii_case(&root) => {}
+            Ok(expected) => fail(
+                "root",
+                format!("Expected root {}, got {root}", expected.root),
+            ),
+            Err(error) => fail("root", format!("Invalid roots.yaml: {error}")),
+        }
+    }
+
+    match decode_value::<T>(&value).and_then(|value| crate::encode_ssz(&value)) {
+        Ok(encoded)
+            if serialized
+                .as_ref()
+                .is_ok_and(|serialized| encoded == *serialized) => {}
+        Ok(_) => fail(
+            "value",
+            "value.yaml does not encode to serialized.ssz_snappy".to_owned(),
+        ),
+        Err(error) => fail("value", error),
+    }
+
+    Ok(failures)
+}
+
+/// The preset, fork and type name in a path ending in
+/// `<preset>/<fork>/ssz_static/<Type>/<handler>/<case>`.
+fn path_parts(case_dir: &Path) -> Option<[String; 3]> {
+    let parts: Vec<_> = case_dir
+        .iter()
+        .map(|part| part.to_string_lossy().into_owned())
+        .collect();
+
+    match parts.as_slice() {
+        [.., preset, fork, kind, name, _, _] if kind == "ssz_static" => {
+            Some([preset.clone(), fork.clone(), name.clone()])
+        }
+        _ => None,
+    }
+}
+
+/// The entry for `name` under the latest fork up to `fork`.
+fn find_entry<'a>(entries: &'a [Entry], preset: &str, fork: Fork, name: &str) -> Option<&'a Entry> {
+    entries
+        .iter()
+        .filter(|entry| entry.preset == preset && entry.name == name)
+        .filter_map(|entry| {
+            let entry_fork = Fork::parse(entry.fork).ok()?;
+            (entry_fork <= fork).then_some((entry_fork, entry))
+        })
+        .max_by_key(|(entry_fork, _)| *entry_fork)
+        .map(|(_, entry)| entry)
+}
+
+/// Run the consensus-spec `ssz_static` test case in `case_dir`.
+///
+/// The preset, fork and type are taken from the path
+/// (`<preset>/<fork>/ssz_static/<Type>/<handler>/<case>`) unless given.
+/// Returns a dict with the `preset`, `fork` and `type` the case ran against,
+/// `passed`, and `failures` mapping each failed check (`"serialized"`,
+/// `"root"` or `"value"`) to a message.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if the preset, fork or type cannot be inferred or
+/// no type is registered for them, or `OSError` if a file of the case cannot
+/// be read.
+#[pyfunction]
+#[pyo3(signature = (case_dir, *, preset = None, fork = None, type_name = None))]
+pub fn run_ssz_static_test<'py>(
+    py: Python<'py>,
+    case_dir: PathBuf,
+    preset: Option<String>,
+    fork: Option<String>,
+    type_name: Option<String>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let inferred = path_parts(&case_dir);
+    let [preset, fork, name] = match (preset, fork, type_name, inferred) {
+        (Some(preset), Some(fork), Some(name), _) => [preset, fork, name],
+        (preset, fork, name, Some([path_preset, path_fork, path_name])) => [
+            preset.unwrap_or(path_preset),
+            fork.unwrap_or(path_fork),
+            name.unwrap_or(path_name),
+        ],
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Cannot infer the preset, fork and type of {}: expected a path ending in \
+                 <preset>/<fork>/ssz_static/<Type>/<handler>/<case>",
+                case_dir.display()
+            )))
+        }
+    };
+
+    let entries = entries();
+    let entry = find_entry(
+        &entries,
+        &preset,
+        Fork::parse(&fork).map_err(PyValueError::new_err)?,
+        &name,
+    )
+    .ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "No ssz_static type {name:?} for preset {preset:?} and fork {fork:?}"
+        ))
+    })?;
+
+    let run_case = entry.run_case;
+    let failures = py.detach(|| run_case(&case_dir))?;
+
+    let failed = PyDict::new(py);
+    for Failure { check, message } in &failures {
+        failed.set_item(check, message)?;
+    }
+
+    let result = PyDict::new(py);
+    result.set_item("preset", entry.preset)?;
+    result.set_item("fork", fork.to_lowercase())?;
+    result.set_item("type", entry.name)?;
+    result.set_item("passed", failures.is_empty())?;
+    result.set_item("failures", failed)?;
+    Ok(result)
+}
+
+/// Registers the spec test runner with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(run_ssz_static_test, m)?)?;
+    Ok(())
+}
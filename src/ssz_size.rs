@@ -0,0 +1,857 @@
+//! SSZ size bounds of the exposed types.
+//!
+//! Bounds are computed from the container layouts in the consensus specs and
+//! the list limits of each preset, so they can be used to size buffers and
+//! reject oversized messages before decoding. For every type:
+//!
+//! - the fixed size is the size of the fixed part: the whole encoding for
+//!   fixed-size types, and the fixed-size fields plus one 4-byte offset per
+//!   variable-size field otherwise;
+//! - the minimum size is the size with every list and bitlist empty;
+//! - the maximum size is the size with every list at its limit.
+
+use grandine_builder_api::combined::SignedBuilderBid;
+use grandine_builder_api::unphased::containers::{
+    SignedValidatorRegistrationV1, ValidatorRegistrationV1,
+};
+use grandine_types::altair::containers::{
+    ContributionAndProof, SignedContributionAndProof, SyncCommitteeContribution,
+    SyncCommitteeMessage,
+};
+use grandine_types::capella::containers::{BlsToExecutionChange, SignedBlsToExecutionChange};
+use grandine_types::deneb::containers::{BlobSidecar, ExecutionPayload, ExecutionPayloadHeader};
+use grandine_types::electra::containers::{
+    AggregateAndProof, Attestation, AttesterSlashing, BlindedBeaconBlock, IndexedAttestation,
+    SignedAggregateAndProof, SignedBeaconBlock, SignedBlindedBeaconBlock, SingleAttestation,
+};
+use grandine_types::fulu::containers::DataColumnSidecar;
+use grandine_types::phase0::containers::{
+    Attestation as Phase0Attestation, AttesterSlashing as Phase0AttesterSlashing, Deposit,
+    DepositData, IndexedAttestation as Phase0IndexedAttestation, ProposerSlashing,
+    SignedBeaconBlockHeader, SignedVoluntaryExit, VoluntaryExit,
+};
+use grandine_types::preset::Preset;
+use grandine_types::{altair, bellatrix, capella, deneb, electra, phase0};
+use typenum::Unsigned as _;
+
+use crate::electra::block::{BeaconBlockContents, SignedBeaconBlockContents};
+use crate::electra::execution::ExecutionPayloadAndBlobsBundle;
+use crate::envelope::Fork;
+use crate::fulu::block::{
+    BeaconBlockContents as FuluBeaconBlockContents,
+    SignedBeaconBlockContents as FuluSignedBeaconBlockContents,
+};
+
+const OFFSET: u64 = 4;
+
+const BYTES32: u64 = 32;
+const PUBKEY: u64 = 48;
+const SIGNATURE: u64 = 96;
+const KZG: u64 = 48;
+
+const CHECKPOINT: u64 = 8 + BYTES32;
+const ATTESTATION_DATA: u64 = 8 + 8 + BYTES32 + 2 * CHECKPOINT;
+const ETH1_DATA: u64 = BYTES32 + 8 + BYTES32;
+const BEACON_BLOCK_HEADER: u64 = 8 + 8 + 3 * BYTES32;
+const PROPOSER_SLASHING: u64 = 2 * (BEACON_BLOCK_HEADER + SIGNATURE);
+const DEPOSIT: u64 = 33 * BYTES32 + PUBKEY + BYTES32 + 8 + SIGNATURE;
+const VOLUNTARY_EXIT: u64 = 8 + 8;
+const SIGNED_VOLUNTARY_EXIT: u64 = VOLUNTARY_EXIT + SIGNATURE;
+const WITHDRAWAL: u64 = 8 + 8 + 20 + 8;
+const BLS_TO_EXECUTION_CHANGE: u64 = 8 + PUBKEY + 20;
+const SIGNED_BLS_TO_EXECUTION_CHANGE: u64 = BLS_TO_EXECUTION_CHANGE + SIGNATURE;
+const DEPOSIT_REQUEST: u64 = PUBKEY + BYTES32 + 8 + SIGNATURE + 8;
+const WITHDRAWAL_REQUEST: u64 = 20 + PUBKEY + 8;
+const CONSOLIDATION_REQUEST: u64 = 20 + 2 * PUBKEY;
+const FORK: u64 = 4 + 4 + 8;
+const VALIDATOR: u64 = PUBKEY + BYTES32 + 8 + 1 + 4 * 8;
+const HISTORICAL_SUMMARY: u64 = 2 * BYTES32;
+const PENDING_DEPOSIT: u64 = PUBKEY + BYTES32 + 8 + SIGNATURE + 8;
+const PENDING_PARTIAL_WITHDRAWAL: u64 = 3 * 8;
+const PENDING_CONSOLIDATION: u64 = 2 * 8;
+const SIGNED_BEACON_BLOCK_HEADER: u64 = BEACON_BLOCK_HEADER + SIGNATURE;
+/// Length of the Merkle branch of the execution payload header in the block
+/// body, which light client headers carry from Capella on.
+const EXECUTION_BRANCH: u64 = 4 * BYTES32;
+const VALIDATOR_REGISTRATION: u64 = 20 + 8 + 8 + PUBKEY;
+const SIGNED_VALIDATOR_REGISTRATION: u64 = VALIDATOR_REGISTRATION + SIGNATURE;
+
+/// `SYNC_COMMITTEE_SUBNET_COUNT`, the number of sync subcommittees.
+const SYNC_COMMITTEE_SUBNET_COUNT: u64 = 4;
+
+/// SSZ size bounds of a type, in bytes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Bounds {
+    pub variable: bool,
+    pub fixed: u64,
+    pub min: u64,
+    pub max: u64,
+}
+
+impl Bounds {
+    /// A fixed-size type.
+    const fn fixed(size: u64) -> Self {
+        Self {
+            variable: false,
+            fixed: size,
+            min: size,
+            max: size,
+        }
+    }
+
+    /// A list of up to `limit` elements.
+    const fn list(element: Self, limit: u64) -> Self {
+        let element_max = if element.variable {
+            OFFSET + element.max
+        } else {
+            element.max
+        };
+
+        Self {
+            variable: true,
+            fixed: 0,
+            min: 0,
+            max: limit * element_max,
+        }
+    }
+
+    /// A list of up to `limit` fixed-size elements.
+    const fn list_of(size: u64, limit: u64) -> Self {
+        Self::list(Self::fixed(size), limit)
+    }
+
+    /// A bitlist of up to `limit` bits, including the delimiting bit.
+    const fn bitlist(limit: u64) -> Self {
+        Self {
+            variable: true,
+            fixed: 0,
+            min: 1,
+            max: limit / 8 + 1,
+        }
+    }
+
+    /// A container with the given fields, in order.
+    fn container(fields: &[Self]) -> Self {
+        fields.iter().fold(Self::fixed(0), |container, field| {
+            let (fixed, min, max) = if field.variable {
+                (OFFSET, OFFSET + field.min, OFFSET + field.max)
+            } else {
+                (field.fixed, field.fixed, field.fixed)
+            };
+
+            Self {
+                variable: container.variable || field.variable,
+                fixed: container.fixed + fixed,
+                min: container.min + min,
+                max: container.max + max,
+            }
+        })
+    }
+}
+
+/// A type with known SSZ size bounds.
+pub trait SszBounds {
+    fn ssz_bounds() -> Bounds;
+}
+
+const fn bitvector(length: u64) -> u64 {
+    length.div_ceil(8)
+}
+
+fn sync_aggregate<P: Preset>() -> u64 {
+    bitvector(P::SyncCommitteeSize::U64) + SIGNATURE
+}
+
+fn sync_committee<P: Preset>() -> u64 {
+    P::SyncCommitteeSize::U64 * PUBKEY + PUBKEY
+}
+
+fn electra_aggregation_limit<P: Preset>() -> u64 {
+    P::MaxValidatorsPerCommittee::U64 * P::MaxCommitteesPerSlot::U64
+}
+
+fn execution_requests<P: Preset>() -> Bounds {
+    Bounds::container(&[
+        Bounds::list_of(DEPOSIT_REQUEST, P::MaxDepositRequestsPerPayload::U64),
+        Bounds::list_of(WITHDRAWAL_REQUEST, P::MaxWithdrawalRequestsPerPayload::U64),
+        Bounds::list_of(
+            CONSOLIDATION_REQUEST,
+            P::MaxConsolidationRequestsPerPayload::U64,
+        ),
+    ])
+}
+
+/// Fields of an execution payload or header up to `extra_data`.
+fn execution_prefix<P: Preset>() -> [Bounds; 11] {
+    [
+        Bounds::fixed(BYTES32),
+        Bounds::fixed(20),
+        Bounds::fixed(BYTES32),
+        Bounds::fixed(BYTES32),
+        Bounds::fixed(P::BytesPerLogsBloom::U64),
+        Bounds::fixed(BYTES32),
+        Bounds::fixed(8),
+        Bounds::fixed(8),
+        Bounds::fixed(8),
+        Bounds::fixed(8),
+        Bounds::list_of(1, P::MaxExtraDataBytes::U64),
+    ]
+}
+
+/// Fields of a Bellatrix execution payload, or of its header if `header`.
+fn bellatrix_payload<P: Preset>(header: bool) -> Vec<Bounds> {
+    let transactions = if header {
+        Bounds::fixed(BYTES32)
+    } else {
+        Bounds::list(
+            Bounds::list_of(1, P::MaxBytesPerTransaction::U64),
+            P::MaxTransactionsPerPayload::U64,
+        )
+    };
+
+    let mut fields = execution_prefix::<P>().to_vec();
+    fields.extend([Bounds::fixed(BYTES32), Bounds::fixed(BYTES32), transactions]);
+    fields
+}
+
+/// Fields of a Capella execution payload, or of its header if `header`.
+fn capella_payload<P: Preset>(header: bool) -> Vec<Bounds> {
+    let withdrawals = if header {
+        Bounds::fixed(BYTES32)
+    } else {
+        Bounds::list_of(WITHDRAWAL, P::MaxWithdrawalsPerPayload::U64)
+    };
+
+    let mut fields = bellatrix_payload::<P>(header);
+    fields.push(withdrawals);
+    fields
+}
+
+/// Fields of a Phase 0 block body, with the operation lists of `P` and the
+/// attester slashings and attestations of the fork.
+fn phase0_body<P: Preset>(attester_slashings: Bounds, attestations: Bounds) -> Vec<Bounds> {
+    vec![
+        Bounds::fixed(SIGNATURE),
+        Bounds::fixed(ETH1_DATA),
+        Bounds::fixed(BYTES32),
+        Bounds::list_of(PROPOSER_SLASHING, P::MaxProposerSlashings::U64),
+        attester_slashings,
+        attestations,
+        Bounds::list_of(DEPOSIT, P::MaxDeposits::U64),
+        Bounds::list_of(SIGNED_VOLUNTARY_EXIT, P::MaxVoluntaryExits::U64),
+    ]
+}
+
+/// Fields of a block body before Electra, which changed the attestation
+/// layout, up to and including those added in `fork`.
+///
+/// `payload` is the execution payload or header from Bellatrix on.
+fn pre_electra_body<P: Preset>(fork: Fork, payload: Option<Bounds>) -> Vec<Bounds> {
+    let mut fields = phase0_body::<P>(
+        Bounds::list(
+            Phase0AttesterSlashing::<P>::ssz_bounds(),
+            P::MaxAttesterSlashings::U64,
+        ),
+        Bounds::list(
+            Phase0Attestation::<P>::ssz_bounds(),
+            P::MaxAttestations::U64,
+        ),
+    );
+
+    if fork >= Fork::Altair {
+        fields.push(Bounds::fixed(sync_aggregate::<P>()));
+    }
+    fields.extend(payload);
+    if fork >= Fork::Capella {
+        fields.push(Bounds::list_of(
+            SIGNED_BLS_TO_EXECUTION_CHANGE,
+            P::MaxBlsToExecutionChanges::U64,
+        ));
+    }
+    if fork >= Fork::Deneb {
+        fields.push(Bounds::list_of(KZG, P::MaxBlobCommitmentsPerBlock::U64));
+    }
+
+    fields
+}
+
+/// The fixed size of the block body of `fork`, which its first offset holds.
+///
+/// It tells the block layouts of the forks apart, except those of Electra and
+/// Fulu, and is the same for blinded blocks.
+pub fn block_body_fixed_size<P: Preset>(fork: Fork) -> u64 {
+    let payload = match fork {
+        Fork::Phase0 | Fork::Altair => None,
+        Fork::Bellatrix => Some(Bounds::container(&bellatrix_payload::<P>(false))),
+        Fork::Capella => Some(Bounds::container(&capella_payload::<P>(false))),
+        Fork::Deneb => Some(ExecutionPayload::<P>::ssz_bounds()),
+        Fork::Electra | Fork::Fulu => {
+            return block_body::<P>(ExecutionPayload::<P>::ssz_bounds()).fixed;
+        }
+    };
+
+    Bounds::container(&pre_electra_body::<P>(fork, payload)).fixed
+}
+
+/// The body of a block, with `payload` as the execution payload or header.
+fn block_body<P: Preset>(payload: Bounds) -> Bounds {
+    let mut fields = phase0_body::<P>(
+        Bounds::list(
+            AttesterSlashing::<P>::ssz_bounds(),
+            P::MaxAttesterSlashingsElectra::U64,
+        ),
+        Bounds::list(
+            Attestation::<P>::ssz_bounds(),
+            P::MaxAttestationsElectra::U64,
+        ),
+    );
+
+    fields.extend([
+        Bounds::fixed(sync_aggregate::<P>()),
+        payload,
+        Bounds::list_of(
+            SIGNED_BLS_TO_EXECUTION_CHANGE,
+            P::MaxBlsToExecutionChanges::U64,
+        ),
+        Bounds::list_of(KZG, P::MaxBlobCommitmentsPerBlock::U64),
+        execution_requests::<P>(),
+    ]);
+    Bounds::container(&fields)
+}
+
+/// A block with the given body.
+fn block(body: Bounds) -> Bounds {
+    Bounds::container(&[
+        Bounds::fixed(8),
+        Bounds::fixed(8),
+        Bounds::fixed(BYTES32),
+        Bounds::fixed(BYTES32),
+        body,
+    ])
+}
+
+fn signed(message: Bounds) -> Bounds {
+    Bounds::container(&[message, Bounds::fixed(SIGNATURE)])
+}
+
+/// The KZG proofs and blobs following a block in block contents.
+fn blob_lists<P: Preset>() -> [Bounds; 2] {
+    let blob = BYTES32 * P::FieldElementsPerBlob::U64;
+
+    [
+        Bounds::list_of(KZG, P::MaxBlobCommitmentsPerBlock::U64),
+        Bounds::list_of(blob, P::MaxBlobCommitmentsPerBlock::U64),
+    ]
+}
+
+/// Bounds of the cell proof and blob lists of Fulu block contents.
+fn cell_proof_blob_lists<P: Preset>() -> [Bounds; 2] {
+    let [_, blobs] = blob_lists::<P>();
+    [Bounds::list_of(KZG, P::MaxCellProofsPerBlock::U64), blobs]
+}
+
+/// The fields of an Electra beacon state, followed by `extra` fields of
+/// later forks.
+fn beacon_state<P: Preset>(extra: &[Bounds]) -> Bounds {
+    let slots_per_historical_root = P::EpochsPerHistoricalRoot::U64 * P::SlotsPerEpoch::U64;
+    let registry_limit = P::ValidatorRegistryLimit::U64;
+
+    let mut fields = vec![
+        Bounds::fixed(8),
+        Bounds::fixed(BYTES32),
+        Bounds::fixed(8),
+        Bounds::fixed(FORK),
+        Bounds::fixed(BEACON_BLOCK_HEADER),
+        Bounds::fixed(slots_per_historical_root * BYTES32),
+        Bounds::fixed(slots_per_historical_root * BYTES32),
+        Bounds::list_of(BYTES32, P::HistoricalRootsLimit::U64),
+        Bounds::fixed(ETH1_DATA),
+        Bounds::list_of(
+            ETH1_DATA,
+            P::EpochsPerEth1VotingPeriod::U64 * P::SlotsPerEpoch::U64,
+        ),
+        Bounds::fixed(8),
+        Bounds::list_of(VALIDATOR, registry_limit),
+        Bounds::list_of(8, registry_limit),
+        Bounds::fixed(P::EpochsPerHistoricalVector::U64 * BYTES32),
+        Bounds::fixed(P::EpochsPerSlashingsVector::U64 * 8),
+        Bounds::list_of(1, registry_limit),
+        Bounds::list_of(1, registry_limit),
+        Bounds::fixed(bitvector(4)),
+        Bounds::fixed(CHECKPOINT),
+        Bounds::fixed(CHECKPOINT),
+        Bounds::fixed(CHECKPOINT),
+        Bounds::list_of(8, registry_limit),
+        Bounds::fixed(sync_committee::<P>()),
+        Bounds::fixed(sync_committee::<P>()),
+        ExecutionPayloadHeader::<P>::ssz_bounds(),
+        Bounds::fixed(8),
+        Bounds::fixed(8),
+        Bounds::list_of(HISTORICAL_SUMMARY, P::HistoricalRootsLimit::U64),
+        Bounds::fixed(8),
+        Bounds::fixed(8),
+        Bounds::fixed(8),
+        Bounds::fixed(8),
+        Bounds::fixed(8),
+        Bounds::fixed(8),
+        Bounds::list_of(PENDING_DEPOSIT, P::PendingDepositsLimit::U64),
+        Bounds::list_of(
+            PENDING_PARTIAL_WITHDRAWAL,
+            P::PendingPartialWithdrawalsLimit::U64,
+        ),
+        Bounds::list_of(PENDING_CONSOLIDATION, P::PendingConsolidationsLimit::U64),
+    ];
+
+    fields.extend_from_slice(extra);
+    Bounds::container(&fields)
+}
+
+impl<P: Preset> SszBounds for Phase0Attestation<P> {
+    fn ssz_bounds() -> Bounds {
+        Bounds::container(&[
+            Bounds::bitlist(P::MaxValidatorsPerCommittee::U64),
+            Bounds::fixed(ATTESTATION_DATA),
+            Bounds::fixed(SIGNATURE),
+        ])
+    }
+}
+
+impl<P: Preset> SszBounds for Phase0IndexedAttestation<P> {
+    fn ssz_bounds() -> Bounds {
+        Bounds::container(&[
+            Bounds::list_of(8, P::MaxValidatorsPerCommittee::U64),
+            Bounds::fixed(ATTESTATION_DATA),
+            Bounds::fixed(SIGNATURE),
+        ])
+    }
+}
+
+impl<P: Preset> SszBounds for Phase0AttesterSlashing<P> {
+    fn ssz_bounds() -> Bounds {
+        let indexed_attestation = Phase0IndexedAttestation::<P>::ssz_bounds();
+        Bounds::container(&[indexed_attestation, indexed_attestation])
+    }
+}
+
+impl<P: Preset> SszBounds for Attestation<P> {
+    fn ssz_bounds() -> Bounds {
+        Bounds::container(&[
+            Bounds::bitlist(electra_aggregation_limit::<P>()),
+            Bounds::fixed(ATTESTATION_DATA),
+            Bounds::fixed(SIGNATURE),
+            Bounds::fixed(bitvector(P::MaxCommitteesPerSlot::U64)),
+        ])
+    }
+}
+
+impl<P: Preset> SszBounds for IndexedAttestation<P> {
+    fn ssz_bounds() -> Bounds {
+        Bounds::container(&[
+            Bounds::list_of(8, electra_aggregation_limit::<P>()),
+            Bounds::fixed(ATTESTATION_DATA),
+            Bounds::fixed(SIGNATURE),
+        ])
+    }
+}
+
+impl<P: Preset> SszBounds for AttesterSlashing<P> {
+    fn ssz_bounds() -> Bounds {
+        let indexed_attestation = IndexedAttestation::<P>::ssz_bounds();
+        Bounds::container(&[indexed_attestation, indexed_attestation])
+    }
+}
+
+impl<P: Preset> SszBounds for AggregateAndProof<P> {
+    fn ssz_bounds() -> Bounds {
+        Bounds::container(&[
+            Bounds::fixed(8),
+            Attestation::<P>::ssz_bounds(),
+            Bounds::fixed(SIGNATURE),
+        ])
+    }
+}
+
+impl<P: Preset> SszBounds for SignedAggregateAndProof<P> {
+    fn ssz_bounds() -> Bounds {
+        Bounds::container(&[
+            AggregateAndProof::<P>::ssz_bounds(),
+            Bounds::fixed(SIGNATURE),
+        ])
+    }
+}
+
+impl SszBounds for SingleAttestation {
+    fn ssz_bounds() -> Bounds {
+        Bounds::fixed(8 + 8 + ATTESTATION_DATA + SIGNATURE)
+    }
+}
+
+impl SszBounds for SyncCommitteeMessage {
+    fn ssz_bounds() -> Bounds {
+        Bounds::fixed(8 + BYTES32 + 8 + SIGNATURE)
+    }
+}
+
+impl<P: Preset> SszBounds for SyncCommitteeContribution<P> {
+    fn ssz_bounds() -> Bounds {
+        let subcommittee_size = P::SyncCommitteeSize::U64 / SYNC_COMMITTEE_SUBNET_COUNT;
+        Bounds::fixed(8 + BYTES32 + 8 + bitvector(subcommittee_size) + SIGNATURE)
+    }
+}
+
+impl<P: Preset> SszBounds for ContributionAndProof<P> {
+    fn ssz_bounds() -> Bounds {
+        Bounds::container(&[
+            Bounds::fixed(8),
+            SyncCommitteeContribution::<P>::ssz_bounds(),
+            Bounds::fixed(SIGNATURE),
+        ])
+    }
+}
+
+impl<P: Preset> SszBounds for SignedContributionAndProof<P> {
+    fn ssz_bounds() -> Bounds {
+        Bounds::container(&[
+            ContributionAndProof::<P>::ssz_bounds(),
+            Bounds::fixed(SIGNATURE),
+        ])
+    }
+}
+
+impl SszBounds for SignedBeaconBlockHeader {
+    fn ssz_bounds() -> Bounds {
+        Bounds::fixed(SIGNED_BEACON_BLOCK_HEADER)
+    }
+}
+
+impl SszBounds for DepositData {
+    fn ssz_bounds() -> Bounds {
+        Bounds::fixed(PUBKEY + BYTES32 + 8 + SIGNATURE)
+    }
+}
+
+impl SszBounds for ProposerSlashing {
+    fn ssz_bounds() -> Bounds {
+        Bounds::fixed(PROPOSER_SLASHING)
+    }
+}
+
+impl SszBounds for Deposit {
+    fn ssz_bounds() -> Bounds {
+        Bounds::fixed(DEPOSIT)
+    }
+}
+
+impl SszBounds for VoluntaryExit {
+    fn ssz_bounds() -> Bounds {
+        Bounds::fixed(VOLUNTARY_EXIT)
+    }
+}
+
+impl SszBounds for SignedVoluntaryExit {
+    fn ssz_bounds() -> Bounds {
+        Bounds::fixed(SIGNED_VOLUNTARY_EXIT)
+    }
+}
+
+impl SszBounds for BlsToExecutionChange {
+    fn ssz_bounds() -> Bounds {
+        Bounds::fixed(BLS_TO_EXECUTION_CHANGE)
+    }
+}
+
+impl SszBounds for SignedBlsToExecutionChange {
+    fn ssz_bounds() -> Bounds {
+        Bounds::fixed(SIGNED_BLS_TO_EXECUTION_CHANGE)
+    }
+}
+
+impl<P: Preset> SszBounds for ExecutionPayload<P> {
+    fn ssz_bounds() -> Bounds {
+        let mut fields = capella_payload::<P>(false);
+        fields.extend([Bounds::fixed(8), Bounds::fixed(8)]);
+        Bounds::container(&fields)
+    }
+}
+
+impl<P: Preset> SszBounds for ExecutionPayloadHeader<P> {
+    fn ssz_bounds() -> Bounds {
+        let mut fields = capella_payload::<P>(true);
+        fields.extend([Bounds::fixed(8), Bounds::fixed(8)]);
+        Bounds::container(&fields)
+    }
+}
+
+impl<P: Preset> SszBounds for phase0::containers::SignedBeaconBlock<P> {
+    fn ssz_bounds() -> Bounds {
+        let body = pre_electra_body::<P>(Fork::Phase0, None);
+        signed(block(Bounds::container(&body)))
+    }
+}
+
+impl<P: Preset> SszBounds for altair::containers::SignedBeaconBlock<P> {
+    fn ssz_bounds() -> Bounds {
+        let body = pre_electra_body::<P>(Fork::Altair, None);
+        signed(block(Bounds::container(&body)))
+    }
+}
+
+impl<P: Preset> SszBounds for bellatrix::containers::SignedBeaconBlock<P> {
+    fn ssz_bounds() -> Bounds {
+        let payload = Bounds::container(&bellatrix_payload::<P>(false));
+        let body = pre_electra_body::<P>(Fork::Bellatrix, Some(payload));
+        signed(block(Bounds::container(&body)))
+    }
+}
+
+impl<P: Preset> SszBounds for bellatrix::containers::SignedBlindedBeaconBlock<P> {
+    fn ssz_bounds() -> Bounds {
+        let header = Bounds::container(&bellatrix_payload::<P>(true));
+        let body = pre_electra_body::<P>(Fork::Bellatrix, Some(header));
+        signed(block(Bounds::container(&body)))
+    }
+}
+
+impl<P: Preset> SszBounds for capella::containers::SignedBeaconBlock<P> {
+    fn ssz_bounds() -> Bounds {
+        let payload = Bounds::container(&capella_payload::<P>(false));
+        let body = pre_electra_body::<P>(Fork::Capella, Some(payload));
+        signed(block(Bounds::container(&body)))
+    }
+}
+
+impl<P: Preset> SszBounds for capella::containers::SignedBlindedBeaconBlock<P> {
+    fn ssz_bounds() -> Bounds {
+        let header = Bounds::container(&capella_payload::<P>(true));
+        let body = pre_electra_body::<P>(Fork::Capella, Some(header));
+        signed(block(Bounds::container(&body)))
+    }
+}
+
+impl<P: Preset> SszBounds for deneb::containers::SignedBeaconBlock<P> {
+    fn ssz_bounds() -> Bounds {
+        let payload = ExecutionPayload::<P>::ssz_bounds();
+        let body = pre_electra_body::<P>(Fork::Deneb, Some(payload));
+        signed(block(Bounds::container(&body)))
+    }
+}
+
+impl<P: Preset> SszBounds for deneb::containers::SignedBlindedBeaconBlock<P> {
+    fn ssz_bounds() -> Bounds {
+        let header = ExecutionPayloadHeader::<P>::ssz_bounds();
+        let body = pre_electra_body::<P>(Fork::Deneb, Some(header));
+        signed(block(Bounds::container(&body)))
+    }
+}
+
+impl<P: Preset> SszBounds for SignedBeaconBlock<P> {
+    fn ssz_bounds() -> Bounds {
+        signed(block(block_body::<P>(ExecutionPayload::<P>::ssz_bounds())))
+    }
+}
+
+impl<P: Preset> SszBounds for BlindedBeaconBlock<P> {
+    fn ssz_bounds() -> Bounds {
+        block(block_body::<P>(ExecutionPayloadHeader::<P>::ssz_bounds()))
+    }
+}
+
+impl<P: Preset> SszBounds for SignedBlindedBeaconBlock<P> {
+    fn ssz_bounds() -> Bounds {
+        signed(BlindedBeaconBlock::<P>::ssz_bounds())
+    }
+}
+
+impl<P: Preset> SszBounds for BeaconBlockContents<P> {
+    fn ssz_bounds() -> Bounds {
+        let [kzg_proofs, blobs] = blob_lists::<P>();
+        let block = block(block_body::<P>(ExecutionPayload::<P>::ssz_bounds()));
+        Bounds::container(&[block, kzg_proofs, blobs])
+    }
+}
+
+impl<P: Preset> SszBounds for SignedBeaconBlockContents<P> {
+    fn ssz_bounds() -> Bounds {
+        let [kzg_proofs, blobs] = blob_lists::<P>();
+        Bounds::container(&[SignedBeaconBlock::<P>::ssz_bounds(), kzg_proofs, blobs])
+    }
+}
+
+impl<P: Preset> SszBounds for FuluBeaconBlockContents<P> {
+    fn ssz_bounds() -> Bounds {
+        let [kzg_proofs, blobs] = cell_proof_blob_lists::<P>();
+        let block = block(block_body::<P>(ExecutionPayload::<P>::ssz_bounds()));
+        Bounds::container(&[block, kzg_proofs, blobs])
+    }
+}
+
+impl<P: Preset> SszBounds for FuluSignedBeaconBlockContents<P> {
+    fn ssz_bounds() -> Bounds {
+        let [kzg_proofs, blobs] = cell_proof_blob_lists::<P>();
+        Bounds::container(&[SignedBeaconBlock::<P>::ssz_bounds(), kzg_proofs, blobs])
+    }
+}
+
+impl<P: Preset> SszBounds for ExecutionPayloadAndBlobsBundle<P> {
+    fn ssz_bounds() -> Bounds {
+        let [kzg_proofs, blobs] = blob_lists::<P>();
+        let commitments = Bounds::list_of(KZG, P::MaxBlobCommitmentsPerBlock::U64);
+        let blobs_bundle = Bounds::container(&[commitments, kzg_proofs, blobs]);
+        Bounds::container(&[ExecutionPayload::<P>::ssz_bounds(), blobs_bundle])
+    }
+}
+
+/// Bounds of the Electra layout, the only one `from_ssz` accepts.
+impl<P: Preset> SszBounds for SignedBuilderBid<P> {
+    fn ssz_bounds() -> Bounds {
+        let builder_bid = Bounds::container(&[
+            ExecutionPayloadHeader::<P>::ssz_bounds(),
+            Bounds::list_of(KZG, P::MaxBlobCommitmentsPerBlock::U64),
+            execution_requests::<P>(),
+            Bounds::fixed(BYTES32),
+            Bounds::fixed(PUBKEY),
+        ]);
+
+        signed(builder_bid)
+    }
+}
+
+impl SszBounds for ValidatorRegistrationV1 {
+    fn ssz_bounds() -> Bounds {
+        Bounds::fixed(VALIDATOR_REGISTRATION)
+    }
+}
+
+impl SszBounds for SignedValidatorRegistrationV1 {
+    fn ssz_bounds() -> Bounds {
+        Bounds::fixed(SIGNED_VALIDATOR_REGISTRATION)
+    }
+}
+
+/// The light client header of `fork`.
+fn light_client_header<P: Preset>(fork: Fork) -> Bounds {
+    if fork < Fork::Capella {
+        return Bounds::fixed(BEACON_BLOCK_HEADER);
+    }
+
+    let mut payload_header = capella_payload::<P>(true);
+    if fork >= Fork::Deneb {
+        payload_header.extend([Bounds::fixed(8), Bounds::fixed(8)]);
+    }
+
+    Bounds::container(&[
+        Bounds::fixed(BEACON_BLOCK_HEADER),
+        Bounds::container(&payload_header),
+        Bounds::fixed(EXECUTION_BRANCH),
+    ])
+}
+
+/// The sync committee and finality branches of `fork`. Electra deepened the
+/// beacon state tree by a level.
+fn light_client_branches(fork: Fork) -> (Bounds, Bounds) {
+    let sync_committee_depth = if fork >= Fork::Electra { 6 } else { 5 };
+
+    (
+        Bounds::fixed(sync_committee_depth * BYTES32),
+        Bounds::fixed((sync_committee_depth + 1) * BYTES32),
+    )
+}
+
+macro_rules! light_client_bounds {
+    ($module:ident, $fork:ident) => {
+        impl<P: Preset> SszBounds for $module::containers::LightClientBootstrap<P> {
+            fn ssz_bounds() -> Bounds {
+                let (sync_committee_branch, _) = light_client_branches(Fork::$fork);
+
+                Bounds::container(&[
+                    light_client_header::<P>(Fork::$fork),
+                    Bounds::fixed(sync_committee::<P>()),
+                    sync_committee_branch,
+                ])
+            }
+        }
+
+        impl<P: Preset> SszBounds for $module::containers::LightClientUpdate<P> {
+            fn ssz_bounds() -> Bounds {
+                let header = light_client_header::<P>(Fork::$fork);
+                let (sync_committee_branch, finality_branch) = light_client_branches(Fork::$fork);
+
+                Bounds::container(&[
+                    header,
+                    Bounds::fixed(sync_committee::<P>()),
+                    sync_committee_branch,
+                    header,
+                    finality_branch,
+                    Bounds::fixed(sync_aggregate::<P>()),
+                    Bounds::fixed(8),
+                ])
+            }
+        }
+
+        impl<P: Preset> SszBounds for $module::containers::LightClientFinalityUpdate<P> {
+            fn ssz_bounds() -> Bounds {
+                let header = light_client_header::<P>(Fork::$fork);
+                let (_, finality_branch) = light_client_branches(Fork::$fork);
+
+                Bounds::container(&[
+                    header,
+                    header,
+                    finality_branch,
+                    Bounds::fixed(sync_aggregate::<P>()),
+                    Bounds::fixed(8),
+                ])
+            }
+        }
+
+        impl<P: Preset> SszBounds for $module::containers::LightClientOptimisticUpdate<P> {
+            fn ssz_bounds() -> Bounds {
+                Bounds::container(&[
+                    light_client_header::<P>(Fork::$fork),
+                    Bounds::fixed(sync_aggregate::<P>()),
+                    Bounds::fixed(8),
+                ])
+            }
+        }
+    };
+}
+
+light_client_bounds!(altair, Altair);
+light_client_bounds!(capella, Capella);
+light_client_bounds!(deneb, Deneb);
+light_client_bounds!(electra, Electra);
+
+impl<P: Preset> SszBounds for grandine_types::electra::beacon_state::BeaconState<P> {
+    fn ssz_bounds() -> Bounds {
+        beacon_state::<P>(&[])
+    }
+}
+
+impl<P: Preset> SszBounds for grandine_types::fulu::beacon_state::BeaconState<P> {
+    fn ssz_bounds() -> Bounds {
+        let lookahead = (P::MinSeedLookahead::U64 + 1) * P::SlotsPerEpoch::U64;
+        beacon_state::<P>(&[Bounds::fixed(lookahead * 8)])
+    }
+}
+
+impl<P: Preset> SszBounds for BlobSidecar<P> {
+    fn ssz_bounds() -> Bounds {
+        let blob = BYTES32 * P::FieldElementsPerBlob::U64;
+        let inclusion_proof = BYTES32 * P::KzgCommitmentInclusionProofDepth::U64;
+        Bounds::fixed(8 + blob + KZG + KZG + SIGNED_BEACON_BLOCK_HEADER + inclusion_proof)
+    }
+}
+
+impl<P: Preset> SszBounds for DataColumnSidecar<P> {
+    fn ssz_bounds() -> Bounds {
+        let cell = BYTES32 * P::FieldElementsPerCell::U64;
+        let limit = P::MaxBlobCommitmentsPerBlock::U64;
+        Bounds::container(&[
+            Bounds::fixed(8),
+            Bounds::list_of(cell, limit),
+            Bounds::list_of(KZG, limit),
+            Bounds::list_of(KZG, limit),
+            Bounds::fixed(SIGNED_BEACON_BLOCK_HEADER),
+            Bounds::fixed(BYTES32 * P::KzgCommitmentsInclusionProofDepth::U64),
+        ])
+    }
+}
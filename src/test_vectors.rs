@@ -0,0 +1,738 @@
+//! Export of SSZ test vectors in the consensus-spec `ssz_static` format.
+//!
+//! `export_test_vectors` writes randomly generated instances of every
+//! registered type as
+//!
+//! ```text
+//! <dir>/<preset>/<fork>/ssz_static/<Type>/ssz_random/case_<n>/
+//!     value.yaml           the value, with uints as YAML ints
+//!     serialized.ssz_snappy  the SSZ encoding, snappy block compressed
+//!     roots.yaml           `root: '0x…'`, the hash tree root
+//! ```
+//!
+//! so that other SSZ implementations can be tested against Grandine. Types
+//! are listed under the fork that introduced them; the `spec_tests` module
+//! runs cases from the same table.
+//! `value.yaml` is written in YAML flow style (JSON with unquoted integers),
+//! which keeps container fields in declaration order.
+//!
+//! Instances are generated without type information: starting from the
+//! default value, every group of JSON leaves with the same path (ignoring
+//! list indices) is replaced by random values of the same shape, and the
+//! change is kept only if the result still decodes. Empty lists are first
+//! grown in the same way, with up to `MAX_LIST_GROWTH` elements of the first
+//! shape that decodes: a few basic shapes (uints, byte vectors of common
+//! sizes), then the default values of the containers blocks and states hold
+//! in lists. Lists that only decode shorter, such as the single attester
+//! slashing of Electra blocks, are grown up to their limit, and lists inside
+//! grown elements are grown in turn. Lists of byte vectors of other sizes,
+//! such as blobs, stay empty. Output is deterministic for a given `seed` and
+//! does not depend on `set_hex_format`.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use grandine_ssz::{SszHash, SszWrite};
+use grandine_types::altair::containers::SignedBeaconBlock as AltairSignedBeaconBlock;
+use grandine_types::bellatrix::containers::{
+    SignedBeaconBlock as BellatrixSignedBeaconBlock,
+    SignedBlindedBeaconBlock as BellatrixSignedBlindedBeaconBlock,
+};
+use grandine_types::capella::containers::{
+    BlsToExecutionChange, HistoricalSummary, SignedBeaconBlock as CapellaSignedBeaconBlock,
+    SignedBlindedBeaconBlock as CapellaSignedBlindedBeaconBlock, SignedBlsToExecutionChange,
+    Withdrawal,
+};
+use grandine_types::deneb::containers::{
+    BlobSidecar, ExecutionPayload, ExecutionPayloadHeader,
+    SignedBeaconBlock as DenebSignedBeaconBlock,
+    SignedBlindedBeaconBlock as DenebSignedBlindedBeaconBlock,
+};
+use grandine_types::electra::beacon_state::BeaconState;
+use grandine_types::electra::containers::{
+    AggregateAndProof, Attestation, AttesterSlashing, BlindedBeaconBlock, ConsolidationRequest,
+    DepositRequest, IndexedAttestation, PendingConsolidation, PendingDeposit,
+    PendingPartialWithdrawal, SignedAggregateAndProof, SignedBeaconBlock, SignedBlindedBeaconBlock,
+    SingleAttestation, WithdrawalRequest,
+};
+use grandine_types::fulu::beacon_state::BeaconState as FuluBeaconState;
+use grandine_types::fulu::containers::DataColumnSidecar;
+use grandine_types::phase0::containers::{
+    Attestation as Phase0Attestation, AttestationData, AttesterSlashing as Phase0AttesterSlashing,
+    BeaconBlockHeader, Checkpoint, Deposit, DepositData, Eth1Data, ProposerSlashing,
+    SignedBeaconBlock as Phase0SignedBeaconBlock, SignedBeaconBlockHeader, SignedVoluntaryExit,
+    Validator, VoluntaryExit,
+};
+use grandine_types::preset::{Mainnet, Minimal, Preset};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::convert::is_decimal;
+use crate::electra::block::{BeaconBlockContents, SignedBeaconBlockContents};
+use crate::electra::execution::ExecutionPayloadAndBlobsBundle;
+use crate::fulu::block::{
+    BeaconBlockContents as FuluBeaconBlockContents,
+    SignedBeaconBlockContents as FuluSignedBeaconBlockContents,
+};
+use crate::presets::NamedPreset;
+use crate::Gnosis;
+
+/// Attempts at a random replacement before a group of leaves is left as is.
+const ATTEMPTS: usize = 4;
+/// Most elements added to an empty list.
+const MAX_LIST_GROWTH: u64 = 3;
+/// Basic element shapes tried when growing an empty list: a uint (`None`)
+/// or a byte vector of the given size.
+const LIST_ELEMENT_SIZES: &[Option<usize>] =
+    &[None, Some(0), Some(20), Some(32), Some(48), Some(96)];
+/// Rounds of list growth, each growing the empty lists of the previous one.
+const LIST_GROWTH_ROUNDS: usize = 3;
+
+/// A small deterministic PRNG (SplitMix64).
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn bytes(&mut self, length: usize) -> Vec<u8> {
+        (0..length).map(|_| self.next().to_le_bytes()[0]).collect()
+    }
+}
+
+/// Returns a random value with the shape of `value`. Later attempts use
+/// smaller integers, for fields narrower than 64 bits.
+fn random_like(value: &Value, attempt: usize, rng: &mut Rng) -> Value {
+    match value {
+        Value::Bool(_) => Value::Bool(rng.below(2) == 1),
+        Value::String(string) if is_decimal(string) => {
+            let integer = match attempt {
+                0 => rng.next(),
+                1 => rng.below(1 << 32),
+                _ => rng.below(1 << 8),
+            };
+            Value::String(integer.to_string())
+        }
+        Value::String(string) => match string.strip_prefix("0x") {
+            Some(digits) => {
+                // Empty strings are byte lists: try random lengths before
+                // leaving them empty.
+                let length = match digits.len() / 2 {
+                    0 if attempt + 1 < ATTEMPTS => {
+                        usize::try_from(rng.below(32)).unwrap_or_default() + 1
+                    }
+                    length => length,
+                };
+                Value::String(format!("0x{}", hex::encode(rng.bytes(length))))
+            }
+            None => value.clone(),
+        },
+        _ => value.clone(),
+    }
+}
+
+/// Returns the element shapes tried when growing an empty list, in order: the
+/// basic shapes, then the default values of containers held in lists.
+fn list_elements<P: Preset>() -> Vec<Value> {
+    let basic = LIST_ELEMENT_SIZES.iter().map(|size| match size {
+        None => Value::String("0".to_owned()),
+        Some(size) => Value::String(format!("0x{}", "00".repeat(*size))),
+    });
+
+    let containers = [
+        serde_json::to_value(Phase0Attestation::<P>::default()),
+        serde_json::to_value(Attestation::<P>::default()),
+        serde_json::to_value(Phase0AttesterSlashing::<P>::default()),
+        serde_json::to_value(AttesterSlashing::<P>::default()),
+        serde_json::to_value(ProposerSlashing::default()),
+        serde_json::to_value(Deposit::default()),
+        serde_json::to_value(SignedVoluntaryExit::default()),
+        serde_json::to_value(SignedBlsToExecutionChange::default()),
+        serde_json::to_value(Withdrawal::default()),
+        serde_json::to_value(DepositRequest::default()),
+        serde_json::to_value(WithdrawalRequest::default()),
+        serde_json::to_value(ConsolidationRequest::default()),
+        serde_json::to_value(Eth1Data::default()),
+        serde_json::to_value(Validator::default()),
+        serde_json::to_value(HistoricalSummary::default()),
+        serde_json::to_value(PendingDeposit::default()),
+        serde_json::to_value(PendingPartialWithdrawal::default()),
+        serde_json::to_value(PendingConsolidation::default()),
+    ];
+
+    basic
+        .chain(containers.into_iter().filter_map(Result::ok))
+        .collect()
+}
+
+/// Pointers to the leaves or empty lists of a JSON value, grouped by path
+/// with list indices replaced by `*`, in traversal order.
+#[derive(Default)]
+struct Groups {
+    order: Vec<String>,
+    pointers: HashMap<String, Vec<String>>,
+}
+
+impl Groups {
+    fn collect(value: &Value, empty_lists: bool) -> Vec<Vec<String>> {
+        let mut groups = Self::default();
+        groups.visit(value, String::new(), String::new(), empty_lists);
+        let Self {
+            order,
+            mut pointers,
+        } = groups;
+        order
+            .into_iter()
+            .filter_map(|pattern| pointers.remove(&pattern))
+            .collect()
+    }
+
+    fn visit(&mut self, value: &Value, pointer: String, pattern: String, empty_lists: bool) {
+        match value {
+            Value::Object(map) => {
+                for (key, item) in map {
+                    self.visit(
+                        item,
+                        format!("{pointer}/{key}"),
+                        format!("{pattern}/{key}"),
+                        empty_lists,
+                    );
+                }
+            }
+            Value::Array(items) if !items.is_empty() => {
+                for (index, item) in items.iter().enumerate() {
+                    self.visit(
+                        item,
+                        format!("{pointer}/{index}"),
+                        format!("{pattern}/*"),
+                        empty_lists,
+                    );
+                }
+            }
+            Value::Array(_) => {
+                if empty_lists {
+                    self.push(pattern, pointer);
+                }
+            }
+            _ => {
+                if !empty_lists {
+                    self.push(pattern, pointer);
+                }
+            }
+        }
+    }
+
+    fn push(&mut self, pattern: String, pointer: String) {
+        if !self.pointers.contains_key(&pattern) {
+            self.order.push(pattern.clone());
+        }
+        self.pointers.entry(pattern).or_default().push(pointer);
+    }
+}
+
+fn decodes<T: DeserializeOwned>(value: &Value) -> bool {
+    T::deserialize(value).is_ok()
+}
+
+/// Replaces the values at `pointers`, returning the previous values.
+fn replace(root: &mut Value, pointers: &[String], values: Vec<Value>) -> Vec<Value> {
+    pointers
+        .iter()
+        .zip(values)
+        .filter_map(|(pointer, value)| {
+            root.pointer_mut(pointer)
+                .map(|slot| std::mem::replace(slot, value))
+        })
+        .collect()
+}
+
+/// Sets the value at `pointer` to `candidate` if the result decodes,
+/// returning whether it did.
+fn try_set<T: DeserializeOwned>(root: &mut Value, pointer: &str, candidate: Value) -> bool {
+    let pointers = [pointer.to_owned()];
+    let previous = replace(root, &pointers, vec![candidate]);
+
+    if decodes::<T>(root) {
+        return true;
+    }
+
+    replace(root, &pointers, previous);
+    false
+}
+
+/// Grows the empty list at `pointer` with copies of the first of `elements`
+/// that decodes, as many as a random length up to `MAX_LIST_GROWTH` that the
+/// list limit allows. Returns whether the list was grown.
+fn grow_list<T: DeserializeOwned>(
+    root: &mut Value,
+    pointer: &str,
+    elements: &[Value],
+    rng: &mut Rng,
+) -> bool {
+    let length = usize::try_from(rng.below(MAX_LIST_GROWTH)).unwrap_or_default() + 1;
+
+    let Some(element) = elements
+        .iter()
+        .find(|element| try_set::<T>(root, pointer, Value::Array(vec![(*element).clone()])))
+    else {
+        return false;
+    };
+
+    for length in (2..=length).rev() {
+        if try_set::<T>(root, pointer, Value::Array(vec![element.clone(); length])) {
+            break;
+        }
+    }
+
+    true
+}
+
+/// Grows the empty lists of `root` with elements of `elements`, then the
+/// empty lists inside the new elements, for up to `LIST_GROWTH_ROUNDS`
+/// rounds. Lists that cannot be grown are not tried again.
+fn grow_lists<T: DeserializeOwned>(root: &mut Value, elements: &[Value], rng: &mut Rng) {
+    let mut stuck = HashSet::new();
+
+    for _ in 0..LIST_GROWTH_ROUNDS {
+        let mut grown = false;
+
+        for pointer in Groups::collect(root, true).into_iter().flatten() {
+            if stuck.contains(&pointer) {
+                continue;
+            }
+
+            if grow_list::<T>(root, &pointer, elements, rng) {
+                grown = true;
+            } else {
+                stuck.insert(pointer);
+            }
+        }
+
+        if !grown {
+            break;
+        }
+    }
+}
+
+/// Replaces the leaves of `root` with random values of the same shape.
+fn randomize_leaves<T: DeserializeOwned>(root: &mut Value, rng: &mut Rng) {
+    for pointers in Groups::collect(root, false) {
+        for attempt in 0..ATTEMPTS {
+            let values = pointers
+                .iter()
+                .filter_map(|pointer| root.pointer(pointer))
+                .map(|value| random_like(value, attempt, rng))
+                .collect();
+            let previous = replace(root, &pointers, values);
+
+            if decodes::<T>(root) {
+                break;
+            }
+
+            replace(root, &pointers, previous);
+        }
+    }
+}
+
+/// Converts compact JSON to YAML flow style by unquoting decimal string
+/// values, which are the JSON encoding of uints.
+fn json_to_yaml(json: &str) -> String {
+    let mut yaml = String::with_capacity(json.len() + 1);
+    let mut rest = json;
+
+    while let Some(start) = rest.find('"') {
+        yaml.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        // Strings in the output are hex or decimal, so they have no escapes.
+        let end = rest[1..].find('"').map_or(rest.len(), |end| end + 2);
+        let (string, after) = rest.split_at(end);
+        let contents = string.trim_matches('"');
+
+        if is_decimal(contents) && !after.starts_with(':') {
+            yaml.push_str(contents);
+        } else {
+            yaml.push_str(string);
+        }
+
+        rest = after;
+    }
+
+    yaml.push_str(rest);
+    yaml.push('\n');
+    yaml
+}
+
+/// The files of one test case.
+struct Case {
+    value: String,
+    serialized: Vec<u8>,
+    root: String,
+}
+
+/// Generates a random instance of `T` of preset `P` and encodes it as a
+/// test case.
+fn generate<P, T>(rng: &mut Rng) -> Result<Case, String>
+where
+    P: Preset,
+    T: Default + Serialize + DeserializeOwned + SszWrite + SszHash,
+{
+    let mut value = serde_json::to_value(T::default()).map_err(|e| e.to_string())?;
+
+    grow_lists::<T>(&mut value, &list_elements::<P>(), rng);
+    randomize_leaves::<T>(&mut value, rng);
+
+    let instance = T::deserialize(&value).map_err(|e| e.to_string())?;
+    let json = serde_json::to_string(&instance).map_err(|e| e.to_string())?;
+    let ssz = crate::encode_ssz(&instance)?;
+
+    Ok(Case {
+        value: json_to_yaml(&json),
+        serialized: snap::raw::Encoder::new()
+            .compress_vec(&ssz)
+            .map_err(|e| e.to_string())?,
+        root: format!(
+            "root: '0x{}'\n",
+            hex::encode(instance.hash_tree_root().as_bytes())
+        ),
+    })
+}
+
+/// A type for which test vectors are exported.
+pub(crate) struct Entry {
+    pub(crate) preset: &'static str,
+    pub(crate) fork: &'static str,
+    pub(crate) name: &'static str,
+    generate: fn(&mut Rng) -> Result<Case, String>,
+    /// Runs an `ssz_static` case of the type (see `spec_tests`).
+    #[cfg(feature = "spec_tests")]
+    pub(crate) run_case: fn(&Path) -> std::io::Result<Vec<crate::spec_tests::Failure>>,
+}
+
+macro_rules! entry {
+    ($preset:ty, $fork:expr, $name:literal, $type:ty) => {
+        Entry {
+            preset: <$preset as NamedPreset>::KIND.name(),
+            fork: $fork,
+            name: $name,
+            generate: generate::<$preset, $type>,
+            #[cfg(feature = "spec_tests")]
+            run_case: crate::spec_tests::run_case::<$type>,
+        }
+    };
+}
+
+macro_rules! entries_for_preset {
+    ($preset:ty) => {
+        [
+            entry!(
+                $preset,
+                "electra",
+                "SignedBeaconBlock",
+                SignedBeaconBlock<$preset>
+            ),
+            entry!(
+                $preset,
+                "electra",
+                "BeaconBlockContents",
+                BeaconBlockContents<$preset>
+            ),
+            entry!(
+                $preset,
+                "electra",
+                "SignedBeaconBlockContents",
+                SignedBeaconBlockContents<$preset>
+            ),
+            entry!(
+                $preset,
+                "electra",
+                "BlindedBeaconBlock",
+                BlindedBeaconBlock<$preset>
+            ),
+            entry!(
+                $preset,
+                "electra",
+                "SignedBlindedBeaconBlock",
+                SignedBlindedBeaconBlock<$preset>
+            ),
+            entry!(
+                $preset,
+                "electra",
+                "ExecutionPayload",
+                ExecutionPayload<$preset>
+            ),
+            entry!(
+                $preset,
+                "electra",
+                "ExecutionPayloadHeader",
+                ExecutionPayloadHeader<$preset>
+            ),
+            entry!(
+                $preset,
+                "electra",
+                "ExecutionPayloadAndBlobsBundle",
+                ExecutionPayloadAndBlobsBundle<$preset>
+            ),
+            entry!($preset, "electra", "Attestation", Attestation<$preset>),
+            entry!(
+                $preset,
+                "electra",
+                "IndexedAttestation",
+                IndexedAttestation<$preset>
+            ),
+            entry!(
+                $preset,
+                "electra",
+                "AttesterSlashing",
+                AttesterSlashing<$preset>
+            ),
+            entry!(
+                $preset,
+                "electra",
+                "AggregateAndProof",
+                AggregateAndProof<$preset>
+            ),
+            entry!(
+                $preset,
+                "electra",
+                "SignedAggregateAndProof",
+                SignedAggregateAndProof<$preset>
+            ),
+            entry!($preset, "electra", "SingleAttestation", SingleAttestation),
+            entry!($preset, "electra", "BeaconState", BeaconState<$preset>),
+            entry!($preset, "fulu", "BeaconState", FuluBeaconState<$preset>),
+            entry!(
+                $preset,
+                "fulu",
+                "BeaconBlockContents",
+                FuluBeaconBlockContents<$preset>
+            ),
+            entry!(
+                $preset,
+                "fulu",
+                "SignedBeaconBlockContents",
+                FuluSignedBeaconBlockContents<$preset>
+            ),
+            entry!(
+                $preset,
+                "fulu",
+                "DataColumnSidecar",
+                DataColumnSidecar<$preset>
+            ),
+            entry!($preset, "phase0", "Attestation", Phase0Attestation<$preset>),
+            entry!($preset, "phase0", "AttestationData", AttestationData),
+            entry!($preset, "phase0", "BeaconBlockHeader", BeaconBlockHeader),
+            entry!($preset, "phase0", "Checkpoint", Checkpoint),
+            entry!($preset, "phase0", "Deposit", Deposit),
+            entry!($preset, "phase0", "DepositData", DepositData),
+            entry!($preset, "phase0", "Eth1Data", Eth1Data),
+            entry!($preset, "phase0", "ProposerSlashing", ProposerSlashing),
+            entry!(
+                $preset,
+                "phase0",
+                "SignedBeaconBlockHeader",
+                SignedBeaconBlockHeader
+            ),
+            entry!(
+                $preset,
+                "phase0",
+                "SignedVoluntaryExit",
+                SignedVoluntaryExit
+            ),
+            entry!($preset, "phase0", "VoluntaryExit", VoluntaryExit),
+            entry!(
+                $preset,
+                "phase0",
+                "SignedBeaconBlock",
+                Phase0SignedBeaconBlock<$preset>
+            ),
+            entry!(
+                $preset,
+                "altair",
+                "SignedBeaconBlock",
+                AltairSignedBeaconBlock<$preset>
+            ),
+            entry!(
+                $preset,
+                "bellatrix",
+                "SignedBeaconBlock",
+                BellatrixSignedBeaconBlock<$preset>
+            ),
+            entry!(
+                $preset,
+                "bellatrix",
+                "SignedBlindedBeaconBlock",
+                BellatrixSignedBlindedBeaconBlock<$preset>
+            ),
+            entry!(
+                $preset,
+                "capella",
+                "SignedBeaconBlock",
+                CapellaSignedBeaconBlock<$preset>
+            ),
+            entry!(
+                $preset,
+                "capella",
+                "SignedBlindedBeaconBlock",
+                CapellaSignedBlindedBeaconBlock<$preset>
+            ),
+            entry!(
+                $preset,
+                "capella",
+                "BLSToExecutionChange",
+                BlsToExecutionChange
+            ),
+            entry!(
+                $preset,
+                "capella",
+                "SignedBLSToExecutionChange",
+                SignedBlsToExecutionChange
+            ),
+            entry!(
+                $preset,
+                "deneb",
+                "SignedBeaconBlock",
+                DenebSignedBeaconBlock<$preset>
+            ),
+            entry!(
+                $preset,
+                "deneb",
+                "SignedBlindedBeaconBlock",
+                DenebSignedBlindedBeaconBlock<$preset>
+            ),
+            entry!($preset, "deneb", "BlobSidecar", BlobSidecar<$preset>),
+        ]
+    };
+}
+
+/// Light client types, which every fork from Altair redefines.
+macro_rules! light_client_entries {
+    ($preset:ty) => {
+        light_client_entries!($preset, altair, capella, deneb, electra)
+    };
+    ($preset:ty, $($fork:ident),*) => {
+        [$(
+            entry!(
+                $preset,
+                stringify!($fork),
+                "LightClientBootstrap",
+                grandine_types::$fork::containers::LightClientBootstrap<$preset>
+            ),
+            entry!(
+                $preset,
+                stringify!($fork),
+                "LightClientUpdate",
+                grandine_types::$fork::containers::LightClientUpdate<$preset>
+            ),
+            entry!(
+                $preset,
+                stringify!($fork),
+                "LightClientFinalityUpdate",
+                grandine_types::$fork::containers::LightClientFinalityUpdate<$preset>
+            ),
+            entry!(
+                $preset,
+                stringify!($fork),
+                "LightClientOptimisticUpdate",
+                grandine_types::$fork::containers::LightClientOptimisticUpdate<$preset>
+            ),
+        )*]
+    };
+}
+
+pub(crate) fn entries() -> Vec<Entry> {
+    let mut all = Vec::new();
+    all.extend(entries_for_preset!(Mainnet));
+    all.extend(light_client_entries!(Mainnet));
+    all.extend(entries_for_preset!(Minimal));
+    all.extend(light_client_entries!(Minimal));
+    all.extend(entries_for_preset!(Gnosis));
+    all.extend(light_client_entries!(Gnosis));
+    all
+}
+
+fn write_case(dir: &Path, case: &Case) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(dir.join("value.yaml"), &case.value)?;
+    std::fs::write(dir.join("serialized.ssz_snappy"), &case.serialized)?;
+    std::fs::write(dir.join("roots.yaml"), &case.root)
+}
+
+/// Write SSZ test vectors for every registered type to `dir_path`.
+///
+/// Writes `cases` random instances per type, preset and fork; `types`
+/// restricts the export to the given type names (e.g. `"BeaconState"`).
+/// Returns the number of cases written.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `types` names an unknown type or an instance
+/// cannot be encoded, or `OSError` if a file cannot be written.
+#[pyfunction]
+#[pyo3(signature = (dir_path, *, cases = 1, seed = 0, types = None))]
+pub fn export_test_vectors(
+    py: Python<'_>,
+    dir_path: PathBuf,
+    cases: usize,
+    seed: u64,
+    types: Option<Vec<String>>,
+) -> PyResult<usize> {
+    let mut entries = entries();
+
+    if let Some(types) = types {
+        if let Some(unknown) = types
+            .iter()
+            .find(|name| !entries.iter().any(|entry| entry.name == name.as_str()))
+        {
+            return Err(PyValueError::new_err(format!("Unknown type {unknown:?}")));
+        }
+
+        entries.retain(|entry| types.iter().any(|name| name == entry.name));
+    }
+
+    py.detach(|| {
+        let mut rng = Rng(seed);
+        let mut written = 0;
+
+        for entry in &entries {
+            let type_dir = dir_path
+                .join(entry.preset)
+                .join(entry.fork)
+                .join("ssz_static")
+                .join(entry.name)
+                .join("ssz_random");
+
+            for index in 0..cases {
+                let case = (entry.generate)(&mut rng).map_err(|e| {
+                    PyValueError::new_err(format!("Cannot generate {}: {e}", entry.name))
+                })?;
+                write_case(&type_dir.join(format!("case_{index}")), &case)?;
+                written += 1;
+            }
+        }
+
+        Ok(written)
+    })
+}
+
+/// Registers the test vector export function with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if function registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(export_test_vectors, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,429 @@
+//! Stateless sanity checks of signed blocks.
+//!
+//! Relays check blocks before forwarding them, without a beacon state at
+//! hand. Signed Electra and Fulu blocks have two methods for this:
+//!
+//! - `verify_signatures` checks the BLS signatures of the block against
+//!   pubkeys supplied by the caller: the proposer signature
+//!   (`DOMAIN_BEACON_PROPOSER`), the RANDAO reveal (`DOMAIN_RANDAO`), every
+//!   attestation (`DOMAIN_BEACON_ATTESTER` of its target epoch) and the sync
+//!   aggregate (`DOMAIN_SYNC_COMMITTEE` of the previous slot's epoch, over the
+//!   parent root).
+//! - `check_structure` checks the invariants of the body that SSZ decoding
+//!   does not enforce, such as attestations being includable at the block's
+//!   slot and slashings being slashable.
+//!
+//! Who signed an attestation depends on the committee shuffling, which needs
+//! the state. Committees and the sync committee are therefore given as
+//! validator indices, as returned by the Beacon API `committees` and
+//! `sync_committees` endpoints, and `pubkey_provider` (a `PubkeyIndexMap`,
+//! or a callable taking a validator index) maps them to pubkeys.
+//!
+//! All signatures are verified in one batch, and one by one only if the
+//! batch fails, to find the invalid ones. Every signature is checked against
+//! the one `fork_version` given, so attestations from the last epoch before a
+//! fork, included in blocks of the first epoch after it, are reported
+//! invalid.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use grandine_bls::{PublicKey, PublicKeyBytes, Signature, SignatureBytes};
+use grandine_ssz::{SszHash as _, SszReadDefault as _, H256};
+use grandine_types::electra::containers::{BeaconBlock, SignedBeaconBlock};
+use grandine_types::phase0::containers::AttestationData;
+use grandine_types::preset::Preset;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use typenum::Unsigned as _;
+
+use crate::convert::parse_root;
+use crate::domains::{compute_domain, parse_fork_version, DOMAIN_BEACON_PROPOSER, DOMAIN_RANDAO};
+use crate::pubkey_index::{parse_pubkey, PyPubkeyIndexMap};
+use crate::signing::signing_root;
+
+const DOMAIN_BEACON_ATTESTER: [u8; 4] = [1, 0, 0, 0];
+const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [7, 0, 0, 0];
+
+/// `MIN_ATTESTATION_INCLUSION_DELAY`, which is the same in all presets.
+const MIN_ATTESTATION_INCLUSION_DELAY: u64 = 1;
+
+/// The compressed G2 point at infinity, the signature of an empty sync
+/// aggregate.
+const INFINITY_SIGNATURE: [u8; 96] = {
+    let mut bytes = [0; 96];
+    bytes[0] = 0xc0;
+    bytes
+};
+
+/// A signature to verify, or `None` if it is known to be invalid, e.g.
+/// because it has no signers or is not a valid point.
+type Check = Option<(Signature, H256, PublicKey)>;
+
+/// Looks up and decompresses the pubkeys of validators, calling
+/// `pubkey_provider` at most once per validator.
+struct Pubkeys<'a, 'py> {
+    provider: &'a Bound<'py, PyAny>,
+    /// `None` for pubkeys that are not valid G1 points.
+    cache: HashMap<u64, Option<PublicKey>>,
+}
+
+impl<'a, 'py> Pubkeys<'a, 'py> {
+    fn new(provider: &'a Bound<'py, PyAny>) -> PyResult<Self> {
+        if !provider.is_instance_of::<PyPubkeyIndexMap>() && !provider.is_callable() {
+            return Err(PyTypeError::new_err(format!(
+                "pubkey_provider must be a PubkeyIndexMap or a callable, got {}",
+                provider.get_type().name()?
+            )));
+        }
+
+        Ok(Self {
+            provider,
+            cache: HashMap::new(),
+        })
+    }
+
+    fn get(&mut self, index: u64) -> PyResult<Option<PublicKey>> {
+        if let Some(pubkey) = self.cache.get(&index) {
+            return Ok(*pubkey);
+        }
+
+        let bytes = if let Ok(map) = self.provider.cast::<PyPubkeyIndexMap>() {
+            *map.borrow()
+                .pubkey(index)
+                .ok_or_else(|| PyValueError::new_err(format!("No pubkey for validator {index}")))?
+        } else {
+            parse_pubkey(&self.provider.call1((index,))?)?
+        };
+
+        let pubkey = PublicKeyBytes::from_ssz_default(&bytes)
+            .ok()
+            .and_then(|pubkey| PublicKey::try_from(pubkey).ok());
+
+        self.cache.insert(index, pubkey);
+        Ok(pubkey)
+    }
+
+    /// Aggregates the pubkeys of `indices`, or returns `None` if there are
+    /// none or one is not a valid point.
+    fn aggregate(&mut self, indices: impl IntoIterator<Item = u64>) -> PyResult<Option<PublicKey>> {
+        let mut aggregate: Option<PublicKey> = None;
+
+        for index in indices {
+            let Some(pubkey) = self.get(index)? else {
+                return Ok(None);
+            };
+
+            match &mut aggregate {
+                Some(aggregate) => aggregate.aggregate_in_place(pubkey),
+                None => aggregate = Some(pubkey),
+            }
+        }
+
+        Ok(aggregate)
+    }
+}
+
+/// Returns the check of `signature` over `message` by `pubkey`.
+fn check(signature: SignatureBytes, message: H256, pubkey: Option<PublicKey>) -> Check {
+    Some((Signature::try_from(signature).ok()?, message, pubkey?))
+}
+
+/// Verifies the checks, in one batch if they all pass.
+fn verify_checks(checks: &[Check]) -> Vec<bool> {
+    let valid = checks.iter().flatten().collect::<Vec<_>>();
+
+    if valid.len() == checks.len()
+        && Signature::multi_verify(
+            valid.iter().map(|(_, message, _)| message.as_bytes()),
+            valid.iter().map(|(signature, _, _)| signature),
+            valid.iter().map(|(_, _, pubkey)| pubkey),
+        )
+    {
+        return vec![true; checks.len()];
+    }
+
+    checks
+        .iter()
+        .map(|check| {
+            check
+                .as_ref()
+                .is_some_and(|(signature, message, pubkey)| signature.verify(*message, pubkey))
+        })
+        .collect()
+}
+
+/// Returns the attesting validators of an attestation, or `None` if its
+/// aggregation bits do not match the sizes of its committees.
+fn attesting_indices(
+    committees: &Bound<'_, PyAny>,
+    cache: &mut HashMap<(u64, usize), Vec<u64>>,
+    slot: u64,
+    committee_bits: impl IntoIterator<Item = usize>,
+    aggregation_bits: impl ExactSizeIterator<Item = bool>,
+) -> PyResult<Option<Vec<u64>>> {
+    let mut members = Vec::new();
+
+    for committee_index in committee_bits {
+        let committee = match cache.entry((slot, committee_index)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                entry.insert(committees.call1((slot, committee_index))?.extract()?)
+            }
+        };
+
+        members.extend_from_slice(committee);
+    }
+
+    if members.len() != aggregation_bits.len() {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        members
+            .into_iter()
+            .zip(aggregation_bits)
+            .filter_map(|(index, bit)| bit.then_some(index))
+            .collect(),
+    ))
+}
+
+/// Verifies the signatures of a signed block, see the module documentation.
+///
+/// `committees` is a callable taking a slot and committee index and returning
+/// the committee's validator indices, and `sync_committee` the validator
+/// indices of the sync committee of the block's parent slot. The results of
+/// the checks that need them are `None` if they are not given, except for
+/// empty sync aggregates, which are valid only with the infinity signature.
+///
+/// # Errors
+///
+/// Returns `PyValueError` if `genesis_validators_root` is not 32 bytes,
+/// `fork_version` is not 4 bytes, `sync_committee` does not have
+/// `SYNC_COMMITTEE_SIZE` members or a pubkey is missing, `PyTypeError` if
+/// `pubkey_provider` is neither a `PubkeyIndexMap` nor callable, or the error
+/// raised by a callable.
+pub fn verify_signatures_impl<'py, P: Preset>(
+    py: Python<'py>,
+    signed_block: &SignedBeaconBlock<P>,
+    pubkey_provider: &Bound<'py, PyAny>,
+    genesis_validators_root: &Bound<'py, PyAny>,
+    fork_version: &Bound<'py, PyAny>,
+    committees: Option<&Bound<'py, PyAny>>,
+    sync_committee: Option<Vec<u64>>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let genesis_validators_root = parse_root(genesis_validators_root)?;
+    let fork_version = parse_fork_version(fork_version)?;
+    let domain = |domain_type| compute_domain(domain_type, fork_version, genesis_validators_root);
+
+    let block = &signed_block.message;
+    let body = &block.body;
+    let epoch = block.slot / P::SlotsPerEpoch::U64;
+    let mut pubkeys = Pubkeys::new(pubkey_provider)?;
+
+    let proposer = pubkeys.get(block.proposer_index)?;
+    let mut checks = vec![
+        check(
+            signed_block.signature,
+            signing_root(block.hash_tree_root(), domain(DOMAIN_BEACON_PROPOSER)),
+            proposer,
+        ),
+        check(
+            body.randao_reveal,
+            signing_root(epoch.hash_tree_root(), domain(DOMAIN_RANDAO)),
+            proposer,
+        ),
+    ];
+
+    let attestation_count = if let Some(committees) = committees {
+        let mut cache = HashMap::new();
+
+        for attestation in &body.attestations {
+            let data = attestation.data;
+            let indices = attesting_indices(
+                committees,
+                &mut cache,
+                data.slot,
+                attestation.committee_bits.iter_ones(),
+                attestation.aggregation_bits.iter().map(|bit| *bit),
+            )?;
+
+            let pubkey = match indices {
+                Some(indices) => pubkeys.aggregate(indices)?,
+                None => None,
+            };
+
+            checks.push(check(
+                attestation.signature,
+                signing_root(data.hash_tree_root(), domain(DOMAIN_BEACON_ATTESTER)),
+                pubkey,
+            ));
+        }
+
+        Some(body.attestations.len())
+    } else {
+        None
+    };
+
+    let sync_aggregate = &body.sync_aggregate;
+    // An empty sync aggregate is only valid with the infinity signature,
+    // which `multi_verify` cannot check.
+    let empty_sync_aggregate = sync_aggregate.sync_committee_bits.count_ones() == 0;
+
+    let has_sync_check = match sync_committee {
+        Some(sync_committee) if !empty_sync_aggregate => {
+            if sync_committee.len() != P::SyncCommitteeSize::USIZE {
+                return Err(PyValueError::new_err(format!(
+                    "sync_committee must have {} members, got {}",
+                    P::SyncCommitteeSize::USIZE,
+                    sync_committee.len()
+                )));
+            }
+
+            let participants = sync_committee
+                .into_iter()
+                .zip(sync_aggregate.sync_committee_bits.iter())
+                .filter_map(|(index, bit)| (*bit).then_some(index));
+
+            checks.push(check(
+                sync_aggregate.sync_committee_signature,
+                signing_root(block.parent_root, domain(DOMAIN_SYNC_COMMITTEE)),
+                pubkeys.aggregate(participants)?,
+            ));
+            true
+        }
+        _ => false,
+    };
+
+    let results = py.detach(|| verify_checks(&checks));
+
+    let sync_aggregate_result = if empty_sync_aggregate {
+        Some(sync_aggregate.sync_committee_signature.as_bytes() == INFINITY_SIGNATURE)
+    } else {
+        has_sync_check.then(|| results[results.len() - 1])
+    };
+
+    let dict = PyDict::new(py);
+    dict.set_item("proposer", results[0])?;
+    dict.set_item("randao_reveal", results[1])?;
+    dict.set_item(
+        "attestations",
+        attestation_count
+            .map(|count| PyList::new(py, &results[2..2 + count]))
+            .transpose()?,
+    )?;
+    dict.set_item("sync_aggregate", sync_aggregate_result)?;
+    Ok(dict)
+}
+
+/// `is_slashable_attestation_data` from the consensus specs.
+fn is_slashable(data_1: &AttestationData, data_2: &AttestationData) -> bool {
+    let double_vote = data_1 != data_2 && data_1.target.epoch == data_2.target.epoch;
+    let surround_vote =
+        data_1.source.epoch < data_2.source.epoch && data_2.target.epoch < data_1.target.epoch;
+    double_vote || surround_vote
+}
+
+/// Returns the invariants of a block body broken by a block, see the module
+/// documentation.
+pub fn check_structure_impl<P: Preset>(block: &BeaconBlock<P>) -> Vec<String> {
+    let body = &block.body;
+    let epoch = block.slot / P::SlotsPerEpoch::U64;
+    let mut failures = Vec::new();
+
+    for (i, slashing) in body.proposer_slashings.iter().enumerate() {
+        let header_1 = &slashing.signed_header_1.message;
+        let header_2 = &slashing.signed_header_2.message;
+
+        if header_1.slot != header_2.slot || header_1.proposer_index != header_2.proposer_index {
+            failures.push(format!(
+                "Proposer slashing {i} has headers of different slots or proposers"
+            ));
+        } else if header_1 == header_2 {
+            failures.push(format!("Proposer slashing {i} has identical headers"));
+        }
+    }
+
+    for (i, slashing) in body.attester_slashings.iter().enumerate() {
+        let attestation_1 = &slashing.attestation_1;
+        let attestation_2 = &slashing.attestation_2;
+
+        if !is_slashable(&attestation_1.data, &attestation_2.data) {
+            failures.push(format!(
+                "Attester slashing {i} has attestation data that is not slashable"
+            ));
+        }
+
+        for (n, attestation) in [(1, attestation_1), (2, attestation_2)] {
+            let indices = attestation.attesting_indices.iter().collect::<Vec<_>>();
+            if indices.is_empty() || indices.windows(2).any(|pair| pair[0] >= pair[1]) {
+                failures.push(format!(
+                    "Attester slashing {i} has attestation {n} with attesting indices that \
+                     are empty or not sorted and unique"
+                ));
+            }
+        }
+    }
+
+    for (i, attestation) in body.attestations.iter().enumerate() {
+        let data = attestation.data;
+
+        if data.index != 0 {
+            failures.push(format!(
+                "Attestation {i} has data.index {} instead of 0",
+                data.index
+            ));
+        }
+
+        if attestation.committee_bits.count_ones() == 0 {
+            failures.push(format!("Attestation {i} has no committee bits set"));
+        }
+
+        if attestation.aggregation_bits.count_ones() == 0 {
+            failures.push(format!("Attestation {i} has no aggregation bits set"));
+        }
+
+        // A slot too late for the delay to be added cannot be included yet.
+        if data
+            .slot
+            .checked_add(MIN_ATTESTATION_INCLUSION_DELAY)
+            .is_none_or(|earliest| earliest > block.slot)
+        {
+            failures.push(format!(
+                "Attestation {i} of slot {} cannot be included at slot {}",
+                data.slot, block.slot
+            ));
+        }
+
+        if data.target.epoch != data.slot / P::SlotsPerEpoch::U64 {
+            failures.push(format!(
+                "Attestation {i} has target epoch {} not matching its slot {}",
+                data.target.epoch, data.slot
+            ));
+        }
+
+        if data
+            .target
+            .epoch
+            .checked_add(1)
+            .is_some_and(|next| next < epoch)
+        {
+            failures.push(format!(
+                "Attestation {i} has target epoch {}, before the previous epoch",
+                data.target.epoch
+            ));
+        }
+    }
+
+    let sync_aggregate = &body.sync_aggregate;
+    if sync_aggregate.sync_committee_bits.count_ones() == 0
+        && sync_aggregate.sync_committee_signature.as_bytes() != INFINITY_SIGNATURE
+    {
+        failures.push(
+            "Sync aggregate without participants does not have the infinity signature".to_owned(),
+        );
+    }
+
+    failures
+}
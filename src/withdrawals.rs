@@ -0,0 +1,101 @@
+//! Execution layer withdrawal addresses of the validator set.
+//!
+//! Validators withdraw to an execution address once their withdrawal
+//! credentials have the `ETH1_ADDRESS_WITHDRAWAL_PREFIX` (`0x01`) or, since
+//! Electra, `COMPOUNDING_WITHDRAWAL_PREFIX` (`0x02`). The address is the last
+//! 20 bytes of the credentials. Validators with BLS (`0x00`) credentials have
+//! no execution address yet.
+
+use grandine_types::phase0::containers::Validator;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::electra::state::{PyBeaconStateGnosis, PyBeaconStateMainnet, PyBeaconStateMinimal};
+use crate::fulu::state::{
+    PyFuluBeaconStateGnosis, PyFuluBeaconStateMainnet, PyFuluBeaconStateMinimal,
+};
+use crate::hex_format::format_hex;
+
+const ETH1_ADDRESS_WITHDRAWAL_PREFIX: u8 = 0x01;
+const COMPOUNDING_WITHDRAWAL_PREFIX: u8 = 0x02;
+
+/// Returns the index and execution address of every validator with
+/// execution withdrawal credentials.
+fn execution_addresses<'a>(
+    validators: impl IntoIterator<Item = &'a Validator>,
+) -> Vec<(u64, [u8; 20])> {
+    validators
+        .into_iter()
+        .zip(0..)
+        .filter_map(|(validator, index)| {
+            let credentials = validator.withdrawal_credentials.as_bytes();
+            matches!(
+                credentials[0],
+                ETH1_ADDRESS_WITHDRAWAL_PREFIX | COMPOUNDING_WITHDRAWAL_PREFIX
+            )
+            .then(|| {
+                let mut address = [0; 20];
+                address.copy_from_slice(&credentials[12..]);
+                (index, address)
+            })
+        })
+        .collect()
+}
+
+/// Map the index of every validator with `0x01` or `0x02` withdrawal
+/// credentials to its execution withdrawal address.
+///
+/// Validators with BLS (`0x00`) credentials are left out. The registry is
+/// scanned natively with the GIL released.
+///
+/// # Errors
+///
+/// Returns `PyTypeError` if `state` is not a beacon state.
+#[pyfunction]
+pub fn withdrawal_addresses<'py>(
+    py: Python<'py>,
+    state: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyDict>> {
+    macro_rules! try_states {
+        ($($class:ty),* $(,)?) => {
+            $(
+                if let Ok(state) = state.cast::<$class>() {
+                    let state = state.borrow();
+                    let validators = &state.inner.validators;
+                    let addresses = py.detach(|| execution_addresses(validators));
+
+                    let dict = PyDict::new(py);
+                    for (index, address) in addresses {
+                        dict.set_item(index, format_hex(&address))?;
+                    }
+                    return Ok(dict);
+                }
+            )*
+        };
+    }
+
+    try_states!(
+        PyBeaconStateMainnet,
+        PyBeaconStateMinimal,
+        PyBeaconStateGnosis,
+        PyFuluBeaconStateMainnet,
+        PyFuluBeaconStateMinimal,
+        PyFuluBeaconStateGnosis,
+    );
+
+    Err(PyTypeError::new_err(format!(
+        "Expected a beacon state, got {}",
+        state.get_type().name()?
+    )))
+}
+
+/// Registers the withdrawal address query with the Python module.
+///
+/// # Errors
+///
+/// Returns `PyErr` if registration fails.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(withdrawal_addresses, m)?)?;
+    Ok(())
+}
@@ -1,5 +1,10 @@
+import hashlib
+
 import pytest
 
+FAR_FUTURE_EPOCH = 2**64 - 1
+ZERO_ROOT = "0x" + "00" * 32
+
 
 @pytest.fixture
 def builder_bid_json() -> dict[str, object]:
@@ -60,3 +65,113 @@ def builder_bid_json() -> dict[str, object]:
             "signature": "0x" + ("55" * 96),
         },
     }
+
+
+def _validator(index: int, effective_balance: int, exit_epoch: int) -> dict:
+    return {
+        "pubkey": "0x" + f"{index:02x}" * 48,
+        "withdrawal_credentials": "0x02" + "00" * 11 + f"{index:02x}" * 20,
+        "effective_balance": str(effective_balance),
+        "slashed": False,
+        "activation_eligibility_epoch": "0",
+        "activation_epoch": "0",
+        "exit_epoch": str(exit_epoch),
+        "withdrawable_epoch": str(exit_epoch),
+    }
+
+
+def _checkpoint() -> dict:
+    return {"epoch": "0", "root": ZERO_ROOT}
+
+
+def _sync_committee() -> dict:
+    return {
+        "pubkeys": ["0x" + "aa" * 48] * 32,
+        "aggregate_pubkey": "0x" + "aa" * 48,
+    }
+
+
+@pytest.fixture
+def minimal_state_json() -> dict:
+    """A small Electra state of the minimal preset with 12 validators."""
+    balances = [32, 2048, 64, 32, 1000, 32, 512, 32, 32, 2048, 32, 100]
+    validators = [
+        _validator(index, balance * 10**9, FAR_FUTURE_EPOCH)
+        for index, balance in enumerate(balances)
+    ]
+    # Exits before the next epoch, so it must never be selected.
+    validators[1]["exit_epoch"] = validators[1]["withdrawable_epoch"] = "3"
+
+    return {
+        "genesis_time": "1578009600",
+        "genesis_validators_root": "0x" + "01" * 32,
+        "slot": "21",
+        "fork": {
+            "previous_version": "0x04000001",
+            "current_version": "0x05000001",
+            "epoch": "0",
+        },
+        "latest_block_header": {
+            "slot": "0",
+            "proposer_index": "0",
+            "parent_root": ZERO_ROOT,
+            "state_root": ZERO_ROOT,
+            "body_root": ZERO_ROOT,
+        },
+        "block_roots": [ZERO_ROOT] * 64,
+        "state_roots": [ZERO_ROOT] * 64,
+        "historical_roots": [],
+        "eth1_data": {
+            "deposit_root": ZERO_ROOT,
+            "deposit_count": "0",
+            "block_hash": ZERO_ROOT,
+        },
+        "eth1_data_votes": [],
+        "eth1_deposit_index": "0",
+        "validators": validators,
+        "balances": [validator["effective_balance"] for validator in validators],
+        "randao_mixes": [
+            "0x" + hashlib.sha256(bytes([index])).hexdigest() for index in range(64)
+        ],
+        "slashings": ["0"] * 64,
+        "previous_epoch_participation": ["0"] * len(validators),
+        "current_epoch_participation": ["0"] * len(validators),
+        "justification_bits": "0x00",
+        "previous_justified_checkpoint": _checkpoint(),
+        "current_justified_checkpoint": _checkpoint(),
+        "finalized_checkpoint": _checkpoint(),
+        "inactivity_scores": ["0"] * len(validators),
+        "current_sync_committee": _sync_committee(),
+        "next_sync_committee": _sync_committee(),
+        "latest_execution_payload_header": {
+            "parent_hash": ZERO_ROOT,
+            "fee_recipient": "0x" + "00" * 20,
+            "state_root": ZERO_ROOT,
+            "receipts_root": ZERO_ROOT,
+            "logs_bloom": "0x" + "00" * 256,
+            "prev_randao": ZERO_ROOT,
+            "block_number": "0",
+            "gas_limit": "0",
+            "gas_used": "0",
+            "timestamp": "0",
+            "extra_data": "0x",
+            "base_fee_per_gas": "0",
+            "block_hash": ZERO_ROOT,
+            "transactions_root": ZERO_ROOT,
+            "withdrawals_root": ZERO_ROOT,
+            "blob_gas_used": "0",
+            "excess_blob_gas": "0",
+        },
+        "next_withdrawal_index": "0",
+        "next_withdrawal_validator_index": "0",
+        "historical_summaries": [],
+        "deposit_requests_start_index": "0",
+        "deposit_balance_to_consume": "0",
+        "exit_balance_to_consume": "0",
+        "earliest_exit_epoch": "0",
+        "consolidation_balance_to_consume": "0",
+        "earliest_consolidation_epoch": "0",
+        "pending_deposits": [],
+        "pending_partial_withdrawals": [],
+        "pending_consolidations": [],
+    }
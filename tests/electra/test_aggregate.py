@@ -0,0 +1,98 @@
+import hashlib
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    BlsLengthError,
+    ElectraAggregateAndProofMainnet,
+    ElectraAttestationMainnet,
+    ElectraAttestationMinimal,
+    ElectraSignedAggregateAndProofMainnet,
+    ElectraSingleAttestation,
+    make_aggregate_and_proof,
+)
+
+FIXTURES = Path(__file__).parent / "fixtures"
+DOMAIN = bytes.fromhex("06000000") + bytes(range(28))
+
+
+def _block_attestation() -> dict:
+    block = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    return block["data"]["message"]["body"]["attestations"][0]
+
+
+def _aggregate(cls=ElectraAttestationMainnet, **changes: str):
+    attestation = _block_attestation() | {
+        "aggregation_bits": "0x0d",
+        "committee_bits": "0x2000000000000000",
+    }
+    return cls.from_json(json.dumps({"data": attestation | changes}).encode())
+
+
+def test_make_aggregate_and_proof() -> None:
+    aggregate = _aggregate()
+    selection_proof = _block_attestation()["signature"]
+
+    message = make_aggregate_and_proof(42, aggregate, selection_proof)
+
+    assert isinstance(message, ElectraAggregateAndProofMainnet)
+    assert message.aggregator_index() == 42
+    assert message.aggregate().to_ssz() == aggregate.to_ssz()
+    assert message.selection_proof() == selection_proof
+
+    message_json = json.loads(message.to_json())
+    assert message_json["aggregator_index"] == "42"
+    assert message_json["aggregate"]["committee_bits"] == "0x2000000000000000"
+
+    minimal = make_aggregate_and_proof(
+        1, _aggregate(ElectraAttestationMinimal, committee_bits="0x02"), selection_proof
+    )
+    assert type(minimal).__name__ == "ElectraAggregateAndProofMinimal"
+
+
+def test_signing_root_and_signature() -> None:
+    selection_proof = _block_attestation()["signature"]
+    message = make_aggregate_and_proof(42, _aggregate(), selection_proof)
+
+    # compute_signing_root: hash_tree_root(SigningData(object_root, domain)).
+    object_root = bytes.fromhex(
+        ElectraAggregateAndProofMainnet.hash_tree_root_of_ssz(message.to_ssz())[2:]
+    )
+    expected = "0x" + hashlib.sha256(object_root + DOMAIN).hexdigest()
+    assert message.signing_root(DOMAIN) == expected
+    assert message.signing_root("0x" + DOMAIN.hex()) == expected
+
+    signed = message.with_signature(bytes.fromhex(selection_proof[2:]))
+    assert isinstance(signed, ElectraSignedAggregateAndProofMainnet)
+    assert signed.message().to_ssz() == message.to_ssz()
+    assert signed.signature() == selection_proof
+    assert signed.signing_root(DOMAIN) == expected
+
+    roundtrip = ElectraSignedAggregateAndProofMainnet.from_ssz(signed.to_ssz())
+    assert roundtrip.to_json() == signed.to_json()
+
+    with pytest.raises(BlsLengthError):
+        message.with_signature("0x1234")
+
+
+def test_rejects_aggregates_not_fit_for_gossip() -> None:
+    selection_proof = _block_attestation()["signature"]
+
+    with pytest.raises(ValueError, match="exactly one committee, got 2"):
+        make_aggregate_and_proof(
+            0, _aggregate(committee_bits="0x0a00000000000000"), selection_proof
+        )
+    with pytest.raises(ValueError, match="no aggregation bits"):
+        make_aggregate_and_proof(
+            0, _aggregate(aggregation_bits="0x08"), selection_proof
+        )
+    with pytest.raises(TypeError, match="Electra attestation"):
+        make_aggregate_and_proof(
+            0,
+            ElectraSingleAttestation.from_ssz(bytes(240)),
+            selection_proof,
+        )
+    with pytest.raises(BlsLengthError):
+        make_aggregate_and_proof(0, _aggregate(), "0x00")
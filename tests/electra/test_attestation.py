@@ -0,0 +1,139 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraAttestationMainnet,
+    ElectraSingleAttestation,
+    Phase0AttestationMainnet,
+    group_by_data_root,
+)
+
+FIXTURES = Path(__file__).parent / "fixtures"
+
+
+def _block_attestations() -> list[dict]:
+    block = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    return block["data"]["message"]["body"]["attestations"]
+
+
+def test_group_by_data_root() -> None:
+    attestations_json = _block_attestations()
+    attestations = [
+        ElectraAttestationMainnet.from_json(json.dumps({"data": a}).encode())
+        for a in attestations_json
+    ]
+    single = ElectraSingleAttestation.from_json(
+        json.dumps(
+            {
+                "data": {
+                    "committee_index": "3",
+                    "attester_index": "12345",
+                    "data": attestations_json[0]["data"],
+                    "signature": attestations_json[0]["signature"],
+                }
+            }
+        ).encode()
+    )
+
+    groups = group_by_data_root([attestations[0], *attestations, single])
+
+    assert len(groups) == 3
+    first_root, first_group = next(iter(groups.items()))
+    assert first_root.startswith("0x") and len(first_root) == 66
+    assert first_group == [attestations[0], attestations[0], single]
+    assert [group[0] for group in groups.values()] == attestations
+
+
+def test_group_by_data_root_rejects_other_objects() -> None:
+    with pytest.raises(TypeError):
+        group_by_data_root([object()])
+
+
+def _phase0_attestation(committee_index: int) -> Phase0AttestationMainnet:
+    attestation = _block_attestations()[0]
+    data = {**attestation["data"], "index": str(committee_index)}
+    return Phase0AttestationMainnet.from_json(
+        json.dumps(
+            {
+                "data": {
+                    "aggregation_bits": "0x0d",
+                    "data": data,
+                    "signature": attestation["signature"],
+                }
+            }
+        ).encode()
+    )
+
+
+def test_phase0_electra_roundtrip() -> None:
+    phase0 = _phase0_attestation(5)
+
+    electra = phase0.to_electra()
+    electra_json = json.loads(electra.to_json())
+    assert electra_json["committee_bits"] == "0x2000000000000000"
+    assert electra_json["aggregation_bits"] == "0x0d"
+    assert electra_json["data"]["index"] == "0"
+
+    assert electra.to_phase0().to_ssz() == phase0.to_ssz()
+
+
+def test_phase0_rejects_out_of_range_committee() -> None:
+    with pytest.raises(ValueError, match="out of range"):
+        _phase0_attestation(64).to_electra()
+
+
+def test_to_phase0_rejects_multiple_committees() -> None:
+    attestation = ElectraAttestationMainnet.from_json(
+        json.dumps({"data": _block_attestations()[0]}).encode()
+    )
+
+    with pytest.raises(ValueError, match="exactly one committee"):
+        attestation.to_phase0()
+
+
+def _two_committee_attestation() -> ElectraAttestationMainnet:
+    attestation = _block_attestations()[0]
+    return ElectraAttestationMainnet.from_json(
+        json.dumps(
+            {
+                "data": {
+                    **attestation,
+                    # Committees 1 (bits 1, 0, 1) and 3 (bits 0, 1).
+                    "aggregation_bits": "0x35",
+                    "committee_bits": "0x0a00000000000000",
+                }
+            }
+        ).encode()
+    )
+
+
+def test_committee_indices_and_for_committee() -> None:
+    attestation = _two_committee_attestation()
+
+    assert attestation.committee_indices() == [1, 3]
+
+    sizes = [64, 3, 64, 2]
+    assert attestation.for_committee(1, sizes) == [True, False, True]
+    assert attestation.for_committee(3, sizes) == [False, True]
+
+
+def test_for_committee_validates_layout() -> None:
+    attestation = _two_committee_attestation()
+
+    with pytest.raises(ValueError, match="not set"):
+        attestation.for_committee(0, [1, 3, 1, 2])
+    with pytest.raises(ValueError, match="committee_sizes is required"):
+        attestation.for_committee(1)
+    with pytest.raises(ValueError, match="add up to 6"):
+        attestation.for_committee(1, [0, 3, 0, 3])
+    with pytest.raises(ValueError, match="No size given for committee 3"):
+        attestation.for_committee(1, [0, 3])
+
+
+def test_for_committee_single_committee_needs_no_sizes() -> None:
+    attestation = _phase0_attestation(5).to_electra()
+
+    assert attestation.committee_indices() == [5]
+    assert attestation.for_committee(5) == list(attestation.aggregation_bits())
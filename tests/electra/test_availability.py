@@ -0,0 +1,315 @@
+import hashlib
+import json
+import struct
+from pathlib import Path
+from typing import Any
+
+import pytest
+
+from grandine_py import (
+    ElectraBlobSidecarMainnet,
+    ElectraBlobSidecarMinimal,
+    Config,
+    ElectraSignedBeaconBlockMinimal,
+    FuluDataColumnSidecarMinimal,
+    FuluSignedBeaconBlockMinimal,
+    TrustedSetup,
+    check_data_availability,
+)
+
+FIXTURE = Path(__file__).parent / "fixtures/minimal-signed-blinded-block.json"
+
+# The commitment and proofs of an all-zero blob are the point at infinity.
+INFINITY = b"\xc0" + bytes(47)
+BLOB_SIZE = 4096 * 32
+# A blob whose polynomial is the constant 1, which the proofs do not open.
+NONZERO_BLOB = bytes(31) + b"\x01" + bytes(BLOB_SIZE - 32)
+CELL_SIZE = 64 * 32
+HEADER_SIZE = 208
+
+
+def _block(
+    commitments: int = 2,
+    cls: type[Any] = ElectraSignedBeaconBlockMinimal,
+    **changes: Any,
+) -> Any:
+    signed = json.loads(FIXTURE.read_text())["data"]
+    body = signed["message"]["body"]
+    payload = dict(body.pop("execution_payload_header"))
+    del payload["transactions_root"], payload["withdrawals_root"]
+    body["execution_payload"] = payload | {"transactions": [], "withdrawals": []}
+    body["blob_kzg_commitments"] = ["0x" + INFINITY.hex()] * commitments
+    signed["message"] |= changes
+    return cls.from_json(json.dumps({"data": signed}).encode())
+
+
+def _fulu_block(commitments: int = 2, **changes: Any) -> FuluSignedBeaconBlockMinimal:
+    return _block(commitments, FuluSignedBeaconBlockMinimal, **changes)
+
+
+def _blob_sidecar(
+    block: ElectraSignedBeaconBlockMinimal,
+    index: int,
+    blob: bytes = bytes(BLOB_SIZE),
+    commitment: bytes = INFINITY,
+) -> ElectraBlobSidecarMinimal:
+    fixed = BLOB_SIZE + 8 + 2 * 48 + HEADER_SIZE
+    proof_size = ElectraBlobSidecarMinimal.ssz_fixed_size() - fixed
+    ssz = (
+        struct.pack("<Q", index)
+        + blob
+        + commitment
+        + INFINITY
+        + block.signed_header().to_ssz()
+        + bytes(proof_size)
+    )
+    return ElectraBlobSidecarMinimal.from_ssz(ssz)
+
+
+def _column_sidecar(
+    block: FuluSignedBeaconBlockMinimal, index: int, commitments: int = 2
+) -> FuluDataColumnSidecarMinimal:
+    fixed = 8 + 3 * 4 + HEADER_SIZE + 4 * 32
+    column = bytes(CELL_SIZE) * commitments
+    kzg = INFINITY * commitments
+    ssz = (
+        struct.pack("<QIII", index, fixed, fixed + len(column), fixed + len(column) * 2)
+        + block.signed_header().to_ssz()
+        + bytes(4 * 32)
+        + column
+        + kzg
+        + kzg
+    )
+    return FuluDataColumnSidecarMinimal.from_ssz(ssz)
+
+
+def test_blob_sidecar() -> None:
+    block = _block()
+    sidecar = _blob_sidecar(block, 1)
+
+    assert sidecar.index() == 1
+    assert sidecar.kzg_commitment() == "0x" + INFINITY.hex()
+    assert sidecar.block_root() == block.signed_header().block_root()
+    assert sidecar.verify_kzg_proof()
+    assert not _blob_sidecar(block, 1, blob=NONZERO_BLOB).verify_kzg_proof()
+
+
+def test_column_sidecar() -> None:
+    block = _fulu_block()
+    sidecar = _column_sidecar(block, 7)
+
+    assert sidecar.index() == 7
+    assert sidecar.kzg_commitments() == ["0x" + INFINITY.hex()] * 2
+    assert sidecar.block_root() == block.signed_header().block_root()
+    assert sidecar.verify_kzg_proofs()
+
+
+def test_available_from_blob_sidecars() -> None:
+    block = _block()
+    report = check_data_availability(block, [_blob_sidecar(block, i) for i in (1, 0)])
+
+    assert report["fork"] == "electra"
+    assert report["slot"] == block.signed_header().slot()
+    assert report["block_root"] == block.signed_header().block_root()
+    assert report["commitments"] == 2
+    assert report["available"]
+    assert report["missing_blobs"] == []
+    assert report["invalid"] == []
+
+
+def test_missing_blob() -> None:
+    block = _block()
+    report = check_data_availability(block, [_blob_sidecar(block, 0)])
+
+    assert not report["available"]
+    assert report["missing_blobs"] == [1]
+    assert report["missing_columns"] == []
+
+
+def test_invalid_blob_sidecars() -> None:
+    block = _block()
+    other_block = _block(slot="1")
+
+    report = check_data_availability(
+        block,
+        [
+            _blob_sidecar(block, 0, commitment=b"\x80" + bytes(47)),
+            _blob_sidecar(block, 1, blob=NONZERO_BLOB),
+            _blob_sidecar(block, 2),
+            _blob_sidecar(other_block, 0),
+        ],
+    )
+
+    assert report["missing_blobs"] == [0, 1]
+    reasons = [
+        (item["kind"], item["index"], item["reason"]) for item in report["invalid"]
+    ]
+    assert reasons[:3] == [
+        ("blob", 0, "KZG commitment does not match the block"),
+        ("blob", 1, "Invalid KZG proof"),
+        ("blob", 2, "Blob index 2 is out of range for 2 KZG commitments"),
+    ]
+    other_root = other_block.signed_header().block_root()
+    assert reasons[3] == ("blob", 0, f"Sidecar is for block {other_root}")
+
+
+def test_available_from_columns() -> None:
+    block = _fulu_block()
+    columns = [_column_sidecar(block, i) for i in (0, 5)]
+
+    report = check_data_availability(block, columns, custody_columns=[0, 5])
+    assert report["fork"] == "fulu"
+    assert report["available"]
+    assert report["missing_columns"] == []
+    assert report["missing_blobs"] == []
+
+    report = check_data_availability(block, columns[:1], custody_columns=[0, 5])
+    assert not report["available"]
+    assert report["missing_columns"] == [5]
+
+    report = check_data_availability(block, columns)
+    assert report["missing_columns"] == [i for i in range(128) if i not in (0, 5)]
+
+
+def test_invalid_column_sidecars() -> None:
+    block = _fulu_block()
+    report = check_data_availability(
+        block,
+        [_column_sidecar(block, 0, commitments=1), _column_sidecar(block, 128)],
+        custody_columns=[0],
+    )
+
+    assert report["missing_columns"] == [0]
+    assert [(item["index"], item["reason"]) for item in report["invalid"]] == [
+        (0, "KZG commitments do not match the block"),
+        (128, "Column index 128 is out of range for 128 columns"),
+    ]
+
+
+def test_sidecars_of_other_fork() -> None:
+    block = _block()
+    fulu_block = _fulu_block()
+
+    columns = [_column_sidecar(block, i) for i in range(128)]
+    report = check_data_availability(block, columns)
+    assert not report["available"]
+    assert report["missing_blobs"] == [0, 1]
+    assert report["invalid"][0] == {
+        "kind": "column",
+        "index": 0,
+        "reason": "Data column sidecars are not used before Fulu",
+    }
+
+    report = check_data_availability(
+        fulu_block, [_blob_sidecar(fulu_block, i) for i in range(2)]
+    )
+    assert not report["available"]
+    assert report["missing_columns"] == list(range(128))
+    assert [item["reason"] for item in report["invalid"]] == [
+        "Blob sidecars are not used from Fulu on"
+    ] * 2
+
+
+def test_fork_from_config() -> None:
+    block = _block(slot="16")
+    columns = [_column_sidecar(block, 3)]
+
+    for fulu_fork_epoch, fork in [(2, "fulu"), (3, "electra")]:
+        config = Config("devnet", "minimal", 6, fulu_fork_epoch=fulu_fork_epoch)
+        report = check_data_availability(
+            block, columns, config=config, custody_columns=[3]
+        )
+        assert report["fork"] == fork
+        assert report["available"] == (fork == "fulu")
+
+
+def test_block_contents_and_no_blobs() -> None:
+    block = _block()
+    contents = block.with_blobs([bytes(BLOB_SIZE)] * 2, [INFINITY] * 2)
+    sidecars = [_blob_sidecar(block, i) for i in range(2)]
+    assert check_data_availability(contents, sidecars)["available"]
+
+    report = check_data_availability(_block(commitments=0), [])
+    assert report["available"]
+    assert report["missing_blobs"] == []
+    assert report["missing_columns"] == []
+
+    report = check_data_availability(_fulu_block(commitments=0), [])
+    assert report["fork"] == "fulu"
+    assert report["available"]
+
+
+def test_verify_blob_kzg_proofs() -> None:
+    block = _block()
+    blobs = [bytes(BLOB_SIZE)] * 2
+
+    assert block.with_blobs(blobs, [INFINITY] * 2).verify_blob_kzg_proofs()
+    contents = block.with_blobs([bytes(BLOB_SIZE), NONZERO_BLOB], [INFINITY] * 2)
+    assert not contents.verify_blob_kzg_proofs(setup=TrustedSetup.mainnet())
+
+    with pytest.raises(ValueError, match="Blob 1: KZG proof verification failed"):
+        block.with_blobs(blobs, [INFINITY, bytes(48)]).verify_blob_kzg_proofs()
+
+
+def test_with_blobs_verify() -> None:
+    block = _block()
+    blobs = [bytes(BLOB_SIZE)] * 2
+    contents = block.with_blobs(blobs, [INFINITY] * 2, verify=True)
+    assert contents.verify_blob_kzg_proofs()
+
+    with pytest.raises(ValueError, match="KZG proofs do not verify"):
+        block.with_blobs([NONZERO_BLOB] * 2, [INFINITY] * 2, verify=True)
+    with pytest.raises(ValueError, match="Expected 2 KZG proofs"):
+        block.with_blobs(blobs, [INFINITY], verify=True)
+
+
+def _is_valid_merkle_branch(
+    leaf: bytes, branch: list[bytes], index: int, root: bytes
+) -> bool:
+    for depth, node in enumerate(branch):
+        if index >> depth & 1:
+            leaf = hashlib.sha256(node + leaf).digest()
+        else:
+            leaf = hashlib.sha256(leaf + node).digest()
+    return leaf == root
+
+
+def test_blob_sidecars_from_contents() -> None:
+    block = _block()
+    contents = block.with_blobs([bytes(BLOB_SIZE)] * 2, [INFINITY] * 2)
+    sidecars = contents.blob_sidecars()
+    body_root = block.signed_header().to_dict()["message"]["body_root"]
+
+    assert [sidecar.index() for sidecar in sidecars] == [0, 1]
+    assert check_data_availability(block, sidecars)["available"]
+    for index, sidecar in enumerate(sidecars):
+        assert sidecar.block_root() == block.signed_header().block_root()
+        assert sidecar.verify_kzg_proof()
+        proof = sidecar.to_dict()["kzg_commitment_inclusion_proof"]
+        # The minimal preset has 32 commitments per block, so 5 list levels,
+        # the length mix-in and 4 levels of the body at field 11.
+        assert len(proof) == 10
+        assert _is_valid_merkle_branch(
+            hashlib.sha256(INFINITY + bytes(16)).digest(),
+            [bytes.fromhex(node[2:]) for node in proof],
+            11 << 6 | index,
+            bytes.fromhex(body_root[2:]),
+        )
+
+    assert _block(commitments=0).with_blobs([], []).blob_sidecars() == []
+
+
+def test_errors() -> None:
+    block = _block()
+
+    mainnet_sidecar = ElectraBlobSidecarMainnet.from_ssz(
+        bytes(ElectraBlobSidecarMainnet.ssz_fixed_size())
+    )
+    with pytest.raises(TypeError, match="sidecar of the block's preset"):
+        check_data_availability(block, [mainnet_sidecar])
+
+    with pytest.raises(TypeError, match="Expected a signed block"):
+        check_data_availability(block.signed_header(), [])
+
+    with pytest.raises(ValueError, match="Custody column 128 is out of range"):
+        check_data_availability(block, [], custody_columns=[128])
@@ -1,4 +1,6 @@
 import json
+import struct
+from concurrent.futures import ThreadPoolExecutor
 from pathlib import Path
 
 import pytest
@@ -6,8 +8,12 @@ import pytest
 from grandine_py import (
     ElectraBeaconBlockContentsMainnet,
     ElectraBlindedBeaconBlockMinimal,
+    ElectraExecutionPayloadHeaderMinimal,
+    ElectraExecutionPayloadMainnet,
     ElectraSignedBeaconBlockGnosis,
+    ElectraSignedBeaconBlockContentsMainnet,
     ElectraSignedBeaconBlockMainnet,
+    ElectraSignedBlindedBeaconBlockMainnet,
     ElectraSignedBuilderBidMainnet,
     ElectraSignedBuilderBidMinimal,
 )
@@ -184,3 +190,340 @@ def test_replace_execution_payload_with_builder_bid_ssz_and_json_twins_match() -
     assert json.loads(blinded_from_ssz.to_json()) == json.loads(
         blinded_from_json.to_json()
     )
+
+
+def test_hash_tree_root_of_ssz_matches_decoded_root() -> None:
+    with open(
+        Path(__file__).parent / "fixtures/minimal-signed-blinded-block.json", "rb"
+    ) as f:
+        signed_blinded_block = json.loads(f.read().decode())
+
+    block = ElectraBlindedBeaconBlockMinimal.from_json(
+        json.dumps({"data": signed_blinded_block["data"]["message"]}).encode()
+    )
+
+    assert (
+        ElectraBlindedBeaconBlockMinimal.hash_tree_root_of_ssz(block.to_ssz())
+        == block.block_hash_tree_root()
+    )
+    with pytest.raises(ValueError):
+        ElectraBlindedBeaconBlockMinimal.hash_tree_root_of_ssz(b"\x00")
+
+
+def test_header_tuple_matches_header_dict() -> None:
+    with open(
+        Path(__file__).parent / "fixtures/minimal-signed-blinded-block.json", "rb"
+    ) as f:
+        signed_blinded_block = json.loads(f.read().decode())
+
+    block = ElectraBlindedBeaconBlockMinimal.from_json(
+        json.dumps({"data": signed_blinded_block["data"]["message"]}).encode()
+    )
+
+    header = block.header_dict()
+    slot, proposer_index, parent_root, state_root, body_root = block.header_tuple()
+
+    assert slot == int(header["slot"])
+    assert proposer_index == int(header["proposer_index"])
+    assert parent_root == bytes.fromhex(header["parent_root"][2:])
+    assert state_root == bytes.fromhex(header["state_root"][2:])
+    assert body_root == bytes.fromhex(header["body_root"][2:])
+
+
+def test_strip_blobs_and_with_blobs_roundtrip() -> None:
+    block_json = json.loads(
+        (Path(__file__).parent / "fixtures/mainnet-13689000.json").read_text()
+    )
+    block_json["data"]["message"]["body"]["blob_kzg_commitments"] = [
+        "0x" + ("aa" * 48),
+        "0x" + ("bb" * 48),
+    ]
+    block = ElectraSignedBeaconBlockMainnet.from_json(json.dumps(block_json).encode())
+    blobs = [bytes([1]) * 131072, bytes([2]) * 131072]
+    proofs = [bytes([0xC0]) + bytes(47), bytes([0xC0]) + bytes(47)]
+
+    contents = block.with_blobs(blobs, proofs)
+
+    assert isinstance(contents, ElectraSignedBeaconBlockContentsMainnet)
+    assert [bytes(blob) for blob in contents.blobs()] == blobs
+    assert [bytes(proof) for proof in contents.kzg_proofs()] == proofs
+    assert contents.strip_blobs().to_ssz() == block.to_ssz()
+
+
+def test_with_blobs_checks_commitment_counts() -> None:
+    block = ElectraSignedBeaconBlockMainnet.from_ssz(
+        (Path(__file__).parent / "fixtures/mainnet-13689000.ssz").read_bytes()
+    )
+
+    assert block.with_blobs([], []).strip_blobs().to_ssz() == block.to_ssz()
+
+    with pytest.raises(ValueError, match="Expected 0 blobs"):
+        block.with_blobs([bytes(131072)], [bytes(48)])
+
+
+def _blobs_bundle(commitments: list[bytes], blobs: list[bytes]) -> bytes:
+    proofs = [bytes([0xC0]) + bytes(47)] * len(blobs)
+    offsets = [12, 12 + 48 * len(commitments), 12 + 48 * (len(commitments) * 2)]
+    return (
+        struct.pack("<III", *offsets)
+        + b"".join(commitments)
+        + b"".join(proofs)
+        + b"".join(blobs)
+    )
+
+
+def test_blind_and_unblind_roundtrip() -> None:
+    block_json = json.loads(
+        (Path(__file__).parent / "fixtures/mainnet-13689000.json").read_text()
+    )
+    commitments = [bytes([0xAA]) * 48, bytes([0xBB]) * 48]
+    body = block_json["data"]["message"]["body"]
+    body["blob_kzg_commitments"] = ["0x" + c.hex() for c in commitments]
+    block = ElectraSignedBeaconBlockMainnet.from_json(json.dumps(block_json).encode())
+    payload = ElectraExecutionPayloadMainnet.from_json(
+        json.dumps({"data": body["execution_payload"]}).encode()
+    )
+    blobs = [bytes([1]) * 131072, bytes([2]) * 131072]
+
+    blinded = block.blind()
+    assert isinstance(blinded, ElectraSignedBlindedBeaconBlockMainnet)
+    assert blinded.signed_header().to_ssz() == block.signed_header().to_ssz()
+
+    contents = blinded.unblind(payload.to_ssz(), _blobs_bundle(commitments, blobs))
+    assert contents.strip_blobs().to_ssz() == block.to_ssz()
+    assert [bytes(blob) for blob in contents.blobs()] == blobs
+
+    with pytest.raises(ValueError, match="commitments do not match"):
+        blinded.unblind(payload.to_ssz(), _blobs_bundle(commitments[:1], blobs[:1]))
+    with pytest.raises(ValueError, match="Expected one blob and one KZG proof"):
+        blinded.unblind(payload.to_ssz(), _blobs_bundle(commitments, blobs[:1]))
+    with pytest.raises(ValueError, match="Invalid blobs bundle"):
+        blinded.unblind(payload.to_ssz(), b"")
+
+    other = body["execution_payload"] | {"gas_used": "1"}
+    other_payload = ElectraExecutionPayloadMainnet.from_json(
+        json.dumps({"data": other}).encode()
+    )
+    with pytest.raises(ValueError, match="does not match the payload header"):
+        blinded.unblind(other_payload.to_ssz(), _blobs_bundle(commitments, blobs))
+
+
+def test_recompute_roots_fixes_block_hash() -> None:
+    signed_block = json.loads(
+        (Path(__file__).parent / "fixtures/mainnet-13689000.json").read_text()
+    )
+    block_json = signed_block["data"]["message"]
+    contents_json = {"block": block_json, "kzg_proofs": [], "blobs": []}
+
+    contents = ElectraBeaconBlockContentsMainnet.from_json(
+        json.dumps({"data": contents_json}).encode()
+    )
+    assert contents.clone_mut().recompute_roots() == {}
+
+    expected_hash = block_json["body"]["execution_payload"]["block_hash"]
+    block_json["body"]["execution_payload"]["block_hash"] = "0x" + "00" * 32
+    frozen = ElectraBeaconBlockContentsMainnet.from_json(
+        json.dumps({"data": contents_json}).encode()
+    )
+    edited = frozen.clone_mut()
+
+    assert edited.recompute_roots() == {
+        "block.body.execution_payload.block_hash": ("0x" + "00" * 32, expected_hash)
+    }
+    assert edited.to_ssz() == contents.to_ssz()
+    assert edited.recompute_roots() == {}
+
+    assert frozen.to_ssz() != contents.to_ssz()
+    assert edited.freeze().to_ssz() == contents.to_ssz()
+    assert not hasattr(frozen, "recompute_roots")
+
+
+def test_setters_patch_contents() -> None:
+    signed_block = json.loads(
+        (Path(__file__).parent / "fixtures/mainnet-13689000.json").read_text()
+    )
+    block_json = signed_block["data"]["message"]
+    contents = ElectraBeaconBlockContentsMainnet.from_json(
+        json.dumps({"data": {"block": block_json, "kzg_proofs": [], "blobs": []}}).encode()
+    )
+    payload_json = block_json["body"]["execution_payload"] | {"gas_used": "1"}
+    payload = ElectraExecutionPayloadMainnet.from_json(
+        json.dumps({"data": payload_json}).encode()
+    )
+
+    mutable = contents.clone_mut()
+    mutable.set_state_root("0x" + "11" * 32)
+    mutable.set_execution_payload(payload)
+    edited = json.loads(mutable.freeze().to_json())["block"]
+
+    assert edited["state_root"] == "0x" + "11" * 32
+    assert edited["body"]["execution_payload"] == payload_json
+    assert json.loads(contents.to_json())["block"] == block_json
+
+    with pytest.raises(ValueError, match="32 bytes"):
+        mutable.set_state_root(b"short")
+    with pytest.raises(TypeError):
+        mutable.set_execution_payload(contents)
+
+
+def test_setters_patch_blinded_block() -> None:
+    message = json.loads(
+        (Path(__file__).parent / "fixtures/minimal-signed-blinded-block.json").read_text()
+    )["data"]["message"]
+    block = ElectraBlindedBeaconBlockMinimal.from_json(
+        json.dumps({"data": message}).encode()
+    )
+    header_json = message["body"]["execution_payload_header"] | {"gas_limit": "1"}
+    header = ElectraExecutionPayloadHeaderMinimal.from_json(
+        json.dumps({"data": header_json}).encode()
+    )
+
+    mutable = block.clone_mut()
+    mutable.set_state_root(b"\x22" * 32)
+    mutable.set_execution_payload_header(header)
+    edited = mutable.freeze()
+
+    assert json.loads(edited.to_json())["state_root"] == "0x" + "22" * 32
+    assert json.loads(edited.to_json())["body"]["execution_payload_header"] == header_json
+    assert edited.block_hash_tree_root() != block.block_hash_tree_root()
+    assert edited.body_root() == edited.header_dict()["body_root"]
+    assert edited.payload_root() == (
+        ElectraExecutionPayloadHeaderMinimal.hash_tree_root_of_ssz(header.to_ssz())
+    )
+
+
+def test_cached_roots_match_fresh_roots() -> None:
+    signed_block = json.loads(
+        (Path(__file__).parent / "fixtures/mainnet-13689000.json").read_text()
+    )
+    block_json = signed_block["data"]["message"]
+    contents = ElectraBeaconBlockContentsMainnet.from_json(
+        json.dumps({"data": {"block": block_json, "kzg_proofs": [], "blobs": []}}).encode()
+    )
+    payload = ElectraExecutionPayloadMainnet.from_json(
+        json.dumps({"data": block_json["body"]["execution_payload"]}).encode()
+    )
+    block_root = (
+        ElectraSignedBeaconBlockMainnet.from_json(json.dumps(signed_block).encode())
+        .signed_header()
+        .block_root()
+    )
+
+    assert contents.block_hash_tree_root() == block_root
+    assert contents.block_hash_tree_root() == block_root
+    assert contents.body_root() == contents.header_dict()["body_root"]
+    assert contents.payload_root() == (
+        ElectraExecutionPayloadMainnet.hash_tree_root_of_ssz(payload.to_ssz())
+    )
+
+
+def test_setters_clear_cached_roots() -> None:
+    signed_block = json.loads(
+        (Path(__file__).parent / "fixtures/mainnet-13689000.json").read_text()
+    )
+    block_json = signed_block["data"]["message"]
+    contents = ElectraBeaconBlockContentsMainnet.from_json(
+        json.dumps({"data": {"block": block_json, "kzg_proofs": [], "blobs": []}}).encode()
+    )
+    payload = ElectraExecutionPayloadMainnet.from_json(
+        json.dumps(
+            {"data": block_json["body"]["execution_payload"] | {"gas_used": "1"}}
+        ).encode()
+    )
+
+    def fresh(block: ElectraBeaconBlockContentsMainnet) -> tuple[str, str, str]:
+        block = ElectraBeaconBlockContentsMainnet.from_ssz(block.to_ssz())
+        return block.block_hash_tree_root(), block.body_root(), block.payload_root()
+
+    def cached(block: ElectraBeaconBlockContentsMainnet) -> tuple[str, str, str]:
+        return block.block_hash_tree_root(), block.body_root(), block.payload_root()
+
+    roots = cached(contents)
+
+    mutable = contents.clone_mut()
+    mutable.set_state_root("0x" + "11" * 32)
+    edited = mutable.freeze()
+    assert cached(edited) == fresh(edited)
+    assert cached(edited)[1:] == roots[1:]
+
+    mutable.set_graffiti("0x" + "22" * 32)
+    edited = mutable.freeze()
+    assert cached(edited) == fresh(edited)
+    assert cached(edited)[2] == roots[2]
+
+    mutable.set_execution_payload(payload)
+    edited = mutable.freeze()
+    assert cached(edited) == fresh(edited)
+    assert cached(edited)[2] != roots[2]
+
+    mutable.recompute_roots()
+    assert cached(mutable.freeze()) == fresh(mutable.freeze())
+    assert cached(contents) == roots
+
+
+def test_shared_between_threads() -> None:
+    signed_block = json.loads(
+        (Path(__file__).parent / "fixtures/mainnet-13689000.json").read_text()
+    )
+    block = ElectraSignedBeaconBlockMainnet.from_json(json.dumps(signed_block).encode())
+    expected = block.to_ssz()
+
+    with ThreadPoolExecutor(max_workers=4) as executor:
+        results = list(executor.map(lambda _: block.to_ssz(), range(16)))
+
+    assert results == [expected] * 16
+
+
+def test_decode_from_buffers() -> None:
+    fixtures = Path(__file__).parent / "fixtures"
+    encoded = (fixtures / "mainnet-13689000.ssz").read_bytes()
+    json_bytes = (fixtures / "mainnet-13689000.json").read_bytes()
+    root = ElectraSignedBeaconBlockMainnet.hash_tree_root_of_ssz(encoded)
+
+    for buffer in (bytearray(encoded), memoryview(encoded)):
+        block = ElectraSignedBeaconBlockMainnet.from_ssz(buffer)
+        assert block.to_ssz() == encoded
+        assert ElectraSignedBeaconBlockMainnet.hash_tree_root_of_ssz(buffer) == root
+
+    # A slice of a larger buffer is read in place.
+    padded = memoryview(b"\xff" * 8 + encoded + b"\xff" * 8)[8:-8]
+    assert ElectraSignedBeaconBlockMainnet.from_ssz(padded).to_ssz() == encoded
+
+    block = ElectraSignedBeaconBlockMainnet.from_json(memoryview(json_bytes))
+    assert block.to_ssz() == encoded
+    blocks = ElectraSignedBeaconBlockMainnet.from_json_batch([bytearray(json_bytes)])
+    assert blocks[0].to_ssz() == encoded
+
+    with pytest.raises(ValueError, match="not contiguous"):
+        ElectraSignedBeaconBlockMainnet.from_ssz(memoryview(encoded + encoded)[::2])
+    with pytest.raises(TypeError, match="Expected bytes or a buffer of bytes, got str"):
+        ElectraSignedBeaconBlockMainnet.from_ssz("00")
+
+
+def test_ssz_batch() -> None:
+    fixtures = Path(__file__).parent / "fixtures"
+    encoded = (fixtures / "mainnet-13689000.ssz").read_bytes()
+    block = ElectraSignedBeaconBlockMainnet.from_ssz(encoded)
+    other = ElectraSignedBeaconBlockMainnet.from_dict(
+        block.to_dict() | {"signature": b"\xc0" + bytes(95)}
+    ).to_ssz()
+
+    blocks = ElectraSignedBeaconBlockMainnet.from_ssz_batch(
+        [encoded, bytearray(other), memoryview(encoded)]
+    )
+    assert [b.to_ssz() for b in blocks] == [encoded, other, encoded]
+    assert ElectraSignedBeaconBlockMainnet.to_ssz_batch(blocks) == [
+        encoded,
+        other,
+        encoded,
+    ]
+    assert ElectraSignedBeaconBlockMainnet.hash_tree_root_batch(blocks) == [
+        ElectraSignedBeaconBlockMainnet.hash_tree_root_of_ssz(ssz)
+        for ssz in (encoded, other, encoded)
+    ]
+    assert ElectraSignedBeaconBlockMainnet.from_ssz_batch([]) == []
+
+    with pytest.raises(ValueError, match="Item 1: "):
+        ElectraSignedBeaconBlockMainnet.from_ssz_batch([encoded, b"\x00"])
+    with pytest.raises(TypeError):
+        ElectraSignedBeaconBlockMainnet.to_ssz_batch([object()])
@@ -0,0 +1,182 @@
+import json
+from pathlib import Path
+from typing import Any
+
+import pytest
+
+from grandine_py import (
+    BlockBodyBuilder,
+    BlsEncodingError,
+    Config,
+    ElectraAttestationMinimal,
+    ElectraBeaconBlockContentsMinimal,
+    ElectraBlindedBeaconBlockMinimal,
+    ElectraExecutionPayloadHeaderMinimal,
+    ElectraExecutionPayloadMinimal,
+)
+
+FIXTURE = Path(__file__).parent / "fixtures/minimal-signed-blinded-block.json"
+
+
+@pytest.fixture
+def message() -> dict[str, Any]:
+    with open(FIXTURE, "rb") as f:
+        return json.loads(f.read().decode())["data"]["message"]
+
+
+def envelope(data: object) -> bytes:
+    return json.dumps({"data": data}).encode()
+
+
+def header_of(message: dict[str, Any]) -> ElectraExecutionPayloadHeaderMinimal:
+    return ElectraExecutionPayloadHeaderMinimal.from_json(
+        envelope(message["body"]["execution_payload_header"])
+    )
+
+
+def payload_of(message: dict[str, Any]) -> ElectraExecutionPayloadMinimal:
+    payload = dict(message["body"]["execution_payload_header"])
+    del payload["transactions_root"], payload["withdrawals_root"]
+    payload["transactions"] = []
+    payload["withdrawals"] = []
+    return ElectraExecutionPayloadMinimal.from_json(envelope(payload))
+
+
+def builder_from(message: dict[str, Any]) -> BlockBodyBuilder:
+    body = message["body"]
+    builder = BlockBodyBuilder(Config.minimal())
+    builder.set_randao_reveal(body["randao_reveal"])
+    builder.set_eth1_data(
+        body["eth1_data"]["deposit_root"],
+        int(body["eth1_data"]["deposit_count"]),
+        body["eth1_data"]["block_hash"],
+    )
+    builder.set_graffiti(body["graffiti"])
+    builder.set_sync_aggregate(
+        bytes.fromhex(body["sync_aggregate"]["sync_committee_bits"][2:]),
+        body["sync_aggregate"]["sync_committee_signature"],
+    )
+    for attestation in body["attestations"]:
+        builder.add_attestation(
+            ElectraAttestationMinimal.from_json(envelope(attestation))
+        )
+    return builder
+
+
+def build(builder: BlockBodyBuilder, message: dict[str, Any]) -> Any:
+    return builder.build(
+        int(message["slot"]),
+        int(message["proposer_index"]),
+        message["parent_root"],
+        message["state_root"],
+    )
+
+
+def test_build_blinded_block_matches_fixture(message: dict[str, Any]) -> None:
+    builder = builder_from(message)
+    builder.set_execution_payload_header(header_of(message))
+
+    block = build(builder, message)
+
+    assert isinstance(block, ElectraBlindedBeaconBlockMinimal)
+    assert json.loads(block.to_json().decode()) == message
+
+
+def test_build_full_block_returns_contents(message: dict[str, Any]) -> None:
+    builder = builder_from(message)
+    builder.set_execution_payload(payload_of(message))
+    builder.set_blob_kzg_commitments([b"\xc0" + b"\x00" * 47])
+
+    with pytest.raises(ValueError, match="Expected 1 blobs for 1 KZG commitments"):
+        build(builder, message)
+
+    builder.set_blobs([bytes(131072)], [b"\xc0" + b"\x00" * 47])
+    contents = build(builder, message)
+
+    assert isinstance(contents, ElectraBeaconBlockContentsMinimal)
+    contents_json = json.loads(contents.to_json().decode())
+    assert contents_json["block"]["slot"] == message["slot"]
+    assert len(contents_json["blobs"]) == 1
+
+
+def test_build_requires_randao_reveal_and_payload(message: dict[str, Any]) -> None:
+    builder = BlockBodyBuilder(Config.minimal())
+
+    with pytest.raises(ValueError, match="Missing randao reveal"):
+        build(builder, message)
+
+    builder.set_randao_reveal(message["body"]["randao_reveal"])
+
+    with pytest.raises(ValueError, match="Missing execution payload"):
+        build(builder, message)
+
+
+def test_build_rejects_attestations_from_the_future(message: dict[str, Any]) -> None:
+    builder = builder_from(message)
+    builder.set_execution_payload_header(header_of(message))
+    attestation_slot = int(message["body"]["attestations"][0]["data"]["slot"])
+
+    with pytest.raises(ValueError, match="cannot be included"):
+        builder.build(attestation_slot, 0, b"\x00" * 32)
+
+
+@pytest.mark.parametrize(
+    ("changes", "error"),
+    [
+        ({"index": "1"}, "committee index 1, which must be 0"),
+        ({"target": {"epoch": "3"}}, "targets epoch 3, expected 4"),
+    ],
+)
+def test_build_rejects_attestations_against_electra_rules(
+    message: dict[str, Any], changes: dict[str, Any], error: str
+) -> None:
+    attestation = message["body"]["attestations"][0]
+    attestation["data"] |= {
+        key: attestation["data"][key] | value if isinstance(value, dict) else value
+        for key, value in changes.items()
+    }
+    builder = builder_from(message)
+    builder.set_execution_payload_header(header_of(message))
+
+    with pytest.raises(ValueError, match=error):
+        build(builder, message)
+
+
+def test_build_checks_blob_limit(message: dict[str, Any]) -> None:
+    commitment = b"\xc0" + b"\x00" * 47
+    builder = builder_from(message)
+    builder.set_execution_payload_header(header_of(message))
+    builder.set_blob_kzg_commitments([commitment] * 10)
+
+    with pytest.raises(ValueError, match=r"10 KZG commitments exceed .* \(9\)"):
+        build(builder, message)
+
+    config = Config("devnet", "minimal", 6, max_blobs_per_block_electra=12)
+    limited = BlockBodyBuilder(config)
+    limited.set_randao_reveal(message["body"]["randao_reveal"])
+    limited.set_execution_payload_header(header_of(message))
+    limited.set_blob_kzg_commitments([commitment] * 10)
+    block = build(limited, message)
+    assert len(block.to_dict()["body"]["blob_kzg_commitments"]) == 10
+
+
+def test_setters_validate_inputs(message: dict[str, Any]) -> None:
+    builder = BlockBodyBuilder(Config.minimal())
+
+    with pytest.raises(BlsEncodingError):
+        builder.set_randao_reveal("0x1234")
+    with pytest.raises(ValueError, match="32 bytes"):
+        builder.set_graffiti(b"\x00")
+    with pytest.raises(ValueError, match="Invalid KZG commitment 0"):
+        builder.set_blob_kzg_commitments([b"\x00"])
+    with pytest.raises(TypeError, match="Expected ElectraAttestationMinimal"):
+        builder.add_attestation(header_of(message))
+
+
+def test_blinded_block_rejects_blobs(message: dict[str, Any]) -> None:
+    builder = builder_from(message)
+    builder.set_execution_payload_header(header_of(message))
+    builder.set_blobs([bytes(131072)], [b"\xc0" + b"\x00" * 47])
+
+    with pytest.raises(ValueError, match="cannot carry blobs"):
+        build(builder, message)
@@ -0,0 +1,109 @@
+import hashlib
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraSignedBuilderBidMainnet,
+    SignedValidatorRegistration,
+    ValidatorRegistration,
+    bls_pubkey,
+    bls_sign,
+)
+
+FIXTURES = Path(__file__).parent / "fixtures"
+MAINNET_GENESIS_FORK_VERSION = "0x00000000"
+SECRET_KEY = (7).to_bytes(32, "big")
+OTHER_SECRET_KEY = (8).to_bytes(32, "big")
+
+
+def _sha256(data: bytes) -> bytes:
+    return hashlib.sha256(data).digest()
+
+
+def _mainnet_bid_json() -> dict:
+    return json.loads(
+        (FIXTURES / "mainnet-signed-builder-bid-slot-14149070.json").read_text()
+    )["data"]
+
+
+def _registration_json() -> dict:
+    return {
+        "fee_recipient": "0x" + "ab" * 20,
+        "gas_limit": "36000000",
+        "timestamp": "1700000000",
+        "pubkey": bls_pubkey(SECRET_KEY),
+    }
+
+
+def _signed_registration(secret_key: bytes) -> SignedValidatorRegistration:
+    message = ValidatorRegistration.from_json(
+        json.dumps({"data": _registration_json()}).encode()
+    )
+    signature = bls_sign(
+        secret_key, message.signing_root(MAINNET_GENESIS_FORK_VERSION)
+    )
+    signed = {"message": _registration_json(), "signature": signature}
+    return SignedValidatorRegistration.from_json(json.dumps({"data": signed}).encode())
+
+
+def test_bid_value_and_pubkey() -> None:
+    bid_json = _mainnet_bid_json()
+    bid = ElectraSignedBuilderBidMainnet.from_json(json.dumps(bid_json).encode())
+
+    assert bid.value() == 54778214863022513
+    assert bid.pubkey() == bid_json["data"]["message"]["pubkey"]
+
+
+def test_bid_value_beyond_64_bits(builder_bid_json: dict) -> None:
+    builder_bid_json["data"]["message"]["value"] = str(2**200 + 1)
+    bid = ElectraSignedBuilderBidMainnet.from_json(
+        json.dumps({"data": builder_bid_json["data"]}).encode()
+    )
+
+    assert bid.value() == 2**200 + 1
+
+
+def test_verify_bid() -> None:
+    bid = ElectraSignedBuilderBidMainnet.from_json(
+        json.dumps(_mainnet_bid_json()).encode()
+    )
+
+    assert bid.verify(bid.pubkey(), MAINNET_GENESIS_FORK_VERSION)
+    assert not bid.verify(bls_pubkey(SECRET_KEY), MAINNET_GENESIS_FORK_VERSION)
+    assert not bid.verify(bid.pubkey(), "0x00000064")
+
+    with pytest.raises(ValueError, match="fork_version must be 4 bytes"):
+        bid.verify(bid.pubkey(), "0x00")
+
+
+def test_validator_registration() -> None:
+    registration = _signed_registration(SECRET_KEY)
+    message = registration.message()
+
+    assert message.fee_recipient() == "0x" + "ab" * 20
+    assert message.gas_limit() == 36000000
+    assert message.timestamp() == 1700000000
+    assert message.pubkey() == bls_pubkey(SECRET_KEY)
+
+    pubkey = bytes.fromhex(message.pubkey()[2:])
+    leaves = [
+        bytes.fromhex("ab" * 20) + bytes(12),
+        (36000000).to_bytes(32, "little"),
+        (1700000000).to_bytes(32, "little"),
+        _sha256(pubkey + bytes(16)),
+    ]
+    root = _sha256(_sha256(leaves[0] + leaves[1]) + _sha256(leaves[2] + leaves[3]))
+    assert message.hash_tree_root() == "0x" + root.hex()
+
+    assert len(registration.to_ssz()) == 180
+    assert SignedValidatorRegistration.from_ssz(registration.to_ssz()) == registration
+
+
+def test_verify_validator_registration() -> None:
+    assert _signed_registration(SECRET_KEY).verify(MAINNET_GENESIS_FORK_VERSION)
+    assert not _signed_registration(SECRET_KEY).verify("0x10000910")
+    assert not _signed_registration(OTHER_SECRET_KEY).verify(
+        MAINNET_GENESIS_FORK_VERSION
+    )
@@ -0,0 +1,76 @@
+import json
+
+import pytest
+
+from grandine_py import (
+    Config,
+    ElectraBeaconStateMinimal,
+    FuluBeaconStateMinimal,
+    committee_size_stats,
+)
+
+# Minimal preset values.
+SLOTS_PER_EPOCH = 8
+ATTESTATION_SUBNET_COUNT = 64
+
+
+def _decode(cls, state_json: dict):
+    return cls.from_json(json.dumps({"data": state_json}).encode())
+
+
+def test_committee_size_stats_current_epoch(minimal_state_json: dict) -> None:
+    state = _decode(ElectraBeaconStateMinimal, minimal_state_json)
+
+    stats = committee_size_stats(state, 2)
+
+    # 12 validators are far below `SLOTS_PER_EPOCH * TARGET_COMMITTEE_SIZE`,
+    # so every slot has a single committee.
+    assert stats["epoch"] == 2
+    assert stats["active_validators"] == 12
+    assert stats["committees_per_slot"] == 1
+    assert [entry["slot"] for entry in stats["slots"]] == list(range(16, 24))
+    assert [entry["committee_sizes"] for entry in stats["slots"]] == [
+        [1],
+        [2],
+        [1],
+        [2],
+        [1],
+        [2],
+        [1],
+        [2],
+    ]
+    assert all(entry["committee_count"] == 1 for entry in stats["slots"])
+
+    subnets = stats["subnet_attestations"]
+    assert len(subnets) == ATTESTATION_SUBNET_COUNT
+    assert subnets[:SLOTS_PER_EPOCH] == [1, 2, 1, 2, 1, 2, 1, 2]
+    assert sum(subnets) == stats["active_validators"]
+
+
+def test_committee_size_stats_next_epoch_excludes_exits(
+    minimal_state_json: dict,
+) -> None:
+    state = _decode(FuluBeaconStateMinimal, minimal_state_json)
+
+    stats = committee_size_stats(state, 3, Config.minimal())
+
+    assert stats["active_validators"] == 11
+    sizes = [size for entry in stats["slots"] for size in entry["committee_sizes"]]
+    assert sum(sizes) == 11
+    assert sum(stats["subnet_attestations"]) == 11
+
+
+def test_committee_size_stats_rejects_out_of_range_epoch(
+    minimal_state_json: dict,
+) -> None:
+    state = _decode(ElectraBeaconStateMinimal, minimal_state_json)
+
+    committee_size_stats(state, 1)
+    with pytest.raises(ValueError, match="not within one epoch"):
+        committee_size_stats(state, 0)
+    with pytest.raises(ValueError, match="not within one epoch"):
+        committee_size_stats(state, 4)
+    with pytest.raises(ValueError, match="not within one epoch"):
+        committee_size_stats(state, 2**64 - 1)
+    with pytest.raises(TypeError):
+        committee_size_stats(object(), 2)
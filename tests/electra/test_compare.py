@@ -0,0 +1,119 @@
+import copy
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraBeaconBlockContentsMainnet,
+    ElectraBlindedBeaconBlockMainnet,
+    ElectraBlindedBeaconBlockMinimal,
+    ElectraSignedBuilderBidMainnet,
+    compare_candidates,
+)
+
+FIXTURES = Path(__file__).parent / "fixtures"
+COMMITMENT = "0x" + "aa" * 48
+
+
+def _block_contents_json() -> dict:
+    block = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    return {"block": block["data"]["message"], "kzg_proofs": [], "blobs": []}
+
+
+def _block_contents() -> ElectraBeaconBlockContentsMainnet:
+    return ElectraBeaconBlockContentsMainnet.from_json(
+        json.dumps({"data": _block_contents_json()}).encode()
+    )
+
+
+def _bid(bid_json: dict) -> ElectraSignedBuilderBidMainnet:
+    return ElectraSignedBuilderBidMainnet.from_json(
+        json.dumps({"data": bid_json}).encode()
+    )
+
+
+def _matching_bid(builder_bid_json: dict) -> dict:
+    """A bid with the payload of the fixture block, apart from the roots."""
+    bid_json = copy.deepcopy(builder_bid_json["data"])
+    payload = dict(_block_contents_json()["block"]["body"]["execution_payload"])
+    del payload["transactions"], payload["withdrawals"]
+    header = bid_json["message"]["header"]
+    bid_json["message"]["header"] = header | payload
+    return bid_json
+
+
+def test_differences(builder_bid_json: dict) -> None:
+    report = compare_candidates(
+        _block_contents(), _bid(builder_bid_json["data"]), local_value=10
+    )
+
+    assert report["local_value"] == 10
+    assert report["bid_value"] == 16
+    assert report["value_delta"] == 6
+
+    payload = _block_contents_json()["block"]["body"]["execution_payload"]
+    differences = report["header_differences"]
+    assert differences["gas_limit"] == (payload["gas_limit"], "888")
+    assert differences["extra_data"] == (payload["extra_data"], "0x1234")
+    assert "transactions" not in differences
+
+    assert report["blob_commitments"] == {
+        "local": 0,
+        "bid": 1,
+        "only_local": [],
+        "only_bid": [COMMITMENT],
+    }
+
+
+def test_matching_header(builder_bid_json: dict) -> None:
+    bid = _bid(_matching_bid(builder_bid_json))
+    report = compare_candidates(_block_contents(), bid)
+
+    assert report["local_value"] is None
+    assert report["value_delta"] is None
+    assert sorted(report["header_differences"]) == [
+        "transactions_root",
+        "withdrawals_root",
+    ]
+
+
+def test_produced_block(builder_bid_json: dict) -> None:
+    response = {
+        "version": "electra",
+        "execution_payload_blinded": False,
+        "execution_payload_value": "20",
+        "data": _block_contents_json(),
+    }
+    produced = ElectraBlindedBeaconBlockMainnet.from_produce_block_v3(
+        json.dumps(response).encode()
+    )
+    bid = _bid(builder_bid_json["data"])
+
+    report = compare_candidates(produced, bid)
+    assert report["local_value"] == 20
+    assert report["value_delta"] == -4
+
+    assert compare_candidates(produced, bid, local_value=16)["value_delta"] == 0
+
+
+def test_errors(builder_bid_json: dict) -> None:
+    bid = _bid(builder_bid_json["data"])
+
+    blinded = json.loads((FIXTURES / "minimal-signed-blinded-block.json").read_text())
+    response = {
+        "version": "electra",
+        "execution_payload_blinded": True,
+        "data": blinded["data"]["message"],
+    }
+    produced = ElectraBlindedBeaconBlockMinimal.from_produce_block_v3(
+        json.dumps(response).encode()
+    )
+    with pytest.raises(ValueError, match="ProducedBlock is blinded"):
+        compare_candidates(produced, bid)
+
+    with pytest.raises(TypeError, match="Expected block contents"):
+        compare_candidates(bid, bid)
+
+    with pytest.raises(TypeError, match="builder bid of the contents' preset"):
+        compare_candidates(_block_contents(), _block_contents())
@@ -0,0 +1,204 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraBeaconStateMinimal,
+    ElectraSignedBeaconBlockMinimal,
+    EraReader,
+    EraWriter,
+    FuluSignedBeaconBlockMinimal,
+    Phase0SignedBeaconBlockMinimal,
+)
+
+FIXTURE = Path(__file__).parent / "fixtures/minimal-signed-blinded-block.json"
+
+# SLOTS_PER_HISTORICAL_ROOT of the minimal preset.
+ERA_SLOTS = 64
+
+
+def _block(slot: int) -> ElectraSignedBeaconBlockMinimal:
+    signed = json.loads(FIXTURE.read_text())["data"]
+    body = signed["message"]["body"]
+    payload = dict(body.pop("execution_payload_header"))
+    del payload["transactions_root"], payload["withdrawals_root"]
+    body["execution_payload"] = payload | {"transactions": [], "withdrawals": []}
+    signed["message"]["slot"] = str(slot)
+    return ElectraSignedBeaconBlockMinimal.from_json(
+        json.dumps({"data": signed}).encode()
+    )
+
+
+def _phase0_block(slot: int) -> Phase0SignedBeaconBlockMinimal:
+    signed = json.loads(FIXTURE.read_text())["data"]
+    body = signed["message"]["body"]
+    lists = [
+        "proposer_slashings",
+        "attester_slashings",
+        "attestations",
+        "deposits",
+        "voluntary_exits",
+    ]
+    signed["message"]["body"] = {
+        "randao_reveal": body["randao_reveal"],
+        "eth1_data": body["eth1_data"],
+        "graffiti": body["graffiti"],
+    } | {name: [] for name in lists}
+    signed["message"]["slot"] = str(slot)
+    return Phase0SignedBeaconBlockMinimal.from_json(
+        json.dumps({"data": signed}).encode()
+    )
+
+
+def _state(state_json: dict, slot: int) -> ElectraBeaconStateMinimal:
+    return ElectraBeaconStateMinimal.from_json(
+        json.dumps({"data": state_json | {"slot": str(slot)}}).encode()
+    )
+
+
+def _records(data: bytes) -> list[tuple[int, bytes, bytes]]:
+    """Split an e2store file into (position, type, data) records."""
+    records = []
+    position = 0
+    while position < len(data):
+        length = int.from_bytes(data[position + 2 : position + 6], "little")
+        assert data[position + 6 : position + 8] == bytes(2)
+        start = position + 8
+        records.append((position, data[position : position + 2], data[start:][:length]))
+        position = start + length
+    return records
+
+
+def test_roundtrip(tmp_path: Path, minimal_state_json: dict) -> None:
+    path = tmp_path / "minimal-00001.era"
+    blocks = [_block(slot) for slot in (1, 5, 63)]
+    state = _state(minimal_state_json, ERA_SLOTS)
+
+    with EraWriter(path, "minimal") as writer:
+        for block in blocks:
+            writer.add_block(block)
+        assert writer.finish(state) == 1
+
+    reader = EraReader(path, "minimal")
+    assert reader.era == 1
+    assert reader.starting_slot == 0
+    assert reader.state_slot == ERA_SLOTS
+    assert len(reader) == 3
+    assert reader.slots() == [1, 5, 63]
+    assert [block.to_ssz() for block in reader] == [block.to_ssz() for block in blocks]
+
+    assert isinstance(reader.block(5), ElectraSignedBeaconBlockMinimal)
+    assert reader.block(2) is None
+    assert reader.block_ssz(2) is None
+    assert reader.block_ssz(63) == blocks[2].to_ssz()
+    assert reader.state_ssz() == state.to_ssz()
+    assert reader.state().hash_tree_root() == state.hash_tree_root()
+
+    # Blocks from the second epoch on decode as Fulu blocks.
+    reader = EraReader(path, "minimal", fork_schedule={"electra": 0, "fulu": 1})
+    assert [type(block) for block in reader] == [
+        ElectraSignedBeaconBlockMinimal,
+        ElectraSignedBeaconBlockMinimal,
+        FuluSignedBeaconBlockMinimal,
+    ]
+
+
+def test_phase0_roundtrip(tmp_path: Path, minimal_state_json: dict) -> None:
+    path = tmp_path / "minimal-00001.era"
+    blocks = [_phase0_block(slot) for slot in (1, 2)]
+    state = _state(minimal_state_json, ERA_SLOTS)
+
+    with EraWriter(path, "minimal") as writer:
+        for block in blocks:
+            writer.add_block(block)
+        writer.finish(state)
+
+    # Blocks are told apart by their layout, and the state is tried oldest
+    # fork first.
+    for reader in (
+        EraReader(path, "minimal"),
+        EraReader(path, "minimal", fork_schedule={"phase0": 0, "electra": 1}),
+    ):
+        assert [type(block) for block in reader] == [Phase0SignedBeaconBlockMinimal] * 2
+        assert [block.to_ssz() for block in reader] == [b.to_ssz() for b in blocks]
+        assert reader.state().hash_tree_root() == state.hash_tree_root()
+
+
+def test_file_layout(tmp_path: Path, minimal_state_json: dict) -> None:
+    path = tmp_path / "minimal-00001.era"
+
+    with EraWriter(path, "minimal") as writer:
+        writer.add_block(_block(5))
+        writer.add_block(_block(6))
+        writer.finish(_state(minimal_state_json, ERA_SLOTS))
+
+    records = _records(path.read_bytes())
+    types = [kind for _, kind, _ in records]
+    assert types == [b"e2", b"\x01\x00", b"\x01\x00", b"\x02\x00", b"i2", b"i2"]
+    assert records[0][2] == b""
+
+    def index(data: bytes) -> list[int]:
+        return [
+            int.from_bytes(data[i : i + 8], "little", signed=True)
+            for i in range(0, len(data), 8)
+        ]
+
+    block_index_position, _, block_index = records[4]
+    starting_slot, *offsets, count = index(block_index)
+    assert (starting_slot, count, len(offsets)) == (0, ERA_SLOTS, ERA_SLOTS)
+    assert block_index_position + offsets[5] == records[1][0]
+    assert block_index_position + offsets[6] == records[2][0]
+    assert offsets[:5] == [0] * 5 and offsets[7:] == [0] * (ERA_SLOTS - 7)
+
+    state_index_position, _, state_index = records[5]
+    assert index(state_index)[0::2] == [ERA_SLOTS, 1]
+    assert state_index_position + index(state_index)[1] == records[3][0]
+
+
+def test_genesis_era(tmp_path: Path, minimal_state_json: dict) -> None:
+    path = tmp_path / "minimal-00000.era"
+    state = _state(minimal_state_json, 0)
+
+    with EraWriter(path, "minimal") as writer:
+        assert writer.finish(state) == 0
+
+    reader = EraReader(path, "minimal")
+    assert (reader.era, reader.starting_slot, reader.state_slot) == (0, None, 0)
+    assert len(reader) == 0
+    assert list(reader) == []
+    assert reader.state().to_ssz() == state.to_ssz()
+
+
+def test_errors(tmp_path: Path, minimal_state_json: dict) -> None:
+    path = tmp_path / "minimal-00001.era"
+    writer = EraWriter(path, "minimal")
+
+    writer.add_block(_block(5))
+    with pytest.raises(ValueError, match="not after the previous block at slot 5"):
+        writer.add_block(_block(5))
+    with pytest.raises(ValueError, match="not a multiple"):
+        writer.finish(_state(minimal_state_json, ERA_SLOTS + 1))
+    with pytest.raises(ValueError, match="Block at slot 5 is not in era 2"):
+        writer.finish(_state(minimal_state_json, 2 * ERA_SLOTS))
+
+    writer.finish(_state(minimal_state_json, ERA_SLOTS))
+    with pytest.raises(ValueError, match="closed"):
+        writer.add_block(_block(6))
+
+    with pytest.raises(ValueError, match="Unknown preset"):
+        EraReader(path, "nope")
+    with pytest.raises(ValueError, match="states of fork deneb are not supported"):
+        EraReader(path, "minimal", fork="deneb").state()
+
+    truncated = tmp_path / "truncated.era"
+    truncated.write_bytes(path.read_bytes()[:-8])
+    with pytest.raises(ValueError, match="Invalid era file: missing state index"):
+        EraReader(truncated, "minimal")
+
+    capture = tmp_path / "capture.bin"
+    capture.write_bytes(b"GPGC\x01\x00\x00\x00")
+    with pytest.raises(ValueError, match="Invalid era file: missing version record"):
+        EraReader(capture, "minimal")
+    with pytest.raises(OSError):
+        EraReader(tmp_path / "missing.era")
@@ -0,0 +1,258 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraExecutionPayloadAndBlobsBundleMainnet,
+    ElectraExecutionPayloadHeaderMainnet,
+    ElectraExecutionPayloadMainnet,
+    ElectraSignedBeaconBlockMainnet,
+    set_hex_format,
+)
+
+FIXTURES = Path(__file__).parent / "fixtures"
+
+
+def test_payload_stats() -> None:
+    block = ElectraSignedBeaconBlockMainnet.from_ssz(
+        (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    )
+
+    stats = block.payload_stats()
+
+    assert stats == {
+        "gas_used": 20687591,
+        "gas_limit": 60000000,
+        "base_fee_per_gas": 52179350,
+        "blob_gas_used": 0,
+        "excess_blob_gas": 173213499,
+        "tx_count": 263,
+        "blob_tx_count": 0,
+        "payload_size": 96898,
+    }
+    assert isinstance(stats["base_fee_per_gas"], int)
+
+
+def test_execution_payload_roundtrip() -> None:
+    block_json = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    payload_json = block_json["data"]["message"]["body"]["execution_payload"]
+
+    payload = ElectraExecutionPayloadMainnet.from_json(
+        json.dumps({"data": payload_json}).encode()
+    )
+
+    assert json.loads(payload.to_json()) == payload_json
+    assert ElectraExecutionPayloadMainnet.from_ssz(payload.to_ssz()).to_ssz() == payload.to_ssz()
+    assert payload.payload_stats()["payload_size"] == len(payload.to_ssz())
+
+
+def test_transactions_by_type() -> None:
+    block_json = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    payload_json = block_json["data"]["message"]["body"]["execution_payload"]
+    payload = ElectraExecutionPayloadMainnet.from_json(
+        json.dumps({"data": payload_json}).encode()
+    )
+    raw = [bytes.fromhex(tx[2:]) for tx in payload_json["transactions"]]
+
+    groups = payload.transactions_by_type()
+
+    assert list(groups) == [
+        "legacy",
+        "eip2930",
+        "eip1559",
+        "eip4844",
+        "eip7702",
+        "unknown",
+    ]
+    assert groups["legacy"]["transactions"] == [tx for tx in raw if tx[0] >= 0xC0]
+    assert groups["eip1559"]["transactions"] == [tx for tx in raw if tx[0] == 0x02]
+    assert [group["count"] for group in groups.values()] == [36, 1, 224, 0, 2, 0]
+    assert groups["eip4844"]["count"] == payload.payload_stats()["blob_tx_count"]
+    assert sum(group["count"] for group in groups.values()) == len(raw)
+    assert groups["unknown"] == {"count": 0, "transactions": []}
+
+
+def test_el_withdrawals_root_matches_el_header() -> None:
+    block = ElectraSignedBeaconBlockMainnet.from_ssz(
+        (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    )
+
+    assert (
+        block.el_withdrawals_root()
+        == "0x41d3fddf4f91264083fae77a2c2734571586006932c5d7ab10f5e6485be866b4"
+    )
+
+
+def test_requests_hash() -> None:
+    block_json = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    block = ElectraSignedBeaconBlockMainnet.from_json(json.dumps(block_json).encode())
+
+    # sha256 of nothing: all request lists are empty.
+    assert (
+        block.requests_hash()
+        == "0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    )
+
+    requests = block_json["data"]["message"]["body"]["execution_requests"]
+    requests["deposits"] = [
+        {
+            "pubkey": "0x" + ("bb" * 48),
+            "withdrawal_credentials": "0x" + ("cc" * 32),
+            "amount": "13",
+            "signature": "0x" + ("dd" * 96),
+            "index": "14",
+        }
+    ]
+    requests["consolidations"] = [
+        {
+            "source_address": "0x" + ("11" * 20),
+            "source_pubkey": "0x" + ("22" * 48),
+            "target_pubkey": "0x" + ("33" * 48),
+        }
+    ]
+    block = ElectraSignedBeaconBlockMainnet.from_json(json.dumps(block_json).encode())
+
+    # Empty withdrawal requests are skipped rather than hashed.
+    assert (
+        block.requests_hash()
+        == "0x84e0f0305bb3e22b3567621bf76d66e30621c1269fbbbc2d9a7068de4e043a10"
+    )
+
+
+def _el_rpc_block(payload_json: dict) -> dict:
+    def quantity(value: str) -> str:
+        return hex(int(value))
+
+    return {
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": {
+            "parentHash": payload_json["parent_hash"],
+            "miner": payload_json["fee_recipient"],
+            "stateRoot": payload_json["state_root"],
+            "receiptsRoot": payload_json["receipts_root"],
+            "logsBloom": payload_json["logs_bloom"],
+            "mixHash": payload_json["prev_randao"],
+            "number": quantity(payload_json["block_number"]),
+            "gasLimit": quantity(payload_json["gas_limit"]),
+            "gasUsed": quantity(payload_json["gas_used"]),
+            "timestamp": quantity(payload_json["timestamp"]),
+            "extraData": payload_json["extra_data"],
+            "baseFeePerGas": quantity(payload_json["base_fee_per_gas"]),
+            "hash": payload_json["block_hash"],
+            "transactions": [f"0x{i:064x}" for i in range(len(payload_json["transactions"]))],
+            "transactionsRoot": "0xc094705b913b0e221265cbd473216f17d3fb400294542f765fc03906e4aed7bd",
+            "withdrawals": [
+                {
+                    "index": quantity(w["index"]),
+                    "validatorIndex": quantity(w["validator_index"]),
+                    "address": w["address"],
+                    "amount": quantity(w["amount"]),
+                }
+                for w in payload_json["withdrawals"]
+            ],
+            "withdrawalsRoot": "0x41d3fddf4f91264083fae77a2c2734571586006932c5d7ab10f5e6485be866b4",
+            "blobGasUsed": quantity(payload_json["blob_gas_used"]),
+            "excessBlobGas": quantity(payload_json["excess_blob_gas"]),
+        },
+    }
+
+
+def test_header_from_el_header_json() -> None:
+    block_json = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    payload_json = block_json["data"]["message"]["body"]["execution_payload"]
+    payload = ElectraExecutionPayloadMainnet.from_json(
+        json.dumps({"data": payload_json}).encode()
+    )
+    raw_transactions = [bytes.fromhex(tx[2:]) for tx in payload_json["transactions"]]
+    rpc_block = _el_rpc_block(payload_json)
+
+    header = ElectraExecutionPayloadHeaderMainnet.from_el_header_json(
+        json.dumps(rpc_block).encode(), raw_transactions
+    )
+
+    # A header and its payload share the same hash tree root.
+    assert ElectraExecutionPayloadHeaderMainnet.hash_tree_root_of_ssz(
+        header.to_ssz()
+    ) == ElectraExecutionPayloadMainnet.hash_tree_root_of_ssz(payload.to_ssz())
+    assert json.loads(header.to_json())["block_hash"] == payload_json["block_hash"]
+
+    with pytest.raises(ValueError, match="raw_transactions"):
+        ElectraExecutionPayloadHeaderMainnet.from_el_header_json(
+            json.dumps(rpc_block).encode()
+        )
+    with pytest.raises(ValueError, match="transactionsRoot mismatch"):
+        ElectraExecutionPayloadHeaderMainnet.from_el_header_json(
+            json.dumps(rpc_block).encode(), raw_transactions[::-1]
+        )
+
+
+def test_header_from_el_header_json_ignores_hex_format() -> None:
+    block_json = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    payload_json = block_json["data"]["message"]["body"]["execution_payload"]
+    raw_transactions = [bytes.fromhex(tx[2:]) for tx in payload_json["transactions"]]
+    rpc_block = bytearray(json.dumps(_el_rpc_block(payload_json)).encode())
+
+    set_hex_format(uppercase=True, prefix=False)
+    try:
+        header = ElectraExecutionPayloadHeaderMainnet.from_el_header_json(
+            memoryview(rpc_block), raw_transactions
+        )
+    finally:
+        set_hex_format()
+
+    assert json.loads(header.to_json())["block_hash"] == payload_json["block_hash"]
+
+
+def test_payload_accessors() -> None:
+    block_json = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    payload_json = block_json["data"]["message"]["body"]["execution_payload"]
+    payload = ElectraExecutionPayloadMainnet.from_json(
+        json.dumps({"data": payload_json}).encode()
+    )
+    raw_transactions = [bytes.fromhex(tx[2:]) for tx in payload_json["transactions"]]
+    header = ElectraExecutionPayloadHeaderMainnet.from_el_header_json(
+        json.dumps(_el_rpc_block(payload_json)).encode(), raw_transactions
+    )
+    header_json = json.loads(header.to_json())
+
+    assert payload.block_hash() == header.block_hash() == payload_json["block_hash"]
+    # Payloads return the EL trie roots, headers the SSZ roots they store.
+    assert payload.el_transactions_root() == (
+        "0xc094705b913b0e221265cbd473216f17d3fb400294542f765fc03906e4aed7bd"
+    )
+    assert header.transactions_root() == header_json["transactions_root"]
+    assert header.withdrawals_root() == header_json["withdrawals_root"]
+    assert not hasattr(payload, "transactions_root")
+
+
+def test_execution_payload_and_blobs_bundle() -> None:
+    block_json = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    payload_json = block_json["data"]["message"]["body"]["execution_payload"]
+    blobs_bundle = {
+        "commitments": ["0x" + "aa" * 48],
+        "proofs": ["0xc0" + "00" * 47],
+        "blobs": ["0x" + "00" * 131072],
+    }
+    response = {
+        "version": "electra",
+        "data": {"execution_payload": payload_json, "blobs_bundle": blobs_bundle},
+    }
+
+    bundle = ElectraExecutionPayloadAndBlobsBundleMainnet.from_json(
+        json.dumps(response).encode()
+    )
+    payload = bundle.execution_payload()
+
+    assert payload.to_json() == ElectraExecutionPayloadMainnet.from_json(
+        json.dumps({"data": payload_json}).encode()
+    ).to_json()
+    assert bundle.block_hash() == payload_json["block_hash"]
+    assert bundle.el_transactions_root() == payload.el_transactions_root()
+    assert bundle.el_withdrawals_root() == payload.el_withdrawals_root()
+    assert [bytes(c) for c in bundle.kzg_commitments()] == [bytes([0xAA]) * 48]
+    assert len(bundle.kzg_proofs()) == len(bundle.blobs()) == 1
+
+    decoded = ElectraExecutionPayloadAndBlobsBundleMainnet.from_ssz(bundle.to_ssz())
+    assert decoded.to_ssz() == bundle.to_ssz()
@@ -0,0 +1,118 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraBeaconStateMinimal,
+    ElectraSignedBeaconBlockMainnet,
+    FinalityTracker,
+)
+
+FIXTURES = Path(__file__).parent / "fixtures"
+ROOT_A = "0x" + "aa" * 32
+ROOT_B = "0x" + "bb" * 32
+
+
+def _state(
+    state_json: dict, justified: int, finalized: int
+) -> ElectraBeaconStateMinimal:
+    state_json = state_json | {
+        "current_justified_checkpoint": {"epoch": str(justified), "root": ROOT_A},
+        "finalized_checkpoint": {"epoch": str(finalized), "root": ROOT_B},
+    }
+    return ElectraBeaconStateMinimal.from_json(
+        json.dumps({"data": state_json}).encode()
+    )
+
+
+def test_update_from_state(minimal_state_json: dict) -> None:
+    tracker = FinalityTracker("minimal")
+    assert tracker.justified is None
+    assert tracker.finality_lag() is None
+
+    events = tracker.update_from_state(_state(minimal_state_json, 1, 0))
+    assert [event["kind"] for event in events] == ["finalized", "justified"]
+    assert events[1] == {
+        "kind": "justified",
+        "epoch": 1,
+        "root": ROOT_A,
+        "previous_epoch": None,
+        "previous_root": None,
+        "slot": 21,
+    }
+    assert tracker.justified == (1, ROOT_A)
+    assert tracker.finalized == (0, ROOT_B)
+    assert tracker.head_slot == 21
+    # Slot 21 is in epoch 2 of the minimal preset.
+    assert tracker.finality_lag() == 2
+    assert tracker.finality_lag(current_epoch=5) == 5
+
+    assert tracker.update_from_state(_state(minimal_state_json, 1, 0)) == []
+
+    events = tracker.update_from_state(_state(minimal_state_json, 2, 1))
+    assert [(event["kind"], event["previous_epoch"]) for event in events] == [
+        ("finalized", 0),
+        ("justified", 1),
+    ]
+
+    # Older checkpoints never replace newer ones.
+    assert tracker.update_from_state(_state(minimal_state_json, 1, 0)) == []
+    assert tracker.finalized == (1, ROOT_B)
+
+
+def test_update_from_block() -> None:
+    block_json = (FIXTURES / "mainnet-13689000.json").read_bytes()
+    block = ElectraSignedBeaconBlockMainnet.from_json(block_json)
+    attestations = json.loads(block_json)["data"]["message"]["body"]["attestations"]
+    source = attestations[0]["data"]["source"]
+    tracker = FinalityTracker()
+
+    events = tracker.update_from_block(block)
+    assert [(event["kind"], event["epoch"]) for event in events] == [
+        ("justified", 427780)
+    ]
+    assert tracker.justified == (427780, source["root"])
+    assert tracker.finalized is None
+    assert tracker.head_slot == 13689000
+
+    assert tracker.update_from_attestation(block.attestations()[0]) == []
+
+
+def test_update_from_finality_checkpoints() -> None:
+    tracker = FinalityTracker()
+    response = {
+        "data": {
+            "previous_justified": {"epoch": "9", "root": ROOT_B},
+            "current_justified": {"epoch": "10", "root": ROOT_A},
+            "finalized": {"epoch": "9", "root": ROOT_B},
+        }
+    }
+
+    events = tracker.update_from_finality_checkpoints(response)
+    assert [(event["kind"], event["epoch"]) for event in events] == [
+        ("finalized", 9),
+        ("justified", 10),
+    ]
+    assert events[0]["slot"] is None
+    assert tracker.finality_lag() is None
+    assert tracker.finality_lag(12) == 3
+
+    assert tracker.update_from_finality_checkpoints(response["data"]) == []
+
+
+def test_errors(minimal_state_json: dict) -> None:
+    with pytest.raises(ValueError, match="Unknown preset"):
+        FinalityTracker("holesky")
+
+    tracker = FinalityTracker()
+    with pytest.raises(ValueError, match="Expected a mainnet object"):
+        tracker.update_from_state(_state(minimal_state_json, 1, 0))
+    with pytest.raises(TypeError, match="Expected a beacon state"):
+        tracker.update_from_state(object())
+    with pytest.raises(TypeError, match="Expected a signed beacon block"):
+        tracker.update_from_block(object())
+    with pytest.raises(ValueError, match="missing 'finalized'"):
+        tracker.update_from_finality_checkpoints(
+            {"current_justified": {"epoch": "1", "root": ROOT_A}}
+        )
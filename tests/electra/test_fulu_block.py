@@ -0,0 +1,124 @@
+import json
+import struct
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraBeaconBlockContentsMainnet,
+    ElectraExecutionPayloadMinimal,
+    ElectraSignedBeaconBlockMainnet,
+    FuluBeaconBlockContentsMainnet,
+    FuluBlindedBeaconBlockMinimal,
+    FuluSignedBeaconBlockMainnet,
+    FuluSignedBeaconBlockMinimal,
+    FuluSignedBlindedBeaconBlockMinimal,
+)
+
+FIXTURES = Path(__file__).parent / "fixtures"
+BLOB_SIZE = 4096 * 32
+CELLS_PER_EXT_BLOB = 128
+
+
+def _minimal_block(commitments: int) -> FuluSignedBeaconBlockMinimal:
+    signed = json.loads((FIXTURES / "minimal-signed-blinded-block.json").read_text())
+    body = signed["data"]["message"]["body"]
+    payload = dict(body.pop("execution_payload_header"))
+    del payload["transactions_root"], payload["withdrawals_root"]
+    body["execution_payload"] = payload | {"transactions": [], "withdrawals": []}
+    body["blob_kzg_commitments"] = ["0xc0" + "00" * 47] * commitments
+    return FuluSignedBeaconBlockMinimal.from_json(json.dumps(signed).encode())
+
+
+def test_electra_layout() -> None:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    block = FuluSignedBeaconBlockMainnet.from_ssz(ssz)
+    electra = ElectraSignedBeaconBlockMainnet.from_ssz(ssz)
+
+    assert block.to_ssz() == ssz
+    assert block.signed_header().to_ssz() == electra.signed_header().to_ssz()
+    assert block.payload_stats() == electra.payload_stats()
+
+
+def test_cell_proofs() -> None:
+    block = _minimal_block(commitments=2)
+    proofs = [bytes(48)] * (2 * CELLS_PER_EXT_BLOB)
+
+    contents = block.with_blobs([bytes(BLOB_SIZE)] * 2, proofs)
+    assert len(contents.blobs()) == 2
+    assert len(contents.kzg_proofs()) == 2 * CELLS_PER_EXT_BLOB
+    assert contents.strip_blobs().to_ssz() == block.to_ssz()
+
+    with pytest.raises(ValueError, match="Expected 256 cell proofs"):
+        block.with_blobs([bytes(BLOB_SIZE)] * 2, [bytes(48)] * 2)
+
+
+def test_verify_cell_proofs() -> None:
+    block = _minimal_block(commitments=1)
+    infinity = b"\xc0" + bytes(47)
+    proofs = [infinity] * CELLS_PER_EXT_BLOB
+
+    assert block.with_blobs([bytes(BLOB_SIZE)], proofs).verify_blob_kzg_proofs()
+    nonzero_blob = bytes(31) + b"\x01" + bytes(BLOB_SIZE - 32)
+    contents = block.with_blobs([nonzero_blob], proofs)
+    assert not contents.verify_blob_kzg_proofs()
+
+    with pytest.raises(ValueError, match="KZG proof verification failed"):
+        block.with_blobs(
+            [bytes(BLOB_SIZE)], [bytes(48)] * CELLS_PER_EXT_BLOB
+        ).verify_blob_kzg_proofs()
+
+    assert block.with_blobs([bytes(BLOB_SIZE)], proofs, verify=True)
+    with pytest.raises(ValueError, match="KZG proofs do not verify"):
+        block.with_blobs([nonzero_blob], proofs, verify=True)
+
+
+def test_blind_and_unblind() -> None:
+    block = _minimal_block(commitments=1)
+    payload = block.to_dict()["message"]["body"]["execution_payload"]
+    payload_ssz = ElectraExecutionPayloadMinimal.from_dict(payload).to_ssz()
+    blinded = block.blind()
+    assert blinded.signed_header().to_ssz() == block.signed_header().to_ssz()
+
+    commitment = b"\xc0" + bytes(47)
+    proofs = commitment * CELLS_PER_EXT_BLOB
+    offsets = struct.pack("<III", 12, 12 + 48, 12 + 48 + len(proofs))
+    bundle = offsets + commitment + proofs + bytes(BLOB_SIZE)
+
+    contents = blinded.unblind(payload_ssz, bundle)
+    assert contents.strip_blobs().to_ssz() == block.to_ssz()
+    assert len(contents.kzg_proofs()) == CELLS_PER_EXT_BLOB
+
+    with pytest.raises(ValueError, match="128 cell proofs"):
+        short = struct.pack("<III", 12, 12 + 48, 12 + 96)
+        blinded.unblind(payload_ssz, short + commitment * 2 + bytes(BLOB_SIZE))
+
+
+def test_contents_bounds() -> None:
+    electra = ElectraBeaconBlockContentsMainnet
+    assert FuluBeaconBlockContentsMainnet.ssz_min_size() == electra.ssz_min_size()
+    assert FuluBeaconBlockContentsMainnet.ssz_max_size() > electra.ssz_max_size()
+
+
+def test_blinded_block() -> None:
+    signed = (FIXTURES / "minimal-signed-blinded-block.json").read_bytes()
+    block = FuluSignedBlindedBeaconBlockMinimal.from_json(signed)
+
+    slot = int(json.loads(signed)["data"]["message"]["slot"])
+    assert block.signed_header().slot() == slot
+
+
+def test_mutable_blinded_block() -> None:
+    message = json.loads((FIXTURES / "minimal-signed-blinded-block.json").read_text())[
+        "data"
+    ]["message"]
+    block = FuluBlindedBeaconBlockMinimal.from_json(json.dumps({"data": message}).encode())
+
+    mutable = block.clone_mut()
+    mutable.set_state_root("0x" + "33" * 32)
+    mutable.set_graffiti(b"\x44" * 32)
+    edited = json.loads(mutable.freeze().to_json())
+
+    assert edited["state_root"] == "0x" + "33" * 32
+    assert edited["body"]["graffiti"] == "0x" + "44" * 32
+    assert json.loads(block.to_json()) == message
@@ -0,0 +1,61 @@
+import json
+from pathlib import Path
+
+from grandine_py import (
+    ElectraExecutionPayloadGnosis,
+    ElectraSignedBeaconBlockGnosis,
+    gwei_to_gno_wei,
+    set_hex_format,
+)
+
+FIXTURES = Path(__file__).parent / "fixtures"
+
+
+def test_gwei_to_gno_wei() -> None:
+    # A full 32 mGNO validator balance is worth 1 GNO.
+    assert gwei_to_gno_wei(32 * 10**9) == 10**18
+    assert gwei_to_gno_wei(1) == 10**9 // 32
+    assert gwei_to_gno_wei(0) == 0
+    assert gwei_to_gno_wei(2**64 - 1) == (2**64 - 1) * 10**9 // 32
+
+
+def test_gno_withdrawals() -> None:
+    block = ElectraSignedBeaconBlockGnosis.from_ssz(
+        (FIXTURES / "gnosis-26539000.ssz").read_bytes()
+    )
+    block_json = json.loads((FIXTURES / "gnosis-26539000.json").read_text())
+    payload_json = block_json["data"]["message"]["body"]["execution_payload"]
+    expected = payload_json["withdrawals"]
+
+    withdrawals = block.gno_withdrawals()
+
+    assert len(withdrawals) == len(expected) == 8
+    assert withdrawals[0] == {
+        "index": 124415767,
+        "validator_index": 282856,
+        "address": "0x9009b4411d0e1171cc042b77d7701f46b737fdb9",
+        "amount": 6380293,
+        "amount_gno_wei": 6380293 * 31_250_000,
+    }
+    for withdrawal, raw in zip(withdrawals, expected):
+        assert withdrawal["amount"] == int(raw["amount"])
+        assert withdrawal["amount_gno_wei"] == gwei_to_gno_wei(int(raw["amount"]))
+
+    payload = ElectraExecutionPayloadGnosis.from_json(
+        json.dumps({"data": payload_json}).encode()
+    )
+    assert payload.gno_withdrawals() == withdrawals
+
+
+def test_gno_withdrawals_follow_the_hex_format() -> None:
+    block = ElectraSignedBeaconBlockGnosis.from_ssz(
+        (FIXTURES / "gnosis-26539000.ssz").read_bytes()
+    )
+
+    set_hex_format(uppercase=True, prefix=False)
+    try:
+        address = block.gno_withdrawals()[0]["address"]
+    finally:
+        set_hex_format()
+
+    assert address == "9009B4411D0E1171CC042B77D7701F46B737FDB9"
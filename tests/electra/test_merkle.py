@@ -0,0 +1,120 @@
+import hashlib
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraBeaconStateMinimal,
+    ElectraSignedBeaconBlockMainnet,
+    verify_merkle_proof,
+)
+
+FIXTURES = Path(__file__).parent / "fixtures"
+
+
+def _hash(left: bytes, right: bytes) -> bytes:
+    return hashlib.sha256(left + right).digest()
+
+
+def _merkleize(chunks: list[bytes]) -> bytes:
+    while len(chunks) > 1:
+        chunks = [_hash(*chunks[i : i + 2]) for i in range(0, len(chunks), 2)]
+    return chunks[0]
+
+
+def _uint64(value: str) -> bytes:
+    return int(value).to_bytes(8, "little").ljust(32, b"\x00")
+
+
+def _validator_root(validator: dict) -> bytes:
+    pubkey = bytes.fromhex(validator["pubkey"][2:]).ljust(64, b"\x00")
+    return _merkleize(
+        [
+            _hash(pubkey[:32], pubkey[32:]),
+            bytes.fromhex(validator["withdrawal_credentials"][2:]),
+            _uint64(validator["effective_balance"]),
+            bytes([validator["slashed"]]).ljust(32, b"\x00"),
+            _uint64(validator["activation_eligibility_epoch"]),
+            _uint64(validator["activation_epoch"]),
+            _uint64(validator["exit_epoch"]),
+            _uint64(validator["withdrawable_epoch"]),
+        ]
+    )
+
+
+def test_prove_block() -> None:
+    block_json = (FIXTURES / "mainnet-13689000.json").read_bytes()
+    block = ElectraSignedBeaconBlockMainnet.from_json(block_json)
+    message = json.loads(block_json)["data"]["message"]
+    root = block.signed_header().block_root()
+
+    # Field 4 of the block, 9 of the body and 12 of the payload.
+    path = ["body", "execution_payload", "block_hash"]
+    assert block.generalized_index(path) == ((8 + 4) * 16 + 9) * 32 + 12
+    branch = block.prove_path(path)
+    assert len(branch) == 3 + 4 + 5
+    assert all(len(node) == 32 for node in branch)
+    assert branch == block.prove(6444)
+
+    block_hash = message["body"]["execution_payload"]["block_hash"]
+    assert verify_merkle_proof(block_hash, branch, 6444, root)
+    assert verify_merkle_proof(bytes.fromhex(block_hash[2:]), branch, 6444, root)
+    assert not verify_merkle_proof(bytes(32), branch, 6444, root)
+    assert not verify_merkle_proof(block_hash, branch, 6445, root)
+
+    state_root = message["state_root"]
+    assert verify_merkle_proof(state_root, block.prove(11), 11, root)
+    assert block.prove(1) == []
+
+
+def test_prove_state(minimal_state_json: dict) -> None:
+    state = ElectraBeaconStateMinimal.from_json(
+        json.dumps({"data": minimal_state_json}).encode()
+    )
+    root = state.hash_tree_root()
+
+    # 37 fields, so a depth of 6, with the checkpoint's root at index 1.
+    path = ["finalized_checkpoint", "root"]
+    assert state.generalized_index(path) == (64 + 20) * 2 + 1
+    checkpoint_root = minimal_state_json["finalized_checkpoint"]["root"]
+    assert verify_merkle_proof(checkpoint_root, state.prove_path(path), 169, root)
+
+    # A vector of 64 mixes: no length is mixed in.
+    mix = minimal_state_json["randao_mixes"][3]
+    index = state.generalized_index(["randao_mixes", 3])
+    assert index == (64 + 13) * 64 + 3
+    assert verify_merkle_proof(mix, state.prove(index), index, root)
+
+    # A list of up to 2**40 validators, below its length mix-in.
+    validator = _validator_root(minimal_state_json["validators"][2])
+    index = state.generalized_index(["validators", 2])
+    assert index == (64 + 11) * 2 * 2**40 + 2
+    branch = state.prove_path(["validators", 2])
+    assert len(branch) == 6 + 1 + 40
+    assert verify_merkle_proof(validator, branch, index, root)
+
+    length = (12).to_bytes(32, "little")
+    index = (64 + 11) * 2 + 1
+    assert verify_merkle_proof(length, state.prove(index), index, root)
+
+
+def test_errors(minimal_state_json: dict) -> None:
+    state = ElectraBeaconStateMinimal.from_json(
+        json.dumps({"data": minimal_state_json}).encode()
+    )
+
+    with pytest.raises(ValueError, match="at least 1"):
+        state.prove(0)
+    with pytest.raises(ValueError, match="Unknown field 'nope'"):
+        state.prove_path(["nope"])
+    with pytest.raises(ValueError, match="Expected an element index"):
+        state.prove_path(["validators", "pubkey"])
+    with pytest.raises(ValueError, match="out of range"):
+        state.generalized_index(["randao_mixes", 64])
+    with pytest.raises(ValueError, match="below a leaf"):
+        state.prove_path(["balances", 0])
+    with pytest.raises(ValueError, match="below a list element"):
+        state.prove(((64 + 11) * 2 * 2**40 + 2) * 2)
+    with pytest.raises(ValueError, match="Expected a branch of 3 nodes"):
+        verify_merkle_proof(bytes(32), [bytes(32)], 8, bytes(32))
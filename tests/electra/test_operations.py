@@ -0,0 +1,177 @@
+import hashlib
+import json
+from pathlib import Path
+from typing import Any
+
+from grandine_py import (
+    BLSToExecutionChange,
+    Deposit,
+    DepositData,
+    ElectraAttesterSlashingMainnet,
+    ElectraIndexedAttestationMainnet,
+    ElectraSignedBeaconBlockMainnet,
+    ProposerSlashing,
+    SignedBLSToExecutionChange,
+    SignedVoluntaryExit,
+    VoluntaryExit,
+)
+
+FIXTURES = Path(__file__).parent / "fixtures"
+SIGNATURE = "0x" + "ab" * 96
+PUBKEY = "0x" + "cd" * 48
+
+
+def _hash(left: bytes, right: bytes) -> bytes:
+    return hashlib.sha256(left + right).digest()
+
+
+def _signed_header(slot: str, body_root: str) -> dict:
+    return {
+        "message": {
+            "slot": slot,
+            "proposer_index": "7",
+            "parent_root": "0x" + "01" * 32,
+            "state_root": "0x" + "02" * 32,
+            "body_root": body_root,
+        },
+        "signature": SIGNATURE,
+    }
+
+
+def _indexed_attestation(data: dict, indices: list[int]) -> dict:
+    return {
+        "attesting_indices": [str(index) for index in indices],
+        "data": data,
+        "signature": SIGNATURE,
+    }
+
+
+def _block() -> ElectraSignedBeaconBlockMainnet:
+    block = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    body = block["data"]["message"]["body"]
+    data = body["attestations"][0]["data"]
+
+    body["proposer_slashings"] = [
+        {
+            "signed_header_1": _signed_header("100", "0x" + "03" * 32),
+            "signed_header_2": _signed_header("100", "0x" + "04" * 32),
+        }
+    ]
+    body["attester_slashings"] = [
+        {
+            "attestation_1": _indexed_attestation(data, [1, 2, 5]),
+            "attestation_2": _indexed_attestation(
+                data | {"beacon_block_root": "0x" + "05" * 32}, [2, 5, 7]
+            ),
+        }
+    ]
+    body["deposits"] = [
+        {
+            "proof": ["0x" + f"{index:02x}" * 32 for index in range(33)],
+            "data": {
+                "pubkey": PUBKEY,
+                "withdrawal_credentials": "0x" + "06" * 32,
+                "amount": "32000000000",
+                "signature": SIGNATURE,
+            },
+        }
+    ]
+    body["voluntary_exits"] = [
+        {"message": {"epoch": "12", "validator_index": "34"}, "signature": SIGNATURE}
+    ]
+    body["bls_to_execution_changes"] = [
+        {
+            "message": {
+                "validator_index": "56",
+                "from_bls_pubkey": PUBKEY,
+                "to_execution_address": "0x" + "07" * 20,
+            },
+            "signature": SIGNATURE,
+        }
+    ]
+    return ElectraSignedBeaconBlockMainnet.from_json(json.dumps(block).encode())
+
+
+def _operations(block: Any) -> list[Any]:
+    return [
+        block.proposer_slashings(),
+        block.attester_slashings(),
+        block.deposits(),
+        block.voluntary_exits(),
+        block.bls_to_execution_changes(),
+    ]
+
+
+def test_block_operations() -> None:
+    block = _block()
+
+    for source in (block, block.blind()):
+        assert [len(operations) for operations in _operations(source)] == [1] * 5
+        assert [type(operations[0]) for operations in _operations(source)] == [
+            ProposerSlashing,
+            ElectraAttesterSlashingMainnet,
+            Deposit,
+            SignedVoluntaryExit,
+            SignedBLSToExecutionChange,
+        ]
+
+    proposer_slashing = block.proposer_slashings()[0]
+    assert proposer_slashing.signed_header_1().slot() == 100
+    assert proposer_slashing.signed_header_2().block_root() != (
+        proposer_slashing.signed_header_1().block_root()
+    )
+
+    attester_slashing = block.attester_slashings()[0]
+    attestation_1 = attester_slashing.attestation_1()
+    assert isinstance(attestation_1, ElectraIndexedAttestationMainnet)
+    assert attestation_1.attesting_indices() == [1, 2, 5]
+    assert attester_slashing.attestation_2().attesting_indices() == [2, 5, 7]
+    assert attester_slashing.slashable_indices() == [2, 5]
+
+    deposit = block.deposits()[0]
+    assert isinstance(deposit.data(), DepositData)
+    assert deposit.data().amount() == 32 * 10**9
+    assert deposit.proof()[32] == "0x" + "20" * 32
+    assert len(deposit.proof()) == 33
+
+    voluntary_exit = block.voluntary_exits()[0]
+    message = voluntary_exit.message()
+    assert isinstance(message, VoluntaryExit)
+    assert (message.epoch(), message.validator_index()) == (12, 34)
+    assert voluntary_exit.signature() == SIGNATURE
+
+    change = block.bls_to_execution_changes()[0]
+    assert isinstance(change.message(), BLSToExecutionChange)
+    assert change.message().validator_index() == 56
+    assert change.message().from_bls_pubkey() == PUBKEY
+    assert change.message().to_execution_address() == "0x" + "07" * 20
+
+
+def test_operation_roots() -> None:
+    block = _block()
+    voluntary_exit = block.voluntary_exits()[0]
+
+    message_root = _hash((12).to_bytes(32, "little"), (34).to_bytes(32, "little"))
+    signature = bytes.fromhex(SIGNATURE[2:]).ljust(128, b"\x00")
+    signature_root = _hash(
+        _hash(signature[:32], signature[32:64]), _hash(signature[64:96], signature[96:])
+    )
+    root = _hash(message_root, signature_root)
+    assert voluntary_exit.message().hash_tree_root() == "0x" + message_root.hex()
+    assert voluntary_exit.hash_tree_root() == "0x" + root.hex()
+
+    operations = [
+        block.proposer_slashings()[0],
+        block.attester_slashings()[0],
+        block.attester_slashings()[0].attestation_1(),
+        block.deposits()[0],
+        block.bls_to_execution_changes()[0],
+        block.bls_to_execution_changes()[0].message(),
+        block.attestations()[0],
+    ]
+    for operation in operations:
+        cls = type(operation)
+        decoded = cls.from_ssz(operation.to_ssz())
+        assert decoded.hash_tree_root() == operation.hash_tree_root()
+        ssz = operation.to_ssz()
+        assert cls.hash_tree_root_of_ssz(ssz) == operation.hash_tree_root()
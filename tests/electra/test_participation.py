@@ -0,0 +1,90 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraAttestationMainnet,
+    classify_attestation,
+    classify_attestations,
+)
+
+FIXTURES = Path(__file__).parent / "fixtures"
+OTHER_ROOT = "0x" + "11" * 32
+
+
+def _attestation() -> tuple[ElectraAttestationMainnet, dict]:
+    block = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    attestation_json = block["data"]["message"]["body"]["attestations"][0]
+    attestation = ElectraAttestationMainnet.from_json(
+        json.dumps({"data": attestation_json}).encode()
+    )
+    return attestation, attestation_json["data"]
+
+
+def _canonical_chain(data: dict) -> dict[int, str]:
+    return {
+        int(data["source"]["epoch"]) * 32: data["source"]["root"],
+        int(data["target"]["epoch"]) * 32: data["target"]["root"],
+        int(data["slot"]): data["beacon_block_root"],
+    }
+
+
+def test_classify_correct_attestation() -> None:
+    attestation, data = _attestation()
+    slot = int(data["slot"])
+
+    result = classify_attestation(
+        attestation, _canonical_chain(data), inclusion_slot=slot + 1
+    )
+
+    assert result == {
+        "source": True,
+        "target": True,
+        "head": True,
+        "inclusion_delay": 1,
+        "timely_source": True,
+        "timely_target": True,
+        "timely_head": True,
+    }
+
+    late = classify_attestation(
+        attestation, _canonical_chain(data), inclusion_slot=slot + 6
+    )
+    assert (late["timely_source"], late["timely_target"], late["timely_head"]) == (
+        False,
+        True,
+        False,
+    )
+
+
+def test_classify_wrong_head_and_callable_provider() -> None:
+    attestation, data = _attestation()
+    chain = {**_canonical_chain(data), int(data["slot"]): OTHER_ROOT}
+    calls = []
+
+    def provider(slot: int) -> str | None:
+        calls.append(slot)
+        return chain.get(slot)
+
+    results = classify_attestations([attestation, attestation], provider)
+
+    assert [r["head"] for r in results] == [False, False]
+    assert all(r["source"] and r["target"] for r in results)
+    assert results[0]["timely_head"] is None
+    assert len(calls) == len(set(calls))
+
+
+def test_classify_unknown_roots_are_incorrect() -> None:
+    attestation, _ = _attestation()
+
+    result = classify_attestation(attestation, {})
+
+    assert (result["source"], result["target"], result["head"]) == (False, False, False)
+
+
+def test_classify_rejects_bad_provider() -> None:
+    attestation, _ = _attestation()
+
+    with pytest.raises(TypeError):
+        classify_attestation(attestation, 42)
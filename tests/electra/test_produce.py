@@ -0,0 +1,123 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraBeaconBlockContentsMainnet,
+    ElectraBlindedBeaconBlockMainnet,
+    ElectraBlindedBeaconBlockMinimal,
+    set_decode_limits,
+)
+
+FIXTURES = Path(__file__).parent / "fixtures"
+
+
+def _blinded_block() -> dict:
+    block = json.loads((FIXTURES / "minimal-signed-blinded-block.json").read_text())
+    return block["data"]["message"]
+
+
+def _block_contents() -> dict:
+    block = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    return {"block": block["data"]["message"], "kzg_proofs": [], "blobs": []}
+
+
+def test_from_produce_block_v3_blinded() -> None:
+    response = {
+        "version": "electra",
+        "execution_payload_blinded": True,
+        "execution_payload_value": "123456789012345678901234567890",
+        "consensus_block_value": "42",
+        "data": _blinded_block(),
+    }
+
+    produced = ElectraBlindedBeaconBlockMinimal.from_produce_block_v3(
+        json.dumps(response).encode()
+    )
+
+    assert produced.version == "electra"
+    assert produced.execution_payload_blinded is True
+    assert produced.execution_payload_value == 123456789012345678901234567890
+    assert produced.consensus_block_value == 42
+    assert isinstance(produced.block, ElectraBlindedBeaconBlockMinimal)
+    assert json.loads(produced.block.to_json()) == _blinded_block()
+
+
+def test_from_produce_block_v3_full_contents_with_headers() -> None:
+    response = {"data": _block_contents()}
+    headers = {
+        "Eth-Consensus-Version": "electra",
+        "Eth-Execution-Payload-Blinded": "false",
+        "Eth-Execution-Payload-Value": "7",
+    }
+
+    produced = ElectraBlindedBeaconBlockMainnet.from_produce_block_v3(
+        json.dumps(response).encode(), headers
+    )
+
+    assert produced.execution_payload_blinded is False
+    assert produced.execution_payload_value == 7
+    assert produced.consensus_block_value is None
+    assert isinstance(produced.block, ElectraBeaconBlockContentsMainnet)
+    assert json.loads(produced.block.to_json()) == _block_contents()
+
+
+def test_from_produce_block_v3_body_takes_precedence_over_headers() -> None:
+    response = {
+        "version": "electra",
+        "execution_payload_blinded": True,
+        "data": _blinded_block(),
+    }
+
+    produced = ElectraBlindedBeaconBlockMinimal.from_produce_block_v3(
+        json.dumps(response).encode(), {"eth-execution-payload-blinded": "false"}
+    )
+
+    assert produced.execution_payload_blinded is True
+
+
+@pytest.mark.parametrize(
+    ("response", "message"),
+    [
+        ({"execution_payload_blinded": True}, "Missing fork version"),
+        ({"version": "deneb", "execution_payload_blinded": True}, "Unsupported fork"),
+        ({"version": "electra"}, "Missing execution_payload_blinded"),
+        ({"version": "electra", "execution_payload_blinded": False}, "block contents"),
+    ],
+)
+def test_from_produce_block_v3_rejects_invalid_responses(
+    response: dict, message: str
+) -> None:
+    body = json.dumps({**response, "data": _blinded_block()}).encode()
+
+    with pytest.raises(ValueError, match=message):
+        ElectraBlindedBeaconBlockMinimal.from_produce_block_v3(body)
+
+
+def test_from_produce_block_v3_checks_the_block_against_the_flag() -> None:
+    response = {"version": "electra", "data": _block_contents()}
+    body = json.dumps(response).encode()
+
+    with pytest.raises(ValueError, match="Expected a blinded block"):
+        ElectraBlindedBeaconBlockMainnet.from_produce_block_v3(
+            body, {"eth-execution-payload-blinded": "true"}
+        )
+
+    with pytest.raises(ValueError, match="did not match"):
+        ElectraBlindedBeaconBlockMainnet.from_produce_block_v3(
+            json.dumps({**response, "data": {"slot": "1"}}).encode()
+        )
+
+
+def test_from_produce_block_v3_applies_decode_limits() -> None:
+    body = json.dumps({"version": "electra", "data": _block_contents()}).encode()
+
+    set_decode_limits(max_input_size=len(body) - 1)
+    try:
+        with pytest.raises(ValueError, match="decode limit"):
+            ElectraBlindedBeaconBlockMainnet.from_produce_block_v3(
+                body, {"eth-execution-payload-blinded": "false"}
+            )
+    finally:
+        set_decode_limits()
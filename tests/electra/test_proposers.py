@@ -0,0 +1,231 @@
+import hashlib
+import json
+
+import pytest
+
+from grandine_py import (
+    Config,
+    ElectraBeaconStateMinimal,
+    FuluBeaconStateMinimal,
+    next_epoch_proposers,
+    verify_proposer_schedule,
+)
+
+# Minimal preset values used by the reference implementation below.
+SLOTS_PER_EPOCH = 8
+EPOCHS_PER_HISTORICAL_VECTOR = 64
+MIN_SEED_LOOKAHEAD = 1
+SHUFFLE_ROUND_COUNT = 10
+MAX_EFFECTIVE_BALANCE_ELECTRA = 2048 * 10**9
+
+
+def _sha256(data: bytes) -> bytes:
+    return hashlib.sha256(data).digest()
+
+
+def _shuffled_index(index: int, count: int, seed: bytes) -> int:
+    for round_ in range(SHUFFLE_ROUND_COUNT):
+        pivot = int.from_bytes(_sha256(seed + bytes([round_]))[:8], "little") % count
+        flip = (pivot + count - index) % count
+        position = max(index, flip)
+        source = _sha256(
+            seed + bytes([round_]) + (position // 256).to_bytes(4, "little")
+        )
+        if (source[(position % 256) // 8] >> (position % 8)) % 2:
+            index = flip
+    return index
+
+
+def _proposer_index(active: list[int], balances: list[int], seed: bytes) -> int:
+    i = 0
+    while True:
+        candidate = active[_shuffled_index(i % len(active), len(active), seed)]
+        random_bytes = _sha256(seed + (i // 16).to_bytes(8, "little"))
+        offset = i % 16 * 2
+        random_value = int.from_bytes(random_bytes[offset : offset + 2], "little")
+        if balances[candidate] * 0xFFFF >= MAX_EFFECTIVE_BALANCE_ELECTRA * random_value:
+            return candidate
+        i += 1
+
+
+def _reference_proposers(state: dict) -> list[int]:
+    epoch = int(state["slot"]) // SLOTS_PER_EPOCH + 1
+    validators = state["validators"]
+    active = [
+        index
+        for index, validator in enumerate(validators)
+        if int(validator["activation_epoch"]) <= epoch < int(validator["exit_epoch"])
+    ]
+    balances = [int(validator["effective_balance"]) for validator in validators]
+    mix_index = (
+        epoch + EPOCHS_PER_HISTORICAL_VECTOR - MIN_SEED_LOOKAHEAD - 1
+    ) % EPOCHS_PER_HISTORICAL_VECTOR
+    mix = bytes.fromhex(state["randao_mixes"][mix_index][2:])
+    epoch_seed = _sha256(bytes(4) + epoch.to_bytes(8, "little") + mix)
+
+    return [
+        _proposer_index(
+            active, balances, _sha256(epoch_seed + slot.to_bytes(8, "little"))
+        )
+        for slot in range(epoch * SLOTS_PER_EPOCH, (epoch + 1) * SLOTS_PER_EPOCH)
+    ]
+
+
+def _decode(cls, state_json: dict):
+    return cls.from_json(json.dumps({"data": state_json}).encode())
+
+
+def test_electra_next_epoch_proposers_match_spec(minimal_state_json: dict) -> None:
+    state_json = minimal_state_json
+    state = _decode(ElectraBeaconStateMinimal, state_json)
+
+    proposers = next_epoch_proposers(state)
+
+    assert len(proposers) == SLOTS_PER_EPOCH
+    assert proposers == _reference_proposers(state_json)
+    assert 1 not in proposers
+
+
+def test_electra_next_epoch_proposers_accepts_matching_config(
+    minimal_state_json: dict,
+) -> None:
+    state = _decode(ElectraBeaconStateMinimal, minimal_state_json)
+
+    assert next_epoch_proposers(state, Config.minimal()) == next_epoch_proposers(state)
+
+    with pytest.raises(ValueError):
+        next_epoch_proposers(state, Config.mainnet())
+
+
+def test_electra_state_ssz_round_trip(minimal_state_json: dict) -> None:
+    state = _decode(ElectraBeaconStateMinimal, minimal_state_json)
+
+    decoded = ElectraBeaconStateMinimal.from_ssz(state.to_ssz())
+
+    assert next_epoch_proposers(decoded) == next_epoch_proposers(state)
+
+
+def test_fulu_next_epoch_proposers_use_lookahead(minimal_state_json: dict) -> None:
+    lookahead = list(range(2 * SLOTS_PER_EPOCH))
+    state_json = minimal_state_json | {
+        "fork": {
+            "previous_version": "0x05000001",
+            "current_version": "0x06000001",
+            "epoch": "0",
+        },
+        "proposer_lookahead": [str(index % 12) for index in lookahead],
+    }
+    state = _decode(FuluBeaconStateMinimal, state_json)
+
+    assert state.proposer_lookahead() == [index % 12 for index in lookahead]
+    assert next_epoch_proposers(state) == [
+        index % 12 for index in lookahead[SLOTS_PER_EPOCH:]
+    ]
+
+
+def test_next_epoch_proposers_rejects_other_objects() -> None:
+    with pytest.raises(TypeError, match="Expected a beacon state"):
+        next_epoch_proposers(object())
+
+
+def _duties(epoch: int, proposers: list[int]) -> bytes:
+    return json.dumps(
+        {
+            "dependent_root": "0x" + "00" * 32,
+            "execution_optimistic": False,
+            "data": [
+                {
+                    "pubkey": "0x" + f"{index:02x}" * 48,
+                    "validator_index": str(index),
+                    "slot": str(epoch * SLOTS_PER_EPOCH + offset),
+                }
+                for offset, index in enumerate(proposers)
+            ],
+        }
+    ).encode()
+
+
+def test_verify_proposer_schedule_accepts_correct_duties(
+    minimal_state_json: dict,
+) -> None:
+    state = _decode(ElectraBeaconStateMinimal, minimal_state_json)
+    proposers = next_epoch_proposers(state)
+
+    assert verify_proposer_schedule(state, 3, _duties(3, proposers)) == []
+
+
+def test_verify_proposer_schedule_reports_discrepancies(
+    minimal_state_json: dict,
+) -> None:
+    state = _decode(ElectraBeaconStateMinimal, minimal_state_json)
+    proposers = next_epoch_proposers(state)
+    duties = json.loads(_duties(3, proposers))
+    wrong = (proposers[0] + 1) % 12 or 2
+    duties["data"][0] |= {
+        "validator_index": str(wrong),
+        "pubkey": "0x" + f"{wrong:02x}" * 48,
+    }
+    duties["data"][1]["pubkey"] = "0x" + "ff" * 48
+    duties["data"][2]["validator_index"] = "1000"
+    duties["data"][3]["slot"] = "100"
+    del duties["data"][4]
+
+    result = verify_proposer_schedule(state, 3, json.dumps(duties).encode())
+
+    assert result == [
+        {
+            "slot": 24,
+            "kind": "wrong_proposer",
+            "expected_validator_index": proposers[0],
+            "claimed_validator_index": wrong,
+        },
+        {
+            "slot": 25,
+            "kind": "wrong_pubkey",
+            "expected_validator_index": proposers[1],
+            "claimed_validator_index": proposers[1],
+        },
+        {
+            "slot": 26,
+            "kind": "wrong_proposer",
+            "expected_validator_index": proposers[2],
+            "claimed_validator_index": 1000,
+        },
+        {
+            "slot": 26,
+            "kind": "unknown_validator",
+            "expected_validator_index": proposers[2],
+            "claimed_validator_index": 1000,
+        },
+        {
+            "slot": 27,
+            "kind": "missing",
+            "expected_validator_index": proposers[3],
+            "claimed_validator_index": None,
+        },
+        {
+            "slot": 28,
+            "kind": "missing",
+            "expected_validator_index": proposers[4],
+            "claimed_validator_index": None,
+        },
+        {
+            "slot": 100,
+            "kind": "wrong_epoch",
+            "expected_validator_index": None,
+            "claimed_validator_index": proposers[3],
+        },
+    ]
+
+
+def test_verify_proposer_schedule_rejects_unknown_epochs(
+    minimal_state_json: dict,
+) -> None:
+    state = _decode(ElectraBeaconStateMinimal, minimal_state_json)
+
+    with pytest.raises(ValueError, match="cannot be determined"):
+        verify_proposer_schedule(state, 4, _duties(4, []))
+    with pytest.raises(ValueError, match="cannot be determined"):
+        verify_proposer_schedule(state, 1, _duties(1, []))
+    with pytest.raises(ValueError):
+        verify_proposer_schedule(state, 3, b'{"data": [{"slot": "24"}]}')
@@ -0,0 +1,85 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import ElectraBeaconStateMinimal, PubkeyIndexMap
+
+
+def _decode(state_json: dict) -> ElectraBeaconStateMinimal:
+    return ElectraBeaconStateMinimal.from_json(json.dumps({"data": state_json}).encode())
+
+
+def _pubkey(index: int) -> str:
+    return "0x" + f"{index:02x}" * 48
+
+
+def test_from_state_maps_every_validator(minimal_state_json: dict) -> None:
+    pubkeys = PubkeyIndexMap.from_state(_decode(minimal_state_json))
+
+    assert len(pubkeys) == 12
+    assert pubkeys.index_of(_pubkey(7)) == 7
+    assert pubkeys.index_of(bytes.fromhex(_pubkey(3)[2:])) == 3
+    assert pubkeys.index_of(_pubkey(0xEE)) is None
+    assert _pubkey(11) in pubkeys
+    assert pubkeys.pubkey_at(5) == _pubkey(5)
+
+    with pytest.raises(IndexError):
+        pubkeys.pubkey_at(12)
+    with pytest.raises(ValueError):
+        pubkeys.index_of(b"short")
+
+
+def test_update_adds_new_validators(minimal_state_json: dict) -> None:
+    pubkeys = PubkeyIndexMap.from_state(_decode(minimal_state_json))
+
+    later_json = json.loads(json.dumps(minimal_state_json))
+    later_json["validators"].append(
+        dict(later_json["validators"][0], pubkey=_pubkey(0x20))
+    )
+    for field in (
+        "balances",
+        "previous_epoch_participation",
+        "current_epoch_participation",
+        "inactivity_scores",
+    ):
+        later_json[field].append("0")
+
+    assert pubkeys.update(_decode(later_json)) == 1
+    assert pubkeys.index_of(_pubkey(0x20)) == 12
+    assert pubkeys.update(_decode(later_json)) == 0
+
+    with pytest.raises(ValueError, match="already has 13"):
+        pubkeys.update(_decode(minimal_state_json))
+
+
+def test_add_assigns_next_index_to_new_pubkeys() -> None:
+    pubkeys = PubkeyIndexMap()
+
+    assert pubkeys.add(_pubkey(1)) == 0
+    assert pubkeys.add(_pubkey(2)) == 1
+    # A top-up deposit keeps the existing index.
+    assert pubkeys.add(_pubkey(1)) == 0
+    assert len(pubkeys) == 2
+
+
+def test_save_and_load_round_trip(tmp_path: Path, minimal_state_json: dict) -> None:
+    pubkeys = PubkeyIndexMap.from_state(_decode(minimal_state_json))
+    path = tmp_path / "pubkeys.bin"
+
+    pubkeys.save(path)
+    loaded = PubkeyIndexMap.load(path)
+
+    assert loaded.to_bytes() == pubkeys.to_bytes()
+    assert loaded.index_of(_pubkey(9)) == 9
+    assert PubkeyIndexMap.from_bytes(pubkeys.to_bytes()).to_bytes() == pubkeys.to_bytes()
+
+    with pytest.raises(ValueError, match="Not a PubkeyIndexMap"):
+        PubkeyIndexMap.from_bytes(b"nope")
+    with pytest.raises(ValueError, match="Expected 12 pubkeys"):
+        PubkeyIndexMap.from_bytes(pubkeys.to_bytes()[:-1])
+
+
+def test_from_state_rejects_other_objects() -> None:
+    with pytest.raises(TypeError, match="Expected a beacon state"):
+        PubkeyIndexMap.from_state(object())
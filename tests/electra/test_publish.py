@@ -0,0 +1,133 @@
+import hashlib
+import json
+from pathlib import Path
+from typing import Any
+
+import pytest
+
+from grandine_py import (
+    ElectraBeaconStateMinimal,
+    ElectraSignedBeaconBlockMinimal,
+    SignedBeaconBlockHeader,
+    next_epoch_proposers,
+    validate_for_publish,
+)
+
+FIXTURE = Path(__file__).parent / "fixtures/minimal-signed-blinded-block.json"
+SECONDS_PER_SLOT = 6
+SLOT = 24
+
+
+def _sha256(data: bytes) -> bytes:
+    return hashlib.sha256(data).digest()
+
+
+def _header_root(state: ElectraBeaconStateMinimal) -> str:
+    # The state's latest block header is all zeros apart from the state root,
+    # which is filled in with the state's own root.
+    state_root = bytes.fromhex(
+        ElectraBeaconStateMinimal.hash_tree_root_of_ssz(state.to_ssz())[2:]
+    )
+    nodes = [bytes(32)] * 3 + [state_root] + [bytes(32)] * 4
+    while len(nodes) > 1:
+        nodes = [_sha256(nodes[i] + nodes[i + 1]) for i in range(0, len(nodes), 2)]
+    return "0x" + nodes[0].hex()
+
+
+@pytest.fixture
+def state(minimal_state_json: dict) -> ElectraBeaconStateMinimal:
+    return ElectraBeaconStateMinimal.from_json(
+        json.dumps({"data": minimal_state_json}).encode()
+    )
+
+
+def _block(state: ElectraBeaconStateMinimal, **changes: Any) -> Any:
+    signed = json.loads(FIXTURE.read_text())["data"]
+    message = signed["message"]
+    payload = dict(message["body"].pop("execution_payload_header"))
+    del payload["transactions_root"], payload["withdrawals_root"]
+    payload |= {
+        "transactions": [],
+        "withdrawals": [],
+        "parent_hash": "0x" + "00" * 32,
+        # The RANDAO mix of the state's epoch 2.
+        "prev_randao": "0x" + _sha256(bytes([2])).hex(),
+        "timestamp": str(1578009600 + SLOT * SECONDS_PER_SLOT),
+    }
+    message["body"]["execution_payload"] = payload
+    message |= {
+        "slot": str(SLOT),
+        "proposer_index": str(next_epoch_proposers(state)[0]),
+        "parent_root": _header_root(state),
+    } | changes
+    return ElectraSignedBeaconBlockMinimal.from_json(
+        json.dumps({"data": signed}).encode()
+    )
+
+
+def test_gossip_level(state: ElectraBeaconStateMinimal) -> None:
+    # The fixture's signatures are not by the state's validators.
+    assert validate_for_publish(_block(state), state) == ["Invalid proposer signature"]
+
+    wrong_proposer = (next_epoch_proposers(state)[0] + 1) % 12
+    failures = validate_for_publish(
+        _block(state, proposer_index=str(wrong_proposer)), state
+    )
+    assert any("is not the expected proposer" in failure for failure in failures)
+
+
+def test_consensus_level(state: ElectraBeaconStateMinimal) -> None:
+    assert validate_for_publish(_block(state), state, "consensus") == [
+        "Invalid proposer signature",
+        "Invalid RANDAO reveal",
+    ]
+
+    failures = validate_for_publish(
+        _block(state, parent_root="0x" + "11" * 32), state, "consensus"
+    )
+    assert any(failure.startswith("Parent root") for failure in failures)
+
+    # Parent root checks are consensus checks only.
+    assert validate_for_publish(
+        _block(state, parent_root="0x" + "11" * 32), state, "gossip"
+    ) == ["Invalid proposer signature"]
+
+
+def test_equivocation_level(state: ElectraBeaconStateMinimal) -> None:
+    block = _block(state)
+    header = json.loads(block.signed_header().to_json())
+    header["message"]["state_root"] = "0x" + "22" * 32
+    other = SignedBeaconBlockHeader.from_json(json.dumps({"data": header}).encode())
+
+    level = "consensus_and_equivocation"
+    assert validate_for_publish(block, state, level, [block.signed_header()]) == [
+        "Invalid proposer signature",
+        "Invalid RANDAO reveal",
+    ]
+    failures = validate_for_publish(block, state, level, [other])
+    assert failures[-1].startswith(f"Proposer {block.signed_header().proposer_index()}")
+
+
+def test_state_provider_callable(state: ElectraBeaconStateMinimal) -> None:
+    block = _block(state)
+    roots = []
+
+    def provider(root: str) -> ElectraBeaconStateMinimal:
+        roots.append(root)
+        return state
+
+    assert validate_for_publish(block, provider) == ["Invalid proposer signature"]
+    assert roots == [_header_root(state)]
+
+
+def test_rejects_invalid_arguments(state: ElectraBeaconStateMinimal) -> None:
+    block = _block(state)
+
+    with pytest.raises(ValueError, match="Unknown broadcast validation level"):
+        validate_for_publish(block, state, "full")
+    with pytest.raises(TypeError, match="beacon state"):
+        validate_for_publish(block, object())
+    with pytest.raises(TypeError, match="signed block"):
+        validate_for_publish(object(), state)
+    with pytest.raises(ValueError, match="after block slot"):
+        validate_for_publish(_block(state, slot="20"), state)
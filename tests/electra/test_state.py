@@ -0,0 +1,47 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import ElectraBeaconStateMinimal, read_grandine_state_file
+
+
+def test_state_roundtrip(minimal_state_json: dict) -> None:
+    state = ElectraBeaconStateMinimal.from_json(
+        json.dumps({"data": minimal_state_json}).encode()
+    )
+    ssz = state.to_ssz()
+
+    decoded = ElectraBeaconStateMinimal.from_ssz(ssz)
+    assert decoded.to_ssz() == ssz
+    assert json.loads(decoded.to_json()) == json.loads(state.to_json())
+
+
+def test_state_hash_tree_root(minimal_state_json: dict) -> None:
+    state = ElectraBeaconStateMinimal.from_json(
+        json.dumps({"data": minimal_state_json}).encode()
+    )
+
+    root = state.hash_tree_root()
+    assert root == ElectraBeaconStateMinimal.hash_tree_root_of_ssz(state.to_ssz())
+    assert root.startswith("0x") and len(root) == 66
+
+
+def test_read_grandine_state_file(tmp_path: Path, minimal_state_json: dict) -> None:
+    state = ElectraBeaconStateMinimal.from_json(
+        json.dumps({"data": minimal_state_json}).encode()
+    )
+    path = tmp_path / "state.ssz_snappy"
+    path.write_bytes(state.to_ssz_snappy())
+
+    decoded, fork = read_grandine_state_file(path, "minimal")
+    assert (type(decoded), fork) == (ElectraBeaconStateMinimal, "electra")
+    assert decoded.to_ssz() == state.to_ssz()
+
+    schedule = {"electra": 0}
+    decoded, fork = read_grandine_state_file(path, "minimal", fork_schedule=schedule)
+    assert (type(decoded), fork) == (ElectraBeaconStateMinimal, "electra")
+
+    # Fulu states have a field Electra states do not.
+    with pytest.raises(ValueError):
+        read_grandine_state_file(path, "minimal", fork="fulu")
@@ -0,0 +1,26 @@
+import json
+
+import pytest
+
+from grandine_py import ElectraBeaconStateMinimal, withdrawal_addresses
+
+
+def test_withdrawal_addresses(minimal_state_json: dict) -> None:
+    validators = minimal_state_json["validators"]
+    # BLS credentials have no execution address, 0x01 and 0x02 ones do.
+    validators[3]["withdrawal_credentials"] = "0x00" + "33" * 31
+    validators[5]["withdrawal_credentials"] = "0x01" + "00" * 11 + "ab" * 20
+    state = ElectraBeaconStateMinimal.from_json(
+        json.dumps({"data": minimal_state_json}).encode()
+    )
+
+    addresses = withdrawal_addresses(state)
+    assert sorted(addresses) == [i for i in range(len(validators)) if i != 3]
+    assert addresses[0] == "0x" + "00" * 20
+    assert addresses[5] == "0x" + "ab" * 20
+    assert addresses[11] == "0x" + "0b" * 20
+
+
+def test_withdrawal_addresses_type_error() -> None:
+    with pytest.raises(TypeError, match="Expected a beacon state"):
+        withdrawal_addresses(object())
@@ -0,0 +1,120 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    BlsEncodingError,
+    BlsHexError,
+    BlsLengthError,
+    BlsPointError,
+    ElectraBlindedBeaconBlockMinimal,
+    bls_pubkey,
+    bls_sign,
+    validate_pubkey_bytes,
+    validate_signature_bytes,
+)
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+
+PUBKEY = (
+    "0x8c4ed5e24fe5c6ae21018437bde147693f68cda427cd1122cf20819c30eda7ed"
+    "74f72dece09bb313f2a1855595ab677d"
+)
+INFINITY_SIGNATURE = b"\xc0" + b"\x00" * 95
+# A key pair of the consensus spec BLS test vectors.
+SECRET_KEY = "0x263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3"
+SECRET_KEY_PUBKEY = (
+    "0xa491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20f"
+    "d6e10c1b77654d067c0618f6e5a7f79a"
+)
+DOMAIN = "0x00000000" + "ab" * 28
+
+
+def test_validate_accepts_valid_encodings() -> None:
+    validate_pubkey_bytes(PUBKEY)
+    validate_pubkey_bytes(bytes.fromhex(PUBKEY[2:]))
+    validate_signature_bytes(INFINITY_SIGNATURE)
+    validate_signature_bytes("0x" + INFINITY_SIGNATURE.hex())
+
+
+def test_validate_rejects_invalid_hex() -> None:
+    with pytest.raises(BlsHexError, match="Invalid pubkey hex"):
+        validate_pubkey_bytes("0xzz")
+    with pytest.raises(BlsHexError, match="Invalid signature hex"):
+        validate_signature_bytes("0xzz")
+
+
+def test_validate_rejects_wrong_length() -> None:
+    with pytest.raises(BlsLengthError, match="pubkey must be 48 bytes, got 47"):
+        validate_pubkey_bytes(b"\x00" * 47)
+    with pytest.raises(BlsLengthError, match="signature must be 96 bytes, got 48"):
+        validate_signature_bytes("0x" + "00" * 48)
+
+
+def test_validate_rejects_invalid_points() -> None:
+    with pytest.raises(BlsPointError, match="G1"):
+        validate_pubkey_bytes(b"\xff" * 48)
+    with pytest.raises(BlsPointError, match="G2"):
+        validate_signature_bytes(b"\x00" * 96)
+
+
+def test_validate_rejects_other_types() -> None:
+    with pytest.raises(TypeError):
+        validate_pubkey_bytes(42)  # type: ignore[arg-type]
+
+
+def test_errors_are_value_errors() -> None:
+    for error in (BlsHexError, BlsLengthError, BlsPointError):
+        assert issubclass(error, BlsEncodingError)
+    assert issubclass(BlsEncodingError, ValueError)
+
+
+def test_sign_raises_typed_errors() -> None:
+    with open(FIXTURES / "minimal-signed-blinded-block.json", "rb") as f:
+        signed_blinded_block = json.loads(f.read().decode())
+
+    block = ElectraBlindedBeaconBlockMinimal.from_json(
+        json.dumps({"data": signed_blinded_block["data"]["message"]}).encode()
+    )
+
+    with pytest.raises(BlsLengthError):
+        block.sign("0x1234")
+    with pytest.raises(BlsPointError):
+        block.sign("0x" + "00" * 96)
+    with pytest.raises(ValueError):
+        block.sign("0xzz")
+
+    signed = block.sign("0x" + INFINITY_SIGNATURE.hex())
+    signed_json = json.loads(signed.to_json().decode())
+    assert signed_json["signature"] == "0x" + INFINITY_SIGNATURE.hex()
+
+
+def test_bls_sign_and_verify() -> None:
+    with open(FIXTURES / "minimal-signed-blinded-block.json", "rb") as f:
+        signed_blinded_block = json.loads(f.read().decode())
+
+    block = ElectraBlindedBeaconBlockMinimal.from_json(
+        json.dumps({"data": signed_blinded_block["data"]["message"]}).encode()
+    )
+    assert bls_pubkey(SECRET_KEY) == SECRET_KEY_PUBKEY
+
+    signing_root = block.signing_root(DOMAIN)
+    signature = bls_sign(bytes.fromhex(SECRET_KEY[2:]), signing_root)
+    validate_signature_bytes(signature)
+
+    signed = block.sign(signature)
+    assert signed.signing_root(DOMAIN) == signing_root
+    assert signed.verify(SECRET_KEY_PUBKEY, DOMAIN)
+    assert not signed.verify(PUBKEY, DOMAIN)
+    assert not signed.verify(SECRET_KEY_PUBKEY, "0x" + "00" * 32)
+    assert signed.signed_header().verify(SECRET_KEY_PUBKEY, DOMAIN)
+
+
+def test_bls_sign_rejects_invalid_keys() -> None:
+    with pytest.raises(BlsLengthError, match="secret key must be 32 bytes"):
+        bls_sign(b"\x01" * 31, "0x" + "00" * 32)
+    with pytest.raises(ValueError, match="Invalid secret key"):
+        bls_sign(b"\xff" * 32, "0x" + "00" * 32)
+    with pytest.raises(ValueError):
+        bls_sign(SECRET_KEY, "0x1234")
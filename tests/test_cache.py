@@ -0,0 +1,88 @@
+import threading
+
+import pytest
+
+from grandine_py import StateCache
+
+ROOT_A = "0x" + "aa" * 32
+ROOT_B = "0x" + "bb" * 32
+ROOT_C = "0x" + "cc" * 32
+
+
+def test_lru_eviction_and_pinning() -> None:
+    cache = StateCache(2)
+    state_a, state_b, state_c = object(), object(), object()
+
+    cache.insert(ROOT_A, 1, state_a)
+    cache.insert(ROOT_B, 2, state_b)
+    assert cache.get(bytes.fromhex("aa" * 32), 1) is state_a
+
+    cache.insert(ROOT_C, 3, state_c)
+    assert (ROOT_B, 2) not in cache
+    assert cache.keys() == [(ROOT_A, 1), (ROOT_C, 3)]
+
+    cache.pin(ROOT_A, 1)
+    cache.insert(ROOT_B, 2, state_b)
+    assert cache.get(ROOT_A, 1) is state_a
+    assert cache.get(ROOT_C, 3) is None
+    assert len(cache) == 2
+
+    assert cache.evict(ROOT_A, 1)
+    assert not cache.evict(ROOT_A, 1)
+    with pytest.raises(KeyError):
+        cache.pin(ROOT_A, 1)
+
+
+def test_get_or_insert_calls_factory_once() -> None:
+    cache = StateCache()
+    calls = []
+
+    def decode() -> object:
+        calls.append(None)
+        return object()
+
+    state = cache.get_or_insert(ROOT_A, 10, decode)
+    assert cache.get_or_insert(ROOT_A, 10, decode) is state
+    assert len(calls) == 1
+
+
+def test_shared_across_threads() -> None:
+    cache = StateCache(8)
+
+    def worker(epoch: int) -> None:
+        for _ in range(100):
+            cache.get_or_insert(ROOT_A, epoch, object)
+
+    threads = [threading.Thread(target=worker, args=(epoch,)) for epoch in range(4)]
+    for thread in threads:
+        thread.start()
+    for thread in threads:
+        thread.join()
+
+    assert len(cache) == 4
+
+
+def test_replaced_states_are_dropped_outside_the_lock() -> None:
+    cache = StateCache(capacity=1)
+    seen = []
+
+    class State:
+        def __del__(self) -> None:
+            # Runs when the cache drops the state, which must not hold the lock.
+            seen.append(len(cache))
+
+    cache.insert(ROOT_A, 0, State())
+    cache.insert(ROOT_A, 0, object())
+    cache.insert(ROOT_B, 0, State())
+    cache.insert(ROOT_C, 0, object())
+
+    assert seen == [1, 1]
+
+
+def test_rejects_invalid_roots() -> None:
+    cache = StateCache()
+
+    with pytest.raises(ValueError):
+        cache.get("0x1234", 0)
+    with pytest.raises(ValueError):
+        StateCache(0)
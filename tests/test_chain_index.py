@@ -0,0 +1,196 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import ChainIndex, SignedBeaconBlockHeader
+
+
+def root(name: int) -> str:
+    return "0x" + f"{name:02x}" * 32
+
+
+def build() -> ChainIndex:
+    # 1 - 2 - 4 - 6      canonical, slot 3 and 5 skipped
+    #      \
+    #       3 - 5        fork
+    index = ChainIndex()
+    # The head only advances to children of the head, so 4 comes before 3.
+    for name, slot, parent in [
+        (1, 1, 0),
+        (2, 2, 1),
+        (4, 4, 2),
+        (3, 3, 2),
+        (6, 6, 4),
+        (5, 5, 3),
+    ]:
+        assert index.insert_block(root(name), slot, root(parent))
+    return index
+
+
+def test_canonical_chain_follows_head() -> None:
+    index = build()
+
+    assert len(index) == 6
+    assert index.head == root(6)
+    assert [index.is_canonical(root(name)) for name in range(1, 7)] == [
+        True,
+        True,
+        False,
+        True,
+        False,
+        True,
+    ]
+    assert index.root_at_slot(3) is None
+    assert index.root_at_slot(4) == root(4)
+    assert index.slot_of(root(5)) == 5
+    assert index.parent_of(root(5)) == root(3)
+    assert index.slot_of(root(9)) is None
+    assert root(0) not in index
+
+
+def test_out_of_order_blocks_stay_off_the_canonical_chain() -> None:
+    index = ChainIndex()
+    for name, slot, parent in [(1, 1, 0), (3, 3, 2), (2, 2, 1)]:
+        assert index.insert_block(root(name), slot, root(parent))
+
+    assert index.head == root(2)
+    assert not index.is_canonical(root(3))
+    assert index.root_at_slot(3) is None
+    assert index.ancestor_at_slot(root(3), 1) == root(1)
+
+    index.set_head(root(3))
+    assert [index.root_at_slot(slot) for slot in range(1, 4)] == [
+        root(1),
+        root(2),
+        root(3),
+    ]
+
+
+def test_ancestor_at_slot() -> None:
+    index = build()
+
+    assert index.ancestor_at_slot(root(6), 6) == root(6)
+    assert index.ancestor_at_slot(root(6), 5) == root(4)
+    assert index.ancestor_at_slot(root(6), 3) == root(2)
+    assert index.ancestor_at_slot(root(5), 4) == root(3)
+    assert index.ancestor_at_slot(root(5), 2) == root(2)
+    assert index.ancestor_at_slot(root(5), 0) is None
+    assert index.ancestor_at_slot(root(9), 3) is None
+
+
+def test_path_between() -> None:
+    index = build()
+
+    assert index.path_between(root(1), root(6)) == [root(n) for n in (1, 2, 4, 6)]
+    assert index.path_between(root(2), root(5)) == [root(n) for n in (2, 3, 5)]
+    assert index.path_between(root(4), root(4)) == [root(4)]
+    assert index.path_between(root(3), root(6)) is None
+    assert index.path_between(root(6), root(1)) is None
+
+
+def test_set_head_reorgs() -> None:
+    index = build()
+
+    index.set_head(root(5))
+    assert index.head == root(5)
+    assert index.is_canonical(root(3))
+    assert not index.is_canonical(root(4))
+    assert not index.is_canonical(root(6))
+    assert index.root_at_slot(4) is None
+    assert index.root_at_slot(6) is None
+    assert index.ancestor_at_slot(root(6), 5) == root(4)
+    assert index.path_between(root(1), root(5)) == [root(n) for n in (1, 2, 3, 5)]
+
+    # New blocks on the head extend the canonical chain.
+    assert index.insert_block(root(7), 7, root(5))
+    assert index.head == root(7)
+
+    with pytest.raises(ValueError, match="not indexed"):
+        index.set_head(root(9))
+
+
+def test_rejects_inconsistent_blocks() -> None:
+    index = build()
+
+    assert not index.insert_block(root(6), 6, root(4))
+    with pytest.raises(ValueError, match="already indexed"):
+        index.insert_block(root(6), 7, root(4))
+    with pytest.raises(ValueError, match="not after the parent"):
+        index.insert_block(root(8), 4, root(4))
+
+
+def test_rejects_parent_after_child() -> None:
+    index = ChainIndex()
+    assert index.insert_block(root(5), 5, root(10))
+
+    # The parent of an indexed block must be before it, even if it is added
+    # after it.
+    with pytest.raises(ValueError, match="not before the child block slot 5"):
+        index.insert_block(root(10), 10, root(9))
+    with pytest.raises(ValueError, match="not before the child block slot 5"):
+        index.insert_block(root(10), 5, root(9))
+    assert root(10) not in index
+
+    assert index.insert_block(root(10), 4, root(9))
+    index.set_head(root(5))
+    assert [index.root_at_slot(slot) for slot in (4, 5)] == [root(10), root(5)]
+
+
+def test_load_rejects_parent_after_child() -> None:
+    index = ChainIndex()
+    index.insert_block(root(5), 5, root(10))
+    index.insert_block(root(10), 4, root(9))
+
+    # Move the parent to slot 10, after its child.
+    data = bytearray(index.to_bytes())
+    entry = data.index(bytes.fromhex(root(10)[2:]), 44)
+    data[entry + 32 : entry + 40] = (10).to_bytes(8, "little")
+
+    with pytest.raises(ValueError, match="not after its parent"):
+        ChainIndex.from_bytes(bytes(data))
+
+
+def test_add_headers() -> None:
+    fixture = Path(__file__).parent / "electra/fixtures/mainnet-13689000.json"
+    message = json.loads(fixture.read_text())["data"]["message"]
+    header = {
+        "message": {
+            "slot": message["slot"],
+            "proposer_index": message["proposer_index"],
+            "parent_root": message["parent_root"],
+            "state_root": message["state_root"],
+            "body_root": root(0),
+        },
+        "signature": "0x" + "00" * 96,
+    }
+    signed = SignedBeaconBlockHeader.from_json(json.dumps({"data": header}).encode())
+
+    index = ChainIndex()
+    assert index.extend([signed, signed]) == 1
+    assert index.head == signed.block_root()
+    assert index.parent_of(signed.block_root()) == message["parent_root"]
+
+
+def test_save_and_load(tmp_path: Path) -> None:
+    index = build()
+    index.set_head(root(5))
+
+    path = tmp_path / "chain.idx"
+    index.save(path)
+    loaded = ChainIndex.load(path)
+
+    assert len(loaded) == 6
+    assert loaded.head == root(5)
+    assert loaded.is_canonical(root(3))
+    assert loaded.to_bytes() == index.to_bytes()
+    assert ChainIndex.from_bytes(ChainIndex().to_bytes()).head is None
+    data = index.to_bytes()
+    assert ChainIndex.from_bytes(memoryview(bytearray(data))).to_bytes() == data
+
+    with pytest.raises(ValueError, match="Not a ChainIndex"):
+        ChainIndex.from_bytes(b"GPKI" + bytes(40))
+    with pytest.raises(ValueError, match="Expected 6 entries"):
+        ChainIndex.from_bytes(index.to_bytes()[:-1])
+    with pytest.raises(TypeError):
+        ChainIndex.from_bytes("GCIX")  # type: ignore[arg-type]
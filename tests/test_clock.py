@@ -0,0 +1,121 @@
+import time
+
+import pytest
+
+from grandine_py import ArrivalTracker, Config, SlotClock, seconds_into_slot
+
+GNOSIS_GENESIS = Config.gnosis().genesis_time
+
+
+def test_gnosis_slot_timing() -> None:
+    clock = SlotClock(Config.gnosis())
+    now = GNOSIS_GENESIS + 5 * 1000 + 2
+
+    slot = clock.current_slot(now)
+    assert slot == 1000
+    assert slot.config == Config.gnosis()
+    assert clock.current_epoch(now) == 1000 // 16
+    assert clock.slot_progress(now) == pytest.approx(0.4)
+    assert clock.time_to_next_slot(now) == pytest.approx(3.0)
+    assert clock.time_to_slot_start(1001, now) == pytest.approx(3.0)
+    assert clock.time_to_slot_start(Config.gnosis().slot(999), now) == pytest.approx(-7.0)
+
+
+def test_slot_boundaries_are_exact() -> None:
+    clock = SlotClock(Config.gnosis())
+    start = Config.gnosis().slot(123_456).start_time()
+
+    assert clock.current_slot(start) == 123_456
+    assert clock.slot_progress(start) == 0.0
+    assert clock.current_slot(start - 0.001) == 123_455
+
+
+def test_before_genesis() -> None:
+    clock = SlotClock(Config.gnosis())
+    now = GNOSIS_GENESIS - 10
+
+    assert clock.current_slot(now) is None
+    assert clock.current_epoch(now) is None
+    assert clock.slot_progress(now) == 0.0
+    assert clock.time_to_next_slot(now) == pytest.approx(10.0)
+    assert clock.time_to_slot_start(0, now) == pytest.approx(10.0)
+
+
+def test_clock_follows_system_time() -> None:
+    clock = SlotClock()
+
+    assert clock.config == Config.mainnet()
+    assert clock.time() == pytest.approx(time.time(), abs=1.0)
+    assert clock.current_slot() is not None
+    assert 0.0 <= clock.slot_progress() < 1.0
+
+    before = clock.time()
+    clock.resync()
+    assert clock.time() >= before - 1.0
+
+
+def test_rejects_invalid_input() -> None:
+    clock = SlotClock(Config.gnosis())
+
+    with pytest.raises(ValueError):
+        clock.current_slot(-1.0)
+    with pytest.raises(ValueError, match="different configs"):
+        clock.time_to_slot_start(Config.mainnet().slot(1), GNOSIS_GENESIS)
+
+
+def test_seconds_into_slot() -> None:
+    start = Config.gnosis().slot(1000).start_time()
+
+    assert seconds_into_slot(start + 1.5, 1000, Config.gnosis()) == pytest.approx(1.5)
+    assert seconds_into_slot(start - 2, Config.gnosis().slot(1000)) == pytest.approx(-2)
+    assert seconds_into_slot(Config.mainnet().genesis_time + 13, 1) == pytest.approx(1)
+
+    with pytest.raises(ValueError, match="different configs"):
+        seconds_into_slot(start, Config.mainnet().slot(1000), Config.gnosis())
+
+
+def test_arrival_tracker() -> None:
+    tracker = ArrivalTracker(Config.gnosis())
+    start = Config.gnosis().slot(1000).start_time()
+    roots = [bytes([index]) * 32 for index in range(4)]
+
+    assert tracker.record(roots[0], 1000, start + 4) == pytest.approx(4)
+    assert tracker.record("0x" + roots[1].hex(), 1000, start + 1) == pytest.approx(1)
+    assert tracker.record(roots[2], 1000, start + 2) == pytest.approx(2)
+    assert tracker.record(roots[3], 1001, start + 5.5) == pytest.approx(0.5)
+    # Later copies of a root do not count.
+    assert tracker.record(roots[0], 1000, start + 9) is None
+
+    assert len(tracker) == 4
+    assert roots[1] in tracker
+    assert tracker.latency(roots[0]) == pytest.approx(4)
+    assert tracker.latency(bytes(32)) is None
+    assert tracker.latencies(1000) == pytest.approx([4, 1, 2])
+    assert tracker.distribution(1000) == pytest.approx(
+        {
+            "slot": 1000,
+            "count": 3,
+            "min": 1,
+            "max": 4,
+            "mean": 7 / 3,
+            "p50": 2,
+            "p90": 4,
+            "p99": 4,
+        }
+    )
+    assert tracker.distribution(999) is None
+
+    assert tracker.prune(1001) == 3
+    assert len(tracker) == 1
+    assert tracker.latencies(1000) == []
+    assert tracker.latency(roots[3]) == pytest.approx(0.5)
+
+
+def test_arrival_tracker_defaults_to_system_time() -> None:
+    tracker = ArrivalTracker()
+    slot = SlotClock().current_slot()
+    assert slot is not None
+
+    latency = tracker.record(bytes(32), slot)
+    assert latency is not None
+    assert 0 <= latency < 13
@@ -0,0 +1,82 @@
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraSignedBeaconBlockGnosis,
+    ElectraSignedBeaconBlockMainnet,
+    FuluSignedBeaconBlockMainnet,
+    decode_signed_beacon_block,
+    read_grandine_block_file,
+)
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+# Slot 13689000 is in epoch 427781.
+SSZ = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+
+
+def test_explicit_fork() -> None:
+    block, fork = decode_signed_beacon_block(SSZ, fork="fulu")
+
+    assert fork == "fulu"
+    assert isinstance(block, FuluSignedBeaconBlockMainnet)
+    assert block.to_ssz() == SSZ
+
+
+def test_fork_from_schedule() -> None:
+    schedule = {"electra": 364032, "fulu": 427782}
+    block, fork = decode_signed_beacon_block(SSZ, fork_schedule=schedule)
+    assert (type(block), fork) == (ElectraSignedBeaconBlockMainnet, "electra")
+
+    schedule["fulu"] = 427781
+    block, fork = decode_signed_beacon_block(SSZ, fork_schedule=schedule)
+    assert (type(block), fork) == (FuluSignedBeaconBlockMainnet, "fulu")
+
+
+def test_tries_forks_in_order() -> None:
+    block, fork = decode_signed_beacon_block(SSZ)
+    assert (type(block), fork) == (ElectraSignedBeaconBlockMainnet, "electra")
+
+    gnosis = (FIXTURES / "gnosis-26539000.ssz").read_bytes()
+    block, _ = decode_signed_beacon_block(gnosis, preset="gnosis")
+    assert isinstance(block, ElectraSignedBeaconBlockGnosis)
+
+
+@pytest.mark.parametrize("framed", [False, True])
+def test_read_grandine_block_file(tmp_path: Path, framed: bool) -> None:
+    path = tmp_path / "block.ssz_snappy"
+    block = ElectraSignedBeaconBlockMainnet.from_ssz(SSZ)
+    path.write_bytes(block.to_ssz_snappy(framed))
+
+    decoded, fork = read_grandine_block_file(path)
+    assert (type(decoded), fork) == (ElectraSignedBeaconBlockMainnet, "electra")
+    assert decoded.to_ssz() == SSZ
+
+    decoded, fork = read_grandine_block_file(path, fork_schedule={"fulu": 0})
+    assert (type(decoded), fork) == (FuluSignedBeaconBlockMainnet, "fulu")
+
+
+def test_read_grandine_block_file_errors(tmp_path: Path) -> None:
+    with pytest.raises(OSError):
+        read_grandine_block_file(tmp_path / "missing.ssz_snappy")
+
+    path = tmp_path / "block.ssz"
+    path.write_bytes(SSZ)
+    with pytest.raises(ValueError):
+        read_grandine_block_file(path)
+
+
+def test_errors() -> None:
+    with pytest.raises(ValueError, match="Unknown preset"):
+        decode_signed_beacon_block(SSZ, preset="sepolia")
+    with pytest.raises(ValueError, match="Unknown fork"):
+        decode_signed_beacon_block(SSZ, fork="glamsterdam")
+    # The Deneb block layout has no execution requests.
+    with pytest.raises(ValueError):
+        decode_signed_beacon_block(SSZ, fork="deneb")
+    with pytest.raises(ValueError, match="before every fork"):
+        decode_signed_beacon_block(SSZ, fork_schedule={"electra": 500000})
+    with pytest.raises(ValueError, match="too short"):
+        decode_signed_beacon_block(b"\x00", fork_schedule={"electra": 0})
+    with pytest.raises(ValueError):
+        decode_signed_beacon_block(SSZ[:200])
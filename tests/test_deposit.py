@@ -0,0 +1,87 @@
+import hashlib
+import json
+
+import pytest
+
+from grandine_py import DepositData, parse_deposit_log
+
+PUBKEY = bytes(range(1, 49))
+WITHDRAWAL_CREDENTIALS = b"\x01" + bytes(11) + bytes(range(20))
+AMOUNT = 32_000_000_000
+SIGNATURE = bytes(range(100, 196))
+INDEX = 1_234_567
+
+
+def abi_bytes(value: bytes) -> bytes:
+    padding = -len(value) % 32
+    return len(value).to_bytes(32, "big") + value + bytes(padding)
+
+
+def deposit_log(
+    amount: bytes = AMOUNT.to_bytes(8, "little"),
+    signature: bytes = SIGNATURE,
+) -> bytes:
+    fields = [
+        PUBKEY,
+        WITHDRAWAL_CREDENTIALS,
+        amount,
+        signature,
+        INDEX.to_bytes(8, "little"),
+    ]
+    offsets = b""
+    body = b""
+    for field in fields:
+        offsets += (32 * len(fields) + len(body)).to_bytes(32, "big")
+        body += abi_bytes(field)
+    return offsets + body
+
+
+def sha256(data: bytes) -> bytes:
+    return hashlib.sha256(data).digest()
+
+
+def test_parse_deposit_log() -> None:
+    data = deposit_log()
+    assert len(data) == 576
+
+    deposit_data, index = parse_deposit_log(data)
+
+    assert index == INDEX
+    assert deposit_data.amount() == AMOUNT
+    assert json.loads(deposit_data.to_json().decode()) == {
+        "pubkey": "0x" + PUBKEY.hex(),
+        "withdrawal_credentials": "0x" + WITHDRAWAL_CREDENTIALS.hex(),
+        "amount": str(AMOUNT),
+        "signature": "0x" + SIGNATURE.hex(),
+    }
+    assert DepositData.ssz_fixed_size() == 184
+
+
+def test_deposit_data_hash_tree_root() -> None:
+    deposit_data, _ = parse_deposit_log(deposit_log())
+
+    pubkey_root = sha256(PUBKEY + bytes(16))
+    signature_root = sha256(
+        sha256(SIGNATURE[:64]) + sha256(SIGNATURE[64:] + bytes(32))
+    )
+    amount_root = AMOUNT.to_bytes(32, "little")
+    expected = sha256(
+        sha256(pubkey_root + WITHDRAWAL_CREDENTIALS)
+        + sha256(amount_root + signature_root)
+    )
+
+    assert deposit_data.hash_tree_root() == "0x" + expected.hex()
+
+
+def test_parse_deposit_log_rejects_invalid_data() -> None:
+    with pytest.raises(ValueError, match="amount must be 8 bytes, got 4"):
+        parse_deposit_log(deposit_log(amount=bytes(4)))
+    with pytest.raises(ValueError, match="signature must be 96 bytes"):
+        parse_deposit_log(deposit_log(signature=bytes(95)))
+    with pytest.raises(ValueError, match="Truncated"):
+        parse_deposit_log(deposit_log()[:-32])
+
+    data = bytearray(deposit_log())
+    data[32 * 5 + 32 + 48] = 1
+    with pytest.raises(ValueError, match="Non-zero padding after pubkey"):
+        parse_deposit_log(bytes(data))
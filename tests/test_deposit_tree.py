@@ -0,0 +1,172 @@
+import hashlib
+import json
+
+import pytest
+
+from grandine_py import DepositData, DepositTree, DepositTreeSnapshot
+
+DEPTH = 32
+BLOCK_HASH = "0x" + "ab" * 32
+
+
+def sha256(data: bytes) -> bytes:
+    return hashlib.sha256(data).digest()
+
+
+ZEROS = [bytes(32)]
+for _ in range(DEPTH):
+    ZEROS.append(sha256(ZEROS[-1] * 2))
+
+
+def leaf(index: int) -> bytes:
+    return sha256(index.to_bytes(8, "little"))
+
+
+def subtree_root(leaves: list[bytes], height: int) -> bytes:
+    if height == 0:
+        return leaves[0] if leaves else bytes(32)
+    if not leaves:
+        return ZEROS[height]
+    half = 2 ** (height - 1)
+    left = subtree_root(leaves[:half], height - 1)
+    return sha256(left + subtree_root(leaves[half:], height - 1))
+
+
+def deposit_root(count: int) -> str:
+    # Only the first 2**5 leaves are ever used, the rest of the tree is empty.
+    node = subtree_root([leaf(i) for i in range(count)], 5)
+    for height in range(5, DEPTH):
+        node = sha256(node + ZEROS[height])
+    return "0x" + sha256(node + count.to_bytes(32, "little")).hex()
+
+
+def snapshot_json(count: int, root: str | None = None) -> bytes:
+    finalized, start = [], 0
+    for height in reversed(range(DEPTH)):
+        if count >> height & 1:
+            leaves = [leaf(i) for i in range(start, start + 2**height)]
+            finalized.append("0x" + subtree_root(leaves, height).hex())
+            start += 2**height
+    snapshot = {
+        "finalized": finalized,
+        "deposit_root": root or deposit_root(count),
+        "deposit_count": str(count),
+        "execution_block_hash": BLOCK_HASH,
+        "execution_block_height": "1000",
+    }
+    return json.dumps({"data": snapshot}).encode()
+
+
+def is_valid_proof(leaf_root: bytes, proof: list[str], index: int, root: str) -> bool:
+    node = leaf_root
+    for height, sibling in enumerate(bytes.fromhex(p[2:]) for p in proof):
+        if index >> height & 1:
+            node = sha256(sibling + node)
+        else:
+            node = sha256(node + sibling)
+    return "0x" + node.hex() == root
+
+
+def test_empty_tree_matches_deposit_contract() -> None:
+    tree = DepositTree()
+
+    assert tree.deposit_count() == 0
+    assert tree.deposit_root() == deposit_root(0)
+
+    for index in range(11):
+        assert tree.push_root(leaf(index)) == index
+        assert tree.deposit_root() == deposit_root(index + 1)
+
+
+def test_snapshot_from_beacon_api_json() -> None:
+    snapshot = DepositTreeSnapshot.from_beacon_api_json(snapshot_json(13))
+
+    assert snapshot.deposit_count == 13
+    assert len(snapshot.finalized) == 3
+    assert snapshot.deposit_root == deposit_root(13)
+    assert snapshot.execution_block_hash == BLOCK_HASH
+    assert snapshot.execution_block_height == 1000
+
+    reparsed = DepositTreeSnapshot.from_beacon_api_json(
+        b'{"data": ' + snapshot.to_json() + b"}"
+    )
+    assert reparsed.to_json() == snapshot.to_json()
+
+
+def test_tree_from_snapshot_continues_and_proves() -> None:
+    tree = DepositTree.from_snapshot(
+        DepositTreeSnapshot.from_beacon_api_json(snapshot_json(13))
+    )
+    assert tree.deposit_root() == deposit_root(13)
+    assert tree.finalized_count() == 13
+
+    for index in range(13, 21):
+        tree.push_root(leaf(index))
+    assert tree.deposit_root() == deposit_root(21)
+
+    for index in range(13, 21):
+        proof = tree.proof(index)
+        assert len(proof) == DEPTH + 1
+        assert is_valid_proof(leaf(index), proof, index, tree.deposit_root())
+
+    with pytest.raises(ValueError, match="is finalized"):
+        tree.proof(12)
+    with pytest.raises(ValueError, match="not in the tree"):
+        tree.proof(21)
+
+
+def test_finalize_prunes_and_snapshots() -> None:
+    tree = DepositTree()
+    with pytest.raises(ValueError, match="not been finalized"):
+        tree.snapshot()
+
+    for index in range(20):
+        tree.push_root(leaf(index))
+
+    tree.finalize(13, BLOCK_HASH, 1000)
+    assert tree.finalized_count() == 13
+    assert tree.deposit_root() == deposit_root(20)
+    assert is_valid_proof(leaf(15), tree.proof(15), 15, tree.deposit_root())
+    snapshot = json.loads(tree.snapshot().to_json())
+    assert snapshot == json.loads(snapshot_json(13))["data"]
+
+    with pytest.raises(ValueError, match="Cannot finalize 12 deposits"):
+        tree.finalize(12, BLOCK_HASH, 1001)
+    with pytest.raises(ValueError, match="Cannot finalize 21 deposits"):
+        tree.finalize(21, BLOCK_HASH, 1001)
+
+
+def test_push_deposit_data() -> None:
+    deposit_data = DepositData.from_json(
+        json.dumps(
+            {
+                "data": {
+                    "pubkey": "0x" + "11" * 48,
+                    "withdrawal_credentials": "0x" + "22" * 32,
+                    "amount": "32000000000",
+                    "signature": "0x" + "33" * 96,
+                }
+            }
+        ).encode()
+    )
+    tree = DepositTree()
+
+    assert tree.push(deposit_data, index=0) == 0
+    expected = DepositTree()
+    expected.push_root(deposit_data.hash_tree_root())
+    assert tree.deposit_root() == expected.deposit_root()
+
+    with pytest.raises(ValueError, match="Expected deposit 1, got deposit 2"):
+        tree.push(deposit_data, index=2)
+
+
+def test_rejects_inconsistent_snapshots() -> None:
+    with pytest.raises(ValueError, match="does not match"):
+        DepositTreeSnapshot.from_beacon_api_json(
+            snapshot_json(13, root="0x" + "00" * 32)
+        )
+
+    snapshot = json.loads(snapshot_json(13))
+    snapshot["data"]["deposit_count"] = "15"
+    with pytest.raises(ValueError, match="needs 4 finalized roots, got 3"):
+        DepositTreeSnapshot.from_beacon_api_json(json.dumps(snapshot).encode())
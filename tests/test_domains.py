@@ -0,0 +1,92 @@
+import hashlib
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraSignedBeaconBlockMainnet,
+    SignedVoluntaryExit,
+    compute_domain,
+    compute_signing_root,
+    compute_voluntary_exit_signing_root,
+)
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+GENESIS_VALIDATORS_ROOT = bytes(range(32))
+FORK_VERSION = "0x05000000"
+
+# DOMAIN_DEPOSIT of mainnet, for the genesis fork version and a zero genesis
+# validators root.
+MAINNET_DEPOSIT_DOMAIN = (
+    "0x03000000f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a9"
+)
+
+
+def _domain(domain_type: bytes, fork_version: bytes, root: bytes) -> str:
+    fork_data_root = hashlib.sha256(fork_version.ljust(32, b"\x00") + root).digest()
+    return "0x" + (domain_type + fork_data_root[:28]).hex()
+
+
+def test_compute_domain() -> None:
+    assert compute_domain("DOMAIN_DEPOSIT", bytes(4)) == MAINNET_DEPOSIT_DOMAIN
+    assert compute_domain(b"\x03\x00\x00\x00", "0x00000000") == MAINNET_DEPOSIT_DOMAIN
+
+    expected = _domain(
+        bytes.fromhex("07000000"), bytes.fromhex("05000000"), GENESIS_VALIDATORS_ROOT
+    )
+    for domain_type in ("DOMAIN_SYNC_COMMITTEE", "0x07000000", b"\x07\x00\x00\x00"):
+        assert (
+            compute_domain(domain_type, FORK_VERSION, GENESIS_VALIDATORS_ROOT)
+            == expected
+        )
+
+
+def test_compute_domain_errors() -> None:
+    with pytest.raises(ValueError, match="Unknown domain type: DOMAIN_NOPE"):
+        compute_domain("DOMAIN_NOPE", FORK_VERSION)
+    with pytest.raises(ValueError, match="domain_type must be 4 bytes, got 3"):
+        compute_domain(b"\x00" * 3, FORK_VERSION)
+    with pytest.raises(ValueError, match="fork_version must be 4 bytes, got 32"):
+        compute_domain("DOMAIN_RANDAO", GENESIS_VALIDATORS_ROOT)
+    with pytest.raises(TypeError, match="fork_version must be bytes"):
+        compute_domain("DOMAIN_RANDAO", 5)  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="32 bytes"):
+        compute_domain("DOMAIN_RANDAO", FORK_VERSION, b"\x00" * 4)
+
+
+def test_compute_signing_root() -> None:
+    domain = compute_domain("DOMAIN_VOLUNTARY_EXIT", FORK_VERSION)
+    voluntary_exit = SignedVoluntaryExit.from_json(
+        b'{"data": {"message": {"epoch": "12", "validator_index": "34"},'
+        b' "signature": "0x' + b"ab" * 96 + b'"}}'
+    )
+
+    assert compute_signing_root(voluntary_exit.message(), domain) == (
+        compute_voluntary_exit_signing_root(12, 34, domain)
+    )
+
+    # Signed containers are hashed as a whole.
+    root = bytes.fromhex(voluntary_exit.hash_tree_root()[2:])
+    expected = "0x" + hashlib.sha256(root + bytes.fromhex(domain[2:])).hexdigest()
+    assert compute_signing_root(voluntary_exit, domain) == expected
+
+    with pytest.raises(TypeError, match="generated class, got bytes"):
+        compute_signing_root(b"\x00" * 32, domain)  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="32 bytes"):
+        compute_signing_root(voluntary_exit, b"\x00" * 4)
+
+
+def test_block_signing_root() -> None:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    block = ElectraSignedBeaconBlockMainnet.from_ssz(ssz)
+    domain = compute_domain(
+        "DOMAIN_BEACON_PROPOSER", FORK_VERSION, GENESIS_VALIDATORS_ROOT
+    )
+
+    expected = block.signing_root(domain)
+    for source in (block, block.blind()):
+        assert source.signing_root(GENESIS_VALIDATORS_ROOT, FORK_VERSION) == expected
+        assert source.signing_root(domain) == expected
+
+    with pytest.raises(ValueError, match="fork_version must be 4 bytes"):
+        block.signing_root(GENESIS_VALIDATORS_ROOT, b"\x00")
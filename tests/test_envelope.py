@@ -0,0 +1,85 @@
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraSignedBeaconBlockGnosis,
+    ElectraSignedBeaconBlockMainnet,
+    FuluSignedBeaconBlockMainnet,
+    content_key,
+    parse_content_key,
+    unwrap,
+    wrap,
+)
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+
+
+def test_wrap_unwrap_roundtrip() -> None:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    block = ElectraSignedBeaconBlockMainnet.from_ssz(ssz)
+
+    envelope = wrap(block)
+    assert envelope[:4] == b"GPSZ"
+    assert envelope[8:] == ssz
+
+    decoded = unwrap(envelope)
+    assert isinstance(decoded, ElectraSignedBeaconBlockMainnet)
+    assert decoded.to_ssz() == ssz
+
+
+def test_unwrap_selects_preset() -> None:
+    ssz = (FIXTURES / "gnosis-26539000.ssz").read_bytes()
+    envelope = wrap(ElectraSignedBeaconBlockGnosis.from_ssz(ssz))
+
+    assert isinstance(unwrap(envelope), ElectraSignedBeaconBlockGnosis)
+
+
+def test_unwrap_selects_fork() -> None:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    electra = wrap(ElectraSignedBeaconBlockMainnet.from_ssz(ssz))
+    fulu = wrap(FuluSignedBeaconBlockMainnet.from_ssz(ssz))
+
+    assert (electra[4], fulu[4]) == (5, 6)
+    assert isinstance(unwrap(fulu), FuluSignedBeaconBlockMainnet)
+
+
+def test_wrap_rejects_unknown_object() -> None:
+    with pytest.raises(TypeError):
+        wrap(object())
+
+
+def test_unwrap_rejects_invalid_envelopes() -> None:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    envelope = wrap(ElectraSignedBeaconBlockMainnet.from_ssz(ssz))
+
+    with pytest.raises(ValueError, match="Not a grandine_py envelope"):
+        unwrap(ssz)
+    with pytest.raises(ValueError, match="Unknown envelope type"):
+        unwrap(envelope[:6] + (0xFFFF).to_bytes(2, "little") + envelope[8:])
+    with pytest.raises(ValueError):
+        unwrap(envelope[:100])
+
+
+def test_content_key() -> None:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    block = ElectraSignedBeaconBlockMainnet.from_ssz(ssz)
+    root = ElectraSignedBeaconBlockMainnet.hash_tree_root_of_ssz(ssz)
+
+    key = content_key(block)
+    assert key == bytes([5, 0]) + bytes.fromhex(root[2:])
+    assert parse_content_key(key) == ("electra", "mainnet", root)
+
+    fulu = content_key(FuluSignedBeaconBlockMainnet.from_ssz(ssz))
+    assert parse_content_key(fulu) == ("fulu", "mainnet", root)
+
+
+def test_content_key_errors() -> None:
+    with pytest.raises(TypeError, match="has no content key"):
+        content_key(object())
+    with pytest.raises(ValueError, match="must be 34 bytes"):
+        parse_content_key(bytes(33))
+    with pytest.raises(ValueError, match="Unknown fork byte 7"):
+        parse_content_key(bytes([7, 0]) + bytes(32))
+    with pytest.raises(ValueError, match="Unknown preset byte 3"):
+        parse_content_key(bytes([5, 3]) + bytes(32))
@@ -0,0 +1,209 @@
+import hashlib
+import time
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    Config,
+    ElectraSignedBeaconBlockGnosis,
+    GossipCaptureReader,
+    GossipCaptureWriter,
+    ProposerSlashing,
+    compute_message_id,
+    fork_digest,
+    set_hex_format,
+    topic_for,
+)
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+BLOCK_TOPIC = "/eth2/00000000/beacon_block/ssz_snappy"
+EXIT_TOPIC = "/eth2/00000000/voluntary_exit/ssz_snappy"
+SLASHING_TOPIC = "/eth2/00000000/proposer_slashing/ssz_snappy"
+MAINNET_GENESIS_VALIDATORS_ROOT = (
+    "0x4b363db94e286120d76eb905340fdd4e54bfe9f06bf33ff6cf5ad27f511bfe95"
+)
+
+
+def snappy_literal(data: bytes) -> bytes:
+    """Encode data as an uncompressed (literal-only) snappy block."""
+    out = bytearray()
+    length = len(data)
+    while True:
+        byte = length & 0x7F
+        length >>= 7
+        out.append(byte | (0x80 if length else 0))
+        if not length:
+            break
+    for start in range(0, len(data), 65536):
+        chunk = data[start : start + 65536]
+        out.append(62 << 2)
+        out += (len(chunk) - 1).to_bytes(3, "little")
+        out += chunk
+    return bytes(out)
+
+
+def test_capture_roundtrip_and_decode(tmp_path: Path) -> None:
+    block_ssz = (FIXTURES / "gnosis-26539000.ssz").read_bytes()
+    path = tmp_path / "capture.gpgc"
+
+    with GossipCaptureWriter(path) as writer:
+        writer.write(BLOCK_TOPIC, snappy_literal(block_ssz), timestamp=1000.5)
+    with GossipCaptureWriter(path) as writer:
+        writer.write(EXIT_TOPIC, snappy_literal(b"\x01\x02"), timestamp=1001.0)
+
+    reader = GossipCaptureReader(path, Config.gnosis())
+    assert len(reader) == 2
+
+    block_message, exit_message = list(reader)
+    assert block_message.topic == BLOCK_TOPIC
+    assert block_message.timestamp == 1000.5
+    assert block_message.ssz() == block_ssz
+    assert reader[-1].topic == EXIT_TOPIC
+
+    block = reader.decode(block_message)
+    assert isinstance(block, ElectraSignedBeaconBlockGnosis)
+    assert block.to_ssz() == block_ssz
+    assert reader.decode(exit_message) == b"\x01\x02"
+
+
+def test_decode_proposer_slashing(tmp_path: Path) -> None:
+    header = bytes(range(112)) + b"\xc0" + bytes(95)
+    slashing_ssz = header + header
+    path = tmp_path / "capture.gpgc"
+
+    with GossipCaptureWriter(path) as writer:
+        writer.write(SLASHING_TOPIC, snappy_literal(slashing_ssz))
+
+    slashing = GossipCaptureReader(path).decode(GossipCaptureReader(path)[0])
+    assert isinstance(slashing, ProposerSlashing)
+    assert slashing.to_ssz() == slashing_ssz
+
+
+def test_reader_rebuilds_missing_index(tmp_path: Path) -> None:
+    path = tmp_path / "capture.gpgc"
+
+    with GossipCaptureWriter(path) as writer:
+        for i in range(3):
+            writer.write(EXIT_TOPIC, snappy_literal(bytes([i])), timestamp=float(i))
+
+    Path(f"{path}.idx").unlink()
+    # A truncated trailing record is ignored.
+    with open(path, "ab") as f:
+        f.write(b"\x00" * 5)
+
+    reader = GossipCaptureReader(path)
+    assert [message.ssz() for message in reader] == [b"\x00", b"\x01", b"\x02"]
+
+
+@pytest.mark.parametrize("keep_index", [True, False])
+def test_writer_cuts_off_torn_record(tmp_path: Path, keep_index: bool) -> None:
+    path = tmp_path / "capture.gpgc"
+
+    with GossipCaptureWriter(path) as writer:
+        for i in range(3):
+            writer.write(EXIT_TOPIC, snappy_literal(bytes([i])), timestamp=float(i))
+
+    # Tear the last record, as a writer crashing mid-write would.
+    path.write_bytes(path.read_bytes()[:-3])
+    if not keep_index:
+        Path(f"{path}.idx").unlink()
+
+    with GossipCaptureWriter(path) as writer:
+        writer.write(EXIT_TOPIC, snappy_literal(b"\x03"), timestamp=3.0)
+
+    reader = GossipCaptureReader(path)
+    assert [message.ssz() for message in reader] == [b"\x00", b"\x01", b"\x03"]
+    assert reader[-1].timestamp == 3.0
+
+    Path(f"{path}.idx").unlink()
+    assert len(GossipCaptureReader(path)) == 3
+
+
+def test_replay_keeps_relative_timing(tmp_path: Path) -> None:
+    path = tmp_path / "capture.gpgc"
+
+    with GossipCaptureWriter(path) as writer:
+        writer.write(EXIT_TOPIC, snappy_literal(b"a"), timestamp=10.0)
+        writer.write(EXIT_TOPIC, snappy_literal(b"b"), timestamp=10.2)
+
+    started = time.monotonic()
+    messages = list(GossipCaptureReader(path).replay(speed=2.0))
+
+    assert len(messages) == 2
+    assert time.monotonic() - started >= 0.09
+
+
+def test_rejects_foreign_files(tmp_path: Path) -> None:
+    path = tmp_path / "not-a-capture"
+    path.write_bytes(b"hello world")
+
+    with pytest.raises(ValueError):
+        GossipCaptureReader(path)
+    with pytest.raises(ValueError):
+        GossipCaptureWriter(path)
+
+
+def expected_message_id(domain: bytes, topic: str, payload: bytes) -> str:
+    topic_bytes = topic.encode()
+    digest = hashlib.sha256(
+        domain + len(topic_bytes).to_bytes(8, "little") + topic_bytes + payload
+    ).digest()
+    return "0x" + digest[:20].hex()
+
+
+def test_compute_message_id() -> None:
+    data = b"\x01\x02\x03"
+
+    assert compute_message_id(EXIT_TOPIC, snappy_literal(data)) == expected_message_id(
+        b"\x01\x00\x00\x00", EXIT_TOPIC, data
+    )
+    assert compute_message_id(EXIT_TOPIC, b"\xff") == expected_message_id(
+        b"\x00\x00\x00\x00", EXIT_TOPIC, b"\xff"
+    )
+    assert compute_message_id(BLOCK_TOPIC, snappy_literal(data)) != (
+        compute_message_id(EXIT_TOPIC, snappy_literal(data))
+    )
+
+
+def test_message_id_of_captured_message(tmp_path: Path) -> None:
+    path = tmp_path / "capture.gpgc"
+    data = snappy_literal(b"\x01\x02")
+
+    with GossipCaptureWriter(path) as writer:
+        writer.write(EXIT_TOPIC, data, timestamp=1000.0)
+
+    (message,) = list(GossipCaptureReader(path))
+    assert message.message_id() == compute_message_id(EXIT_TOPIC, data)
+
+
+def test_fork_digest_and_topic() -> None:
+    root = bytes.fromhex(MAINNET_GENESIS_VALIDATORS_ROOT[2:])
+    expected = hashlib.sha256(bytes.fromhex("05000000") + bytes(28) + root).digest()
+    digest = fork_digest(MAINNET_GENESIS_VALIDATORS_ROOT, "0x05000000")
+
+    assert digest == "0x" + expected[:4].hex()
+    assert fork_digest(root, b"\x05\x00\x00\x00") == digest
+    assert topic_for(digest, "beacon_block") == (
+        f"/eth2/{expected[:4].hex()}/beacon_block/ssz_snappy"
+    )
+    assert topic_for(b"\x00" * 4, "beacon_attestation", subnet_id=5) == (
+        "/eth2/00000000/beacon_attestation_5/ssz_snappy"
+    )
+
+    set_hex_format(uppercase=True, prefix=False)
+    try:
+        assert fork_digest(root, b"\x05\x00\x00\x00") == expected[:4].hex().upper()
+        assert compute_message_id(EXIT_TOPIC, b"\xff") == (
+            expected_message_id(b"\x00\x00\x00\x00", EXIT_TOPIC, b"\xff")[2:].upper()
+        )
+        assert topic_for(digest, "beacon_block") == (
+            f"/eth2/{expected[:4].hex()}/beacon_block/ssz_snappy"
+        )
+    finally:
+        set_hex_format()
+
+    with pytest.raises(ValueError, match="4 bytes"):
+        topic_for("0x00", "beacon_block")
+    with pytest.raises(ValueError, match="Invalid gossip topic name"):
+        topic_for(digest, "beacon_block/ssz_snappy")
@@ -0,0 +1,54 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import ElectraBlindedBeaconBlockMinimal, render_graffiti
+
+FIXTURE = Path(__file__).parent / "electra/fixtures/minimal-signed-blinded-block.json"
+
+
+def test_render_graffiti() -> None:
+    template = "{client} slot {slot} by {validator_index}"
+    graffiti = render_graffiti(template, 12, 345, "gp")
+
+    assert graffiti == b"gp slot 12 by 345".ljust(32, b"\x00")
+    assert render_graffiti("{{slot}}", 1, 2) == b"{slot}".ljust(32, b"\x00")
+    assert render_graffiti("{client}", 1, 2) == bytes(32)
+
+
+def test_render_graffiti_truncates_on_character_boundary() -> None:
+    # 31 ASCII bytes leave one byte, too few for the 2-byte "é".
+    graffiti = render_graffiti("a" * 31 + "é", 0, 0)
+    assert graffiti == b"a" * 31 + b"\x00"
+
+    graffiti = render_graffiti("€" * 11, 0, 0)
+    assert graffiti == ("€" * 10).encode() + bytes(2)
+    assert graffiti.rstrip(b"\x00").decode() == "€" * 10
+
+
+def test_render_graffiti_rejects_invalid_templates() -> None:
+    with pytest.raises(ValueError, match="Unknown graffiti placeholder"):
+        render_graffiti("{epoch}", 0, 0)
+    with pytest.raises(ValueError, match="Unmatched '{'"):
+        render_graffiti("{slot", 0, 0)
+    with pytest.raises(ValueError, match="Unmatched '}'"):
+        render_graffiti("slot}", 0, 0)
+
+
+def test_set_graffiti() -> None:
+    message = json.loads(FIXTURE.read_text())["data"]["message"]
+    block = ElectraBlindedBeaconBlockMinimal.from_json(
+        json.dumps({"data": message}).encode()
+    )
+    graffiti = render_graffiti("proposed at {slot}", 7, 1)
+
+    mutable = block.clone_mut()
+    mutable.set_graffiti(graffiti)
+    edited = mutable.freeze()
+
+    assert json.loads(edited.to_json())["body"]["graffiti"] == "0x" + graffiti.hex()
+    assert edited.block_hash_tree_root() != block.block_hash_tree_root()
+
+    with pytest.raises(ValueError, match="32 bytes"):
+        mutable.set_graffiti(b"short")
@@ -0,0 +1,101 @@
+import hashlib
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    BlsLengthError,
+    ElectraSignedBeaconBlockMainnet,
+    ProposerSlashing,
+    SignedBeaconBlockHeader,
+)
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+
+BUILDER_PUBKEY = (
+    "0x8c4ed5e24fe5c6ae21018437bde147693f68cda427cd1122cf20819c30eda7ed"
+    "74f72dece09bb313f2a1855595ab677d"
+)
+DOMAIN = bytes.fromhex("00000000") + bytes(range(28))
+
+
+def block() -> ElectraSignedBeaconBlockMainnet:
+    return ElectraSignedBeaconBlockMainnet.from_ssz(
+        (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    )
+
+
+def signed_header(**changes: str) -> SignedBeaconBlockHeader:
+    header = json.loads(block().signed_header().to_json().decode())
+    header["message"].update(changes)
+    return SignedBeaconBlockHeader.from_json(json.dumps({"data": header}).encode())
+
+
+def test_header_roundtrip_and_roots() -> None:
+    header = signed_header()
+    block_json = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+
+    assert header.slot() == 13689000
+    assert header.proposer_index() == int(
+        block_json["data"]["message"]["proposer_index"]
+    )
+    assert header.to_ssz()[-96:] == bytes.fromhex(block_json["data"]["signature"][2:])
+    assert SignedBeaconBlockHeader.from_ssz(header.to_ssz()).to_ssz() == header.to_ssz()
+    assert SignedBeaconBlockHeader.ssz_fixed_size() == 208
+
+    block_root = bytes.fromhex(header.block_root()[2:])
+    expected = "0x" + hashlib.sha256(block_root + DOMAIN).hexdigest()
+    assert header.signing_root(DOMAIN) == expected
+
+
+def test_from_ssz_non_strict() -> None:
+    first, second = signed_header(), signed_header(slot="1")
+    buffer = first.to_ssz() + second.to_ssz() + bytes(3)
+
+    with pytest.raises(ValueError):
+        SignedBeaconBlockHeader.from_ssz(buffer)
+
+    header, consumed = SignedBeaconBlockHeader.from_ssz(buffer, strict=False)
+    assert consumed == 208
+    assert header.to_ssz() == first.to_ssz()
+
+    header, consumed = SignedBeaconBlockHeader.from_ssz(buffer[208:], strict=False)
+    assert (header.slot(), consumed) == (1, 208)
+
+    with pytest.raises(ValueError):
+        SignedBeaconBlockHeader.from_ssz(buffer[:207], strict=False)
+
+    ssz = block().to_ssz()
+    with pytest.raises(ValueError, match="is variable-size"):
+        ElectraSignedBeaconBlockMainnet.from_ssz(ssz + bytes(4), strict=False)
+
+
+def test_verify_rejects_wrong_pubkey() -> None:
+    header = signed_header()
+
+    assert header.verify(BUILDER_PUBKEY, DOMAIN) is False
+    with pytest.raises(BlsLengthError):
+        header.verify(b"\x00", DOMAIN)
+
+
+def test_proposer_slashing_constructor() -> None:
+    header_1 = signed_header()
+    header_2 = signed_header(state_root="0x" + "11" * 32)
+
+    slashing = ProposerSlashing(header_1, header_2)
+
+    assert slashing.signed_header_1().to_ssz() == header_1.to_ssz()
+    assert slashing.signed_header_2().to_ssz() == header_2.to_ssz()
+    assert ProposerSlashing.from_ssz(slashing.to_ssz()).to_ssz() == slashing.to_ssz()
+
+
+def test_proposer_slashing_rejects_non_slashable_headers() -> None:
+    header = signed_header()
+
+    with pytest.raises(ValueError, match="same"):
+        ProposerSlashing(header, header)
+    with pytest.raises(ValueError, match="different slots"):
+        ProposerSlashing(header, signed_header(slot="1"))
+    with pytest.raises(ValueError, match="different proposers"):
+        ProposerSlashing(header, signed_header(proposer_index="1"))
@@ -0,0 +1,76 @@
+import json
+from collections.abc import Iterator
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraSignedBeaconBlockMainnet,
+    get_hex_format,
+    set_hex_format,
+)
+
+FIXTURE = Path(__file__).parent / "electra/fixtures/mainnet-13689000.ssz"
+
+
+@pytest.fixture(autouse=True)
+def reset_hex_format() -> Iterator[None]:
+    yield
+    set_hex_format()
+
+
+@pytest.fixture
+def block() -> ElectraSignedBeaconBlockMainnet:
+    return ElectraSignedBeaconBlockMainnet.from_ssz(FIXTURE.read_bytes())
+
+
+def test_default_hex_format(block: ElectraSignedBeaconBlockMainnet) -> None:
+    assert get_hex_format() == {"uppercase": False, "prefix": True}
+
+    signature = json.loads(block.to_json())["signature"]
+    assert signature.startswith("0x")
+    assert signature == signature.lower()
+
+
+def test_uppercase_without_prefix(block: ElectraSignedBeaconBlockMainnet) -> None:
+    default_json = json.loads(block.to_json())
+    default_dict = block.to_pyspec_dict()
+
+    set_hex_format(uppercase=True, prefix=False)
+    assert get_hex_format() == {"uppercase": True, "prefix": False}
+
+    formatted_json = json.loads(block.to_json())
+    assert formatted_json["signature"] == default_json["signature"][2:].upper()
+    assert formatted_json["message"]["slot"] == default_json["message"]["slot"]
+    assert (
+        formatted_json["message"]["body"]["graffiti"]
+        == default_json["message"]["body"]["graffiti"][2:].upper()
+    )
+
+    formatted_dict = block.to_pyspec_dict()
+    assert formatted_dict["message"]["parent_root"] == (
+        default_dict["message"]["parent_root"][2:].upper()
+    )
+    assert formatted_dict["message"]["slot"] == default_dict["message"]["slot"]
+
+    root = ElectraSignedBeaconBlockMainnet.hash_tree_root_of_ssz(FIXTURE.read_bytes())
+    assert len(root) == 64
+    assert root == root.upper()
+
+
+def test_canonical_json_is_not_affected(
+    block: ElectraSignedBeaconBlockMainnet,
+) -> None:
+    canonical = block.to_json_canonical()
+
+    set_hex_format(uppercase=True)
+
+    assert block.to_json_canonical() == canonical
+    assert json.loads(block.to_json())["signature"].startswith("0x")
+
+
+def test_unset_arguments_reset_to_defaults() -> None:
+    set_hex_format(uppercase=True, prefix=False)
+    set_hex_format(prefix=False)
+
+    assert get_hex_format() == {"uppercase": False, "prefix": False}
@@ -0,0 +1,218 @@
+import hashlib
+import json
+from typing import Any
+
+import pytest
+
+from grandine_py import (
+    AltairSignedBeaconBlockMainnet,
+    BellatrixSignedBeaconBlockMainnet,
+    BellatrixSignedBlindedBeaconBlockMainnet,
+    CapellaSignedBeaconBlockMainnet,
+    CapellaSignedBlindedBeaconBlockMainnet,
+    DenebSignedBeaconBlockMainnet,
+    DenebSignedBlindedBeaconBlockMainnet,
+    Phase0AttestationMainnet,
+    Phase0SignedBeaconBlockMainnet,
+    SignedBLSToExecutionChange,
+    decode_signed_beacon_block,
+    unwrap,
+    wrap,
+)
+
+FORKS = ["phase0", "altair", "bellatrix", "capella", "deneb", "electra", "fulu"]
+SIGNATURE = "0x" + "ab" * 96
+ROOT = "0x" + "11" * 32
+
+
+def _body(fork: str, blinded: bool = False) -> dict[str, Any]:
+    body: dict[str, Any] = {
+        "randao_reveal": SIGNATURE,
+        "eth1_data": {"deposit_root": ROOT, "deposit_count": "3", "block_hash": ROOT},
+        "graffiti": "0x" + "00" * 32,
+        "proposer_slashings": [],
+        "attester_slashings": [],
+        "attestations": [],
+        "deposits": [],
+        "voluntary_exits": [],
+    }
+    if fork == "phase0":
+        return body
+
+    body["sync_aggregate"] = {
+        "sync_committee_bits": "0x" + "ff" * 64,
+        "sync_committee_signature": SIGNATURE,
+    }
+    if fork == "altair":
+        return body
+
+    payload: dict[str, Any] = {
+        "parent_hash": ROOT,
+        "fee_recipient": "0x" + "22" * 20,
+        "state_root": ROOT,
+        "receipts_root": ROOT,
+        "logs_bloom": "0x" + "00" * 256,
+        "prev_randao": ROOT,
+        "block_number": "100",
+        "gas_limit": "30000000",
+        "gas_used": "21000",
+        "timestamp": "1700000000",
+        "extra_data": "0x",
+        "base_fee_per_gas": "7",
+        "block_hash": ROOT,
+    }
+    lists = ["transactions"]
+    if fork != "bellatrix":
+        lists.append("withdrawals")
+        body["bls_to_execution_changes"] = []
+    for name in lists:
+        payload |= {f"{name}_root": ROOT} if blinded else {name: []}
+    if fork == "deneb":
+        payload |= {"blob_gas_used": "0", "excess_blob_gas": "0"}
+        body["blob_kzg_commitments"] = []
+
+    body["execution_payload_header" if blinded else "execution_payload"] = payload
+    return body
+
+
+def _block_json(fork: str, blinded: bool = False) -> dict[str, Any]:
+    message = {
+        "slot": "7",
+        "proposer_index": "3",
+        "parent_root": ROOT,
+        "state_root": ROOT,
+        "body": _body(fork, blinded),
+    }
+    return {"message": message, "signature": SIGNATURE}
+
+
+def _load(cls: Any, block_json: dict[str, Any]) -> Any:
+    return cls.from_json(json.dumps({"data": block_json}).encode())
+
+
+BLOCKS = [
+    (Phase0SignedBeaconBlockMainnet, "phase0", False),
+    (AltairSignedBeaconBlockMainnet, "altair", False),
+    (BellatrixSignedBeaconBlockMainnet, "bellatrix", False),
+    (BellatrixSignedBlindedBeaconBlockMainnet, "bellatrix", True),
+    (CapellaSignedBeaconBlockMainnet, "capella", False),
+    (CapellaSignedBlindedBeaconBlockMainnet, "capella", True),
+    (DenebSignedBeaconBlockMainnet, "deneb", False),
+    (DenebSignedBlindedBeaconBlockMainnet, "deneb", True),
+]
+
+
+@pytest.mark.parametrize(("cls", "fork", "blinded"), BLOCKS)
+def test_roundtrip(cls: Any, fork: str, blinded: bool) -> None:
+    block_json = _block_json(fork, blinded)
+    block = _load(cls, block_json)
+
+    assert json.loads(block.to_json()) == block_json
+    assert cls.from_ssz(block.to_ssz()).to_json() == block.to_json()
+    # Every list is empty, so the encoding has the minimum size.
+    assert len(block.to_ssz()) == cls.ssz_min_size()
+
+    if fork != "phase0":
+        with pytest.raises(ValueError, match="missing field"):
+            _load(cls, _block_json("phase0"))
+
+
+@pytest.mark.parametrize(("cls", "fork", "blinded"), BLOCKS)
+def test_block_api(cls: Any, fork: str, blinded: bool) -> None:
+    block = _load(cls, _block_json(fork, blinded))
+
+    assert block.slot() == 7
+    header = block.signed_header()
+    assert (header.slot(), header.proposer_index()) == (7, 3)
+
+    block_root = bytes.fromhex(header.block_root()[2:])
+    domain = bytes(range(32))
+    expected = "0x" + hashlib.sha256(block_root + domain).hexdigest()
+    assert block.signing_root(domain) == expected
+    assert len(block.attestations()) == 0
+    assert len(block.voluntary_exits()) == 0
+
+
+@pytest.mark.parametrize(("cls", "fork", "blinded"), BLOCKS)
+def test_envelope_roundtrip(cls: Any, fork: str, blinded: bool) -> None:
+    block = _load(cls, _block_json(fork, blinded))
+
+    envelope = wrap(block)
+    assert envelope[4] == FORKS.index(fork)
+    decoded = unwrap(envelope)
+    assert type(decoded) is cls
+    assert decoded.to_ssz() == block.to_ssz()
+
+
+@pytest.mark.parametrize(
+    ("cls", "fork"), [(cls, fork) for cls, fork, blinded in BLOCKS if not blinded]
+)
+def test_decode_detects_fork(cls: Any, fork: str) -> None:
+    ssz = _load(cls, _block_json(fork)).to_ssz()
+
+    block, decoded_fork = decode_signed_beacon_block(ssz)
+    assert (type(block), decoded_fork) == (cls, fork)
+    assert block.to_ssz() == ssz
+
+    # Slot 7 is in epoch 0.
+    block, decoded_fork = decode_signed_beacon_block(ssz, fork_schedule={fork: 0})
+    assert (type(block), decoded_fork) == (cls, fork)
+
+
+def test_decode_rejects_other_fork() -> None:
+    ssz = _load(Phase0SignedBeaconBlockMainnet, _block_json("phase0")).to_ssz()
+
+    with pytest.raises(ValueError):
+        decode_signed_beacon_block(ssz, fork="altair")
+
+
+def test_operations() -> None:
+    block_json = _block_json("phase0")
+    checkpoint = {"epoch": "0", "root": ROOT}
+    block_json["message"]["body"]["attestations"] = [
+        {
+            "aggregation_bits": "0x03",
+            "data": {
+                "slot": "6",
+                "index": "1",
+                "beacon_block_root": ROOT,
+                "source": checkpoint,
+                "target": checkpoint,
+            },
+            "signature": SIGNATURE,
+        }
+    ]
+    block = _load(Phase0SignedBeaconBlockMainnet, block_json)
+
+    attestation = block.attestations()[0]
+    assert isinstance(attestation, Phase0AttestationMainnet)
+    assert list(attestation.aggregation_bits()) == [True]
+
+    block_json = _block_json("capella")
+    body = block_json["message"]["body"]
+    body["execution_payload"]["transactions"] = ["0x0102"]
+    body["execution_payload"]["withdrawals"] = [
+        {
+            "index": "1",
+            "validator_index": "2",
+            "address": "0x" + "33" * 20,
+            "amount": "4",
+        }
+    ]
+    body["bls_to_execution_changes"] = [
+        {
+            "message": {
+                "validator_index": "5",
+                "from_bls_pubkey": "0x" + "cd" * 48,
+                "to_execution_address": "0x" + "44" * 20,
+            },
+            "signature": SIGNATURE,
+        }
+    ]
+    block = _load(CapellaSignedBeaconBlockMainnet, block_json)
+
+    assert list(block.transactions()) == [b"\x01\x02"]
+    assert block.withdrawals()[0]["validator_index"] == "2"
+    change = block.bls_to_execution_changes()[0]
+    assert isinstance(change, SignedBLSToExecutionChange)
+    assert change.message().validator_index() == 5
@@ -0,0 +1,263 @@
+import gzip
+import io
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraSignedBeaconBlockMainnet,
+    set_decode_limits,
+    set_hex_format,
+)
+
+FIXTURE = Path(__file__).parent / "electra/fixtures/mainnet-13689000.json"
+
+
+def _load(block_json: dict) -> ElectraSignedBeaconBlockMainnet:
+    return ElectraSignedBeaconBlockMainnet.from_json(json.dumps(block_json).encode())
+
+
+def test_json_delta_roundtrip() -> None:
+    base_json = json.loads(FIXTURE.read_text())
+    target_json = json.loads(FIXTURE.read_text())
+    target_json["data"]["message"]["slot"] = "13689001"
+    target_json["data"]["message"]["body"]["graffiti"] = "0x" + ("ab" * 32)
+
+    base = _load(base_json)
+    target = _load(target_json)
+
+    delta = target.to_json_delta(base)
+
+    assert json.loads(delta) == {
+        "message": {
+            "slot": "13689001",
+            "body": {"graffiti": "0x" + ("ab" * 32)},
+        }
+    }
+    restored = ElectraSignedBeaconBlockMainnet.from_json_delta(base, delta)
+    assert restored.to_ssz() == target.to_ssz()
+
+
+def test_json_delta_identical_is_empty() -> None:
+    block = _load(json.loads(FIXTURE.read_text()))
+
+    assert json.loads(block.to_json_delta(block)) == {}
+
+
+def test_json_delta_rejects_invalid_patch() -> None:
+    block = _load(json.loads(FIXTURE.read_text()))
+
+    with pytest.raises(ValueError):
+        ElectraSignedBeaconBlockMainnet.from_json_delta(
+            block, b'{"message": {"slot": "not a number"}}'
+        )
+
+
+def test_json_canonical_sorts_keys_compactly() -> None:
+    block = _load(json.loads(FIXTURE.read_text()))
+
+    canonical = block.to_json_canonical()
+
+    expected = json.dumps(
+        json.loads(block.to_json()), sort_keys=True, separators=(",", ":")
+    )
+    assert canonical == expected.encode()
+    assert canonical.startswith(b'{"message":{"body":{"attestations":')
+    assert block.to_json_canonical() == canonical
+
+
+def test_pyspec_dict_roundtrip() -> None:
+    block = _load(json.loads(FIXTURE.read_text()))
+
+    spec = block.to_pyspec_dict()
+    message = spec["message"]
+    payload = message["body"]["execution_payload"]
+
+    assert message["slot"] == 13689000
+    assert payload["gas_used"] == 20687591
+    # uint256 values are strings in eth2spec's encoding.
+    assert payload["base_fee_per_gas"] == "52179350"
+    assert message["parent_root"].startswith("0x")
+
+    spec["message"]["body"]["graffiti"] = b"\xab" * 32
+    restored = ElectraSignedBeaconBlockMainnet.from_pyspec_dict(spec)
+    assert (
+        json.loads(restored.to_json())["message"]["body"]["graffiti"]
+        == "0x" + "ab" * 32
+    )
+    assert restored.to_pyspec_dict()["message"]["slot"] == 13689000
+
+
+def test_pyspec_dict_rejects_unsupported_values() -> None:
+    block = _load(json.loads(FIXTURE.read_text()))
+    spec = block.to_pyspec_dict()
+    spec["message"]["slot"] = object()
+
+    with pytest.raises(TypeError):
+        ElectraSignedBeaconBlockMainnet.from_pyspec_dict(spec)
+
+
+def test_json_batch_decodes_in_order() -> None:
+    base_json = json.loads(FIXTURE.read_text())
+    payloads = []
+    for offset in range(4):
+        base_json["data"]["message"]["slot"] = str(13689000 + offset)
+        payloads.append(json.dumps(base_json).encode())
+
+    blocks = ElectraSignedBeaconBlockMainnet.from_json_batch(payloads)
+
+    assert [block.slot() for block in blocks] == [
+        13689000 + offset for offset in range(4)
+    ]
+    assert ElectraSignedBeaconBlockMainnet.from_json_batch([]) == []
+
+
+def test_json_batch_names_failing_item() -> None:
+    valid = FIXTURE.read_bytes()
+
+    with pytest.raises(ValueError, match="Item 1"):
+        ElectraSignedBeaconBlockMainnet.from_json_batch([valid, b"{}", b"[]"])
+
+
+def test_equals_ignoring_masks_fields() -> None:
+    base_json = json.loads(FIXTURE.read_text())
+    target_json = json.loads(FIXTURE.read_text())
+    target_json["data"]["signature"] = "0x" + ("cd" * 96)
+    target_json["data"]["message"]["state_root"] = "0x" + ("ab" * 32)
+
+    base = _load(base_json)
+    target = _load(target_json)
+
+    assert target.equals_ignoring(base)
+    assert not target.equals_ignoring(base, fields=["signature"])
+    assert target.equals_ignoring(base, ["signature", "message.state_root"])
+    assert not target.equals_ignoring(base, ["message.signature", "state_root"])
+    assert base.equals_ignoring(base, [])
+
+
+def test_equals_ignoring_masks_list_elements() -> None:
+    base_json = json.loads(FIXTURE.read_text())
+    target_json = json.loads(FIXTURE.read_text())
+    attestations = target_json["data"]["message"]["body"]["attestations"]
+    attestations[0]["signature"] = "0x" + ("cd" * 96)
+
+    base = _load(base_json)
+    target = _load(target_json)
+
+    assert target.equals_ignoring(base, ["message.body.attestations.signature"])
+    assert not target.equals_ignoring(base, ["message.body.signature"])
+
+
+def test_json_api_roundtrip() -> None:
+    fixture = json.loads(FIXTURE.read_text())
+
+    block, metadata = ElectraSignedBeaconBlockMainnet.from_json_api(
+        FIXTURE.read_bytes()
+    )
+    assert metadata == {
+        "version": "fulu",
+        "execution_optimistic": False,
+        "finalized": True,
+    }
+
+    body = block.to_json_api("FULU", execution_optimistic=False, finalized=True)
+    assert list(json.loads(body)) == list(fixture)
+    assert json.loads(body) == fixture | {"data": json.loads(block.to_json())}
+
+    restored, metadata = ElectraSignedBeaconBlockMainnet.from_json_api(
+        block.to_json_api("electra")
+    )
+    assert json.loads(block.to_json_api("electra")) == {
+        "version": "electra",
+        "data": json.loads(block.to_json()),
+    }
+    assert metadata == {"version": "electra"}
+    assert restored.to_ssz() == block.to_ssz()
+
+    plain = json.dumps({"data": json.loads(block.to_json())}).encode()
+    _, metadata = ElectraSignedBeaconBlockMainnet.from_json_api(plain)
+    assert metadata == {}
+
+
+def test_json_api_rejects_unknown_version() -> None:
+    block = _load(json.loads(FIXTURE.read_text()))
+
+    with pytest.raises(ValueError, match="Unknown fork"):
+        block.to_json_api("gloas")
+
+
+def test_json_file_from_path(tmp_path: Path) -> None:
+    block = ElectraSignedBeaconBlockMainnet.from_json_file(FIXTURE)
+    assert block.to_ssz() == _load(json.loads(FIXTURE.read_text())).to_ssz()
+    assert ElectraSignedBeaconBlockMainnet.from_json_file(str(FIXTURE)) == block
+
+    path = tmp_path / "block.json"
+    block.to_json_file(path)
+    assert path.read_bytes() == block.to_json()
+
+
+def test_json_file_objects(tmp_path: Path) -> None:
+    block = _load(json.loads(FIXTURE.read_text()))
+
+    with gzip.open(tmp_path / "block.json.gz", "wb") as file:
+        file.write(b'{"data": ')
+        block.to_json_file(file)
+        file.write(b"}")
+    with gzip.open(tmp_path / "block.json.gz", "rb") as file:
+        assert ElectraSignedBeaconBlockMainnet.from_json_file(file) == block
+
+    buffer = io.BytesIO()
+    set_hex_format(uppercase=True)
+    try:
+        block.to_json_file(buffer)
+        assert buffer.getvalue() == block.to_json()
+    finally:
+        set_hex_format()
+
+
+def test_json_file_errors(tmp_path: Path) -> None:
+    path = tmp_path / "block.json"
+    path.write_text('{"data": {"message": {}}}')
+    with pytest.raises(ValueError, match="missing field"):
+        ElectraSignedBeaconBlockMainnet.from_json_file(path)
+
+    with pytest.raises(FileNotFoundError):
+        ElectraSignedBeaconBlockMainnet.from_json_file(tmp_path / "missing.json")
+    with pytest.raises(TypeError, match="binary mode"):
+        ElectraSignedBeaconBlockMainnet.from_json_file(io.StringIO("{}"))
+    with pytest.raises(TypeError, match="path or a file object"):
+        ElectraSignedBeaconBlockMainnet.from_json_file(42)
+
+    set_decode_limits(max_input_size=1024)
+    try:
+        with pytest.raises(ValueError, match="decode limit of 1024 bytes"):
+            ElectraSignedBeaconBlockMainnet.from_json_file(FIXTURE)
+    finally:
+        set_decode_limits()
+
+
+def test_json_file_limits(tmp_path: Path) -> None:
+    block_json = json.loads(FIXTURE.read_text())
+    transactions = block_json["data"]["message"]["body"]["execution_payload"][
+        "transactions"
+    ]
+    path = tmp_path / "nested.json"
+    path.write_bytes(b"[" * 1000)
+
+    try:
+        set_decode_limits(max_list_length=len(transactions))
+        ElectraSignedBeaconBlockMainnet.from_json_file(FIXTURE)
+
+        set_decode_limits(max_list_length=len(transactions) - 1)
+        with pytest.raises(ValueError, match="elements"):
+            ElectraSignedBeaconBlockMainnet.from_json_file(FIXTURE)
+        with pytest.raises(ValueError, match="elements"):
+            file = io.BytesIO(FIXTURE.read_bytes())
+            ElectraSignedBeaconBlockMainnet.from_json_file(file)
+
+        set_decode_limits(max_json_depth=3)
+        with pytest.raises(ValueError, match="nesting"):
+            ElectraSignedBeaconBlockMainnet.from_json_file(path)
+    finally:
+        set_decode_limits()
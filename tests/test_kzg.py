@@ -0,0 +1,104 @@
+from collections.abc import Iterator
+
+import pytest
+
+from grandine_py import (
+    TrustedSetup,
+    get_trusted_setup,
+    load_trusted_setup,
+    set_trusted_setup,
+    verify_blob_kzg_proof_batch,
+)
+
+
+@pytest.fixture(autouse=True)
+def restore_default() -> Iterator[None]:
+    yield
+    set_trusted_setup()
+
+
+def test_mainnet_is_default() -> None:
+    setup = get_trusted_setup()
+
+    assert setup.is_mainnet
+    assert repr(setup) == "TrustedSetup(mainnet)"
+    assert TrustedSetup.mainnet().is_mainnet
+
+
+def test_set_trusted_setup_resets_to_mainnet() -> None:
+    set_trusted_setup(TrustedSetup.mainnet())
+    assert get_trusted_setup().is_mainnet
+
+    set_trusted_setup(None)
+    assert get_trusted_setup().is_mainnet
+
+
+def test_load_trusted_setup_rejects_invalid_setups(tmp_path) -> None:
+    with pytest.raises(ValueError, match="Invalid trusted setup"):
+        load_trusted_setup(b"4096\n65\n00\n")
+    with pytest.raises(ValueError, match="Invalid trusted setup JSON"):
+        load_trusted_setup(b'{"g1_lagrange": []}')
+    with pytest.raises(ValueError, match="Invalid trusted setup"):
+        load_trusted_setup(
+            b'{"g1_monomial": ["0x00"], "g1_lagrange": ["0x00"], "g2_monomial": []}'
+        )
+
+    path = tmp_path / "trusted_setup.txt"
+    path.write_bytes(b"1\n1\n")
+    with pytest.raises(ValueError, match="Invalid trusted setup"):
+        load_trusted_setup(path)
+    with pytest.raises(OSError):
+        load_trusted_setup(str(tmp_path / "missing.txt"))
+
+    assert get_trusted_setup().is_mainnet
+
+
+BLOB_SIZE = 131072
+# The commitment and proof of an all-zero blob are the point at infinity.
+INFINITY = b"\xc0" + bytes(47)
+
+
+def test_verify_blob_kzg_proof_batch() -> None:
+    blobs = bytearray(3 * BLOB_SIZE)
+    views = [memoryview(blobs)[i * BLOB_SIZE : (i + 1) * BLOB_SIZE] for i in range(3)]
+
+    assert verify_blob_kzg_proof_batch(views, [INFINITY] * 3, [INFINITY] * 3)
+    assert verify_blob_kzg_proof_batch([], [], [], TrustedSetup.mainnet())
+
+    # The constant polynomial 1 is not committed to by the point at infinity.
+    blobs[BLOB_SIZE + 31] = 1
+    assert not verify_blob_kzg_proof_batch(views, [INFINITY] * 3, [INFINITY] * 3)
+
+
+def test_verify_blob_kzg_proof_batch_reads_read_only_buffers() -> None:
+    blobs = bytearray(5 * BLOB_SIZE)
+    blobs[3 * BLOB_SIZE + 31] = 1
+    # Slices of one read-only buffer are adjacent and verified in place.
+    view = memoryview(bytes(blobs))
+    views = [view[i * BLOB_SIZE : (i + 1) * BLOB_SIZE] for i in range(5)]
+
+    assert verify_blob_kzg_proof_batch(views[:3], [INFINITY] * 3, [INFINITY] * 3)
+    assert not verify_blob_kzg_proof_batch(views, [INFINITY] * 5, [INFINITY] * 5)
+    assert verify_blob_kzg_proof_batch(
+        [views[0], bytes(BLOB_SIZE), views[2]], [INFINITY] * 3, [INFINITY] * 3
+    )
+    with pytest.raises(ValueError, match="Blob 4"):
+        verify_blob_kzg_proof_batch(views, [INFINITY] * 4 + [bytes(48)], [INFINITY] * 5)
+
+
+def test_verify_blob_kzg_proof_batch_rejects_invalid_input() -> None:
+    blob = bytes(BLOB_SIZE)
+
+    with pytest.raises(ValueError, match="one commitment and proof per blob"):
+        verify_blob_kzg_proof_batch([blob], [INFINITY], [])
+    with pytest.raises(ValueError, match="Blob 1 must be 131072 bytes, got 3"):
+        verify_blob_kzg_proof_batch([blob, b"abc"], [INFINITY] * 2, [INFINITY] * 2)
+    with pytest.raises(ValueError, match="Proof 0 must be 48 bytes"):
+        verify_blob_kzg_proof_batch([blob], [INFINITY], [bytes(47)])
+    with pytest.raises(ValueError, match="not a contiguous buffer"):
+        strided = memoryview(bytes(2 * BLOB_SIZE))[::2]
+        verify_blob_kzg_proof_batch([strided], [INFINITY], [INFINITY])
+    with pytest.raises(ValueError, match="Blob 0"):
+        verify_blob_kzg_proof_batch([blob], [bytes(48)], [INFINITY])
+    with pytest.raises(TypeError):
+        verify_blob_kzg_proof_batch([object()], [INFINITY], [INFINITY])
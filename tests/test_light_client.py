@@ -0,0 +1,225 @@
+import hashlib
+import json
+
+from grandine_py import (
+    AltairLightClientBootstrapMinimal,
+    AltairLightClientFinalityUpdateMinimal,
+    AltairLightClientOptimisticUpdateMinimal,
+    AltairLightClientUpdateMinimal,
+    ElectraLightClientBootstrapMinimal,
+)
+
+ZERO_ROOT = "0x" + "00" * 32
+PUBKEYS = ["0x" + f"{index:02x}" * 48 for index in range(32)]
+AGGREGATE_PUBKEY = "0x" + "aa" * 48
+EMPTY_SYNC_AGGREGATE = {
+    "sync_committee_bits": "0x00000000",
+    "sync_committee_signature": "0xc0" + "00" * 95,
+}
+
+
+def sha256(data: bytes) -> bytes:
+    return hashlib.sha256(data).digest()
+
+
+def hex_root(root: bytes) -> str:
+    return "0x" + root.hex()
+
+
+def merkleize(leaves: list[bytes]) -> bytes:
+    while len(leaves) > 1:
+        leaves = [sha256(a + b) for a, b in zip(leaves[::2], leaves[1::2])]
+    return leaves[0]
+
+
+def pubkey_root(pubkey: str) -> bytes:
+    return sha256(bytes.fromhex(pubkey[2:]) + bytes(16))
+
+
+def sync_committee_root() -> bytes:
+    pubkeys = merkleize([pubkey_root(pubkey) for pubkey in PUBKEYS])
+    return sha256(pubkeys + pubkey_root(AGGREGATE_PUBKEY))
+
+
+def nodes(count: int, seed: int) -> list[bytes]:
+    return [sha256(bytes([seed, index])) for index in range(count)]
+
+
+def proven_root(leaf: bytes, branch: list[bytes], generalized_index: int) -> bytes:
+    for height, sibling in enumerate(branch):
+        if (generalized_index >> height) & 1:
+            leaf = sha256(sibling + leaf)
+        else:
+            leaf = sha256(leaf + sibling)
+    return leaf
+
+
+def header(slot: int, state_root: bytes = bytes(32)) -> dict:
+    return {
+        "beacon": {
+            "slot": str(slot),
+            "proposer_index": "3",
+            "parent_root": ZERO_ROOT,
+            "state_root": hex_root(state_root),
+            "body_root": ZERO_ROOT,
+        }
+    }
+
+
+def header_root(header: dict) -> bytes:
+    beacon = header["beacon"]
+    leaves = [
+        int(beacon["slot"]).to_bytes(32, "little"),
+        int(beacon["proposer_index"]).to_bytes(32, "little"),
+        bytes.fromhex(beacon["parent_root"][2:]),
+        bytes.fromhex(beacon["state_root"][2:]),
+        bytes.fromhex(beacon["body_root"][2:]),
+    ]
+    return merkleize(leaves + [bytes(32)] * 3)
+
+
+def sync_committee() -> dict:
+    return {"pubkeys": PUBKEYS, "aggregate_pubkey": AGGREGATE_PUBKEY}
+
+
+def electra_header(slot: int, state_root: bytes) -> dict:
+    execution = {
+        "parent_hash": ZERO_ROOT,
+        "fee_recipient": "0x" + "00" * 20,
+        "state_root": ZERO_ROOT,
+        "receipts_root": ZERO_ROOT,
+        "logs_bloom": "0x" + "00" * 256,
+        "prev_randao": ZERO_ROOT,
+        "block_number": "0",
+        "gas_limit": "0",
+        "gas_used": "0",
+        "timestamp": "0",
+        "extra_data": "0x",
+        "base_fee_per_gas": "0",
+        "block_hash": ZERO_ROOT,
+        "transactions_root": ZERO_ROOT,
+        "withdrawals_root": ZERO_ROOT,
+        "blob_gas_used": "0",
+        "excess_blob_gas": "0",
+    }
+    return header(slot, state_root) | {
+        "execution": execution,
+        "execution_branch": [ZERO_ROOT] * 4,
+    }
+
+
+def encode(value: dict) -> bytes:
+    return json.dumps({"data": value}).encode()
+
+
+def test_bootstrap() -> None:
+    branch = nodes(5, 1)
+    state_root = proven_root(sync_committee_root(), branch, 54)
+    bootstrap_json = {
+        "header": header(64, state_root),
+        "current_sync_committee": sync_committee(),
+        "current_sync_committee_branch": [hex_root(node) for node in branch],
+    }
+    bootstrap = AltairLightClientBootstrapMinimal.from_json(encode(bootstrap_json))
+
+    assert bootstrap.slot() == 64
+    assert bootstrap.block_root() == hex_root(header_root(bootstrap_json["header"]))
+    assert bootstrap.current_sync_committee_branch() == [
+        hex_root(node) for node in branch
+    ]
+    assert bootstrap.verify_current_sync_committee()
+    assert (
+        AltairLightClientBootstrapMinimal.from_ssz(bootstrap.to_ssz()) == bootstrap
+    )
+
+    bootstrap_json["current_sync_committee"]["pubkeys"] = PUBKEYS[::-1]
+    tampered = AltairLightClientBootstrapMinimal.from_json(encode(bootstrap_json))
+    assert not tampered.verify_current_sync_committee()
+
+
+def test_electra_bootstrap_has_deeper_branch() -> None:
+    branch = nodes(6, 2)
+    state_root = proven_root(sync_committee_root(), branch, 86)
+    bootstrap = ElectraLightClientBootstrapMinimal.from_json(
+        encode(
+            {
+                "header": electra_header(64, state_root),
+                "current_sync_committee": sync_committee(),
+                "current_sync_committee_branch": [hex_root(node) for node in branch],
+            }
+        )
+    )
+
+    assert len(bootstrap.current_sync_committee_branch()) == 6
+    assert bootstrap.verify_current_sync_committee()
+
+
+def test_update() -> None:
+    # The next sync committee (gindex 55) and the finalized root (gindex 105)
+    # share the state tree above node 13.
+    finalized = header(40)
+    finalized_epoch = (5).to_bytes(32, "little")
+    node_52, node_12, node_7, node_2 = nodes(4, 3)
+    node_26 = sha256(node_52 + sha256(finalized_epoch + header_root(finalized)))
+    node_27 = sha256(sync_committee_root() + sync_committee_root())
+    next_branch = [sync_committee_root(), node_26, node_12, node_7, node_2]
+    finality_branch = [finalized_epoch, node_52, node_27, node_12, node_7, node_2]
+    state_root = proven_root(sync_committee_root(), next_branch, 55)
+
+    update_json = {
+        "attested_header": header(64, state_root),
+        "next_sync_committee": sync_committee(),
+        "next_sync_committee_branch": [hex_root(node) for node in next_branch],
+        "finalized_header": finalized,
+        "finality_branch": [hex_root(node) for node in finality_branch],
+        "sync_aggregate": EMPTY_SYNC_AGGREGATE,
+        "signature_slot": "65",
+    }
+    update = AltairLightClientUpdateMinimal.from_json(encode(update_json))
+
+    assert (update.attested_slot(), update.finalized_slot()) == (64, 40)
+    assert update.signature_slot() == 65
+    assert len(update.finality_branch()) == 6
+    assert update.verify_next_sync_committee()
+    assert update.verify_finality_branch()
+
+    update_json["finalized_header"] = header(48)
+    tampered = AltairLightClientUpdateMinimal.from_json(encode(update_json))
+    assert tampered.verify_next_sync_committee()
+    assert not tampered.verify_finality_branch()
+
+
+def test_finality_update_from_genesis() -> None:
+    branch = nodes(6, 4)
+    state_root = proven_root(bytes(32), branch, 105)
+    finality_update = AltairLightClientFinalityUpdateMinimal.from_json(
+        encode(
+            {
+                "attested_header": header(16, state_root),
+                "finalized_header": header(0),
+                "finality_branch": [hex_root(node) for node in branch],
+                "sync_aggregate": EMPTY_SYNC_AGGREGATE,
+                "signature_slot": "17",
+            }
+        )
+    )
+
+    assert finality_update.finalized_slot() == 0
+    assert finality_update.verify_finality_branch()
+
+
+def test_optimistic_update() -> None:
+    optimistic_update = AltairLightClientOptimisticUpdateMinimal.from_json(
+        encode(
+            {
+                "attested_header": header(16),
+                "sync_aggregate": EMPTY_SYNC_AGGREGATE,
+                "signature_slot": "17",
+            }
+        )
+    )
+
+    assert optimistic_update.attested_slot() == 16
+    assert optimistic_update.signature_slot() == 17
+    assert len(optimistic_update.to_ssz()) == 112 + 4 + 96 + 8
+    assert AltairLightClientOptimisticUpdateMinimal.ssz_fixed_size() == 220
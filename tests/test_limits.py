@@ -0,0 +1,62 @@
+import json
+from collections.abc import Iterator
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraSignedBeaconBlockMainnet,
+    get_decode_limits,
+    set_decode_limits,
+)
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+
+
+@pytest.fixture(autouse=True)
+def reset_limits() -> Iterator[None]:
+    yield
+    set_decode_limits()
+
+
+def test_limits_disabled_by_default() -> None:
+    assert get_decode_limits() == {
+        "max_input_size": None,
+        "max_list_length": None,
+        "max_json_depth": None,
+    }
+
+
+def test_max_input_size() -> None:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    json_bytes = (FIXTURES / "mainnet-13689000.json").read_bytes()
+
+    set_decode_limits(max_input_size=len(ssz))
+    assert get_decode_limits()["max_input_size"] == len(ssz)
+    ElectraSignedBeaconBlockMainnet.from_ssz(ssz)
+
+    set_decode_limits(max_input_size=len(ssz) - 1)
+    with pytest.raises(ValueError, match="decode limit"):
+        ElectraSignedBeaconBlockMainnet.from_ssz(ssz)
+    with pytest.raises(ValueError, match="decode limit"):
+        ElectraSignedBeaconBlockMainnet.from_json(json_bytes)
+
+
+def test_max_list_length() -> None:
+    json_bytes = (FIXTURES / "mainnet-13689000.json").read_bytes()
+    transactions = json.loads(json_bytes)["data"]["message"]["body"][
+        "execution_payload"
+    ]["transactions"]
+
+    set_decode_limits(max_list_length=len(transactions))
+    ElectraSignedBeaconBlockMainnet.from_json(json_bytes)
+
+    set_decode_limits(max_list_length=len(transactions) - 1)
+    with pytest.raises(ValueError, match="elements"):
+        ElectraSignedBeaconBlockMainnet.from_json(json_bytes)
+
+
+def test_max_json_depth() -> None:
+    set_decode_limits(max_json_depth=3)
+    with pytest.raises(ValueError, match="nesting"):
+        ElectraSignedBeaconBlockMainnet.from_json(b"[" * 1000)
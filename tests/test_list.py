@@ -0,0 +1,98 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraAttestationMainnet,
+    ElectraBeaconBlockContentsMainnet,
+    ElectraSignedBeaconBlockMainnet,
+)
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+
+
+@pytest.fixture
+def block() -> ElectraSignedBeaconBlockMainnet:
+    return ElectraSignedBeaconBlockMainnet.from_ssz(
+        (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    )
+
+
+@pytest.fixture
+def body_json() -> dict:
+    block = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    return block["data"]["message"]["body"]
+
+
+def test_transactions(block: ElectraSignedBeaconBlockMainnet, body_json: dict) -> None:
+    expected = [
+        bytes.fromhex(tx[2:]) for tx in body_json["execution_payload"]["transactions"]
+    ]
+    transactions = block.transactions()
+
+    assert len(transactions) == 263
+    assert transactions[0] == expected[0]
+    assert transactions[-1] == expected[-1]
+    assert list(transactions[10:20:3]) == expected[10:20:3]
+    assert list(transactions[::-50]) == expected[::-50]
+    assert list(transactions) == expected
+
+    with pytest.raises(IndexError):
+        transactions[263]
+    with pytest.raises(TypeError):
+        transactions["0"]
+
+
+def test_attestations_and_withdrawals(
+    block: ElectraSignedBeaconBlockMainnet, body_json: dict
+) -> None:
+    attestations = block.attestations()
+    assert len(attestations) == len(body_json["attestations"])
+    assert isinstance(attestations[0], ElectraAttestationMainnet)
+    assert json.loads(attestations[1].to_json()) == body_json["attestations"][1]
+
+    withdrawals = block.withdrawals()
+    assert list(withdrawals) == body_json["execution_payload"]["withdrawals"]
+
+
+def test_aggregation_bits(block: ElectraSignedBeaconBlockMainnet) -> None:
+    attestation = block.attestations()[1]
+    bits = attestation.aggregation_bits()
+
+    assert len(bits) == 1420
+    assert all(isinstance(bit, bool) for bit in bits[:8])
+    assert sum(bits) > 0
+
+
+def test_blobs_and_kzg_proofs_are_zero_copy_views() -> None:
+    block = json.loads((FIXTURES / "mainnet-13689000.json").read_text())
+    blobs = [bytes([index]) * 131072 for index in range(3)]
+    proofs = [bytes([0xC0 + index]) * 48 for index in range(3)]
+    contents = ElectraBeaconBlockContentsMainnet.from_json(
+        json.dumps(
+            {
+                "data": {
+                    "block": block["data"]["message"],
+                    "kzg_proofs": ["0x" + proof.hex() for proof in proofs],
+                    "blobs": ["0x" + blob.hex() for blob in blobs],
+                }
+            }
+        ).encode()
+    )
+
+    views = contents.blobs()
+    assert len(views) == 3
+    assert [bytes(view) for view in views] == blobs
+    assert [bytes(view) for view in contents.kzg_proofs()] == proofs
+
+    view = views[-1]
+    memory = memoryview(view)
+    assert memory.readonly
+    assert memory.nbytes == 131072
+    assert memory[:4] == b"\x02" * 4
+    assert view == blobs[2]
+
+    # The view keeps the contents alive.
+    del contents, views
+    assert bytes(memory[-2:]) == b"\x02\x02"
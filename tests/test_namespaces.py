@@ -0,0 +1,93 @@
+import ast
+import importlib
+import subprocess
+import sys
+from pathlib import Path
+
+import pytest
+
+import grandine_py
+from grandine_py import (
+    ElectraSignedBeaconBlockMainnet,
+    ElectraSingleAttestation,
+    FuluBeaconStateGnosis,
+    SignedBeaconBlockHeader,
+)
+
+ROOT = Path(__file__).parent.parent
+FORKS = ["phase0", "altair", "bellatrix", "capella", "deneb", "electra", "fulu"]
+PRESETS = ["mainnet", "minimal", "gnosis"]
+
+
+def _stub_exports(path: Path) -> set[str]:
+    tree = ast.parse(path.read_text())
+    for node in tree.body:
+        if isinstance(node, ast.Assign | ast.AnnAssign):
+            return set(ast.literal_eval(node.value))
+    raise AssertionError(f"{path} has no __all__")
+
+
+def test_preset_submodules_hold_the_top_level_classes() -> None:
+    from grandine_py.electra.mainnet import SignedBeaconBlock
+    from grandine_py.fulu.gnosis import BeaconState
+
+    assert SignedBeaconBlock is ElectraSignedBeaconBlockMainnet
+    assert BeaconState is FuluBeaconStateGnosis
+    assert grandine_py.electra.mainnet.SignedBeaconBlock.__name__ == (
+        "ElectraSignedBeaconBlockMainnet"
+    )
+
+
+def test_fork_submodules_hold_preset_independent_classes() -> None:
+    from grandine_py.electra import SingleAttestation
+
+    assert SingleAttestation is ElectraSingleAttestation
+    assert not hasattr(grandine_py.electra, "SignedBeaconBlock")
+
+
+def test_classes_of_no_fork_stay_at_the_top_level() -> None:
+    assert grandine_py.SignedBeaconBlockHeader is SignedBeaconBlockHeader
+    assert not hasattr(grandine_py.phase0, "SignedBeaconBlockHeader")
+
+
+@pytest.mark.parametrize("fork", FORKS)
+def test_submodules_are_importable(fork: str) -> None:
+    module = importlib.import_module(f"grandine_py.{fork}")
+    assert module is getattr(grandine_py, fork)
+    for preset in PRESETS:
+        submodule = importlib.import_module(f"grandine_py.{fork}.{preset}")
+        assert submodule is getattr(module, preset)
+
+
+@pytest.mark.parametrize("fork", FORKS)
+def test_submodules_match_stubs(fork: str) -> None:
+    stubs = ROOT / "grandine_py" / fork
+    module = getattr(grandine_py, fork)
+    public = {name for name in dir(module) if not name.startswith("_")}
+    assert public == _stub_exports(stubs / "__init__.pyi")
+
+    for preset in PRESETS:
+        submodule = getattr(module, preset)
+        public = {name for name in dir(submodule) if not name.startswith("_")}
+        assert public == _stub_exports(stubs / f"{preset}.pyi")
+
+
+def test_stubs_cover_top_level_names() -> None:
+    tree = ast.parse((ROOT / "grandine_py.pyi").read_text())
+    stubbed = set()
+    for node in tree.body:
+        if isinstance(node, ast.ClassDef | ast.FunctionDef):
+            stubbed.add(node.name)
+        elif isinstance(node, ast.AnnAssign) and isinstance(node.target, ast.Name):
+            stubbed.add(node.target.id)
+
+    assert set(grandine_py.__all__) - set(FORKS) - stubbed == set()
+
+
+def test_submodule_stubs_are_up_to_date() -> None:
+    result = subprocess.run(
+        [sys.executable, ROOT / "scripts/generate_stubs.py", "--check"],
+        capture_output=True,
+        text=True,
+    )
+    assert result.returncode == 0, result.stdout
@@ -0,0 +1,68 @@
+import copy
+import pickle
+from pathlib import Path
+from typing import Any
+
+from grandine_py import (
+    ElectraSignedBeaconBlockMainnet,
+    SignedVoluntaryExit,
+)
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+SIGNATURE = "0x" + "ab" * 96
+
+
+def _exit(epoch: int) -> SignedVoluntaryExit:
+    return SignedVoluntaryExit.from_pyspec_dict(
+        {"message": {"epoch": epoch, "validator_index": 34}, "signature": SIGNATURE}
+    )
+
+
+def _block() -> ElectraSignedBeaconBlockMainnet:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    return ElectraSignedBeaconBlockMainnet.from_ssz(ssz)
+
+
+def _root(obj: Any) -> str:
+    return type(obj).hash_tree_root_batch([obj])[0]
+
+
+def test_equality_and_hashing() -> None:
+    assert _exit(12) == _exit(12)
+    assert _exit(12) != _exit(13)
+    assert _exit(12) != _exit(12).to_ssz()
+    assert len({_exit(12), _exit(12), _exit(13)}) == 2
+
+    block = _block()
+    assert block == _block()
+    assert hash(block) == hash(_block())
+    assert block != block.blind()
+
+
+def test_repr() -> None:
+    block = _block()
+    assert repr(block) == (
+        f"ElectraSignedBeaconBlockMainnet(slot=13689000, root={_root(block)})"
+    )
+
+    header = block.signed_header()
+    assert repr(header) == (
+        f"SignedBeaconBlockHeader(slot=13689000, root={_root(header)})"
+    )
+
+    # Voluntary exits have an epoch but no slot.
+    voluntary_exit = _exit(12)
+    assert repr(voluntary_exit) == f"SignedVoluntaryExit(root={_root(voluntary_exit)})"
+
+
+def test_copy_and_pickle() -> None:
+    block = _block()
+    assert copy.copy(block) is block
+    assert copy.deepcopy([block])[0] is block
+
+    for obj in (block, _exit(12), block.signed_header()):
+        restored = pickle.loads(pickle.dumps(obj))
+        assert type(restored) is type(obj)
+        assert restored == obj
+
+    assert type(block).__module__ == "grandine_py"
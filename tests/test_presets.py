@@ -0,0 +1,94 @@
+import sys
+
+import pytest
+
+from grandine_py import Config, diff_configs, diff_presets, presets
+
+
+def test_preset_constants() -> None:
+    assert presets.MAINNET.SLOTS_PER_EPOCH == 32
+    assert presets.MINIMAL.SLOTS_PER_EPOCH == 8
+    assert presets.GNOSIS.SLOTS_PER_EPOCH == 16
+
+    assert presets.MAINNET.MAX_WITHDRAWALS_PER_PAYLOAD == 16
+    assert presets.GNOSIS.MAX_WITHDRAWALS_PER_PAYLOAD == 8
+    assert presets.GNOSIS.BASE_REWARD_FACTOR == 25
+    assert presets.MAINNET.MAX_BLOB_COMMITMENTS_PER_BLOCK == 4096
+    assert presets.MAINNET.SLOTS_PER_HISTORICAL_ROOT == 8192
+    assert presets.GNOSIS.preset == "gnosis"
+
+
+def test_preset_constants_namespace() -> None:
+    import grandine_py.presets
+
+    assert sys.modules["grandine_py.presets"] is presets
+    assert isinstance(presets.MINIMAL, presets.PresetConstants)
+
+    constants = presets.MINIMAL.as_dict()
+    assert constants["MAX_COMMITTEES_PER_SLOT"] == 4
+    assert set(constants) <= set(dir(presets.MINIMAL))
+
+    with pytest.raises(AttributeError):
+        presets.MAINNET.NOT_A_CONSTANT
+
+
+def test_diff_presets() -> None:
+    differences = diff_presets("mainnet", presets.GNOSIS)
+    assert differences["SLOTS_PER_EPOCH"] == (32, 16)
+    assert differences["MAX_BLOBS_PER_BLOCK_ELECTRA"] == (9, 2)
+    assert differences["MAX_WITHDRAWALS_PER_PAYLOAD"] == (16, 8)
+    assert "MAX_BLOB_COMMITMENTS_PER_BLOCK" not in differences
+
+    assert diff_presets("Minimal", presets.MINIMAL) == {}
+
+    with pytest.raises(ValueError, match="Unknown preset"):
+        diff_presets("holesky", "mainnet")
+
+
+def test_diff_configs() -> None:
+    devnet = Config(
+        "devnet",
+        "mainnet",
+        12,
+        genesis_time=1_700_000_000,
+        fulu_fork_epoch=Config.mainnet().fulu_fork_epoch,
+    )
+    assert diff_configs(Config.mainnet(), devnet) == {
+        "CONFIG_NAME": ("mainnet", "devnet"),
+        "GENESIS_TIME": (Config.mainnet().genesis_time, 1_700_000_000),
+    }
+
+    assert diff_configs(Config.hoodi(), Config("hoodi", "mainnet", 12)) == {
+        "GENESIS_TIME": (Config.hoodi().genesis_time, 0),
+        "FULU_FORK_EPOCH": (50_688, 2**64 - 1),
+    }
+
+    differences = diff_configs(Config.mainnet(), Config.gnosis())
+    assert differences["PRESET_BASE"] == ("mainnet", "gnosis")
+    assert differences["SECONDS_PER_SLOT"] == (12, 5)
+    assert differences["SLOTS_PER_EPOCH"] == (32, 16)
+    assert differences["MAX_BLOBS_PER_BLOCK_ELECTRA"] == (9, 2)
+
+
+def test_diff_configs_compares_every_value() -> None:
+    mainnet = Config.mainnet()
+    custom = Config(
+        "custom",
+        "minimal",
+        6,
+        genesis_time=1,
+        fulu_fork_epoch=0,
+        max_blobs_per_block_electra=6,
+    )
+    expected = {
+        "CONFIG_NAME": ("mainnet", "custom"),
+        "PRESET_BASE": ("mainnet", "minimal"),
+        "SECONDS_PER_SLOT": (12, 6),
+        "GENESIS_TIME": (mainnet.genesis_time, 1),
+        "FULU_FORK_EPOCH": (mainnet.fulu_fork_epoch, 0),
+        "MAX_BLOBS_PER_BLOCK_ELECTRA": (9, 6),
+    }
+
+    differences = diff_configs(mainnet, custom)
+    assert {name: differences.get(name) for name in expected} == expected
+    assert diff_configs(custom, custom) == {}
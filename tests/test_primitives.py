@@ -0,0 +1,58 @@
+from pathlib import Path
+
+import pytest
+
+from grandine_py import Config, ElectraSignedBeaconBlockGnosis, Epoch, Slot
+
+
+def test_slot_epoch_conversions_use_config_preset() -> None:
+    mainnet_slot = Config.mainnet().slot(100)
+    gnosis_slot = Config.gnosis().slot(100)
+
+    assert mainnet_slot.epoch() == 3
+    assert mainnet_slot.index_in_epoch() == 4
+    assert gnosis_slot.epoch() == 6
+    assert gnosis_slot.index_in_epoch() == 4
+    assert Epoch(6, Config.gnosis()).start_slot() == 96
+    assert Epoch(6, Config.gnosis()).end_slot() == 111
+    assert gnosis_slot in Epoch(6, Config.gnosis())
+
+
+def test_slot_arithmetic() -> None:
+    slot = Slot(10)
+
+    assert isinstance(slot + 5, Slot)
+    assert slot + 5 == 15
+    assert 5 + slot == 15
+    assert Slot(15) - slot == 5
+    assert isinstance(slot - 5, Slot)
+    assert hash(slot) == hash(10)
+    assert Slot(0).start_time() == Config.mainnet().genesis_time
+
+    with pytest.raises(TypeError):
+        slot + Slot(1)
+    with pytest.raises(TypeError):
+        slot + Epoch(1)
+    with pytest.raises(OverflowError):
+        slot - 11
+
+
+def test_mixing_configs_is_rejected() -> None:
+    with pytest.raises(ValueError):
+        Config.mainnet().slot(1) - Config.gnosis().slot(1)
+    with pytest.raises(ValueError):
+        Config.mainnet().slot(1) < Config.gnosis().slot(2)
+
+
+def test_block_slot_is_bound_to_preset_config() -> None:
+    with open(Path(__file__).parent / "electra/fixtures/gnosis-26539000.ssz", "rb") as f:
+        block = ElectraSignedBeaconBlockGnosis.from_ssz(f.read())
+
+    slot = block.slot()
+    assert slot == 26539000
+    assert slot.config == Config.gnosis()
+    assert slot.epoch() == 26539000 // 16
+    assert block.slot(Config.chiado()).config == Config.chiado()
+
+    with pytest.raises(ValueError):
+        block.slot(Config.mainnet())
@@ -0,0 +1,165 @@
+import array
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraSignedBeaconBlockMainnet,
+    extract,
+    extract_columns,
+    set_hex_format,
+)
+
+FIXTURE = Path(__file__).parent / "electra/fixtures/mainnet-13689000.json"
+
+
+@pytest.fixture
+def block_json() -> dict:
+    return json.loads(FIXTURE.read_text())["data"]
+
+
+@pytest.fixture
+def block(block_json: dict) -> ElectraSignedBeaconBlockMainnet:
+    return ElectraSignedBeaconBlockMainnet.from_json(
+        json.dumps({"data": block_json}).encode()
+    )
+
+
+def test_extract_fields_and_projections(
+    block: ElectraSignedBeaconBlockMainnet, block_json: dict
+) -> None:
+    body = block_json["message"]["body"]
+
+    assert extract([block, block], "message.slot") == [13689000, 13689000]
+    assert extract([block], "message.body.attestations[*].data.slot") == [
+        [int(attestation["data"]["slot"]) for attestation in body["attestations"]]
+    ]
+    assert extract([block], "message.body.attestations[-1].data.source") == [
+        {
+            "epoch": int(body["attestations"][-1]["data"]["source"]["epoch"]),
+            "root": body["attestations"][-1]["data"]["source"]["root"],
+        }
+    ]
+    assert extract([block], "message.body.execution_payload.base_fee_per_gas") == [
+        body["execution_payload"]["base_fee_per_gas"]
+    ]
+
+
+def test_extract_filters(
+    block: ElectraSignedBeaconBlockMainnet, block_json: dict
+) -> None:
+    attestations = block_json["message"]["body"]["attestations"]
+    slot = int(attestations[0]["data"]["slot"])
+
+    assert extract(
+        [block], f"message.body.attestations[?data.slot == {slot}].data.slot"
+    ) == [[int(a["data"]["slot"]) for a in attestations if int(a["data"]["slot"]) == slot]]
+    assert extract(
+        [block], f"message.body.attestations[?data.slot < {slot} || !data].data.slot"
+    ) == [[int(a["data"]["slot"]) for a in attestations if int(a["data"]["slot"]) < slot]]
+    assert extract(
+        [block],
+        f"message.body.attestations[?data.target.root == '{attestations[0]['data']['target']['root']}']"
+        ".data.target.epoch",
+    ) == [
+        [
+            int(a["data"]["target"]["epoch"])
+            for a in attestations
+            if a["data"]["target"]["root"] == attestations[0]["data"]["target"]["root"]
+        ]
+    ]
+
+
+def test_extract_missing_paths_yield_none(
+    block: ElectraSignedBeaconBlockMainnet,
+) -> None:
+    assert extract([block], "message.no_such_field") == [None]
+    assert extract([block], "message.body.attestations[1000]") == [None]
+    assert extract([block], "message.body.attestations[*].no_such_field") == [[]]
+
+
+def test_extract_rejects_invalid_input(block: ElectraSignedBeaconBlockMainnet) -> None:
+    with pytest.raises(ValueError, match="Invalid expression"):
+        extract([block], "message.body.attestations[*")
+
+    with pytest.raises(ValueError, match="Invalid expression"):
+        extract([block], "message..slot")
+
+    with pytest.raises(TypeError, match="to_json"):
+        extract([object()], "message.slot")
+
+
+def test_extract_from_any_class(
+    block: ElectraSignedBeaconBlockMainnet, block_json: dict
+) -> None:
+    # Signed headers cannot be enveloped, so they go through to_json_canonical.
+    header = block.signed_header()
+    paths = ["message.slot", "message.proposer_index", "signature"]
+
+    assert [extract([header], path) for path in paths] == [
+        extract([block], path) for path in paths
+    ]
+    assert extract([header], "signature") == [block_json["signature"]]
+
+
+def test_extract_with_hex_format(block: ElectraSignedBeaconBlockMainnet) -> None:
+    # Without the prefix, these committee bits are all decimal digits.
+    bits = "message.body.attestations[1].committee_bits"
+    selected = "message.body.attestations[?committee_bits == '0x0424100012223210']"
+
+    set_hex_format(uppercase=True, prefix=False)
+    try:
+        assert extract([block], bits) == ["0424100012223210"]
+        assert extract([block], f"{selected}.committee_bits") == [["0424100012223210"]]
+        column = "body.attestations[1].committee_bits"
+        assert extract_columns([block], [column]) == {column: ["0424100012223210"]}
+    finally:
+        set_hex_format()
+
+
+def test_extract_columns(
+    block: ElectraSignedBeaconBlockMainnet, block_json: dict
+) -> None:
+    message = block_json["message"]
+    payload = message["body"]["execution_payload"]
+
+    columns = extract_columns(
+        [block, block],
+        [
+            "slot",
+            "proposer_index",
+            "body.execution_payload.gas_used",
+            "body.execution_payload.block_hash",
+            "signature",
+        ],
+    )
+
+    assert list(columns) == [
+        "slot",
+        "proposer_index",
+        "body.execution_payload.gas_used",
+        "body.execution_payload.block_hash",
+        "signature",
+    ]
+    assert isinstance(columns["slot"], array.array)
+    assert columns["slot"].typecode == "Q"
+    assert columns["slot"].tolist() == [int(message["slot"])] * 2
+    assert columns["proposer_index"].tolist() == [int(message["proposer_index"])] * 2
+    assert list(memoryview(columns["body.execution_payload.gas_used"])) == [
+        int(payload["gas_used"])
+    ] * 2
+    assert columns["body.execution_payload.block_hash"] == [payload["block_hash"]] * 2
+    assert columns["signature"] == [block_json["signature"]] * 2
+
+
+def test_extract_columns_mixed_values_fall_back_to_lists(
+    block: ElectraSignedBeaconBlockMainnet,
+) -> None:
+    columns = extract_columns(
+        [block], ["no_such_field", "body.execution_payload.base_fee_per_gas"]
+    )
+
+    assert columns["no_such_field"] == [None]
+    assert isinstance(columns["body.execution_payload.base_fee_per_gas"], list)
+    assert extract_columns([], ["slot"])["slot"].tolist() == []
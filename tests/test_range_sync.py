@@ -0,0 +1,58 @@
+import pytest
+
+from grandine_py import BeaconBlocksByRangeRequest, Config, plan_block_requests
+
+
+def _ranges(requests: list[BeaconBlocksByRangeRequest]) -> list[tuple[int, int]]:
+    return [(request.start_slot, request.end_slot) for request in requests]
+
+
+def test_requests_are_epoch_aligned() -> None:
+    requests = plan_block_requests(5, 300)
+
+    assert _ranges(requests) == [(5, 128), (128, 256), (256, 300)]
+    assert all(request.step == 1 for request in requests)
+    assert sum(request.count for request in requests) == 295
+
+
+def test_small_requests_do_not_cross_epochs() -> None:
+    requests = plan_block_requests(28, 70, 10)
+
+    assert _ranges(requests) == [
+        (28, 32),
+        (32, 42),
+        (42, 52),
+        (52, 62),
+        (62, 64),
+        (64, 70),
+    ]
+
+
+def test_config_selects_slots_per_epoch() -> None:
+    requests = plan_block_requests(0, 50, 20, Config.gnosis())
+
+    assert _ranges(requests) == [(0, 16), (16, 32), (32, 48), (48, 50)]
+
+
+def test_empty_and_invalid_ranges() -> None:
+    assert plan_block_requests(10, 10) == []
+    with pytest.raises(ValueError, match="before start_slot"):
+        plan_block_requests(10, 9)
+    with pytest.raises(ValueError, match="max_per_request"):
+        plan_block_requests(0, 10, 0)
+    with pytest.raises(ValueError, match="max_per_request"):
+        plan_block_requests(0, 10, 129)
+
+
+def test_request_ssz_roundtrip() -> None:
+    request = BeaconBlocksByRangeRequest(64, 32)
+    ssz = request.to_ssz()
+
+    assert ssz == b"".join(value.to_bytes(8, "little") for value in (64, 32, 1))
+    assert BeaconBlocksByRangeRequest.from_ssz(ssz) == request
+    assert hash(BeaconBlocksByRangeRequest.from_ssz(ssz)) == hash(request)
+    assert repr(request) == (
+        "BeaconBlocksByRangeRequest(start_slot=64, count=32, step=1)"
+    )
+    with pytest.raises(ValueError, match="24 bytes"):
+        BeaconBlocksByRangeRequest.from_ssz(ssz[:-1])
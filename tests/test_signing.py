@@ -0,0 +1,117 @@
+import hashlib
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    SignedVoluntaryExit,
+    Slot,
+    bls_sign,
+    compute_selection_proof_signing_root,
+    compute_sync_selection_proof_signing_root,
+    compute_voluntary_exit_signing_root,
+    generate_presigned_exits,
+)
+
+SECRET_KEYS = [bytes([index]) * 32 for index in (1, 2)]
+DOMAIN = bytes.fromhex("05000000") + bytes(range(28))
+
+
+def uint64_root(value: int) -> bytes:
+    return value.to_bytes(32, "little")
+
+
+def signing_root(object_root: bytes, domain: bytes) -> str:
+    return "0x" + hashlib.sha256(object_root + domain).hexdigest()
+
+
+def test_selection_proof_signing_root() -> None:
+    expected = signing_root(uint64_root(123_456), DOMAIN)
+
+    assert compute_selection_proof_signing_root(123_456, DOMAIN) == expected
+    assert (
+        compute_selection_proof_signing_root(123_456, "0x" + DOMAIN.hex()) == expected
+    )
+    assert (
+        compute_selection_proof_signing_root(Slot(123_456), DOMAIN)
+        == expected
+    )
+
+
+def test_sync_selection_proof_signing_root() -> None:
+    data_root = hashlib.sha256(uint64_root(123_456) + uint64_root(3)).digest()
+
+    assert compute_sync_selection_proof_signing_root(
+        123_456, 3, DOMAIN
+    ) == signing_root(data_root, DOMAIN)
+
+
+def test_voluntary_exit_signing_root() -> None:
+    exit_root = hashlib.sha256(uint64_root(400_000) + uint64_root(42)).digest()
+
+    assert compute_voluntary_exit_signing_root(400_000, 42, DOMAIN) == signing_root(
+        exit_root, DOMAIN
+    )
+
+
+def test_generate_presigned_exits() -> None:
+    validators = [(42, SECRET_KEYS[0]), (43, "0x" + SECRET_KEYS[1].hex())]
+
+    exits = generate_presigned_exits(validators, [400_000, 400_001], DOMAIN)
+
+    assert len(exits) == 4
+    for exit, (validator_index, secret_key, epoch) in zip(
+        exits,
+        [
+            (42, SECRET_KEYS[0], 400_000),
+            (42, SECRET_KEYS[0], 400_001),
+            (43, SECRET_KEYS[1], 400_000),
+            (43, SECRET_KEYS[1], 400_001),
+        ],
+        strict=True,
+    ):
+        assert isinstance(exit, SignedVoluntaryExit)
+        assert exit.message().validator_index() == validator_index
+        assert exit.message().epoch() == epoch
+        signing_root = compute_voluntary_exit_signing_root(
+            epoch, validator_index, DOMAIN
+        )
+        assert exit.signature() == bls_sign(secret_key, signing_root)
+
+    encoded = exits[0].to_ssz()
+    assert SignedVoluntaryExit.from_ssz(encoded) == exits[0]
+
+
+def test_generate_presigned_exits_writes_files(tmp_path: Path) -> None:
+    output_dir = tmp_path / "exits"
+
+    exits = generate_presigned_exits(
+        [(42, SECRET_KEYS[0])], [400_000, 400_001], DOMAIN, output_dir=output_dir
+    )
+
+    assert sorted(path.name for path in output_dir.iterdir()) == [
+        "exit_42_400000.json",
+        "exit_42_400000.ssz",
+        "exit_42_400001.json",
+        "exit_42_400001.ssz",
+    ]
+    for exit in exits:
+        stem = f"exit_42_{exit.message().epoch()}"
+        json = (output_dir / f"{stem}.json").read_bytes()
+        ssz = (output_dir / f"{stem}.ssz").read_bytes()
+        assert SignedVoluntaryExit.from_json(json) == exit
+        assert ssz == exit.to_ssz()
+
+
+def test_generate_presigned_exits_rejects_invalid_keys() -> None:
+    with pytest.raises(ValueError, match="Invalid secret key"):
+        generate_presigned_exits(
+            [(42, SECRET_KEYS[0]), (43, b"\xff" * 32)], [1], DOMAIN
+        )
+
+
+def test_signing_root_rejects_invalid_domain() -> None:
+    with pytest.raises(ValueError, match="32 bytes"):
+        compute_selection_proof_signing_root(1, b"\x00" * 4)
+    with pytest.raises(TypeError):
+        compute_sync_selection_proof_signing_root(1, 0, 5)  # type: ignore[arg-type]
@@ -0,0 +1,75 @@
+import pytest
+
+from grandine_py import slashing_exposure
+
+KEY_A = "0x" + "aa" * 48
+KEY_B = bytes([0xBB]) * 48
+ROOT_1 = "0x" + "11" * 32
+ROOT_2 = "0x" + "22" * 32
+
+
+def test_clean_history() -> None:
+    history = [
+        (KEY_A, 0, 1, ROOT_1),
+        (KEY_A, 1, 2),
+        (KEY_A, 1, 2),
+        {"pubkey": KEY_A, "source_epoch": "2", "target_epoch": "3"},
+        (KEY_B, 5, 9),
+    ]
+    report = slashing_exposure(iter(history), [KEY_A])
+
+    assert report["safe"]
+    assert report["attestations"] == 4
+    assert report["skipped"] == 1
+    assert report["keys"] == {
+        KEY_A: {"attestations": 4, "max_source_epoch": 2, "max_target_epoch": 3}
+    }
+    assert report["double_votes"] == report["surround_votes"] == []
+
+
+def test_double_votes() -> None:
+    history = [
+        (KEY_A, 3, 5, ROOT_1),
+        (KEY_A, 3, 5, ROOT_2),
+        (KEY_A, 3, 6, ROOT_1),
+        (KEY_A, 3, 6),
+        (KEY_A, 4, 6),
+    ]
+    report = slashing_exposure(history, [KEY_A])
+
+    assert not report["safe"]
+    votes = [
+        (vote["target_epoch"], vote["first"]["source_epoch"], vote["second"])
+        for vote in report["double_votes"]
+    ]
+    assert votes == [
+        (5, 3, {"source_epoch": 3, "target_epoch": 5, "signing_root": ROOT_2}),
+        (6, 3, {"source_epoch": 4, "target_epoch": 6, "signing_root": None}),
+    ]
+
+
+def test_surround_votes() -> None:
+    history = [(KEY_B, 1, 10), (KEY_B, 2, 9), (KEY_B, 3, 12), (KEY_B, 4, 11)]
+    report = slashing_exposure(history, [KEY_B.hex(), KEY_A])
+
+    assert list(report["keys"]) == ["0x" + KEY_B.hex(), KEY_A]
+    assert report["keys"][KEY_A]["max_target_epoch"] is None
+    assert report["double_votes"] == []
+    assert [
+        (
+            (vote["surrounding"]["source_epoch"], vote["surrounding"]["target_epoch"]),
+            (vote["surrounded"]["source_epoch"], vote["surrounded"]["target_epoch"]),
+        )
+        for vote in report["surround_votes"]
+    ] == [((1, 10), (2, 9)), ((3, 12), (4, 11))]
+
+
+def test_errors() -> None:
+    with pytest.raises(ValueError, match='missing "target_epoch"'):
+        slashing_exposure([{"pubkey": KEY_A, "source_epoch": 0}], [KEY_A])
+
+    with pytest.raises(ValueError, match="3 or 4 items"):
+        slashing_exposure([(KEY_A, 0)], [KEY_A])
+
+    with pytest.raises(ValueError, match="pubkey must be 48 bytes"):
+        slashing_exposure([], ["0x1234"])
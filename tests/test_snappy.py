@@ -0,0 +1,108 @@
+from collections.abc import Iterator
+from pathlib import Path
+
+import pytest
+
+from grandine_py import ElectraSignedBeaconBlockMainnet, set_decode_limits
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+STREAM_IDENTIFIER = b"\xff\x06\x00\x00sNaPpY"
+
+
+@pytest.fixture(autouse=True)
+def reset_limits() -> Iterator[None]:
+    yield
+    set_decode_limits()
+
+
+def _crc32c(data: bytes) -> int:
+    crc = 0xFFFFFFFF
+    for byte in data:
+        crc ^= byte
+        for _ in range(8):
+            crc = (crc >> 1) ^ (0x82F63B78 if crc & 1 else 0)
+    return crc ^ 0xFFFFFFFF
+
+
+def _framed_uncompressed(data: bytes) -> bytes:
+    """Encode data as a framed snappy stream of uncompressed chunks."""
+    out = bytearray(STREAM_IDENTIFIER)
+    for start in range(0, len(data), 65536):
+        chunk = data[start : start + 65536]
+        crc = _crc32c(chunk)
+        masked = (((crc >> 15) | (crc << 17)) + 0xA282EAD8) & 0xFFFFFFFF
+        out.append(0x01)
+        out += (len(chunk) + 4).to_bytes(3, "little")
+        out += masked.to_bytes(4, "little") + chunk
+    return bytes(out)
+
+
+def _raw_literal(data: bytes) -> bytes:
+    """Encode data as an uncompressed (literal-only) raw snappy block."""
+    out = bytearray()
+    length = len(data)
+    while True:
+        out.append((length & 0x7F) | (0x80 if length >> 7 else 0))
+        length >>= 7
+        if not length:
+            break
+    for start in range(0, len(data), 65536):
+        chunk = data[start : start + 65536]
+        out.append(62 << 2)
+        out += (len(chunk) - 1).to_bytes(3, "little")
+        out += chunk
+    return bytes(out)
+
+
+def test_roundtrip() -> None:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    block = ElectraSignedBeaconBlockMainnet.from_ssz(ssz)
+
+    raw = block.to_ssz_snappy()
+    framed = block.to_ssz_snappy(framed=True)
+    assert len(raw) < len(ssz)
+    assert framed.startswith(STREAM_IDENTIFIER)
+    assert not raw.startswith(STREAM_IDENTIFIER)
+
+    for data, framing in ((raw, None), (raw, False), (framed, None), (framed, True)):
+        decoded = ElectraSignedBeaconBlockMainnet.from_ssz_snappy(data, framing)
+        assert decoded.to_ssz() == ssz
+
+
+def test_decode_other_encoders() -> None:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+
+    for data in (_raw_literal(ssz), _framed_uncompressed(ssz)):
+        block = ElectraSignedBeaconBlockMainnet.from_ssz_snappy(data)
+        assert block.to_ssz() == ssz
+
+
+def test_errors() -> None:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    raw = _raw_literal(ssz)
+    framed = bytearray(_framed_uncompressed(ssz))
+
+    with pytest.raises(ValueError, match="Invalid framed snappy data"):
+        ElectraSignedBeaconBlockMainnet.from_ssz_snappy(raw, framed=True)
+    with pytest.raises(ValueError, match="Invalid raw snappy data"):
+        ElectraSignedBeaconBlockMainnet.from_ssz_snappy(bytes(framed), framed=False)
+
+    framed[-1] ^= 0xFF
+    with pytest.raises(ValueError, match="Invalid framed snappy data"):
+        ElectraSignedBeaconBlockMainnet.from_ssz_snappy(bytes(framed))
+
+    with pytest.raises(ValueError):
+        ElectraSignedBeaconBlockMainnet.from_ssz_snappy(_raw_literal(ssz[:-1]))
+
+
+def test_decode_limits() -> None:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+
+    set_decode_limits(max_input_size=len(ssz))
+    for data in (_raw_literal(ssz), _framed_uncompressed(ssz)):
+        ElectraSignedBeaconBlockMainnet.from_ssz_snappy(data)
+
+    set_decode_limits(max_input_size=len(ssz) - 1)
+    for data in (_raw_literal(ssz), _framed_uncompressed(ssz)):
+        with pytest.raises(ValueError, match="decode limit"):
+            ElectraSignedBeaconBlockMainnet.from_ssz_snappy(data)
@@ -0,0 +1,95 @@
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    SignedBeaconBlockHeader,
+    export_test_vectors,
+    run_ssz_static_test,
+)
+
+ROOT = "0x" + "11" * 32
+SIGNATURE = "0x" + "ab" * 96
+
+
+def _case(root: Path, fork: str, name: str) -> Path:
+    return root / "minimal" / fork / "ssz_static" / name / "ssz_random" / "case_0"
+
+
+def test_exported_vectors_pass(tmp_path: Path) -> None:
+    export_test_vectors(tmp_path, types=["Attestation", "BeaconBlockHeader"])
+
+    for fork, name in [
+        ("electra", "Attestation"),
+        ("phase0", "Attestation"),
+        ("phase0", "BeaconBlockHeader"),
+    ]:
+        result = run_ssz_static_test(_case(tmp_path, fork, name))
+        assert result == {
+            "preset": "minimal",
+            "fork": fork,
+            "type": name,
+            "passed": True,
+            "failures": {},
+        }
+
+
+def test_block_style_yaml(tmp_path: Path) -> None:
+    header = SignedBeaconBlockHeader.from_pyspec_dict(
+        {
+            "message": {
+                "slot": 2**64 - 1,
+                "proposer_index": 7,
+                "parent_root": ROOT,
+                "state_root": ROOT,
+                "body_root": ROOT,
+            },
+            "signature": SIGNATURE,
+        }
+    )
+    ssz = header.to_ssz()
+
+    # The official vectors nest containers in block style, and list types
+    # under every fork from the one that introduced them.
+    case = _case(tmp_path, "deneb", "SignedBeaconBlockHeader")
+    case.mkdir(parents=True)
+    (case / "serialized.ssz_snappy").write_bytes(header.to_ssz_snappy())
+    (case / "roots.yaml").write_text(
+        f"root: '{SignedBeaconBlockHeader.hash_tree_root_of_ssz(ssz)}'\n"
+    )
+    (case / "value.yaml").write_text(
+        "message: {slot: 18446744073709551615, proposer_index: 7,\n"
+        f"  parent_root: '{ROOT}', state_root: '{ROOT}', body_root: '{ROOT}'}}\n"
+        f"signature: '{SIGNATURE}'\n"
+    )
+
+    result = run_ssz_static_test(case)
+    assert (result["fork"], result["passed"]) == ("deneb", True)
+
+
+def test_failed_checks(tmp_path: Path) -> None:
+    export_test_vectors(tmp_path, types=["SignedVoluntaryExit"])
+    case = _case(tmp_path, "phase0", "SignedVoluntaryExit")
+
+    (case / "roots.yaml").write_text("root: '0x" + "00" * 32 + "'\n")
+    result = run_ssz_static_test(case)
+    assert not result["passed"]
+    assert list(result["failures"]) == ["root"]
+    assert result["failures"]["root"].startswith("Expected root 0x0000")
+
+    (case / "serialized.ssz_snappy").write_bytes(b"\x00")
+    (case / "value.yaml").write_text("{message: {epoch: 1}}\n")
+    result = run_ssz_static_test(case)
+    assert set(result["failures"]) == {"serialized", "value"}
+    assert "missing field" in result["failures"]["value"]
+
+
+def test_unknown_cases(tmp_path: Path) -> None:
+    with pytest.raises(ValueError, match="Cannot infer"):
+        run_ssz_static_test(tmp_path)
+    with pytest.raises(ValueError, match="No ssz_static type"):
+        run_ssz_static_test(_case(tmp_path, "electra", "NotAType"))
+    with pytest.raises(ValueError, match="No ssz_static type"):
+        run_ssz_static_test(_case(tmp_path, "phase0", "SingleAttestation"))
+    with pytest.raises(OSError):
+        run_ssz_static_test(_case(tmp_path, "electra", "SingleAttestation"))
@@ -0,0 +1,166 @@
+import json
+from pathlib import Path
+from typing import Any
+
+import pytest
+
+import grandine_py
+from grandine_py import (
+    ElectraAttestationMainnet,
+    ElectraBeaconStateMainnet,
+    ElectraBeaconStateMinimal,
+    ElectraExecutionPayloadHeaderMainnet,
+    ElectraExecutionPayloadMainnet,
+    ElectraSignedBeaconBlockGnosis,
+    ElectraSignedBeaconBlockMainnet,
+    ElectraSignedBuilderBidMainnet,
+    ElectraSingleAttestation,
+    FuluBeaconStateMainnet,
+    Phase0AttestationMainnet,
+    export_test_vectors,
+)
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+FORKS = ["Phase0", "Altair", "Bellatrix", "Capella", "Deneb", "Electra", "Fulu"]
+PRESETS = ["Mainnet", "Minimal", "Gnosis"]
+
+# Every class with SSZ size bounds that can be built from a dict, which leaves
+# out the signed builder bids (checked against a fixture below).
+CLASSES = [
+    cls
+    for name in grandine_py.__all__
+    if hasattr(cls := getattr(grandine_py, name), "ssz_max_size")
+    and hasattr(cls, "from_pyspec_dict")
+]
+FIXED_SIZE = [cls for cls in CLASSES if cls.ssz_min_size() == cls.ssz_max_size()]
+VARIABLE_SIZE = [cls for cls in CLASSES if cls not in FIXED_SIZE]
+
+
+def _bounds(cls) -> tuple[int, int, int]:
+    return cls.ssz_fixed_size(), cls.ssz_min_size(), cls.ssz_max_size()
+
+
+def test_fixed_size_type() -> None:
+    assert _bounds(ElectraSingleAttestation) == (240, 240, 240)
+
+
+def test_variable_size_types() -> None:
+    assert _bounds(Phase0AttestationMainnet) == (228, 229, 485)
+    # 2048 * 64 aggregation bits plus the delimiting bit.
+    assert _bounds(ElectraAttestationMainnet) == (236, 237, 236 + 16385)
+    assert _bounds(ElectraExecutionPayloadHeaderMainnet) == (584, 584, 584 + 32)
+
+    max_transactions = 2**20 * (4 + 2**30)
+    assert _bounds(ElectraExecutionPayloadMainnet) == (
+        528,
+        528,
+        528 + 32 + max_transactions + 16 * 44,
+    )
+
+
+def test_bounds_depend_on_fork_and_preset() -> None:
+    electra = ElectraBeaconStateMainnet.ssz_fixed_size()
+
+    # Fulu adds the proposer lookahead vector of 2 * SLOTS_PER_EPOCH indices.
+    assert FuluBeaconStateMainnet.ssz_fixed_size() == electra + 2 * 32 * 8
+    assert ElectraBeaconStateMinimal.ssz_max_size() < ElectraBeaconStateMainnet.ssz_max_size()
+
+
+@pytest.mark.parametrize(
+    ("cls", "fixture"),
+    [
+        (ElectraSignedBeaconBlockMainnet, "mainnet-13689000.ssz"),
+        (ElectraSignedBeaconBlockGnosis, "gnosis-26539000.ssz"),
+        (
+            ElectraSignedBuilderBidMainnet,
+            "mainnet-signed-builder-bid-slot-14149070.ssz",
+        ),
+    ],
+)
+def test_fixtures_fall_within_bounds(cls, fixture: str) -> None:
+    size = len((FIXTURES / fixture).read_bytes())
+
+    assert cls.ssz_fixed_size() <= cls.ssz_min_size() <= size <= cls.ssz_max_size()
+
+
+def _vector_dir(root: Path, cls) -> Path:
+    """
+    Returns the directory of the exported vectors of `cls`, under the latest
+    fork up to the fork of the class that lists its type.
+    """
+    name = cls.__name__
+    fork = next(fork for fork in FORKS if name.startswith(fork))
+    preset = next((preset for preset in PRESETS if name.endswith(preset)), "Mainnet")
+    type_name = name.removeprefix(fork).removesuffix(preset).removeprefix("Mutable")
+
+    for candidate in reversed(FORKS[: FORKS.index(fork) + 1]):
+        path = root / preset.lower() / candidate.lower() / "ssz_static" / type_name
+        if path.exists():
+            return path / "ssz_random"
+    raise AssertionError(f"No test vectors of {type_name} up to {fork}")
+
+
+def _empty_lists(cls, value: Any, root: dict[str, Any]) -> None:
+    """
+    Empties every list in `value`, a part of `root`, that `cls` still decodes
+    without. Lists and vectors are both JSON arrays, and byte lists, bitlists
+    and byte vectors all hex strings, so each is tried and kept if it decodes.
+    """
+
+    def decodes() -> bool:
+        try:
+            cls.from_pyspec_dict(root)
+        except ValueError:
+            return False
+        return True
+
+    items = value.items() if isinstance(value, dict) else enumerate(value)
+    for key, item in list(items):
+        if isinstance(item, str):
+            for empty in ["0x", "0x01"]:
+                value[key] = empty
+                if decodes():
+                    break
+            else:
+                value[key] = item
+        elif isinstance(item, list):
+            value[key] = []
+            if not decodes():
+                value[key] = item
+                # Vectors of containers may hold lists.
+                if any(isinstance(element, dict) for element in item):
+                    _empty_lists(cls, item, root)
+        elif isinstance(item, dict):
+            _empty_lists(cls, item, root)
+
+
+@pytest.fixture(scope="module")
+def vectors(tmp_path_factory: pytest.TempPathFactory) -> Path:
+    root = tmp_path_factory.mktemp("vectors")
+    export_test_vectors(root, cases=2)
+    return root
+
+
+@pytest.mark.parametrize("cls", FIXED_SIZE, ids=lambda cls: cls.__name__)
+def test_fixed_size_bounds_match_encoding(cls) -> None:
+    size = cls.ssz_fixed_size()
+
+    assert _bounds(cls) == (size, size, size)
+    assert len(cls.from_ssz(bytes(size)).to_ssz()) == size
+    with pytest.raises(ValueError):
+        cls.from_ssz(bytes(size - 1))
+
+
+@pytest.mark.parametrize("cls", VARIABLE_SIZE, ids=lambda cls: cls.__name__)
+def test_variable_size_bounds_match_encoding(cls, vectors: Path) -> None:
+    fixed, min_size, max_size = _bounds(cls)
+    assert fixed <= min_size < max_size
+
+    for case_dir in sorted(_vector_dir(vectors, cls).iterdir()):
+        value = json.loads((case_dir / "value.yaml").read_text())
+        size = len(cls.from_pyspec_dict(value).to_ssz())
+        assert min_size <= size <= max_size
+
+        # With every list empty, the encoding is as small as it gets.
+        _empty_lists(cls, value, value)
+        assert len(cls.from_pyspec_dict(value).to_ssz()) == min_size
@@ -0,0 +1,117 @@
+import hashlib
+import json
+
+import pytest
+
+from grandine_py import (
+    AltairContributionAndProofMinimal,
+    AltairSignedContributionAndProofMainnet,
+    AltairSignedContributionAndProofMinimal,
+    AltairSyncCommitteeMessage,
+)
+
+BLOCK_ROOT = "0x" + "ab" * 32
+SIGNATURE = "0x" + "cd" * 96
+DOMAIN = bytes.fromhex("07000000") + bytes(range(28))
+
+
+def _sha256(data: bytes) -> bytes:
+    return hashlib.sha256(data).digest()
+
+
+def _uint64(value: int) -> bytes:
+    return value.to_bytes(32, "little")
+
+
+def _signature_root(signature: str) -> bytes:
+    raw = bytes.fromhex(signature[2:])
+    return _sha256(_sha256(raw[:64]) + _sha256(raw[64:] + bytes(32)))
+
+
+def _decode(cls, data: dict):
+    return cls.from_json(json.dumps({"data": data}).encode())
+
+
+def _contribution(bits: str) -> dict:
+    return {
+        "slot": "100",
+        "beacon_block_root": BLOCK_ROOT,
+        "subcommittee_index": "3",
+        "aggregation_bits": bits,
+        "signature": SIGNATURE,
+    }
+
+
+def _signed_contribution(bits: str) -> dict:
+    return {
+        "message": {
+            "aggregator_index": "9",
+            "contribution": _contribution(bits),
+            "selection_proof": SIGNATURE,
+        },
+        "signature": SIGNATURE,
+    }
+
+
+def test_sync_committee_message() -> None:
+    message = _decode(
+        AltairSyncCommitteeMessage,
+        {
+            "slot": "100",
+            "beacon_block_root": BLOCK_ROOT,
+            "validator_index": "42",
+            "signature": SIGNATURE,
+        },
+    )
+
+    assert message.slot() == 100
+    assert message.beacon_block_root() == BLOCK_ROOT
+    assert message.validator_index() == 42
+    assert message.signature() == SIGNATURE
+    assert AltairSyncCommitteeMessage.from_ssz(message.to_ssz()).slot() == 100
+
+    block_root = bytes.fromhex(BLOCK_ROOT[2:])
+    expected = _sha256(
+        _sha256(_uint64(100) + block_root)
+        + _sha256(_uint64(42) + _signature_root(SIGNATURE))
+    )
+    assert message.hash_tree_root() == "0x" + expected.hex()
+    assert message.signing_root(DOMAIN) == "0x" + _sha256(block_root + DOMAIN).hex()
+
+
+def test_signed_contribution_and_proof() -> None:
+    # Minimal sync subcommittees have 8 members.
+    signed = _decode(
+        AltairSignedContributionAndProofMinimal, _signed_contribution("0x05")
+    )
+    contribution = signed.message().contribution()
+
+    assert signed.message().aggregator_index() == 9
+    assert contribution.subcommittee_index() == 3
+    assert list(contribution.aggregation_bits()) == [True, False, True] + [False] * 5
+    assert signed.ssz_fixed_size() == 8 + 8 + 32 + 8 + 1 + 3 * 96 + 96
+
+    message_root = signed.message().hash_tree_root()
+    assert signed.signing_root(DOMAIN) == signed.message().signing_root(DOMAIN)
+    assert signed.signing_root(DOMAIN) == (
+        "0x" + _sha256(bytes.fromhex(message_root[2:]) + DOMAIN).hex()
+    )
+    assert signed.hash_tree_root() == (
+        AltairSignedContributionAndProofMinimal.hash_tree_root_of_ssz(signed.to_ssz())
+    )
+
+    resigned = signed.message().with_signature(SIGNATURE)
+    assert resigned.to_ssz() == signed.to_ssz()
+    assert isinstance(signed.message(), AltairContributionAndProofMinimal)
+
+
+def test_contribution_bits_per_preset() -> None:
+    # Mainnet sync subcommittees have 128 members.
+    signed = _decode(
+        AltairSignedContributionAndProofMainnet,
+        _signed_contribution("0x" + "ff" * 16),
+    )
+    assert list(signed.message().contribution().aggregation_bits()) == [True] * 128
+
+    with pytest.raises(ValueError):
+        _decode(AltairSignedContributionAndProofMainnet, _signed_contribution("0x05"))
@@ -0,0 +1,71 @@
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraAttestationMinimal,
+    ElectraSingleAttestation,
+    export_test_vectors,
+)
+
+
+def test_export_writes_consistent_cases(tmp_path: Path) -> None:
+    written = export_test_vectors(
+        tmp_path, cases=2, types=["Attestation", "SingleAttestation"]
+    )
+
+    # Electra and phase0 attestations plus single attestations, per preset.
+    assert written == 3 * 3 * 2
+
+    for cls, fork, name in [
+        (ElectraAttestationMinimal, "electra", "Attestation"),
+        (ElectraSingleAttestation, "electra", "SingleAttestation"),
+    ]:
+        case_dir = tmp_path / "minimal" / fork / "ssz_static" / name / "ssz_random" / "case_1"
+        # `value.yaml` is flow style, which is also valid JSON.
+        value = cls.from_pyspec_dict(json.loads((case_dir / "value.yaml").read_text()))
+        root = (case_dir / "roots.yaml").read_text()
+
+        assert root == f"root: '{cls.hash_tree_root_of_ssz(value.to_ssz())}'\n"
+        assert (case_dir / "serialized.ssz_snappy").stat().st_size > 0
+
+
+def test_export_grows_lists_of_containers(tmp_path: Path) -> None:
+    export_test_vectors(tmp_path, types=["SignedBeaconBlock"])
+
+    case_dir = tmp_path / "minimal/electra/ssz_static/SignedBeaconBlock/ssz_random/case_0"
+    body = json.loads((case_dir / "value.yaml").read_text())["message"]["body"]
+
+    for field in [
+        "proposer_slashings",
+        "attestations",
+        "deposits",
+        "voluntary_exits",
+        "bls_to_execution_changes",
+        "blob_kzg_commitments",
+    ]:
+        assert 1 <= len(body[field]) <= 3, field
+    # Electra blocks hold at most one attester slashing.
+    assert len(body["attester_slashings"]) == 1
+    # Lists inside grown elements are grown too.
+    slashing = body["attester_slashings"][0]
+    assert slashing["attestation_1"]["attesting_indices"]
+    assert body["execution_payload"]["withdrawals"]
+
+
+def test_export_is_deterministic_per_seed(tmp_path: Path) -> None:
+    def root(seed: int) -> str:
+        out = tmp_path / str(seed)
+        export_test_vectors(out, seed=seed, types=["SingleAttestation"])
+        return (
+            out / "mainnet/electra/ssz_static/SingleAttestation/ssz_random/case_0/roots.yaml"
+        ).read_text()
+
+    assert root(1) == root(1)
+    assert root(1) != root(2)
+
+
+def test_export_rejects_unknown_types(tmp_path: Path) -> None:
+    with pytest.raises(ValueError, match="Unknown type"):
+        export_test_vectors(tmp_path, types=["NotAType"])
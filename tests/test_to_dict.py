@@ -0,0 +1,81 @@
+import json
+from pathlib import Path
+from typing import Any
+
+import pytest
+
+from grandine_py import ElectraSignedBeaconBlockMainnet, SignedBeaconBlockHeader
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+
+
+def _native(value: Any) -> Any:
+    """The `to_dict` conversion of a Beacon API JSON value."""
+    if isinstance(value, dict):
+        return {key: _native(item) for key, item in value.items()}
+    if isinstance(value, list):
+        return [_native(item) for item in value]
+    if isinstance(value, str) and value.isdigit():
+        return int(value)
+    if isinstance(value, str) and value.startswith("0x"):
+        return bytes.fromhex(value[2:])
+    return value
+
+
+@pytest.fixture
+def block() -> ElectraSignedBeaconBlockMainnet:
+    ssz = (FIXTURES / "mainnet-13689000.ssz").read_bytes()
+    return ElectraSignedBeaconBlockMainnet.from_ssz(ssz)
+
+
+def test_to_dict_matches_json(block: ElectraSignedBeaconBlockMainnet) -> None:
+    native = block.to_dict()
+
+    assert native == _native(json.loads(block.to_json()))
+    payload = native["message"]["body"]["execution_payload"]
+    assert isinstance(payload["block_number"], int)
+    assert isinstance(payload["base_fee_per_gas"], int)
+    assert isinstance(native["signature"], bytes)
+    assert len(native["signature"]) == 96
+
+
+def test_field_access(block: ElectraSignedBeaconBlockMainnet) -> None:
+    assert block.message["slot"] == 13689000
+    assert block.signature == block.to_dict()["signature"]
+
+    header = block.signed_header()
+    assert header.message["slot"] == header.slot()
+
+    with pytest.raises(AttributeError, match="has no attribute 'payload'"):
+        block.payload
+    assert not hasattr(block, "__deepcopy__")
+    assert not hasattr(SignedBeaconBlockHeader, "message")
+
+
+def test_from_dict_roundtrip(block: ElectraSignedBeaconBlockMainnet) -> None:
+    native = block.to_dict()
+    restored = ElectraSignedBeaconBlockMainnet.from_dict(native)
+    assert restored.to_ssz() == block.to_ssz()
+
+    # The Beacon API encoding is accepted as well.
+    from_json = json.loads(block.to_json())
+    from_api = ElectraSignedBeaconBlockMainnet.from_dict(from_json)
+    assert from_api.to_ssz() == block.to_ssz()
+
+
+def test_from_dict_error_paths(block: ElectraSignedBeaconBlockMainnet) -> None:
+    native = block.to_dict()
+    native["message"]["body"]["attestations"][1]["data"]["slot"] = "soon"
+    path = r"at message\.body\.attestations\[1\]\.data\.slot$"
+    with pytest.raises(ValueError, match=path):
+        ElectraSignedBeaconBlockMainnet.from_dict(native)
+
+    native = block.to_dict()
+    del native["message"]["proposer_index"]
+    with pytest.raises(ValueError, match="missing field `proposer_index` at message$"):
+        ElectraSignedBeaconBlockMainnet.from_dict(native)
+
+    native = block.to_dict()
+    native["message"]["body"]["graffiti"] = {1, 2}
+    with pytest.raises(TypeError, match=r"set .* at message\.body\.graffiti$"):
+        ElectraSignedBeaconBlockMainnet.from_dict(native)
@@ -0,0 +1,243 @@
+import hashlib
+import json
+from pathlib import Path
+
+import pytest
+
+from grandine_py import (
+    ElectraSignedBeaconBlockMinimal,
+    FuluSignedBeaconBlockMinimal,
+    PubkeyIndexMap,
+    bls_pubkey,
+    bls_sign,
+    compute_domain,
+)
+
+FIXTURES = Path(__file__).parent / "electra/fixtures"
+GENESIS_VALIDATORS_ROOT = "0x" + "ab" * 32
+FORK_VERSION = "0x05000001"
+INFINITY_SIGNATURE = "0xc0" + "00" * 95
+PROPOSER = 51
+COMMITTEES = {(39, 0): [3, 1, 4, 5], (39, 1): [9, 2, 6, 8]}
+SYNC_COMMITTEE = list(range(10, 42))
+
+
+def secret_key(index: int) -> bytes:
+    return (index + 1).to_bytes(32, "big")
+
+
+def sha256(data: bytes) -> bytes:
+    return hashlib.sha256(data).digest()
+
+
+def uint64_root(value: int) -> bytes:
+    return value.to_bytes(32, "little")
+
+
+def root(value: str) -> bytes:
+    return bytes.fromhex(value[2:])
+
+
+def attestation_data_root(data: dict) -> bytes:
+    def checkpoint(checkpoint: dict) -> bytes:
+        return sha256(uint64_root(int(checkpoint["epoch"])) + root(checkpoint["root"]))
+
+    leaves = [
+        uint64_root(int(data["slot"])),
+        uint64_root(int(data["index"])),
+        root(data["beacon_block_root"]),
+        checkpoint(data["source"]),
+        checkpoint(data["target"]),
+    ] + [bytes(32)] * 3
+    while len(leaves) > 1:
+        leaves = [sha256(a + b) for a, b in zip(leaves[::2], leaves[1::2])]
+    return leaves[0]
+
+
+def sign(index: int, object_root: bytes, domain_type: str) -> str:
+    domain = root(compute_domain(domain_type, FORK_VERSION, GENESIS_VALIDATORS_ROOT))
+    return bls_sign(secret_key(index), "0x" + sha256(object_root + domain).hex())
+
+
+def signed_block_json() -> dict:
+    """The minimal fixture block with a full payload, signed by test keys."""
+    signed = json.loads((FIXTURES / "minimal-signed-blinded-block.json").read_text())
+    message = signed["data"]["message"]
+    body = message["body"]
+    payload = dict(body.pop("execution_payload_header"))
+    del payload["transactions_root"], payload["withdrawals_root"]
+    body["execution_payload"] = payload | {"transactions": [], "withdrawals": []}
+
+    epoch = int(message["slot"]) // 8
+    body["randao_reveal"] = sign(PROPOSER, uint64_root(epoch), "DOMAIN_RANDAO")
+
+    attestation = body["attestations"][0]
+    attestation["aggregation_bits"] = "0x0101"
+    attestation["signature"] = sign(
+        COMMITTEES[(39, 0)][0],
+        attestation_data_root(attestation["data"]),
+        "DOMAIN_BEACON_ATTESTER",
+    )
+
+    body["sync_aggregate"] = {
+        "sync_committee_bits": "0x01000000",
+        "sync_committee_signature": sign(
+            SYNC_COMMITTEE[0], root(message["parent_root"]), "DOMAIN_SYNC_COMMITTEE"
+        ),
+    }
+
+    unsigned = ElectraSignedBeaconBlockMinimal.from_json(json.dumps(signed).encode())
+    signing_root = unsigned.signing_root(GENESIS_VALIDATORS_ROOT, FORK_VERSION)
+    signed["data"]["signature"] = bls_sign(secret_key(PROPOSER), signing_root)
+    return signed
+
+
+def pubkey_provider(index: int) -> str:
+    return bls_pubkey(secret_key(index))
+
+
+def committees(slot: int, committee_index: int) -> list[int]:
+    return COMMITTEES[(slot, committee_index)]
+
+
+@pytest.mark.parametrize(
+    "block_cls", [ElectraSignedBeaconBlockMinimal, FuluSignedBeaconBlockMinimal]
+)
+def test_verify_signatures(
+    block_cls: type[ElectraSignedBeaconBlockMinimal | FuluSignedBeaconBlockMinimal],
+) -> None:
+    block = block_cls.from_json(json.dumps(signed_block_json()).encode())
+
+    assert block.verify_signatures(
+        pubkey_provider,
+        GENESIS_VALIDATORS_ROOT,
+        FORK_VERSION,
+        committees,
+        SYNC_COMMITTEE,
+    ) == {
+        "proposer": True,
+        "randao_reveal": True,
+        "attestations": [True],
+        "sync_aggregate": True,
+    }
+
+    assert block.verify_signatures(
+        pubkey_provider, GENESIS_VALIDATORS_ROOT, FORK_VERSION
+    ) == {
+        "proposer": True,
+        "randao_reveal": True,
+        "attestations": None,
+        "sync_aggregate": None,
+    }
+
+
+def test_verify_signatures_reports_invalid_ones() -> None:
+    signed = signed_block_json()
+    body = signed["data"]["message"]["body"]
+    body["attestations"][0]["aggregation_bits"] = "0x0201"
+    block = ElectraSignedBeaconBlockMinimal.from_json(json.dumps(signed).encode())
+
+    results = block.verify_signatures(
+        pubkey_provider,
+        GENESIS_VALIDATORS_ROOT,
+        "0x05000002",
+        committees,
+        SYNC_COMMITTEE,
+    )
+    assert results == {
+        "proposer": False,
+        "randao_reveal": False,
+        "attestations": [False],
+        "sync_aggregate": False,
+    }
+
+    # The body changed under the proposer signature, and the attestation
+    # names a member that did not sign it.
+    results = block.verify_signatures(
+        pubkey_provider, GENESIS_VALIDATORS_ROOT, FORK_VERSION, committees, SYNC_COMMITTEE
+    )
+    assert results["proposer"] is False
+    assert results["randao_reveal"] is True
+    assert results["attestations"] == [False]
+    assert results["sync_aggregate"] is True
+
+
+def test_verify_signatures_with_pubkey_index_map() -> None:
+    block = ElectraSignedBeaconBlockMinimal.from_json(
+        json.dumps(signed_block_json()).encode()
+    )
+    pubkeys = PubkeyIndexMap()
+    for index in range(PROPOSER + 1):
+        pubkeys.add(pubkey_provider(index))
+
+    results = block.verify_signatures(
+        pubkeys, GENESIS_VALIDATORS_ROOT, FORK_VERSION, committees, SYNC_COMMITTEE
+    )
+    assert results["proposer"] and results["attestations"] == [True]
+
+    with pytest.raises(ValueError, match="No pubkey for validator 51"):
+        block.verify_signatures(PubkeyIndexMap(), GENESIS_VALIDATORS_ROOT, FORK_VERSION)
+    with pytest.raises(TypeError, match="pubkey_provider"):
+        block.verify_signatures({}, GENESIS_VALIDATORS_ROOT, FORK_VERSION)
+    with pytest.raises(ValueError, match="sync_committee must have 32 members"):
+        block.verify_signatures(
+            pubkeys, GENESIS_VALIDATORS_ROOT, FORK_VERSION, None, SYNC_COMMITTEE[:1]
+        )
+
+
+def test_empty_sync_aggregate() -> None:
+    signed = signed_block_json()
+    body = signed["data"]["message"]["body"]
+    body["sync_aggregate"] = {
+        "sync_committee_bits": "0x00000000",
+        "sync_committee_signature": INFINITY_SIGNATURE,
+    }
+    block = ElectraSignedBeaconBlockMinimal.from_json(json.dumps(signed).encode())
+
+    results = block.verify_signatures(
+        pubkey_provider, GENESIS_VALIDATORS_ROOT, FORK_VERSION
+    )
+    assert results["sync_aggregate"] is True
+    assert block.check_structure() == []
+
+    body["sync_aggregate"]["sync_committee_signature"] = body["randao_reveal"]
+    block = ElectraSignedBeaconBlockMinimal.from_json(json.dumps(signed).encode())
+    assert block.verify_signatures(
+        pubkey_provider, GENESIS_VALIDATORS_ROOT, FORK_VERSION
+    )["sync_aggregate"] is False
+    assert block.check_structure() == [
+        "Sync aggregate without participants does not have the infinity signature"
+    ]
+
+
+def test_check_structure() -> None:
+    signed = signed_block_json()
+    block = ElectraSignedBeaconBlockMinimal.from_json(json.dumps(signed).encode())
+    assert block.check_structure() == []
+
+    attestation = signed["data"]["message"]["body"]["attestations"][0]
+    attestation["committee_bits"] = "0x00"
+    attestation["data"]["index"] = "1"
+    attestation["data"]["slot"] = "40"
+    block = ElectraSignedBeaconBlockMinimal.from_json(json.dumps(signed).encode())
+
+    assert block.check_structure() == [
+        "Attestation 0 has data.index 1 instead of 0",
+        "Attestation 0 has no committee bits set",
+        "Attestation 0 of slot 40 cannot be included at slot 40",
+        "Attestation 0 has target epoch 4 not matching its slot 40",
+    ]
+
+
+def test_check_structure_with_overflowing_attestation_data() -> None:
+    max_value = 2**64 - 1
+    signed = signed_block_json()
+    data = signed["data"]["message"]["body"]["attestations"][0]["data"]
+    data["slot"] = str(max_value)
+    data["target"]["epoch"] = str(max_value)
+    block = ElectraSignedBeaconBlockMinimal.from_json(json.dumps(signed).encode())
+
+    assert block.check_structure() == [
+        f"Attestation 0 of slot {max_value} cannot be included at slot 40",
+        f"Attestation 0 has target epoch {max_value} not matching its slot {max_value}",
+    ]